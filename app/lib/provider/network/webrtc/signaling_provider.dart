@@ -92,6 +92,7 @@ class _SetupSignalingConnection extends AsyncGlobalAction {
         deviceType: deviceInfo.deviceType.toRustDeviceType(),
       ),
       privateKey: key.privateKey,
+      allowInsecure: false,
       onConnection: (c) {
         connection = c;
 
@@ -154,6 +155,11 @@ class _SetupSignalingConnection extends AsyncGlobalAction {
 
             await ref.redux(provider).dispatchAsync(AcceptOfferAction());
             break;
+          case WsServerMessage_Announcement():
+            if (kDebugMode) {
+              print('Announcement from $signalingServer (${message.kind}): ${message.message}');
+            }
+            break;
           case WsServerMessage_Answer():
           case WsServerMessage_Error():
         }
@@ -114,6 +114,9 @@ class _AcceptOfferAction extends AsyncReduxAction<WebRTCReceiveService, WebRTCRe
           RTCStatus_Sending() => SessionStatus.sending,
           RTCStatus_Finished() => SessionStatus.finished,
           RTCStatus_Error() => SessionStatus.finishedWithErrors,
+          RTCStatus_Timeout() => SessionStatus.finishedWithErrors,
+          RTCStatus_Paused() => SessionStatus.sending,
+          RTCStatus_Resumed() => SessionStatus.sending,
         },
         sender: state.offer.peer.toDevice(notifier._signalingServer),
         showSenderInfo: true,
@@ -0,0 +1,337 @@
+use localsend::discovery::multicast::DEFAULT_MULTICAST_PORT;
+use localsend::model::transfer::TransferConfig;
+use localsend::webhook::WebhookNotifier;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::net::{Ipv4Addr, SocketAddr};
+use std::path::{Path, PathBuf};
+
+const DEFAULT_ALIAS: &str = "LocalSend CLI";
+const DEFAULT_SIGNALING_SERVER: &str = "wss://public.localsend.org/v1/ws";
+const DEFAULT_STUN_SERVER: &str = "stun:stun.localsend.org:5349";
+
+/// Name of the key file in the config directory, unless configured otherwise.
+const DEFAULT_KEY_FILE: &str = "key.pem";
+
+/// Name of the file with the token of the daemon's control API in the config directory,
+/// unless configured otherwise.
+const DEFAULT_DAEMON_TOKEN_FILE: &str = "daemon-token";
+
+/// The port of the daemon's control API, next to the port of the HTTP server.
+const DEFAULT_DAEMON_PORT: u16 = 53319;
+
+/// Files of at least this size are memory-mapped if `mmap` is enabled,
+/// mapping smaller files costs more than copying them.
+const MMAP_MIN_SIZE: u64 = 16 * 1024 * 1024;
+
+/// The config file: the defaults at the top level and named profiles in `[profiles.<name>]`.
+/// See `config.example.toml`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    /// The directory of the config file, relative paths are resolved against it.
+    pub dir: PathBuf,
+
+    pub defaults: Profile,
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Settings of the config file. Unset values fall back to the top level, then to the built-in defaults.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Profile {
+    /// The name shown to other devices.
+    pub alias: Option<String>,
+
+    /// The port of the HTTP server and of the multicast discovery.
+    pub port: Option<u16>,
+
+    /// The PEM encoded private key identifying this device, generated on first use.
+    /// Devices remember each other by the fingerprint derived from it.
+    pub key_file: Option<PathBuf>,
+
+    /// The directory received files are saved to.
+    pub download_dir: Option<PathBuf>,
+
+    /// The signaling server for transfers via WebRTC.
+    pub signaling_server: Option<String>,
+
+    pub stun_servers: Option<Vec<String>>,
+    pub turn_servers: Option<Vec<TurnServer>>,
+
+    /// The directory `watch` sends new files of.
+    pub watch_dir: Option<PathBuf>,
+
+    /// The device `watch` sends to: its alias, a prefix of its fingerprint, or its IP address.
+    pub watch_to: Option<String>,
+
+    /// Memory-maps large files to send instead of reading them, which saves copying them.
+    /// Files modified while they are sent may crash the CLI.
+    pub mmap: Option<bool>,
+
+    /// The address the control API of `daemon` listens on.
+    pub daemon_listen: Option<SocketAddr>,
+
+    /// The token clients of the control API must present, generated on first use.
+    pub daemon_token_file: Option<PathBuf>,
+
+    /// Which incoming transfers `daemon` accepts until a client changes it.
+    pub daemon_accept: Option<Accept>,
+
+    /// Fingerprints of the devices accepted with `daemon_accept = "listed"`.
+    pub daemon_accept_fingerprints: Option<Vec<String>>,
+
+    /// URLs notified about the start and end of sends and receives via `POST`.
+    pub webhooks: Option<Vec<String>>,
+}
+
+/// Which incoming transfers are accepted without asking.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Accept {
+    /// None, all are declined.
+    #[default]
+    None,
+
+    All,
+
+    /// Those of the devices in `daemon_accept_fingerprints`.
+    Listed,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct TurnServer {
+    /// e.g. `turn:turn.example.com:3478`.
+    pub url: String,
+    pub username: Option<String>,
+
+    /// Never printed.
+    #[serde(skip_serializing)]
+    pub credential: Option<String>,
+}
+
+/// The settings of the selected profile with all defaults applied.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Settings {
+    pub alias: String,
+    pub port: u16,
+    pub key_file: PathBuf,
+    pub download_dir: PathBuf,
+    pub signaling_server: String,
+    pub stun_servers: Vec<String>,
+    pub turn_servers: Vec<TurnServer>,
+    pub watch_dir: Option<PathBuf>,
+    pub watch_to: Option<String>,
+    pub mmap: bool,
+    pub daemon_listen: SocketAddr,
+    pub daemon_token_file: PathBuf,
+    pub daemon_accept: Accept,
+    pub daemon_accept_fingerprints: Vec<String>,
+
+    /// Never printed, the URLs may contain secrets, e.g. Home Assistant webhook IDs.
+    #[serde(skip_serializing)]
+    pub webhooks: Vec<String>,
+}
+
+impl Settings {
+    /// How files to send are read.
+    pub fn transfer(&self) -> TransferConfig {
+        TransferConfig {
+            mmap_min_size: self.mmap.then_some(MMAP_MIN_SIZE),
+            ..TransferConfig::default()
+        }
+    }
+
+    /// Notifies the webhooks about the transfers.
+    pub fn webhooks(&self) -> anyhow::Result<WebhookNotifier> {
+        WebhookNotifier::try_new(&self.webhooks)
+    }
+}
+
+/// `$XDG_CONFIG_HOME/localsend/config.toml`, or `~/.config/localsend/config.toml` if it is not set.
+pub fn default_config_path() -> Option<PathBuf> {
+    let config_home = std::env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| home_dir().map(|home| home.join(".config")))?;
+    Some(config_home.join("localsend").join("config.toml"))
+}
+
+impl Config {
+    /// Loads the config file at `path`, or the default path if `None`.
+    /// A missing file at the default path results in the defaults.
+    pub fn load(path: Option<&Path>) -> anyhow::Result<Self> {
+        let (path, required) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => match default_config_path() {
+                Some(path) => (path, false),
+                None => return Ok(Self::default()),
+            },
+        };
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        match std::fs::read_to_string(&path) {
+            Ok(content) => Self::parse(&content, dir)
+                .map_err(|e| anyhow::anyhow!("Invalid config {}: {e:#}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && !required => Ok(Self {
+                dir,
+                ..Self::default()
+            }),
+            Err(e) => Err(anyhow::anyhow!("Failed to read {}: {e}", path.display())),
+        }
+    }
+
+    fn parse(content: &str, dir: PathBuf) -> anyhow::Result<Self> {
+        let mut table: toml::Table = toml::from_str(content)?;
+        let profiles = match table.remove("profiles") {
+            Some(profiles) => profiles.try_into()?,
+            None => BTreeMap::new(),
+        };
+
+        Ok(Self {
+            dir,
+            defaults: table.try_into()?,
+            profiles,
+        })
+    }
+
+    /// Resolves the settings of the profile, or of the top level if `None`.
+    pub fn settings(&self, profile: Option<&str>) -> anyhow::Result<Settings> {
+        let selected = match profile {
+            Some(name) => self.profiles.get(name).ok_or_else(|| {
+                let names: Vec<_> = self.profiles.keys().map(String::as_str).collect();
+                match names.is_empty() {
+                    true => anyhow::anyhow!("Unknown profile {name}, none are configured"),
+                    false => {
+                        anyhow::anyhow!("Unknown profile {name}, available: {}", names.join(", "))
+                    }
+                }
+            })?,
+            None => &Profile::default(),
+        };
+
+        macro_rules! pick {
+            ($field:ident) => {
+                selected
+                    .$field
+                    .clone()
+                    .or_else(|| self.defaults.$field.clone())
+            };
+        }
+
+        Ok(Settings {
+            alias: pick!(alias).unwrap_or_else(|| DEFAULT_ALIAS.to_string()),
+            port: pick!(port).unwrap_or(DEFAULT_MULTICAST_PORT),
+            key_file: self.resolve(&pick!(key_file).unwrap_or_else(|| DEFAULT_KEY_FILE.into())),
+            download_dir: pick!(download_dir)
+                .map(|dir| self.resolve(&dir))
+                .unwrap_or_else(|| PathBuf::from(".")),
+            signaling_server: pick!(signaling_server)
+                .unwrap_or_else(|| DEFAULT_SIGNALING_SERVER.to_string()),
+            stun_servers: pick!(stun_servers)
+                .unwrap_or_else(|| vec![DEFAULT_STUN_SERVER.to_string()]),
+            turn_servers: pick!(turn_servers).unwrap_or_default(),
+            watch_dir: pick!(watch_dir).map(|dir| self.resolve(&dir)),
+            watch_to: pick!(watch_to),
+            mmap: pick!(mmap).unwrap_or(false),
+            daemon_listen: pick!(daemon_listen).unwrap_or_else(|| {
+                SocketAddr::new(Ipv4Addr::LOCALHOST.into(), DEFAULT_DAEMON_PORT)
+            }),
+            daemon_token_file: self.resolve(
+                &pick!(daemon_token_file).unwrap_or_else(|| DEFAULT_DAEMON_TOKEN_FILE.into()),
+            ),
+            daemon_accept: pick!(daemon_accept).unwrap_or_default(),
+            daemon_accept_fingerprints: pick!(daemon_accept_fingerprints).unwrap_or_default(),
+            webhooks: pick!(webhooks).unwrap_or_default(),
+        })
+    }
+
+    /// Expands `~/` and makes relative paths relative to the config directory.
+    fn resolve(&self, path: &Path) -> PathBuf {
+        if let Ok(rest) = path.strip_prefix("~")
+            && let Some(home) = home_dir()
+        {
+            return home.join(rest);
+        }
+        self.dir.join(path)
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    let home = std::env::var_os("USERPROFILE");
+    #[cfg(not(windows))]
+    let home = std::env::var_os("HOME");
+
+    home.filter(|home| !home.is_empty()).map(PathBuf::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example_config() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/config.example.toml");
+        let config = Config::load(Some(Path::new(path))).unwrap();
+
+        let settings = config.settings(None).unwrap();
+        assert_eq!(settings.alias, "Home Server");
+        assert_eq!(settings.port, 53317);
+        assert_eq!(settings.key_file, config.dir.join("key.pem"));
+        assert_eq!(settings.stun_servers, vec![DEFAULT_STUN_SERVER]);
+        assert_eq!(settings.daemon_token_file, config.dir.join("daemon-token"));
+        assert_eq!(settings.daemon_accept, Accept::Listed);
+        assert_eq!(settings.daemon_accept_fingerprints.len(), 1);
+        assert_eq!(settings.webhooks.len(), 1);
+        assert!(settings.webhooks().is_ok());
+
+        let work = config.settings(Some("work")).unwrap();
+        assert_eq!(work.alias, "Work Laptop");
+        assert_eq!(work.key_file, config.dir.join("work.pem"));
+        assert_eq!(work.signaling_server, settings.signaling_server);
+        assert_eq!(work.turn_servers.len(), 1);
+        assert_eq!(work.turn_servers[0].credential.as_deref(), Some("secret"));
+        assert_eq!(settings.watch_to, None);
+        assert_eq!(settings.transfer().mmap_min_size, None);
+
+        let scanner = config.settings(Some("scanner")).unwrap();
+        assert_eq!(scanner.watch_to.as_deref(), Some("Office PC"));
+        assert!(scanner.watch_dir.is_some());
+        assert_eq!(scanner.transfer().mmap_min_size, Some(MMAP_MIN_SIZE));
+
+        assert!(config.settings(Some("unknown")).is_err());
+    }
+
+    #[test]
+    fn test_profile_fallback() {
+        let config = Config::parse(
+            r#"
+            alias = "Top"
+            download_dir = "downloads"
+
+            [profiles.a]
+            port = 4000
+            "#,
+            PathBuf::from("/etc/localsend"),
+        )
+        .unwrap();
+
+        let settings = config.settings(Some("a")).unwrap();
+        assert_eq!(settings.alias, "Top");
+        assert_eq!(settings.port, 4000);
+        assert_eq!(
+            settings.download_dir,
+            PathBuf::from("/etc/localsend/downloads")
+        );
+        assert_eq!(config.settings(None).unwrap().port, DEFAULT_MULTICAST_PORT);
+        assert_eq!(settings.daemon_accept, Accept::None);
+        assert!(settings.daemon_listen.ip().is_loopback());
+    }
+
+    #[test]
+    fn test_unknown_fields() {
+        assert!(Config::parse("alais = \"Typo\"", PathBuf::new()).is_err());
+        assert!(Config::parse("[profiles.a]\nprot = 1", PathBuf::new()).is_err());
+    }
+}
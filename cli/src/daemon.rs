@@ -0,0 +1,1035 @@
+//! Headless daemon: receives files according to the accept policy and serves the gRPC
+//! control API (`localsend::grpc`), so NAS and server users can drive transfers remotely.
+//! On unix, it can also serve the JSON-RPC control socket (`localsend::control`)
+//! for local scripts.
+
+use crate::config::Accept;
+use crate::discover::{self, Discovery};
+use crate::identity::Identity;
+use crate::receive;
+use crate::send::{self, Target};
+use anyhow::Context;
+#[cfg(unix)]
+use localsend::control::{
+    ControlConfig, ControlEvent, ControlPeer, ControlSocket, TransferUpdate, start_control_socket,
+};
+use localsend::discovery::DiscoveredPeer;
+use localsend::grpc::proto::{
+    self, AcceptMode, AcceptPolicy, Direction, Peer, TransferEvent, TransferFailed,
+    TransferFinished, TransferProgress, TransferStarted, transfer_event,
+};
+use localsend::grpc::{GrpcConfig, GrpcEvent, GrpcServer, GrpcTlsConfig, start_grpc_server};
+use localsend::http::client::sender::{UploadProgress, send_files_v2};
+use localsend::http::client::{ClientError, LsHttpClientV2};
+use localsend::http::dto_v2::RegisterDtoV2;
+use localsend::http::server::common::save::FileUploadTarget;
+use localsend::http::server::v2::{PrepareUploadDecisionV2, ServerEventV2, SessionEndReasonV2};
+use localsend::model::transfer::{FileContent, FileDto, TransferConfig};
+use localsend::webhook::{
+    TransferDirection, WebhookEvent, WebhookEventType, WebhookNotifier, WebhookPeer,
+};
+use localsend::webrtc::webrtc::{RTCFileError, RTCStatus};
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot};
+use tokio_util::sync::CancellationToken;
+
+pub struct DaemonOptions {
+    /// The directory received files are saved to.
+    pub out: PathBuf,
+
+    /// PIN senders must enter.
+    pub pin: Option<String>,
+
+    /// The address the control API listens on.
+    pub listen: SocketAddr,
+
+    /// The file with the token clients of the control API must present.
+    pub token_file: PathBuf,
+
+    /// Which incoming transfers are accepted until a client changes it.
+    pub accept: Accept,
+
+    /// The devices accepted with [`Accept::Listed`].
+    pub accept_fingerprints: Vec<String>,
+
+    /// How sent files are read.
+    pub transfer: TransferConfig,
+
+    /// Notified about the start and end of all transfers.
+    pub webhooks: WebhookNotifier,
+
+    /// The path of the JSON-RPC control socket, not served if `None`.
+    #[cfg(unix)]
+    pub control_socket: Option<PathBuf>,
+}
+
+/// The devices found, mapped by fingerprint.
+type Peers = Arc<Mutex<HashMap<String, DiscoveredPeer>>>;
+
+/// Shared by all sends.
+#[derive(Clone)]
+struct SendContext {
+    client: Arc<LsHttpClientV2>,
+    info: RegisterDtoV2,
+    transfer: TransferConfig,
+    reporter: Reporter,
+}
+
+/// Reports the transfers to the clients of the control APIs and to the webhooks.
+#[derive(Clone)]
+struct Reporter {
+    grpc: Arc<GrpcServer>,
+    #[cfg(unix)]
+    control: Option<Arc<ControlSocket>>,
+    webhooks: WebhookNotifier,
+}
+
+impl Reporter {
+    /// Starts reporting a transfer of the files offered to or by `peer`.
+    fn transfer(
+        &self,
+        id: String,
+        direction: Direction,
+        peer: Peer,
+        webhook_peer: WebhookPeer,
+        files: Vec<FileDto>,
+    ) -> Arc<Transfer> {
+        Arc::new(Transfer {
+            reporter: self.clone(),
+            id,
+            direction,
+            peer,
+            webhook_peer,
+            files: Mutex::new(files),
+            #[cfg(unix)]
+            done: Mutex::new(HashMap::new()),
+        })
+    }
+
+    #[cfg(unix)]
+    fn publish_control(&self, update: TransferUpdate) {
+        if let Some(control) = &self.control {
+            control.publish(update);
+        }
+    }
+}
+
+/// A transfer, reported until it ends.
+struct Transfer {
+    reporter: Reporter,
+    id: String,
+    direction: Direction,
+    peer: Peer,
+    webhook_peer: WebhookPeer,
+
+    /// The offered files, reduced to the accepted ones once started.
+    files: Mutex<Vec<FileDto>>,
+
+    /// The bytes transferred so far, mapped by file ID.
+    #[cfg(unix)]
+    done: Mutex<HashMap<String, u64>>,
+}
+
+impl Transfer {
+    /// The receiver has accepted the files with the given IDs.
+    fn started(&self, accepted: &HashSet<String>) {
+        let mut files = self.files.lock().unwrap();
+        files.retain(|file| accepted.contains(&file.id));
+        self.reporter.grpc.publish(transfer(
+            &self.id,
+            transfer_event::Kind::Started(TransferStarted {
+                direction: self.direction.into(),
+                peer: Some(self.peer.clone()),
+                files: files.iter().map(Into::into).collect(),
+            }),
+        ));
+        #[cfg(unix)]
+        self.reporter.publish_control(TransferUpdate::Started {
+            transfer_id: self.id.clone(),
+        });
+        self.notify(WebhookEventType::SessionStarted, &files, None);
+    }
+
+    fn progress(&self, file_id: String, done_bytes: u64) {
+        #[cfg(unix)]
+        {
+            let mut done = self.done.lock().unwrap();
+            done.insert(file_id.clone(), done_bytes);
+            self.reporter.publish_control(TransferUpdate::Progress {
+                transfer_id: self.id.clone(),
+                sent_bytes: done.values().sum(),
+                total_bytes: crate::progress::total_size(self.files.lock().unwrap().iter()),
+            });
+        }
+        self.reporter.grpc.publish(transfer(
+            &self.id,
+            transfer_event::Kind::Progress(TransferProgress {
+                file_id,
+                done_bytes,
+            }),
+        ));
+    }
+
+    fn finished(&self) {
+        self.reporter.grpc.publish(transfer(
+            &self.id,
+            transfer_event::Kind::Finished(TransferFinished {}),
+        ));
+        #[cfg(unix)]
+        self.reporter.publish_control(TransferUpdate::Finished {
+            transfer_id: self.id.clone(),
+        });
+        let files = self.files.lock().unwrap();
+        self.notify(WebhookEventType::SessionCompleted, &files, None);
+    }
+
+    /// The transfer has been declined, cancelled or has failed.
+    fn failed(&self, error: String) {
+        self.reporter.grpc.publish(transfer(
+            &self.id,
+            transfer_event::Kind::Failed(TransferFailed {
+                error: error.clone(),
+            }),
+        ));
+        #[cfg(unix)]
+        self.reporter.publish_control(TransferUpdate::Failed {
+            transfer_id: self.id.clone(),
+            error: error.clone(),
+        });
+        let files = self.files.lock().unwrap();
+        self.notify(WebhookEventType::SessionFailed, &files, Some(error));
+    }
+
+    fn notify(&self, event: WebhookEventType, files: &[FileDto], error: Option<String>) {
+        self.reporter
+            .webhooks
+            .notify(self.webhook_event(event, files, error));
+    }
+
+    fn webhook_event(
+        &self,
+        event: WebhookEventType,
+        files: &[FileDto],
+        error: Option<String>,
+    ) -> WebhookEvent {
+        let direction = match self.direction {
+            Direction::Receive => TransferDirection::Receive,
+            _ => TransferDirection::Send,
+        };
+        WebhookEvent::new(
+            event,
+            self.id.clone(),
+            direction,
+            self.webhook_peer.clone(),
+            files.iter().map(Into::into).collect(),
+            error,
+        )
+    }
+}
+
+pub async fn run(
+    identity: &Identity,
+    info: RegisterDtoV2,
+    options: DaemonOptions,
+) -> anyhow::Result<()> {
+    tokio::fs::create_dir_all(&options.out)
+        .await
+        .with_context(|| format!("Failed to create {}", options.out.display()))?;
+    let out = Arc::new(options.out);
+    let token = load_or_generate_token(&options.token_file)?;
+
+    let (event_tx, mut event_rx) = mpsc::channel(16);
+    let (stop_tx, stop_rx) = oneshot::channel();
+    let server = receive::start_server(identity, &info, options.pin, event_tx, stop_rx).await?;
+    let mut discovery = Discovery::start(info.clone())?;
+    discovery.announce().await?;
+
+    // The certificate is the one of the HTTP server, so clients can pin its fingerprint.
+    let (grpc_tx, mut grpc_rx) = mpsc::channel(16);
+    let (grpc_stop_tx, grpc_stop_rx) = oneshot::channel();
+    let grpc = start_grpc_server(
+        options.listen,
+        GrpcConfig {
+            token,
+            tls: Some(GrpcTlsConfig {
+                cert: identity.cert.clone(),
+                private_key: identity.private_key.clone(),
+            }),
+            accept_policy: AcceptPolicy {
+                mode: match options.accept {
+                    Accept::None => AcceptMode::Decline,
+                    Accept::All => AcceptMode::AcceptAll,
+                    Accept::Listed => AcceptMode::AcceptListed,
+                }
+                .into(),
+                fingerprints: options.accept_fingerprints,
+            },
+            event_tx: grpc_tx,
+        },
+        grpc_stop_rx,
+    )
+    .await
+    .with_context(|| format!("Failed to start the control API on {}", options.listen))?;
+    let grpc = Arc::new(grpc);
+
+    #[cfg(unix)]
+    let (control, control_stop_tx) = match &options.control_socket {
+        Some(path) => {
+            let (control_tx, control_rx) = mpsc::channel(16);
+            let (control_stop_tx, control_stop_rx) = oneshot::channel();
+            let control = start_control_socket(
+                path,
+                ControlConfig {
+                    event_tx: control_tx,
+                },
+                control_stop_rx,
+            )
+            .await
+            .with_context(|| format!("Failed to start the control socket on {}", path.display()))?;
+            (Some((Arc::new(control), control_rx)), Some(control_stop_tx))
+        }
+        None => (None, None),
+    };
+
+    let reporter = Reporter {
+        grpc: grpc.clone(),
+        #[cfg(unix)]
+        control: control.as_ref().map(|(control, _)| control.clone()),
+        webhooks: options.webhooks,
+    };
+    let context = SendContext {
+        client: Arc::new(LsHttpClientV2::try_new(
+            &identity.private_key,
+            &identity.cert,
+            None,
+        )?),
+        info: info.clone(),
+        transfer: options.transfer,
+        reporter: reporter.clone(),
+    };
+
+    eprintln!(
+        "Running as {} on port {}, saving to {}.",
+        info.alias,
+        info.port,
+        out.display()
+    );
+    eprintln!(
+        "Control API on {} (TLS, certificate fingerprint {}), token in {}. Press Ctrl+C to stop.",
+        grpc.local_addr(),
+        info.fingerprint,
+        options.token_file.display()
+    );
+
+    let peers: Peers = Arc::default();
+    let cancel = CancellationToken::new();
+
+    // Served by a task of its own, as it only exists on unix.
+    #[cfg(unix)]
+    if let Some((control, control_rx)) = control {
+        eprintln!("Control socket on {}.", control.path().display());
+        tokio::spawn(serve_control_socket(
+            control_rx,
+            peers.clone(),
+            context.clone(),
+            cancel.clone(),
+        ));
+    }
+
+    // The incoming transfers, mapped by session ID.
+    let mut transfers: HashMap<String, Arc<Transfer>> = HashMap::new();
+    loop {
+        tokio::select! {
+            Some(peer) = discovery.peers_rx.recv() => {
+                if discover::is_connectable(&peer) && peer.fingerprint != info.fingerprint {
+                    peers.lock().unwrap().insert(peer.fingerprint.clone(), peer);
+                }
+            }
+            Some(event) = grpc_rx.recv() => match event {
+                GrpcEvent::ListPeers { peers_tx } => {
+                    // Devices answering now are listed by the next call.
+                    if let Err(e) = discovery.announce().await {
+                        tracing::warn!("Failed to search for devices: {e:#}");
+                    }
+                    let _ = peers_tx.send(peers.lock().unwrap().values().map(Peer::from).collect());
+                }
+                GrpcEvent::SendFiles {
+                    peer_id,
+                    paths,
+                    pin,
+                    transfer_tx,
+                } => start_send(&context, &peers, &peer_id, paths, pin, transfer_tx, &cancel),
+            },
+            Some(event) = event_rx.recv() => handle_server_event(&reporter, &mut transfers, event, &out),
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    cancel.cancel();
+    let _ = grpc_stop_tx.send(());
+    let _ = stop_tx.send(());
+    #[cfg(unix)]
+    if let Some(control_stop_tx) = control_stop_tx {
+        let _ = control_stop_tx.send(());
+    }
+    grpc.wait_stopped().await;
+    server.wait_stopped().await;
+    #[cfg(unix)]
+    if let Some(control) = &reporter.control {
+        control.wait_stopped().await;
+    }
+    Ok(())
+}
+
+/// Answers the clients of the control socket like those of the gRPC API.
+#[cfg(unix)]
+async fn serve_control_socket(
+    mut control_rx: mpsc::Receiver<ControlEvent>,
+    peers: Peers,
+    context: SendContext,
+    cancel: CancellationToken,
+) {
+    while let Some(event) = control_rx.recv().await {
+        match event {
+            ControlEvent::ListPeers { peers_tx } => {
+                let _ = peers_tx.send(
+                    peers
+                        .lock()
+                        .unwrap()
+                        .values()
+                        .map(ControlPeer::from)
+                        .collect(),
+                );
+            }
+            ControlEvent::Send {
+                peer_id,
+                files,
+                transfer_tx,
+            } => start_send(
+                &context,
+                &peers,
+                &peer_id,
+                files,
+                None,
+                transfer_tx,
+                &cancel,
+            ),
+        }
+    }
+}
+
+/// Sends the files to the device with the fingerprint `peer_id` in the background.
+fn start_send(
+    context: &SendContext,
+    peers: &Peers,
+    peer_id: &str,
+    paths: Vec<PathBuf>,
+    pin: Option<String>,
+    transfer_tx: oneshot::Sender<Result<String, String>>,
+    cancel: &CancellationToken,
+) {
+    let peer = peers
+        .lock()
+        .unwrap()
+        .values()
+        .find(|peer| peer.fingerprint.eq_ignore_ascii_case(peer_id))
+        .cloned();
+    let Some(peer) = peer else {
+        let _ = transfer_tx.send(Err(format!("Unknown peer {peer_id}, list the peers first")));
+        return;
+    };
+    tokio::spawn(send_files(
+        context.clone(),
+        uuid::Uuid::new_v4().to_string(),
+        peer,
+        paths,
+        pin,
+        transfer_tx,
+        cancel.child_token(),
+    ));
+}
+
+/// Loads the token of the control API, or generates and stores one if the file does not exist.
+fn load_or_generate_token(path: &Path) -> anyhow::Result<String> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => {
+            let token = content.trim().to_string();
+            if token.is_empty() {
+                anyhow::bail!("The token file {} is empty", path.display());
+            }
+            Ok(token)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            // Two random UUIDs, 244 random bits.
+            let token = format!(
+                "{}{}",
+                uuid::Uuid::new_v4().simple(),
+                uuid::Uuid::new_v4().simple()
+            );
+            store_token(path, &token)
+                .map_err(|e| anyhow::anyhow!("Failed to write {}: {e}", path.display()))?;
+            Ok(token)
+        }
+        Err(e) => Err(anyhow::anyhow!("Failed to read {}: {e}", path.display())),
+    }
+}
+
+fn store_token(path: &Path, token: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path)?;
+    std::io::Write::write_all(&mut file, format!("{token}\n").as_bytes())
+}
+
+fn transfer(transfer_id: &str, kind: transfer_event::Kind) -> TransferEvent {
+    TransferEvent {
+        transfer_id: transfer_id.to_string(),
+        kind: Some(kind),
+    }
+}
+
+/// The sender of an incoming transfer, identified by the fingerprint of its certificate.
+fn sender_peer(info: &RegisterDtoV2, ip: IpAddr, fingerprint: Option<String>) -> Peer {
+    Peer {
+        id: fingerprint.unwrap_or_default(),
+        alias: info.alias.clone(),
+        device_model: info.device_model.clone(),
+        device_type: info
+            .device_type
+            .as_ref()
+            .map_or(proto::DeviceType::Unspecified, Into::into)
+            .into(),
+        ip: Some(ip.to_string()),
+    }
+}
+
+/// Accepts or declines incoming transfers according to the policy, and reports them.
+fn handle_server_event(
+    reporter: &Reporter,
+    transfers: &mut HashMap<String, Arc<Transfer>>,
+    event: ServerEventV2,
+    out: &Arc<PathBuf>,
+) {
+    match event {
+        ServerEventV2::PrepareUpload {
+            session_id,
+            ip,
+            info,
+            cert_fingerprint,
+            files,
+            decision_tx,
+        } => {
+            let mut files: Vec<FileDto> = files.into_values().collect();
+            files.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+            let ids: HashSet<String> = files.iter().map(|file| file.id.clone()).collect();
+
+            // The fingerprint of the certificate is verified, the one of the info is not.
+            let accepted = cert_fingerprint
+                .as_ref()
+                .is_some_and(|fingerprint| reporter.grpc.accept_policy().accepts(fingerprint));
+            let webhook_peer = WebhookPeer {
+                alias: info.alias.clone(),
+                fingerprint: cert_fingerprint.clone(),
+                device_model: info.device_model.clone(),
+                device_type: info.device_type.clone(),
+            };
+            let transfer = reporter.transfer(
+                session_id.clone(),
+                Direction::Receive,
+                sender_peer(&info, ip, cert_fingerprint),
+                webhook_peer,
+                files,
+            );
+            let alias = &info.alias;
+            if !accepted {
+                eprintln!(
+                    "Declined {} file(s) from {alias}, see the accept policy.",
+                    ids.len()
+                );
+                let _ = decision_tx.send(PrepareUploadDecisionV2::Decline);
+                transfer.failed("Declined by the accept policy".to_string());
+                return;
+            }
+
+            eprintln!("Receiving {} file(s) from {alias}.", ids.len());
+            let _ = decision_tx.send(PrepareUploadDecisionV2::Accept(ids.clone()));
+            transfer.started(&ids);
+            transfers.insert(session_id, transfer);
+        }
+        ServerEventV2::FileUpload {
+            session_id,
+            file,
+            target_tx,
+            ..
+        } => {
+            // Uploads are only accepted for the files of an accepted session.
+            if let Some(transfer) = transfers.get(&session_id) {
+                tokio::spawn(save_file(out.clone(), file, target_tx, transfer.clone()));
+            }
+        }
+        ServerEventV2::SessionEnd { session_id, reason } => {
+            let Some(transfer) = transfers.remove(&session_id) else {
+                return;
+            };
+            let alias = &transfer.peer.alias;
+            match reason {
+                SessionEndReasonV2::Finished => {
+                    eprintln!("Transfer from {alias} finished.");
+                    transfer.finished();
+                }
+                SessionEndReasonV2::Cancelled => {
+                    eprintln!("Transfer from {alias} cancelled by the sender.");
+                    transfer.failed("Cancelled by the sender".to_string());
+                }
+            }
+        }
+        ServerEventV2::PrepareUploadAborted { .. }
+        | ServerEventV2::Register { .. }
+        | ServerEventV2::CancelReceived { .. } => {}
+    }
+}
+
+/// Writes the file below `out`, renaming it if the name is taken.
+async fn save_file(
+    out: Arc<PathBuf>,
+    file: FileDto,
+    target_tx: oneshot::Sender<FileUploadTarget>,
+    transfer: Arc<Transfer>,
+) {
+    let path = match receive::reserve_file(&out, &file).await {
+        Ok(path) => path,
+        Err(e) => {
+            // Dropping the target fails the upload.
+            eprintln!("{}  failed: {e:#}", file.file_name);
+            return;
+        }
+    };
+
+    let (result_tx, result_rx) = oneshot::channel();
+    let (progress_tx, mut progress_rx) = mpsc::channel(16);
+    let target = FileUploadTarget::Path {
+        path: path.clone(),
+        result_tx,
+        progress_tx: Some(progress_tx),
+    };
+    if target_tx.send(target).is_err() {
+        let _ = tokio::fs::remove_file(&path).await;
+        return;
+    }
+
+    while let Some(done) = progress_rx.recv().await {
+        transfer.progress(file.id.clone(), done);
+    }
+
+    let result = result_rx
+        .await
+        .unwrap_or_else(|_| Err("Upload aborted".to_string()));
+    if let Err(e) = result {
+        eprintln!("{}  failed: {e}", file.file_name);
+        // Remove the placeholder of the reserved name.
+        let _ = tokio::fs::remove_file(&path).await;
+    }
+}
+
+/// Sends the files to the device, reporting to the clients of the control APIs.
+/// Answers `transfer_tx` once the files have been collected, so missing files fail the call.
+async fn send_files(
+    context: SendContext,
+    transfer_id: String,
+    peer: DiscoveredPeer,
+    paths: Vec<PathBuf>,
+    pin: Option<String>,
+    transfer_tx: oneshot::Sender<Result<String, String>>,
+    cancel: CancellationToken,
+) {
+    let files = match send::collect_files(paths).await {
+        Ok(files) => files,
+        Err(e) => {
+            let _ = transfer_tx.send(Err(format!("{e:#}")));
+            return;
+        }
+    };
+    if transfer_tx.send(Ok(transfer_id.clone())).is_err() {
+        return;
+    }
+
+    let dtos: Vec<FileDto> = files.iter().map(|(file, _)| file.clone()).collect();
+    let transfer = context.reporter.transfer(
+        transfer_id,
+        Direction::Send,
+        Peer::from(&peer),
+        WebhookPeer::from(&peer),
+        dtos.clone(),
+    );
+    let target = Target::from(peer);
+    eprintln!("Sending {} file(s) to {}.", files.len(), target.alias);
+
+    let (status_tx, status_rx) = mpsc::channel(8);
+    let (progress_tx, progress_rx) = mpsc::channel(16);
+    let (selected_files_tx, selected_files_rx) = oneshot::channel::<HashSet<String>>();
+    let (feed_tx, feed_rx) = oneshot::channel();
+    let (error_tx, error_rx) = mpsc::channel(8);
+    let (pin_tx, pin_rx) = mpsc::channel(1);
+    let (sending_tx, sending_rx) = mpsc::channel(1);
+
+    // Reports the files accepted by the receiver before providing them.
+    tokio::spawn({
+        let transfer = transfer.clone();
+        async move {
+            let Ok(selected) = selected_files_rx.await else {
+                return;
+            };
+            transfer.started(&selected);
+            let _ = feed_tx.send(selected);
+        }
+    });
+    let files = files
+        .into_iter()
+        .map(|(file, path)| (file, FileContent::Path(path)))
+        .collect();
+    tokio::spawn(send::feed_files(
+        files,
+        context.transfer,
+        feed_rx,
+        sending_tx,
+    ));
+
+    let reporter = tokio::spawn(report(
+        transfer.clone(),
+        status_rx,
+        progress_rx,
+        error_rx,
+        pin_rx,
+        pin,
+    ));
+
+    let result = send_files_v2(
+        &context.client,
+        target.protocol,
+        &target.ip,
+        target.port,
+        None,
+        context.info,
+        dtos,
+        status_tx,
+        progress_tx,
+        selected_files_tx,
+        error_tx,
+        pin_tx,
+        sending_rx,
+        cancel,
+    )
+    .await;
+    let outcome = match (result, reporter.await.ok().flatten()) {
+        (Err(ClientError::Cancelled), _) => Err("Cancelled".to_string()),
+        (_, Some(outcome)) => outcome,
+        (Err(e), None) => Err(e.to_string()),
+        (Ok(()), None) => Err("Transfer aborted".to_string()),
+    };
+    match outcome {
+        Ok(()) => {
+            eprintln!("Sent to {}.", target.alias);
+            transfer.finished();
+        }
+        Err(e) => {
+            eprintln!("Failed to send to {}: {e}", target.alias);
+            transfer.failed(e);
+        }
+    }
+}
+
+/// Publishes the progress of a send, answering the first PIN request with `pin`.
+/// Returns the outcome reported by the receiver, if any.
+async fn report(
+    transfer: Arc<Transfer>,
+    mut status_rx: mpsc::Receiver<RTCStatus>,
+    mut progress_rx: mpsc::Receiver<UploadProgress>,
+    mut error_rx: mpsc::Receiver<RTCFileError>,
+    mut pin_rx: mpsc::Receiver<oneshot::Sender<String>>,
+    mut pin: Option<String>,
+) -> Option<Result<(), String>> {
+    let mut failed_files = 0;
+    loop {
+        tokio::select! {
+            Some(progress) = progress_rx.recv() => transfer.progress(progress.file_id, progress.sent),
+            Some(error) = error_rx.recv() => {
+                tracing::warn!("Failed to send file {}: {}", error.file_id, error.error);
+                failed_files += 1;
+            }
+            Some(pin_res_tx) = pin_rx.recv() => {
+                // Dropping the channel aborts the send, a wrong PIN is not tried again.
+                if let Some(pin) = pin.take() {
+                    let _ = pin_res_tx.send(pin);
+                }
+            }
+            Some(status) = status_rx.recv() => match status {
+                RTCStatus::Declined => return Some(Err("Declined by the receiver".to_string())),
+                RTCStatus::TooManyAttempts => return Some(Err("Too many wrong PINs".to_string())),
+                RTCStatus::Error(e) => return Some(Err(e)),
+                RTCStatus::Finished => {
+                    return Some(match failed_files {
+                        0 => Ok(()),
+                        n => Err(format!("{n} file(s) failed")),
+                    });
+                }
+                status => tracing::debug!("Status: {status:?}"),
+            },
+            else => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use localsend::http::dto_v2::ProtocolTypeV2;
+    use localsend::model::discovery::DeviceType;
+    use std::net::Ipv4Addr;
+    use tokio::sync::broadcast;
+
+    /// Reports to a gRPC server that accepts the transfers of `ABCD` only.
+    async fn reporter() -> (Reporter, broadcast::Receiver<TransferEvent>) {
+        let (event_tx, _event_rx) = mpsc::channel(1);
+        let config = GrpcConfig {
+            token: "secret".to_string(),
+            tls: None,
+            accept_policy: AcceptPolicy {
+                mode: AcceptMode::AcceptListed.into(),
+                fingerprints: vec!["ABCD".to_string()],
+            },
+            event_tx,
+        };
+        // Publishing does not need clients, so the server stops right away.
+        let (_, stop_rx) = oneshot::channel();
+        let grpc = start_grpc_server(
+            SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+            config,
+            stop_rx,
+        )
+        .await
+        .unwrap();
+        let events = grpc.subscribe();
+        let reporter = Reporter {
+            grpc: Arc::new(grpc),
+            #[cfg(unix)]
+            control: None,
+            webhooks: WebhookNotifier::try_new(&[]).unwrap(),
+        };
+        (reporter, events)
+    }
+
+    fn file(id: &str, name: &str) -> FileDto {
+        FileDto {
+            id: id.to_string(),
+            file_name: name.to_string(),
+            size: 6,
+            file_type: "text/plain".to_string(),
+            sha256: None,
+            preview: None,
+            metadata: None,
+        }
+    }
+
+    fn prepare_upload(
+        session_id: &str,
+        cert_fingerprint: Option<&str>,
+    ) -> (ServerEventV2, oneshot::Receiver<PrepareUploadDecisionV2>) {
+        let (decision_tx, decision_rx) = oneshot::channel();
+        let event = ServerEventV2::PrepareUpload {
+            session_id: session_id.to_string(),
+            ip: Ipv4Addr::new(192, 168, 1, 2).into(),
+            info: RegisterDtoV2 {
+                alias: "Nice Orange".to_string(),
+                version: "2.1".to_string(),
+                device_model: None,
+                device_type: Some(DeviceType::Mobile),
+                fingerprint: "spoofed".to_string(),
+                port: 53317,
+                protocol: ProtocolTypeV2::Https,
+                download: false,
+            },
+            cert_fingerprint: cert_fingerprint.map(str::to_string),
+            files: HashMap::from([
+                ("f2".to_string(), file("f2", "b.txt")),
+                ("f1".to_string(), file("f1", "a.txt")),
+            ]),
+            decision_tx,
+        };
+        (event, decision_rx)
+    }
+
+    fn session_end(session_id: &str, reason: SessionEndReasonV2) -> ServerEventV2 {
+        ServerEventV2::SessionEnd {
+            session_id: session_id.to_string(),
+            reason,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_accept_policy() {
+        let (reporter, mut events) = reporter().await;
+        let mut transfers = HashMap::new();
+        let out = Arc::new(PathBuf::from("/nonexistent"));
+
+        let (event, decision_rx) = prepare_upload("s1", Some("abcd"));
+        handle_server_event(&reporter, &mut transfers, event, &out);
+        match decision_rx.await.unwrap() {
+            PrepareUploadDecisionV2::Accept(ids) => {
+                assert_eq!(ids, HashSet::from(["f1".to_string(), "f2".to_string()]));
+            }
+            PrepareUploadDecisionV2::Decline => panic!("Declined a listed peer"),
+        }
+        assert!(transfers.contains_key("s1"));
+        let event = events.recv().await.unwrap();
+        assert_eq!(event.transfer_id, "s1");
+        let Some(transfer_event::Kind::Started(started)) = event.kind else {
+            panic!("Expected a started event, got {event:?}");
+        };
+        assert_eq!(started.direction(), Direction::Receive);
+        let peer = started.peer.unwrap();
+        assert_eq!(peer.id, "abcd");
+        assert_eq!(peer.ip.as_deref(), Some("192.168.1.2"));
+        let names: Vec<_> = started
+            .files
+            .iter()
+            .map(|file| file.name.as_str())
+            .collect();
+        assert_eq!(names, ["a.txt", "b.txt"]);
+
+        // Unlisted and unverified senders are declined.
+        for fingerprint in [Some("EFGH"), None] {
+            let (event, decision_rx) = prepare_upload("s2", fingerprint);
+            handle_server_event(&reporter, &mut transfers, event, &out);
+            assert!(matches!(
+                decision_rx.await.unwrap(),
+                PrepareUploadDecisionV2::Decline
+            ));
+            assert!(!transfers.contains_key("s2"));
+            let event = events.recv().await.unwrap();
+            assert_eq!(
+                event.kind,
+                Some(transfer_event::Kind::Failed(TransferFailed {
+                    error: "Declined by the accept policy".to_string(),
+                }))
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_end() {
+        let (reporter, mut events) = reporter().await;
+        let mut transfers = HashMap::new();
+        let out = Arc::new(PathBuf::from("/nonexistent"));
+
+        for session_id in ["s1", "s2"] {
+            let (event, _decision_rx) = prepare_upload(session_id, Some("ABCD"));
+            handle_server_event(&reporter, &mut transfers, event, &out);
+            events.recv().await.unwrap();
+        }
+
+        let event = session_end("s1", SessionEndReasonV2::Finished);
+        handle_server_event(&reporter, &mut transfers, event, &out);
+        let event = events.recv().await.unwrap();
+        assert_eq!(event.transfer_id, "s1");
+        assert_eq!(
+            event.kind,
+            Some(transfer_event::Kind::Finished(TransferFinished {}))
+        );
+
+        let event = session_end("s2", SessionEndReasonV2::Cancelled);
+        handle_server_event(&reporter, &mut transfers, event, &out);
+        let event = events.recv().await.unwrap();
+        assert_eq!(event.transfer_id, "s2");
+        assert_eq!(
+            event.kind,
+            Some(transfer_event::Kind::Failed(TransferFailed {
+                error: "Cancelled by the sender".to_string(),
+            }))
+        );
+        assert!(transfers.is_empty());
+
+        // Sessions that have not been accepted are not reported.
+        let event = session_end("s3", SessionEndReasonV2::Finished);
+        handle_server_event(&reporter, &mut transfers, event, &out);
+        assert!(events.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_event() {
+        let (reporter, _events) = reporter().await;
+        let peer = WebhookPeer {
+            alias: "Nice Orange".to_string(),
+            fingerprint: Some("ABCD".to_string()),
+            device_model: None,
+            device_type: Some(DeviceType::Mobile),
+        };
+        let transfer = reporter.transfer(
+            "t1".to_string(),
+            Direction::Send,
+            Peer::default(),
+            peer.clone(),
+            vec![file("f1", "a.txt"), file("f2", "b.txt")],
+        );
+
+        // Only the accepted files are reported once started.
+        transfer.started(&HashSet::from(["f2".to_string()]));
+        let files = transfer.files.lock().unwrap().clone();
+        let event = transfer.webhook_event(
+            WebhookEventType::SessionFailed,
+            &files,
+            Some("Timeout".to_string()),
+        );
+        assert_eq!(event.event, WebhookEventType::SessionFailed);
+        assert_eq!(event.session_id, "t1");
+        assert_eq!(event.direction, TransferDirection::Send);
+        assert_eq!(event.peer, peer);
+        assert_eq!(event.files.len(), 1);
+        assert_eq!(event.files[0].file_name, "b.txt");
+        assert_eq!(event.error.as_deref(), Some("Timeout"));
+    }
+
+    #[tokio::test]
+    async fn test_save_file_removes_failed_upload() {
+        let out = std::env::temp_dir().join(format!("localsend-test-{}", uuid::Uuid::new_v4()));
+        let (reporter, _events) = reporter().await;
+        let transfer = reporter.transfer(
+            "s1".to_string(),
+            Direction::Receive,
+            Peer::default(),
+            WebhookPeer {
+                alias: "Nice Orange".to_string(),
+                fingerprint: None,
+                device_model: None,
+                device_type: None,
+            },
+            vec![file("f1", "a.txt")],
+        );
+
+        let (target_tx, target_rx) = oneshot::channel();
+        let save = tokio::spawn(save_file(
+            Arc::new(out.clone()),
+            file("f1", "a.txt"),
+            target_tx,
+            transfer,
+        ));
+        let FileUploadTarget::Path { path, .. } = target_rx.await.unwrap() else {
+            panic!("Expected a path target");
+        };
+        assert!(path.exists());
+
+        // Dropping the result channel aborts the upload.
+        save.await.unwrap();
+        assert!(!path.exists());
+        let _ = std::fs::remove_dir_all(&out);
+    }
+}
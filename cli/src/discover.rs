@@ -0,0 +1,130 @@
+use localsend::discovery::mdns::MdnsDiscovery;
+use localsend::discovery::multicast::{MulticastConfig, MulticastDiscovery};
+use localsend::discovery::{self, DiscoveredPeer};
+use localsend::http::dto_v2::RegisterDtoV2;
+use std::net::IpAddr;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Listens for devices via multicast and mDNS.
+pub struct Discovery {
+    multicast: MulticastDiscovery,
+
+    // Kept until the end, dropping it unregisters this device.
+    _mdns: Option<MdnsDiscovery>,
+
+    /// The devices found, possibly several times.
+    pub peers_rx: mpsc::Receiver<DiscoveredPeer>,
+}
+
+impl Discovery {
+    /// Registers this device and starts listening. mDNS is optional, as it is unavailable on some systems.
+    pub fn start(info: RegisterDtoV2) -> anyhow::Result<Self> {
+        let multicast = MulticastDiscovery::bind(MulticastConfig {
+            port: info.port,
+            ..MulticastConfig::new(info.clone())
+        })?;
+
+        let mut receivers = vec![multicast.listen()];
+        let mdns = match MdnsDiscovery::register(info).and_then(|mdns| {
+            receivers.push(mdns.listen()?);
+            Ok(mdns)
+        }) {
+            Ok(mdns) => Some(mdns),
+            Err(e) => {
+                tracing::warn!("mDNS discovery unavailable: {e:#}");
+                None
+            }
+        };
+
+        Ok(Self {
+            multicast,
+            _mdns: mdns,
+            peers_rx: discovery::merge(receivers),
+        })
+    }
+
+    /// Asks the devices on the network to answer.
+    pub async fn announce(&self) -> anyhow::Result<()> {
+        self.multicast.announce().await
+    }
+}
+
+/// Announces this device via multicast and mDNS and collects the devices
+/// answering within `timeout`, once per fingerprint.
+/// Returns early once `found` matches a device.
+pub async fn discover(
+    info: RegisterDtoV2,
+    timeout: Duration,
+    mut found: impl FnMut(&DiscoveredPeer) -> bool,
+) -> anyhow::Result<Vec<DiscoveredPeer>> {
+    let own_fingerprint = info.fingerprint.clone();
+    let mut discovery = Discovery::start(info)?;
+    discovery.announce().await?;
+
+    let mut peers: Vec<DiscoveredPeer> = Vec::new();
+    let deadline = tokio::time::sleep(timeout);
+    tokio::pin!(deadline);
+    loop {
+        let peer = tokio::select! {
+            peer = discovery.peers_rx.recv() => match peer {
+                Some(peer) => peer,
+                None => break,
+            },
+            _ = &mut deadline => break,
+        };
+
+        if !is_connectable(&peer)
+            || peer.fingerprint == own_fingerprint
+            || peers.iter().any(|p| p.fingerprint == peer.fingerprint)
+        {
+            continue;
+        }
+
+        let done = found(&peer);
+        peers.push(peer);
+        if done {
+            break;
+        }
+    }
+
+    Ok(peers)
+}
+
+/// Link-local IPv6 addresses cannot be connected to without the interface.
+pub fn is_connectable(peer: &DiscoveredPeer) -> bool {
+    !matches!(peer.ip, IpAddr::V6(ip) if ip.is_unicast_link_local())
+}
+
+/// Whether `target` (from `--to`) names the peer: its alias (ignoring case)
+/// or a prefix of its fingerprint.
+pub fn matches(peer: &DiscoveredPeer, target: &str) -> bool {
+    peer.alias.eq_ignore_ascii_case(target)
+        || (target.len() >= 4
+            && peer
+                .fingerprint
+                .to_ascii_uppercase()
+                .starts_with(&target.to_ascii_uppercase()))
+}
+
+pub fn print_peers(peers: &[DiscoveredPeer]) {
+    if peers.is_empty() {
+        println!("No devices found.");
+        return;
+    }
+
+    let alias_width = peers
+        .iter()
+        .map(|p| p.alias.chars().count())
+        .max()
+        .unwrap_or(0);
+    for peer in peers {
+        let address = format!("{}:{}", peer.ip, peer.port);
+        let model = peer.device_model.as_deref().unwrap_or("-");
+        let fingerprint: String = peer.fingerprint.chars().take(8).collect();
+        println!(
+            "{:alias_width$}  {address:21}  {model:12}  {fingerprint}",
+            peer.alias
+        );
+    }
+}
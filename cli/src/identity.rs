@@ -0,0 +1,105 @@
+use localsend::crypto::cert::{fingerprint_from_cert_der, generate_self_signed_cert};
+use localsend::crypto::token;
+use localsend::http::dto_v2::{PROTOCOL_VERSION_V2, ProtocolTypeV2, RegisterDtoV2};
+use localsend::model::discovery::DeviceType;
+use pem::{EncodeConfig, LineEnding};
+use std::path::Path;
+
+/// The key and self-signed certificate used for HTTPS, in PEM format.
+pub struct Identity {
+    pub private_key: String,
+    pub cert: String,
+
+    /// The SHA-256 fingerprint of the certificate, identifying this device.
+    pub fingerprint: String,
+}
+
+impl Identity {
+    pub fn generate() -> anyhow::Result<Self> {
+        let key = token::generate_key();
+        let private_key = token::export_private_key(&key)?.to_string();
+        let cert = generate_self_signed_cert(&private_key)?;
+        let fingerprint = fingerprint_from_cert_der(pem::parse(&cert)?.contents());
+
+        Ok(Self {
+            private_key,
+            cert,
+            fingerprint,
+        })
+    }
+
+    /// Loads the identity stored at `path`, the key followed by the certificate.
+    /// Generates and stores a new one if the file does not exist,
+    /// so the fingerprint stays the same across runs.
+    pub fn load_or_generate(path: &Path) -> anyhow::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|e| anyhow::anyhow!("Invalid key file {}: {e:#}", path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                let identity = Self::generate()?;
+                identity
+                    .store(path)
+                    .map_err(|e| anyhow::anyhow!("Failed to write {}: {e}", path.display()))?;
+                Ok(identity)
+            }
+            Err(e) => Err(anyhow::anyhow!("Failed to read {}: {e}", path.display())),
+        }
+    }
+
+    fn parse(content: &str) -> anyhow::Result<Self> {
+        let blocks = pem::parse_many(content)?;
+        let key = blocks
+            .iter()
+            .find(|block| block.tag() == "PRIVATE KEY")
+            .ok_or_else(|| anyhow::anyhow!("No private key"))?;
+        let private_key = encode(key);
+
+        // The certificate contains the date of its creation, a new one would change the fingerprint.
+        let cert = match blocks.iter().find(|block| block.tag() == "CERTIFICATE") {
+            Some(cert) => encode(cert),
+            None => generate_self_signed_cert(&private_key)?,
+        };
+        let fingerprint = fingerprint_from_cert_der(pem::parse(&cert)?.contents());
+
+        Ok(Self {
+            private_key,
+            cert,
+            fingerprint,
+        })
+    }
+
+    fn store(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+        let mut file = options.open(path)?;
+        std::io::Write::write_all(
+            &mut file,
+            format!("{}{}", self.private_key, self.cert).as_bytes(),
+        )
+    }
+
+    /// The info announced to other devices.
+    pub fn register_dto(&self, alias: &str, port: u16) -> RegisterDtoV2 {
+        RegisterDtoV2 {
+            alias: alias.to_string(),
+            version: PROTOCOL_VERSION_V2.to_string(),
+            device_model: Some(std::env::consts::OS.to_string()),
+            device_type: Some(DeviceType::Headless),
+            fingerprint: self.fingerprint.clone(),
+            port,
+            protocol: ProtocolTypeV2::Https,
+            download: false,
+        }
+    }
+}
+
+fn encode(block: &pem::Pem) -> String {
+    pem::encode_config(block, EncodeConfig::new().set_line_ending(LineEnding::LF))
+}
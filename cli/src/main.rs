@@ -1,3 +1,330 @@
-fn main() {
-    println!("Hello, world!");
+//! Command line interface of LocalSend.
+//!
+//! Discovers devices on the local network and sends or receives files
+//! via the HTTP protocol (v2), so it works with every LocalSend app.
+
+mod config;
+mod daemon;
+mod discover;
+mod identity;
+mod progress;
+mod receive;
+mod send;
+mod tui;
+mod watch;
+
+use clap::{Parser, Subcommand};
+use config::{Accept, Config};
+use identity::Identity;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "localsend", version, about)]
+/// Send and receive files with LocalSend devices on the local network.
+struct Cli {
+    /// The config file [default: ~/.config/localsend/config.toml].
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Uses the settings of a profile of the config file.
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    /// The name shown to other devices, overrides the config.
+    #[arg(long, global = true)]
+    alias: Option<String>,
+
+    /// The port of the HTTP server and of the multicast discovery, overrides the config.
+    #[arg(long, global = true)]
+    port: Option<u16>,
+
+    /// Prints debug logs.
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Lists the devices on the local network.
+    Peers {
+        /// How long to wait for devices to answer, in seconds.
+        #[arg(long, default_value_t = 3)]
+        timeout: u64,
+    },
+
+    /// Sends files and directories to a device.
+    Send {
+        /// The files and directories to send, or `-` to send stdin as a single file.
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+
+        /// The receiver: its alias, a prefix of its fingerprint, or its IP address.
+        #[arg(long)]
+        to: String,
+
+        /// How long to search for the receiver, in seconds.
+        #[arg(long, default_value_t = 5)]
+        timeout: u64,
+
+        /// PIN of the receiver, asked for when required otherwise.
+        #[arg(long)]
+        pin: Option<String>,
+
+        /// The file name stdin is sent with.
+        #[arg(long, default_value = "stdin")]
+        name: String,
+    },
+
+    /// Receives files until stopped with Ctrl+C.
+    Receive {
+        /// The directory received files are saved to, overrides the config.
+        #[arg(long)]
+        out: Option<PathBuf>,
+
+        /// PIN senders must enter.
+        #[arg(long)]
+        pin: Option<String>,
+
+        /// Accepts all files without asking.
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Exits after the first transfer.
+        #[arg(long)]
+        once: bool,
+
+        /// Writes a single received file to stdout instead of saving it, then exits.
+        #[arg(long, conflicts_with = "out")]
+        stdout: bool,
+    },
+
+    /// Sends the files appearing in a directory to a device until stopped with Ctrl+C,
+    /// e.g. the scans of a scanner.
+    Watch {
+        /// The directory to watch, overrides the config.
+        dir: Option<PathBuf>,
+
+        /// The receiver: its alias, a prefix of its fingerprint, or its IP address.
+        /// Overrides the config.
+        #[arg(long)]
+        to: Option<String>,
+
+        /// PIN sent if the receiver requires one.
+        #[arg(long)]
+        pin: Option<String>,
+
+        /// How long a file must be unchanged before it is sent, in seconds.
+        #[arg(long, default_value_t = 2)]
+        debounce: u64,
+
+        /// How long to search for the receiver, in seconds.
+        #[arg(long, default_value_t = 5)]
+        timeout: u64,
+
+        /// How often to try sending a file before giving up.
+        #[arg(long, default_value_t = 5)]
+        attempts: u32,
+
+        /// Also sends the files present at the start.
+        #[arg(long)]
+        existing: bool,
+
+        /// Deletes files once sent.
+        #[arg(long)]
+        remove: bool,
+    },
+
+    /// Opens an interactive terminal UI to send files and receive files.
+    Tui {
+        /// Files and directories sent to the selected device; asks for a path if none are given.
+        files: Vec<PathBuf>,
+
+        /// The directory received files are saved to, overrides the config.
+        #[arg(long)]
+        out: Option<PathBuf>,
+
+        /// PIN senders must enter.
+        #[arg(long)]
+        pin: Option<String>,
+    },
+
+    /// Runs headless: receives files according to the accept policy and serves
+    /// the gRPC control API to list devices, send files and stream the transfers.
+    Daemon {
+        /// The directory received files are saved to, overrides the config.
+        #[arg(long)]
+        out: Option<PathBuf>,
+
+        /// PIN senders must enter.
+        #[arg(long)]
+        pin: Option<String>,
+
+        /// The address of the control API, overrides the config.
+        #[arg(long)]
+        listen: Option<SocketAddr>,
+
+        /// Which incoming transfers are accepted, overrides the config.
+        #[arg(long, value_enum)]
+        accept: Option<Accept>,
+
+        /// Also serves the JSON-RPC control socket for local scripts,
+        /// at the given path [default: $XDG_RUNTIME_DIR/localsend.sock].
+        #[cfg(unix)]
+        #[arg(long, value_name = "PATH")]
+        control_socket: Option<Option<PathBuf>>,
+    },
+
+    /// Prints the settings in effect, without credentials.
+    Config,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    // Outcomes are printed by the commands; logs are for debugging only
+    // and would garble the terminal UI.
+    if cli.verbose && !matches!(cli.command, Command::Tui { .. }) {
+        tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_writer(std::io::stderr)
+            .init();
+    }
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> anyhow::Result<()> {
+    let mut settings = Config::load(cli.config.as_deref())?.settings(cli.profile.as_deref())?;
+    if let Some(alias) = cli.alias {
+        settings.alias = alias;
+    }
+    if let Some(port) = cli.port {
+        settings.port = port;
+    }
+
+    if let Command::Config = cli.command {
+        print!("{}", toml::to_string(&settings)?);
+        return Ok(());
+    }
+
+    let identity = Identity::load_or_generate(&settings.key_file)?;
+    let info = identity.register_dto(&settings.alias, settings.port);
+    let transfer = settings.transfer();
+    let webhooks = settings.webhooks()?;
+
+    match cli.command {
+        Command::Peers { timeout } => {
+            let peers = discover::discover(info, Duration::from_secs(timeout), |_| false).await?;
+            discover::print_peers(&peers);
+            Ok(())
+        }
+        Command::Send {
+            files,
+            to,
+            timeout,
+            pin,
+            name,
+        } => {
+            let options = send::SendOptions {
+                paths: files,
+                to,
+                timeout: Duration::from_secs(timeout),
+                pin,
+                stdin_name: name,
+                transfer,
+                webhooks,
+            };
+            send::send(&identity, info, options).await
+        }
+        Command::Receive {
+            out,
+            pin,
+            yes,
+            once,
+            stdout,
+        } => {
+            let options = receive::ReceiveOptions {
+                out: out.unwrap_or(settings.download_dir),
+                pin,
+                auto_accept: yes,
+                once,
+                stdout,
+                webhooks,
+            };
+            receive::receive(&identity, info, options).await
+        }
+        Command::Watch {
+            dir,
+            to,
+            pin,
+            debounce,
+            timeout,
+            attempts,
+            existing,
+            remove,
+        } => {
+            let options = watch::WatchOptions {
+                dir: dir
+                    .or(settings.watch_dir)
+                    .ok_or_else(|| anyhow::anyhow!("No directory to watch, see --help"))?,
+                to: to
+                    .or(settings.watch_to)
+                    .ok_or_else(|| anyhow::anyhow!("No receiver, see --to"))?,
+                pin,
+                debounce: Duration::from_secs(debounce),
+                timeout: Duration::from_secs(timeout),
+                attempts: attempts.max(1),
+                existing,
+                remove,
+                transfer,
+            };
+            watch::watch(&identity, info, options).await
+        }
+        Command::Tui { files, out, pin } => {
+            let options = tui::TuiOptions {
+                out: out.unwrap_or(settings.download_dir),
+                pin,
+                files,
+                transfer,
+            };
+            tui::run(&identity, info, options).await
+        }
+        Command::Daemon {
+            out,
+            pin,
+            listen,
+            accept,
+            #[cfg(unix)]
+            control_socket,
+        } => {
+            let options = daemon::DaemonOptions {
+                out: out.unwrap_or(settings.download_dir),
+                pin,
+                listen: listen.unwrap_or(settings.daemon_listen),
+                token_file: settings.daemon_token_file,
+                accept: accept.unwrap_or(settings.daemon_accept),
+                accept_fingerprints: settings.daemon_accept_fingerprints,
+                transfer,
+                webhooks,
+                #[cfg(unix)]
+                control_socket: control_socket
+                    .map(|path| path.unwrap_or_else(localsend::control::default_socket_path)),
+            };
+            daemon::run(&identity, info, options).await
+        }
+        Command::Config => unreachable!("printed above"),
+    }
 }
@@ -0,0 +1,118 @@
+use localsend::model::transfer::{FileDto, UNKNOWN_SIZE};
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+/// How often the progress line is redrawn at most.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A progress line on stderr for the file being transferred, redrawn in place.
+pub struct Progress {
+    file_name: String,
+    total: u64,
+    done: u64,
+    last_draw: Option<Instant>,
+}
+
+impl Progress {
+    pub fn new(file_name: &str, total: u64) -> Self {
+        Self {
+            file_name: file_name.to_string(),
+            total,
+            done: 0,
+            last_draw: None,
+        }
+    }
+
+    pub fn update(&mut self, done: u64) {
+        self.done = done;
+        if self
+            .last_draw
+            .is_some_and(|last| last.elapsed() < REDRAW_INTERVAL)
+        {
+            return;
+        }
+        self.last_draw = Some(Instant::now());
+        self.draw(done);
+    }
+
+    /// Ends the line with the result of the file.
+    pub fn finish(self, result: Result<(), String>) {
+        match result {
+            Ok(()) => {
+                match self.total {
+                    UNKNOWN_SIZE => self.draw(self.done),
+                    total => self.draw(total),
+                }
+                eprintln!();
+            }
+            Err(e) => eprintln!("\r\x1b[2K{}  failed: {e}", self.file_name),
+        }
+    }
+
+    fn draw(&self, done: u64) {
+        match self.total {
+            UNKNOWN_SIZE => eprint!("\r\x1b[2K{}  {}", self.file_name, format_size(done)),
+            total => {
+                let percent = match total {
+                    0 => 100,
+                    total => done.min(total) * 100 / total,
+                };
+                eprint!(
+                    "\r\x1b[2K{}  {percent:3}%  {} / {}",
+                    self.file_name,
+                    format_size(done),
+                    format_size(total)
+                );
+            }
+        }
+        let _ = std::io::stderr().flush();
+    }
+}
+
+/// The total size of the files, [`UNKNOWN_SIZE`] if the size of one is unknown.
+pub fn total_size<'a>(files: impl IntoIterator<Item = &'a FileDto>) -> u64 {
+    files
+        .into_iter()
+        .map(|file| file.size)
+        .fold(0, u64::saturating_add)
+}
+
+/// Formats a number of bytes with binary units, e.g. `1.5 MiB`.
+pub fn format_size(bytes: u64) -> String {
+    if bytes == UNKNOWN_SIZE {
+        return "unknown size".to_string();
+    }
+
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{bytes} B"),
+        _ => format!("{size:.1} {}", UNITS[unit]),
+    }
+}
+
+/// Asks a question on stdin. Returns `None` at the end of the input.
+///
+/// Reads on a detached thread, so a pending prompt does not keep the program from exiting.
+pub async fn prompt(question: &str) -> Option<String> {
+    eprint!("{question}");
+    let _ = std::io::stderr().flush();
+
+    let (answer_tx, answer_rx) = tokio::sync::oneshot::channel();
+    std::thread::spawn(move || {
+        let mut line = String::new();
+        let answer = match std::io::stdin().read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line.trim().to_string()),
+        };
+        let _ = answer_tx.send(answer);
+    });
+    answer_rx.await.ok().flatten()
+}
@@ -0,0 +1,414 @@
+use crate::identity::Identity;
+use crate::progress::{Progress, format_size, prompt, total_size};
+use anyhow::Context;
+use localsend::discovery::mdns::MdnsDiscovery;
+use localsend::discovery::multicast::{MulticastConfig, MulticastDiscovery};
+use localsend::http::dto_v2::RegisterDtoV2;
+use localsend::http::server::common::save::FileUploadTarget;
+use localsend::http::server::v2::{PrepareUploadDecisionV2, ServerEventV2, SessionEndReasonV2};
+use localsend::http::server::{ServerConfigV2, ServerHandle, TlsConfig, start_with_port};
+use localsend::http::state::ClientInfo;
+use localsend::model::collision::{CollisionPolicy, Reservation, reserve_path};
+use localsend::model::file::sanitize_file_name;
+use localsend::model::transfer::FileDto;
+use localsend::util::io::{DEFAULT_BUFFER_SIZE, buffered_writer};
+use localsend::webhook::{
+    TransferDirection, WebhookEvent, WebhookEventType, WebhookNotifier, WebhookPeer,
+};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::{JoinHandle, JoinSet};
+
+pub struct ReceiveOptions {
+    /// The directory received files are saved to.
+    pub out: PathBuf,
+
+    /// PIN senders must enter.
+    pub pin: Option<String>,
+
+    /// Accepts all files without asking.
+    pub auto_accept: bool,
+
+    /// Stops after the first session has ended.
+    pub once: bool,
+
+    /// Writes a single file to stdout instead of saving it, then stops.
+    pub stdout: bool,
+
+    /// Notified when a session has been accepted and when it has ended.
+    pub webhooks: WebhookNotifier,
+}
+
+/// An offered session, reported to the webhooks.
+#[derive(Clone)]
+struct Session {
+    id: String,
+    peer: WebhookPeer,
+    files: Vec<FileDto>,
+}
+
+impl Session {
+    fn event(&self, event: WebhookEventType, error: Option<String>) -> WebhookEvent {
+        WebhookEvent::new(
+            event,
+            self.id.clone(),
+            TransferDirection::Receive,
+            self.peer.clone(),
+            self.files.iter().map(Into::into).collect(),
+            error,
+        )
+    }
+}
+
+pub async fn receive(
+    identity: &Identity,
+    info: RegisterDtoV2,
+    options: ReceiveOptions,
+) -> anyhow::Result<()> {
+    if !options.stdout {
+        tokio::fs::create_dir_all(&options.out)
+            .await
+            .with_context(|| format!("Failed to create {}", options.out.display()))?;
+    }
+    let out = Arc::new(options.out);
+
+    let (event_tx, mut event_rx) = mpsc::channel(16);
+    let (stop_tx, stop_rx) = oneshot::channel();
+    let server = start_server(identity, &info, options.pin, event_tx, stop_rx).await?;
+
+    // Answers the announcements of other devices, so they find this device.
+    let multicast = MulticastDiscovery::bind(MulticastConfig {
+        port: info.port,
+        ..MulticastConfig::new(info.clone())
+    })?;
+    let mut peers_rx = multicast.listen();
+    tokio::spawn(async move { while peers_rx.recv().await.is_some() {} });
+    multicast.announce().await?;
+    let _mdns = MdnsDiscovery::register(info.clone())
+        .inspect_err(|e| tracing::warn!("mDNS registration unavailable: {e:#}"))
+        .ok();
+
+    let destination = match options.stdout {
+        true => "writing to stdout".to_string(),
+        false => format!("saving to {}", out.display()),
+    };
+    eprintln!(
+        "Receiving as {} on port {}, {destination}. Press Ctrl+C to stop.",
+        info.alias, info.port
+    );
+
+    // The offered sessions, mapped by session ID.
+    let mut sessions: HashMap<String, Session> = HashMap::new();
+    let mut saves = JoinSet::new();
+    // Awaited before exiting, which would abort them.
+    let mut deliveries = JoinSet::new();
+    let mut notify = |event: WebhookEvent| {
+        let webhooks = options.webhooks.clone();
+        deliveries.spawn(async move { webhooks.deliver(&event).await });
+    };
+    // The decisions on the offers, reported once the sender has been answered.
+    let (decided_tx, mut decided_rx) = mpsc::channel::<WebhookEvent>(16);
+    let mut stdout_write: Option<JoinHandle<Result<(), String>>> = None;
+    loop {
+        let event = tokio::select! {
+            event = event_rx.recv() => match event {
+                Some(event) => event,
+                None => break,
+            },
+            Some(event) = decided_rx.recv() => {
+                if event.event == WebhookEventType::SessionFailed {
+                    sessions.remove(&event.session_id);
+                }
+                notify(event);
+                continue;
+            }
+            _ = tokio::signal::ctrl_c() => break,
+        };
+        while saves.try_join_next().is_some() {}
+
+        match event {
+            ServerEventV2::PrepareUpload {
+                session_id,
+                info,
+                cert_fingerprint,
+                files,
+                decision_tx,
+                ..
+            } => {
+                let mut files: Vec<FileDto> = files.into_values().collect();
+                files.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+                let session = Session {
+                    id: session_id,
+                    peer: WebhookPeer {
+                        alias: info.alias,
+                        fingerprint: cert_fingerprint,
+                        device_model: info.device_model,
+                        device_type: info.device_type,
+                    },
+                    files,
+                };
+                if options.stdout && session.files.len() != 1 {
+                    eprintln!(
+                        "Declined {} file(s) from {}, only a single file can be written to stdout.",
+                        session.files.len(),
+                        session.peer.alias
+                    );
+                    let _ = decision_tx.send(PrepareUploadDecisionV2::Decline);
+                    notify(session.event(
+                        WebhookEventType::SessionFailed,
+                        Some("Only a single file can be written to stdout".to_string()),
+                    ));
+                    continue;
+                }
+                sessions.insert(session.id.clone(), session.clone());
+                tokio::spawn(decide(
+                    session,
+                    options.auto_accept,
+                    decision_tx,
+                    decided_tx.clone(),
+                ));
+            }
+            ServerEventV2::FileUpload {
+                file, target_tx, ..
+            } => {
+                if options.stdout {
+                    // Only the first upload is written, the output would be mixed up otherwise.
+                    if stdout_write.is_none() {
+                        stdout_write = Some(tokio::spawn(write_stdout(file, target_tx)));
+                    }
+                } else {
+                    saves.spawn(save_file(out.clone(), file, target_tx));
+                }
+            }
+            ServerEventV2::SessionEnd { session_id, reason } => {
+                if let Some(session) = sessions.remove(&session_id) {
+                    let alias = &session.peer.alias;
+                    match reason {
+                        SessionEndReasonV2::Finished => {
+                            eprintln!("Transfer from {alias} finished.");
+                            notify(session.event(WebhookEventType::SessionCompleted, None));
+                        }
+                        SessionEndReasonV2::Cancelled => {
+                            eprintln!("Transfer from {alias} cancelled by the sender.");
+                            notify(session.event(
+                                WebhookEventType::SessionFailed,
+                                Some("Cancelled by the sender".to_string()),
+                            ));
+                        }
+                    }
+                }
+                if options.once || options.stdout {
+                    break;
+                }
+            }
+            ServerEventV2::PrepareUploadAborted { session_id } => {
+                if let Some(session) = sessions.remove(&session_id) {
+                    eprintln!("{} withdrew the request.", session.peer.alias);
+                    notify(session.event(
+                        WebhookEventType::SessionFailed,
+                        Some("Withdrawn by the sender".to_string()),
+                    ));
+                }
+            }
+            ServerEventV2::Register { .. } | ServerEventV2::CancelReceived { .. } => {}
+        }
+    }
+
+    while let Ok(event) = decided_rx.try_recv() {
+        notify(event);
+    }
+    let _ = stop_tx.send(());
+    server.wait_stopped().await;
+    while saves.join_next().await.is_some() {}
+    while deliveries.join_next().await.is_some() {}
+
+    if options.stdout {
+        match stdout_write {
+            Some(write) => write.await?.map_err(|e| anyhow::anyhow!(e))?,
+            None => anyhow::bail!("Nothing received"),
+        }
+    }
+    Ok(())
+}
+
+/// Starts the HTTPS server receiving files, its events are sent to `event_tx`.
+pub async fn start_server(
+    identity: &Identity,
+    info: &RegisterDtoV2,
+    pin: Option<String>,
+    event_tx: mpsc::Sender<ServerEventV2>,
+    stop_rx: oneshot::Receiver<()>,
+) -> anyhow::Result<ServerHandle> {
+    start_with_port(
+        info.port,
+        Some(TlsConfig {
+            cert: identity.cert.clone(),
+            private_key: identity.private_key.clone(),
+        }),
+        ClientInfo {
+            alias: info.alias.clone(),
+            version: info.version.clone(),
+            device_model: info.device_model.clone(),
+            device_type: info.device_type.clone(),
+            token: info.fingerprint.clone(),
+        },
+        None,
+        Some(ServerConfigV2 { pin, event_tx }),
+        None,
+        stop_rx,
+    )
+    .await
+    .with_context(|| format!("Failed to start the server on port {}", info.port))
+}
+
+/// Asks whether to accept the offered files, unless all offers are accepted.
+/// The event for the webhooks is sent to `decided_tx` once the sender has been answered.
+async fn decide(
+    session: Session,
+    auto_accept: bool,
+    decision_tx: oneshot::Sender<PrepareUploadDecisionV2>,
+    decided_tx: mpsc::Sender<WebhookEvent>,
+) {
+    let (alias, files) = (&session.peer.alias, &session.files);
+    let total = total_size(files);
+    eprintln!(
+        "{alias} wants to send {} file(s) ({}):",
+        files.len(),
+        format_size(total)
+    );
+    for file in files {
+        eprintln!("  {} ({})", file.file_name, format_size(file.size));
+    }
+
+    let accept = auto_accept
+        || prompt("Accept? [y/N] ")
+            .await
+            .is_some_and(|answer| matches!(answer.to_ascii_lowercase().as_str(), "y" | "yes"));
+
+    let (decision, event) = match accept {
+        true => (
+            PrepareUploadDecisionV2::Accept(files.iter().map(|file| file.id.clone()).collect()),
+            session.event(WebhookEventType::SessionStarted, None),
+        ),
+        false => (
+            PrepareUploadDecisionV2::Decline,
+            session.event(
+                WebhookEventType::SessionFailed,
+                Some("Declined".to_string()),
+            ),
+        ),
+    };
+    // Fails if the sender withdrew the request in the meantime.
+    if decision_tx.send(decision).is_ok() {
+        let _ = decided_tx.send(event).await;
+    }
+}
+
+/// Reserves the path of the file below `out`, renaming it if the name is taken.
+pub async fn reserve_file(out: &Path, file: &FileDto) -> anyhow::Result<PathBuf> {
+    let path = out.join(sanitize_file_name(&file.file_name)?);
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    match reserve_path(path, CollisionPolicy::Rename).await? {
+        Reservation::Reserved(path) => Ok(path),
+        reservation => anyhow::bail!("Unexpected reservation: {reservation:?}"),
+    }
+}
+
+/// Writes the file below `out`, renaming it if the name is taken.
+async fn save_file(out: Arc<PathBuf>, file: FileDto, target_tx: oneshot::Sender<FileUploadTarget>) {
+    let path = match reserve_file(&out, &file).await {
+        Ok(path) => path,
+        Err(e) => {
+            // Dropping the target fails the upload.
+            eprintln!("{}  failed: {e:#}", file.file_name);
+            return;
+        }
+    };
+
+    let (result_tx, result_rx) = oneshot::channel();
+    let (progress_tx, mut progress_rx) = mpsc::channel(16);
+    let target = FileUploadTarget::Path {
+        path: path.clone(),
+        result_tx,
+        progress_tx: Some(progress_tx),
+    };
+    if target_tx.send(target).is_err() {
+        let _ = tokio::fs::remove_file(&path).await;
+        return;
+    }
+
+    let name = path
+        .strip_prefix(out.as_path())
+        .unwrap_or(&path)
+        .display()
+        .to_string();
+    let mut progress = Progress::new(&name, file.size);
+    while let Some(written) = progress_rx.recv().await {
+        progress.update(written);
+    }
+
+    let result = result_rx
+        .await
+        .unwrap_or_else(|_| Err("Upload aborted".to_string()));
+    if result.is_err() {
+        // Remove the placeholder of the reserved name.
+        let _ = tokio::fs::remove_file(&path).await;
+    }
+    progress.finish(result);
+}
+
+/// Writes the file to stdout as it arrives, the progress goes to stderr.
+async fn write_stdout(
+    file: FileDto,
+    target_tx: oneshot::Sender<FileUploadTarget>,
+) -> Result<(), String> {
+    let (binary_tx, mut binary_rx) = mpsc::channel(16);
+    let (result_tx, result_rx) = oneshot::channel();
+    let target = FileUploadTarget::Stream {
+        binary_tx,
+        result_rx,
+    };
+    if target_tx.send(target).is_err() {
+        return Err("Upload aborted".to_string());
+    }
+
+    let mut stdout = buffered_writer(tokio::io::stdout(), DEFAULT_BUFFER_SIZE);
+    let mut progress = Progress::new(&file.file_name, file.size);
+    let mut written = 0;
+    let mut result = Ok(());
+    while let Some(chunk) = binary_rx.recv().await {
+        if let Err(e) = stdout.write_all(&chunk).await {
+            // Dropping the channel fails the upload.
+            result = Err(format!("Failed to write to stdout: {e}"));
+            break;
+        }
+        written += chunk.len() as u64;
+        progress.update(written);
+    }
+    if result.is_ok() {
+        result = stdout
+            .flush()
+            .await
+            .map_err(|e| format!("Failed to write to stdout: {e}"));
+    }
+
+    // The server drops the result channel first if the upload was interrupted.
+    if result.is_ok() && result_tx.is_closed() {
+        result = Err("Upload interrupted".to_string());
+    }
+    if result.is_ok() && file.has_known_size() && written != file.size {
+        result = Err(format!(
+            "Size mismatch: expected {} bytes, received {written}",
+            file.size
+        ));
+    }
+
+    let _ = result_tx.send(result.clone());
+    progress.finish(result.clone());
+    result
+}
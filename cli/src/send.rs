@@ -0,0 +1,429 @@
+use crate::discover;
+use crate::identity::Identity;
+use crate::progress::{Progress, format_size, prompt, total_size};
+use anyhow::Context;
+use localsend::discovery::DiscoveredPeer;
+use localsend::http::client::sender::{UploadProgress, send_files_v2};
+use localsend::http::client::{ClientError, LsHttpClientV2};
+use localsend::http::dto::ProtocolType;
+use localsend::http::dto_v2::{ProtocolTypeV2, RegisterDtoV2};
+use localsend::model::discovery::DeviceType;
+use localsend::model::scan::{ScanOptions, scan_dir};
+use localsend::model::transfer::{FileContent, FileDto, TransferConfig, UNKNOWN_SIZE};
+use localsend::util::io::{DEFAULT_BUFFER_SIZE, forward_chunks};
+use localsend::webhook::{
+    TransferDirection, WebhookEvent, WebhookEventType, WebhookFile, WebhookNotifier, WebhookPeer,
+};
+use localsend::webrtc::webrtc::{RTCFile, RTCFileError, RTCStatus};
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio_util::sync::CancellationToken;
+
+/// Chunks read ahead of the upload.
+const STDIN_CHANNEL_CAPACITY: usize = 16;
+
+/// The HTTP server of the receiver.
+pub struct Target {
+    pub alias: String,
+
+    /// Unverified, as announced by the receiver.
+    pub fingerprint: String,
+
+    pub device_model: Option<String>,
+    pub device_type: Option<DeviceType>,
+    pub protocol: ProtocolType,
+    pub ip: String,
+    pub port: u16,
+}
+
+impl From<DiscoveredPeer> for Target {
+    fn from(peer: DiscoveredPeer) -> Self {
+        Self {
+            alias: peer.alias,
+            fingerprint: peer.fingerprint,
+            device_model: peer.device_model,
+            device_type: peer.device_type,
+            protocol: match peer.protocol {
+                ProtocolTypeV2::Http => ProtocolType::Http,
+                ProtocolTypeV2::Https => ProtocolType::Https,
+            },
+            ip: peer.ip.to_string(),
+            port: peer.port,
+        }
+    }
+}
+
+/// What `send` sends, and where.
+pub struct SendOptions {
+    /// The files and directories to send, or `-` for stdin.
+    pub paths: Vec<PathBuf>,
+
+    /// The receiver: its alias, a prefix of its fingerprint, or its IP address.
+    pub to: String,
+
+    /// How long to search for the receiver.
+    pub timeout: Duration,
+
+    /// Answers the first PIN request of the receiver.
+    pub pin: Option<String>,
+
+    /// The file name stdin is sent with.
+    pub stdin_name: String,
+
+    /// How the files are read.
+    pub transfer: TransferConfig,
+
+    /// Notified when the receiver has accepted the files and when the send has ended.
+    pub webhooks: WebhookNotifier,
+}
+
+pub async fn send(
+    identity: &Identity,
+    info: RegisterDtoV2,
+    options: SendOptions,
+) -> anyhow::Result<()> {
+    let cancel = CancellationToken::new();
+    let from_stdin = options.paths.iter().any(|path| path.as_os_str() == "-");
+    let files: Vec<(FileDto, FileContent)> = match options.paths.len() {
+        1 if from_stdin => vec![stdin_file(&options.stdin_name, cancel.clone())],
+        _ if from_stdin => anyhow::bail!("Stdin (`-`) can only be sent on its own"),
+        _ => collect_files(options.paths)
+            .await?
+            .into_iter()
+            .map(|(file, path)| (file, FileContent::Path(path)))
+            .collect(),
+    };
+    let client = LsHttpClientV2::try_new(&identity.private_key, &identity.cert, None)?;
+    let target = resolve_target(&client, info.clone(), &options.to, options.timeout).await?;
+
+    let total = total_size(files.iter().map(|(file, _)| file));
+    eprintln!(
+        "Sending {} file(s) ({}) to {}",
+        files.len(),
+        format_size(total),
+        target.alias
+    );
+
+    let (status_tx, status_rx) = mpsc::channel(8);
+    let (progress_tx, progress_rx) = mpsc::channel(16);
+    let (selected_files_tx, selected_files_rx) = oneshot::channel::<HashSet<String>>();
+    let (error_tx, error_rx) = mpsc::channel(8);
+    let (pin_tx, pin_rx) = mpsc::channel(1);
+    let (sending_tx, sending_rx) = mpsc::channel(1);
+
+    tokio::spawn({
+        let cancel = cancel.clone();
+        async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                cancel.cancel();
+            }
+        }
+    });
+
+    let names: HashMap<String, FileDto> = files
+        .iter()
+        .map(|(file, _)| (file.id.clone(), file.clone()))
+        .collect();
+    let dtos: Vec<FileDto> = files.iter().map(|(file, _)| file.clone()).collect();
+
+    // Notifies the webhooks about the files accepted by the receiver while providing them.
+    // Returns the accepted files.
+    let session_id = uuid::Uuid::new_v4().to_string();
+    let peer = WebhookPeer::from(&target);
+    let offered: Vec<WebhookFile> = dtos.iter().map(Into::into).collect();
+    let (feed_tx, feed_rx) = oneshot::channel();
+    let started = tokio::spawn({
+        let webhooks = options.webhooks.clone();
+        let session_id = session_id.clone();
+        let peer = peer.clone();
+        let dtos = dtos.clone();
+        async move {
+            let selected = selected_files_rx.await.ok()?;
+            let files: Vec<WebhookFile> = dtos
+                .iter()
+                .filter(|file| selected.contains(&file.id))
+                .map(Into::into)
+                .collect();
+            let _ = feed_tx.send(selected);
+            let event = WebhookEvent::new(
+                WebhookEventType::SessionStarted,
+                session_id,
+                TransferDirection::Send,
+                peer,
+                files.clone(),
+                None,
+            );
+            webhooks.deliver(&event).await;
+            Some(files)
+        }
+    });
+    tokio::spawn(feed_files(files, options.transfer, feed_rx, sending_tx));
+
+    // Stdin is the content, it cannot be asked for the PIN.
+    let ui = tokio::spawn(report(
+        names,
+        status_rx,
+        progress_rx,
+        error_rx,
+        pin_rx,
+        options.pin,
+        !from_stdin,
+    ));
+
+    let result = send_files_v2(
+        &client,
+        target.protocol,
+        &target.ip,
+        target.port,
+        None,
+        info,
+        dtos,
+        status_tx,
+        progress_tx,
+        selected_files_tx,
+        error_tx,
+        pin_tx,
+        sending_rx,
+        cancel,
+    )
+    .await;
+
+    let outcome = ui.await?;
+    let outcome = match result {
+        Err(ClientError::Cancelled) => Err(anyhow::anyhow!("Cancelled")),
+        Err(e) => Err(e.into()),
+        Ok(()) => outcome,
+    };
+
+    let (event, error) = match &outcome {
+        Ok(()) => (WebhookEventType::SessionCompleted, None),
+        Err(e) => (WebhookEventType::SessionFailed, Some(format!("{e:#}"))),
+    };
+    // Delivered in order and before exiting, which would abort deliveries in the background.
+    let files = started.await.ok().flatten().unwrap_or(offered);
+    let event = WebhookEvent::new(
+        event,
+        session_id,
+        TransferDirection::Send,
+        peer,
+        files,
+        error,
+    );
+    options.webhooks.deliver(&event).await;
+    outcome
+}
+
+impl From<&Target> for WebhookPeer {
+    fn from(target: &Target) -> Self {
+        Self {
+            alias: target.alias.clone(),
+            fingerprint: Some(target.fingerprint.clone()),
+            device_model: target.device_model.clone(),
+            device_type: target.device_type.clone(),
+        }
+    }
+}
+
+/// Provides the files accepted by the receiver, in the order given.
+pub async fn feed_files(
+    files: Vec<(FileDto, FileContent)>,
+    config: TransferConfig,
+    selected_files_rx: oneshot::Receiver<HashSet<String>>,
+    sending_tx: mpsc::Sender<RTCFile>,
+) {
+    let Ok(selected) = selected_files_rx.await else {
+        return;
+    };
+    for (file, content) in files {
+        if !selected.contains(&file.id) {
+            continue;
+        }
+        let message = RTCFile {
+            file_id: file.id,
+            binary_rx: content.into_receiver_with(config),
+        };
+        if sending_tx.send(message).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Describes stdin as a file of unknown size, streamed as it is read.
+/// A read error cancels the transfer, so the receiver does not keep a truncated file.
+fn stdin_file(name: &str, cancel: CancellationToken) -> (FileDto, FileContent) {
+    let (content_tx, content_rx) = mpsc::channel(STDIN_CHANNEL_CAPACITY);
+    tokio::spawn(async move {
+        let stdin = tokio::io::stdin();
+        if let Err(e) = forward_chunks(stdin, DEFAULT_BUFFER_SIZE, &content_tx).await {
+            eprintln!("Failed to read stdin: {e}");
+            cancel.cancel();
+        }
+    });
+
+    let file = FileDto {
+        id: uuid::Uuid::new_v4().to_string(),
+        file_name: name.to_string(),
+        size: UNKNOWN_SIZE,
+        file_type: "application/octet-stream".to_string(),
+        sha256: None,
+        preview: None,
+        metadata: None,
+    };
+    (file, FileContent::Stream(content_rx))
+}
+
+/// Describes the files to send, including the files of directories.
+pub async fn collect_files(paths: Vec<PathBuf>) -> anyhow::Result<Vec<(FileDto, PathBuf)>> {
+    let mut files = Vec::new();
+    for path in paths {
+        let metadata = tokio::fs::metadata(&path)
+            .await
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        if metadata.is_dir() {
+            for scanned in scan_dir(&path, ScanOptions::default()).await? {
+                files.push((scanned.file, scanned.path));
+            }
+        } else {
+            let file = FileDto::from_path(&path, false)
+                .await
+                .with_context(|| format!("Failed to read {}", path.display()))?;
+            files.push((file, path));
+        }
+    }
+
+    if files.is_empty() {
+        anyhow::bail!("No files to send");
+    }
+    Ok(files)
+}
+
+/// Finds the receiver by IP address (`192.168.1.2` or `192.168.1.2:53317`),
+/// or by alias or fingerprint via discovery.
+pub async fn resolve_target(
+    client: &LsHttpClientV2,
+    info: RegisterDtoV2,
+    to: &str,
+    timeout: Duration,
+) -> anyhow::Result<Target> {
+    let address = to
+        .parse::<SocketAddr>()
+        .ok()
+        .or_else(|| Some(SocketAddr::new(to.parse::<IpAddr>().ok()?, info.port)));
+    if let Some(address) = address {
+        let ip = address.ip().to_string();
+        for protocol in [ProtocolType::Https, ProtocolType::Http] {
+            match client.info(protocol.clone(), &ip, address.port()).await {
+                Ok(peer) => {
+                    return Ok(Target {
+                        alias: peer.alias,
+                        fingerprint: peer.fingerprint,
+                        device_model: peer.device_model,
+                        device_type: peer.device_type,
+                        protocol,
+                        ip,
+                        port: address.port(),
+                    });
+                }
+                Err(e) => tracing::debug!("No {} server at {address}: {e}", protocol.as_str()),
+            }
+        }
+        anyhow::bail!("No LocalSend device at {address}");
+    }
+
+    eprintln!("Searching for {to}...");
+    let peers = discover::discover(info, timeout, |peer| discover::matches(peer, to)).await?;
+    let peer = peers
+        .into_iter()
+        .find(|peer| discover::matches(peer, to))
+        .ok_or_else(|| anyhow::anyhow!("Device {to} not found, see `localsend peers`"))?;
+
+    Ok(peer.into())
+}
+
+/// Prints the progress of the transfer and asks for the PIN when required.
+/// Fails if the transfer did not finish.
+async fn report(
+    files: HashMap<String, FileDto>,
+    mut status_rx: mpsc::Receiver<RTCStatus>,
+    mut progress_rx: mpsc::Receiver<UploadProgress>,
+    mut error_rx: mpsc::Receiver<RTCFileError>,
+    mut pin_rx: mpsc::Receiver<oneshot::Sender<String>>,
+    mut pin: Option<String>,
+    can_prompt: bool,
+) -> anyhow::Result<()> {
+    let mut current: Option<(String, Progress)> = None;
+    let mut failed = HashSet::new();
+    let mut outcome = Err(anyhow::anyhow!("Transfer aborted"));
+
+    loop {
+        tokio::select! {
+            Some(progress) = progress_rx.recv() => {
+                if current.as_ref().is_none_or(|(id, _)| *id != progress.file_id) {
+                    if let Some((_, previous)) = current.take() {
+                        previous.finish(Ok(()));
+                    }
+                    let Some(file) = files.get(&progress.file_id) else {
+                        continue;
+                    };
+                    current = Some((progress.file_id.clone(), Progress::new(&file.file_name, file.size)));
+                }
+                if let Some((_, bar)) = &mut current {
+                    bar.update(progress.sent);
+                }
+            }
+            Some(error) = error_rx.recv() => {
+                failed.insert(error.file_id.clone());
+                match current.take() {
+                    Some((id, bar)) if id == error.file_id => bar.finish(Err(error.error)),
+                    other => {
+                        current = other;
+                        let name = files.get(&error.file_id).map_or(error.file_id.as_str(), |f| f.file_name.as_str());
+                        eprintln!("{name}  failed: {}", error.error);
+                    }
+                }
+            }
+            Some(pin_res_tx) = pin_rx.recv() => {
+                // A given PIN is only tried once, a wrong one is asked for again.
+                let pin = match pin.take() {
+                    Some(pin) => pin,
+                    None if can_prompt => match prompt("PIN: ").await {
+                        Some(pin) => pin,
+                        None => continue,
+                    },
+                    None => {
+                        // Dropping the channel aborts the send.
+                        eprintln!("The receiver requires a PIN, see --pin");
+                        continue;
+                    }
+                };
+                let _ = pin_res_tx.send(pin);
+            }
+            Some(status) = status_rx.recv() => match status {
+                RTCStatus::PinRequired => {}
+                RTCStatus::Sending => {}
+                RTCStatus::Declined => outcome = Err(anyhow::anyhow!("Declined by the receiver")),
+                RTCStatus::TooManyAttempts => outcome = Err(anyhow::anyhow!("Too many wrong PINs")),
+                RTCStatus::Error(e) => outcome = Err(anyhow::anyhow!(e)),
+                RTCStatus::Finished => {
+                    if let Some((_, bar)) = current.take() {
+                        bar.finish(Ok(()));
+                    }
+                    outcome = match failed.len() {
+                        0 => Ok(()),
+                        n => Err(anyhow::anyhow!("{n} file(s) failed")),
+                    };
+                }
+                status => tracing::debug!("Status: {status:?}"),
+            },
+            else => break,
+        }
+    }
+
+    if outcome.is_ok() {
+        eprintln!("Done.");
+    }
+    outcome
+}
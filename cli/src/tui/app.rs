@@ -0,0 +1,423 @@
+use localsend::discovery::DiscoveredPeer;
+use localsend::http::server::v2::PrepareUploadDecisionV2;
+use localsend::model::transfer::{FileDto, UNKNOWN_SIZE};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::widgets::ListState;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use tokio::sync::oneshot;
+
+/// An upload request of another device, waiting for a decision.
+pub struct Offer {
+    pub session_id: String,
+    pub alias: String,
+    pub files: Vec<FileDto>,
+    pub decision_tx: oneshot::Sender<PrepareUploadDecisionV2>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Direction {
+    Send,
+    Receive,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransferState {
+    /// Waiting for the receiver to accept.
+    Waiting,
+    Running,
+    Done,
+    Failed(String),
+}
+
+/// A file being sent or received.
+#[derive(Clone, Debug)]
+pub struct Transfer {
+    /// The upload session, or a local ID for sends.
+    pub session: String,
+    pub file_id: String,
+    pub direction: Direction,
+
+    /// The alias of the other device.
+    pub peer: String,
+    pub name: String,
+    pub size: u64,
+
+    /// The bytes sent or received.
+    pub done: u64,
+    pub state: TransferState,
+}
+
+impl Transfer {
+    /// Counts all bytes as transferred, the progress may lag behind.
+    fn finish(&mut self) {
+        if self.size != UNKNOWN_SIZE {
+            self.done = self.size;
+        }
+    }
+}
+
+/// Reported by the tasks sending and receiving files.
+pub enum Update {
+    Added(Vec<Transfer>),
+    Progress {
+        session: String,
+        file_id: String,
+        done: u64,
+    },
+    FileFinished {
+        session: String,
+        file_id: String,
+        result: Result<(), String>,
+    },
+    /// Finishes all files of the session not finished yet.
+    SessionFinished {
+        session: String,
+        result: Result<(), String>,
+    },
+    /// The receiver requires a PIN.
+    Pin(oneshot::Sender<String>),
+    Status(String),
+}
+
+/// What keyboard input goes to.
+pub enum Input {
+    Peers,
+
+    /// The path of a file or directory to send to the selected device.
+    Path(String),
+
+    /// The PIN the receiver requires.
+    Pin {
+        input: String,
+        pin_tx: oneshot::Sender<String>,
+    },
+}
+
+/// What the event loop has to do after a key press.
+#[derive(Debug, PartialEq)]
+pub enum Action {
+    None,
+    Quit,
+
+    /// Asks the devices on the network to answer again.
+    Refresh,
+
+    /// Cancels all running sends.
+    Cancel,
+    Send {
+        peer: DiscoveredPeer,
+        paths: Vec<PathBuf>,
+    },
+}
+
+pub struct App {
+    pub alias: String,
+    pub port: u16,
+    pub out: PathBuf,
+    own_fingerprint: String,
+
+    /// Files given on the command line, sent instead of asking for a path.
+    files: Vec<PathBuf>,
+
+    pub peers: Vec<DiscoveredPeer>,
+    pub peer_list: ListState,
+
+    /// The first one is shown.
+    pub offers: VecDeque<Offer>,
+    pub transfers: Vec<Transfer>,
+    pub input: Input,
+    pub status: String,
+}
+
+impl App {
+    pub fn new(
+        alias: String,
+        port: u16,
+        out: PathBuf,
+        own_fingerprint: String,
+        files: Vec<PathBuf>,
+    ) -> Self {
+        Self {
+            alias,
+            port,
+            out,
+            own_fingerprint,
+            files,
+            peers: Vec::new(),
+            peer_list: ListState::default(),
+            offers: VecDeque::new(),
+            transfers: Vec::new(),
+            input: Input::Peers,
+            status: "Searching for devices...".to_string(),
+        }
+    }
+
+    /// Adds the device, or updates it if it is known already.
+    pub fn add_peer(&mut self, peer: DiscoveredPeer) {
+        if peer.fingerprint == self.own_fingerprint || !crate::discover::is_connectable(&peer) {
+            return;
+        }
+        match self
+            .peers
+            .iter_mut()
+            .find(|p| p.fingerprint == peer.fingerprint)
+        {
+            Some(known) => *known = peer,
+            None => self.peers.push(peer),
+        }
+        if self.peer_list.selected().is_none() {
+            self.peer_list.select(Some(0));
+        }
+    }
+
+    /// Removes the offer, e.g. because the sender withdrew it.
+    pub fn remove_offer(&mut self, session_id: &str) {
+        self.offers.retain(|offer| offer.session_id != session_id);
+    }
+
+    pub fn update(&mut self, update: Update) {
+        match update {
+            Update::Added(transfers) => self.transfers.extend(transfers),
+            Update::Progress {
+                session,
+                file_id,
+                done,
+            } => {
+                if let Some(transfer) = self.transfer(&session, &file_id) {
+                    transfer.done = done;
+                    if transfer.state == TransferState::Waiting {
+                        transfer.state = TransferState::Running;
+                    }
+                }
+            }
+            Update::FileFinished {
+                session,
+                file_id,
+                result,
+            } => {
+                if let Some(transfer) = self.transfer(&session, &file_id) {
+                    transfer.state = match result {
+                        Ok(()) => {
+                            transfer.finish();
+                            TransferState::Done
+                        }
+                        Err(e) => TransferState::Failed(e),
+                    };
+                }
+            }
+            Update::SessionFinished { session, result } => {
+                let unfinished = self.transfers.iter_mut().filter(|t| {
+                    t.session == session
+                        && matches!(t.state, TransferState::Waiting | TransferState::Running)
+                });
+                for transfer in unfinished {
+                    transfer.state = match &result {
+                        Ok(()) => {
+                            transfer.finish();
+                            TransferState::Done
+                        }
+                        Err(e) => TransferState::Failed(e.clone()),
+                    };
+                }
+            }
+            Update::Pin(pin_tx) => {
+                self.input = Input::Pin {
+                    input: String::new(),
+                    pin_tx,
+                }
+            }
+            Update::Status(status) => self.status = status,
+        }
+    }
+
+    fn transfer(&mut self, session: &str, file_id: &str) -> Option<&mut Transfer> {
+        self.transfers
+            .iter_mut()
+            .find(|t| t.session == session && t.file_id == file_id)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> Action {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            return Action::Quit;
+        }
+
+        // Offers need an answer first, the sender is waiting.
+        if let Some(offer) = self.offers.front() {
+            let decision = match key.code {
+                KeyCode::Char('y') => PrepareUploadDecisionV2::Accept(
+                    offer.files.iter().map(|file| file.id.clone()).collect(),
+                ),
+                KeyCode::Char('n') | KeyCode::Esc => PrepareUploadDecisionV2::Decline,
+                _ => return Action::None,
+            };
+            if let Some(offer) = self.offers.pop_front() {
+                let _ = offer.decision_tx.send(decision);
+            }
+            return Action::None;
+        }
+
+        match &mut self.input {
+            Input::Peers => self.handle_peers_key(key),
+            Input::Path(path) => match key.code {
+                KeyCode::Char(c) => {
+                    path.push(c);
+                    Action::None
+                }
+                KeyCode::Backspace => {
+                    path.pop();
+                    Action::None
+                }
+                KeyCode::Enter => {
+                    let path = expand_home(path.trim());
+                    self.input = Input::Peers;
+                    match self.selected_peer() {
+                        Some(peer) if !path.as_os_str().is_empty() => Action::Send {
+                            peer,
+                            paths: vec![path],
+                        },
+                        _ => Action::None,
+                    }
+                }
+                KeyCode::Esc => {
+                    self.input = Input::Peers;
+                    Action::None
+                }
+                _ => Action::None,
+            },
+            Input::Pin { input, .. } => match key.code {
+                KeyCode::Char(c) => {
+                    input.push(c);
+                    Action::None
+                }
+                KeyCode::Backspace => {
+                    input.pop();
+                    Action::None
+                }
+                KeyCode::Enter => {
+                    if let Input::Pin { input, pin_tx } =
+                        std::mem::replace(&mut self.input, Input::Peers)
+                    {
+                        let _ = pin_tx.send(input);
+                    }
+                    Action::None
+                }
+                KeyCode::Esc => {
+                    // Dropping the channel aborts the send.
+                    self.input = Input::Peers;
+                    Action::Cancel
+                }
+                _ => Action::None,
+            },
+        }
+    }
+
+    fn handle_peers_key(&mut self, key: KeyEvent) -> Action {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => Action::Quit,
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.peer_list.select_previous();
+                Action::None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if self
+                    .peer_list
+                    .selected()
+                    .is_some_and(|i| i + 1 < self.peers.len())
+                {
+                    self.peer_list.select_next();
+                }
+                Action::None
+            }
+            KeyCode::Enter => match self.selected_peer() {
+                Some(peer) if !self.files.is_empty() => Action::Send {
+                    peer,
+                    paths: self.files.clone(),
+                },
+                Some(_) => {
+                    self.input = Input::Path(String::new());
+                    Action::None
+                }
+                None => Action::None,
+            },
+            KeyCode::Char('r') => {
+                // Devices gone offline do not answer again.
+                self.peers.clear();
+                self.peer_list.select(None);
+                self.status = "Searching for devices...".to_string();
+                Action::Refresh
+            }
+            KeyCode::Char('c') => Action::Cancel,
+            KeyCode::Char('x') => {
+                self.transfers
+                    .retain(|t| matches!(t.state, TransferState::Waiting | TransferState::Running));
+                Action::None
+            }
+            _ => Action::None,
+        }
+    }
+
+    fn selected_peer(&self) -> Option<DiscoveredPeer> {
+        self.peer_list
+            .selected()
+            .and_then(|i| self.peers.get(i))
+            .cloned()
+    }
+}
+
+/// Expands `~/`, as the path is not typed into a shell.
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer(file_id: &str, state: TransferState) -> Transfer {
+        Transfer {
+            session: "s".to_string(),
+            file_id: file_id.to_string(),
+            direction: Direction::Send,
+            peer: "Peer".to_string(),
+            name: format!("{file_id}.txt"),
+            size: 10,
+            done: 0,
+            state,
+        }
+    }
+
+    #[test]
+    fn test_session_finished() {
+        let mut app = App::new("Me".to_string(), 53317, ".".into(), "F".to_string(), vec![]);
+        app.update(Update::Added(vec![
+            transfer("a", TransferState::Waiting),
+            transfer("b", TransferState::Waiting),
+        ]));
+        app.update(Update::FileFinished {
+            session: "s".to_string(),
+            file_id: "a".to_string(),
+            result: Err("Skipped".to_string()),
+        });
+        app.update(Update::Progress {
+            session: "s".to_string(),
+            file_id: "b".to_string(),
+            done: 4,
+        });
+        assert_eq!(app.transfers[1].state, TransferState::Running);
+
+        app.update(Update::SessionFinished {
+            session: "s".to_string(),
+            result: Ok(()),
+        });
+        assert_eq!(
+            app.transfers[0].state,
+            TransferState::Failed("Skipped".to_string())
+        );
+        assert_eq!(app.transfers[1].state, TransferState::Done);
+        assert_eq!(app.transfers[1].done, 10);
+    }
+}
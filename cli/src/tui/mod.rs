@@ -0,0 +1,488 @@
+//! Interactive terminal UI: lists the devices on the network, sends files to them
+//! and asks before receiving files, with live progress.
+
+mod app;
+mod view;
+
+use crate::discover::Discovery;
+use crate::identity::Identity;
+use crate::receive;
+use crate::send::{self, Target};
+use anyhow::Context;
+use app::{Action, App, Direction, Offer, Transfer, TransferState, Update};
+use localsend::discovery::DiscoveredPeer;
+use localsend::http::client::sender::{UploadProgress, send_files_v2};
+use localsend::http::client::{ClientError, LsHttpClientV2};
+use localsend::http::dto_v2::RegisterDtoV2;
+use localsend::http::server::common::save::FileUploadTarget;
+use localsend::http::server::v2::{ServerEventV2, SessionEndReasonV2};
+use localsend::model::transfer::{FileContent, FileDto, TransferConfig};
+use localsend::webrtc::webrtc::{RTCFileError, RTCStatus};
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio_util::sync::CancellationToken;
+
+/// Progress is drawn at most this often.
+const FRAME_INTERVAL: Duration = Duration::from_millis(50);
+
+pub struct TuiOptions {
+    /// The directory received files are saved to.
+    pub out: PathBuf,
+
+    /// PIN senders must enter.
+    pub pin: Option<String>,
+
+    /// Sent to the selected device instead of asking for a path.
+    pub files: Vec<PathBuf>,
+
+    /// How sent files are read.
+    pub transfer: TransferConfig,
+}
+
+/// Shared by all sends.
+#[derive(Clone)]
+struct SendContext {
+    client: Arc<LsHttpClientV2>,
+    info: RegisterDtoV2,
+    transfer: TransferConfig,
+}
+
+pub async fn run(
+    identity: &Identity,
+    info: RegisterDtoV2,
+    options: TuiOptions,
+) -> anyhow::Result<()> {
+    tokio::fs::create_dir_all(&options.out)
+        .await
+        .with_context(|| format!("Failed to create {}", options.out.display()))?;
+    let out = Arc::new(options.out);
+
+    let (event_tx, mut event_rx) = mpsc::channel(16);
+    let (stop_tx, stop_rx) = oneshot::channel();
+    let server = receive::start_server(identity, &info, options.pin, event_tx, stop_rx).await?;
+    let mut discovery = Discovery::start(info.clone())?;
+    discovery.announce().await?;
+    let context = SendContext {
+        client: Arc::new(LsHttpClientV2::try_new(
+            &identity.private_key,
+            &identity.cert,
+            None,
+        )?),
+        info: info.clone(),
+        transfer: options.transfer,
+    };
+
+    let mut app = App::new(
+        info.alias.clone(),
+        info.port,
+        out.to_path_buf(),
+        info.fingerprint.clone(),
+        options.files,
+    );
+    let (update_tx, mut update_rx) = mpsc::channel(64);
+    let mut terminal_rx = read_terminal_events();
+    // The alias of the sender, mapped by session ID.
+    let mut senders: HashMap<String, String> = HashMap::new();
+    let mut cancel = CancellationToken::new();
+    let mut sends = 0u64;
+
+    let mut terminal = ratatui::init();
+    let mut frame = tokio::time::interval(FRAME_INTERVAL);
+    let mut dirty = true;
+    let result = loop {
+        tokio::select! {
+            _ = frame.tick() => {
+                if dirty {
+                    if let Err(e) = terminal.draw(|frame| view::draw(frame, &mut app)) {
+                        break Err(e.into());
+                    }
+                    dirty = false;
+                }
+                continue;
+            }
+            Some(event) = terminal_rx.recv() => {
+                let Event::Key(key) = event else {
+                    // Resized, redraw.
+                    dirty = true;
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                match app.handle_key(key) {
+                    Action::None => {}
+                    Action::Quit => break Ok(()),
+                    Action::Refresh => {
+                        if let Err(e) = discovery.announce().await {
+                            app.status = format!("Failed to search for devices: {e:#}");
+                        }
+                    }
+                    Action::Cancel => {
+                        cancel.cancel();
+                        cancel = CancellationToken::new();
+                    }
+                    Action::Send { peer, paths } => {
+                        sends += 1;
+                        tokio::spawn(send_files(
+                            context.clone(),
+                            format!("send-{sends}"),
+                            peer,
+                            paths,
+                            update_tx.clone(),
+                            cancel.child_token(),
+                        ));
+                    }
+                }
+            }
+            Some(peer) = discovery.peers_rx.recv() => app.add_peer(peer),
+            Some(event) = event_rx.recv() => {
+                handle_server_event(&mut app, &mut senders, event, &out, &update_tx);
+            }
+            Some(update) = update_rx.recv() => app.update(update),
+        }
+        dirty = true;
+    };
+    ratatui::restore();
+
+    cancel.cancel();
+    let _ = stop_tx.send(());
+    server.wait_stopped().await;
+    result
+}
+
+/// Reads the terminal events on a thread, as reading blocks.
+fn read_terminal_events() -> mpsc::Receiver<Event> {
+    let (tx, rx) = mpsc::channel(16);
+    std::thread::spawn(move || {
+        while let Ok(event) = event::read() {
+            if tx.blocking_send(event).is_err() {
+                return;
+            }
+        }
+    });
+    rx
+}
+
+fn handle_server_event(
+    app: &mut App,
+    senders: &mut HashMap<String, String>,
+    event: ServerEventV2,
+    out: &Arc<PathBuf>,
+    update_tx: &mpsc::Sender<Update>,
+) {
+    match event {
+        ServerEventV2::PrepareUpload {
+            session_id,
+            info,
+            files,
+            decision_tx,
+            ..
+        } => {
+            let mut files: Vec<FileDto> = files.into_values().collect();
+            files.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+            senders.insert(session_id.clone(), info.alias.clone());
+            app.offers.push_back(Offer {
+                session_id,
+                alias: info.alias,
+                files,
+                decision_tx,
+            });
+        }
+        ServerEventV2::FileUpload {
+            session_id,
+            file,
+            target_tx,
+            ..
+        } => {
+            app.update(Update::Added(vec![Transfer {
+                session: session_id.clone(),
+                file_id: file.id.clone(),
+                direction: Direction::Receive,
+                peer: senders.get(&session_id).cloned().unwrap_or_default(),
+                name: file.file_name.clone(),
+                size: file.size,
+                done: 0,
+                state: TransferState::Running,
+            }]));
+            tokio::spawn(save_file(
+                out.clone(),
+                session_id,
+                file,
+                target_tx,
+                update_tx.clone(),
+            ));
+        }
+        ServerEventV2::SessionEnd { session_id, reason } => {
+            let alias = senders.remove(&session_id).unwrap_or_default();
+            let result = match reason {
+                SessionEndReasonV2::Finished => {
+                    app.status = format!("Received files from {alias}");
+                    Ok(())
+                }
+                SessionEndReasonV2::Cancelled => {
+                    app.status = format!("{alias} cancelled the transfer");
+                    Err("Cancelled by the sender".to_string())
+                }
+            };
+            app.update(Update::SessionFinished {
+                session: session_id,
+                result,
+            });
+        }
+        ServerEventV2::PrepareUploadAborted { session_id } => {
+            app.remove_offer(&session_id);
+            if let Some(alias) = senders.remove(&session_id) {
+                app.status = format!("{alias} withdrew the request");
+            }
+        }
+        ServerEventV2::Register { .. } | ServerEventV2::CancelReceived { .. } => {}
+    }
+}
+
+/// Writes the file below `out`, renaming it if the name is taken.
+async fn save_file(
+    out: Arc<PathBuf>,
+    session: String,
+    file: FileDto,
+    target_tx: oneshot::Sender<FileUploadTarget>,
+    update_tx: mpsc::Sender<Update>,
+) {
+    let path = match receive::reserve_file(&out, &file).await {
+        Ok(path) => path,
+        Err(e) => {
+            // Dropping the target fails the upload.
+            let _ = update_tx
+                .send(Update::FileFinished {
+                    session,
+                    file_id: file.id,
+                    result: Err(format!("{e:#}")),
+                })
+                .await;
+            return;
+        }
+    };
+
+    let (result_tx, result_rx) = oneshot::channel();
+    let (progress_tx, mut progress_rx) = mpsc::channel(16);
+    let target = FileUploadTarget::Path {
+        path: path.clone(),
+        result_tx,
+        progress_tx: Some(progress_tx),
+    };
+    if target_tx.send(target).is_err() {
+        let _ = tokio::fs::remove_file(&path).await;
+        return;
+    }
+
+    while let Some(done) = progress_rx.recv().await {
+        let progress = Update::Progress {
+            session: session.clone(),
+            file_id: file.id.clone(),
+            done,
+        };
+        if update_tx.send(progress).await.is_err() {
+            break;
+        }
+    }
+
+    let result = result_rx
+        .await
+        .unwrap_or_else(|_| Err("Upload aborted".to_string()));
+    if result.is_err() {
+        // Remove the placeholder of the reserved name.
+        let _ = tokio::fs::remove_file(&path).await;
+    }
+    let _ = update_tx
+        .send(Update::FileFinished {
+            session,
+            file_id: file.id,
+            result,
+        })
+        .await;
+}
+
+/// Sends the files to the device, reporting to `update_tx`.
+async fn send_files(
+    context: SendContext,
+    session: String,
+    peer: DiscoveredPeer,
+    paths: Vec<PathBuf>,
+    update_tx: mpsc::Sender<Update>,
+    cancel: CancellationToken,
+) {
+    let target = Target::from(peer);
+    let files = match send::collect_files(paths).await {
+        Ok(files) => files,
+        Err(e) => {
+            let _ = update_tx.send(Update::Status(format!("{e:#}"))).await;
+            return;
+        }
+    };
+
+    let transfers = files
+        .iter()
+        .map(|(file, _)| Transfer {
+            session: session.clone(),
+            file_id: file.id.clone(),
+            direction: Direction::Send,
+            peer: target.alias.clone(),
+            name: file.file_name.clone(),
+            size: file.size,
+            done: 0,
+            state: TransferState::Waiting,
+        })
+        .collect();
+    let _ = update_tx.send(Update::Added(transfers)).await;
+    let _ = update_tx
+        .send(Update::Status(format!(
+            "Waiting for {} to accept",
+            target.alias
+        )))
+        .await;
+
+    let (status_tx, status_rx) = mpsc::channel(8);
+    let (progress_tx, progress_rx) = mpsc::channel(16);
+    let (selected_files_tx, selected_files_rx) = oneshot::channel::<HashSet<String>>();
+    let (feed_tx, feed_rx) = oneshot::channel();
+    let (error_tx, error_rx) = mpsc::channel(8);
+    let (pin_tx, pin_rx) = mpsc::channel(1);
+    let (sending_tx, sending_rx) = mpsc::channel(1);
+
+    // Marks the files the receiver did not accept before providing the accepted ones.
+    let ids: Vec<String> = files.iter().map(|(file, _)| file.id.clone()).collect();
+    tokio::spawn({
+        let session = session.clone();
+        let update_tx = update_tx.clone();
+        async move {
+            let Ok(selected) = selected_files_rx.await else {
+                return;
+            };
+            for file_id in ids.into_iter().filter(|id| !selected.contains(id)) {
+                let skipped = Update::FileFinished {
+                    session: session.clone(),
+                    file_id,
+                    result: Err("Skipped by the receiver".to_string()),
+                };
+                let _ = update_tx.send(skipped).await;
+            }
+            let _ = feed_tx.send(selected);
+        }
+    });
+    let dtos = files.iter().map(|(file, _)| file.clone()).collect();
+    let files = files
+        .into_iter()
+        .map(|(file, path)| (file, FileContent::Path(path)))
+        .collect();
+    tokio::spawn(send::feed_files(
+        files,
+        context.transfer,
+        feed_rx,
+        sending_tx,
+    ));
+
+    let reporter = tokio::spawn(report(
+        session.clone(),
+        target.alias.clone(),
+        status_rx,
+        progress_rx,
+        error_rx,
+        pin_rx,
+        update_tx.clone(),
+    ));
+
+    let result = send_files_v2(
+        &context.client,
+        target.protocol,
+        &target.ip,
+        target.port,
+        None,
+        context.info,
+        dtos,
+        status_tx,
+        progress_tx,
+        selected_files_tx,
+        error_tx,
+        pin_tx,
+        sending_rx,
+        cancel,
+    )
+    .await;
+    let _ = reporter.await;
+
+    let result = match result {
+        Ok(()) => return,
+        Err(ClientError::Cancelled) => "Cancelled".to_string(),
+        Err(e) => e.to_string(),
+    };
+    let _ = update_tx
+        .send(Update::Status(format!(
+            "Failed to send to {}: {result}",
+            target.alias
+        )))
+        .await;
+    let _ = update_tx
+        .send(Update::SessionFinished {
+            session,
+            result: Err(result),
+        })
+        .await;
+}
+
+/// Forwards the reports of a send to the UI.
+async fn report(
+    session: String,
+    alias: String,
+    mut status_rx: mpsc::Receiver<RTCStatus>,
+    mut progress_rx: mpsc::Receiver<UploadProgress>,
+    mut error_rx: mpsc::Receiver<RTCFileError>,
+    mut pin_rx: mpsc::Receiver<oneshot::Sender<String>>,
+    update_tx: mpsc::Sender<Update>,
+) {
+    let mut failed = 0;
+    loop {
+        let update = tokio::select! {
+            Some(progress) = progress_rx.recv() => Update::Progress {
+                session: session.clone(),
+                file_id: progress.file_id,
+                done: progress.sent,
+            },
+            Some(error) = error_rx.recv() => {
+                failed += 1;
+                Update::FileFinished {
+                    session: session.clone(),
+                    file_id: error.file_id,
+                    result: Err(error.error),
+                }
+            }
+            Some(pin_tx) = pin_rx.recv() => Update::Pin(pin_tx),
+            Some(status) = status_rx.recv() => {
+                let (status, result) = match status {
+                    RTCStatus::Sending => {
+                        let _ = update_tx.send(Update::Status(format!("Sending to {alias}"))).await;
+                        continue;
+                    }
+                    RTCStatus::Declined => (format!("{alias} declined"), Err("Declined".to_string())),
+                    RTCStatus::TooManyAttempts => {
+                        (format!("{alias}: too many wrong PINs"), Err("Too many wrong PINs".to_string()))
+                    }
+                    RTCStatus::Error(e) => (format!("Failed to send to {alias}: {e}"), Err(e)),
+                    RTCStatus::Finished => match failed {
+                        0 => (format!("Sent to {alias}"), Ok(())),
+                        n => (format!("Sent to {alias}, {n} file(s) failed"), Ok(())),
+                    },
+                    _ => continue,
+                };
+                let _ = update_tx.send(Update::Status(status)).await;
+                Update::SessionFinished { session: session.clone(), result }
+            }
+            else => break,
+        };
+        if update_tx.send(update).await.is_err() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use super::app::{App, Direction, Input, Offer, Transfer, TransferState};
+use crate::progress::{format_size, total_size};
+use localsend::model::transfer::UNKNOWN_SIZE;
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Clear, List, ListItem, Paragraph, Wrap};
+
+const BAR_WIDTH: usize = 20;
+
+pub fn draw(frame: &mut Frame, app: &mut App) {
+    let [header, body, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(2),
+    ])
+    .areas(frame.area());
+    let [peers, transfers] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(body);
+
+    frame.render_widget(
+        Line::from(vec![
+            Span::from(" LocalSend ").bold().reversed(),
+            Span::from(format!(
+                " {} on port {}, saving to {}",
+                app.alias,
+                app.port,
+                app.out.display()
+            )),
+        ]),
+        header,
+    );
+
+    draw_peers(frame, app, peers);
+    draw_transfers(frame, &app.transfers, transfers);
+    draw_footer(frame, app, footer);
+
+    if let Some(offer) = app.offers.front() {
+        draw_offer(frame, offer, app.offers.len() - 1);
+    }
+}
+
+fn draw_peers(frame: &mut Frame, app: &mut App, area: Rect) {
+    let items: Vec<ListItem> = app
+        .peers
+        .iter()
+        .map(|peer| {
+            ListItem::new(vec![
+                Line::from(peer.alias.clone()).bold(),
+                Line::from(format!(
+                    "  {}:{}  {}",
+                    peer.ip,
+                    peer.port,
+                    peer.device_model.as_deref().unwrap_or("-")
+                ))
+                .dim(),
+            ])
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(Block::bordered().title(" Devices "))
+        .highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .highlight_symbol("> ");
+    frame.render_stateful_widget(list, area, &mut app.peer_list);
+}
+
+fn draw_transfers(frame: &mut Frame, transfers: &[Transfer], area: Rect) {
+    // The latest transfers are the most interesting, at the top.
+    let items: Vec<ListItem> = transfers.iter().rev().map(transfer_item).collect();
+    frame.render_widget(
+        List::new(items).block(Block::bordered().title(" Transfers ")),
+        area,
+    );
+}
+
+fn transfer_item(transfer: &Transfer) -> ListItem<'_> {
+    let arrow = match transfer.direction {
+        Direction::Send => "↑",
+        Direction::Receive => "↓",
+    };
+    let title = Line::from(vec![
+        Span::from(format!("{arrow} ")).bold(),
+        Span::from(transfer.name.as_str()),
+        Span::from(format!("  {}", transfer.peer)).dim(),
+    ]);
+
+    let state = match &transfer.state {
+        TransferState::Waiting => Line::from("  waiting for the receiver").dim(),
+        TransferState::Running => {
+            if transfer.size == UNKNOWN_SIZE {
+                return ListItem::new(vec![
+                    title,
+                    Line::from(format!("  {} received", format_size(transfer.done))),
+                ]);
+            }
+            let ratio = match transfer.size {
+                0 => 1.0,
+                size => (transfer.done as f64 / size as f64).min(1.0),
+            };
+            let filled = (ratio * BAR_WIDTH as f64).round() as usize;
+            Line::from(format!(
+                "  {}{} {:>3}%  {} / {}",
+                "█".repeat(filled),
+                "░".repeat(BAR_WIDTH - filled),
+                (ratio * 100.0) as u8,
+                format_size(transfer.done),
+                format_size(transfer.size)
+            ))
+        }
+        TransferState::Done => {
+            Line::from(format!("  done, {}", format_size(transfer.done))).fg(Color::Green)
+        }
+        TransferState::Failed(e) => Line::from(format!("  failed: {e}")).fg(Color::Red),
+    };
+
+    ListItem::new(vec![title, state])
+}
+
+fn draw_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let [status, keys] =
+        Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).areas(area);
+
+    let (status_line, keys_line) = match &app.input {
+        Input::Peers => (
+            Line::from(app.status.as_str()),
+            "↑↓ select  enter send  r refresh  c cancel  x clear  q quit",
+        ),
+        Input::Path(path) => (
+            Line::from(format!("Send file or directory: {path}_")),
+            "enter send  esc back",
+        ),
+        Input::Pin { input, .. } => (
+            Line::from(format!("PIN of the receiver: {input}_")),
+            "enter confirm  esc cancel",
+        ),
+    };
+    frame.render_widget(status_line, status);
+    frame.render_widget(Line::from(keys_line).dim(), keys);
+}
+
+fn draw_offer(frame: &mut Frame, offer: &Offer, queued: usize) {
+    let total = total_size(&offer.files);
+    let mut lines = vec![
+        Line::from(format!(
+            "{} wants to send {} file(s) ({}):",
+            offer.alias,
+            offer.files.len(),
+            format_size(total)
+        )),
+        Line::from(""),
+    ];
+    lines.extend(
+        offer
+            .files
+            .iter()
+            .map(|file| Line::from(format!("  {} ({})", file.file_name, format_size(file.size)))),
+    );
+    lines.push(Line::from(""));
+    lines.push(Line::from("y accept  n decline").bold());
+    if queued > 0 {
+        lines.push(Line::from(format!("{queued} more request(s) waiting")).dim());
+    }
+
+    let area = frame.area();
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let [popup] = Layout::vertical([Constraint::Length(height)])
+        .flex(Flex::Center)
+        .areas(area);
+    let [popup] = Layout::horizontal([Constraint::Percentage(70)])
+        .flex(Flex::Center)
+        .areas(popup);
+
+    frame.render_widget(Clear, popup);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::bordered().title(" Incoming files ")),
+        popup,
+    );
+}
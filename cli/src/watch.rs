@@ -0,0 +1,407 @@
+use crate::identity::Identity;
+use crate::send;
+use anyhow::Context;
+use localsend::http::client::sender::send_files_v2;
+use localsend::http::client::{ClientError, LsHttpClientV2};
+use localsend::http::dto_v2::RegisterDtoV2;
+use localsend::model::scan::{ScanOptions, scan_dir};
+use localsend::model::transfer::{FileContent, FileDto, TransferConfig};
+use localsend::webrtc::webrtc::{RTCFileError, RTCStatus};
+use notify::event::ModifyKind;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+use tokio::sync::{mpsc, oneshot};
+use tokio_util::sync::CancellationToken;
+
+/// The delay before the first retry, doubled for every further one.
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(300);
+
+/// How often pending files are checked.
+const CHECK_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Suffixes of files still being written by other programs.
+const TEMPORARY_SUFFIXES: [&str; 5] = [".part", ".tmp", ".crdownload", ".download", "~"];
+
+pub struct WatchOptions {
+    pub dir: PathBuf,
+
+    /// The receiver: its alias, a prefix of its fingerprint, or its IP address.
+    pub to: String,
+
+    /// Sent if the receiver requires a PIN.
+    pub pin: Option<String>,
+
+    /// How long a file must be unchanged before it is sent.
+    pub debounce: Duration,
+
+    /// How long to search for the receiver.
+    pub timeout: Duration,
+
+    /// Attempts per file before giving up.
+    pub attempts: u32,
+
+    /// Also sends the files present at the start.
+    pub existing: bool,
+
+    /// Deletes files once sent.
+    pub remove: bool,
+
+    /// How sent files are read.
+    pub transfer: TransferConfig,
+}
+
+/// A file that changed recently.
+struct Pending {
+    changed: Instant,
+    stamp: Option<(u64, SystemTime)>,
+}
+
+/// Why sending failed.
+enum Failure {
+    /// Worth retrying, e.g. the receiver is offline.
+    Temporary(anyhow::Error),
+    Permanent(anyhow::Error),
+}
+
+/// Sends the files appearing in the directory until stopped with Ctrl+C.
+pub async fn watch(
+    identity: &Identity,
+    info: RegisterDtoV2,
+    options: WatchOptions,
+) -> anyhow::Result<()> {
+    let dir = tokio::fs::canonicalize(&options.dir)
+        .await
+        .with_context(|| format!("Failed to read {}", options.dir.display()))?;
+    let client = LsHttpClientV2::try_new(&identity.private_key, &identity.cert, None)?;
+
+    let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        let _ = event_tx.send(event);
+    })?;
+    watcher
+        .watch(&dir, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", dir.display()))?;
+
+    let stop = CancellationToken::new();
+    tokio::spawn({
+        let stop = stop.clone();
+        async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                stop.cancel();
+            }
+        }
+    });
+
+    let mut pending: HashMap<PathBuf, Pending> = HashMap::new();
+    if options.existing {
+        for scanned in scan_dir(&dir, ScanOptions::default()).await? {
+            changed(&mut pending, scanned.path);
+        }
+    }
+
+    eprintln!(
+        "Watching {}, sending new files to {}. Press Ctrl+C to stop.",
+        dir.display(),
+        options.to
+    );
+
+    let mut check = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        tokio::select! {
+            event = event_rx.recv() => {
+                let event: notify::Event = match event {
+                    Some(Ok(event)) => event,
+                    Some(Err(e)) => {
+                        tracing::warn!("Failed to watch {}: {e}", dir.display());
+                        continue;
+                    }
+                    None => break,
+                };
+                match event.kind {
+                    // Reading the files for sending changes their metadata.
+                    EventKind::Create(_)
+                    | EventKind::Modify(ModifyKind::Data(_) | ModifyKind::Name(_) | ModifyKind::Any) => {
+                        for path in event.paths {
+                            changed(&mut pending, path);
+                        }
+                    }
+                    EventKind::Remove(_) => {
+                        for path in &event.paths {
+                            pending.remove(path);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            _ = check.tick() => {
+                let ready = take_ready(&mut pending, &dir, options.debounce);
+                if !ready.is_empty() {
+                    deliver_with_retry(&client, &info, &options, &dir, ready, &stop).await;
+                }
+            }
+            _ = stop.cancelled() => break,
+        }
+    }
+
+    Ok(())
+}
+
+/// Restarts the debounce of the file.
+fn changed(pending: &mut HashMap<PathBuf, Pending>, path: PathBuf) {
+    pending.insert(
+        path.clone(),
+        Pending {
+            changed: Instant::now(),
+            stamp: stamp(&path),
+        },
+    );
+}
+
+/// The size and modification time of the file, `None` if it is not a file.
+fn stamp(path: &Path) -> Option<(u64, SystemTime)> {
+    let metadata = std::fs::metadata(path).ok()?;
+    match metadata.is_file() {
+        true => Some((metadata.len(), metadata.modified().ok()?)),
+        false => None,
+    }
+}
+
+/// Removes the files unchanged for `debounce` from `pending`.
+/// Files changed since their last event, e.g. by a slow writer, wait again.
+fn take_ready(
+    pending: &mut HashMap<PathBuf, Pending>,
+    dir: &Path,
+    debounce: Duration,
+) -> Vec<PathBuf> {
+    let mut ready = Vec::new();
+    pending.retain(|path, file| {
+        if file.changed.elapsed() < debounce {
+            return true;
+        }
+        let current = stamp(path);
+        if current != file.stamp {
+            file.changed = Instant::now();
+            file.stamp = current;
+            return true;
+        }
+        if current.is_some() && !is_ignored(path.strip_prefix(dir).unwrap_or(path)) {
+            ready.push(path.clone());
+        }
+        false
+    });
+    ready.sort();
+    ready
+}
+
+/// Hidden files and files still being downloaded or written.
+fn is_ignored(relative: &Path) -> bool {
+    let hidden = relative
+        .components()
+        .any(|c| matches!(c, Component::Normal(name) if name.to_string_lossy().starts_with('.')));
+    let name = relative
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    hidden
+        || TEMPORARY_SUFFIXES
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+}
+
+/// The name the file is sent with: its path below the watched directory, separated by `/`.
+fn file_name(dir: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(dir).unwrap_or(path);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Sends the files, retrying with increasing delays.
+async fn deliver_with_retry(
+    client: &LsHttpClientV2,
+    info: &RegisterDtoV2,
+    options: &WatchOptions,
+    dir: &Path,
+    paths: Vec<PathBuf>,
+    stop: &CancellationToken,
+) {
+    let names: Vec<String> = paths.iter().map(|path| file_name(dir, path)).collect();
+    let mut delay = RETRY_DELAY;
+    for attempt in 1..=options.attempts {
+        let error = match deliver(client, info, options, dir, &paths, stop).await {
+            Ok(alias) => {
+                for name in &names {
+                    eprintln!("Sent {name} to {alias}");
+                }
+                if options.remove {
+                    for path in &paths {
+                        if let Err(e) = tokio::fs::remove_file(path).await {
+                            eprintln!("Failed to remove {}: {e}", path.display());
+                        }
+                    }
+                }
+                return;
+            }
+            Err(Failure::Permanent(e)) => e,
+            Err(Failure::Temporary(e)) if attempt < options.attempts => {
+                eprintln!(
+                    "Failed to send {}: {e:#}, retrying in {}s",
+                    names.join(", "),
+                    delay.as_secs()
+                );
+                tokio::select! {
+                    _ = tokio::time::sleep(delay) => {}
+                    _ = stop.cancelled() => return,
+                }
+                delay = (delay * 2).min(MAX_RETRY_DELAY);
+                continue;
+            }
+            Err(Failure::Temporary(e)) => e,
+        };
+        eprintln!("Failed to send {}: {error:#}", names.join(", "));
+        return;
+    }
+}
+
+/// Sends the files to the receiver, returns its alias.
+async fn deliver(
+    client: &LsHttpClientV2,
+    info: &RegisterDtoV2,
+    options: &WatchOptions,
+    dir: &Path,
+    paths: &[PathBuf],
+    stop: &CancellationToken,
+) -> Result<String, Failure> {
+    let target = send::resolve_target(client, info.clone(), &options.to, options.timeout)
+        .await
+        .map_err(Failure::Temporary)?;
+
+    let mut files = Vec::new();
+    for path in paths {
+        let mut file = FileDto::from_path(path, false)
+            .await
+            .with_context(|| format!("Failed to read {}", path.display()))
+            .map_err(Failure::Permanent)?;
+        file.file_name = file_name(dir, path);
+        files.push((file, FileContent::Path(path.clone())));
+    }
+    let dtos = files.iter().map(|(file, _)| file.clone()).collect();
+
+    let (status_tx, mut status_rx) = mpsc::channel(8);
+    let (progress_tx, mut progress_rx) = mpsc::channel(16);
+    let (selected_files_tx, selected_files_rx) = oneshot::channel::<HashSet<String>>();
+    let (error_tx, mut error_rx) = mpsc::channel::<RTCFileError>(8);
+    let (pin_tx, mut pin_rx) = mpsc::channel::<oneshot::Sender<String>>(1);
+    let (sending_tx, sending_rx) = mpsc::channel(1);
+    tokio::spawn(send::feed_files(
+        files,
+        options.transfer,
+        selected_files_rx,
+        sending_tx,
+    ));
+
+    let pin = options.pin.clone();
+    let outcome = tokio::spawn(async move {
+        let mut outcome = Err(Failure::Temporary(anyhow::anyhow!("Transfer aborted")));
+        let mut failed = 0;
+        loop {
+            tokio::select! {
+                Some(_) = progress_rx.recv() => {}
+                Some(error) = error_rx.recv() => {
+                    tracing::debug!("Failed to send {}: {}", error.file_id, error.error);
+                    failed += 1;
+                }
+                Some(pin_res_tx) = pin_rx.recv() => match &pin {
+                    Some(pin) => {
+                        let _ = pin_res_tx.send(pin.clone());
+                    }
+                    None => {
+                        // Dropping the channel aborts the transfer.
+                        outcome = Err(Failure::Permanent(anyhow::anyhow!(
+                            "The receiver requires a PIN, see --pin"
+                        )));
+                    }
+                },
+                Some(status) = status_rx.recv() => match status {
+                    RTCStatus::Declined => {
+                        outcome = Err(Failure::Permanent(anyhow::anyhow!("Declined by the receiver")));
+                    }
+                    RTCStatus::TooManyAttempts => {
+                        outcome = Err(Failure::Permanent(anyhow::anyhow!("Wrong PIN")));
+                    }
+                    RTCStatus::Error(e) => outcome = Err(Failure::Temporary(anyhow::anyhow!(e))),
+                    RTCStatus::Finished => {
+                        outcome = match failed {
+                            0 => Ok(()),
+                            n => Err(Failure::Temporary(anyhow::anyhow!("{n} file(s) failed"))),
+                        };
+                    }
+                    _ => {}
+                },
+                else => break,
+            }
+        }
+        outcome
+    });
+
+    let result = send_files_v2(
+        client,
+        target.protocol,
+        &target.ip,
+        target.port,
+        None,
+        info.clone(),
+        dtos,
+        status_tx,
+        progress_tx,
+        selected_files_tx,
+        error_tx,
+        pin_tx,
+        sending_rx,
+        stop.child_token(),
+    )
+    .await;
+    let outcome = outcome
+        .await
+        .map_err(|e| Failure::Temporary(anyhow::anyhow!(e)))?;
+
+    match result {
+        Err(ClientError::Cancelled) => Err(Failure::Permanent(anyhow::anyhow!("Stopped"))),
+        Err(e) => match outcome {
+            // The outcome explains the error better, e.g. a missing PIN.
+            Err(Failure::Permanent(reason)) => Err(Failure::Permanent(reason)),
+            _ => Err(Failure::Temporary(e.into())),
+        },
+        Ok(()) => outcome.map(|()| target.alias),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_ignored() {
+        assert!(!is_ignored(Path::new("scan.pdf")));
+        assert!(!is_ignored(Path::new("2024/photo.jpg")));
+        assert!(is_ignored(Path::new(".scan.pdf")));
+        assert!(is_ignored(Path::new(".sync/photo.jpg")));
+        assert!(is_ignored(Path::new("video.mp4.part")));
+        assert!(is_ignored(Path::new("notes.txt~")));
+    }
+
+    #[test]
+    fn test_file_name() {
+        let dir = Path::new("/srv/scans");
+        assert_eq!(file_name(dir, &dir.join("a.pdf")), "a.pdf");
+        assert_eq!(
+            file_name(dir, &dir.join("2024").join("b.pdf")),
+            "2024/b.pdf"
+        );
+    }
+}
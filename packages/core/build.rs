@@ -0,0 +1,13 @@
+fn main() {
+    // The gRPC service is generated from the proto file, compiled in Rust so no `protoc` is needed.
+    #[cfg(feature = "grpc")]
+    {
+        println!("cargo:rerun-if-changed=proto");
+
+        let descriptors = protox::compile(["proto/daemon.proto"], ["proto"])
+            .expect("Failed to compile proto/daemon.proto");
+        tonic_prost_build::configure()
+            .compile_fds(descriptors)
+            .expect("Failed to generate the gRPC service");
+    }
+}
@@ -0,0 +1,586 @@
+//! Local control socket: a JSON-RPC 2.0 interface on a unix socket, so that shell scripts
+//! and desktop applets can drive a running LocalSend instance without an HTTP client.
+//!
+//! Each line is one JSON-RPC message, e.g. with socat:
+//! `echo '{"jsonrpc":"2.0","method":"listPeers","id":1}' | socat - UNIX-CONNECT:localsend.sock`
+//!
+//! Methods:
+//! - `listPeers`: the peers known to the application.
+//! - `send` (`{"peerId": "...", "files": ["/absolute/path"]}`): enqueues a transfer,
+//!   resolves to `{"transferId": "..."}`.
+//! - `subscribe` / `unsubscribe`: starts / stops `transferUpdate` notifications
+//!   with the updates published via [`ControlSocket::publish`].
+//!
+//! The socket is only accessible by the owner, as any client can send files on their behalf.
+//! Batch requests are not supported.
+
+use crate::model::discovery::DeviceType;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::io;
+use std::net::IpAddr;
+use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::unix::OwnedWriteHalf;
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{broadcast, mpsc, oneshot, Mutex};
+use tokio::task::{AbortHandle, JoinSet};
+
+/// Maximum length of a single request line.
+const MAX_REQUEST_LENGTH: u64 = 1024 * 1024;
+
+/// Number of transfer updates buffered for slow subscribers before they miss updates.
+const UPDATE_CAPACITY: usize = 256;
+
+/// Number of responses and notifications buffered per connection.
+const OUTGOING_CAPACITY: usize = 64;
+
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_FOUND: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+const INTERNAL_ERROR: i32 = -32603;
+
+/// Error code of requests rejected by the application.
+const APPLICATION_ERROR: i32 = -32000;
+
+/// Events emitted by the control socket that must be handled by the application.
+#[derive(Debug)]
+pub enum ControlEvent {
+    /// A client lists the known peers.
+    ListPeers {
+        /// Channel to provide the peers. Dropping it results in an error response.
+        peers_tx: oneshot::Sender<Vec<ControlPeer>>,
+    },
+
+    /// A client enqueues a transfer.
+    Send {
+        /// The [`ControlPeer::id`] of the receiver.
+        peer_id: String,
+
+        /// Absolute paths of the files to send.
+        files: Vec<PathBuf>,
+
+        /// Channel to provide the ID of the enqueued transfer, or the reason it was rejected.
+        /// The progress of the transfer is reported via [`ControlSocket::publish`].
+        transfer_tx: oneshot::Sender<Result<String, String>>,
+    },
+}
+
+/// A peer as listed to clients of the control socket.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ControlPeer {
+    /// Identifies the peer in `send` requests, e.g. its fingerprint.
+    pub id: String,
+
+    pub alias: String,
+    pub device_model: Option<String>,
+    pub device_type: Option<DeviceType>,
+
+    /// The address on the local network. `None` if the peer is only reachable via WebRTC.
+    pub ip: Option<IpAddr>,
+}
+
+#[cfg(feature = "discovery")]
+impl From<&crate::discovery::DiscoveredPeer> for ControlPeer {
+    fn from(peer: &crate::discovery::DiscoveredPeer) -> Self {
+        Self {
+            id: peer.fingerprint.clone(),
+            alias: peer.alias.clone(),
+            device_model: peer.device_model.clone(),
+            device_type: peer.device_type.clone(),
+            ip: Some(peer.ip),
+        }
+    }
+}
+
+/// The progress of a transfer, sent to subscribed clients as `transferUpdate` notification.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum TransferUpdate {
+    /// The receiver has accepted the transfer.
+    Started {
+        transfer_id: String,
+    },
+
+    Progress {
+        transfer_id: String,
+        sent_bytes: u64,
+        total_bytes: u64,
+    },
+
+    Finished {
+        transfer_id: String,
+    },
+
+    /// The transfer has been declined, cancelled or has failed.
+    Failed {
+        transfer_id: String,
+        error: String,
+    },
+}
+
+/// Configuration of the control socket.
+pub struct ControlConfig {
+    /// Channel on which the socket emits events that must be handled by the application.
+    pub event_tx: mpsc::Sender<ControlEvent>,
+}
+
+/// A handle to a running control socket.
+pub struct ControlSocket {
+    path: PathBuf,
+    updates: broadcast::Sender<TransferUpdate>,
+    task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl ControlSocket {
+    /// The path the socket is bound to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Notifies the subscribed clients about the progress of a transfer.
+    pub fn publish(&self, update: TransferUpdate) {
+        // Fails only if no client is subscribed.
+        let _ = self.updates.send(update);
+    }
+
+    /// Waits until the socket has stopped after a stop was requested via the stop channel.
+    /// The socket file is removed and all connections are closed by then.
+    pub async fn wait_stopped(&self) {
+        if let Some(task) = self.task.lock().await.take() {
+            let _ = task.await;
+        }
+    }
+}
+
+/// The default socket path: `localsend.sock` in `$XDG_RUNTIME_DIR`,
+/// or `localsend-<uid>.sock` in the temporary directory shared by all users if it is not set.
+pub fn default_socket_path() -> PathBuf {
+    match std::env::var_os("XDG_RUNTIME_DIR").filter(|dir| !dir.is_empty()) {
+        Some(dir) => PathBuf::from(dir).join("localsend.sock"),
+        None => {
+            // SAFETY: getuid cannot fail.
+            let uid = unsafe { libc::getuid() };
+            std::env::temp_dir().join(format!("localsend-{uid}.sock"))
+        }
+    }
+}
+
+/// Binds the control socket at `path` and serves clients until a stop is requested.
+///
+/// A stale socket file left by a crashed instance is replaced.
+/// Fails if another instance is still listening at `path`, or if `path` is not a socket.
+pub async fn start_control_socket(
+    path: impl Into<PathBuf>,
+    config: ControlConfig,
+    stop_rx: oneshot::Receiver<()>,
+) -> anyhow::Result<ControlSocket> {
+    let path = path.into();
+    match tokio::fs::symlink_metadata(&path).await {
+        Ok(metadata) if !metadata.file_type().is_socket() => {
+            anyhow::bail!("{} exists and is not a socket", path.display());
+        }
+        Ok(_) => {
+            if UnixStream::connect(&path).await.is_ok() {
+                anyhow::bail!("Another instance is listening on {}", path.display());
+            }
+            tokio::fs::remove_file(&path).await?;
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    let listener = bind_private(&path).await?;
+
+    let (updates, _) = broadcast::channel(UPDATE_CAPACITY);
+
+    tracing::info!("Started control socket on {}", path.display());
+
+    let task = tokio::spawn({
+        let path = path.clone();
+        let updates = updates.clone();
+        async move {
+            tokio::select! {
+                _ = accept_loop(listener, config.event_tx, updates) => {}
+                _ = stop_rx => {}
+            }
+
+            if let Err(e) = tokio::fs::remove_file(&path).await {
+                tracing::warn!("Failed to remove control socket: {e}");
+            }
+            tracing::info!("Control socket stopped on {}", path.display());
+        }
+    });
+
+    Ok(ControlSocket {
+        path,
+        updates,
+        task: Mutex::new(Some(task)),
+    })
+}
+
+/// Binds the socket inside a directory only the owner can access and moves it to `path`
+/// once restricted to the owner, so no other user can connect in between.
+async fn bind_private(path: &Path) -> anyhow::Result<UnixListener> {
+    // Not longer than `localsend.sock`, socket paths are limited to about 100 bytes.
+    let dir = path.with_file_name(format!(".ls{:08x}", rand::random::<u32>()));
+    tokio::fs::DirBuilder::new()
+        .mode(0o700)
+        .create(&dir)
+        .await?;
+
+    let private_path = dir.join("s");
+    let result = async {
+        let listener = UnixListener::bind(&private_path)?;
+        tokio::fs::set_permissions(&private_path, std::fs::Permissions::from_mode(0o600)).await?;
+        tokio::fs::rename(&private_path, path).await?;
+        anyhow::Ok(listener)
+    }
+    .await;
+
+    let _ = tokio::fs::remove_file(&private_path).await;
+    let _ = tokio::fs::remove_dir(&dir).await;
+    result
+}
+
+async fn accept_loop(
+    listener: UnixListener,
+    event_tx: mpsc::Sender<ControlEvent>,
+    updates: broadcast::Sender<TransferUpdate>,
+) {
+    // Dropped with the loop, which closes the remaining connections.
+    let mut connections = JoinSet::new();
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    connections.spawn(serve_connection(stream, event_tx.clone(), updates.clone()));
+                }
+                Err(err) => tracing::warn!("Failed to accept control connection: {err:#}"),
+            },
+            Some(_) = connections.join_next() => {}
+        }
+    }
+}
+
+async fn serve_connection(
+    stream: UnixStream,
+    event_tx: mpsc::Sender<ControlEvent>,
+    updates: broadcast::Sender<TransferUpdate>,
+) {
+    let (read, write) = stream.into_split();
+    let mut reader = BufReader::new(read);
+    let (out_tx, out_rx) = mpsc::channel(OUTGOING_CAPACITY);
+
+    // The writer and the subscription; aborted if the connection is dropped.
+    let mut tasks = JoinSet::new();
+    tasks.spawn(write_loop(write, out_rx));
+
+    let mut connection = Connection {
+        event_tx,
+        updates,
+        out_tx,
+        subscription: None,
+        tasks,
+    };
+
+    let mut line = Vec::new();
+    loop {
+        match read_line(&mut reader, &mut line).await {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(e) => {
+                tracing::debug!("Closing control connection: {e}");
+                break;
+            }
+        }
+
+        if let Some(response) = connection.handle_line(&line).await {
+            if connection.out_tx.send(response).await.is_err() {
+                break;
+            }
+        }
+    }
+
+    // Stop the subscription, then let the writer flush the pending responses.
+    let Connection {
+        subscription,
+        out_tx,
+        mut tasks,
+        ..
+    } = connection;
+    if let Some(subscription) = subscription {
+        subscription.abort();
+    }
+    drop(out_tx);
+    while tasks.join_next().await.is_some() {}
+}
+
+/// Reads the next non-empty line into `line`. Returns `false` at the end of the stream.
+async fn read_line(
+    reader: &mut (impl AsyncBufRead + Unpin),
+    line: &mut Vec<u8>,
+) -> io::Result<bool> {
+    loop {
+        line.clear();
+        let read = (&mut *reader)
+            .take(MAX_REQUEST_LENGTH)
+            .read_until(b'\n', line)
+            .await?;
+        if read == 0 {
+            return Ok(false);
+        }
+        if line.last() != Some(&b'\n') && read as u64 == MAX_REQUEST_LENGTH {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Request too long",
+            ));
+        }
+        if !line.trim_ascii().is_empty() {
+            return Ok(true);
+        }
+    }
+}
+
+async fn write_loop(mut write: OwnedWriteHalf, mut out_rx: mpsc::Receiver<String>) {
+    while let Some(mut message) = out_rx.recv().await {
+        message.push('\n');
+        if let Err(e) = write.write_all(message.as_bytes()).await {
+            tracing::debug!("Failed to write to control connection: {e}");
+            return;
+        }
+    }
+}
+
+struct Connection {
+    event_tx: mpsc::Sender<ControlEvent>,
+    updates: broadcast::Sender<TransferUpdate>,
+    out_tx: mpsc::Sender<String>,
+    subscription: Option<AbortHandle>,
+    tasks: JoinSet<()>,
+}
+
+#[derive(Deserialize)]
+struct RpcRequest {
+    jsonrpc: String,
+    method: String,
+    #[serde(default)]
+    params: Value,
+
+    /// Requests without ID are notifications, which are not answered.
+    id: Option<Value>,
+}
+
+#[derive(Serialize)]
+struct RpcResponse {
+    jsonrpc: &'static str,
+    #[serde(flatten)]
+    outcome: RpcOutcome,
+    id: Value,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+enum RpcOutcome {
+    Result(Value),
+    Error(RpcError),
+}
+
+#[derive(Debug, Serialize)]
+struct RpcError {
+    code: i32,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct RpcNotification<T> {
+    jsonrpc: &'static str,
+    method: &'static str,
+    params: T,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SendParams {
+    peer_id: String,
+    files: Vec<PathBuf>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SendResult {
+    transfer_id: String,
+}
+
+impl Connection {
+    /// Handles a request line and returns the encoded response, if any.
+    async fn handle_line(&mut self, line: &[u8]) -> Option<String> {
+        let (outcome, id) = match serde_json::from_slice::<Value>(line) {
+            Err(e) => (Err(RpcError::new(PARSE_ERROR, e.to_string())), Value::Null),
+            Ok(value) => {
+                let id = value.get("id").cloned().unwrap_or(Value::Null);
+                match serde_json::from_value::<RpcRequest>(value) {
+                    Ok(request) if request.jsonrpc == "2.0" => {
+                        let outcome = self.handle_request(&request.method, request.params).await;
+                        match request.id {
+                            Some(id) => (outcome, id),
+                            None => return None,
+                        }
+                    }
+                    Ok(_) => (
+                        Err(RpcError::new(
+                            INVALID_REQUEST,
+                            "Unsupported JSON-RPC version",
+                        )),
+                        id,
+                    ),
+                    Err(e) => (Err(RpcError::new(INVALID_REQUEST, e.to_string())), id),
+                }
+            }
+        };
+
+        let response = RpcResponse {
+            jsonrpc: "2.0",
+            outcome: match outcome {
+                Ok(result) => RpcOutcome::Result(result),
+                Err(error) => RpcOutcome::Error(error),
+            },
+            id,
+        };
+        serde_json::to_string(&response).ok()
+    }
+
+    async fn handle_request(&mut self, method: &str, params: Value) -> Result<Value, RpcError> {
+        match method {
+            "listPeers" => {
+                let (peers_tx, peers_rx) = oneshot::channel();
+                self.emit(ControlEvent::ListPeers { peers_tx }).await?;
+                let peers = peers_rx.await.map_err(|_| not_handled())?;
+                serde_json::to_value(peers).map_err(internal_error)
+            }
+            "send" => {
+                let params = serde_json::from_value::<SendParams>(params)
+                    .map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))?;
+                if params.files.is_empty() {
+                    return Err(RpcError::new(INVALID_PARAMS, "No files to send"));
+                }
+                if let Some(path) = params.files.iter().find(|path| !path.is_absolute()) {
+                    return Err(RpcError::new(
+                        INVALID_PARAMS,
+                        format!("Path is not absolute: {}", path.display()),
+                    ));
+                }
+
+                let (transfer_tx, transfer_rx) = oneshot::channel();
+                self.emit(ControlEvent::Send {
+                    peer_id: params.peer_id,
+                    files: params.files,
+                    transfer_tx,
+                })
+                .await?;
+                let transfer_id = transfer_rx
+                    .await
+                    .map_err(|_| not_handled())?
+                    .map_err(|e| RpcError::new(APPLICATION_ERROR, e))?;
+                serde_json::to_value(SendResult { transfer_id }).map_err(internal_error)
+            }
+            "subscribe" => {
+                if self.subscription.is_none() {
+                    let updates = self.updates.subscribe();
+                    let out_tx = self.out_tx.clone();
+                    self.subscription = Some(self.tasks.spawn(forward_updates(updates, out_tx)));
+                }
+                Ok(Value::Bool(true))
+            }
+            "unsubscribe" => {
+                if let Some(subscription) = self.subscription.take() {
+                    subscription.abort();
+                }
+                Ok(Value::Bool(true))
+            }
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method: {method}"),
+            )),
+        }
+    }
+
+    async fn emit(&self, event: ControlEvent) -> Result<(), RpcError> {
+        self.event_tx.send(event).await.map_err(|_| not_handled())
+    }
+}
+
+fn not_handled() -> RpcError {
+    RpcError::new(INTERNAL_ERROR, "Request not handled by the application")
+}
+
+fn internal_error(e: impl std::fmt::Display) -> RpcError {
+    RpcError::new(INTERNAL_ERROR, e.to_string())
+}
+
+async fn forward_updates(
+    mut updates: broadcast::Receiver<TransferUpdate>,
+    out_tx: mpsc::Sender<String>,
+) {
+    loop {
+        let update = match updates.recv().await {
+            Ok(update) => update,
+            Err(broadcast::error::RecvError::Lagged(missed)) => {
+                tracing::warn!("Control client missed {missed} transfer updates");
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => return,
+        };
+
+        let notification = RpcNotification {
+            jsonrpc: "2.0",
+            method: "transferUpdate",
+            params: update,
+        };
+        let Ok(notification) = serde_json::to_string(&notification) else {
+            continue;
+        };
+        if out_tx.send(notification).await.is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transfer_update_encoding() {
+        let notification = RpcNotification {
+            jsonrpc: "2.0",
+            method: "transferUpdate",
+            params: TransferUpdate::Progress {
+                transfer_id: "1".to_string(),
+                sent_bytes: 5,
+                total_bytes: 10,
+            },
+        };
+
+        assert_eq!(
+            serde_json::to_string(&notification).unwrap(),
+            r#"{"jsonrpc":"2.0","method":"transferUpdate","params":{"type":"progress","transferId":"1","sentBytes":5,"totalBytes":10}}"#
+        );
+    }
+}
@@ -45,6 +45,29 @@ fn verify_cert_from_cert(cert: X509Certificate, public_key: Option<&str>) -> any
     Ok(())
 }
 
+/// Creates a self-signed certificate for the key pair of `private_key` (PKCS#8 PEM),
+/// as used by the HTTPS server and for client authentication.
+/// Encoded in PEM format. Valid from today for 10 years, like the certificates of the Dart app.
+#[cfg(feature = "http")]
+pub fn generate_self_signed_cert(private_key: &str) -> anyhow::Result<String> {
+    let key_pair = rcgen::KeyPair::from_pem(private_key)?;
+    let days = crate::util::time::unix_timestamp_u64()? / 86_400;
+    let (year, month, day) = crate::util::time::civil_from_days(days as i64);
+    let (year, month, day) = (year as i32, month as u8, day as u8);
+
+    let mut params = rcgen::CertificateParams::default();
+    params.distinguished_name = rcgen::DistinguishedName::new();
+    params
+        .distinguished_name
+        .push(rcgen::DnType::CommonName, "LocalSend User");
+    params.not_before = rcgen::date_time_ymd(year, month, day);
+    // February 29 does not exist in most years.
+    let expiry_day = if (month, day) == (2, 29) { 28 } else { day };
+    params.not_after = rcgen::date_time_ymd(year + 10, month, expiry_day);
+
+    Ok(params.self_signed(&key_pair)?.pem())
+}
+
 /// Computes the SHA-256 fingerprint of a certificate in DER format.
 /// Encoded as uppercase hex, the format used for LocalSend fingerprints.
 pub fn fingerprint_from_cert_der(cert: &[u8]) -> String {
@@ -188,6 +211,21 @@ nidU/qXQvBJ7NPUkXXgbcgqxK735iijOqQHmKts=
         );
     }
 
+    #[cfg(feature = "http")]
+    #[test]
+    fn test_generate_self_signed_cert() {
+        let key = crate::crypto::token::generate_key();
+        let private_key = crate::crypto::token::export_private_key(&key).unwrap();
+        let public_key = crate::crypto::token::export_public_key(&key).unwrap();
+
+        let cert = generate_self_signed_cert(&private_key).unwrap();
+        assert_eq!(
+            verify_cert_from_pem(cert, Some(&public_key)).map_err(|e| e.to_string()),
+            Ok(())
+        );
+        assert!(generate_self_signed_cert("invalid").is_err());
+    }
+
     #[test]
     fn test_fingerprint_from_cert_der() {
         let (cert_pem, _) = Pem::read(Cursor::new(CERT.as_bytes().to_vec())).unwrap();
@@ -5,3 +5,22 @@ pub fn sha256(data: &[u8]) -> Vec<u8> {
     hasher.update(data);
     hasher.finalize().to_vec()
 }
+
+/// SHA-256 of data arriving in chunks.
+#[derive(Default)]
+pub struct StreamingSha256(Sha256);
+
+impl StreamingSha256 {
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    /// Encoded as lowercase hex, the format of `FileDto::sha256`.
+    pub fn finalize_hex(self) -> String {
+        self.0
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+}
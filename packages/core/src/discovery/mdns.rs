@@ -0,0 +1,260 @@
+use crate::discovery::{DiscoveredPeer, DiscoveryMethod};
+use crate::http::dto_v2::{device_type_v2, ProtocolTypeV2, RegisterDtoV2};
+use mdns_sd::{ServiceDaemon, ServiceEvent, ServiceInfo};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use tokio::sync::mpsc;
+
+/// The DNS-SD service type used by LocalSend.
+pub const SERVICE_TYPE: &str = "_localsend._tcp.local.";
+
+/// Channel capacity of the discovered peer stream.
+const PEER_CHANNEL_CAPACITY: usize = 16;
+
+/// Maximum length of a DNS label.
+const MAX_LABEL_LENGTH: usize = 63;
+
+const TXT_ALIAS: &str = "alias";
+const TXT_VERSION: &str = "version";
+const TXT_DEVICE_MODEL: &str = "deviceModel";
+const TXT_DEVICE_TYPE: &str = "deviceType";
+const TXT_FINGERPRINT: &str = "fingerprint";
+const TXT_PROTOCOL: &str = "protocol";
+const TXT_DOWNLOAD: &str = "download";
+
+/// Advertises this device and browses for LocalSend devices via mDNS/DNS-SD.
+///
+/// Useful on networks where the multicast announcements are filtered
+/// but mDNS traffic is allowed (which is the case for most consumer routers).
+/// The service is unregistered once this is dropped.
+pub struct MdnsDiscovery {
+    daemon: ServiceDaemon,
+    fullname: String,
+    fingerprint: String,
+}
+
+impl MdnsDiscovery {
+    /// Starts the mDNS daemon and registers the `_localsend._tcp` service
+    /// with the given information as TXT records.
+    /// The addresses of all interfaces are advertised.
+    pub fn register(info: RegisterDtoV2) -> anyhow::Result<Self> {
+        let daemon = ServiceDaemon::new()?;
+
+        let name: String = info.fingerprint.chars().take(MAX_LABEL_LENGTH).collect();
+        let service = ServiceInfo::new(
+            SERVICE_TYPE,
+            &name,
+            &format!("{name}.local."),
+            (),
+            info.port,
+            txt_properties(&info),
+        )?
+        .enable_addr_auto();
+
+        let fullname = service.get_fullname().to_string();
+        daemon.register(service)?;
+
+        tracing::debug!("Registered mDNS service {fullname}");
+
+        Ok(Self {
+            daemon,
+            fullname,
+            fingerprint: info.fingerprint,
+        })
+    }
+
+    /// Starts browsing for other devices.
+    ///
+    /// Browsing stops once the returned receiver is dropped.
+    pub fn listen(&self) -> anyhow::Result<mpsc::Receiver<DiscoveredPeer>> {
+        let events = self.daemon.browse(SERVICE_TYPE)?;
+        let (tx, rx) = mpsc::channel(PEER_CHANNEL_CAPACITY);
+        let daemon = self.daemon.clone();
+        let fingerprint = self.fingerprint.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let event = tokio::select! {
+                    event = events.recv_async() => match event {
+                        Ok(event) => event,
+                        Err(_) => break,
+                    },
+                    _ = tx.closed() => break,
+                };
+
+                let ServiceEvent::ServiceResolved(service) = event else {
+                    continue;
+                };
+
+                let Some(peer) = parse_service(&fingerprint, &service) else {
+                    continue;
+                };
+
+                if tx.send(peer).await.is_err() {
+                    break;
+                }
+            }
+
+            let _ = daemon.stop_browse(SERVICE_TYPE);
+        });
+
+        Ok(rx)
+    }
+}
+
+impl Drop for MdnsDiscovery {
+    fn drop(&mut self) {
+        if let Err(e) = self.daemon.unregister(&self.fullname) {
+            tracing::warn!("Failed to unregister mDNS service: {e}");
+        }
+        let _ = self.daemon.shutdown();
+    }
+}
+
+fn txt_properties(info: &RegisterDtoV2) -> HashMap<String, String> {
+    let mut properties = HashMap::from([
+        (TXT_ALIAS.to_string(), info.alias.clone()),
+        (TXT_VERSION.to_string(), info.version.clone()),
+        (TXT_FINGERPRINT.to_string(), info.fingerprint.clone()),
+        (TXT_PROTOCOL.to_string(), info.protocol.as_str().to_string()),
+        (TXT_DOWNLOAD.to_string(), info.download.to_string()),
+    ]);
+
+    if let Some(device_model) = &info.device_model {
+        properties.insert(TXT_DEVICE_MODEL.to_string(), device_model.clone());
+    }
+
+    if let Ok(serde_json::Value::String(device_type)) =
+        device_type_v2::serialize(&info.device_type, serde_json::value::Serializer)
+    {
+        properties.insert(TXT_DEVICE_TYPE.to_string(), device_type);
+    }
+
+    properties
+}
+
+/// Parses a resolved service.
+///
+/// Returns `None` for incomplete services and our own service.
+fn parse_service(own_fingerprint: &str, service: &ServiceInfo) -> Option<DiscoveredPeer> {
+    let fingerprint = service.get_property_val_str(TXT_FINGERPRINT)?;
+    if fingerprint == own_fingerprint {
+        return None;
+    }
+
+    // Prefer IPv4 as this is what the multicast discovery reports as well.
+    let addresses = service.get_addresses();
+    let ip = addresses
+        .iter()
+        .find(|ip| ip.is_ipv4())
+        .or_else(|| addresses.iter().next())
+        .copied();
+    let Some(ip): Option<IpAddr> = ip else {
+        tracing::debug!(
+            "Ignoring mDNS service without address: {}",
+            service.get_fullname()
+        );
+        return None;
+    };
+
+    let protocol = match service.get_property_val_str(TXT_PROTOCOL) {
+        Some("http") => ProtocolTypeV2::Http,
+        _ => ProtocolTypeV2::Https,
+    };
+
+    let device_type = service
+        .get_property_val_str(TXT_DEVICE_TYPE)
+        .and_then(|value| {
+            device_type_v2::deserialize(serde_json::Value::String(value.to_string())).ok()
+        })
+        .flatten();
+
+    Some(DiscoveredPeer {
+        ip,
+        port: service.get_port(),
+        protocol,
+        alias: service.get_property_val_str(TXT_ALIAS)?.to_string(),
+        version: service.get_property_val_str(TXT_VERSION)?.to_string(),
+        device_model: service
+            .get_property_val_str(TXT_DEVICE_MODEL)
+            .map(str::to_string),
+        device_type,
+        fingerprint: fingerprint.to_string(),
+        download: service.get_property_val_str(TXT_DOWNLOAD) == Some("true"),
+        method: DiscoveryMethod::Mdns,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::discovery::DeviceType;
+    use std::net::Ipv4Addr;
+
+    fn register_dto() -> RegisterDtoV2 {
+        RegisterDtoV2 {
+            alias: "Nice Orange".to_string(),
+            version: "2.1".to_string(),
+            device_model: Some("Samsung".to_string()),
+            device_type: Some(DeviceType::Mobile),
+            fingerprint: "remote".to_string(),
+            port: 53317,
+            protocol: ProtocolTypeV2::Https,
+            download: true,
+        }
+    }
+
+    #[test]
+    fn txt_properties_roundtrip() {
+        let service = ServiceInfo::new(
+            SERVICE_TYPE,
+            "remote",
+            "remote.local.",
+            "192.168.1.2",
+            53317,
+            txt_properties(&register_dto()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            parse_service("own", &service),
+            Some(DiscoveredPeer {
+                ip: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2)),
+                port: 53317,
+                protocol: ProtocolTypeV2::Https,
+                alias: "Nice Orange".to_string(),
+                version: "2.1".to_string(),
+                device_model: Some("Samsung".to_string()),
+                device_type: Some(DeviceType::Mobile),
+                fingerprint: "remote".to_string(),
+                download: true,
+                method: DiscoveryMethod::Mdns,
+            })
+        );
+    }
+
+    #[test]
+    fn ignores_own_and_incomplete_services() {
+        let own = ServiceInfo::new(
+            SERVICE_TYPE,
+            "remote",
+            "remote.local.",
+            "192.168.1.2",
+            53317,
+            txt_properties(&register_dto()),
+        )
+        .unwrap();
+        assert!(parse_service("remote", &own).is_none());
+
+        let incomplete = ServiceInfo::new(
+            SERVICE_TYPE,
+            "other",
+            "other.local.",
+            "192.168.1.3",
+            53317,
+            HashMap::from([(TXT_FINGERPRINT.to_string(), "other".to_string())]),
+        )
+        .unwrap();
+        assert!(parse_service("own", &incomplete).is_none());
+    }
+}
@@ -0,0 +1,85 @@
+//! Discovery of LocalSend devices on the local network.
+//!
+//! Works without the signaling server, so devices can find each other
+//! even when there is no internet connection.
+
+pub mod mdns;
+pub mod multicast;
+
+use crate::http::dto_v2::ProtocolTypeV2;
+use crate::model::discovery::DeviceType;
+use std::net::IpAddr;
+use tokio::sync::mpsc;
+
+/// The mechanism a peer has been discovered with.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DiscoveryMethod {
+    /// UDP multicast announcement.
+    Multicast,
+
+    /// mDNS/DNS-SD service browsing.
+    Mdns,
+}
+
+/// A LocalSend device found on the local network.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiscoveredPeer {
+    /// The address the peer has been seen from.
+    pub ip: IpAddr,
+
+    /// The port of the peer's HTTP server.
+    pub port: u16,
+
+    /// Whether the peer's HTTP server uses HTTP or HTTPS.
+    pub protocol: ProtocolTypeV2,
+
+    /// The display name of the peer.
+    pub alias: String,
+
+    /// Protocol version (major.minor).
+    pub version: String,
+
+    /// The device model of the peer.
+    pub device_model: Option<String>,
+
+    /// The device type of the peer.
+    pub device_type: Option<DeviceType>,
+
+    /// Used to merge the same peers detected on different channels (LAN, WebRTC, etc.).
+    pub fingerprint: String,
+
+    /// Whether the download API is active.
+    pub download: bool,
+
+    /// How the peer has been discovered.
+    pub method: DiscoveryMethod,
+}
+
+/// Merges the peer streams of multiple discovery mechanisms into a single stream.
+///
+/// The same peer may be reported by several mechanisms;
+/// use the fingerprint to deduplicate.
+pub fn merge(receivers: Vec<mpsc::Receiver<DiscoveredPeer>>) -> mpsc::Receiver<DiscoveredPeer> {
+    let (tx, rx) = mpsc::channel(receivers.len().max(1) * 16);
+
+    for mut receiver in receivers {
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    peer = receiver.recv() => match peer {
+                        Some(peer) => {
+                            if tx.send(peer).await.is_err() {
+                                return;
+                            }
+                        }
+                        None => return,
+                    },
+                    _ = tx.closed() => return,
+                }
+            }
+        });
+    }
+
+    rx
+}
@@ -0,0 +1,245 @@
+use crate::discovery::{DiscoveredPeer, DiscoveryMethod};
+use crate::http::dto_v2::{MulticastMessageV2, RegisterDtoV2};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::sync::Arc;
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc;
+
+/// The multicast group used by LocalSend.
+pub const DEFAULT_MULTICAST_GROUP: Ipv4Addr = Ipv4Addr::new(224, 0, 0, 167);
+
+/// The port used by LocalSend for both multicast and the HTTP server.
+pub const DEFAULT_MULTICAST_PORT: u16 = 53317;
+
+/// Maximum size of a UDP datagram.
+const MAX_DATAGRAM_SIZE: usize = 64 * 1024;
+
+/// Channel capacity of the discovered peer stream.
+const PEER_CHANNEL_CAPACITY: usize = 16;
+
+pub struct MulticastConfig {
+    /// The multicast group to join.
+    pub group: Ipv4Addr,
+
+    /// The port to listen on and to send announcements to.
+    pub port: u16,
+
+    /// The information about this device announced to other devices.
+    /// The fingerprint is also used to ignore our own announcements.
+    pub info: RegisterDtoV2,
+}
+
+impl MulticastConfig {
+    /// Creates a config with the default multicast group and port.
+    pub fn new(info: RegisterDtoV2) -> Self {
+        Self {
+            group: DEFAULT_MULTICAST_GROUP,
+            port: DEFAULT_MULTICAST_PORT,
+            info,
+        }
+    }
+}
+
+/// Discovers LocalSend devices via UDP multicast announcements.
+///
+/// Other devices respond to an announcement with a multicast message of their own
+/// (the `announce` flag set to false), so a single [`announce`](Self::announce)
+/// is usually enough to find all devices that are currently online.
+pub struct MulticastDiscovery {
+    socket: Arc<UdpSocket>,
+    target: SocketAddr,
+    info: Arc<RegisterDtoV2>,
+}
+
+impl MulticastDiscovery {
+    /// Binds the multicast socket and joins the group on the default interface.
+    /// Must be called within a tokio runtime.
+    pub fn bind(config: MulticastConfig) -> anyhow::Result<Self> {
+        let socket = socket2::Socket::new(
+            socket2::Domain::IPV4,
+            socket2::Type::DGRAM,
+            Some(socket2::Protocol::UDP),
+        )?;
+
+        // Other LocalSend instances (or the HTTP server) may use the same port.
+        socket.set_reuse_address(true)?;
+        #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+        socket.set_reuse_port(true)?;
+        socket.set_nonblocking(true)?;
+        socket.bind(&SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, config.port).into())?;
+        socket.join_multicast_v4(&config.group, &Ipv4Addr::UNSPECIFIED)?;
+        socket.set_multicast_loop_v4(true)?;
+
+        let socket = UdpSocket::from_std(socket.into())?;
+
+        tracing::debug!("Joined multicast group {}:{}", config.group, config.port);
+
+        Ok(Self {
+            socket: Arc::new(socket),
+            target: SocketAddr::V4(SocketAddrV4::new(config.group, config.port)),
+            info: Arc::new(config.info),
+        })
+    }
+
+    /// Announces this device so that other devices respond.
+    pub async fn announce(&self) -> anyhow::Result<()> {
+        send_message(&self.socket, self.target, &self.info, true).await
+    }
+
+    /// Starts listening for announcements and responses of other devices.
+    ///
+    /// Announcements of other devices are answered automatically.
+    /// Listening stops once the returned receiver is dropped.
+    pub fn listen(&self) -> mpsc::Receiver<DiscoveredPeer> {
+        let (tx, rx) = mpsc::channel(PEER_CHANNEL_CAPACITY);
+        let socket = self.socket.clone();
+        let target = self.target;
+        let info = self.info.clone();
+
+        tokio::spawn(async move {
+            let mut buffer = vec![0u8; MAX_DATAGRAM_SIZE];
+            loop {
+                let (len, from) = tokio::select! {
+                    result = socket.recv_from(&mut buffer) => match result {
+                        Ok(result) => result,
+                        Err(e) => {
+                            tracing::error!("Failed to receive multicast message: {e}");
+                            return;
+                        }
+                    },
+                    _ = tx.closed() => return,
+                };
+
+                let Some((peer, announce)) =
+                    parse_datagram(&info.fingerprint, &buffer[..len], from.ip())
+                else {
+                    continue;
+                };
+
+                if announce {
+                    if let Err(e) = send_message(&socket, target, &info, false).await {
+                        tracing::warn!("Failed to respond to announcement of {from}: {e}");
+                    }
+                }
+
+                if tx.send(peer).await.is_err() {
+                    return;
+                }
+            }
+        });
+
+        rx
+    }
+}
+
+async fn send_message(
+    socket: &UdpSocket,
+    target: SocketAddr,
+    info: &RegisterDtoV2,
+    announce: bool,
+) -> anyhow::Result<()> {
+    let message = MulticastMessageV2 {
+        alias: info.alias.clone(),
+        version: info.version.clone(),
+        device_model: info.device_model.clone(),
+        device_type: info.device_type.clone(),
+        fingerprint: info.fingerprint.clone(),
+        port: info.port,
+        protocol: info.protocol.clone(),
+        download: info.download,
+        announce,
+    };
+
+    socket
+        .send_to(serde_json::to_string(&message)?.as_bytes(), target)
+        .await?;
+
+    Ok(())
+}
+
+/// Parses a received datagram.
+///
+/// Returns the peer and whether it expects a response,
+/// or `None` for invalid messages and our own messages.
+fn parse_datagram(
+    own_fingerprint: &str,
+    data: &[u8],
+    from: IpAddr,
+) -> Option<(DiscoveredPeer, bool)> {
+    let message = match serde_json::from_slice::<MulticastMessageV2>(data) {
+        Ok(message) => message,
+        Err(e) => {
+            tracing::debug!("Ignoring invalid multicast message from {from}: {e}");
+            return None;
+        }
+    };
+
+    if message.fingerprint == own_fingerprint {
+        return None;
+    }
+
+    let peer = DiscoveredPeer {
+        ip: from,
+        port: message.port,
+        protocol: message.protocol,
+        alias: message.alias,
+        version: message.version,
+        device_model: message.device_model,
+        device_type: message.device_type,
+        fingerprint: message.fingerprint,
+        download: message.download,
+        method: DiscoveryMethod::Multicast,
+    };
+
+    Some((peer, message.announce))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::dto_v2::ProtocolTypeV2;
+    use crate::model::discovery::DeviceType;
+
+    const ANNOUNCEMENT: &str = r#"{
+        "alias": "Nice Orange",
+        "version": "2.1",
+        "deviceModel": "Samsung",
+        "deviceType": "mobile",
+        "fingerprint": "remote",
+        "port": 53317,
+        "protocol": "https",
+        "download": true,
+        "announce": true
+    }"#;
+
+    #[test]
+    fn parses_announcement() {
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2));
+        let (peer, announce) = parse_datagram("own", ANNOUNCEMENT.as_bytes(), ip).unwrap();
+
+        assert!(announce);
+        assert_eq!(
+            peer,
+            DiscoveredPeer {
+                ip,
+                port: 53317,
+                protocol: ProtocolTypeV2::Https,
+                alias: "Nice Orange".to_string(),
+                version: "2.1".to_string(),
+                device_model: Some("Samsung".to_string()),
+                device_type: Some(DeviceType::Mobile),
+                fingerprint: "remote".to_string(),
+                download: true,
+                method: DiscoveryMethod::Multicast,
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_own_and_invalid_messages() {
+        let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2));
+
+        assert!(parse_datagram("remote", ANNOUNCEMENT.as_bytes(), ip).is_none());
+        assert!(parse_datagram("own", b"not json", ip).is_none());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::util::fs::JsonFileStore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// A device the user marked as favorite.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FavoriteDevice {
+    /// Fingerprint the device announced when it was added.
+    pub fingerprint: String,
+
+    /// The name of the device, updated whenever it is seen.
+    pub alias: String,
+
+    /// Unix timestamp in seconds of when the device was last seen.
+    #[serde(default)]
+    pub last_seen: Option<u64>,
+
+    /// Free text entered by the user.
+    #[serde(default)]
+    pub notes: String,
+}
+
+/// Favorite devices by fingerprint.
+pub struct FavoriteStore {
+    devices: JsonFileStore<BTreeMap<String, FavoriteDevice>>,
+}
+
+impl FavoriteStore {
+    /// Loads the favorites at `path`, or none if the file does not exist yet.
+    pub async fn open(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        Ok(Self {
+            devices: JsonFileStore::open(path).await?,
+        })
+    }
+
+    pub fn get(&self, fingerprint: &str) -> Option<FavoriteDevice> {
+        self.devices
+            .read(|devices| devices.get(fingerprint).cloned())
+    }
+
+    /// All favorites sorted by alias.
+    pub fn list(&self) -> Vec<FavoriteDevice> {
+        let mut devices: Vec<_> = self
+            .devices
+            .read(|devices| devices.values().cloned().collect());
+        devices.sort_by_cached_key(|device| device.alias.to_lowercase());
+        devices
+    }
+
+    /// Adds the device to the favorites, keeping the notes if it already is one.
+    pub async fn add(
+        &self,
+        fingerprint: &str,
+        alias: &str,
+        notes: Option<String>,
+    ) -> anyhow::Result<FavoriteDevice> {
+        let last_seen = crate::util::time::unix_timestamp_u64()?;
+        self.devices
+            .update(|devices| {
+                let device =
+                    devices
+                        .entry(fingerprint.to_string())
+                        .or_insert_with(|| FavoriteDevice {
+                            fingerprint: fingerprint.to_string(),
+                            alias: String::new(),
+                            last_seen: None,
+                            notes: String::new(),
+                        });
+                device.alias = alias.to_string();
+                device.last_seen = Some(last_seen);
+                if let Some(notes) = notes {
+                    device.notes = notes;
+                }
+                Ok(device.clone())
+            })
+            .await
+    }
+
+    pub async fn set_notes(&self, fingerprint: &str, notes: String) -> anyhow::Result<()> {
+        self.devices
+            .update(|devices| match devices.get_mut(fingerprint) {
+                Some(device) => {
+                    device.notes = notes;
+                    Ok(())
+                }
+                None => Err(anyhow::anyhow!("Device is not a favorite: {fingerprint}")),
+            })
+            .await
+    }
+
+    pub async fn remove(&self, fingerprint: &str) -> anyhow::Result<()> {
+        self.devices
+            .update(|devices| {
+                devices.remove(fingerprint);
+                Ok(())
+            })
+            .await
+    }
+
+    /// Returns the favorite matching a discovered device and records that it was seen.
+    ///
+    /// Devices are matched by fingerprint, then by alias, because some channels
+    /// (e.g. WebRTC signaling) announce a different token on every connection.
+    pub async fn mark_seen(
+        &self,
+        fingerprint: &str,
+        alias: &str,
+    ) -> anyhow::Result<Option<FavoriteDevice>> {
+        if self.find(fingerprint, alias).is_none() {
+            return Ok(None);
+        }
+
+        let last_seen = crate::util::time::unix_timestamp_u64()?;
+        self.devices
+            .update(|devices| {
+                let key = find_key(devices, fingerprint, alias);
+                Ok(key.and_then(|key| devices.get_mut(&key)).map(|device| {
+                    device.alias = alias.to_string();
+                    device.last_seen = Some(last_seen);
+                    device.clone()
+                }))
+            })
+            .await
+    }
+
+    /// Returns the favorite matching a discovered device, see [`Self::mark_seen`].
+    pub fn find(&self, fingerprint: &str, alias: &str) -> Option<FavoriteDevice> {
+        self.devices.read(|devices| {
+            find_key(devices, fingerprint, alias).and_then(|key| devices.get(&key).cloned())
+        })
+    }
+}
+
+fn find_key(
+    devices: &BTreeMap<String, FavoriteDevice>,
+    fingerprint: &str,
+    alias: &str,
+) -> Option<String> {
+    if devices.contains_key(fingerprint) {
+        return Some(fingerprint.to_string());
+    }
+
+    devices
+        .values()
+        .find(|device| device.alias == alias)
+        .map(|device| device.fingerprint.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_favorite_store() {
+        let path = std::env::temp_dir()
+            .join(format!("localsend-{}", uuid::Uuid::new_v4()))
+            .join("favorites.json");
+
+        let store = FavoriteStore::open(&path).await.unwrap();
+        assert_eq!(store.mark_seen("abc", "Nice Orange").await.unwrap(), None);
+
+        let added = store
+            .add("abc", "Nice Orange", Some("Living room".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(added.notes, "Living room");
+        assert!(added.last_seen.is_some());
+
+        // Matched by alias when the fingerprint changed.
+        let seen = store
+            .mark_seen("def", "Nice Orange")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(seen.fingerprint, "abc");
+
+        let seen = store.mark_seen("abc", "Big Apple").await.unwrap().unwrap();
+        assert_eq!(seen.alias, "Big Apple");
+
+        store.set_notes("abc", "Kitchen".to_string()).await.unwrap();
+        assert!(store.set_notes("def", String::new()).await.is_err());
+
+        let reopened = FavoriteStore::open(&path).await.unwrap();
+        assert_eq!(reopened.list(), store.list());
+        assert_eq!(reopened.get("abc").unwrap().notes, "Kitchen");
+
+        reopened.remove("abc").await.unwrap();
+        assert!(FavoriteStore::open(&path).await.unwrap().list().is_empty());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
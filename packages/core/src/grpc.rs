@@ -0,0 +1,389 @@
+//! gRPC control API of a headless daemon, so that NAS and server users can drive transfers
+//! remotely and script them, e.g. with grpcurl:
+//! `grpcurl -insecure -import-path proto -proto daemon.proto -H "authorization: Bearer $TOKEN" nas:53319 localsend.daemon.v1.Daemon/ListPeers`
+//!
+//! The service is defined in `proto/daemon.proto`:
+//! - `ListPeers` and `SendFiles` are handled by the application via [`GrpcEvent`].
+//! - `AcceptPolicy` reads and replaces the policy the application applies to incoming
+//!   transfers, see [`GrpcServer::accept_policy`].
+//! - `StreamEvents` streams the events published via [`GrpcServer::publish`].
+//!
+//! Every call must carry the configured token, as any client can send files on behalf of the user.
+//! Without TLS, the token can be read by everyone on the network,
+//! so plain connections are only suitable for the loopback interface.
+
+/// The messages and the service generated from `proto/daemon.proto`.
+#[allow(clippy::all)]
+pub mod proto {
+    tonic::include_proto!("localsend.daemon.v1");
+}
+
+use crate::model::transfer::FileDto;
+use crate::util::token::is_token_equal;
+use futures_util::Stream;
+use proto::daemon_server::{Daemon, DaemonServer};
+use proto::{
+    AcceptMode, AcceptPolicy, AcceptPolicyRequest, AcceptPolicyResponse, ListPeersRequest,
+    ListPeersResponse, Peer, SendFilesRequest, SendFilesResponse, StreamEventsRequest,
+    TransferEvent,
+};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::{broadcast, mpsc, oneshot, Mutex};
+use tokio_util::sync::CancellationToken;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::{Identity, Server, ServerTlsConfig};
+use tonic::{Request, Response, Status};
+
+/// Number of transfer events buffered for slow subscribers before they miss events.
+const EVENT_CAPACITY: usize = 256;
+
+/// Events emitted by the gRPC server that must be handled by the application.
+#[derive(Debug)]
+pub enum GrpcEvent {
+    /// A client lists the known peers.
+    ListPeers {
+        /// Channel to provide the peers. Dropping it fails the call.
+        peers_tx: oneshot::Sender<Vec<Peer>>,
+    },
+
+    /// A client enqueues a transfer.
+    SendFiles {
+        /// The [`Peer::id`] of the receiver.
+        peer_id: String,
+
+        /// Absolute paths of the files and directories to send.
+        paths: Vec<PathBuf>,
+
+        /// Sent if the receiver requires a PIN.
+        pin: Option<String>,
+
+        /// Channel to provide the ID of the enqueued transfer, or the reason it was rejected.
+        /// The progress of the transfer is reported via [`GrpcServer::publish`].
+        transfer_tx: oneshot::Sender<Result<String, String>>,
+    },
+}
+
+impl AcceptPolicy {
+    /// Whether a transfer from the peer with the given fingerprint is accepted.
+    pub fn accepts(&self, fingerprint: &str) -> bool {
+        // Unknown modes of newer clients decline.
+        match self.mode() {
+            AcceptMode::Decline => false,
+            AcceptMode::AcceptAll => true,
+            AcceptMode::AcceptListed => self
+                .fingerprints
+                .iter()
+                .any(|listed| listed.eq_ignore_ascii_case(fingerprint)),
+        }
+    }
+}
+
+impl From<&crate::model::discovery::DeviceType> for proto::DeviceType {
+    fn from(device_type: &crate::model::discovery::DeviceType) -> Self {
+        use crate::model::discovery::DeviceType;
+        match device_type {
+            DeviceType::Mobile => Self::Mobile,
+            DeviceType::Desktop => Self::Desktop,
+            DeviceType::Web => Self::Web,
+            DeviceType::Headless => Self::Headless,
+            DeviceType::Server => Self::Server,
+        }
+    }
+}
+
+#[cfg(feature = "discovery")]
+impl From<&crate::discovery::DiscoveredPeer> for Peer {
+    fn from(peer: &crate::discovery::DiscoveredPeer) -> Self {
+        Self {
+            id: peer.fingerprint.clone(),
+            alias: peer.alias.clone(),
+            device_model: peer.device_model.clone(),
+            device_type: peer
+                .device_type
+                .as_ref()
+                .map_or(proto::DeviceType::Unspecified, Into::into)
+                .into(),
+            ip: Some(peer.ip.to_string()),
+        }
+    }
+}
+
+impl From<&FileDto> for proto::File {
+    fn from(file: &FileDto) -> Self {
+        Self {
+            id: file.id.clone(),
+            name: file.file_name.clone(),
+            size: file.has_known_size().then_some(file.size),
+        }
+    }
+}
+
+/// The certificate and private key (PEM) the server authenticates with.
+#[derive(Clone, Debug)]
+pub struct GrpcTlsConfig {
+    pub cert: String,
+    pub private_key: String,
+}
+
+/// Configuration of the gRPC server.
+pub struct GrpcConfig {
+    /// The token clients must send as `authorization: Bearer <token>`.
+    pub token: String,
+
+    /// Serves via TLS if set, which is required to keep the token secret on the network.
+    pub tls: Option<GrpcTlsConfig>,
+
+    /// The policy for incoming transfers until a client replaces it.
+    pub accept_policy: AcceptPolicy,
+
+    /// Channel on which the server emits events that must be handled by the application.
+    pub event_tx: mpsc::Sender<GrpcEvent>,
+}
+
+/// A handle to a running gRPC server.
+pub struct GrpcServer {
+    local_addr: SocketAddr,
+    events: broadcast::Sender<TransferEvent>,
+    accept_policy: Arc<std::sync::Mutex<AcceptPolicy>>,
+    task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl GrpcServer {
+    /// The address the server is bound to.
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// The policy for incoming transfers, possibly replaced by a client.
+    /// The application checks it for every incoming transfer.
+    pub fn accept_policy(&self) -> AcceptPolicy {
+        self.accept_policy.lock().unwrap().clone()
+    }
+
+    /// Notifies the clients streaming the events about a transfer.
+    pub fn publish(&self, event: TransferEvent) {
+        // Fails only if no client is streaming.
+        let _ = self.events.send(event);
+    }
+
+    /// Receives the events published from now on, like a client streaming them.
+    pub fn subscribe(&self) -> broadcast::Receiver<TransferEvent> {
+        self.events.subscribe()
+    }
+
+    /// Waits until the server has stopped after a stop was requested via the stop channel.
+    /// All event streams are ended by then.
+    pub async fn wait_stopped(&self) {
+        if let Some(task) = self.task.lock().await.take() {
+            let _ = task.await;
+        }
+    }
+}
+
+/// Binds the gRPC server to `addr` and serves clients until a stop is requested.
+pub async fn start_grpc_server(
+    addr: SocketAddr,
+    config: GrpcConfig,
+    stop_rx: oneshot::Receiver<()>,
+) -> anyhow::Result<GrpcServer> {
+    if config.token.is_empty() {
+        anyhow::bail!("The gRPC token must not be empty");
+    }
+
+    let mut builder = Server::builder();
+    if let Some(tls) = config.tls {
+        builder = builder.tls_config(
+            ServerTlsConfig::new().identity(Identity::from_pem(tls.cert, tls.private_key)),
+        )?;
+    } else if !addr.ip().is_loopback() {
+        tracing::warn!(
+            "The gRPC server on {addr} does not use TLS, the token is sent in plain text"
+        );
+    }
+
+    let listener = TcpListener::bind(addr).await?;
+    let local_addr = listener.local_addr()?;
+
+    let (events, _) = broadcast::channel(EVENT_CAPACITY);
+    let accept_policy = Arc::new(std::sync::Mutex::new(config.accept_policy));
+    let stopped = CancellationToken::new();
+    let service = DaemonService {
+        event_tx: config.event_tx,
+        events: events.clone(),
+        accept_policy: accept_policy.clone(),
+        stopped: stopped.clone(),
+    };
+    let token = config.token;
+    let service = DaemonServer::with_interceptor(service, move |request: Request<()>| {
+        let authorized = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|presented| is_token_equal(presented, &token));
+        match authorized {
+            true => Ok(request),
+            false => Err(Status::unauthenticated("Invalid token")),
+        }
+    });
+
+    tracing::info!("Started gRPC server on {local_addr}");
+
+    let task = tokio::spawn(async move {
+        let shutdown = async move {
+            let _ = stop_rx.await;
+            // End the event streams, the graceful shutdown waits for them otherwise.
+            stopped.cancel();
+        };
+        if let Err(e) = builder
+            .add_service(service)
+            .serve_with_incoming_shutdown(TcpIncoming::from(listener), shutdown)
+            .await
+        {
+            tracing::warn!("gRPC server failed: {e:#}");
+        }
+        tracing::info!("gRPC server stopped on {local_addr}");
+    });
+
+    Ok(GrpcServer {
+        local_addr,
+        events,
+        accept_policy,
+        task: Mutex::new(Some(task)),
+    })
+}
+
+struct DaemonService {
+    event_tx: mpsc::Sender<GrpcEvent>,
+    events: broadcast::Sender<TransferEvent>,
+    accept_policy: Arc<std::sync::Mutex<AcceptPolicy>>,
+    stopped: CancellationToken,
+}
+
+impl DaemonService {
+    async fn emit(&self, event: GrpcEvent) -> Result<(), Status> {
+        self.event_tx.send(event).await.map_err(|_| not_handled())
+    }
+}
+
+fn not_handled() -> Status {
+    Status::internal("Request not handled by the application")
+}
+
+type EventStream = Pin<Box<dyn Stream<Item = Result<TransferEvent, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl Daemon for DaemonService {
+    async fn list_peers(
+        &self,
+        _request: Request<ListPeersRequest>,
+    ) -> Result<Response<ListPeersResponse>, Status> {
+        let (peers_tx, peers_rx) = oneshot::channel();
+        self.emit(GrpcEvent::ListPeers { peers_tx }).await?;
+        let peers = peers_rx.await.map_err(|_| not_handled())?;
+        Ok(Response::new(ListPeersResponse { peers }))
+    }
+
+    async fn send_files(
+        &self,
+        request: Request<SendFilesRequest>,
+    ) -> Result<Response<SendFilesResponse>, Status> {
+        let request = request.into_inner();
+        if request.paths.is_empty() {
+            return Err(Status::invalid_argument("No files to send"));
+        }
+        let paths: Vec<PathBuf> = request.paths.into_iter().map(PathBuf::from).collect();
+        if let Some(path) = paths.iter().find(|path| !path.is_absolute()) {
+            return Err(Status::invalid_argument(format!(
+                "Path is not absolute: {}",
+                path.display()
+            )));
+        }
+
+        let (transfer_tx, transfer_rx) = oneshot::channel();
+        self.emit(GrpcEvent::SendFiles {
+            peer_id: request.peer_id,
+            paths,
+            pin: request.pin,
+            transfer_tx,
+        })
+        .await?;
+        let transfer_id = transfer_rx
+            .await
+            .map_err(|_| not_handled())?
+            .map_err(Status::failed_precondition)?;
+        Ok(Response::new(SendFilesResponse { transfer_id }))
+    }
+
+    async fn accept_policy(
+        &self,
+        request: Request<AcceptPolicyRequest>,
+    ) -> Result<Response<AcceptPolicyResponse>, Status> {
+        let mut policy = self.accept_policy.lock().unwrap();
+        if let Some(replacement) = request.into_inner().policy {
+            tracing::info!("Accept policy replaced: {replacement:?}");
+            *policy = replacement;
+        }
+        Ok(Response::new(AcceptPolicyResponse {
+            policy: Some(policy.clone()),
+        }))
+    }
+
+    type StreamEventsStream = EventStream;
+
+    async fn stream_events(
+        &self,
+        _request: Request<StreamEventsRequest>,
+    ) -> Result<Response<EventStream>, Status> {
+        let events = self.events.subscribe();
+        let stopped = self.stopped.clone();
+        let stream = futures_util::stream::unfold(events, move |mut events| {
+            let stopped = stopped.clone();
+            async move {
+                loop {
+                    let received = tokio::select! {
+                        received = events.recv() => received,
+                        _ = stopped.cancelled() => return None,
+                    };
+                    match received {
+                        Ok(event) => return Some((Ok(event), events)),
+                        Err(broadcast::error::RecvError::Lagged(missed)) => {
+                            tracing::warn!("gRPC client missed {missed} transfer events");
+                        }
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+                }
+            }
+        });
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_policy() {
+        let mut policy = AcceptPolicy {
+            mode: AcceptMode::AcceptListed.into(),
+            fingerprints: vec!["ABCD".to_string()],
+        };
+        assert!(policy.accepts("abcd"));
+        assert!(!policy.accepts("ABCE"));
+
+        policy.mode = AcceptMode::AcceptAll.into();
+        assert!(policy.accepts("ABCE"));
+
+        policy.mode = AcceptMode::Decline.into();
+        assert!(!policy.accepts("ABCD"));
+
+        // Modes of newer clients.
+        policy.mode = 42;
+        assert!(!policy.accepts("ABCD"));
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "webrtc")]
+pub mod sender;
 mod url;
 pub mod v2;
 pub mod v3;
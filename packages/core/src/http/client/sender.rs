@@ -0,0 +1,201 @@
+use crate::http::client::{upload_body, ClientError, LsHttpClientV2};
+use crate::http::dto::ProtocolType;
+use crate::http::dto_v2::{PrepareUploadRequestDtoV2, RegisterDtoV2};
+use crate::http::StatusCodeError;
+use crate::model::transfer::{FileContent, FileDto};
+use crate::webrtc::webrtc::{RTCFile, RTCFileError, RTCStatus};
+use std::collections::HashSet;
+use tokio::sync::{mpsc, oneshot};
+use tokio_util::sync::CancellationToken;
+
+/// Number of bytes of a file that have been sent to the receiver.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UploadProgress {
+    pub file_id: String,
+
+    /// Cumulative number of bytes sent.
+    pub sent: u64,
+}
+
+/// Sends files to a receiver via the HTTP v2 API (prepare-upload + upload).
+///
+/// This is the counterpart of [`send_offer`](crate::webrtc::webrtc::send_offer)
+/// for older LocalSend devices discovered on the LAN that do not support WebRTC.
+/// The same events are reported so the application can drive both paths alike:
+/// * `status_tx` receives [`RTCStatus::PinRequired`], [`RTCStatus::TooManyAttempts`],
+///   [`RTCStatus::Declined`], [`RTCStatus::Sending`], [`RTCStatus::Finished`]
+///   or [`RTCStatus::Error`].
+/// * `pin_tx` is asked for a PIN each time the receiver requires one.
+/// * `selected_files_tx` receives the IDs of the files accepted by the receiver.
+/// * `sending_rx` provides the content of the accepted files, one at a time.
+/// * `error_tx` receives per-file errors; the remaining files are still sent.
+///
+/// Cancelling `cancel` aborts the running upload, notifies the receiver
+/// and returns [`ClientError::Cancelled`].
+#[allow(clippy::too_many_arguments)]
+pub async fn send_files_v2(
+    client: &LsHttpClientV2,
+    protocol: ProtocolType,
+    ip: &str,
+    port: u16,
+    public_key: Option<String>,
+    info: RegisterDtoV2,
+    files: Vec<FileDto>,
+    status_tx: mpsc::Sender<RTCStatus>,
+    progress_tx: mpsc::Sender<UploadProgress>,
+    selected_files_tx: oneshot::Sender<HashSet<String>>,
+    error_tx: mpsc::Sender<RTCFileError>,
+    pin_tx: mpsc::Sender<oneshot::Sender<String>>,
+    mut sending_rx: mpsc::Receiver<RTCFile>,
+    cancel: CancellationToken,
+) -> Result<(), ClientError> {
+    let payload = PrepareUploadRequestDtoV2 {
+        info,
+        files: files.into_iter().map(|f| (f.id.clone(), f)).collect(),
+    };
+
+    let mut pin: Option<String> = None;
+    let result = loop {
+        let result = tokio::select! {
+            result = client.prepare_upload(
+                protocol.clone(),
+                ip,
+                port,
+                public_key.clone(),
+                payload.clone(),
+                pin.as_deref(),
+            ) => result,
+            _ = cancel.cancelled() => return Err(ClientError::Cancelled),
+        };
+
+        match result {
+            Ok(result) => break result,
+            Err(ClientError::StatusCode(StatusCodeError { status: 401, .. })) => {
+                tracing::debug!("PIN required by the receiver.");
+                let _ = status_tx.send(RTCStatus::PinRequired).await;
+                let (pin_res_tx, pin_res_rx) = oneshot::channel();
+                if pin_tx.send(pin_res_tx).await.is_err() {
+                    return Err(anyhow::anyhow!("Failed to request PIN").into());
+                }
+                pin = Some(pin_res_rx.await.map_err(anyhow::Error::from)?);
+            }
+            Err(ClientError::StatusCode(StatusCodeError { status: 403, .. })) => {
+                tracing::debug!("Declined by the receiver.");
+                let _ = status_tx.send(RTCStatus::Declined).await;
+                return Ok(());
+            }
+            Err(ClientError::StatusCode(StatusCodeError { status: 429, .. })) => {
+                let _ = status_tx.send(RTCStatus::TooManyAttempts).await;
+                return Ok(());
+            }
+            Err(e) => {
+                let _ = status_tx.send(RTCStatus::Error(e.to_string())).await;
+                return Err(e);
+            }
+        }
+    };
+
+    let Some(response) = result.response else {
+        // 204: the receiver does not need any file content.
+        let _ = selected_files_tx.send(HashSet::new());
+        let _ = status_tx.send(RTCStatus::Finished).await;
+        return Ok(());
+    };
+
+    if selected_files_tx
+        .send(response.files.keys().cloned().collect())
+        .is_err()
+    {
+        let error = "Could not publish selection";
+        let _ = status_tx.send(RTCStatus::Error(error.to_owned())).await;
+        return Err(anyhow::anyhow!(error).into());
+    }
+
+    let _ = status_tx.send(RTCStatus::Sending).await;
+
+    tracing::debug!("Received file tokens. Sending files...");
+
+    let mut remaining = response.files.len();
+    while remaining > 0 {
+        let message = tokio::select! {
+            message = sending_rx.recv() => match message {
+                Some(message) => message,
+                None => break,
+            },
+            _ = cancel.cancelled() => {
+                cancel_session(client, protocol, ip, port, &response.session_id).await;
+                return Err(ClientError::Cancelled);
+            }
+        };
+
+        let Some(token) = response.files.get(&message.file_id) else {
+            let _ = error_tx
+                .send(RTCFileError {
+                    file_id: message.file_id,
+                    error: "Failed to get file token".to_string(),
+                })
+                .await;
+            continue;
+        };
+
+        let body = {
+            let file_id = message.file_id.clone();
+            let progress_tx = progress_tx.clone();
+            upload_body(FileContent::Stream(message.binary_rx), move |sent| {
+                // Progress is best-effort; skip updates if the application lags behind.
+                let _ = progress_tx.try_send(UploadProgress {
+                    file_id: file_id.clone(),
+                    sent,
+                });
+            })
+        };
+
+        let result = client
+            .upload(
+                protocol.clone(),
+                ip,
+                port,
+                public_key.clone(),
+                &response.session_id,
+                &message.file_id,
+                token,
+                body,
+                cancel.clone(),
+            )
+            .await;
+
+        match result {
+            Ok(()) => remaining -= 1,
+            Err(ClientError::Cancelled) => {
+                cancel_session(client, protocol, ip, port, &response.session_id).await;
+                return Err(ClientError::Cancelled);
+            }
+            Err(e) => {
+                remaining -= 1;
+                let _ = error_tx
+                    .send(RTCFileError {
+                        file_id: message.file_id,
+                        error: e.to_string(),
+                    })
+                    .await;
+            }
+        }
+    }
+
+    let _ = status_tx.send(RTCStatus::Finished).await;
+
+    Ok(())
+}
+
+/// Notifies the receiver that the session has been cancelled by the sender.
+async fn cancel_session(
+    client: &LsHttpClientV2,
+    protocol: ProtocolType,
+    ip: &str,
+    port: u16,
+    session_id: &str,
+) {
+    if let Err(e) = client.cancel(protocol, ip, port, session_id).await {
+        tracing::warn!("Failed to cancel session: {e}");
+    }
+}
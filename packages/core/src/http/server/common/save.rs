@@ -1,3 +1,5 @@
+use crate::model::part::PartFile;
+use crate::model::transfer::UNKNOWN_SIZE;
 use bytes::Bytes;
 use http_body_util::BodyExt;
 use hyper::body::Incoming;
@@ -16,19 +18,23 @@ pub enum FileUploadTarget {
     ///
     /// The server forwards chunks into `binary_tx` and closes it at end of file.
     /// The application should compare the number of received bytes with `file.size`
-    /// and report the result on the sender side of `result_rx` which determines
-    /// the HTTP response (200 on `Ok`, 500 on `Err` or when the sender is dropped).
+    /// (unless it is [`UNKNOWN_SIZE`]) and report the result on the sender side of
+    /// `result_rx` which determines the HTTP response (200 on `Ok`, 500 on `Err` or
+    /// when the sender is dropped).
     Stream {
         /// Channel the server sends the binary chunks of the file into.
         binary_tx: mpsc::Sender<Bytes>,
 
         /// Channel on which the application reports whether the file was
-        /// processed successfully.
+        /// processed successfully. If the upload is interrupted, it is dropped
+        /// before `binary_tx`, so the sender being closed at the end of the file
+        /// tells an incomplete file of unknown size apart.
         result_rx: oneshot::Receiver<Result<(), String>>,
     },
 
-    /// The server writes the file to this path (created or truncated)
-    /// and reports the result on `result_tx`.
+    /// The server writes the file to `<path>.part` and renames it to this path once
+    /// the size and the SHA-256 (if announced by the sender) match.
+    /// It reports the result on `result_tx`.
     Path {
         /// The path to write the file to.
         path: PathBuf,
@@ -62,7 +68,10 @@ pub(crate) async fn save_req_to_target(
     req: Request<Incoming>,
     target: FileUploadTarget,
     file_size: u64,
+    file_sha256: Option<String>,
 ) -> bool {
+    let expected_size = (file_size != UNKNOWN_SIZE).then_some(file_size);
+
     // Resolve the target into a chunk sender and a result receiver.
     let (binary_tx, result_rx) = match target {
         FileUploadTarget::Stream {
@@ -75,11 +84,18 @@ pub(crate) async fn save_req_to_target(
             progress_tx,
         } => spawn_file_writer(
             async move {
-                tokio::fs::File::create(&path)
+                let file = PartFile::create(&path, file_sha256.is_some())
                     .await
-                    .map_err(|e| format!("Failed to create {}: {e}", path.display()))
+                    .map_err(|e| format!("Failed to create {}: {e}", path.display()))?;
+                if let Some(size) = expected_size.filter(|size| *size > 0) {
+                    if let Err(e) = file.preallocate(size).await {
+                        file.cleanup().await;
+                        return Err(format!("Not enough space for {}: {e}", path.display()));
+                    }
+                }
+                Ok(TargetFile::Part(file, file_sha256))
             },
-            file_size,
+            expected_size,
             result_tx,
             progress_tx,
         ),
@@ -95,9 +111,9 @@ pub(crate) async fn save_req_to_target(
                 // SAFETY: the descriptor is owned by this transfer; wrapping it in
                 // a File transfers that ownership so it is closed once writing finishes.
                 let std_file = unsafe { std::fs::File::from_raw_fd(fd) };
-                Ok(tokio::fs::File::from_std(std_file))
+                Ok(TargetFile::Fd(tokio::fs::File::from_std(std_file)))
             },
-            file_size,
+            expected_size,
             result_tx,
             progress_tx,
         ),
@@ -130,19 +146,64 @@ pub(crate) async fn save_req_to_target(
         }
     }
 
-    // Signal end of file to the receiving side.
+    // Signal end of file to the receiving side. On errors, the result channel is closed
+    // first, so the receiving side can tell an interrupted upload from the end of the file.
+    if stream_error {
+        drop(result_rx);
+        drop(binary_tx);
+        return false;
+    }
     drop(binary_tx);
 
-    match stream_error {
-        true => false,
-        false => match result_rx.await {
-            Ok(Ok(())) => true,
-            Ok(Err(err)) => {
-                tracing::warn!("Failed to process file: {err}");
-                false
-            }
-            Err(_) => false,
-        },
+    match result_rx.await {
+        Ok(Ok(())) => true,
+        Ok(Err(err)) => {
+            tracing::warn!("Failed to process file: {err}");
+            false
+        }
+        Err(_) => false,
+    }
+}
+
+/// A file opened by [`spawn_file_writer`].
+enum TargetFile {
+    /// Renamed to its final path once complete, verified with the SHA-256 if known.
+    Part(PartFile, Option<String>),
+
+    #[cfg(target_os = "android")]
+    Fd(tokio::fs::File),
+}
+
+impl TargetFile {
+    async fn write(&mut self, data: &[u8]) -> std::io::Result<()> {
+        match self {
+            TargetFile::Part(file, _) => file.write(data).await,
+            #[cfg(target_os = "android")]
+            TargetFile::Fd(file) => tokio::io::AsyncWriteExt::write_all(file, data).await,
+        }
+    }
+
+    async fn finish(self, expected_size: Option<u64>) -> Result<(), String> {
+        match self {
+            TargetFile::Part(file, sha256) => file
+                .finalize(expected_size, sha256.as_deref())
+                .await
+                .map(|_| ())
+                .map_err(|e| format!("Failed to finalize file: {e:#}")),
+            #[cfg(target_os = "android")]
+            TargetFile::Fd(mut file) => tokio::io::AsyncWriteExt::flush(&mut file)
+                .await
+                .map_err(|e| format!("Failed to flush file: {e}")),
+        }
+    }
+
+    /// Removes what was written, if possible.
+    async fn cleanup(self) {
+        match self {
+            TargetFile::Part(file, _) => file.cleanup().await,
+            #[cfg(target_os = "android")]
+            TargetFile::Fd(_) => {}
+        }
     }
 }
 
@@ -151,8 +212,8 @@ pub(crate) async fn save_req_to_target(
 /// Returns the sender for the binary chunks and a receiver for the final result.
 /// The result is additionally reported to the application on `result_tx`.
 fn spawn_file_writer(
-    open: impl Future<Output = Result<tokio::fs::File, String>> + Send + 'static,
-    expected_size: u64,
+    open: impl Future<Output = Result<TargetFile, String>> + Send + 'static,
+    expected_size: Option<u64>,
     result_tx: oneshot::Sender<Result<(), String>>,
     progress_tx: Option<mpsc::Sender<u64>>,
 ) -> (mpsc::Sender<Bytes>, oneshot::Receiver<Result<(), String>>) {
@@ -160,8 +221,14 @@ fn spawn_file_writer(
     let (internal_tx, internal_rx) = oneshot::channel::<Result<(), String>>();
 
     tokio::spawn(async move {
-        let result =
-            write_file_from_receiver(open, expected_size, &mut binary_rx, progress_tx).await;
+        let result = write_file_from_receiver(
+            open,
+            expected_size,
+            &mut binary_rx,
+            progress_tx,
+            &internal_tx,
+        )
+        .await;
         // Unblock the request handler if it is still sending chunks.
         binary_rx.close();
         let _ = result_tx.send(result.clone());
@@ -174,25 +241,46 @@ fn spawn_file_writer(
 /// Writes all chunks received on `rx` to the file provided by `open`.
 ///
 /// Fails if the total number of written bytes does not match `expected_size`
-/// (e.g. the sender disconnected mid-transfer).
+/// or the upload has been interrupted, i.e. the request handler closed
+/// `internal_tx` before the end of the file (e.g. the sender disconnected mid-transfer).
 async fn write_file_from_receiver(
-    open: impl Future<Output = Result<tokio::fs::File, String>>,
-    expected_size: u64,
+    open: impl Future<Output = Result<TargetFile, String>>,
+    expected_size: Option<u64>,
     rx: &mut mpsc::Receiver<Bytes>,
     progress_tx: Option<mpsc::Sender<u64>>,
+    internal_tx: &oneshot::Sender<Result<(), String>>,
 ) -> Result<(), String> {
-    use tokio::io::AsyncWriteExt;
-
     let mut file = open.await?;
+    let written = write_chunks(&mut file, expected_size, rx, progress_tx)
+        .await
+        .and_then(|()| match internal_tx.is_closed() {
+            true => Err("Upload interrupted".to_string()),
+            false => Ok(()),
+        });
+    match written {
+        Ok(()) => file.finish(expected_size).await,
+        Err(err) => {
+            file.cleanup().await;
+            Err(err)
+        }
+    }
+}
+
+async fn write_chunks(
+    file: &mut TargetFile,
+    expected_size: Option<u64>,
+    rx: &mut mpsc::Receiver<Bytes>,
+    progress_tx: Option<mpsc::Sender<u64>>,
+) -> Result<(), String> {
     let mut written: u64 = 0;
     while let Some(chunk) = rx.recv().await {
         written += chunk.len() as u64;
-        if written > expected_size {
+        if let Some(expected_size) = expected_size.filter(|expected| written > *expected) {
             return Err(format!(
                 "Expected {expected_size} bytes, received at least {written}"
             ));
         }
-        file.write_all(&chunk)
+        file.write(&chunk)
             .await
             .map_err(|e| format!("Failed to write file: {e}"))?;
         if let Some(progress_tx) = &progress_tx {
@@ -200,11 +288,8 @@ async fn write_file_from_receiver(
             let _ = progress_tx.try_send(written);
         }
     }
-    file.flush()
-        .await
-        .map_err(|e| format!("Failed to flush file: {e}"))?;
 
-    if written != expected_size {
+    if let Some(expected_size) = expected_size.filter(|expected| written != *expected) {
         return Err(format!(
             "Expected {expected_size} bytes, received {written}"
         ));
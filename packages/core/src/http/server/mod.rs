@@ -1,5 +1,6 @@
 pub mod common;
 pub mod internal;
+pub mod share;
 pub mod v2;
 pub mod v3;
 pub mod web;
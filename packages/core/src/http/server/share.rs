@@ -0,0 +1,358 @@
+//! One-time share links: files served over plain HTTP to any browser that knows
+//! the random token, e.g. a smart TV without LocalSend or WebRTC support.
+//!
+//! Contrary to web send ([`super::web`]), no confirmation is requested from the
+//! application: possessing the link is the authorization. The link can be limited
+//! in time and in the number of downloads per file.
+
+use crate::http::server::common::error::AppError;
+use crate::http::server::common::response::{full_body, BoxedBody};
+use crate::http::server::web::attachment_response;
+use crate::model::transfer::{FileContent, FileDto};
+use crate::util::base64;
+use hyper::body::Incoming;
+use hyper::{http, Method, Request, Response, StatusCode};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto::Builder;
+use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
+use rand::RngCore;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, oneshot, Mutex, Notify};
+
+/// Number of random bytes in the token of a share link.
+const TOKEN_LENGTH: usize = 16;
+
+/// Events emitted by a share link server that must be handled by the application.
+#[derive(Debug)]
+pub enum ShareEvent {
+    /// A browser downloads a file via the share link.
+    ///
+    /// The application must respond on `content_tx` with exactly `file.size` bytes.
+    /// Dropping `content_tx` results in a 500 response.
+    FileDownload {
+        /// The ID of the file being downloaded.
+        file_id: String,
+
+        /// The metadata of the file being downloaded.
+        file: Box<FileDto>,
+
+        /// Channel to provide the content of the file being downloaded.
+        content_tx: oneshot::Sender<FileContent>,
+    },
+
+    /// The link has expired or all files have reached their download limit.
+    /// The server has stopped accepting connections.
+    Closed,
+}
+
+/// Configuration of a share link.
+pub struct ShareConfig {
+    /// The metadata of the shared files, mapped by file ID.
+    pub files: HashMap<String, FileDto>,
+
+    /// How long the link is valid. `None` keeps it valid until the server is stopped.
+    pub expires_in: Option<Duration>,
+
+    /// How many times each file can be downloaded. `None` for no limit.
+    /// Use `Some(1)` for a one-time link.
+    pub max_downloads: Option<u32>,
+
+    /// Channel on which the server emits events that must be handled by the application.
+    pub event_tx: mpsc::Sender<ShareEvent>,
+}
+
+struct ShareState {
+    token: String,
+    files: HashMap<String, FileDto>,
+    expires_at: Option<Instant>,
+    max_downloads: Option<u32>,
+    event_tx: mpsc::Sender<ShareEvent>,
+
+    /// Number of started downloads, mapped by file ID.
+    downloads: Mutex<HashMap<String, u32>>,
+
+    /// Notified once every file has reached the download limit.
+    exhausted: Notify,
+}
+
+impl ShareState {
+    fn is_expired(&self) -> bool {
+        self.expires_at.is_some_and(|at| Instant::now() >= at)
+    }
+}
+
+/// A handle to a running share link server.
+pub struct ShareLink {
+    /// The port the server is bound to.
+    pub port: u16,
+
+    /// The random token that authorizes access to the files.
+    pub token: String,
+
+    task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl ShareLink {
+    /// The URL to open in a browser, reachable via the given local address.
+    pub fn url(&self, ip: IpAddr) -> String {
+        format!(
+            "http://{}/share/{}",
+            SocketAddr::new(ip, self.port),
+            self.token
+        )
+    }
+
+    /// Waits until the server has stopped, either because it was requested
+    /// via the stop channel or because the link was closed.
+    pub async fn wait_stopped(&self) {
+        if let Some(task) = self.task.lock().await.take() {
+            let _ = task.await;
+        }
+    }
+}
+
+/// Serves the files on the given port (`0` to pick a free one) until the link
+/// expires, every file has reached its download limit, or a stop is requested.
+///
+/// Connections that are still downloading when the link is closed run to completion.
+pub async fn start_share_server(
+    port: u16,
+    config: ShareConfig,
+    stop_rx: oneshot::Receiver<()>,
+) -> anyhow::Result<ShareLink> {
+    let listener =
+        tokio::net::TcpListener::bind(SocketAddr::new(Ipv4Addr::UNSPECIFIED.into(), port)).await?;
+    let port = listener.local_addr()?.port();
+
+    let mut token = [0_u8; TOKEN_LENGTH];
+    rand::rng().fill_bytes(&mut token);
+
+    let state = Arc::new(ShareState {
+        token: base64::encode(token),
+        files: config.files,
+        expires_at: config
+            .expires_in
+            .map(|expires_in| Instant::now() + expires_in),
+        max_downloads: config.max_downloads,
+        event_tx: config.event_tx,
+        downloads: Mutex::new(HashMap::new()),
+        exhausted: Notify::new(),
+    });
+
+    tracing::info!("Started share link server on port {port}");
+
+    let task = tokio::spawn({
+        let state = state.clone();
+        async move {
+            let expired = async {
+                match state.expires_at {
+                    Some(at) => tokio::time::sleep_until(at.into()).await,
+                    None => std::future::pending().await,
+                }
+            };
+
+            let closed = tokio::select! {
+                _ = accept_loop(listener, state.clone()) => false,
+                _ = expired => true,
+                _ = state.exhausted.notified() => true,
+                _ = stop_rx => false,
+            };
+
+            if closed {
+                let _ = state.event_tx.send(ShareEvent::Closed).await;
+            }
+
+            tracing::info!("Share link server stopped on port {port}");
+        }
+    });
+
+    Ok(ShareLink {
+        port,
+        token: state.token.clone(),
+        task: Mutex::new(Some(task)),
+    })
+}
+
+async fn accept_loop(listener: tokio::net::TcpListener, state: Arc<ShareState>) {
+    loop {
+        let tcp_stream = match listener.accept().await {
+            Ok((tcp_stream, _)) => tcp_stream,
+            Err(err) => {
+                tracing::warn!("Failed to accept connection: {err:#}");
+                continue;
+            }
+        };
+
+        let state = state.clone();
+        tokio::spawn(async move {
+            let res = Builder::new(TokioExecutor::new())
+                .serve_connection(
+                    TokioIo::new(tcp_stream),
+                    hyper::service::service_fn(move |req: Request<Incoming>| {
+                        let state = state.clone();
+                        async move { Ok::<_, hyper::Error>(serve(req, &state).await) }
+                    }),
+                )
+                .await;
+
+            if let Err(err) = res {
+                tracing::warn!("Failed to serve connection: {err:#}");
+            }
+        });
+    }
+}
+
+async fn serve(req: Request<Incoming>, state: &ShareState) -> Response<BoxedBody> {
+    handle_request(req, state).await.unwrap_or_else(|err| {
+        tracing::debug!("Error handling share request: {err:?}");
+        err.to_response()
+    })
+}
+
+async fn handle_request(
+    req: Request<Incoming>,
+    state: &ShareState,
+) -> Result<Response<BoxedBody>, AppError> {
+    if req.method() != Method::GET {
+        return Err(AppError::Status(StatusCode::METHOD_NOT_ALLOWED));
+    }
+
+    // Paths: /share/<token> and /share/<token>/<file id>
+    let mut segments = req
+        .uri()
+        .path()
+        .strip_prefix("/share/")
+        .ok_or(AppError::Status(StatusCode::NOT_FOUND))?
+        .splitn(2, '/');
+
+    // Unknown tokens are indistinguishable from unknown paths.
+    if segments.next() != Some(state.token.as_str()) {
+        return Err(AppError::Status(StatusCode::NOT_FOUND));
+    }
+
+    if state.is_expired() {
+        return Err(AppError::Message(
+            StatusCode::GONE,
+            "Link expired.".to_string(),
+        ));
+    }
+
+    match segments.next().filter(|file_id| !file_id.is_empty()) {
+        None => Ok(file_list_page(state)),
+        Some(file_id) => {
+            let file_id = percent_decode_str(file_id)
+                .decode_utf8()
+                .map_err(|_| AppError::Status(StatusCode::NOT_FOUND))?;
+            download(state, &file_id).await
+        }
+    }
+}
+
+async fn download(state: &ShareState, file_id: &str) -> Result<Response<BoxedBody>, AppError> {
+    let Some(file) = state.files.get(file_id) else {
+        return Err(AppError::Status(StatusCode::NOT_FOUND));
+    };
+
+    let mut exhausted = false;
+    if let Some(max_downloads) = state.max_downloads {
+        let mut downloads = state.downloads.lock().await;
+        let count = downloads.entry(file_id.to_string()).or_default();
+        if *count >= max_downloads {
+            return Err(AppError::Message(
+                StatusCode::GONE,
+                "Download limit reached.".to_string(),
+            ));
+        }
+        *count += 1;
+
+        exhausted = state.files.keys().all(|id| {
+            downloads
+                .get(id)
+                .is_some_and(|count| *count >= max_downloads)
+        });
+    }
+
+    let (content_tx, content_rx) = oneshot::channel::<FileContent>();
+    let event = ShareEvent::FileDownload {
+        file_id: file_id.to_string(),
+        file: Box::new(file.clone()),
+        content_tx,
+    };
+    if state.event_tx.send(event).await.is_err() {
+        return Err(AppError::Status(StatusCode::INTERNAL_SERVER_ERROR));
+    }
+
+    if exhausted {
+        // Close the link after announcing the last download.
+        state.exhausted.notify_one();
+    }
+
+    let content = content_rx
+        .await
+        .map_err(|_| AppError::Status(StatusCode::INTERNAL_SERVER_ERROR))?;
+
+    attachment_response(file, content)
+}
+
+/// A minimal page without scripts, so it works in limited browsers (e.g. smart TVs).
+fn file_list_page(state: &ShareState) -> Response<BoxedBody> {
+    let mut files: Vec<&FileDto> = state.files.values().collect();
+    files.sort_by(|a, b| a.file_name.cmp(&b.file_name));
+
+    let mut html = String::from(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\">\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\
+         <title>LocalSend</title></head><body><ul>",
+    );
+    for file in files {
+        let _ = write!(
+            html,
+            "<li><a href=\"/share/{}/{}\">{}</a> ({} bytes)</li>",
+            state.token,
+            utf8_percent_encode(&file.id, NON_ALPHANUMERIC),
+            escape_html(&file.file_name),
+            file.size,
+        );
+    }
+    html.push_str("</ul></body></html>");
+
+    let mut response = Response::new(full_body(html));
+    response.headers_mut().insert(
+        http::header::CONTENT_TYPE,
+        http::HeaderValue::from_static("text/html; charset=utf-8"),
+    );
+    response
+}
+
+fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<a href=\"x\">Tom & Jerry's</a>"),
+            "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+        assert_eq!(escape_html("plain.txt"), "plain.txt");
+    }
+}
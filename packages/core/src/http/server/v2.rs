@@ -359,6 +359,7 @@ pub(crate) async fn upload(
     let mut upload_guard = UploadGuard::new(v2.clone(), session_id.clone(), file_id.clone());
 
     let file_size = file_dto.size;
+    let file_sha256 = file_dto.sha256.clone();
     let (target_tx, target_rx) = oneshot::channel::<FileUploadTarget>();
 
     let event = ServerEventV2::FileUpload {
@@ -377,7 +378,7 @@ pub(crate) async fn upload(
         return Err(AppError::Status(StatusCode::INTERNAL_SERVER_ERROR));
     };
 
-    let success = common::save::save_req_to_target(req, target, file_size).await;
+    let success = common::save::save_req_to_target(req, target, file_size, file_sha256).await;
 
     upload_guard.finish(success).await;
 
@@ -58,7 +58,7 @@ pub enum WebSendEvent {
         file_id: String,
 
         /// The metadata of the file being downloaded.
-        file: FileDto,
+        file: Box<FileDto>,
 
         /// Channel to provide the content of the file being downloaded.
         content_tx: oneshot::Sender<FileContent>,
@@ -326,7 +326,7 @@ pub(crate) async fn download(
     let event = WebSendEvent::FileDownload {
         session_id: session_id.clone(),
         file_id: file_id.clone(),
-        file: file.clone(),
+        file: Box::new(file.clone()),
         content_tx,
     };
     if web.event_tx.send(event).await.is_err() {
@@ -337,6 +337,14 @@ pub(crate) async fn download(
         .await
         .map_err(|_| AppError::Status(StatusCode::INTERNAL_SERVER_ERROR))?;
 
+    attachment_response(file, content)
+}
+
+/// Streams the file content as a download, named after the file.
+pub(crate) fn attachment_response(
+    file: &FileDto,
+    content: FileContent,
+) -> Result<Response<BoxedBody>, AppError> {
     let size = file.size;
     let body = receiver_stream_body(content.into_receiver());
 
@@ -1,9 +1,27 @@
+#[cfg(unix)]
+pub mod control;
 #[cfg(feature = "crypto")]
 pub mod crypto;
+#[cfg(feature = "discovery")]
+pub mod discovery;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod favorites;
+#[cfg(feature = "grpc")]
+pub mod grpc;
 #[cfg(feature = "http")]
 pub mod http;
 pub mod model;
-pub(crate) mod util;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod resume;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod settings;
+#[cfg(all(feature = "http", feature = "webrtc"))]
+pub mod transport;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod trust;
+pub mod util;
+#[cfg(feature = "http")]
+pub mod webhook;
 pub mod webrtc;
 
 #[cfg(feature = "http")]
@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+
+/// What to do when a received file has the name of an existing one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CollisionPolicy {
+    /// Appends " (1)", " (2)", ... to the file stem.
+    #[default]
+    Rename,
+
+    /// Replaces the existing file once the received one is complete.
+    Overwrite,
+
+    /// Keeps the existing file and discards the received one.
+    Skip,
+
+    /// Fails without receiving anything, so the user can be asked which policy to use instead.
+    Ask,
+}
+
+/// Where a received file is written, see [`reserve_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reservation {
+    /// An empty placeholder was created at the path. It is replaced by the received file,
+    /// or has to be removed if the transfer fails.
+    Reserved(PathBuf),
+
+    /// The file at the path, if any, is replaced.
+    Overwrite(PathBuf),
+
+    /// A file with the name exists and is kept.
+    Skip,
+}
+
+/// Picks the path a received file is written to according to the policy.
+///
+/// Unless overwriting, the name is claimed by atomically creating an empty file,
+/// so two files received at once never get the same path. The filesystem decides whether
+/// the name is taken, which also covers case-insensitive filesystems where `photo.JPG`
+/// collides with an existing `Photo.jpg`.
+pub async fn reserve_path(
+    path: impl Into<PathBuf>,
+    policy: CollisionPolicy,
+) -> anyhow::Result<Reservation> {
+    let path = path.into();
+    if policy == CollisionPolicy::Overwrite {
+        return Ok(Reservation::Overwrite(path));
+    }
+
+    let mut candidate = path.clone();
+    let mut counter = 1;
+    loop {
+        let created = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&candidate)
+            .await;
+        match created {
+            Ok(_) => return Ok(Reservation::Reserved(candidate)),
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        match policy {
+            CollisionPolicy::Rename => {
+                candidate = numbered(&path, counter);
+                counter += 1;
+            }
+            CollisionPolicy::Skip => return Ok(Reservation::Skip),
+            CollisionPolicy::Ask | CollisionPolicy::Overwrite => {
+                anyhow::bail!("File already exists: {}", candidate.display())
+            }
+        }
+    }
+}
+
+/// Appends " (n)" to the file stem, e.g. `photo (1).jpg`.
+fn numbered(path: &Path, n: u32) -> PathBuf {
+    let stem = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    path.with_file_name(match path.extension() {
+        Some(extension) => format!("{stem} ({n}).{}", extension.to_string_lossy()),
+        None => format!("{stem} ({n})"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reserve_path() {
+        let dir = std::env::temp_dir().join(format!("localsend-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("photo.jpg");
+
+        let reserved = reserve_path(&path, CollisionPolicy::Ask).await.unwrap();
+        assert_eq!(reserved, Reservation::Reserved(path.clone()));
+        assert!(path.exists());
+
+        assert!(reserve_path(&path, CollisionPolicy::Ask).await.is_err());
+        assert_eq!(
+            reserve_path(&path, CollisionPolicy::Skip).await.unwrap(),
+            Reservation::Skip
+        );
+        assert_eq!(
+            reserve_path(&path, CollisionPolicy::Overwrite)
+                .await
+                .unwrap(),
+            Reservation::Overwrite(path.clone())
+        );
+
+        let renamed = reserve_path(&path, CollisionPolicy::Rename).await.unwrap();
+        assert_eq!(renamed, Reservation::Reserved(dir.join("photo (1).jpg")));
+        let renamed = reserve_path(&path, CollisionPolicy::Rename).await.unwrap();
+        assert_eq!(renamed, Reservation::Reserved(dir.join("photo (2).jpg")));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
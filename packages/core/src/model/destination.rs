@@ -0,0 +1,50 @@
+use std::path::{Component, Path, PathBuf};
+
+/// Applies a destination chosen by the user for a single file to the path it would be saved to.
+///
+/// An absolute `destination` replaces the path. A relative one is resolved against the directory
+/// of `path`, so `holiday.jpg` renames the file and `Photos/holiday.jpg` moves it into a
+/// subdirectory. Relative destinations must not leave that directory.
+pub fn apply_destination(path: &Path, destination: &str) -> anyhow::Result<PathBuf> {
+    let destination = Path::new(destination);
+    if destination.is_absolute() {
+        return Ok(destination.to_path_buf());
+    }
+
+    let mut components = destination.components().peekable();
+    if components.peek().is_none()
+        || !components.all(|component| matches!(component, Component::Normal(_)))
+    {
+        anyhow::bail!("Invalid destination: {}", destination.display());
+    }
+
+    Ok(match path.parent() {
+        Some(parent) => parent.join(destination),
+        None => destination.to_path_buf(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_destination() {
+        let path = Path::new("/downloads/IMG_0001.jpg");
+        assert_eq!(
+            apply_destination(path, "holiday.jpg").unwrap(),
+            Path::new("/downloads/holiday.jpg")
+        );
+        assert_eq!(
+            apply_destination(path, "Photos/holiday.jpg").unwrap(),
+            Path::new("/downloads/Photos/holiday.jpg")
+        );
+        assert_eq!(
+            apply_destination(path, "/media/usb/holiday.jpg").unwrap(),
+            Path::new("/media/usb/holiday.jpg")
+        );
+        assert!(apply_destination(path, "").is_err());
+        assert!(apply_destination(path, "../holiday.jpg").is_err());
+        assert!(apply_destination(path, "./holiday.jpg").is_err());
+    }
+}
@@ -0,0 +1,342 @@
+#[cfg(not(target_arch = "wasm32"))]
+use crate::model::transfer::FileDto;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::util::mime;
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Size of the chunks a file is read in to compute its SHA-256.
+#[cfg(all(feature = "crypto", not(target_arch = "wasm32")))]
+const HASH_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Maximum length in bytes of a file or directory name on common filesystems.
+const MAX_NAME_LENGTH: usize = 255;
+
+/// Characters Windows does not allow in names, in addition to control characters.
+const INVALID_CHARS: [char; 7] = ['<', '>', ':', '"', '|', '?', '*'];
+
+/// Names Windows reserves for devices, also with an extension such as `nul.txt`.
+const RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Why a file name received from a peer cannot be used, see [`sanitize_file_name`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FileNameError {
+    #[error("File name is empty: {0:?}")]
+    Empty(String),
+
+    #[error("File name is longer than {MAX_NAME_LENGTH} bytes: {0}")]
+    TooLong(String),
+}
+
+/// Optional details of a file offered in a [`FileDto`].
+///
+/// Every field may be missing on the wire, so older clients and clients that
+/// do not support a field can still be parsed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileMetadata {
+    /// Time of the last modification, formatted as ISO 8601 in UTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified: Option<String>,
+
+    /// Time of the last access, formatted as ISO 8601 in UTC.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessed: Option<String>,
+
+    /// Unix permission bits, e.g. `0o755` for an executable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mode: Option<u32>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<MediaInfo>,
+}
+
+/// Properties of images, audio and video known to the sender.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaInfo {
+    /// Width in pixels as stored, before applying the orientation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+
+    /// Height in pixels as stored, before applying the orientation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+
+    /// EXIF orientation from 1 to 8. Width and height swap when shown for 5 to 8.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub orientation: Option<u16>,
+
+    /// Duration of audio and video in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+}
+
+impl FileMetadata {
+    /// Takes the timestamps and, on Unix, the permission bits of the file.
+    /// Timestamps not supported by the platform are left out.
+    pub fn from_fs(metadata: &std::fs::Metadata) -> Self {
+        let format = |time: std::io::Result<std::time::SystemTime>| {
+            time.ok().and_then(crate::util::time::format_iso8601)
+        };
+
+        #[cfg(unix)]
+        let mode = {
+            use std::os::unix::fs::PermissionsExt;
+            Some(metadata.permissions().mode() & 0o7777)
+        };
+        #[cfg(not(unix))]
+        let mode = None;
+
+        Self {
+            modified: format(metadata.modified()),
+            accessed: format(metadata.accessed()),
+            mode,
+            media: None,
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileDto {
+    /// Describes the file at `path` with a new ID, its MIME type inferred from the extension and the content
+    /// (see [`mime::infer`]) and its metadata. The SHA-256 is only computed if `with_hash` is set, which requires the `crypto` feature.
+    pub async fn from_path(path: impl AsRef<Path>, with_hash: bool) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let mut file = tokio::fs::File::open(path).await?;
+        let metadata = file.metadata().await?;
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .ok_or_else(|| anyhow::anyhow!("Path has no file name: {}", path.display()))?;
+        let inferred = mime::infer_from_path(path).await?;
+        if inferred.mismatch {
+            tracing::warn!(
+                "The content of {} does not match its extension, sending it as {}",
+                path.display(),
+                inferred.mime_type
+            );
+        }
+        let file_type = inferred.mime_type.to_string();
+
+        #[cfg(feature = "media")]
+        let media = match crate::model::media::read_media_info(path, &file_type).await {
+            Ok(media) => media,
+            Err(e) => {
+                tracing::warn!("Failed to read the media info of {}: {e}", path.display());
+                None
+            }
+        };
+        #[cfg(not(feature = "media"))]
+        let media = None;
+
+        let sha256 = if with_hash {
+            Some(sha256_hex(&mut file).await?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            file_name,
+            size: metadata.len(),
+            file_type,
+            sha256,
+            preview: None,
+            metadata: Some(FileMetadata {
+                media,
+                ..FileMetadata::from_fs(&metadata)
+            }),
+        })
+    }
+}
+
+#[cfg(all(feature = "crypto", not(target_arch = "wasm32")))]
+async fn sha256_hex(file: &mut tokio::fs::File) -> anyhow::Result<String> {
+    use tokio::io::AsyncReadExt;
+
+    let mut hasher = crate::crypto::hash::StreamingSha256::default();
+    let mut buffer = vec![0; HASH_CHUNK_SIZE];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hasher.finalize_hex())
+}
+
+#[cfg(all(not(feature = "crypto"), not(target_arch = "wasm32")))]
+async fn sha256_hex(_file: &mut tokio::fs::File) -> anyhow::Result<String> {
+    anyhow::bail!("Hashing requires the crypto feature")
+}
+
+/// Turns the file name of a [`FileDto`] received from a peer into a relative path
+/// that stays inside the directory it is joined to.
+///
+/// Both `/` and `\` separate directories, so senders can recreate directory trees.
+/// Leading separators, drive letters, `.` and `..` are dropped.
+/// So that the path is valid on every platform, control characters and characters
+/// not allowed on Windows are replaced with `_`, trailing dots and spaces are removed
+/// and reserved names such as `CON` or `nul.txt` get a `_` prefix.
+pub fn sanitize_file_name(file_name: &str) -> Result<PathBuf, FileNameError> {
+    let mut path = PathBuf::new();
+    for (index, component) in file_name.split(['/', '\\']).enumerate() {
+        if index == 0 && is_drive(component) {
+            continue;
+        }
+
+        let component: String = component
+            .chars()
+            .map(|c| {
+                if c.is_control() || INVALID_CHARS.contains(&c) {
+                    '_'
+                } else {
+                    c
+                }
+            })
+            .collect();
+        let component = component.trim_end_matches(['.', ' ']);
+        if component.is_empty() {
+            continue;
+        }
+        if component.len() > MAX_NAME_LENGTH {
+            return Err(FileNameError::TooLong(file_name.to_string()));
+        }
+
+        let stem = component.split('.').next().unwrap_or_default().trim_end();
+        if RESERVED_NAMES
+            .iter()
+            .any(|reserved| stem.eq_ignore_ascii_case(reserved))
+        {
+            path.push(format!("_{component}"));
+        } else {
+            path.push(component);
+        }
+    }
+
+    if path.as_os_str().is_empty() {
+        return Err(FileNameError::Empty(file_name.to_string()));
+    }
+    Ok(path)
+}
+
+/// Whether the component is a Windows drive such as `C:`.
+fn is_drive(component: &str) -> bool {
+    let bytes = component.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_metadata_wire_format() {
+        let json =
+            r#"{"modified":"2024-02-29T13:05:09.123Z","accessed":"2024-03-01T08:00:00.000Z"}"#;
+        let metadata: FileMetadata = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            metadata,
+            FileMetadata {
+                modified: Some("2024-02-29T13:05:09.123Z".to_string()),
+                accessed: Some("2024-03-01T08:00:00.000Z".to_string()),
+                mode: None,
+                media: None,
+            }
+        );
+        assert_eq!(serde_json::to_string(&metadata).unwrap(), json);
+
+        let metadata = FileMetadata {
+            mode: Some(0o644),
+            media: Some(MediaInfo {
+                width: Some(1920),
+                height: Some(1080),
+                orientation: Some(6),
+                duration_ms: None,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&metadata).unwrap(),
+            r#"{"mode":420,"media":{"width":1920,"height":1080,"orientation":6}}"#
+        );
+        assert_eq!(FileMetadata::default(), serde_json::from_str("{}").unwrap());
+    }
+
+    #[test]
+    fn test_sanitize_file_name() {
+        let sanitize =
+            |name| sanitize_file_name(name).map(|path| path.to_string_lossy().replace('\\', "/"));
+        assert_eq!(sanitize("photo.jpg").unwrap(), "photo.jpg");
+        assert_eq!(
+            sanitize("Photos/2024/photo.jpg").unwrap(),
+            "Photos/2024/photo.jpg"
+        );
+        assert_eq!(sanitize("Photos\\photo.jpg").unwrap(), "Photos/photo.jpg");
+        assert_eq!(sanitize("../../etc/passwd").unwrap(), "etc/passwd");
+        assert_eq!(sanitize("/etc/./passwd").unwrap(), "etc/passwd");
+        assert_eq!(sanitize("C:\\Windows\\win.ini").unwrap(), "Windows/win.ini");
+        assert_eq!(sanitize("a<b>:c?.txt").unwrap(), "a_b__c_.txt");
+        assert_eq!(
+            sanitize("line\nbreak\u{7f}.txt").unwrap(),
+            "line_break_.txt"
+        );
+        assert_eq!(sanitize("notes.txt. . ").unwrap(), "notes.txt");
+        assert_eq!(sanitize("CON").unwrap(), "_CON");
+        assert_eq!(sanitize("dir/nul.txt").unwrap(), "dir/_nul.txt");
+        assert_eq!(sanitize("console.txt").unwrap(), "console.txt");
+        assert_eq!(sanitize(".hidden").unwrap(), ".hidden");
+
+        assert_eq!(
+            sanitize_file_name("../.."),
+            Err(FileNameError::Empty("../..".to_string()))
+        );
+        assert!(sanitize_file_name("").is_err());
+        assert!(sanitize_file_name(&"a".repeat(256)).is_err());
+    }
+
+    #[cfg(feature = "crypto")]
+    #[tokio::test]
+    async fn test_from_path() {
+        let dir = std::env::temp_dir().join(format!("localsend-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("hello.txt");
+        std::fs::write(&path, b"hello").unwrap();
+
+        let file = FileDto::from_path(&path, true).await.unwrap();
+        assert_eq!(file.file_name, "hello.txt");
+        assert_eq!(file.size, 5);
+        assert_eq!(file.file_type, "text/plain");
+        assert_eq!(
+            file.sha256.as_deref(),
+            Some("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824")
+        );
+        assert!(file.metadata.unwrap().modified.is_some());
+
+        let other = FileDto::from_path(&path, false).await.unwrap();
+        assert_ne!(other.id, file.id);
+        assert_eq!(other.sha256, None);
+        assert!(FileDto::from_path(dir.join("missing.txt"), false)
+            .await
+            .is_err());
+
+        #[cfg(feature = "media")]
+        {
+            let path = dir.join("image.gif");
+            std::fs::write(&path, b"GIF89a\x80\x02\xe0\x01").unwrap();
+            let file = FileDto::from_path(&path, false).await.unwrap();
+            let media = file.metadata.unwrap().media.unwrap();
+            assert_eq!((media.width, media.height), (Some(640), Some(480)));
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
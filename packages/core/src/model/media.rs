@@ -0,0 +1,324 @@
+use crate::model::file::MediaInfo;
+use crate::model::preview::{exif_tiff, find_box, find_boxes, jpeg_segments, read_moov, Tiff};
+use std::path::Path;
+use tokio::io::AsyncReadExt;
+
+/// JPEG files store their dimensions after the EXIF segment, which is limited to 64 KiB.
+const JPEG_HEADER_BYTES: u64 = 128 * 1024;
+
+/// PNG, GIF, BMP and WebP files store their dimensions within the first bytes.
+const IMAGE_HEADER_BYTES: u64 = 32;
+
+/// EXIF tag of the orientation in IFD0.
+const ORIENTATION_TAG: u16 = 0x0112;
+
+/// Reads the dimensions and orientation of images and the duration of videos
+/// from the headers of the file at `path`, without decoding it.
+///
+/// Returns `None` if the type is not supported or the headers cannot be parsed.
+pub async fn read_media_info(path: &Path, file_type: &str) -> std::io::Result<Option<MediaInfo>> {
+    let mut file = tokio::fs::File::open(path).await?;
+
+    let header_bytes = match file_type {
+        "image/jpeg" => JPEG_HEADER_BYTES,
+        "image/png" | "image/gif" | "image/bmp" | "image/webp" => IMAGE_HEADER_BYTES,
+        "video/mp4" | "video/quicktime" | "video/x-m4v" | "audio/mp4" => {
+            let size = file.metadata().await?.len();
+            let Some(moov) = read_moov(&mut file, size).await? else {
+                return Ok(None);
+            };
+            return Ok(mp4_info(&moov));
+        }
+        _ => return Ok(None),
+    };
+
+    let mut header = Vec::new();
+    (&mut file)
+        .take(header_bytes)
+        .read_to_end(&mut header)
+        .await?;
+    Ok(match file_type {
+        "image/jpeg" => jpeg_info(&header),
+        _ => image_size(&header).map(|(width, height)| MediaInfo {
+            width: Some(width),
+            height: Some(height),
+            ..Default::default()
+        }),
+    })
+}
+
+/// Returns the dimensions of the start of frame and the EXIF orientation.
+fn jpeg_info(jpeg: &[u8]) -> Option<MediaInfo> {
+    let mut orientation = None;
+    for (marker, segment) in jpeg_segments(jpeg) {
+        if let Some(tiff) = exif_tiff(marker, segment) {
+            orientation = tiff_orientation(tiff);
+            continue;
+        }
+
+        // Start of frame. The other markers in the range define tables.
+        if (0xC0..=0xCF).contains(&marker) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+            return Some(MediaInfo {
+                width: Some(u16::from_be_bytes(segment.get(3..5)?.try_into().ok()?).into()),
+                height: Some(u16::from_be_bytes(segment.get(1..3)?.try_into().ok()?).into()),
+                orientation,
+                duration_ms: None,
+            });
+        }
+    }
+
+    None
+}
+
+/// Returns the orientation stored in IFD0 of the EXIF segment.
+fn tiff_orientation(data: &[u8]) -> Option<u16> {
+    let tiff = Tiff::new(data)?;
+    for entry in tiff.entries(tiff.ifd0()?)? {
+        if tiff.u16_at(entry)? == ORIENTATION_TAG {
+            // A single SHORT stored at the start of the value.
+            return tiff
+                .u16_at(entry + 8)
+                .filter(|orientation| (1..=8).contains(orientation));
+        }
+    }
+
+    None
+}
+
+/// Returns the width and height of a PNG, GIF, BMP or WebP image.
+fn image_size(header: &[u8]) -> Option<(u32, u32)> {
+    let le_u16 = |pos: usize| {
+        Some(u16::from_le_bytes(
+            header.get(pos..pos + 2)?.try_into().ok()?,
+        ))
+    };
+    let le_u24 = |pos: usize| {
+        let bytes = header.get(pos..pos + 3)?;
+        Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]))
+    };
+    let le_u32 = |pos: usize| {
+        Some(u32::from_le_bytes(
+            header.get(pos..pos + 4)?.try_into().ok()?,
+        ))
+    };
+    let be_u32 = |pos: usize| {
+        Some(u32::from_be_bytes(
+            header.get(pos..pos + 4)?.try_into().ok()?,
+        ))
+    };
+
+    if header.starts_with(b"\x89PNG\r\n\x1a\n") && header.get(12..16)? == b"IHDR" {
+        return Some((be_u32(16)?, be_u32(20)?));
+    }
+    if header.starts_with(b"GIF8") {
+        return Some((le_u16(6)?.into(), le_u16(8)?.into()));
+    }
+    if header.starts_with(b"BM") {
+        // The height is negative for images stored top-down.
+        let width = le_u32(18)? as i32;
+        let height = le_u32(22)? as i32;
+        return Some((width.unsigned_abs(), height.unsigned_abs()));
+    }
+    if header.starts_with(b"RIFF") && header.get(8..12)? == b"WEBP" {
+        return match header.get(12..16)? {
+            // Lossy, the dimensions follow the frame tag and start code.
+            b"VP8 " => Some(((le_u16(26)? & 0x3FFF).into(), (le_u16(28)? & 0x3FFF).into())),
+            // Lossless, the dimensions minus one are packed into 14 bits each.
+            b"VP8L" => {
+                let bits = le_u32(21)?;
+                Some(((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1))
+            }
+            // Extended, the dimensions minus one follow the flags.
+            b"VP8X" => Some((le_u24(24)? + 1, le_u24(27)? + 1)),
+            _ => None,
+        };
+    }
+
+    None
+}
+
+/// Returns the duration of `moov/mvhd` and the dimensions and rotation of the first
+/// visual track as EXIF orientation.
+fn mp4_info(moov: &[u8]) -> Option<MediaInfo> {
+    let be_u32 = |data: &[u8], pos: usize| {
+        Some(u32::from_be_bytes(data.get(pos..pos + 4)?.try_into().ok()?))
+    };
+    let be_u64 = |data: &[u8], pos: usize| {
+        Some(u64::from_be_bytes(data.get(pos..pos + 8)?.try_into().ok()?))
+    };
+
+    let mvhd = find_box(moov, b"mvhd")?;
+    // All bits set means the duration is unknown, e.g. for fragmented files.
+    let (timescale, duration) = match mvhd.first()? {
+        0 => (
+            be_u32(mvhd, 12)?,
+            be_u32(mvhd, 16).filter(|d| *d != u32::MAX).map(u64::from),
+        ),
+        1 => (
+            be_u32(mvhd, 20)?,
+            be_u64(mvhd, 24).filter(|d| *d != u64::MAX),
+        ),
+        _ => return None,
+    };
+    let mut info = MediaInfo {
+        duration_ms: duration
+            .filter(|_| timescale != 0)
+            .map(|duration| (u128::from(duration) * 1000 / u128::from(timescale)) as u64),
+        ..Default::default()
+    };
+
+    // The track header ends with the matrix and the dimensions as 16.16 fixed-point numbers.
+    let visual = find_boxes(moov, b"trak")
+        .filter_map(|trak| find_box(trak, b"tkhd"))
+        .filter(|tkhd| tkhd.len() >= 44)
+        .find(|tkhd| be_u32(tkhd, tkhd.len() - 8).is_some_and(|width| width >> 16 != 0));
+    if let Some(tkhd) = visual {
+        let end = tkhd.len();
+        info.width = Some(be_u32(tkhd, end - 8)? >> 16);
+        info.height = Some(be_u32(tkhd, end - 4)? >> 16);
+
+        let matrix = |i: usize| be_u32(tkhd, end - 44 + i * 4).map(|value| value as i32);
+        info.orientation = match (matrix(0)?, matrix(1)?, matrix(3)?, matrix(4)?) {
+            (0x10000, 0, 0, 0x10000) => Some(1),
+            (0, 0x10000, -0x10000, 0) => Some(6),
+            (-0x10000, 0, 0, -0x10000) => Some(3),
+            (0, -0x10000, 0x10000, 0) => Some(8),
+            _ => None,
+        };
+    }
+
+    Some(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mp4_box(kind: &[u8; 4], content: &[u8]) -> Vec<u8> {
+        let mut data = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(kind);
+        data.extend_from_slice(content);
+        data
+    }
+
+    fn tkhd(matrix: [i32; 4], width: u32, height: u32) -> Vec<u8> {
+        let mut tkhd = vec![0; 40];
+        for value in [
+            matrix[0],
+            matrix[1],
+            0,
+            matrix[2],
+            matrix[3],
+            0,
+            0,
+            0,
+            0x4000_0000,
+        ] {
+            tkhd.extend_from_slice(&value.to_be_bytes());
+        }
+        tkhd.extend_from_slice(&(width << 16).to_be_bytes());
+        tkhd.extend_from_slice(&(height << 16).to_be_bytes());
+        mp4_box(b"tkhd", &tkhd)
+    }
+
+    #[test]
+    fn test_jpeg_info() {
+        // Big endian TIFF with an IFD0 holding only the orientation.
+        let mut tiff = b"MM".to_vec();
+        tiff.extend_from_slice(&42u16.to_be_bytes());
+        tiff.extend_from_slice(&8u32.to_be_bytes());
+        tiff.extend_from_slice(&1u16.to_be_bytes());
+        tiff.extend_from_slice(&ORIENTATION_TAG.to_be_bytes());
+        tiff.extend_from_slice(&3u16.to_be_bytes());
+        tiff.extend_from_slice(&1u32.to_be_bytes());
+        tiff.extend_from_slice(&[0, 6, 0, 0]);
+        tiff.extend_from_slice(&0u32.to_be_bytes());
+
+        let mut segment = b"Exif\0\0".to_vec();
+        segment.extend_from_slice(&tiff);
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        jpeg.extend_from_slice(&((segment.len() + 2) as u16).to_be_bytes());
+        jpeg.extend_from_slice(&segment);
+        // Huffman table, then the baseline start of frame with a height of 3000 and a width of 4000.
+        jpeg.extend_from_slice(&[0xFF, 0xC4, 0x00, 0x03, 0x00]);
+        jpeg.extend_from_slice(&[0xFF, 0xC0, 0x00, 0x08, 0x08, 0x0B, 0xB8, 0x0F, 0xA0, 0x03]);
+
+        assert_eq!(
+            jpeg_info(&jpeg),
+            Some(MediaInfo {
+                width: Some(4000),
+                height: Some(3000),
+                orientation: Some(6),
+                duration_ms: None,
+            })
+        );
+        assert_eq!(jpeg_info(&[0xFF, 0xD8, 0xFF, 0xDA, 0x00, 0x02]), None);
+    }
+
+    #[test]
+    fn test_image_size() {
+        let mut png = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        png.extend_from_slice(&640u32.to_be_bytes());
+        png.extend_from_slice(&480u32.to_be_bytes());
+        assert_eq!(image_size(&png), Some((640, 480)));
+
+        assert_eq!(image_size(b"GIF89a\x80\x02\xe0\x01"), Some((640, 480)));
+
+        let mut bmp = b"BM".to_vec();
+        bmp.extend_from_slice(&[0; 16]);
+        bmp.extend_from_slice(&640i32.to_le_bytes());
+        bmp.extend_from_slice(&(-480i32).to_le_bytes());
+        assert_eq!(image_size(&bmp), Some((640, 480)));
+
+        let webp = |chunk: &[u8; 4], data: &[u8]| {
+            let mut webp = b"RIFF\0\0\0\0WEBP".to_vec();
+            webp.extend_from_slice(chunk);
+            webp.extend_from_slice(&[0; 4]);
+            webp.extend_from_slice(data);
+            webp
+        };
+        assert_eq!(
+            image_size(&webp(
+                b"VP8 ",
+                &[0, 0, 0, 0x9D, 0x01, 0x2A, 0x80, 0x02, 0xE0, 0x01]
+            )),
+            Some((640, 480))
+        );
+        let bits: u32 = 639 | (479 << 14);
+        let mut lossless = vec![0x2F];
+        lossless.extend_from_slice(&bits.to_le_bytes());
+        assert_eq!(image_size(&webp(b"VP8L", &lossless)), Some((640, 480)));
+        assert_eq!(
+            image_size(&webp(
+                b"VP8X",
+                &[0, 0, 0, 0, 0x7F, 0x02, 0x00, 0xDF, 0x01, 0x00]
+            )),
+            Some((640, 480))
+        );
+
+        assert_eq!(image_size(b"RIFF\0\0\0\0AVI "), None);
+    }
+
+    #[test]
+    fn test_mp4_info() {
+        let mut mvhd = vec![0; 12];
+        mvhd.extend_from_slice(&600u32.to_be_bytes());
+        mvhd.extend_from_slice(&9000u32.to_be_bytes());
+        mvhd.extend_from_slice(&[0; 80]);
+
+        let audio = mp4_box(b"trak", &tkhd([0x10000, 0, 0, 0x10000], 0, 0));
+        let video = mp4_box(b"trak", &tkhd([0, 0x10000, -0x10000, 0], 1920, 1080));
+        let moov = [mp4_box(b"mvhd", &mvhd), audio, video].concat();
+
+        assert_eq!(
+            mp4_info(&moov),
+            Some(MediaInfo {
+                width: Some(1920),
+                height: Some(1080),
+                orientation: Some(6),
+                duration_ms: Some(15000),
+            })
+        );
+        assert_eq!(mp4_info(&mp4_box(b"udta", &[])), None);
+    }
+}
@@ -1,2 +1,13 @@
+#[cfg(not(target_arch = "wasm32"))]
+pub mod collision;
+pub mod destination;
 pub mod discovery;
+pub mod file;
+#[cfg(all(feature = "media", not(target_arch = "wasm32")))]
+pub mod media;
+#[cfg(feature = "crypto")]
+pub mod part;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod preview;
+pub mod scan;
 pub mod transfer;
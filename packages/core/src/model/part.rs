@@ -0,0 +1,246 @@
+use crate::crypto::hash::StreamingSha256;
+use crate::util::io::{FileWriter, DEFAULT_BUFFER_SIZE};
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncReadExt;
+
+/// Extension appended to the name of files being received.
+pub const PART_EXTENSION: &str = "part";
+
+/// Returns `<path>.part`, e.g. `photo.jpg.part`.
+pub fn part_path(path: &Path) -> PathBuf {
+    let mut part_path = path.as_os_str().to_owned();
+    part_path.push(".");
+    part_path.push(PART_EXTENSION);
+    PathBuf::from(part_path)
+}
+
+/// A file being received.
+///
+/// The content is written to `<path>.part` and only moved to `path` by [`PartFile::finalize`]
+/// once it is complete, so aborted transfers never leave a file that looks complete.
+/// Call [`PartFile::cleanup`] if the transfer fails.
+pub struct PartFile {
+    path: PathBuf,
+    part_path: PathBuf,
+    file: FileWriter,
+    hasher: Option<StreamingSha256>,
+    size: u64,
+}
+
+/// A file moved to its final path by [`PartFile::finalize`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FinalizedFile {
+    pub path: PathBuf,
+    pub size: u64,
+
+    /// Lowercase hex SHA-256 of the content if it was hashed.
+    pub sha256: Option<String>,
+}
+
+impl PartFile {
+    /// Creates or truncates `<path>.part`.
+    /// The content is hashed if `with_hash` is set, which is required to verify it.
+    pub async fn create(path: impl Into<PathBuf>, with_hash: bool) -> std::io::Result<Self> {
+        Self::create_with_buffer(path, with_hash, DEFAULT_BUFFER_SIZE).await
+    }
+
+    /// Like [`PartFile::create`], writing through a buffer of `buffer_size` bytes.
+    pub async fn create_with_buffer(
+        path: impl Into<PathBuf>,
+        with_hash: bool,
+        buffer_size: usize,
+    ) -> std::io::Result<Self> {
+        let path = path.into();
+        let part_path = part_path(&path);
+        let file = FileWriter::create(&part_path, buffer_size).await?;
+
+        Ok(Self {
+            path,
+            part_path,
+            file,
+            hasher: with_hash.then(StreamingSha256::default),
+            size: 0,
+        })
+    }
+
+    /// Reserves disk space for `size` bytes, so receiving fails before it starts
+    /// if the disk is too small. The part file stays empty until it is written.
+    pub async fn preallocate(&self, size: u64) -> std::io::Result<()> {
+        let part_path = self.part_path.clone();
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::OpenOptions::new().write(true).open(part_path)?;
+            crate::util::io::preallocate(&file, size)
+        })
+        .await?
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of bytes written so far.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub async fn write(&mut self, data: &[u8]) -> std::io::Result<()> {
+        self.file.write_all(data).await?;
+        if let Some(hasher) = &mut self.hasher {
+            hasher.update(data);
+        }
+        self.size += data.len() as u64;
+        Ok(())
+    }
+
+    /// Verifies the content and renames the part file to the final path, replacing any file there.
+    /// The part file is removed if the content does not match.
+    pub async fn finalize(
+        self,
+        expected_size: Option<u64>,
+        expected_sha256: Option<&str>,
+    ) -> anyhow::Result<FinalizedFile> {
+        let part_path = self.part_path.clone();
+        let result = self.finalize_inner(expected_size, expected_sha256).await;
+        if result.is_err() {
+            let _ = tokio::fs::remove_file(&part_path).await;
+        }
+        result
+    }
+
+    async fn finalize_inner(
+        mut self,
+        expected_size: Option<u64>,
+        expected_sha256: Option<&str>,
+    ) -> anyhow::Result<FinalizedFile> {
+        if let Some(expected) = expected_size.filter(|expected| *expected != self.size) {
+            anyhow::bail!("Expected {expected} bytes, received {}", self.size);
+        }
+
+        let sha256 = self.hasher.take().map(StreamingSha256::finalize_hex);
+        if let Some(expected) = expected_sha256 {
+            match &sha256 {
+                Some(actual) if actual.eq_ignore_ascii_case(expected) => {}
+                Some(actual) => anyhow::bail!("Expected SHA-256 {expected}, received {actual}"),
+                None => anyhow::bail!("The content was not hashed"),
+            }
+        }
+
+        self.file.sync_all().await?;
+        tokio::fs::rename(&self.part_path, &self.path).await?;
+
+        Ok(FinalizedFile {
+            path: self.path,
+            size: self.size,
+            sha256,
+        })
+    }
+
+    /// Removes the part file, e.g. after the transfer failed.
+    pub async fn cleanup(self) {
+        drop(self.file);
+        if let Err(e) = tokio::fs::remove_file(&self.part_path).await {
+            tracing::warn!("Failed to remove {}: {e}", self.part_path.display());
+        }
+    }
+}
+
+/// Removes the part files in `dir` left by transfers that were interrupted,
+/// e.g. because the app was killed. Subdirectories are not searched.
+///
+/// Must not be called while files are received to `dir`.
+/// Returns the paths of the removed files.
+pub async fn remove_part_files(dir: impl AsRef<Path>) -> anyhow::Result<Vec<PathBuf>> {
+    let mut entries = tokio::fs::read_dir(dir).await?;
+    let mut removed = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let path = entry.path();
+        if path
+            .extension()
+            .is_none_or(|extension| extension != PART_EXTENSION)
+            || !entry.file_type().await?.is_file()
+        {
+            continue;
+        }
+
+        tokio::fs::remove_file(&path).await?;
+        removed.push(path);
+    }
+
+    removed.sort();
+    Ok(removed)
+}
+
+/// Returns the lowercase hex SHA-256 of the first `length` bytes of the part file of `path`,
+/// e.g. to check that the data received so far is unchanged before resuming.
+pub async fn partial_sha256(path: &Path, length: u64) -> anyhow::Result<String> {
+    let file = tokio::fs::File::open(part_path(path)).await?;
+    let mut reader = file.take(length);
+    let mut hasher = StreamingSha256::default();
+    let mut buffer = vec![0; DEFAULT_BUFFER_SIZE];
+    let mut read = 0;
+    loop {
+        let n = reader.read(&mut buffer).await?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+        read += n as u64;
+    }
+
+    if read != length {
+        anyhow::bail!("Expected {length} bytes, found {read}");
+    }
+    Ok(hasher.finalize_hex())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_part_file() {
+        let dir = std::env::temp_dir().join(format!("localsend-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("hello.txt");
+        let sha256 = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+        let mut file = PartFile::create(&path, true).await.unwrap();
+        file.write(b"hello").await.unwrap();
+        assert!(dir.join("hello.txt.part").exists());
+        assert!(!path.exists());
+
+        let finalized = file.finalize(Some(5), Some(sha256)).await.unwrap();
+        assert_eq!(finalized.sha256.as_deref(), Some(sha256));
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello");
+        assert!(!dir.join("hello.txt.part").exists());
+
+        // A mismatching hash leaves the existing file untouched.
+        let mut file = PartFile::create(&path, true).await.unwrap();
+        file.write(b"world").await.unwrap();
+        assert!(file.finalize(None, Some(sha256)).await.is_err());
+        assert_eq!(std::fs::read(&path).unwrap(), b"hello");
+        assert!(!dir.join("hello.txt.part").exists());
+
+        // Preallocating does not count as received content.
+        let mut file = PartFile::create(dir.join("a.txt"), false).await.unwrap();
+        file.preallocate(1 << 20).await.unwrap();
+        assert_eq!(std::fs::metadata(dir.join("a.txt.part")).unwrap().len(), 0);
+        file.write(b"a").await.unwrap();
+        assert!(file.finalize(Some(2), None).await.is_err());
+
+        let file = PartFile::create(dir.join("b.txt"), false).await.unwrap();
+        file.cleanup().await;
+
+        let mut file = PartFile::create(dir.join("c.txt"), false).await.unwrap();
+        file.write(b"hello world").await.unwrap();
+        file.file.flush().await.unwrap();
+        assert_eq!(partial_sha256(&dir.join("c.txt"), 5).await.unwrap(), sha256);
+        assert!(partial_sha256(&dir.join("c.txt"), 20).await.is_err());
+        drop(file);
+        let removed = remove_part_files(&dir).await.unwrap();
+        assert_eq!(removed, [dir.join("c.txt.part")]);
+        assert!(path.exists());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
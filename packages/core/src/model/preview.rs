@@ -0,0 +1,377 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use std::io::SeekFrom;
+use std::path::Path;
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+/// Previews larger than this are not used, since they are sent as part of the file list.
+pub const MAX_PREVIEW_BYTES: usize = 64 * 1024;
+
+/// The EXIF segment is limited to 64 KiB and follows the start of the image.
+const EXIF_SEARCH_BYTES: u64 = 128 * 1024;
+
+/// The `moov` box holding the cover art is skipped beyond this size.
+const MAX_MOOV_BYTES: u64 = 32 * 1024 * 1024;
+
+/// A small image embedded in a file, e.g. an EXIF thumbnail or the cover art of a video.
+///
+/// Images are never decoded or downscaled here, so files without such an image have no preview
+/// unless they are small enough to be used as is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preview {
+    pub mime_type: &'static str,
+    pub data: Vec<u8>,
+}
+
+impl Preview {
+    /// Returns the `data:` URI used for [`crate::model::transfer::FileDto::preview`].
+    pub fn to_data_uri(&self) -> String {
+        format!(
+            "data:{};base64,{}",
+            self.mime_type,
+            STANDARD.encode(&self.data)
+        )
+    }
+}
+
+/// Extracts the preview embedded in the image or video file at `path`.
+///
+/// JPEG files use their embedded EXIF thumbnail and MP4/QuickTime videos their cover art.
+/// JPEG and WebP files up to [`MAX_PREVIEW_BYTES`] are used as is.
+/// Returns `None` if the file has no such preview, the image itself is never decoded.
+pub async fn extract_embedded_preview(
+    path: &Path,
+    file_type: &str,
+) -> std::io::Result<Option<Preview>> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let size = file.metadata().await?.len();
+
+    match file_type {
+        "image/jpeg" => {
+            let mut data = Vec::new();
+            (&mut file)
+                .take(EXIF_SEARCH_BYTES)
+                .read_to_end(&mut data)
+                .await?;
+            if let Some(thumbnail) = exif_thumbnail(&data) {
+                return Ok(Some(Preview {
+                    mime_type: "image/jpeg",
+                    data: thumbnail.to_vec(),
+                }));
+            }
+
+            // Already read completely.
+            if size <= MAX_PREVIEW_BYTES as u64 {
+                return Ok(Some(Preview {
+                    mime_type: "image/jpeg",
+                    data,
+                }));
+            }
+            Ok(None)
+        }
+        "image/webp" if size <= MAX_PREVIEW_BYTES as u64 => {
+            let mut data = Vec::new();
+            file.read_to_end(&mut data).await?;
+            Ok(Some(Preview {
+                mime_type: "image/webp",
+                data,
+            }))
+        }
+        "video/mp4" | "video/quicktime" | "video/x-m4v" => {
+            let Some(moov) = read_moov(&mut file, size).await? else {
+                return Ok(None);
+            };
+            Ok(mp4_cover(&moov))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Returns the JPEG thumbnail stored in the second IFD of the EXIF segment.
+fn exif_thumbnail(jpeg: &[u8]) -> Option<&[u8]> {
+    let tiff = jpeg_segments(jpeg).find_map(|(marker, segment)| exif_tiff(marker, segment))?;
+    tiff_thumbnail(tiff)
+}
+
+/// Iterates over the markers and contents of the segments of a JPEG file
+/// up to the start of scan, which is followed by the image data.
+///
+/// Ends early if the file is truncated or malformed.
+pub(crate) fn jpeg_segments(jpeg: &[u8]) -> impl Iterator<Item = (u8, &[u8])> {
+    let mut pos = if jpeg.starts_with(&[0xFF, 0xD8]) {
+        2
+    } else {
+        jpeg.len()
+    };
+    std::iter::from_fn(move || {
+        if pos + 4 > jpeg.len() || jpeg[pos] != 0xFF {
+            return None;
+        }
+
+        let marker = jpeg[pos + 1];
+        let length = u16::from_be_bytes([jpeg[pos + 2], jpeg[pos + 3]]) as usize;
+        if marker == 0xDA || length < 2 {
+            pos = jpeg.len();
+            return None;
+        }
+
+        let Some(segment) = jpeg.get(pos + 4..pos + 2 + length) else {
+            pos = jpeg.len();
+            return None;
+        };
+        pos += 2 + length;
+        Some((marker, segment))
+    })
+}
+
+/// Returns the TIFF structure of the segment if it is the EXIF segment.
+pub(crate) fn exif_tiff(marker: u8, segment: &[u8]) -> Option<&[u8]> {
+    segment.strip_prefix(b"Exif\0\0").filter(|_| marker == 0xE1)
+}
+
+/// Reads the values of a TIFF structure in its byte order.
+pub(crate) struct Tiff<'a> {
+    data: &'a [u8],
+    big_endian: bool,
+}
+
+impl<'a> Tiff<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Option<Self> {
+        let big_endian = match data.get(..2)? {
+            b"II" => false,
+            b"MM" => true,
+            _ => return None,
+        };
+        Some(Self { data, big_endian })
+    }
+
+    pub(crate) fn u16_at(&self, pos: usize) -> Option<u16> {
+        let bytes = self.data.get(pos..pos.checked_add(2)?)?.try_into().ok()?;
+        Some(if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    }
+
+    pub(crate) fn u32_at(&self, pos: usize) -> Option<u32> {
+        let bytes = self.data.get(pos..pos.checked_add(4)?)?.try_into().ok()?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+
+    /// Returns the offset of IFD0.
+    pub(crate) fn ifd0(&self) -> Option<usize> {
+        Some(self.u32_at(4)? as usize)
+    }
+
+    /// Returns the offsets of the 12 byte entries of the IFD at `ifd`.
+    pub(crate) fn entries(&self, ifd: usize) -> Option<impl Iterator<Item = usize>> {
+        let count = self.u16_at(ifd)? as usize;
+        Some((0..count).map(move |i| ifd + 2 + i * 12))
+    }
+}
+
+fn tiff_thumbnail(data: &[u8]) -> Option<&[u8]> {
+    let tiff = Tiff::new(data)?;
+    let ifd0 = tiff.ifd0()?;
+    let ifd0_entries = tiff.u16_at(ifd0)? as usize;
+    let ifd1 = tiff.u32_at(ifd0 + 2 + ifd0_entries * 12)? as usize;
+    if ifd1 == 0 {
+        return None;
+    }
+
+    let mut offset = None;
+    let mut length = None;
+    for entry in tiff.entries(ifd1)? {
+        match tiff.u16_at(entry)? {
+            0x0201 => offset = Some(tiff.u32_at(entry + 8)? as usize),
+            0x0202 => length = Some(tiff.u32_at(entry + 8)? as usize),
+            _ => {}
+        }
+    }
+
+    let (offset, length) = (offset?, length?);
+    if length > MAX_PREVIEW_BYTES {
+        return None;
+    }
+    let thumbnail = data.get(offset..offset.checked_add(length)?)?;
+    thumbnail.starts_with(&[0xFF, 0xD8]).then_some(thumbnail)
+}
+
+/// Reads the content of the top-level `moov` box.
+pub(crate) async fn read_moov(
+    file: &mut tokio::fs::File,
+    size: u64,
+) -> std::io::Result<Option<Vec<u8>>> {
+    let mut pos = 0;
+    while pos + 8 <= size {
+        file.seek(SeekFrom::Start(pos)).await?;
+        let mut header = [0; 8];
+        file.read_exact(&mut header).await?;
+
+        let (box_size, header_size) = match u32::from_be_bytes(header[..4].try_into().unwrap()) {
+            0 => (size - pos, 8),
+            1 => (file.read_u64().await?, 16),
+            box_size => (u64::from(box_size), 8),
+        };
+        if box_size < header_size {
+            return Ok(None);
+        }
+
+        if &header[4..] == b"moov" {
+            if box_size > MAX_MOOV_BYTES {
+                return Ok(None);
+            }
+            let mut moov = vec![0; (box_size - header_size) as usize];
+            file.read_exact(&mut moov).await?;
+            return Ok(Some(moov));
+        }
+        pos += box_size;
+    }
+
+    Ok(None)
+}
+
+/// Returns the cover art at `moov/udta/meta/ilst/covr/data`.
+fn mp4_cover(moov: &[u8]) -> Option<Preview> {
+    let udta = find_box(moov, b"udta")?;
+    let meta = find_box(udta, b"meta")?;
+    // The MP4 `meta` box starts with a version and flags, the QuickTime one does not.
+    let meta = if meta.get(4..8)? == b"hdlr" {
+        meta
+    } else {
+        meta.get(4..)?
+    };
+    let ilst = find_box(meta, b"ilst")?;
+    let covr = find_box(ilst, b"covr")?;
+    let data = find_box(covr, b"data")?;
+
+    // Well-known type of the data, followed by the locale.
+    let mime_type = match u32::from_be_bytes(data.get(..4)?.try_into().ok()?) {
+        13 => "image/jpeg",
+        14 => "image/png",
+        _ => return None,
+    };
+    let image = data.get(8..)?;
+    if image.is_empty() || image.len() > MAX_PREVIEW_BYTES {
+        return None;
+    }
+
+    Some(Preview {
+        mime_type,
+        data: image.to_vec(),
+    })
+}
+
+/// Returns the content of the first box of the kind.
+pub(crate) fn find_box<'a>(data: &'a [u8], kind: &[u8; 4]) -> Option<&'a [u8]> {
+    find_boxes(data, kind).next()
+}
+
+/// Returns the contents of all boxes of the kind, stopping at the first malformed box.
+pub(crate) fn find_boxes<'a>(
+    data: &'a [u8],
+    kind: &[u8; 4],
+) -> impl Iterator<Item = &'a [u8]> + 'a {
+    let kind = *kind;
+    let mut pos = 0;
+    std::iter::from_fn(move || {
+        while pos + 8 <= data.len() {
+            let size = u32::from_be_bytes(data[pos..pos + 4].try_into().ok()?) as usize;
+            if size < 8 {
+                return None;
+            }
+
+            let content = data.get(pos + 8..pos + size)?;
+            let found = data[pos + 4..pos + 8] == kind;
+            pos += size;
+            if found {
+                return Some(content);
+            }
+        }
+
+        None
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mp4_box(kind: &[u8; 4], content: &[u8]) -> Vec<u8> {
+        let mut data = ((content.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(kind);
+        data.extend_from_slice(content);
+        data
+    }
+
+    #[test]
+    fn test_exif_thumbnail() {
+        let thumbnail = [0xFF, 0xD8, 0x01, 0x02, 0xFF, 0xD9];
+
+        // Little endian TIFF with an empty IFD0 and an IFD1 pointing to the thumbnail.
+        let mut tiff = b"II".to_vec();
+        tiff.extend_from_slice(&42u16.to_le_bytes());
+        tiff.extend_from_slice(&8u32.to_le_bytes());
+        tiff.extend_from_slice(&0u16.to_le_bytes());
+        tiff.extend_from_slice(&14u32.to_le_bytes());
+        tiff.extend_from_slice(&2u16.to_le_bytes());
+        for (tag, value) in [(0x0201u16, 44u32), (0x0202, thumbnail.len() as u32)] {
+            tiff.extend_from_slice(&tag.to_le_bytes());
+            tiff.extend_from_slice(&4u16.to_le_bytes());
+            tiff.extend_from_slice(&1u32.to_le_bytes());
+            tiff.extend_from_slice(&value.to_le_bytes());
+        }
+        tiff.extend_from_slice(&0u32.to_le_bytes());
+        tiff.extend_from_slice(&thumbnail);
+
+        let mut segment = b"Exif\0\0".to_vec();
+        segment.extend_from_slice(&tiff);
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        jpeg.extend_from_slice(&((segment.len() + 2) as u16).to_be_bytes());
+        jpeg.extend_from_slice(&segment);
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02]);
+
+        assert_eq!(exif_thumbnail(&jpeg), Some(&thumbnail[..]));
+        assert_eq!(exif_thumbnail(&[0xFF, 0xD8, 0xFF, 0xDA, 0x00, 0x02]), None);
+    }
+
+    #[test]
+    fn test_mp4_cover() {
+        let mut data = 13u32.to_be_bytes().to_vec();
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(&[0xFF, 0xD8, 0xFF, 0xD9]);
+
+        let ilst = mp4_box(b"ilst", &mp4_box(b"covr", &mp4_box(b"data", &data)));
+        let mut meta = vec![0; 4];
+        meta.extend_from_slice(&mp4_box(b"hdlr", &[0; 25]));
+        meta.extend_from_slice(&ilst);
+        let moov = [
+            mp4_box(b"mvhd", &[0; 100]),
+            mp4_box(b"udta", &mp4_box(b"meta", &meta)),
+        ]
+        .concat();
+
+        assert_eq!(
+            mp4_cover(&moov),
+            Some(Preview {
+                mime_type: "image/jpeg",
+                data: vec![0xFF, 0xD8, 0xFF, 0xD9],
+            })
+        );
+        assert_eq!(mp4_cover(&mp4_box(b"mvhd", &[0; 100])), None);
+    }
+
+    #[test]
+    fn test_data_uri() {
+        let preview = Preview {
+            mime_type: "image/webp",
+            data: b"RIFF".to_vec(),
+        };
+        assert_eq!(preview.to_data_uri(), "data:image/webp;base64,UklGRg==");
+    }
+}
@@ -0,0 +1,200 @@
+use crate::model::file::FileMetadata;
+use crate::model::transfer::FileDto;
+use crate::util::mime;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// How [`scan_dir`] handles symbolic links.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// Links are left out.
+    #[default]
+    Skip,
+
+    /// Links are resolved. Directories that were already visited are left out, so cycles end.
+    Follow,
+}
+
+/// Filters applied by [`scan_dir`].
+///
+/// Globs are matched against the path relative to the scanned directory, separated by `/`.
+/// `*` also matches `/`, so `*.jpg` matches JPEG files in all subdirectories.
+#[derive(Debug, Clone, Default)]
+pub struct ScanOptions {
+    /// Globs a file must match to be included. All files are included if empty.
+    pub include: Vec<String>,
+
+    /// Globs of files and directories to leave out, e.g. `**/.git`.
+    /// Excluded directories are not entered.
+    pub exclude: Vec<String>,
+
+    pub symlinks: SymlinkPolicy,
+}
+
+/// A file found by [`scan_dir`].
+#[derive(Debug, Clone)]
+pub struct ScannedFile {
+    /// Path the content of the file is read from.
+    pub path: PathBuf,
+
+    /// The file name is the path relative to the parent of the scanned directory,
+    /// separated by `/`, so the receiver recreates the directory.
+    pub file: FileDto,
+}
+
+/// Lists the files in `dir` and its subdirectories for sending the directory, sorted by file name.
+///
+/// Entries below `dir` that cannot be read are logged and left out.
+pub async fn scan_dir(
+    dir: impl Into<PathBuf>,
+    options: ScanOptions,
+) -> anyhow::Result<Vec<ScannedFile>> {
+    let dir = dir.into();
+    tokio::task::spawn_blocking(move || scan_dir_blocking(&dir, &options)).await?
+}
+
+fn scan_dir_blocking(dir: &Path, options: &ScanOptions) -> anyhow::Result<Vec<ScannedFile>> {
+    let include = build_glob_set(&options.include)?;
+    let exclude = build_glob_set(&options.exclude)?;
+
+    let dir = std::fs::canonicalize(dir)?;
+    let dir_name = dir
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .ok_or_else(|| anyhow::anyhow!("Path has no directory name: {}", dir.display()))?;
+
+    let mut visited = HashSet::from([dir.clone()]);
+    let mut pending = vec![(std::fs::read_dir(&dir)?, String::new())];
+    let mut files = Vec::new();
+
+    while let Some((entries, relative_dir)) = pending.pop() {
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(e) => {
+                    tracing::warn!("Failed to read an entry of {dir_name}/{relative_dir}: {e}");
+                    continue;
+                }
+            };
+
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let relative_path = if relative_dir.is_empty() {
+                name.clone()
+            } else {
+                format!("{relative_dir}/{name}")
+            };
+            if exclude.is_match(&relative_path) {
+                continue;
+            }
+
+            let path = entry.path();
+            let metadata = match entry.file_type() {
+                Ok(file_type) if file_type.is_symlink() => match options.symlinks {
+                    SymlinkPolicy::Skip => continue,
+                    SymlinkPolicy::Follow => std::fs::metadata(&path),
+                },
+                Ok(_) => entry.metadata(),
+                Err(e) => Err(e),
+            };
+            let metadata = match metadata {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    tracing::warn!("Failed to read {}: {e}", path.display());
+                    continue;
+                }
+            };
+
+            if metadata.is_dir() {
+                if options.symlinks == SymlinkPolicy::Follow {
+                    match std::fs::canonicalize(&path) {
+                        Ok(target) => {
+                            if !visited.insert(target) {
+                                continue;
+                            }
+                        }
+                        Err(e) => {
+                            tracing::warn!("Failed to resolve {}: {e}", path.display());
+                            continue;
+                        }
+                    }
+                }
+
+                match std::fs::read_dir(&path) {
+                    Ok(entries) => pending.push((entries, relative_path)),
+                    Err(e) => tracing::warn!("Failed to read {}: {e}", path.display()),
+                }
+            } else if metadata.is_file() {
+                if !include.is_empty() && !include.is_match(&relative_path) {
+                    continue;
+                }
+
+                files.push(ScannedFile {
+                    path,
+                    file: FileDto {
+                        id: uuid::Uuid::new_v4().to_string(),
+                        file_name: format!("{dir_name}/{relative_path}"),
+                        size: metadata.len(),
+                        file_type: mime::from_file_name(&name).to_string(),
+                        sha256: None,
+                        preview: None,
+                        metadata: Some(FileMetadata::from_fs(&metadata)),
+                    },
+                });
+            }
+        }
+    }
+
+    files.sort_by(|a, b| a.file.file_name.cmp(&b.file.file_name));
+    Ok(files)
+}
+
+fn build_glob_set(globs: &[String]) -> anyhow::Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for glob in globs {
+        builder.add(Glob::new(glob)?);
+    }
+
+    Ok(builder.build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_scan_dir() {
+        let root = std::env::temp_dir().join(format!("localsend-{}", uuid::Uuid::new_v4()));
+        let dir = root.join("photos");
+        std::fs::create_dir_all(dir.join("2024/.thumbnails")).unwrap();
+        std::fs::write(dir.join("a.jpg"), b"a").unwrap();
+        std::fs::write(dir.join("notes.txt"), b"notes").unwrap();
+        std::fs::write(dir.join("2024/b.JPG"), b"bb").unwrap();
+        std::fs::write(dir.join("2024/.thumbnails/b.jpg"), b"b").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&dir, dir.join("2024/loop")).unwrap();
+
+        let options = ScanOptions {
+            include: vec!["*.{jpg,JPG}".to_string()],
+            exclude: vec!["**/.thumbnails".to_string()],
+            symlinks: SymlinkPolicy::Skip,
+        };
+        let files = scan_dir(&dir, options.clone()).await.unwrap();
+        let names: Vec<_> = files.iter().map(|f| f.file.file_name.as_str()).collect();
+        assert_eq!(names, ["photos/2024/b.JPG", "photos/a.jpg"]);
+        assert_eq!(files[0].file.size, 2);
+        assert_eq!(files[0].file.file_type, "image/jpeg");
+
+        // The link to the scanned directory is not entered again.
+        let options = ScanOptions {
+            symlinks: SymlinkPolicy::Follow,
+            ..options
+        };
+        assert_eq!(scan_dir(&dir, options).await.unwrap().len(), 2);
+
+        let all = scan_dir(&dir, ScanOptions::default()).await.unwrap();
+        assert_eq!(all.len(), 4);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
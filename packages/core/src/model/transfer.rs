@@ -1,9 +1,20 @@
+use crate::model::file::FileMetadata;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::util::io::forward_chunks;
+use crate::util::io::DEFAULT_BUFFER_SIZE;
+#[cfg(all(
+    feature = "mmap",
+    any(target_os = "linux", target_os = "macos", target_os = "windows")
+))]
+use crate::util::io::{forward_slices, map_file};
 use bytes::Bytes;
 use serde::{Deserialize, Serialize};
+#[cfg(not(target_arch = "wasm32"))]
 use std::path::PathBuf;
 use tokio::sync::mpsc;
 
 /// Channel capacity used when normalizing a file-backed [`FileContent`] into a stream.
+#[cfg(not(target_arch = "wasm32"))]
 const FILE_CHANNEL_CAPACITY: usize = 16;
 
 /// The binary content of a file provided by the application for a transfer.
@@ -18,6 +29,7 @@ pub enum FileContent {
     Stream(mpsc::Receiver<Bytes>),
 
     /// A path to a regular file the content is read from.
+    #[cfg(not(target_arch = "wasm32"))]
     Path(PathBuf),
 
     /// A raw file descriptor the content is read from (Android only).
@@ -25,6 +37,30 @@ pub enum FileContent {
     Fd(std::os::fd::RawFd),
 }
 
+/// How the application reads the content of files for a transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransferConfig {
+    /// Size of the buffer files are read through, which is also the maximum size of the chunks.
+    pub buffer_size: usize,
+
+    /// Files of at least this size are memory-mapped instead of read, so their content is not
+    /// copied into buffers. `None` never maps files.
+    ///
+    /// Requires the `mmap` feature and a desktop platform, ignored otherwise.
+    /// A file truncated while it is sent crashes the process, so only enable it for files
+    /// that are not modified during transfers.
+    pub mmap_min_size: Option<u64>,
+}
+
+impl Default for TransferConfig {
+    fn default() -> Self {
+        Self {
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            mmap_min_size: None,
+        }
+    }
+}
+
 impl FileContent {
     /// Normalizes the content into a stream of binary chunks.
     ///
@@ -32,17 +68,29 @@ impl FileContent {
     /// [`FileContent::Fd`], a background task reads the file and forwards the
     /// chunks; the channel is closed on EOF or on an I/O error.
     pub fn into_receiver(self) -> mpsc::Receiver<Bytes> {
+        self.into_receiver_with(TransferConfig::default())
+    }
+
+    /// Like [`FileContent::into_receiver`], reading files as configured.
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+    pub fn into_receiver_with(self, config: TransferConfig) -> mpsc::Receiver<Bytes> {
+        // Chunks of zero bytes would never reach the end of the file.
+        let config = TransferConfig {
+            buffer_size: config.buffer_size.max(1),
+            ..config
+        };
         match self {
             FileContent::Stream(rx) => {
                 tracing::info!("Reading file content via byte stream from application");
                 rx
             }
+            #[cfg(not(target_arch = "wasm32"))]
             FileContent::Path(path) => {
                 tracing::info!("Reading file content from path: {}", path.display());
                 let (tx, rx) = mpsc::channel(FILE_CHANNEL_CAPACITY);
                 tokio::spawn(async move {
                     match tokio::fs::File::open(&path).await {
-                        Ok(file) => read_file_into_sender(file, tx).await,
+                        Ok(file) => read_file_into_sender(file, config, tx).await,
                         Err(e) => {
                             tracing::error!("Failed to open {}: {e}", path.display());
                         }
@@ -60,39 +108,81 @@ impl FileContent {
                 // a File transfers that ownership so it is closed once reading finishes.
                 let std_file = unsafe { std::fs::File::from_raw_fd(fd) };
                 let file = tokio::fs::File::from_std(std_file);
-                tokio::spawn(read_file_into_sender(file, tx));
+                tokio::spawn(read_file_into_sender(file, config, tx));
                 rx
             }
         }
     }
 }
 
-/// Reads `file` to EOF, forwarding chunks on `tx`.
+/// Reads `file` to EOF, forwarding chunks on `tx`. Maps it instead if configured and large enough.
 ///
 /// Stops early if the receiver is gone or a read error occurs.
-async fn read_file_into_sender(mut file: tokio::fs::File, tx: mpsc::Sender<Bytes>) {
-    use tokio::io::AsyncReadExt;
-
-    let mut buffer = bytes::BytesMut::with_capacity(64 * 1024);
-    let mut total: u64 = 0;
-    loop {
-        match file.read_buf(&mut buffer).await {
-            Ok(0) => break,
-            Ok(n) => {
-                total += n as u64;
-                if tx.send(buffer.split().freeze()).await.is_err() {
-                    break;
-                }
-            }
-            Err(e) => {
-                tracing::error!("Failed to read file content: {e}");
-                break;
-            }
+#[cfg(not(target_arch = "wasm32"))]
+async fn read_file_into_sender(
+    file: tokio::fs::File,
+    config: TransferConfig,
+    tx: mpsc::Sender<Bytes>,
+) {
+    #[cfg(all(
+        feature = "mmap",
+        any(target_os = "linux", target_os = "macos", target_os = "windows")
+    ))]
+    let file = match map_if_large(file, config.mmap_min_size).await {
+        Ok(content) => {
+            let total = forward_slices(content, config.buffer_size, &tx).await;
+            tracing::info!("Finished sending memory-mapped file content ({total} bytes)");
+            return;
+        }
+        Err(file) => file,
+    };
+
+    #[cfg(all(feature = "io-uring", target_os = "linux"))]
+    if crate::util::uring::is_available() {
+        crate::util::uring::read_file(file.into_std().await, config.buffer_size, tx);
+        return;
+    }
+
+    match forward_chunks(file, config.buffer_size, &tx).await {
+        Ok(total) => tracing::info!("Finished reading file content ({total} bytes)"),
+        Err(e) => tracing::error!("Failed to read file content: {e}"),
+    }
+}
+
+/// Maps the file if it has at least `min_size` bytes, returns it to be read otherwise.
+#[cfg(all(
+    feature = "mmap",
+    any(target_os = "linux", target_os = "macos", target_os = "windows")
+))]
+async fn map_if_large(
+    file: tokio::fs::File,
+    min_size: Option<u64>,
+) -> Result<Bytes, tokio::fs::File> {
+    let Some(min_size) = min_size else {
+        return Err(file);
+    };
+    // Empty files cannot be mapped.
+    match file.metadata().await {
+        Ok(metadata) if metadata.len() >= min_size.max(1) => {}
+        _ => return Err(file),
+    }
+
+    let file = file.into_std().await;
+    // SAFETY: the application only enables mapping for files not modified during transfers,
+    // see `TransferConfig::mmap_min_size`.
+    match unsafe { map_file(&file) } {
+        Ok(content) => Ok(content),
+        Err(e) => {
+            tracing::warn!("Failed to map file, reading it instead: {e}");
+            Err(tokio::fs::File::from_std(file))
         }
     }
-    tracing::info!("Finished reading file content ({total} bytes)");
 }
 
+/// A file announced to the receiver.
+///
+/// The protocol version is carried by the enclosing message. Unknown fields sent by newer
+/// peers are ignored, so new fields must be optional.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FileDto {
@@ -108,10 +198,82 @@ pub struct FileDto {
     pub metadata: Option<FileMetadata>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct FileMetadata {
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub modified: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub accessed: Option<String>,
+/// The size announced for content whose length is not known in advance, e.g. read from a pipe.
+///
+/// The receiver accepts any number of bytes, the end of the upload request marks the end of the file.
+/// Implementations not supporting it reject the file, as the value exceeds their integer range.
+pub const UNKNOWN_SIZE: u64 = u64::MAX;
+
+impl FileDto {
+    /// Whether the size is known in advance, see [`UNKNOWN_SIZE`].
+    pub fn has_known_size(&self) -> bool {
+        self.size != UNKNOWN_SIZE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_dto_encoding() {
+        let file = FileDto {
+            id: "1".to_string(),
+            file_name: "photo.jpg".to_string(),
+            size: 5,
+            file_type: "image/jpeg".to_string(),
+            sha256: None,
+            preview: None,
+            metadata: None,
+        };
+
+        let encoded = serde_json::to_string(&file).unwrap();
+        assert_eq!(
+            encoded,
+            r#"{"id":"1","fileName":"photo.jpg","size":5,"fileType":"image/jpeg"}"#
+        );
+
+        let decoded: FileDto = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), encoded);
+
+        // Fields added in later versions must not break older peers.
+        let decoded: FileDto = serde_json::from_str(
+            r#"{"id":"1","fileName":"photo.jpg","size":5,"fileType":"image/jpeg","thumbnail":{"width":64},"metadata":{"modified":"2024-01-01T00:00:00Z","owner":"me"}}"#,
+        )
+        .unwrap();
+        assert_eq!(decoded.file_name, "photo.jpg");
+        assert_eq!(
+            decoded.metadata.and_then(|metadata| metadata.modified),
+            Some("2024-01-01T00:00:00Z".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_file_content_chunks() {
+        let path = std::env::temp_dir().join(format!("localsend-{}", uuid::Uuid::new_v4()));
+        let content: Vec<u8> = (0..1000).map(|i| i as u8).collect();
+        std::fs::write(&path, &content).unwrap();
+
+        // Mapped only with the `mmap` feature, the chunks are the same either way.
+        // A buffer of zero bytes is read as a buffer of one byte.
+        for (buffer_size, max_chunk) in [(300, 300), (0, 1)] {
+            for mmap_min_size in [None, Some(1)] {
+                let config = TransferConfig {
+                    buffer_size,
+                    mmap_min_size,
+                };
+                let mut rx = FileContent::Path(path.clone()).into_receiver_with(config);
+                let mut chunks = Vec::new();
+                while let Some(chunk) = rx.recv().await {
+                    chunks.push(chunk);
+                }
+                assert!(chunks
+                    .iter()
+                    .all(|chunk| !chunk.is_empty() && chunk.len() <= max_chunk));
+                assert_eq!(chunks.concat(), content);
+            }
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
 }
@@ -0,0 +1,190 @@
+use crate::util::fs::JsonFileStore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResumeDirection {
+    Send,
+    Receive,
+}
+
+/// A transfer that was interrupted, e.g. because the app was closed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeSession {
+    pub session_id: String,
+
+    /// Fingerprint of the peer, so resuming can be offered once it is seen again.
+    pub peer_fingerprint: String,
+    pub peer_alias: String,
+    pub direction: ResumeDirection,
+    pub files: Vec<ResumeFile>,
+
+    /// Unix timestamp in seconds of the last change.
+    #[serde(default)]
+    pub updated_at: u64,
+}
+
+/// The progress of a single file of a [`ResumeSession`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResumeFile {
+    pub file_id: String,
+
+    /// Token issued by the receiver for the file, if any.
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// Path the file is read from or, when receiving, written to.
+    pub path: String,
+    pub size: u64,
+
+    /// Number of bytes transferred so far, i.e. the offset to resume from.
+    pub bytes_completed: u64,
+
+    /// Lowercase hex SHA-256 of the first `bytes_completed` bytes,
+    /// to detect that the partial data changed before resuming.
+    #[serde(default)]
+    pub partial_sha256: Option<String>,
+}
+
+/// Interrupted transfers by session ID, kept on disk so they can be resumed after the app restarts.
+pub struct ResumeStore {
+    sessions: JsonFileStore<BTreeMap<String, ResumeSession>>,
+}
+
+impl ResumeStore {
+    /// Loads the sessions at `path`, or none if the file does not exist yet.
+    pub async fn open(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        Ok(Self {
+            sessions: JsonFileStore::open(path).await?,
+        })
+    }
+
+    pub fn get(&self, session_id: &str) -> Option<ResumeSession> {
+        self.sessions
+            .read(|sessions| sessions.get(session_id).cloned())
+    }
+
+    /// All sessions, most recently updated first.
+    pub fn list(&self) -> Vec<ResumeSession> {
+        let mut sessions: Vec<_> = self
+            .sessions
+            .read(|sessions| sessions.values().cloned().collect());
+        sessions.sort_by_key(|session| std::cmp::Reverse(session.updated_at));
+        sessions
+    }
+
+    /// The sessions with the peer, most recently updated first.
+    pub fn list_for_peer(&self, fingerprint: &str) -> Vec<ResumeSession> {
+        let mut sessions = self.list();
+        sessions.retain(|session| session.peer_fingerprint == fingerprint);
+        sessions
+    }
+
+    /// Adds or replaces the session and returns it with the updated timestamp.
+    pub async fn save(&self, mut session: ResumeSession) -> anyhow::Result<ResumeSession> {
+        session.updated_at = crate::util::time::unix_timestamp_u64()?;
+        self.sessions
+            .update(|sessions| {
+                sessions.insert(session.session_id.clone(), session.clone());
+                Ok(session)
+            })
+            .await
+    }
+
+    /// Records the progress of a file, e.g. whenever a chunk has been written to disk.
+    pub async fn set_progress(
+        &self,
+        session_id: &str,
+        file_id: &str,
+        bytes_completed: u64,
+        partial_sha256: Option<String>,
+    ) -> anyhow::Result<()> {
+        let updated_at = crate::util::time::unix_timestamp_u64()?;
+        self.sessions
+            .update(|sessions| {
+                let session = sessions
+                    .get_mut(session_id)
+                    .ok_or_else(|| anyhow::anyhow!("Session not found: {session_id}"))?;
+                let file = session
+                    .files
+                    .iter_mut()
+                    .find(|file| file.file_id == file_id)
+                    .ok_or_else(|| anyhow::anyhow!("File not found: {file_id}"))?;
+                file.bytes_completed = bytes_completed;
+                file.partial_sha256 = partial_sha256;
+                session.updated_at = updated_at;
+                Ok(())
+            })
+            .await
+    }
+
+    /// Removes the session, e.g. once it was resumed successfully or discarded.
+    pub async fn remove(&self, session_id: &str) -> anyhow::Result<()> {
+        self.sessions
+            .update(|sessions| {
+                sessions.remove(session_id);
+                Ok(())
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resume_store() {
+        let path = std::env::temp_dir()
+            .join(format!("localsend-{}", uuid::Uuid::new_v4()))
+            .join("resume.json");
+
+        let store = ResumeStore::open(&path).await.unwrap();
+        let saved = store
+            .save(ResumeSession {
+                session_id: "session".to_string(),
+                peer_fingerprint: "abc".to_string(),
+                peer_alias: "Nice Orange".to_string(),
+                direction: ResumeDirection::Receive,
+                files: vec![ResumeFile {
+                    file_id: "file".to_string(),
+                    token: Some("token".to_string()),
+                    path: "/downloads/photo.jpg".to_string(),
+                    size: 100,
+                    bytes_completed: 0,
+                    partial_sha256: None,
+                }],
+                updated_at: 0,
+            })
+            .await
+            .unwrap();
+        assert!(saved.updated_at > 0);
+
+        store
+            .set_progress("session", "file", 50, Some("hash".to_string()))
+            .await
+            .unwrap();
+        assert!(store
+            .set_progress("session", "other", 1, None)
+            .await
+            .is_err());
+        assert!(store.set_progress("other", "file", 1, None).await.is_err());
+
+        let reopened = ResumeStore::open(&path).await.unwrap();
+        assert_eq!(reopened.list(), store.list());
+        let session = reopened.get("session").unwrap();
+        assert_eq!(session.files[0].bytes_completed, 50);
+        assert_eq!(session.files[0].partial_sha256.as_deref(), Some("hash"));
+        assert_eq!(reopened.list_for_peer("abc").len(), 1);
+        assert!(reopened.list_for_peer("def").is_empty());
+
+        reopened.remove("session").await.unwrap();
+        assert!(ResumeStore::open(&path).await.unwrap().list().is_empty());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,81 @@
+use crate::util::fs::JsonFileStore;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+/// Settings shared by the app and the headless mode.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Settings {
+    /// Alias announced to other devices. Generated by the app if `None`.
+    pub alias: Option<String>,
+
+    /// Directory received files are saved to. The platform default if `None`.
+    pub download_dir: Option<String>,
+
+    /// PIN other devices must enter before sending. No PIN is required if `None`.
+    pub pin: Option<String>,
+
+    /// Maximum transfer speed in KiB per second. Unlimited if `None`.
+    pub bandwidth_limit: Option<u32>,
+
+    /// URLs notified about the transfers via `POST`, see `webhook::WebhookNotifier`.
+    pub webhooks: Vec<String>,
+}
+
+/// The [`Settings`], saved to a JSON file whenever they are updated.
+pub struct SettingsStore {
+    settings: JsonFileStore<Settings>,
+}
+
+impl SettingsStore {
+    /// Loads the settings at `path`, or the defaults if the file does not exist yet.
+    pub async fn open(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        Ok(Self {
+            settings: JsonFileStore::open(path).await?,
+        })
+    }
+
+    pub fn get(&self) -> Settings {
+        self.settings.read(Settings::clone)
+    }
+
+    /// Applies `update` and writes the settings.
+    pub async fn update(&self, update: impl FnOnce(&mut Settings)) -> anyhow::Result<()> {
+        self.settings
+            .update(|settings| {
+                update(settings);
+                Ok(())
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_settings_store() {
+        let path = std::env::temp_dir()
+            .join(format!("localsend-{}", uuid::Uuid::new_v4()))
+            .join("settings.json");
+
+        let store = SettingsStore::open(&path).await.unwrap();
+        assert_eq!(store.get(), Settings::default());
+
+        store
+            .update(|settings| {
+                settings.alias = Some("Nice Orange".to_string());
+                settings.bandwidth_limit = Some(1024);
+            })
+            .await
+            .unwrap();
+
+        let reopened = SettingsStore::open(&path).await.unwrap();
+        assert_eq!(reopened.get(), store.get());
+        assert_eq!(reopened.get().alias.as_deref(), Some("Nice Orange"));
+        assert_eq!(reopened.get().pin, None);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
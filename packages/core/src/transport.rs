@@ -0,0 +1,164 @@
+use crate::crypto::token::{SigningTokenKey, VerifyingTokenKey};
+use crate::http::client::sender::{send_files_v2, UploadProgress};
+use crate::http::client::{ClientError, LsHttpClientV2};
+use crate::http::dto::ProtocolType;
+use crate::http::dto_v2::RegisterDtoV2;
+use crate::model::transfer::FileDto;
+use crate::webrtc::signaling::ManagedSignalingConnection;
+use crate::webrtc::webrtc::{send_offer, RTCFile, RTCFileError, RTCStats, RTCStatus};
+use anyhow::Result;
+use std::collections::HashSet;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+use tokio_util::sync::CancellationToken;
+use uuid::Uuid;
+
+/// How long to wait for the receiver to answer on the LAN
+/// before falling back to WebRTC.
+pub const LAN_PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The transport used to send files to a peer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Transport {
+    /// Direct connection to the HTTP server of the receiver on the local network.
+    LanHttp,
+
+    /// WebRTC data channel negotiated via the signaling server.
+    WebRtc,
+}
+
+/// Address of the receiver on the local network, e.g. found via multicast discovery.
+#[derive(Clone, Debug)]
+pub struct LanTarget {
+    pub protocol: ProtocolType,
+    pub ip: String,
+    pub port: u16,
+
+    /// The expected public key of the receiver's certificate (HTTPS only).
+    pub public_key: Option<String>,
+}
+
+/// Route to the receiver via the signaling server.
+pub struct SignalingTarget<'a> {
+    pub connection: &'a ManagedSignalingConnection,
+    pub target_id: Uuid,
+    pub stun_servers: Vec<String>,
+}
+
+/// All known ways to reach a peer. At least one should be set.
+pub struct PeerTarget<'a> {
+    pub lan: Option<LanTarget>,
+    pub signaling: Option<SignalingTarget<'a>>,
+}
+
+/// Selects the transport to reach the peer with.
+///
+/// The LAN address is preferred if the receiver answers within [`LAN_PROBE_TIMEOUT`],
+/// otherwise WebRTC is used if a signaling route is known.
+/// Returns `None` if the peer is not reachable at all.
+pub async fn select_transport(
+    client: &LsHttpClientV2,
+    target: &PeerTarget<'_>,
+) -> Option<Transport> {
+    if let Some(lan) = &target.lan {
+        let probe = client.info(lan.protocol.clone(), &lan.ip, lan.port);
+        match tokio::time::timeout(LAN_PROBE_TIMEOUT, probe).await {
+            Ok(Ok(_)) => return Some(Transport::LanHttp),
+            Ok(Err(e)) => tracing::debug!("Peer not reachable on LAN: {e}"),
+            Err(_) => tracing::debug!("Peer not reachable on LAN: timeout"),
+        }
+    }
+
+    target.signaling.as_ref().map(|_| Transport::WebRtc)
+}
+
+/// Sends files to a peer, trying the LAN first and falling back to WebRTC.
+///
+/// The chosen transport is published via `transport_tx` before any file is sent.
+/// From then on, the events are the same as for [`send_files_v2`] and [`send_offer`];
+/// channels that do not apply to the chosen transport are dropped
+/// (`progress_tx` for WebRTC, `stats_tx` and `pair_tx` for HTTP).
+///
+/// Use [`send_offer`] directly to require a PIN from the receiver.
+#[allow(clippy::too_many_arguments)]
+pub async fn send_files(
+    http_client: &LsHttpClientV2,
+    target: PeerTarget<'_>,
+    info: RegisterDtoV2,
+    signing_key: SigningTokenKey,
+    expecting_public_key: Option<Box<dyn VerifyingTokenKey + Send>>,
+    files: Vec<FileDto>,
+    transport_tx: oneshot::Sender<Transport>,
+    status_tx: mpsc::Sender<RTCStatus>,
+    stats_tx: mpsc::Sender<RTCStats>,
+    progress_tx: mpsc::Sender<UploadProgress>,
+    selected_files_tx: oneshot::Sender<HashSet<String>>,
+    error_tx: mpsc::Sender<RTCFileError>,
+    pin_tx: mpsc::Sender<oneshot::Sender<String>>,
+    pair_tx: oneshot::Sender<oneshot::Sender<bool>>,
+    sending_rx: mpsc::Receiver<RTCFile>,
+    cancel: CancellationToken,
+) -> Result<()> {
+    let Some(transport) = select_transport(http_client, &target).await else {
+        let error = "Peer is not reachable";
+        let _ = status_tx.send(RTCStatus::Error(error.to_owned())).await;
+        return Err(anyhow::anyhow!(error));
+    };
+
+    tracing::debug!("Selected transport: {transport:?}");
+    let _ = transport_tx.send(transport);
+
+    match (transport, target) {
+        (Transport::LanHttp, PeerTarget { lan: Some(lan), .. }) => {
+            send_files_v2(
+                http_client,
+                lan.protocol,
+                &lan.ip,
+                lan.port,
+                lan.public_key,
+                info,
+                files,
+                status_tx,
+                progress_tx,
+                selected_files_tx,
+                error_tx,
+                pin_tx,
+                sending_rx,
+                cancel,
+            )
+            .await?;
+        }
+        (
+            Transport::WebRtc,
+            PeerTarget {
+                signaling: Some(signaling),
+                ..
+            },
+        ) => {
+            let send = send_offer(
+                signaling.connection,
+                signaling.stun_servers,
+                signaling.target_id,
+                signing_key,
+                expecting_public_key,
+                None,
+                files,
+                status_tx,
+                stats_tx,
+                selected_files_tx,
+                error_tx,
+                pin_tx,
+                pair_tx,
+                sending_rx,
+            );
+
+            tokio::select! {
+                result = send => result?,
+                _ = cancel.cancelled() => return Err(ClientError::Cancelled.into()),
+            }
+        }
+        _ => unreachable!("select_transport only returns transports with a known route"),
+    }
+
+    Ok(())
+}
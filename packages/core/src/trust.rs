@@ -0,0 +1,118 @@
+use crate::util::fs::JsonFileStore;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// A device whose fingerprint was accepted by the user.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrustedDevice {
+    /// SHA-256 fingerprint (uppercase hex) pinned for the device.
+    pub fingerprint: String,
+
+    /// Unix timestamp in seconds of when the fingerprint was pinned.
+    pub trusted_at: u64,
+}
+
+/// Result of checking a fingerprint against the [`TrustStore`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrustStatus {
+    /// The fingerprint matches the pinned one.
+    Trusted,
+
+    /// No fingerprint is pinned for the device yet.
+    New,
+
+    /// The device presents a different fingerprint than the pinned one.
+    /// Either it was reinstalled or another device impersonates it.
+    Changed,
+}
+
+/// Pinned fingerprints of trusted devices, saved to a JSON file on every change.
+///
+/// Devices are identified by a key chosen by the application, e.g. their alias,
+/// so a changed fingerprint can be detected (trust on first use).
+pub struct TrustStore {
+    devices: JsonFileStore<BTreeMap<String, TrustedDevice>>,
+}
+
+impl TrustStore {
+    /// Loads the trusted devices at `path`, or none if the file does not exist yet.
+    pub async fn open(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        Ok(Self {
+            devices: JsonFileStore::open(path).await?,
+        })
+    }
+
+    pub fn get(&self, device: &str) -> Option<TrustedDevice> {
+        self.devices.read(|devices| devices.get(device).cloned())
+    }
+
+    pub fn list(&self) -> BTreeMap<String, TrustedDevice> {
+        self.devices.read(BTreeMap::clone)
+    }
+
+    /// Checks the fingerprint presented by the device against the pinned one.
+    pub fn check(&self, device: &str, fingerprint: &str) -> TrustStatus {
+        self.devices.read(|devices| match devices.get(device) {
+            Some(trusted) if trusted.fingerprint.eq_ignore_ascii_case(fingerprint) => {
+                TrustStatus::Trusted
+            }
+            Some(_) => TrustStatus::Changed,
+            None => TrustStatus::New,
+        })
+    }
+
+    /// Pins the fingerprint for the device, replacing the previous one.
+    pub async fn trust(&self, device: &str, fingerprint: &str) -> anyhow::Result<()> {
+        let trusted = TrustedDevice {
+            fingerprint: fingerprint.to_ascii_uppercase(),
+            trusted_at: crate::util::time::unix_timestamp_u64()?,
+        };
+        self.devices
+            .update(|devices| {
+                devices.insert(device.to_string(), trusted);
+                Ok(())
+            })
+            .await
+    }
+
+    /// Removes the pinned fingerprint of the device.
+    pub async fn forget(&self, device: &str) -> anyhow::Result<()> {
+        self.devices
+            .update(|devices| {
+                devices.remove(device);
+                Ok(())
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_trust_store() {
+        let path = std::env::temp_dir()
+            .join(format!("localsend-{}", uuid::Uuid::new_v4()))
+            .join("trusted_devices.json");
+
+        let store = TrustStore::open(&path).await.unwrap();
+        assert_eq!(store.check("Nice Orange", "ABCD"), TrustStatus::New);
+
+        store.trust("Nice Orange", "abcd").await.unwrap();
+        assert_eq!(store.check("Nice Orange", "ABCD"), TrustStatus::Trusted);
+        assert_eq!(store.check("Nice Orange", "1234"), TrustStatus::Changed);
+
+        let reopened = TrustStore::open(&path).await.unwrap();
+        assert_eq!(reopened.list(), store.list());
+        assert_eq!(reopened.get("Nice Orange").unwrap().fingerprint, "ABCD");
+
+        reopened.forget("Nice Orange").await.unwrap();
+        assert_eq!(reopened.check("Nice Orange", "ABCD"), TrustStatus::New);
+        assert!(TrustStore::open(&path).await.unwrap().list().is_empty());
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
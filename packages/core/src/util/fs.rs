@@ -0,0 +1,62 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Writes `contents` to `path`, creating the parent directories.
+/// The file is replaced atomically, so readers never see a partial write.
+pub(crate) async fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let tmp_path = path.with_extension("tmp");
+    tokio::fs::write(&tmp_path, contents).await?;
+    tokio::fs::rename(&tmp_path, path).await
+}
+
+/// A value persisted as JSON in a single file, which is rewritten on every change.
+pub(crate) struct JsonFileStore<T> {
+    path: PathBuf,
+    value: Mutex<T>,
+
+    /// Held while writing, so the file always ends with the latest value.
+    write_lock: tokio::sync::Mutex<()>,
+}
+
+impl<T: Default + Serialize + DeserializeOwned> JsonFileStore<T> {
+    /// Loads the value at `path`, or the default if the file does not exist yet.
+    pub(crate) async fn open(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        let path = path.into();
+        let value = match tokio::fs::read(&path).await {
+            Ok(json) => serde_json::from_slice(&json)?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => T::default(),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Self {
+            path,
+            value: Mutex::new(value),
+            write_lock: tokio::sync::Mutex::new(()),
+        })
+    }
+
+    pub(crate) fn read<R>(&self, read: impl FnOnce(&T) -> R) -> R {
+        read(&self.value.lock().unwrap())
+    }
+
+    /// Applies `update` and writes the file. Nothing is written if `update` fails.
+    pub(crate) async fn update<R>(
+        &self,
+        update: impl FnOnce(&mut T) -> anyhow::Result<R>,
+    ) -> anyhow::Result<R> {
+        let _write_guard = self.write_lock.lock().await;
+        let (result, json) = {
+            let mut value = self.value.lock().unwrap();
+            let result = update(&mut value)?;
+            (result, serde_json::to_vec_pretty(&*value)?)
+        };
+
+        write_atomic(&self.path, &json).await?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,305 @@
+#[cfg(all(feature = "io-uring", target_os = "linux"))]
+use crate::util::uring::{self, UringFile};
+use bytes::Bytes;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, BufReader, BufWriter};
+use tokio::sync::mpsc;
+
+/// Default size of the buffers file content is read and written with.
+///
+/// Small buffers mean a system call and a channel message per few KiB,
+/// which caps the throughput well below what disks and networks manage.
+pub const DEFAULT_BUFFER_SIZE: usize = 256 * 1024;
+
+/// Reads `reader` to EOF through a buffer of `buffer_size` bytes,
+/// forwarding chunks of at most that size on `tx`.
+///
+/// Stops early without an error if the receiver is gone.
+/// Returns the number of bytes read.
+pub async fn forward_chunks(
+    reader: impl AsyncRead + Unpin,
+    buffer_size: usize,
+    tx: &mpsc::Sender<Bytes>,
+) -> std::io::Result<u64> {
+    let mut reader = BufReader::with_capacity(buffer_size, reader);
+    let mut total = 0;
+    loop {
+        let buffer = reader.fill_buf().await?;
+        if buffer.is_empty() {
+            return Ok(total);
+        }
+        let chunk = Bytes::copy_from_slice(buffer);
+        reader.consume(chunk.len());
+        total += chunk.len() as u64;
+        if tx.send(chunk).await.is_err() {
+            return Ok(total);
+        }
+    }
+}
+
+/// Maps `file` into memory, so its content can be sent without copying it into buffers first.
+///
+/// # Safety
+///
+/// The file must not be truncated or modified while the content is in use.
+/// Reading a truncated part kills the process with `SIGBUS`.
+#[cfg(all(
+    feature = "mmap",
+    any(target_os = "linux", target_os = "macos", target_os = "windows")
+))]
+pub(crate) unsafe fn map_file(file: &std::fs::File) -> std::io::Result<Bytes> {
+    // SAFETY: upheld by the caller.
+    let mmap = unsafe { memmap2::Mmap::map(file)? };
+    #[cfg(unix)]
+    if let Err(e) = mmap.advise(memmap2::Advice::Sequential) {
+        tracing::debug!("Failed to advise sequential access: {e}");
+    }
+    Ok(Bytes::from_owner(mmap))
+}
+
+/// Forwards `content` on `tx` in chunks of at most `chunk_size` bytes.
+/// The chunks are slices of `content`, nothing is copied.
+///
+/// Stops early if the receiver is gone. Returns the number of bytes forwarded.
+pub async fn forward_slices(content: Bytes, chunk_size: usize, tx: &mpsc::Sender<Bytes>) -> u64 {
+    let mut offset = 0;
+    while offset < content.len() {
+        let end = content.len().min(offset + chunk_size);
+        if tx.send(content.slice(offset..end)).await.is_err() {
+            break;
+        }
+        offset = end;
+    }
+    offset as u64
+}
+
+/// Wraps `writer` in a buffer of `buffer_size` bytes.
+/// The buffer must be flushed before the writer is dropped.
+pub fn buffered_writer<W: AsyncWrite>(writer: W, buffer_size: usize) -> BufWriter<W> {
+    BufWriter::with_capacity(buffer_size, writer)
+}
+
+/// A file written through a buffer of a configurable size.
+///
+/// With the `io-uring` feature on Linux, the writes go through io_uring if the kernel supports it.
+#[cfg(not(target_arch = "wasm32"))]
+pub struct FileWriter(Writer);
+
+#[cfg(not(target_arch = "wasm32"))]
+enum Writer {
+    Tokio(BufWriter<tokio::fs::File>),
+    #[cfg(all(feature = "io-uring", target_os = "linux"))]
+    Uring {
+        file: UringFile,
+        buffer: Vec<u8>,
+        buffer_size: usize,
+    },
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl FileWriter {
+    /// Creates or truncates the file at `path`.
+    pub async fn create(path: &std::path::Path, buffer_size: usize) -> std::io::Result<Self> {
+        #[cfg(all(feature = "io-uring", target_os = "linux"))]
+        if uring::is_available() {
+            return Ok(Self(Writer::Uring {
+                file: UringFile::create(path.to_path_buf()).await?,
+                buffer: Vec::with_capacity(buffer_size),
+                buffer_size,
+            }));
+        }
+
+        let file = tokio::fs::File::create(path).await?;
+        Ok(Self(Writer::Tokio(buffered_writer(file, buffer_size))))
+    }
+
+    /// Writes `data` to the buffer, and the buffer to the file once it is full.
+    pub async fn write_all(&mut self, data: &[u8]) -> std::io::Result<()> {
+        match &mut self.0 {
+            Writer::Tokio(file) => tokio::io::AsyncWriteExt::write_all(file, data).await,
+            #[cfg(all(feature = "io-uring", target_os = "linux"))]
+            Writer::Uring {
+                file,
+                buffer,
+                buffer_size,
+            } => {
+                buffer.extend_from_slice(data);
+                if buffer.len() >= *buffer_size {
+                    let full = std::mem::replace(buffer, Vec::with_capacity(*buffer_size));
+                    file.write(full).await?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Writes the buffer to the file.
+    pub async fn flush(&mut self) -> std::io::Result<()> {
+        match &mut self.0 {
+            Writer::Tokio(file) => tokio::io::AsyncWriteExt::flush(file).await,
+            #[cfg(all(feature = "io-uring", target_os = "linux"))]
+            Writer::Uring { file, buffer, .. } => {
+                if !buffer.is_empty() {
+                    file.write(std::mem::take(buffer)).await?;
+                }
+                file.flush().await
+            }
+        }
+    }
+
+    /// Flushes the buffer and waits until the content has reached the disk.
+    pub async fn sync_all(&mut self) -> std::io::Result<()> {
+        self.flush().await?;
+        match &mut self.0 {
+            Writer::Tokio(file) => file.get_ref().sync_all().await,
+            #[cfg(all(feature = "io-uring", target_os = "linux"))]
+            Writer::Uring { file, .. } => file.sync_all().await,
+        }
+    }
+}
+
+/// Reserves disk space for `len` bytes of `file` without changing its size,
+/// which reduces fragmentation and fails early if the disk is too small.
+///
+/// Only a full disk or a file too large for the file system is an error.
+/// Nothing happens if the platform or file system cannot preallocate.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn preallocate(file: &std::fs::File, len: u64) -> std::io::Result<()> {
+    match allocate(file, len) {
+        Ok(()) => Ok(()),
+        Err(e)
+            if matches!(
+                e.kind(),
+                std::io::ErrorKind::StorageFull
+                    | std::io::ErrorKind::QuotaExceeded
+                    | std::io::ErrorKind::FileTooLarge
+            ) =>
+        {
+            Err(e)
+        }
+        Err(e) => {
+            tracing::debug!("Failed to preallocate {len} bytes: {e}");
+            Ok(())
+        }
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn allocate(file: &std::fs::File, len: u64) -> std::io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let Ok(len) = libc::off_t::try_from(len) else {
+        return Ok(());
+    };
+    // SAFETY: the descriptor is open for as long as `file` is borrowed.
+    let result = unsafe { libc::fallocate(file.as_raw_fd(), libc::FALLOC_FL_KEEP_SIZE, 0, len) };
+    match result {
+        0 => Ok(()),
+        _ => Err(std::io::Error::last_os_error()),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn allocate(file: &std::fs::File, len: u64) -> std::io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let Ok(len) = libc::off_t::try_from(len) else {
+        return Ok(());
+    };
+    let mut store = libc::fstore_t {
+        fst_flags: libc::F_ALLOCATECONTIG | libc::F_ALLOCATEALL,
+        fst_posmode: libc::F_PEOFPOSMODE,
+        fst_offset: 0,
+        fst_length: len,
+        fst_bytesalloc: 0,
+    };
+    // SAFETY: the descriptor is open for as long as `file` is borrowed,
+    // and `store` outlives the call.
+    unsafe {
+        if libc::fcntl(file.as_raw_fd(), libc::F_PREALLOCATE, &mut store) == -1 {
+            // The space is too fragmented, fall back to non-contiguous space.
+            store.fst_flags = libc::F_ALLOCATEALL;
+            if libc::fcntl(file.as_raw_fd(), libc::F_PREALLOCATE, &mut store) == -1 {
+                return Err(std::io::Error::last_os_error());
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(windows)]
+fn allocate(file: &std::fs::File, len: u64) -> std::io::Result<()> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Storage::FileSystem::{
+        FileAllocationInfo, SetFileInformationByHandle, FILE_ALLOCATION_INFO,
+    };
+
+    let Ok(len) = i64::try_from(len) else {
+        return Ok(());
+    };
+    let info = FILE_ALLOCATION_INFO {
+        AllocationSize: len,
+    };
+    // SAFETY: the handle is open for as long as `file` is borrowed,
+    // and `info` matches the information class.
+    let result = unsafe {
+        SetFileInformationByHandle(
+            file.as_raw_handle(),
+            FileAllocationInfo,
+            (&info as *const FILE_ALLOCATION_INFO).cast(),
+            std::mem::size_of::<FILE_ALLOCATION_INFO>() as u32,
+        )
+    };
+    match result {
+        0 => Err(std::io::Error::last_os_error()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "wasm32",
+    target_os = "linux",
+    target_os = "android",
+    target_os = "macos",
+    windows
+)))]
+fn allocate(_file: &std::fs::File, _len: u64) -> std::io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_forward_chunks() {
+        let content: Vec<u8> = (0..10_000).map(|i| i as u8).collect();
+        let (tx, mut rx) = mpsc::channel(64);
+
+        let total = forward_chunks(content.as_slice(), 4096, &tx).await.unwrap();
+        drop(tx);
+        assert_eq!(total, 10_000);
+
+        let mut received = Vec::new();
+        while let Some(chunk) = rx.recv().await {
+            assert!(chunk.len() <= 4096);
+            received.extend_from_slice(&chunk);
+        }
+        assert_eq!(received, content);
+    }
+
+    #[tokio::test]
+    async fn test_file_writer() {
+        let path = std::env::temp_dir().join(format!("localsend-{}", uuid::Uuid::new_v4()));
+        let content: Vec<u8> = (0..10_000).map(|i| i as u8).collect();
+
+        let mut writer = FileWriter::create(&path, 4096).await.unwrap();
+        for chunk in content.chunks(1000) {
+            writer.write_all(chunk).await.unwrap();
+        }
+        writer.sync_all().await.unwrap();
+        drop(writer);
+        assert_eq!(std::fs::read(&path).unwrap(), content);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,261 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::path::Path;
+#[cfg(not(target_arch = "wasm32"))]
+use tokio::io::AsyncReadExt;
+
+/// MIME type of files whose type cannot be guessed from their extension.
+pub const DEFAULT_MIME_TYPE: &str = "application/octet-stream";
+
+/// Number of bytes at the start of a file that [`from_content`] looks at.
+pub const MAGIC_BYTES_LENGTH: usize = 16;
+
+/// The MIME type of a file inferred by [`infer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InferredMimeType {
+    pub mime_type: &'static str,
+
+    /// The content does not match the extension, e.g. an executable named `photo.jpg`.
+    /// `mime_type` is the type of the content then.
+    pub mismatch: bool,
+}
+
+/// Guesses the MIME type of a file from the extension of its name, the format of `FileDto::file_type`.
+/// Returns [`DEFAULT_MIME_TYPE`] for unknown extensions.
+pub fn from_file_name(file_name: &str) -> &'static str {
+    let Some((_, extension)) = file_name.rsplit_once('.') else {
+        return DEFAULT_MIME_TYPE;
+    };
+
+    match extension.to_ascii_lowercase().as_str() {
+        // Images
+        "jpg" | "jpeg" => "image/jpeg",
+        "png" => "image/png",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "bmp" => "image/bmp",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "heic" => "image/heic",
+        "heif" => "image/heif",
+        "avif" => "image/avif",
+        "tif" | "tiff" => "image/tiff",
+
+        // Videos
+        "mp4" | "m4v" => "video/mp4",
+        "mov" => "video/quicktime",
+        "mkv" => "video/x-matroska",
+        "webm" => "video/webm",
+        "avi" => "video/x-msvideo",
+        "3gp" => "video/3gpp",
+
+        // Audio
+        "mp3" => "audio/mpeg",
+        "m4a" => "audio/mp4",
+        "aac" => "audio/aac",
+        "wav" => "audio/wav",
+        "flac" => "audio/flac",
+        "ogg" | "oga" => "audio/ogg",
+        "opus" => "audio/opus",
+
+        // Documents
+        "pdf" => "application/pdf",
+        "txt" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "htm" | "html" => "text/html",
+        "json" => "application/json",
+        "xml" => "application/xml",
+        "doc" => "application/msword",
+        "docx" => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        "xls" => "application/vnd.ms-excel",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "ppt" => "application/vnd.ms-powerpoint",
+        "pptx" => "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+        "odt" => "application/vnd.oasis.opendocument.text",
+        "ods" => "application/vnd.oasis.opendocument.spreadsheet",
+        "odp" => "application/vnd.oasis.opendocument.presentation",
+        "epub" => "application/epub+zip",
+
+        // Archives and packages
+        "zip" => "application/zip",
+        "7z" => "application/x-7z-compressed",
+        "rar" => "application/vnd.rar",
+        "tar" => "application/x-tar",
+        "gz" => "application/gzip",
+        "apk" => "application/vnd.android.package-archive",
+
+        _ => DEFAULT_MIME_TYPE,
+    }
+}
+
+/// Guesses the MIME type of a file from its first bytes, see [`MAGIC_BYTES_LENGTH`].
+/// Returns `None` for formats without a signature such as text files.
+///
+/// Formats sharing a container are reported as the container, e.g. `application/zip`
+/// for `.docx` files and `video/mp4` for all ISO media files.
+pub fn from_content(head: &[u8]) -> Option<&'static str> {
+    let riff = |kind: &[u8]| head.starts_with(b"RIFF") && head.get(8..12) == Some(kind);
+    let mime_type = if head.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if head.starts_with(b"\x89PNG\r\n\x1a\n") {
+        "image/png"
+    } else if head.starts_with(b"GIF87a") || head.starts_with(b"GIF89a") {
+        "image/gif"
+    } else if riff(b"WEBP") {
+        "image/webp"
+    } else if head.starts_with(b"BM") && head.len() >= 14 {
+        "image/bmp"
+    } else if head.starts_with(b"II*\0") || head.starts_with(b"MM\0*") {
+        "image/tiff"
+    } else if head.get(4..8) == Some(b"ftyp") {
+        "video/mp4"
+    } else if head.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        "video/x-matroska"
+    } else if riff(b"AVI ") {
+        "video/x-msvideo"
+    } else if head.starts_with(b"ID3") || head.starts_with(&[0xFF, 0xFB]) {
+        "audio/mpeg"
+    } else if riff(b"WAVE") {
+        "audio/wav"
+    } else if head.starts_with(b"fLaC") {
+        "audio/flac"
+    } else if head.starts_with(b"OggS") {
+        "audio/ogg"
+    } else if head.starts_with(b"%PDF-") {
+        "application/pdf"
+    } else if head.starts_with(b"PK\x03\x04") || head.starts_with(b"PK\x05\x06") {
+        "application/zip"
+    } else if head.starts_with(b"7z\xBC\xAF\x27\x1C") {
+        "application/x-7z-compressed"
+    } else if head.starts_with(b"Rar!\x1A\x07") {
+        "application/vnd.rar"
+    } else if head.starts_with(&[0x1F, 0x8B]) {
+        "application/gzip"
+    } else if head.starts_with(b"\x7fELF") {
+        "application/x-executable"
+    } else if head.starts_with(b"MZ") {
+        "application/vnd.microsoft.portable-executable"
+    } else if [
+        [0xFE, 0xED, 0xFA, 0xCE],
+        [0xFE, 0xED, 0xFA, 0xCF],
+        [0xCE, 0xFA, 0xED, 0xFE],
+        [0xCF, 0xFA, 0xED, 0xFE],
+    ]
+    .iter()
+    .any(|magic| head.starts_with(magic))
+    {
+        "application/x-mach-binary"
+    } else if head.starts_with(b"#!") {
+        "text/x-shellscript"
+    } else {
+        return None;
+    };
+
+    Some(mime_type)
+}
+
+/// Infers the MIME type of a file from its name and its first bytes.
+///
+/// The type of the extension is preferred as it is more specific, unless the content
+/// has a signature of a different format. Files without a known extension are not
+/// reported as mismatching.
+pub fn infer(file_name: &str, head: &[u8]) -> InferredMimeType {
+    let by_name = from_file_name(file_name);
+    match from_content(head) {
+        Some(by_content) if container(by_name) != by_content => InferredMimeType {
+            mime_type: by_content,
+            mismatch: by_name != DEFAULT_MIME_TYPE,
+        },
+        _ => InferredMimeType {
+            mime_type: by_name,
+            mismatch: false,
+        },
+    }
+}
+
+/// Reads the first bytes of the file at `path` and infers its MIME type, see [`infer`].
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn infer_from_path(path: impl AsRef<Path>) -> std::io::Result<InferredMimeType> {
+    let path = path.as_ref();
+    let mut head = Vec::with_capacity(MAGIC_BYTES_LENGTH);
+    tokio::fs::File::open(path)
+        .await?
+        .take(MAGIC_BYTES_LENGTH as u64)
+        .read_to_end(&mut head)
+        .await?;
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    Ok(infer(&file_name, &head))
+}
+
+/// The type [`from_content`] reports for files of the MIME type.
+fn container(mime_type: &str) -> &str {
+    match mime_type {
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+        | "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+        | "application/vnd.openxmlformats-officedocument.presentationml.presentation"
+        | "application/vnd.oasis.opendocument.text"
+        | "application/vnd.oasis.opendocument.spreadsheet"
+        | "application/vnd.oasis.opendocument.presentation"
+        | "application/epub+zip"
+        | "application/vnd.android.package-archive" => "application/zip",
+        "video/quicktime" | "video/3gpp" | "audio/mp4" | "image/heic" | "image/heif"
+        | "image/avif" => "video/mp4",
+        "video/webm" => "video/x-matroska",
+        "audio/opus" => "audio/ogg",
+        _ => mime_type,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_file_name() {
+        assert_eq!(from_file_name("photo.JPG"), "image/jpeg");
+        assert_eq!(from_file_name("archive.tar.gz"), "application/gzip");
+        assert_eq!(from_file_name("README"), DEFAULT_MIME_TYPE);
+        assert_eq!(from_file_name("data.unknown"), DEFAULT_MIME_TYPE);
+    }
+
+    #[test]
+    fn test_infer() {
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xE0, 0, 0x10, b'J', b'F', b'I', b'F'];
+        let ftyp = b"\0\0\0\x20ftypheic\0\0\0\0";
+        let elf = b"\x7fELF\x02\x01\x01\0";
+        let not_mismatching = |mime_type| InferredMimeType {
+            mime_type,
+            mismatch: false,
+        };
+
+        assert_eq!(infer("photo.jpg", &jpeg), not_mismatching("image/jpeg"));
+        assert_eq!(infer("photo.heic", ftyp), not_mismatching("image/heic"));
+        assert_eq!(
+            infer("report.docx", b"PK\x03\x04\x14\0"),
+            not_mismatching(
+                "application/vnd.openxmlformats-officedocument.wordprocessingml.document"
+            )
+        );
+        assert_eq!(infer("notes.txt", b"hello"), not_mismatching("text/plain"));
+        assert_eq!(infer("photo", &jpeg), not_mismatching("image/jpeg"));
+        assert_eq!(
+            infer("photo.jpg", elf),
+            InferredMimeType {
+                mime_type: "application/x-executable",
+                mismatch: true,
+            }
+        );
+        assert_eq!(
+            infer("photo.png", &jpeg),
+            InferredMimeType {
+                mime_type: "image/jpeg",
+                mismatch: true,
+            }
+        );
+        assert_eq!(from_content(b""), None);
+    }
+}
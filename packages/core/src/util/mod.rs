@@ -1,2 +1,9 @@
 pub mod base64;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod fs;
+pub mod io;
+pub mod mime;
 pub(crate) mod time;
+pub mod token;
+#[cfg(all(feature = "io-uring", target_os = "linux"))]
+pub(crate) mod uring;
@@ -1,7 +1,61 @@
-use std::time::{SystemTime, SystemTimeError, UNIX_EPOCH};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-pub(crate) fn unix_timestamp_u64() -> Result<u64, SystemTimeError> {
+#[cfg(any(feature = "crypto", not(target_arch = "wasm32")))]
+pub(crate) fn unix_timestamp_u64() -> Result<u64, std::time::SystemTimeError> {
     let seconds = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
 
     Ok(seconds)
 }
+
+/// Formats the time as ISO 8601 in UTC with milliseconds, e.g. `2024-02-29T13:05:09.123Z`.
+/// Returns `None` for times before the Unix epoch.
+pub(crate) fn format_iso8601(time: SystemTime) -> Option<String> {
+    let duration = time.duration_since(UNIX_EPOCH).ok()?;
+    let seconds = duration.as_secs();
+    let (year, month, day) = civil_from_days((seconds / 86_400) as i64);
+    let seconds_of_day = seconds % 86_400;
+
+    Some(format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        seconds_of_day / 3600,
+        seconds_of_day / 60 % 60,
+        seconds_of_day % 60,
+        duration.subsec_millis(),
+    ))
+}
+
+/// Converts days since the Unix epoch to a (year, month, day) date of the proleptic Gregorian calendar.
+/// See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_iso8601() {
+        assert_eq!(
+            format_iso8601(UNIX_EPOCH).as_deref(),
+            Some("1970-01-01T00:00:00.000Z")
+        );
+        assert_eq!(
+            format_iso8601(UNIX_EPOCH + Duration::from_millis(1_709_211_909_123)).as_deref(),
+            Some("2024-02-29T13:05:09.123Z")
+        );
+        assert_eq!(format_iso8601(UNIX_EPOCH - Duration::from_secs(1)), None);
+    }
+}
@@ -0,0 +1,19 @@
+use subtle::ConstantTimeEq;
+
+/// Compares a presented token in constant time, so the response time does not reveal the token.
+pub fn is_token_equal(presented: &str, token: &str) -> bool {
+    presented.as_bytes().ct_eq(token.as_bytes()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_equal() {
+        assert!(is_token_equal("secret", "secret"));
+        assert!(!is_token_equal("secreT", "secret"));
+        assert!(!is_token_equal("secret2", "secret"));
+        assert!(!is_token_equal("", "secret"));
+    }
+}
@@ -0,0 +1,219 @@
+use bytes::Bytes;
+use std::future::Future;
+use std::io;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::OnceLock;
+use tokio::sync::{mpsc, oneshot};
+use tokio_uring::buf::IoBuf;
+
+/// Submission queue entries of the ring shared by all files.
+const RING_ENTRIES: u32 = 256;
+
+type Job = Box<dyn FnOnce() -> Pin<Box<dyn Future<Output = ()>>> + Send>;
+
+/// Sends jobs to the thread running the io_uring runtime.
+/// `None` if io_uring is unavailable, e.g. on old kernels or blocked by seccomp in containers.
+static WORKER: OnceLock<Option<mpsc::UnboundedSender<Job>>> = OnceLock::new();
+
+/// Whether file I/O can go through io_uring. Starts the io_uring thread on first use.
+pub(crate) fn is_available() -> bool {
+    WORKER.get_or_init(start_worker).is_some()
+}
+
+fn start_worker() -> Option<mpsc::UnboundedSender<Job>> {
+    let (job_tx, mut job_rx) = mpsc::unbounded_channel::<Job>();
+    let (ready_tx, ready_rx) = std::sync::mpsc::channel();
+
+    // tokio-uring needs a runtime of its own, the files are only accessible on its thread.
+    let spawned = std::thread::Builder::new()
+        .name("localsend-io-uring".to_string())
+        .spawn(move || {
+            let runtime =
+                match tokio_uring::Runtime::new(tokio_uring::builder().entries(RING_ENTRIES)) {
+                    Ok(runtime) => runtime,
+                    Err(e) => {
+                        let _ = ready_tx.send(Err(e));
+                        return;
+                    }
+                };
+            let _ = ready_tx.send(Ok(()));
+            runtime.block_on(async move {
+                while let Some(job) = job_rx.recv().await {
+                    tokio_uring::spawn(job());
+                }
+            });
+        });
+
+    let ready = match spawned {
+        Ok(_) => ready_rx.recv().unwrap_or_else(|_| Err(stopped())),
+        Err(e) => Err(e),
+    };
+    match ready {
+        Ok(()) => Some(job_tx),
+        Err(e) => {
+            tracing::warn!("io_uring is unavailable, using regular file I/O: {e}");
+            None
+        }
+    }
+}
+
+fn spawn<F, Fut>(job: F)
+where
+    F: FnOnce() -> Fut + Send + 'static,
+    Fut: Future<Output = ()> + 'static,
+{
+    let job: Job = Box::new(move || Box::pin(job()));
+    let sent = WORKER
+        .get()
+        .and_then(Option::as_ref)
+        .is_some_and(|worker| worker.send(job).is_ok());
+    if !sent {
+        tracing::error!("The io_uring thread is not running");
+    }
+}
+
+fn stopped() -> io::Error {
+    io::Error::other("The io_uring thread stopped")
+}
+
+/// Reads `file` to EOF on the io_uring thread, forwarding chunks of at most `buffer_size` bytes
+/// on `tx`. Requires [`is_available`].
+pub(crate) fn read_file(file: std::fs::File, buffer_size: usize, tx: mpsc::Sender<Bytes>) {
+    spawn(move || async move {
+        let file = tokio_uring::fs::File::from_std(file);
+        let mut position = 0;
+        loop {
+            let (result, buffer) = file
+                .read_at(Vec::with_capacity(buffer_size), position)
+                .await;
+            match result {
+                Ok(0) => break,
+                Ok(n) => {
+                    position += n as u64;
+                    if tx.send(Bytes::from(buffer)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("Failed to read file content: {e}");
+                    break;
+                }
+            }
+        }
+        let _ = file.close().await;
+        tracing::info!("Finished reading file content via io_uring ({position} bytes)");
+    });
+}
+
+enum Operation {
+    Write(Vec<u8>, oneshot::Sender<io::Result<()>>),
+    Sync(oneshot::Sender<io::Result<()>>),
+}
+
+/// A file written sequentially on the io_uring thread.
+///
+/// One write is in flight at a time, so the next buffer can be filled meanwhile.
+/// The file is closed once this is dropped.
+pub(crate) struct UringFile {
+    operation_tx: mpsc::Sender<Operation>,
+    pending: Option<oneshot::Receiver<io::Result<()>>>,
+}
+
+impl UringFile {
+    /// Creates or truncates the file. Requires [`is_available`].
+    pub(crate) async fn create(path: PathBuf) -> io::Result<Self> {
+        let (operation_tx, operation_rx) = mpsc::channel(1);
+        let (created_tx, created_rx) = oneshot::channel();
+        spawn(move || async move {
+            match tokio_uring::fs::File::create(&path).await {
+                Ok(file) => {
+                    let _ = created_tx.send(Ok(()));
+                    serve(file, operation_rx).await;
+                }
+                Err(e) => {
+                    let _ = created_tx.send(Err(e));
+                }
+            }
+        });
+        created_rx.await.map_err(|_| stopped())??;
+
+        Ok(Self {
+            operation_tx,
+            pending: None,
+        })
+    }
+
+    /// Queues `buffer` to be written after the previous one, waiting for the previous one only.
+    pub(crate) async fn write(&mut self, buffer: Vec<u8>) -> io::Result<()> {
+        self.flush().await?;
+        let (done_tx, done_rx) = oneshot::channel();
+        self.operation_tx
+            .send(Operation::Write(buffer, done_tx))
+            .await
+            .map_err(|_| stopped())?;
+        self.pending = Some(done_rx);
+        Ok(())
+    }
+
+    /// Waits until the queued write has completed.
+    pub(crate) async fn flush(&mut self) -> io::Result<()> {
+        match self.pending.take() {
+            Some(done_rx) => done_rx.await.map_err(|_| stopped())?,
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) async fn sync_all(&mut self) -> io::Result<()> {
+        self.flush().await?;
+        let (done_tx, done_rx) = oneshot::channel();
+        self.operation_tx
+            .send(Operation::Sync(done_tx))
+            .await
+            .map_err(|_| stopped())?;
+        done_rx.await.map_err(|_| stopped())?
+    }
+}
+
+/// Performs the operations on the file until the [`UringFile`] is dropped or a write fails.
+async fn serve(file: tokio_uring::fs::File, mut operation_rx: mpsc::Receiver<Operation>) {
+    let mut position = 0;
+    while let Some(operation) = operation_rx.recv().await {
+        match operation {
+            Operation::Write(buffer, done_tx) => {
+                let length = buffer.len() as u64;
+                let result = write_all_at(&file, buffer, position).await;
+                let failed = result.is_err();
+                let _ = done_tx.send(result);
+                if failed {
+                    // The position of later writes would be wrong.
+                    break;
+                }
+                position += length;
+            }
+            Operation::Sync(done_tx) => {
+                let _ = done_tx.send(file.sync_all().await);
+            }
+        }
+    }
+    let _ = file.close().await;
+}
+
+async fn write_all_at(
+    file: &tokio_uring::fs::File,
+    mut buffer: Vec<u8>,
+    position: u64,
+) -> io::Result<()> {
+    let mut written = 0;
+    while written < buffer.len() {
+        let (result, slice) = file
+            .write_at(buffer.slice(written..), position + written as u64)
+            .await;
+        buffer = slice.into_inner();
+        match result? {
+            0 => return Err(io::ErrorKind::WriteZero.into()),
+            n => written += n,
+        }
+    }
+    Ok(())
+}
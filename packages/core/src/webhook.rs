@@ -0,0 +1,213 @@
+//! Webhook notifications: transfer events POSTed as JSON to user-configured URLs,
+//! e.g. to trigger a Home Assistant automation once a file has been received.
+//!
+//! Deliveries are best effort: failures are logged, but never retried
+//! and never affect the transfer itself.
+
+use crate::model::discovery::DeviceType;
+use crate::model::transfer::FileDto;
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// How long to wait for a webhook endpoint to respond.
+pub const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The kind of a [`WebhookEvent`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WebhookEventType {
+    /// The receiver has accepted the files.
+    SessionStarted,
+
+    /// All files have been transferred.
+    SessionCompleted,
+
+    /// The session has been declined, cancelled or has failed.
+    SessionFailed,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TransferDirection {
+    Send,
+    Receive,
+}
+
+/// The other device of the session.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPeer {
+    pub alias: String,
+    pub fingerprint: Option<String>,
+    pub device_model: Option<String>,
+    pub device_type: Option<DeviceType>,
+}
+
+#[cfg(feature = "discovery")]
+impl From<&crate::discovery::DiscoveredPeer> for WebhookPeer {
+    fn from(peer: &crate::discovery::DiscoveredPeer) -> Self {
+        Self {
+            alias: peer.alias.clone(),
+            fingerprint: Some(peer.fingerprint.clone()),
+            device_model: peer.device_model.clone(),
+            device_type: peer.device_type.clone(),
+        }
+    }
+}
+
+/// A file of the session. The content is never included.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookFile {
+    pub file_name: String,
+    pub size: u64,
+    pub file_type: String,
+}
+
+impl From<&FileDto> for WebhookFile {
+    fn from(file: &FileDto) -> Self {
+        Self {
+            file_name: file.file_name.clone(),
+            size: file.size,
+            file_type: file.file_type.clone(),
+        }
+    }
+}
+
+/// The JSON body POSTed to the webhooks.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEvent {
+    pub event: WebhookEventType,
+    pub session_id: String,
+    pub direction: TransferDirection,
+    pub peer: WebhookPeer,
+    pub files: Vec<WebhookFile>,
+
+    /// Why the session has failed. Only set for [`WebhookEventType::SessionFailed`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// When the event occurred, as ISO 8601 in UTC.
+    pub timestamp: String,
+}
+
+impl WebhookEvent {
+    /// Creates an event that occurred now.
+    pub fn new(
+        event: WebhookEventType,
+        session_id: String,
+        direction: TransferDirection,
+        peer: WebhookPeer,
+        files: Vec<WebhookFile>,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            event,
+            session_id,
+            direction,
+            peer,
+            files,
+            error,
+            timestamp: crate::util::time::format_iso8601(SystemTime::now()).unwrap_or_default(),
+        }
+    }
+}
+
+/// Delivers [`WebhookEvent`]s to the configured URLs.
+///
+/// Cheap to clone, so it can be shared between sessions.
+#[derive(Clone)]
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    urls: Arc<[reqwest::Url]>,
+}
+
+impl WebhookNotifier {
+    /// Creates a notifier for the given `http` or `https` URLs,
+    /// e.g. [`Settings::webhooks`](crate::settings::Settings::webhooks).
+    ///
+    /// Contrary to the LocalSend clients, the certificates of the endpoints are verified.
+    pub fn try_new(urls: &[String]) -> anyhow::Result<Self> {
+        let urls = urls
+            .iter()
+            .map(|url| {
+                let parsed = reqwest::Url::parse(url)
+                    .map_err(|e| anyhow::anyhow!("Invalid webhook URL {url}: {e}"))?;
+                if !matches!(parsed.scheme(), "http" | "https") {
+                    anyhow::bail!("Invalid webhook URL {url}: unsupported scheme");
+                }
+                Ok(parsed)
+            })
+            .collect::<anyhow::Result<Arc<[_]>>>()?;
+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let client = reqwest::Client::builder()
+            .use_rustls_tls()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()?;
+
+        Ok(Self { client, urls })
+    }
+
+    /// Whether there is any URL to deliver to.
+    pub fn is_empty(&self) -> bool {
+        self.urls.is_empty()
+    }
+
+    /// Delivers the event in the background.
+    pub fn notify(&self, event: WebhookEvent) {
+        if self.is_empty() {
+            return;
+        }
+
+        let notifier = self.clone();
+        tokio::spawn(async move { notifier.deliver(&event).await });
+    }
+
+    /// Delivers the event to all URLs concurrently.
+    /// Returns the number of endpoints that responded with a success status.
+    pub async fn deliver(&self, event: &WebhookEvent) -> usize {
+        let deliveries = self.urls.iter().map(|url| async move {
+            let result = self
+                .client
+                .post(url.clone())
+                .json(event)
+                .send()
+                .await
+                .and_then(|response| response.error_for_status());
+
+            match result {
+                Ok(_) => true,
+                Err(e) => {
+                    // The URL may contain a secret, e.g. a Home Assistant webhook ID.
+                    tracing::warn!(
+                        "Failed to deliver webhook to {}: {}",
+                        url.host_str().unwrap_or_default(),
+                        e.without_url()
+                    );
+                    false
+                }
+            }
+        });
+
+        futures_util::future::join_all(deliveries)
+            .await
+            .into_iter()
+            .filter(|delivered| *delivered)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_urls() {
+        assert!(WebhookNotifier::try_new(&["https://example.com/hook".to_string()]).is_ok());
+        assert!(WebhookNotifier::try_new(&["example.com/hook".to_string()]).is_err());
+        assert!(WebhookNotifier::try_new(&["ftp://example.com/hook".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::webrtc::pairing::PairingPayload;
+use anyhow::Result;
+use uuid::Uuid;
+
+/// Prefix of a deep link inviting the opener to receive files from a peer.
+const RECEIVE_LINK_PREFIX: &str = "localsend://receive";
+
+/// A `localsend://receive?...` link, e.g. shared via chat so that the opener
+/// connects to the peer and receives files from it.
+///
+/// Contrary to [`PairingPayload`], the parameters are readable in plain text.
+/// Unknown parameters are ignored so newer links stay readable by older clients.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReceiveLink {
+    /// The URL of the signaling server the peer is connected to.
+    pub signaling_url: String,
+
+    /// The id assigned to the peer by the signaling server.
+    pub peer_id: Uuid,
+
+    /// The code of the room the peer is waiting in, if any.
+    pub room: Option<String>,
+
+    /// The fingerprint of the peer, used to verify the peer after connecting.
+    pub fingerprint: String,
+}
+
+impl ReceiveLink {
+    /// Encodes the link, e.g. `localsend://receive?server=wss%3A%2F%2F...&peer=...&fingerprint=...`.
+    pub fn encode(&self) -> String {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        query.append_pair("server", &self.signaling_url);
+        query.append_pair("peer", &self.peer_id.to_string());
+        if let Some(room) = &self.room {
+            query.append_pair("room", room);
+        }
+        query.append_pair("fingerprint", &self.fingerprint);
+        format!("{RECEIVE_LINK_PREFIX}?{}", query.finish())
+    }
+
+    /// Reverses [`encode`](Self::encode).
+    /// The scheme is case-insensitive; leading and trailing whitespace is ignored.
+    pub fn decode(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let query = s
+            .get(..RECEIVE_LINK_PREFIX.len())
+            .filter(|prefix| prefix.eq_ignore_ascii_case(RECEIVE_LINK_PREFIX))
+            .map(|_| &s[RECEIVE_LINK_PREFIX.len()..])
+            .and_then(|rest| rest.strip_prefix('/').unwrap_or(rest).strip_prefix('?'))
+            .ok_or_else(|| anyhow::anyhow!("Not a LocalSend receive link"))?;
+
+        let mut signaling_url = None;
+        let mut peer_id = None;
+        let mut room = None;
+        let mut fingerprint = None;
+        for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+            match key.as_ref() {
+                "server" => signaling_url = Some(value.into_owned()),
+                "peer" => peer_id = Some(Uuid::parse_str(&value)?),
+                "room" => room = Some(value.into_owned()),
+                "fingerprint" => fingerprint = Some(value.into_owned()),
+                _ => tracing::debug!("Ignoring unknown deep link parameter: {key}"),
+            }
+        }
+
+        Ok(Self {
+            signaling_url: signaling_url.ok_or_else(|| anyhow::anyhow!("Missing server"))?,
+            peer_id: peer_id.ok_or_else(|| anyhow::anyhow!("Missing peer"))?,
+            room,
+            fingerprint: fingerprint.ok_or_else(|| anyhow::anyhow!("Missing fingerprint"))?,
+        })
+    }
+}
+
+impl From<ReceiveLink> for PairingPayload {
+    fn from(link: ReceiveLink) -> Self {
+        PairingPayload {
+            signaling_url: link.signaling_url,
+            room: link.room,
+            peer_id: link.peer_id,
+            fingerprint: link.fingerprint,
+            psk: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link() -> ReceiveLink {
+        ReceiveLink {
+            signaling_url: "wss://public.localsend.org/v1/ws?a=1&b=2".to_string(),
+            peer_id: Uuid::new_v4(),
+            room: Some("ABC 123".to_string()),
+            fingerprint: "abc+/=".to_string(),
+        }
+    }
+
+    #[test]
+    fn link_roundtrip() {
+        let link = link();
+        let encoded = link.encode();
+        assert!(encoded.starts_with("localsend://receive?server=wss%3A%2F%2F"));
+        assert_eq!(ReceiveLink::decode(&encoded).unwrap(), link);
+
+        let without_room = ReceiveLink { room: None, ..link };
+        let encoded = without_room.encode();
+        assert!(!encoded.contains("room="));
+        assert_eq!(ReceiveLink::decode(&encoded).unwrap(), without_room);
+    }
+
+    #[test]
+    fn decode_is_lenient() {
+        let link = link();
+        let encoded = link
+            .encode()
+            .replace("localsend://receive?", "LocalSend://receive/?");
+        let decoded = ReceiveLink::decode(&format!(" {encoded}&future=1\n")).unwrap();
+        assert_eq!(decoded, link);
+    }
+
+    #[test]
+    fn rejects_invalid_links() {
+        assert!(ReceiveLink::decode("https://localsend.org").is_err());
+        assert!(ReceiveLink::decode("localsend://send?server=a").is_err());
+        assert!(ReceiveLink::decode("localsend://receive?server=a&fingerprint=b").is_err());
+        assert!(ReceiveLink::decode("localsend://receive?server=a&peer=x&fingerprint=b").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+use crate::webrtc::signaling::{
+    ClientInfo, ClientInfoWithoutId, ConnectOptions, SignalingConnection, WsClientMessage,
+    WsClientSdpMessage, WsServerMessage,
+};
+use anyhow::Result;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+use uuid::Uuid;
+
+pub struct FailoverConfig {
+    /// The signaling servers ordered by priority (the first one is the primary).
+    pub uris: Vec<String>,
+
+    /// The timeout for a single connection attempt.
+    pub connect_timeout: Duration,
+
+    /// The delay before trying again after all servers were unreachable.
+    pub retry_interval: Duration,
+
+    /// How often to check whether a server with higher priority is reachable again
+    /// while connected to a fallback server.
+    pub fail_back_interval: Duration,
+
+    /// Allows plain `ws://` URIs. See [`ConnectOptions::allow_insecure`].
+    pub allow_insecure: bool,
+
+    /// Presented to every server. See [`ConnectOptions::access_token`].
+    pub access_token: Option<String>,
+}
+
+impl FailoverConfig {
+    pub fn new(uris: Vec<String>) -> Self {
+        Self {
+            uris,
+            connect_timeout: Duration::from_secs(10),
+            retry_interval: Duration::from_secs(5),
+            fail_back_interval: Duration::from_secs(60),
+            allow_insecure: false,
+            access_token: None,
+        }
+    }
+}
+
+/// The signaling server a [`FailoverSignalingConnection`] is currently connected to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActiveServer {
+    /// The index in [`FailoverConfig::uris`].
+    pub index: usize,
+
+    pub uri: String,
+
+    /// The peer info received from this server.
+    /// The peer ID changes when switching servers.
+    pub client: ClientInfo,
+}
+
+/// A signaling connection that uses the first reachable server of a prioritized list.
+///
+/// If the active server disconnects, the servers are tried again in priority order.
+/// While connected to a fallback server, the servers with higher priority are probed
+/// periodically and the connection switches back once one of them is reachable.
+///
+/// Every switch is announced with a new [`WsServerMessage::Hello`] on the message stream.
+/// Messages sent while no server is reachable are dropped.
+pub struct FailoverSignalingConnection {
+    tx: mpsc::Sender<WsClientMessage>,
+    active: watch::Receiver<Option<ActiveServer>>,
+}
+
+impl FailoverSignalingConnection {
+    /// Starts connecting in the background.
+    /// The connection is closed once the returned receiver is dropped.
+    pub fn connect(
+        config: FailoverConfig,
+        info: ClientInfoWithoutId,
+    ) -> (FailoverSignalingConnection, mpsc::Receiver<WsServerMessage>) {
+        let (tx, client_rx) = mpsc::channel(16);
+        let (server_tx, server_rx) = mpsc::channel(16);
+        let (active_tx, active_rx) = watch::channel(None);
+
+        tokio::spawn(run(config, info, client_rx, server_tx, active_tx));
+
+        (
+            FailoverSignalingConnection {
+                tx,
+                active: active_rx,
+            },
+            server_rx,
+        )
+    }
+
+    /// Returns the server currently connected to.
+    /// `None` if no server is reachable at the moment.
+    pub fn active(&self) -> Option<ActiveServer> {
+        self.active.borrow().clone()
+    }
+
+    /// Returns a receiver that is notified whenever the active server changes.
+    pub fn watch_active(&self) -> watch::Receiver<Option<ActiveServer>> {
+        self.active.clone()
+    }
+
+    /// Sends the update to the active server.
+    /// The update is also used when connecting to another server later on.
+    pub async fn send_update(&self, info: ClientInfoWithoutId) -> Result<()> {
+        self.tx.send(WsClientMessage::Update { info }).await?;
+
+        Ok(())
+    }
+
+    pub async fn send_offer(&self, session_id: String, target: Uuid, sdp: String) -> Result<()> {
+        self.tx
+            .send(WsClientMessage::Offer(WsClientSdpMessage {
+                session_id,
+                target,
+                sdp,
+            }))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn send_answer(&self, session_id: String, target: Uuid, sdp: String) -> Result<()> {
+        self.tx
+            .send(WsClientMessage::Answer(WsClientSdpMessage {
+                session_id,
+                target,
+                sdp,
+            }))
+            .await?;
+
+        Ok(())
+    }
+}
+
+async fn run(
+    config: FailoverConfig,
+    mut info: ClientInfoWithoutId,
+    mut client_rx: mpsc::Receiver<WsClientMessage>,
+    server_tx: mpsc::Sender<WsServerMessage>,
+    active_tx: watch::Sender<Option<ActiveServer>>,
+) {
+    loop {
+        let Some((mut index, mut connection)) = connect_first(&config, &config.uris, &info).await
+        else {
+            tokio::select! {
+                _ = tokio::time::sleep(config.retry_interval) => continue,
+                _ = server_tx.closed() => return,
+            }
+        };
+
+        publish_active(&active_tx, &config, index, &connection);
+
+        let mut fail_back = tokio::time::interval(config.fail_back_interval);
+        fail_back.tick().await;
+
+        loop {
+            tokio::select! {
+                message = connection.rx.recv() => {
+                    let Some(message) = message else {
+                        tracing::warn!("Lost connection to signaling server {}", config.uris[index]);
+                        let _ = active_tx.send(None);
+                        break;
+                    };
+
+                    if server_tx.send(message).await.is_err() {
+                        return;
+                    }
+                }
+                message = client_rx.recv() => {
+                    let Some(message) = message else {
+                        return;
+                    };
+
+                    if let WsClientMessage::Update { info: updated } = &message {
+                        info = updated.clone();
+                    }
+
+                    if connection.tx.send(message).await.is_err() {
+                        tracing::warn!("Dropped message to signaling server {}", config.uris[index]);
+                    }
+                }
+                _ = fail_back.tick(), if index > 0 => {
+                    if let Some((new_index, new_connection)) =
+                        connect_first(&config, &config.uris[..index], &info).await
+                    {
+                        tracing::info!(
+                            "Switching back from signaling server {} to {}",
+                            config.uris[index],
+                            config.uris[new_index]
+                        );
+                        index = new_index;
+                        connection = new_connection;
+                        publish_active(&active_tx, &config, index, &connection);
+                    }
+                }
+                _ = server_tx.closed() => return,
+            }
+        }
+    }
+}
+
+/// Connects to the first reachable server of `uris`.
+async fn connect_first(
+    config: &FailoverConfig,
+    uris: &[String],
+    info: &ClientInfoWithoutId,
+) -> Option<(usize, SignalingConnection)> {
+    let options = ConnectOptions {
+        allow_insecure: config.allow_insecure,
+        access_token: config.access_token.clone(),
+        ..ConnectOptions::default()
+    };
+
+    for (index, uri) in uris.iter().enumerate() {
+        match tokio::time::timeout(
+            config.connect_timeout,
+            SignalingConnection::connect_with_options(uri, info, &options),
+        )
+        .await
+        {
+            Ok(Ok(connection)) => return Some((index, connection)),
+            Ok(Err(e)) => tracing::debug!("Signaling server {uri} is unreachable: {e}"),
+            Err(_) => tracing::debug!("Signaling server {uri} timed out"),
+        }
+    }
+
+    None
+}
+
+fn publish_active(
+    active_tx: &watch::Sender<Option<ActiveServer>>,
+    config: &FailoverConfig,
+    index: usize,
+    connection: &SignalingConnection,
+) {
+    let _ = active_tx.send(Some(ActiveServer {
+        index,
+        uri: config.uris[index].clone(),
+        client: connection.client.clone(),
+    }));
+}
@@ -1,3 +1,14 @@
+#[cfg(feature = "webrtc-signaling")]
+pub mod deep_link;
+#[cfg(feature = "webrtc-signaling")]
+pub mod failover;
+pub mod pairing;
+pub mod proof_of_work;
+#[cfg(any(feature = "webrtc", feature = "wasm"))]
+pub mod sdp;
 pub mod signaling;
+#[cfg(feature = "wasm")]
+pub mod web;
 #[cfg(feature = "webrtc")]
 pub mod webrtc;
+pub mod ws_relay;
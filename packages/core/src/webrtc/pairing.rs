@@ -0,0 +1,116 @@
+use crate::util::base64;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Prefix of an encoded pairing payload.
+/// The number is increased when the format changes incompatibly.
+const PAYLOAD_PREFIX: &str = "LS1:";
+
+/// Everything needed to connect to a peer after scanning its QR code.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PairingPayload {
+    /// The URL of the signaling server the peer is connected to.
+    #[serde(rename = "u")]
+    pub signaling_url: String,
+
+    /// The room on the signaling server, if the server uses explicit rooms.
+    #[serde(rename = "r", default, skip_serializing_if = "Option::is_none")]
+    pub room: Option<String>,
+
+    /// The id assigned to the peer by the signaling server.
+    #[serde(rename = "i")]
+    pub peer_id: Uuid,
+
+    /// The fingerprint of the peer, used to verify the peer after connecting.
+    #[serde(rename = "f")]
+    pub fingerprint: String,
+
+    /// An optional pre-shared key to authenticate the connection.
+    #[serde(
+        rename = "k",
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "psk_base64"
+    )]
+    pub psk: Option<Vec<u8>>,
+}
+
+impl PairingPayload {
+    /// Encodes the payload into a compact string suitable for QR codes.
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("Failed to serialize pairing payload");
+        format!("{PAYLOAD_PREFIX}{}", base64::encode(json))
+    }
+
+    /// Reverses [`encode`](Self::encode).
+    /// Leading and trailing whitespace is ignored.
+    pub fn decode(s: &str) -> Result<Self> {
+        let Some(encoded) = s.trim().strip_prefix(PAYLOAD_PREFIX) else {
+            return Err(anyhow::anyhow!("Not a LocalSend pairing payload"));
+        };
+        let json = base64::decode(encoded)
+            .map_err(|e| anyhow::anyhow!("Base64 decode of pairing payload failed: {e}"))?;
+        Ok(serde_json::from_slice(&json)?)
+    }
+}
+
+mod psk_base64 {
+    use crate::util::base64;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(psk) => serializer.serialize_str(&base64::encode(psk)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|value| base64::decode(&value).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_roundtrip() {
+        let payload = PairingPayload {
+            signaling_url: "wss://public.localsend.org/v1/ws".to_string(),
+            room: Some("living-room".to_string()),
+            peer_id: Uuid::new_v4(),
+            fingerprint: "abc".to_string(),
+            psk: Some(vec![1, 2, 3, 255]),
+        };
+
+        let encoded = payload.encode();
+        assert!(encoded.starts_with(PAYLOAD_PREFIX));
+        assert_eq!(PairingPayload::decode(&encoded).unwrap(), payload);
+
+        let minimal = PairingPayload {
+            room: None,
+            psk: None,
+            ..payload
+        };
+        assert_eq!(
+            PairingPayload::decode(&format!(" {}\n", minimal.encode())).unwrap(),
+            minimal
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_payloads() {
+        assert!(PairingPayload::decode("https://localsend.org").is_err());
+        assert!(PairingPayload::decode("LS1:!!!").is_err());
+        assert!(PairingPayload::decode(&format!("LS1:{}", base64::encode("{}"))).is_err());
+    }
+}
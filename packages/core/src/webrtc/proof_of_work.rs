@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+/// The highest difficulty a client solves. Solving takes about 2^`difficulty` hashes,
+/// so a server could otherwise keep the client busy for years.
+pub const MAX_DIFFICULTY: u8 = 32;
+
+/// The body of an upgrade rejected with [`SignalingErrorCode::ProofOfWorkRequired`].
+///
+/// The client has to find a nonce so that the SHA-256 hash of `<challenge>:<nonce>`
+/// starts with `difficulty` zero bits, then connect again with the query parameters
+/// `c=<challenge>&n=<nonce>`.
+///
+/// [`SignalingErrorCode::ProofOfWorkRequired`]: crate::webrtc::signaling::SignalingErrorCode::ProofOfWorkRequired
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct ProofOfWorkChallenge {
+    pub challenge: String,
+    pub difficulty: u8,
+}
+
+/// The input of the hash for a nonce.
+pub fn hash_input(challenge: &str, nonce: u64) -> String {
+    format!("{challenge}:{nonce}")
+}
+
+/// Counts the zero bits at the start of the hash.
+pub fn leading_zero_bits(hash: &[u8]) -> u32 {
+    let mut bits = 0;
+    for byte in hash {
+        bits += byte.leading_zeros();
+        if *byte != 0 {
+            break;
+        }
+    }
+    bits
+}
+
+/// Finds the first nonce solving the challenge.
+/// Each additional bit of difficulty doubles the expected work.
+#[cfg(feature = "webrtc-signaling")]
+pub fn solve(challenge: &ProofOfWorkChallenge) -> u64 {
+    use sha2::{Digest, Sha256};
+
+    (0..)
+        .find(|nonce| {
+            let hash = Sha256::digest(hash_input(&challenge.challenge, *nonce));
+            leading_zero_bits(&hash) >= u32::from(challenge.difficulty)
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn zero_bits() {
+        assert_eq!(leading_zero_bits(&[0xff, 0x00]), 0);
+        assert_eq!(leading_zero_bits(&[0x00, 0x10]), 11);
+        assert_eq!(leading_zero_bits(&[0x00, 0x00]), 16);
+    }
+
+    #[cfg(feature = "webrtc-signaling")]
+    #[test]
+    fn solve_challenge() {
+        use sha2::{Digest, Sha256};
+
+        let challenge = ProofOfWorkChallenge {
+            challenge: "abc".to_string(),
+            difficulty: 12,
+        };
+        let nonce = solve(&challenge);
+        let hash = Sha256::digest(hash_input("abc", nonce));
+        assert!(leading_zero_bits(&hash) >= 12);
+    }
+}
@@ -0,0 +1,30 @@
+use crate::util::base64;
+use anyhow::Result;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use std::io::{Read, Write};
+
+/// Compresses an SDP with zlib and encodes it with base64 (url-safe without padding).
+/// This is the format exchanged via the signaling server and in manual (out of band) exchanges.
+pub fn encode_sdp(s: &str) -> String {
+    let mut e = ZlibEncoder::new(Vec::new(), flate2::Compression::best());
+    e.write_all(s.as_bytes())
+        .map_err(|e| anyhow::anyhow!("Failed to compress SDP: {e}"))
+        .unwrap();
+    let compressed = e
+        .finish()
+        .map_err(|e| anyhow::anyhow!("Failed to finish compression of SDP: {e}"))
+        .unwrap();
+    base64::encode(&compressed)
+}
+
+/// Reverses [`encode_sdp`].
+pub fn decode_sdp(s: &str) -> Result<String> {
+    let decoded_data =
+        base64::decode(s).map_err(|e| anyhow::anyhow!("Base64 decode of SDP failed: {e}"))?;
+    let mut d = ZlibDecoder::new(&*decoded_data);
+    let mut result = String::new();
+    d.read_to_string(&mut result)
+        .map_err(|e| anyhow::anyhow!("Failed to decompress SDP: {e}"))?;
+    Ok(result)
+}
@@ -1,17 +1,37 @@
 use crate::model::discovery::DeviceType;
+#[cfg(any(feature = "webrtc-signaling", feature = "wasm"))]
 use crate::util::base64;
+#[cfg(feature = "webrtc-signaling")]
+use crate::webrtc::proof_of_work::{self, ProofOfWorkChallenge};
+#[cfg(any(feature = "webrtc-signaling", feature = "wasm"))]
 use anyhow::Result;
+#[cfg(feature = "webrtc-signaling")]
 use futures_util::stream::StreamExt;
+#[cfg(feature = "webrtc-signaling")]
 use futures_util::SinkExt;
 use serde::{Deserialize, Serialize};
 use std::cmp::PartialEq;
+#[cfg(feature = "webrtc-signaling")]
 use std::collections::HashMap;
+#[cfg(feature = "webrtc-signaling")]
 use std::sync::Arc;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::mpsc;
+#[cfg(feature = "webrtc-signaling")]
+use tokio::sync::{broadcast, oneshot, watch, Mutex};
 use tokio::time::Duration;
 #[cfg(feature = "webrtc-signaling")]
 use tokio_tungstenite::connect_async;
 #[cfg(feature = "webrtc-signaling")]
+use tungstenite::client::IntoClientRequest;
+#[cfg(feature = "webrtc-signaling")]
+use tungstenite::error::{ProtocolError, SubProtocolError};
+#[cfg(feature = "webrtc-signaling")]
+use tungstenite::handshake::client::Request as ClientRequest;
+#[cfg(feature = "webrtc-signaling")]
+use tungstenite::http::header::{AUTHORIZATION, SEC_WEBSOCKET_PROTOCOL};
+#[cfg(feature = "webrtc-signaling")]
+use tungstenite::http::HeaderValue;
+#[cfg(feature = "webrtc-signaling")]
 use tungstenite::{Bytes, Message};
 use uuid::Uuid;
 
@@ -20,12 +40,28 @@ use uuid::Uuid;
 #[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WsServerMessage {
     /// The initial message sent to the client that has just connected.
+    /// Sent again after the client has switched rooms.
     Hello {
         /// The client that has just connected.
         client: ClientInfo,
 
-        /// The list of members (excluding the client) in the IP room.
+        /// The list of members (excluding the client) in the IP room
+        /// or in the joined room.
         peers: Vec<ClientInfo>,
+
+        /// A token to present on reconnect to get the same peer ID again.
+        /// Missing if the server does not support session resumption.
+        #[serde(
+            rename = "resumeToken",
+            default,
+            skip_serializing_if = "Option::is_none"
+        )]
+        resume_token: Option<String>,
+
+        /// The code of the joined room.
+        /// Missing if the client is in the IP room.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        room: Option<String>,
     },
 
     /// A new peer has joined the IP room.
@@ -55,9 +91,202 @@ pub enum WsServerMessage {
 
     /// Error message.
     Error {
-        /// The error code.
+        /// The error code. See [`SignalingErrorCode`].
         code: u16,
     },
+
+    /// A notice of the server operator sent to all connected peers.
+    Announcement {
+        kind: AnnouncementKind,
+
+        /// The text to show to the user.
+        message: String,
+    },
+}
+
+/// The purpose of a [`WsServerMessage::Announcement`].
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AnnouncementKind {
+    Info,
+
+    /// The server will be unavailable for a while, e.g. because of an update.
+    Maintenance,
+
+    /// The client uses a protocol version or feature that will stop working.
+    Deprecation,
+
+    /// Sent by a newer server.
+    #[serde(other)]
+    Other,
+}
+
+/// The error codes sent in [`WsServerMessage::Error`].
+///
+/// The codes are based on HTTP status codes.
+/// Codes unknown to this client are preserved in [`SignalingErrorCode::Other`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SignalingErrorCode {
+    /// The client sent a message the server could not parse.
+    InvalidMessage,
+
+    /// The maximum number of connections in the IP room has been reached.
+    RoomFull,
+
+    /// The client sent too many requests.
+    RateLimited,
+
+    /// The network of the client has too many open connections to the server.
+    TooManyConnections,
+
+    /// The protocol version of the client is not supported by the server.
+    UnsupportedVersion,
+
+    /// There is no room with the given code.
+    RoomNotFound,
+
+    /// The room requires a password and none or a wrong one has been given.
+    WrongPassword,
+
+    /// The peer has been disconnected or banned by the server operator.
+    Forbidden,
+
+    /// The message (e.g. an SDP) exceeds the size limit of the server.
+    PayloadTooLarge,
+
+    /// The server requires a proof of work before accepting the connection.
+    /// See [`ProofOfWorkChallenge`](crate::webrtc::proof_of_work::ProofOfWorkChallenge).
+    ProofOfWorkRequired,
+
+    /// The server is shutting down and closes the connection.
+    /// The client should reconnect (possibly to another server).
+    ServerShutdown,
+
+    /// Any other code.
+    Other(u16),
+}
+
+impl SignalingErrorCode {
+    pub fn code(&self) -> u16 {
+        match self {
+            SignalingErrorCode::InvalidMessage => 400,
+            SignalingErrorCode::WrongPassword => 401,
+            SignalingErrorCode::Forbidden => 403,
+            SignalingErrorCode::RoomNotFound => 404,
+            SignalingErrorCode::RoomFull => 409,
+            SignalingErrorCode::PayloadTooLarge => 413,
+            SignalingErrorCode::UnsupportedVersion => 426,
+            SignalingErrorCode::ProofOfWorkRequired => 428,
+            SignalingErrorCode::RateLimited => 429,
+            SignalingErrorCode::TooManyConnections => 430,
+            SignalingErrorCode::ServerShutdown => 503,
+            SignalingErrorCode::Other(code) => *code,
+        }
+    }
+
+    /// The HTTP status of rejected upgrades. The code itself is sent in the JSON body.
+    /// Codes that are not standard HTTP statuses use the closest standard status,
+    /// e.g. `429` with a `Retry-After` header for [`SignalingErrorCode::TooManyConnections`].
+    pub fn http_status(&self) -> u16 {
+        match self {
+            SignalingErrorCode::TooManyConnections => 429,
+            code => code.code(),
+        }
+    }
+
+    /// Whether the same request may succeed when tried again later.
+    /// Unknown server errors (5xx) are considered retryable.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            SignalingErrorCode::RoomFull
+            | SignalingErrorCode::RateLimited
+            | SignalingErrorCode::TooManyConnections
+            | SignalingErrorCode::ServerShutdown => true,
+            SignalingErrorCode::InvalidMessage
+            | SignalingErrorCode::Forbidden
+            | SignalingErrorCode::RoomNotFound
+            | SignalingErrorCode::WrongPassword
+            | SignalingErrorCode::PayloadTooLarge
+            | SignalingErrorCode::UnsupportedVersion
+            | SignalingErrorCode::ProofOfWorkRequired => false,
+            SignalingErrorCode::Other(code) => *code >= 500,
+        }
+    }
+}
+
+impl From<u16> for SignalingErrorCode {
+    fn from(code: u16) -> Self {
+        match code {
+            400 => SignalingErrorCode::InvalidMessage,
+            401 => SignalingErrorCode::WrongPassword,
+            403 => SignalingErrorCode::Forbidden,
+            404 => SignalingErrorCode::RoomNotFound,
+            409 => SignalingErrorCode::RoomFull,
+            413 => SignalingErrorCode::PayloadTooLarge,
+            426 => SignalingErrorCode::UnsupportedVersion,
+            428 => SignalingErrorCode::ProofOfWorkRequired,
+            429 => SignalingErrorCode::RateLimited,
+            430 => SignalingErrorCode::TooManyConnections,
+            503 => SignalingErrorCode::ServerShutdown,
+            code => SignalingErrorCode::Other(code),
+        }
+    }
+}
+
+impl From<SignalingErrorCode> for WsServerMessage {
+    fn from(code: SignalingErrorCode) -> Self {
+        WsServerMessage::Error { code: code.code() }
+    }
+}
+
+impl WsServerMessage {
+    /// The oldest protocol version that knows this message.
+    pub fn min_protocol(&self) -> SignalingProtocol {
+        match self {
+            WsServerMessage::Announcement { .. } => SignalingProtocol::V2,
+            _ => SignalingProtocol::V1,
+        }
+    }
+}
+
+/// The version of the signaling protocol, negotiated as WebSocket subprotocol.
+/// Clients and servers that do not negotiate speak [`SignalingProtocol::V1`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum SignalingProtocol {
+    /// Peer list, offers, answers and errors.
+    V1,
+
+    /// Additionally rooms, session resumption, announcements and federated lookups.
+    V2,
+}
+
+impl SignalingProtocol {
+    /// The versions supported by this crate, newest first.
+    pub const SUPPORTED: [SignalingProtocol; 2] = [SignalingProtocol::V2, SignalingProtocol::V1];
+
+    /// The name used in the `Sec-WebSocket-Protocol` header.
+    pub fn subprotocol(&self) -> &'static str {
+        match self {
+            SignalingProtocol::V1 => "localsend.v1",
+            SignalingProtocol::V2 => "localsend.v2",
+        }
+    }
+
+    pub fn from_subprotocol(subprotocol: &str) -> Option<Self> {
+        Self::SUPPORTED
+            .into_iter()
+            .find(|p| p.subprotocol() == subprotocol)
+    }
+
+    /// Picks the newest supported version of the subprotocols offered by the client.
+    /// Each header value may contain a comma-separated list.
+    pub fn negotiate<'a>(header_values: impl IntoIterator<Item = &'a str>) -> Option<Self> {
+        header_values
+            .into_iter()
+            .flat_map(|v| v.split(','))
+            .filter_map(|p| Self::from_subprotocol(p.trim()))
+            .max()
+    }
 }
 
 #[derive(Clone, Deserialize, Eq, Serialize, Debug, PartialEq)]
@@ -98,6 +327,11 @@ pub struct ClientInfo {
     /// A token generated by the client.
     /// Used to merge the same peers detected on different channels (LAN, WebRTC, etc.).
     pub token: String,
+
+    /// Bitset of optional protocol features supported by the peer. See [`capability`].
+    /// Missing for older clients which is treated as no capabilities.
+    #[serde(default, skip_serializing_if = "capability::is_empty")]
+    pub capabilities: u32,
 }
 
 impl ClientInfo {
@@ -109,8 +343,52 @@ impl ClientInfo {
             device_model: info.device_model,
             device_type: info.device_type,
             token: info.token,
+            capabilities: info.capabilities,
         }
     }
+
+    /// Returns the features both this peer and the local client can use.
+    pub fn negotiate(&self, local: u32) -> u32 {
+        self.capabilities & local
+    }
+
+    /// Whether the peer announced all of the given capabilities.
+    pub fn supports(&self, capabilities: u32) -> bool {
+        self.capabilities & capabilities == capabilities
+    }
+}
+
+/// Bits of the optional protocol features announced in `capabilities`.
+///
+/// Unknown bits sent by newer clients are kept as-is,
+/// so a feature is only used if both sides announce it.
+pub mod capability {
+    /// Compression of the data channel payload.
+    /// Currently the messages of the sending peer carrying the file list.
+    pub const COMPRESSION: u32 = 1 << 0;
+
+    /// Multiple data channels per session (e.g. one per file).
+    pub const MULTI_CHANNEL: u32 = 1 << 1;
+
+    /// Transferring only the changed parts of a file.
+    pub const DELTA_SYNC: u32 = 1 << 2;
+
+    /// Connecting via a TURN relay if no direct connection is possible.
+    pub const TURN: u32 = 1 << 3;
+
+    /// Joining rooms by code, e.g. to invite a peer outside of the IP room.
+    pub const ROOMS: u32 = 1 << 4;
+
+    /// Exchanging data via the WebSocket of the signaling server if ICE fails.
+    /// See [`ws_relay`](crate::webrtc::ws_relay).
+    pub const WS_RELAY: u32 = 1 << 5;
+
+    /// The features implemented by this crate.
+    pub const SUPPORTED: u32 = COMPRESSION | ROOMS;
+
+    pub(crate) fn is_empty(capabilities: &u32) -> bool {
+        *capabilities == 0
+    }
 }
 
 /// The data that is encoded as JSON which is again encoded as base64.
@@ -136,6 +414,10 @@ pub struct ClientInfoWithoutId {
     /// A fingerprint generated by the client.
     /// Used to merge the same peers detected on different channels (LAN, WebRTC, etc.).
     pub token: String,
+
+    /// Bitset of optional protocol features supported by the client. See [`capability`].
+    #[serde(default, skip_serializing_if = "capability::is_empty")]
+    pub capabilities: u32,
 }
 
 impl From<ClientInfo> for ClientInfoWithoutId {
@@ -146,6 +428,7 @@ impl From<ClientInfo> for ClientInfoWithoutId {
             device_model: info.device_model,
             device_type: info.device_type,
             token: info.token,
+            capabilities: info.capabilities,
         }
     }
 }
@@ -154,9 +437,36 @@ impl From<ClientInfo> for ClientInfoWithoutId {
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WsClientMessage {
-    Update { info: ClientInfoWithoutId },
+    Update {
+        info: ClientInfoWithoutId,
+    },
     Offer(WsClientSdpMessage),
     Answer(WsClientSdpMessage),
+
+    /// Creates a new room and moves the client into it.
+    /// The server responds with a hello containing the room code.
+    /// If a password is given, peers must provide it to join the room.
+    CreateRoom {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        password: Option<String>,
+    },
+
+    /// Moves the client into the room with the given code.
+    /// Only the members of the room are visible afterwards.
+    JoinRoom {
+        code: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        password: Option<String>,
+    },
+
+    /// Moves the client back into its IP room.
+    LeaveRoom,
+
+    /// Searches the servers federated with this server for peers with the fingerprint.
+    /// Found peers are sent as [`WsServerMessage::Join`] and can receive offers.
+    Lookup {
+        fingerprint: String,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -174,10 +484,89 @@ pub struct WsClientSdpMessage {
     pub sdp: String,
 }
 
+#[derive(Clone, Debug, Default)]
+pub struct ConnectOptions {
+    /// Allows plain `ws://` URIs.
+    /// Meant for self-hosted servers in the LAN (e.g. on a Raspberry Pi)
+    /// where TLS certificates are impractical.
+    pub allow_insecure: bool,
+
+    /// The resume token of a previous connection. See [`SignalingConnection::resume`].
+    pub resume_token: Option<String>,
+
+    /// Sent as `Authorization: Bearer <token>` to private servers that require an access token.
+    pub access_token: Option<String>,
+}
+
+/// Checks that the URI uses a websocket scheme.
+/// Plain `ws://` is only accepted if `allow_insecure` is set.
+#[cfg(any(feature = "webrtc-signaling", feature = "wasm"))]
+pub(crate) fn validate_uri(uri: &str, allow_insecure: bool) -> Result<()> {
+    let Some((scheme, _)) = uri.split_once("://") else {
+        return Err(anyhow::anyhow!("Invalid signaling server URI: {uri}"));
+    };
+
+    match scheme.to_lowercase().as_str() {
+        "wss" => Ok(()),
+        "ws" if allow_insecure => {
+            tracing::warn!("Connecting to the signaling server {uri} without TLS");
+            Ok(())
+        }
+        "ws" => Err(anyhow::anyhow!(
+            "Insecure signaling server URI {uri} requires allow_insecure"
+        )),
+        _ => Err(anyhow::anyhow!(
+            "Unsupported scheme of signaling server URI: {uri}"
+        )),
+    }
+}
+
+/// Appends the encoded client info and the resume token (if any) to the URI.
+#[cfg(any(feature = "webrtc-signaling", feature = "wasm"))]
+pub(crate) fn connect_uri(
+    uri: &str,
+    info: &ClientInfoWithoutId,
+    resume_token: Option<&str>,
+) -> Result<String> {
+    let encoded_info = base64::encode(&serde_json::to_string(info)?);
+    Ok(match resume_token {
+        Some(token) => format!("{uri}?d={encoded_info}&r={token}"),
+        None => format!("{uri}?d={encoded_info}"),
+    })
+}
+
+/// Builds the upgrade request with the access token of private servers.
+/// The subprotocols are offered unless connecting to a server that predates the negotiation.
+#[cfg(feature = "webrtc-signaling")]
+fn client_request(uri: &str, access_token: Option<&str>, negotiate: bool) -> Result<ClientRequest> {
+    let mut request = uri.into_client_request()?;
+    if let Some(token) = access_token {
+        request.headers_mut().insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {token}"))?,
+        );
+    }
+    if negotiate {
+        let offered = SignalingProtocol::SUPPORTED
+            .map(|p| p.subprotocol())
+            .join(", ");
+        request
+            .headers_mut()
+            .insert(SEC_WEBSOCKET_PROTOCOL, HeaderValue::from_str(&offered)?);
+    }
+    Ok(request)
+}
+
 pub struct SignalingConnection {
     /// The peer info received from the server of the client.
     pub client: ClientInfo,
 
+    /// The token to pass to [`SignalingConnection::resume`] after a disconnect.
+    pub resume_token: Option<String>,
+
+    /// The protocol version negotiated with the server.
+    pub protocol: SignalingProtocol,
+
     /// The sender to send messages to the server.
     pub tx: mpsc::Sender<WsClientMessage>,
 
@@ -187,20 +576,102 @@ pub struct SignalingConnection {
 
 #[cfg(feature = "webrtc-signaling")]
 impl SignalingConnection {
+    /// Connects to the signaling server.
+    /// Plain `ws://` URIs are rejected, see [`ConnectOptions::allow_insecure`].
     pub async fn connect<S: Into<String>>(
         uri: S,
         info: &ClientInfoWithoutId,
     ) -> Result<SignalingConnection> {
-        let encoded_info = base64::encode(&serde_json::to_string(info)?);
-        let uri = format!("{}?d={}", uri.into(), encoded_info);
+        Self::connect_with_options(uri, info, &ConnectOptions::default()).await
+    }
 
+    /// Reconnects to the signaling server presenting the resume token of a previous connection.
+    /// If the token is still valid, the server assigns the same peer ID again
+    /// so that pending offers and answers still reach this client.
+    /// Otherwise, a new peer ID is assigned.
+    pub async fn resume<S: Into<String>>(
+        uri: S,
+        info: &ClientInfoWithoutId,
+        resume_token: &str,
+    ) -> Result<SignalingConnection> {
+        let options = ConnectOptions {
+            resume_token: Some(resume_token.to_string()),
+            ..ConnectOptions::default()
+        };
+        Self::connect_with_options(uri, info, &options).await
+    }
+
+    pub async fn connect_with_options<S: Into<String>>(
+        uri: S,
+        info: &ClientInfoWithoutId,
+        options: &ConnectOptions,
+    ) -> Result<SignalingConnection> {
+        let uri = uri.into();
+        validate_uri(&uri, options.allow_insecure)?;
+
+        // Logged without the query which contains the resume token.
         tracing::debug!("Connecting to the signaling server at {uri}");
 
-        let (ws_stream, _) = connect_async(&uri).await?;
+        let mut uri = connect_uri(&uri, info, options.resume_token.as_deref())?;
+
+        let access_token = options.access_token.as_deref();
+        let mut result = connect_async(client_request(&uri, access_token, true)?).await;
+
+        // Servers under load may require a proof of work before accepting the connection.
+        if let Err(tungstenite::Error::Http(response)) = &result {
+            if response.status().as_u16() == SignalingErrorCode::ProofOfWorkRequired.code() {
+                let challenge: ProofOfWorkChallenge =
+                    serde_json::from_slice(response.body().as_deref().unwrap_or_default())?;
+                if challenge.difficulty > proof_of_work::MAX_DIFFICULTY {
+                    return Err(anyhow::anyhow!(
+                        "Proof of work difficulty {} exceeds the maximum of {}",
+                        challenge.difficulty,
+                        proof_of_work::MAX_DIFFICULTY
+                    ));
+                }
+                tracing::debug!(
+                    "Solving proof of work with difficulty {}...",
+                    challenge.difficulty
+                );
+                let nonce = tokio::task::spawn_blocking({
+                    let challenge = challenge.clone();
+                    move || proof_of_work::solve(&challenge)
+                })
+                .await?;
+                uri = format!("{uri}&c={}&n={nonce}", challenge.challenge);
+                result = connect_async(client_request(&uri, access_token, true)?).await;
+            }
+        }
+
+        let (ws_stream, protocol) = match result {
+            Ok((ws_stream, response)) => {
+                let protocol = response
+                    .headers()
+                    .get(SEC_WEBSOCKET_PROTOCOL)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(SignalingProtocol::from_subprotocol)
+                    .ok_or_else(|| anyhow::anyhow!("Unsupported signaling protocol"))?;
+                (ws_stream, protocol)
+            }
+            Err(tungstenite::Error::Protocol(ProtocolError::SecWebSocketSubProtocolError(
+                SubProtocolError::NoSubProtocol,
+            ))) => {
+                // The server predates the negotiation.
+                tracing::debug!(
+                    "Signaling server does not negotiate the protocol. Reconnecting..."
+                );
+                let (ws_stream, _) =
+                    connect_async(client_request(&uri, access_token, false)?).await?;
+                (ws_stream, SignalingProtocol::V1)
+            }
+            Err(e) => return Err(e.into()),
+        };
 
-        tracing::debug!("Connected to the signaling server. Waiting for hello...");
+        tracing::debug!(
+            "Connected to the signaling server using {protocol:?}. Waiting for hello..."
+        );
 
-        let (mut write, read) = ws_stream.split();
+        let (mut write, mut read) = ws_stream.split();
 
         let (send_tx, mut send_rx) = mpsc::channel(1);
 
@@ -223,6 +694,8 @@ impl SignalingConnection {
                 match send_result {
                     Ok(success) => {
                         if !success {
+                            // Closes the connection once all senders are dropped.
+                            let _ = write.close().await;
                             return;
                         }
                     }
@@ -237,41 +710,49 @@ impl SignalingConnection {
         });
 
         let (receive_tx, receive_rx) = mpsc::channel(1);
-        let (client_tx, mut client_rx) = mpsc::channel::<ClientInfo>(1);
+        let (client_tx, client_rx) = oneshot::channel::<(ClientInfo, Option<String>)>();
 
         tokio::spawn(async move {
-            read.for_each(|message| async {
-                if let Ok(Message::Text(message)) = message {
-                    match serde_json::from_str::<WsServerMessage>(&message) {
-                        Ok(message) => {
-                            if let WsServerMessage::Hello {
-                                client,
-                                peers: _peers,
-                            } = &message
-                            {
-                                if client_tx.send(client.clone()).await.is_err() {
-                                    return;
-                                }
+            // Only the first hello completes the handshake,
+            // later ones follow room changes and are just forwarded.
+            let mut client_tx = Some(client_tx);
+            while let Some(message) = read.next().await {
+                let Ok(Message::Text(message)) = message else {
+                    continue;
+                };
+                match serde_json::from_str::<WsServerMessage>(&message) {
+                    Ok(message) => {
+                        if let WsServerMessage::Hello {
+                            client,
+                            resume_token,
+                            ..
+                        } = &message
+                        {
+                            if let Some(client_tx) = client_tx.take() {
+                                let _ = client_tx.send((client.clone(), resume_token.clone()));
                             }
+                        }
 
-                            match receive_tx.send(message).await {
-                                Ok(_) => {}
-                                Err(e) => tracing::error!("{e:?}"),
-                            }
+                        match receive_tx.send(message).await {
+                            Ok(_) => {}
+                            Err(e) => tracing::error!("{e:?}"),
                         }
-                        Err(e) => tracing::error!("Error: {e}, Server: {message}"),
                     }
+                    Err(e) => tracing::error!("Error: {e}, Server: {message}"),
                 }
-            })
-            .await;
+            }
         });
 
-        let client = client_rx.recv().await.unwrap();
+        let (client, resume_token) = client_rx
+            .await
+            .map_err(|_| anyhow::anyhow!("Connection closed before receiving hello"))?;
 
         tracing::debug!("Received hello from server: {client:?}");
 
         Ok(SignalingConnection {
             client,
+            resume_token,
+            protocol,
             tx: send_tx,
             rx: receive_rx,
         })
@@ -285,11 +766,25 @@ impl SignalingConnection {
         let (tx, rx) = mpsc::channel::<WsServerMessage>(16);
         let on_answer: Arc<Mutex<HashMap<String, AnswerCallback>>> =
             Arc::new(Mutex::new(HashMap::new()));
+        let peers = Arc::new(Mutex::new(PeerList::default()));
+        let (peer_changes, _) = broadcast::channel(PEER_CHANGE_CAPACITY);
 
         {
             let on_answer = on_answer.clone();
+            let peers = peers.clone();
+            let peer_changes = peer_changes.clone();
             tokio::spawn(async move {
                 while let Some(message) = self.rx.recv().await {
+                    {
+                        // Publish while holding the lock so that subscribers
+                        // never miss or double-apply a change to their snapshot.
+                        let mut peers = peers.lock().await;
+                        for change in peers.apply(&message) {
+                            // No subscribers is not an error.
+                            let _ = peer_changes.send(change);
+                        }
+                    }
+
                     // send answer
                     if let WsServerMessage::Answer(sdp) = message.clone() {
                         if let Some(callback) = on_answer.lock().await.remove(&sdp.session_id) {
@@ -297,6 +792,12 @@ impl SignalingConnection {
                         }
                     }
 
+                    if let WsServerMessage::Announcement { kind, message } = &message {
+                        tracing::info!(
+                            "Announcement from the signaling server ({kind:?}): {message}"
+                        );
+                    }
+
                     tx.send(message).await.unwrap();
                 }
             });
@@ -305,8 +806,12 @@ impl SignalingConnection {
         (
             ManagedSignalingConnection {
                 client: self.client,
+                resume_token: self.resume_token,
+                protocol: self.protocol,
                 tx: self.tx,
                 on_answer,
+                peers,
+                peer_changes,
             },
             rx,
         )
@@ -329,15 +834,159 @@ impl SignalingConnection {
 
         Ok(())
     }
+
+    pub async fn create_room(&self, password: Option<String>) -> Result<()> {
+        send_message(&self.tx, WsClientMessage::CreateRoom { password }).await
+    }
+
+    pub async fn join_room(&self, code: String, password: Option<String>) -> Result<()> {
+        send_message(&self.tx, WsClientMessage::JoinRoom { code, password }).await
+    }
+
+    pub async fn leave_room(&self) -> Result<()> {
+        send_message(&self.tx, WsClientMessage::LeaveRoom).await
+    }
+
+    /// Looks up the peers with the fingerprint on federated servers.
+    /// See [`WsClientMessage::Lookup`].
+    pub async fn lookup(&self, fingerprint: String) -> Result<()> {
+        send_message(&self.tx, WsClientMessage::Lookup { fingerprint }).await
+    }
 }
 
+#[cfg(feature = "webrtc-signaling")]
 type AnswerCallback = Box<dyn FnOnce(WsServerSdpMessage) + Send + Sync>;
 
+/// How long to wait for the answer to an offer by default.
+pub const DEFAULT_OFFER_TTL: Duration = Duration::from_secs(60);
+
+/// No answer to an offer has been received within its TTL.
+#[derive(Debug, thiserror::Error)]
+#[error("No answer received within {0:?}")]
+pub struct OfferExpired(pub Duration);
+
+/// Number of peer changes buffered for slow subscribers before they start lagging.
+#[cfg(feature = "webrtc-signaling")]
+const PEER_CHANGE_CAPACITY: usize = 64;
+
+/// A change to the peer list of a [`ManagedSignalingConnection`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PeerChange {
+    /// A peer appeared in the IP room.
+    Added(ClientInfo),
+
+    /// A known peer changed its information.
+    Updated(ClientInfo),
+
+    /// A peer left the IP room.
+    Removed(ClientInfo),
+}
+
+/// The peers (excluding the client itself) currently known from the server messages.
+#[cfg(feature = "webrtc-signaling")]
+#[derive(Debug, Default)]
+struct PeerList {
+    peers: HashMap<Uuid, ClientInfo>,
+}
+
+#[cfg(feature = "webrtc-signaling")]
+impl PeerList {
+    /// Applies a server message to the list and returns the resulting changes.
+    /// Messages unrelated to the peer list yield no changes.
+    fn apply(&mut self, message: &WsServerMessage) -> Vec<PeerChange> {
+        match message {
+            WsServerMessage::Hello { peers, .. } => {
+                // A hello replaces the whole list (e.g. after a reconnect),
+                // so diff it against what we knew before.
+                let mut previous = std::mem::take(&mut self.peers);
+                let mut changes = Vec::new();
+                for peer in peers {
+                    match previous.remove(&peer.id) {
+                        Some(old) if old == *peer => {}
+                        Some(_) => changes.push(PeerChange::Updated(peer.clone())),
+                        None => changes.push(PeerChange::Added(peer.clone())),
+                    }
+                    self.peers.insert(peer.id, peer.clone());
+                }
+                changes.extend(previous.into_values().map(PeerChange::Removed));
+                changes
+            }
+            WsServerMessage::Join { peer } => match self.peers.insert(peer.id, peer.clone()) {
+                Some(old) if old == *peer => vec![],
+                Some(_) => vec![PeerChange::Updated(peer.clone())],
+                None => vec![PeerChange::Added(peer.clone())],
+            },
+            WsServerMessage::Update { peer } => match self.peers.insert(peer.id, peer.clone()) {
+                Some(old) if old == *peer => vec![],
+                // Be lenient if the join was missed.
+                None => vec![PeerChange::Added(peer.clone())],
+                Some(_) => vec![PeerChange::Updated(peer.clone())],
+            },
+            WsServerMessage::Left { peer_id } => match self.peers.remove(peer_id) {
+                Some(old) => vec![PeerChange::Removed(old)],
+                None => vec![],
+            },
+            _ => vec![],
+        }
+    }
+
+    fn to_vec(&self) -> Vec<ClientInfo> {
+        self.peers.values().cloned().collect()
+    }
+}
+
+/// The peer list followed by [`ManagedSignalingConnection::watch_peers`], in the order the peers appeared.
+#[cfg(feature = "webrtc-signaling")]
+#[derive(Debug, Default)]
+struct PeerSnapshot {
+    peers: Vec<ClientInfo>,
+}
+
+#[cfg(feature = "webrtc-signaling")]
+impl PeerSnapshot {
+    fn apply(&mut self, change: PeerChange) {
+        match change {
+            PeerChange::Added(peer) => {
+                self.peers.retain(|p| p.id != peer.id);
+                self.peers.push(peer);
+            }
+            PeerChange::Updated(peer) => match self.peers.iter_mut().find(|p| p.id == peer.id) {
+                Some(p) => *p = peer,
+                None => self.peers.push(peer),
+            },
+            PeerChange::Removed(peer) => self.peers.retain(|p| p.id != peer.id),
+        }
+    }
+
+    /// The peers with one entry per token, keeping the one that appeared last.
+    fn deduplicated(&self) -> Vec<ClientInfo> {
+        let mut tokens = std::collections::HashSet::new();
+        let mut peers: Vec<_> = self
+            .peers
+            .iter()
+            .rev()
+            .filter(|peer| tokens.insert(peer.token.as_str()))
+            .cloned()
+            .collect();
+        peers.reverse();
+        peers
+    }
+}
+
+#[cfg(feature = "webrtc-signaling")]
 pub struct ManagedSignalingConnection {
     /// The peer info received from the server of the client.
     pub client: ClientInfo,
+
+    /// The token to pass to [`SignalingConnection::resume`] after a disconnect.
+    pub resume_token: Option<String>,
+
+    /// The protocol version negotiated with the server.
+    pub protocol: SignalingProtocol,
     tx: mpsc::Sender<WsClientMessage>,
     on_answer: Arc<Mutex<HashMap<String, AnswerCallback>>>,
+    peers: Arc<Mutex<PeerList>>,
+    peer_changes: broadcast::Sender<PeerChange>,
 }
 
 #[cfg(feature = "webrtc-signaling")]
@@ -360,6 +1009,24 @@ impl ManagedSignalingConnection {
         Ok(())
     }
 
+    pub async fn create_room(&self, password: Option<String>) -> Result<()> {
+        send_message(&self.tx, WsClientMessage::CreateRoom { password }).await
+    }
+
+    pub async fn join_room(&self, code: String, password: Option<String>) -> Result<()> {
+        send_message(&self.tx, WsClientMessage::JoinRoom { code, password }).await
+    }
+
+    pub async fn leave_room(&self) -> Result<()> {
+        send_message(&self.tx, WsClientMessage::LeaveRoom).await
+    }
+
+    /// Looks up the peers with the fingerprint on federated servers.
+    /// See [`WsClientMessage::Lookup`].
+    pub async fn lookup(&self, fingerprint: String) -> Result<()> {
+        send_message(&self.tx, WsClientMessage::Lookup { fingerprint }).await
+    }
+
     /// Adds a callback to be called when an answer having a specific `session_id` is received.
     pub async fn on_answer<F>(&self, session_id: String, callback: F)
     where
@@ -368,8 +1035,103 @@ impl ManagedSignalingConnection {
         let mut callbacks = self.on_answer.lock().await;
         callbacks.insert(session_id, Box::new(callback));
     }
+
+    /// Sends an offer and waits for its answer.
+    ///
+    /// Fails with [`OfferExpired`] if no answer arrives within `ttl`.
+    /// The pending answer callback is removed in that case.
+    pub async fn send_offer_and_wait(
+        &self,
+        session_id: String,
+        target: Uuid,
+        sdp: String,
+        ttl: Duration,
+    ) -> Result<WsServerSdpMessage> {
+        let (tx_answer, rx_answer) = oneshot::channel();
+
+        // Register before sending so that a fast answer cannot be missed.
+        self.on_answer(session_id.clone(), |message| {
+            let _ = tx_answer.send(message);
+        })
+        .await;
+
+        if let Err(e) = send_offer(&self.tx, session_id.clone(), target, sdp).await {
+            self.on_answer.lock().await.remove(&session_id);
+            return Err(e);
+        }
+
+        match tokio::time::timeout(ttl, rx_answer).await {
+            Ok(Ok(answer)) => Ok(answer),
+            Ok(Err(_)) => Err(anyhow::anyhow!("Signaling connection closed")),
+            Err(_) => {
+                self.on_answer.lock().await.remove(&session_id);
+                tracing::debug!("Offer with session ID {session_id} expired");
+                Err(OfferExpired(ttl).into())
+            }
+        }
+    }
+
+    /// Returns a snapshot of the peers currently in the IP room (excluding the client).
+    pub async fn peers(&self) -> Vec<ClientInfo> {
+        self.peers.lock().await.to_vec()
+    }
+
+    /// Returns the peer with the given ID, e.g. to check its capabilities before sending an offer.
+    pub async fn peer(&self, id: Uuid) -> Option<ClientInfo> {
+        self.peers.lock().await.peers.get(&id).cloned()
+    }
+
+    /// Subscribes to changes of the peer list.
+    /// Returns the current snapshot together with a receiver for all changes after it.
+    pub async fn subscribe_peers(&self) -> (Vec<ClientInfo>, broadcast::Receiver<PeerChange>) {
+        let peers = self.peers.lock().await;
+        (peers.to_vec(), self.peer_changes.subscribe())
+    }
+
+    /// Follows the whole peer list instead of single changes.
+    /// The receiver starts with the current peers and is updated whenever they change.
+    /// Peers with the same token are listed once, e.g. a client that reconnected
+    /// before its previous session was closed by the server.
+    pub async fn watch_peers(&self) -> watch::Receiver<Vec<ClientInfo>> {
+        let (peers, mut changes) = self.subscribe_peers().await;
+        let mut snapshot = PeerSnapshot { peers };
+        let (tx, rx) = watch::channel(snapshot.deduplicated());
+
+        let peer_list = self.peers.clone();
+        tokio::spawn(async move {
+            loop {
+                let change = tokio::select! {
+                    _ = tx.closed() => return,
+                    change = changes.recv() => change,
+                };
+
+                match change {
+                    Ok(change) => snapshot.apply(change),
+                    Err(broadcast::error::RecvError::Lagged(_)) => {
+                        // Changes were missed, so start over from the current list.
+                        let peer_list = peer_list.lock().await;
+                        snapshot = PeerSnapshot {
+                            peers: peer_list.to_vec(),
+                        };
+                        changes = changes.resubscribe();
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return,
+                }
+
+                let peers = snapshot.deduplicated();
+                tx.send_if_modified(|current| {
+                    let modified = *current != peers;
+                    *current = peers;
+                    modified
+                });
+            }
+        });
+
+        rx
+    }
 }
 
+#[cfg(feature = "webrtc-signaling")]
 async fn send_update(tx: &mpsc::Sender<WsClientMessage>, info: ClientInfoWithoutId) -> Result<()> {
     tx.send(WsClientMessage::Update { info }).await?;
 
@@ -378,6 +1140,7 @@ async fn send_update(tx: &mpsc::Sender<WsClientMessage>, info: ClientInfoWithout
     Ok(())
 }
 
+#[cfg(feature = "webrtc-signaling")]
 async fn send_offer(
     tx: &mpsc::Sender<WsClientMessage>,
     session_id: String,
@@ -396,6 +1159,16 @@ async fn send_offer(
     Ok(())
 }
 
+#[cfg(feature = "webrtc-signaling")]
+async fn send_message(tx: &mpsc::Sender<WsClientMessage>, message: WsClientMessage) -> Result<()> {
+    tracing::debug!("Sending {message:?} to the server");
+
+    tx.send(message).await?;
+
+    Ok(())
+}
+
+#[cfg(feature = "webrtc-signaling")]
 async fn send_answer(
     tx: &mpsc::Sender<WsClientMessage>,
     session_id: String,
@@ -428,8 +1201,11 @@ mod tests {
                 device_model: Some("Dell".to_string()),
                 device_type: Some(DeviceType::Desktop),
                 token: "123".to_string(),
+                capabilities: 0,
             },
             peers: vec![],
+            resume_token: Some("abc".to_string()),
+            room: None,
         };
 
         let encoded = serde_json::to_string_pretty(&message).unwrap();
@@ -446,7 +1222,8 @@ mod tests {
     "deviceType": "DESKTOP",
     "token": "123"
   },
-  "peers": []
+  "peers": [],
+  "resumeToken": "abc"
 }"#
         );
 
@@ -465,6 +1242,7 @@ mod tests {
                 device_model: None,
                 device_type: Some(DeviceType::Desktop),
                 token: "123".to_string(),
+                capabilities: 0,
             },
             session_id: "456".to_string(),
             sdp: "my-sdp".to_string(),
@@ -493,6 +1271,64 @@ mod tests {
         assert_eq!(message, decoded);
     }
 
+    #[test]
+    fn ws_server_announcement_message_encoding() {
+        let message = WsServerMessage::Announcement {
+            kind: AnnouncementKind::Maintenance,
+            message: "Back in 10 minutes".to_string(),
+        };
+
+        let encoded = serde_json::to_string(&message).unwrap();
+
+        assert_eq!(
+            encoded,
+            r#"{"type":"ANNOUNCEMENT","kind":"MAINTENANCE","message":"Back in 10 minutes"}"#
+        );
+
+        let decoded: WsServerMessage = serde_json::from_str(&encoded).unwrap();
+
+        assert_eq!(message, decoded);
+
+        // Kinds added in later server versions must not break older clients.
+        let decoded: WsServerMessage =
+            serde_json::from_str(r#"{"type":"ANNOUNCEMENT","kind":"SURVEY","message":"Hi"}"#)
+                .unwrap();
+
+        assert_eq!(
+            decoded,
+            WsServerMessage::Announcement {
+                kind: AnnouncementKind::Other,
+                message: "Hi".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn ws_server_message_ignores_unknown_fields() {
+        // Fields added in later server versions must not break older clients.
+        let decoded: WsServerMessage = serde_json::from_str(
+            r#"{"type":"JOIN","peer":{"id":"00000000-0000-0000-0000-000000000000","alias":"Cute Apple","version":"2.3","token":"123","avatar":"abc"},"region":"eu"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            decoded,
+            WsServerMessage::Join {
+                peer: peer(0, "Cute Apple")
+            }
+        );
+
+        let decoded: WsClientMessage = serde_json::from_str(
+            r#"{"type":"UPDATE","info":{"alias":"Cute Apple","version":"2.3","token":"123","avatar":"abc"},"priority":1}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            decoded,
+            WsClientMessage::Update {
+                info: peer(0, "Cute Apple").into()
+            }
+        );
+    }
+
     #[test]
     fn ws_client_update_message_encoding() {
         let message = WsClientMessage::Update {
@@ -502,6 +1338,7 @@ mod tests {
                 device_model: Some("Dell".to_string()),
                 device_type: Some(DeviceType::Desktop),
                 token: "123".to_string(),
+                capabilities: 0,
             },
         };
 
@@ -525,4 +1362,342 @@ mod tests {
 
         assert_eq!(message, decoded);
     }
+
+    fn peer(id: u128, alias: &str) -> ClientInfo {
+        ClientInfo {
+            id: Uuid::from_u128(id),
+            alias: alias.to_string(),
+            version: "2.3".to_string(),
+            device_model: None,
+            device_type: None,
+            token: "123".to_string(),
+            capabilities: 0,
+        }
+    }
+
+    #[cfg(feature = "webrtc-signaling")]
+    #[tokio::test]
+    async fn offer_expires_without_answer() {
+        let (client_tx, mut client_rx) = mpsc::channel(4);
+        let (server_tx, server_rx) = mpsc::channel(4);
+        let (connection, _rx) = SignalingConnection {
+            client: peer(0, "Me"),
+            resume_token: None,
+            protocol: SignalingProtocol::V2,
+            tx: client_tx,
+            rx: server_rx,
+        }
+        .start_listener();
+
+        let ttl = Duration::from_millis(50);
+        let result = connection
+            .send_offer_and_wait(
+                "expired".to_string(),
+                Uuid::from_u128(1),
+                "sdp".to_string(),
+                ttl,
+            )
+            .await;
+        assert!(result.unwrap_err().is::<OfferExpired>());
+        assert!(connection.on_answer.lock().await.is_empty());
+        assert!(matches!(
+            client_rx.recv().await,
+            Some(WsClientMessage::Offer(_))
+        ));
+
+        let answer = WsServerSdpMessage {
+            peer: peer(1, "A"),
+            session_id: "answered".to_string(),
+            sdp: "answer".to_string(),
+        };
+        tokio::spawn({
+            let answer = answer.clone();
+            async move {
+                // Answer once the offer has been sent.
+                client_rx.recv().await;
+                server_tx
+                    .send(WsServerMessage::Answer(answer))
+                    .await
+                    .unwrap();
+            }
+        });
+        let result = connection
+            .send_offer_and_wait(
+                "answered".to_string(),
+                Uuid::from_u128(1),
+                "sdp".to_string(),
+                Duration::from_secs(5),
+            )
+            .await;
+        assert_eq!(result.unwrap(), answer);
+    }
+
+    #[test]
+    fn client_info_capabilities_encoding() {
+        let info = ClientInfoWithoutId {
+            alias: "Cute Apple".to_string(),
+            version: "2.3".to_string(),
+            device_model: None,
+            device_type: None,
+            token: "123".to_string(),
+            capabilities: capability::COMPRESSION | capability::DELTA_SYNC,
+        };
+
+        let encoded = serde_json::to_string(&info).unwrap();
+        assert_eq!(
+            encoded,
+            r#"{"alias":"Cute Apple","version":"2.3","token":"123","capabilities":5}"#
+        );
+
+        // Older clients don't send capabilities at all.
+        let decoded: ClientInfoWithoutId =
+            serde_json::from_str(r#"{"alias":"Cute Apple","version":"2.3","token":"123"}"#)
+                .unwrap();
+        assert_eq!(decoded.capabilities, 0);
+    }
+
+    #[test]
+    fn capabilities_negotiation() {
+        let remote = ClientInfo::from(
+            ClientInfoWithoutId {
+                alias: "Cute Apple".to_string(),
+                version: "2.3".to_string(),
+                device_model: None,
+                device_type: None,
+                token: "123".to_string(),
+                capabilities: (1 << 31) | capability::MULTI_CHANNEL,
+            },
+            Uuid::nil(),
+        );
+
+        let local = capability::COMPRESSION | capability::MULTI_CHANNEL;
+
+        assert_eq!(remote.negotiate(local), capability::MULTI_CHANNEL);
+        assert!(remote.supports(capability::MULTI_CHANNEL));
+        assert!(!remote.supports(capability::MULTI_CHANNEL | capability::TURN));
+    }
+
+    #[test]
+    fn join_message_keeps_unknown_capabilities() {
+        let encoded = r#"{"type":"JOIN","peer":{"id":"00000000-0000-0000-0000-000000000000","alias":"Cute Apple","version":"2.3","token":"123","capabilities":2147483664}}"#;
+
+        let decoded: WsServerMessage = serde_json::from_str(encoded).unwrap();
+        let WsServerMessage::Join { peer } = &decoded else {
+            panic!("Expected join, got {decoded:?}");
+        };
+        assert!(peer.supports(capability::ROOMS));
+
+        // The server relays the capabilities untouched.
+        assert_eq!(serde_json::to_string(&decoded).unwrap(), encoded);
+    }
+
+    #[test]
+    fn ws_client_room_message_encoding() {
+        assert_eq!(
+            serde_json::to_string(&WsClientMessage::CreateRoom { password: None }).unwrap(),
+            r#"{"type":"CREATE_ROOM"}"#
+        );
+        assert_eq!(
+            serde_json::to_string(&WsClientMessage::CreateRoom {
+                password: Some("secret".to_string())
+            })
+            .unwrap(),
+            r#"{"type":"CREATE_ROOM","password":"secret"}"#
+        );
+
+        let message: WsClientMessage =
+            serde_json::from_str(r#"{"type":"JOIN_ROOM","code":"K7QX2M"}"#).unwrap();
+        assert_eq!(
+            message,
+            WsClientMessage::JoinRoom {
+                code: "K7QX2M".to_string(),
+                password: None,
+            }
+        );
+
+        let message: WsClientMessage =
+            serde_json::from_str(r#"{"type":"JOIN_ROOM","code":"K7QX2M","password":"secret"}"#)
+                .unwrap();
+        assert_eq!(
+            message,
+            WsClientMessage::JoinRoom {
+                code: "K7QX2M".to_string(),
+                password: Some("secret".to_string()),
+            }
+        );
+
+        assert_eq!(
+            serde_json::to_string(&WsClientMessage::Lookup {
+                fingerprint: "abc".to_string()
+            })
+            .unwrap(),
+            r#"{"type":"LOOKUP","fingerprint":"abc"}"#
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "webrtc-signaling", feature = "wasm"))]
+    fn signaling_uri_validation() {
+        assert!(validate_uri("wss://public.localsend.org/v1/ws", false).is_ok());
+        assert!(validate_uri("WSS://public.localsend.org/v1/ws", false).is_ok());
+        assert!(validate_uri("ws://192.168.1.2:3000/v1/ws", false).is_err());
+        assert!(validate_uri("ws://192.168.1.2:3000/v1/ws", true).is_ok());
+        assert!(validate_uri("https://public.localsend.org/v1/ws", true).is_err());
+        assert!(validate_uri("public.localsend.org", true).is_err());
+    }
+
+    #[test]
+    fn signaling_error_codes() {
+        for code in [
+            SignalingErrorCode::InvalidMessage,
+            SignalingErrorCode::RoomFull,
+            SignalingErrorCode::RateLimited,
+            SignalingErrorCode::TooManyConnections,
+            SignalingErrorCode::UnsupportedVersion,
+            SignalingErrorCode::RoomNotFound,
+            SignalingErrorCode::WrongPassword,
+            SignalingErrorCode::Forbidden,
+            SignalingErrorCode::PayloadTooLarge,
+            SignalingErrorCode::ProofOfWorkRequired,
+            SignalingErrorCode::ServerShutdown,
+            SignalingErrorCode::Other(500),
+        ] {
+            assert_eq!(SignalingErrorCode::from(code.code()), code);
+        }
+        assert_eq!(SignalingErrorCode::TooManyConnections.http_status(), 429);
+        assert_eq!(SignalingErrorCode::RoomFull.http_status(), 409);
+
+        assert_eq!(
+            serde_json::to_string(&WsServerMessage::from(SignalingErrorCode::RateLimited)).unwrap(),
+            r#"{"type":"ERROR","code":429}"#
+        );
+
+        assert!(SignalingErrorCode::RateLimited.is_retryable());
+        assert!(!SignalingErrorCode::UnsupportedVersion.is_retryable());
+        assert!(SignalingErrorCode::ServerShutdown.is_retryable());
+        assert!(!SignalingErrorCode::Forbidden.is_retryable());
+        assert!(SignalingErrorCode::Other(500).is_retryable());
+        assert!(!SignalingErrorCode::Other(418).is_retryable());
+    }
+
+    #[test]
+    fn protocol_negotiation() {
+        assert_eq!(
+            SignalingProtocol::negotiate(["localsend.v1, localsend.v2"]),
+            Some(SignalingProtocol::V2)
+        );
+        assert_eq!(
+            SignalingProtocol::negotiate(["chat", "localsend.v1"]),
+            Some(SignalingProtocol::V1)
+        );
+        assert_eq!(SignalingProtocol::negotiate(["localsend.v9"]), None);
+        assert_eq!(SignalingProtocol::negotiate([]), None);
+
+        for protocol in SignalingProtocol::SUPPORTED {
+            assert_eq!(
+                SignalingProtocol::from_subprotocol(protocol.subprotocol()),
+                Some(protocol)
+            );
+        }
+
+        let announcement = WsServerMessage::Announcement {
+            kind: AnnouncementKind::Info,
+            message: "Hi".to_string(),
+        };
+        assert_eq!(announcement.min_protocol(), SignalingProtocol::V2);
+        assert_eq!(
+            WsServerMessage::from(SignalingErrorCode::RateLimited).min_protocol(),
+            SignalingProtocol::V1
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "webrtc-signaling")]
+    fn peer_list_applies_messages() {
+        let mut list = PeerList::default();
+
+        let changes = list.apply(&WsServerMessage::Hello {
+            client: peer(0, "Me"),
+            peers: vec![peer(1, "A"), peer(2, "B")],
+            resume_token: None,
+            room: None,
+        });
+        assert_eq!(changes.len(), 2);
+        assert_eq!(list.peers.len(), 2);
+
+        let changes = list.apply(&WsServerMessage::Join { peer: peer(3, "C") });
+        assert_eq!(changes, vec![PeerChange::Added(peer(3, "C"))]);
+
+        let changes = list.apply(&WsServerMessage::Update {
+            peer: peer(1, "A2"),
+        });
+        assert_eq!(changes, vec![PeerChange::Updated(peer(1, "A2"))]);
+
+        let changes = list.apply(&WsServerMessage::Left {
+            peer_id: Uuid::from_u128(2),
+        });
+        assert_eq!(changes, vec![PeerChange::Removed(peer(2, "B"))]);
+
+        let changes = list.apply(&WsServerMessage::Left {
+            peer_id: Uuid::from_u128(2),
+        });
+        assert!(changes.is_empty());
+    }
+
+    #[cfg(feature = "webrtc-signaling")]
+    #[test]
+    fn peer_snapshot_deduplicates_by_token() {
+        let mut snapshot = PeerSnapshot::default();
+        snapshot.apply(PeerChange::Added(peer(1, "A")));
+        snapshot.apply(PeerChange::Added(ClientInfo {
+            token: "456".to_string(),
+            ..peer(2, "B")
+        }));
+
+        // The same client reconnected with a new ID.
+        snapshot.apply(PeerChange::Added(peer(3, "A2")));
+        let aliases: Vec<_> = snapshot
+            .deduplicated()
+            .into_iter()
+            .map(|p| p.alias)
+            .collect();
+        assert_eq!(aliases, ["B", "A2"]);
+
+        snapshot.apply(PeerChange::Removed(peer(3, "A2")));
+        let aliases: Vec<_> = snapshot
+            .deduplicated()
+            .into_iter()
+            .map(|p| p.alias)
+            .collect();
+        assert_eq!(aliases, ["A", "B"]);
+    }
+
+    #[cfg(feature = "webrtc-signaling")]
+    #[test]
+    fn peer_list_diffs_repeated_hello() {
+        let mut list = PeerList::default();
+        list.apply(&WsServerMessage::Hello {
+            client: peer(0, "Me"),
+            peers: vec![peer(1, "A"), peer(2, "B")],
+            resume_token: None,
+            room: None,
+        });
+
+        let changes = list.apply(&WsServerMessage::Hello {
+            client: peer(0, "Me"),
+            peers: vec![peer(2, "B2"), peer(3, "C")],
+            resume_token: None,
+            room: None,
+        });
+
+        assert_eq!(
+            changes,
+            vec![
+                PeerChange::Updated(peer(2, "B2")),
+                PeerChange::Added(peer(3, "C")),
+                PeerChange::Removed(peer(1, "A")),
+            ]
+        );
+        assert_eq!(list.peers.len(), 2);
+    }
 }
@@ -0,0 +1,496 @@
+//! Browser backend (feature `wasm`): signaling via the `WebSocket` API
+//! and data channels via `RTCPeerConnection`.
+//!
+//! The messages and the SDP encoding are shared with the native backend,
+//! so browser clients and native clients can connect to each other.
+//! The futures are not `Send` and must run on the browser's event loop,
+//! e.g. via [`wasm_bindgen_futures::spawn_local`].
+
+use crate::webrtc::sdp::{decode_sdp, encode_sdp};
+use crate::webrtc::signaling::{
+    connect_uri, validate_uri, ClientInfo, ClientInfoWithoutId, ConnectOptions,
+    SignalingConnection, SignalingProtocol, WsClientMessage, WsServerMessage,
+};
+use anyhow::Result;
+use bytes::Bytes;
+use std::collections::VecDeque;
+use std::rc::Rc;
+use tokio::sync::{mpsc, oneshot, Notify};
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+use web_sys::{
+    Event, MessageEvent, RtcConfiguration, RtcDataChannel, RtcDataChannelEvent, RtcDataChannelInit,
+    RtcDataChannelState, RtcDataChannelType, RtcIceGatheringState, RtcIceServer, RtcPeerConnection,
+    RtcSdpType, RtcSessionDescriptionInit, WebSocket,
+};
+
+fn js_error(value: JsValue) -> anyhow::Error {
+    match value.as_string() {
+        Some(message) => anyhow::anyhow!(message),
+        None => anyhow::anyhow!("{value:?}"),
+    }
+}
+
+enum SocketEvent {
+    Open,
+    Message(String),
+    Closed,
+}
+
+/// A browser websocket with its event handlers.
+/// The handlers are detached and the socket is closed when dropped.
+struct WebSocketHandle {
+    socket: WebSocket,
+    _on_open: Closure<dyn FnMut(Event)>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut(Event)>,
+}
+
+impl WebSocketHandle {
+    /// Opens the websocket, offering the subprotocols if `negotiate` is set.
+    /// Browsers do not expose why a connection failed, so any failure is reported alike.
+    async fn open(
+        uri: &str,
+        negotiate: bool,
+    ) -> Result<(Self, mpsc::UnboundedReceiver<SocketEvent>)> {
+        let socket = if negotiate {
+            let offered = js_sys::Array::new();
+            for protocol in SignalingProtocol::SUPPORTED {
+                offered.push(&JsValue::from_str(protocol.subprotocol()));
+            }
+            WebSocket::new_with_str_sequence(uri, &offered)
+        } else {
+            WebSocket::new(uri)
+        }
+        .map_err(js_error)?;
+
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+
+        let on_open = Closure::<dyn FnMut(Event)>::new({
+            let event_tx = event_tx.clone();
+            move |_| {
+                let _ = event_tx.send(SocketEvent::Open);
+            }
+        });
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new({
+            let event_tx = event_tx.clone();
+            move |event: MessageEvent| {
+                // Binary messages are not part of the protocol.
+                if let Some(message) = event.data().as_string() {
+                    let _ = event_tx.send(SocketEvent::Message(message));
+                }
+            }
+        });
+        // An error is always followed by a close event.
+        let on_close = Closure::<dyn FnMut(Event)>::new(move |_| {
+            let _ = event_tx.send(SocketEvent::Closed);
+        });
+
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+        let handle = Self {
+            socket,
+            _on_open: on_open,
+            _on_message: on_message,
+            _on_close: on_close,
+        };
+
+        match event_rx.recv().await {
+            Some(SocketEvent::Open) => Ok((handle, event_rx)),
+            _ => Err(anyhow::anyhow!("Failed to connect to {uri}")),
+        }
+    }
+}
+
+impl Drop for WebSocketHandle {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+impl SignalingConnection {
+    /// Connects to the signaling server using the browser's `WebSocket` API.
+    /// Behaves like [`SignalingConnection::connect_with_options`] with these limitations:
+    /// * Access tokens are not supported because browsers cannot set the `Authorization` header.
+    /// * Proof of work is not supported because browsers do not expose the rejection.
+    pub async fn connect_web<S: Into<String>>(
+        uri: S,
+        info: &ClientInfoWithoutId,
+        options: &ConnectOptions,
+    ) -> Result<SignalingConnection> {
+        let uri = uri.into();
+        validate_uri(&uri, options.allow_insecure)?;
+        if options.access_token.is_some() {
+            return Err(anyhow::anyhow!(
+                "Access tokens are not supported in browsers"
+            ));
+        }
+
+        let uri = connect_uri(&uri, info, options.resume_token.as_deref())?;
+
+        tracing::debug!("Connecting to the signaling server at {uri}");
+
+        let (socket, mut event_rx, protocol) = match WebSocketHandle::open(&uri, true).await {
+            Ok((socket, event_rx)) => {
+                let protocol = SignalingProtocol::from_subprotocol(&socket.socket.protocol())
+                    .ok_or_else(|| anyhow::anyhow!("Unsupported signaling protocol"))?;
+                (socket, event_rx, protocol)
+            }
+            Err(e) => {
+                // The server may predate the negotiation, browsers reject that alike.
+                tracing::debug!("{e}. Reconnecting without protocol negotiation...");
+                let (socket, event_rx) = WebSocketHandle::open(&uri, false).await?;
+                (socket, event_rx, SignalingProtocol::V1)
+            }
+        };
+
+        tracing::debug!(
+            "Connected to the signaling server using {protocol:?}. Waiting for hello..."
+        );
+
+        let (send_tx, mut send_rx) = mpsc::channel::<WsClientMessage>(1);
+
+        // Browsers answer pings of the server, so no keep-alive is needed.
+        spawn_local(async move {
+            while let Some(message) = send_rx.recv().await {
+                let message = serde_json::to_string(&message).expect("Failed to serialize message");
+                if socket.socket.send_with_str(&message).is_err() {
+                    break;
+                }
+            }
+
+            // Closes the connection once all senders are dropped.
+            drop(socket);
+        });
+
+        let (receive_tx, receive_rx) = mpsc::channel(1);
+        let (client_tx, client_rx) = oneshot::channel::<(ClientInfo, Option<String>)>();
+
+        spawn_local(async move {
+            // Only the first hello completes the handshake,
+            // later ones follow room changes and are just forwarded.
+            let mut client_tx = Some(client_tx);
+            while let Some(SocketEvent::Message(message)) = event_rx.recv().await {
+                let message = match serde_json::from_str::<WsServerMessage>(&message) {
+                    Ok(message) => message,
+                    Err(e) => {
+                        tracing::error!("Error: {e}, Server: {message}");
+                        continue;
+                    }
+                };
+
+                if let WsServerMessage::Hello {
+                    client,
+                    resume_token,
+                    ..
+                } = &message
+                {
+                    if let Some(client_tx) = client_tx.take() {
+                        let _ = client_tx.send((client.clone(), resume_token.clone()));
+                    }
+                }
+
+                if let Err(e) = receive_tx.send(message).await {
+                    tracing::error!("{e:?}");
+                }
+            }
+        });
+
+        let (client, resume_token) = client_rx
+            .await
+            .map_err(|_| anyhow::anyhow!("Connection closed before receiving hello"))?;
+
+        tracing::debug!("Received hello from server: {client:?}");
+
+        Ok(SignalingConnection {
+            client,
+            resume_token,
+            protocol,
+            tx: send_tx,
+            rx: receive_rx,
+        })
+    }
+}
+
+/// A message received on a [`WebDataChannel`].
+#[derive(Clone, Debug)]
+pub struct WebDataChannelMessage {
+    /// Whether the message was sent as text.
+    pub is_string: bool,
+    pub data: Bytes,
+}
+
+enum ChannelEvent {
+    Open,
+    Message(WebDataChannelMessage),
+    Closed,
+}
+
+/// A data channel of a [`WebPeerConnection`].
+/// The handlers are detached and the channel is closed when dropped.
+pub struct WebDataChannel {
+    channel: RtcDataChannel,
+    event_rx: mpsc::UnboundedReceiver<ChannelEvent>,
+
+    /// Messages received while waiting for the channel to open.
+    pending: VecDeque<WebDataChannelMessage>,
+    _on_open: Closure<dyn FnMut(Event)>,
+    _on_message: Closure<dyn FnMut(MessageEvent)>,
+    _on_close: Closure<dyn FnMut(Event)>,
+}
+
+impl WebDataChannel {
+    fn new(channel: RtcDataChannel) -> Self {
+        channel.set_binary_type(RtcDataChannelType::Arraybuffer);
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+
+        let on_open = Closure::<dyn FnMut(Event)>::new({
+            let event_tx = event_tx.clone();
+            move |_| {
+                let _ = event_tx.send(ChannelEvent::Open);
+            }
+        });
+        let on_message = Closure::<dyn FnMut(MessageEvent)>::new({
+            let event_tx = event_tx.clone();
+            move |event: MessageEvent| {
+                let data = event.data();
+                let message = match data.as_string() {
+                    Some(text) => WebDataChannelMessage {
+                        is_string: true,
+                        data: Bytes::from(text),
+                    },
+                    None => WebDataChannelMessage {
+                        is_string: false,
+                        data: Bytes::from(js_sys::Uint8Array::new(&data).to_vec()),
+                    },
+                };
+                let _ = event_tx.send(ChannelEvent::Message(message));
+            }
+        });
+        let on_close = Closure::<dyn FnMut(Event)>::new(move |_| {
+            let _ = event_tx.send(ChannelEvent::Closed);
+        });
+
+        channel.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        channel.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        channel.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+
+        Self {
+            channel,
+            event_rx,
+            pending: VecDeque::new(),
+            _on_open: on_open,
+            _on_message: on_message,
+            _on_close: on_close,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        self.channel.label()
+    }
+
+    /// Waits until the channel is open.
+    /// Messages received before are kept for [`recv`](Self::recv).
+    pub async fn wait_open(&mut self) -> Result<()> {
+        if self.channel.ready_state() == RtcDataChannelState::Open {
+            return Ok(());
+        }
+
+        loop {
+            match self.event_rx.recv().await {
+                Some(ChannelEvent::Open) => return Ok(()),
+                Some(ChannelEvent::Message(message)) => self.pending.push_back(message),
+                Some(ChannelEvent::Closed) | None => {
+                    return Err(anyhow::anyhow!("Data channel closed before opening"))
+                }
+            }
+        }
+    }
+
+    /// Receives the next message. Returns `None` once the channel is closed.
+    pub async fn recv(&mut self) -> Option<WebDataChannelMessage> {
+        if let Some(message) = self.pending.pop_front() {
+            return Some(message);
+        }
+
+        loop {
+            match self.event_rx.recv().await? {
+                ChannelEvent::Open => continue,
+                ChannelEvent::Message(message) => return Some(message),
+                ChannelEvent::Closed => return None,
+            }
+        }
+    }
+
+    pub fn send_text(&self, text: &str) -> Result<()> {
+        self.channel.send_with_str(text).map_err(js_error)
+    }
+
+    pub fn send_binary(&self, data: &[u8]) -> Result<()> {
+        self.channel.send_with_u8_array(data).map_err(js_error)
+    }
+
+    /// Number of bytes queued but not yet sent.
+    /// Use it to apply backpressure, browsers close the channel if the buffer overflows.
+    pub fn buffered_amount(&self) -> u32 {
+        self.channel.buffered_amount()
+    }
+}
+
+impl Drop for WebDataChannel {
+    fn drop(&mut self) {
+        self.channel.set_onopen(None);
+        self.channel.set_onmessage(None);
+        self.channel.set_onclose(None);
+        self.channel.close();
+    }
+}
+
+/// A browser `RTCPeerConnection` exchanging SDPs in the format of the native backend
+/// (see [`encode_sdp`]), so the SDPs can be sent via [`SignalingConnection`] as is.
+///
+/// ICE candidates are not trickled: offers and answers are created
+/// once the gathering is complete.
+pub struct WebPeerConnection {
+    connection: RtcPeerConnection,
+    gathering_complete: Rc<Notify>,
+    data_channel_rx: mpsc::UnboundedReceiver<WebDataChannel>,
+    _on_ice_gathering_state_change: Closure<dyn FnMut(Event)>,
+    _on_data_channel: Closure<dyn FnMut(RtcDataChannelEvent)>,
+}
+
+impl WebPeerConnection {
+    pub fn new(stun_servers: &[String]) -> Result<Self> {
+        let urls = js_sys::Array::new();
+        for url in stun_servers {
+            urls.push(&JsValue::from_str(url));
+        }
+        let ice_server = RtcIceServer::new();
+        ice_server.set_urls(&urls);
+
+        let config = RtcConfiguration::new();
+        config.set_ice_servers(&js_sys::Array::of1(&ice_server));
+
+        let connection = RtcPeerConnection::new_with_configuration(&config).map_err(js_error)?;
+
+        let gathering_complete = Rc::new(Notify::new());
+        let on_ice_gathering_state_change = Closure::<dyn FnMut(Event)>::new({
+            let connection = connection.clone();
+            let gathering_complete = gathering_complete.clone();
+            move |_| {
+                if connection.ice_gathering_state() == RtcIceGatheringState::Complete {
+                    gathering_complete.notify_one();
+                }
+            }
+        });
+
+        let (data_channel_tx, data_channel_rx) = mpsc::unbounded_channel();
+        let on_data_channel =
+            Closure::<dyn FnMut(RtcDataChannelEvent)>::new(move |event: RtcDataChannelEvent| {
+                let _ = data_channel_tx.send(WebDataChannel::new(event.channel()));
+            });
+
+        connection.set_onicegatheringstatechange(Some(
+            on_ice_gathering_state_change.as_ref().unchecked_ref(),
+        ));
+        connection.set_ondatachannel(Some(on_data_channel.as_ref().unchecked_ref()));
+
+        Ok(Self {
+            connection,
+            gathering_complete,
+            data_channel_rx,
+            _on_ice_gathering_state_change: on_ice_gathering_state_change,
+            _on_data_channel: on_data_channel,
+        })
+    }
+
+    /// Creates an ordered data channel. Must be called before [`create_offer`](Self::create_offer).
+    pub fn create_data_channel(&self, label: &str) -> WebDataChannel {
+        let init = RtcDataChannelInit::new();
+        init.set_ordered(true);
+        WebDataChannel::new(
+            self.connection
+                .create_data_channel_with_data_channel_dict(label, &init),
+        )
+    }
+
+    /// Waits for a data channel created by the remote peer.
+    /// Returns `None` once the connection is dropped.
+    pub async fn accept_data_channel(&mut self) -> Option<WebDataChannel> {
+        self.data_channel_rx.recv().await
+    }
+
+    /// Creates the encoded offer to send to the remote peer.
+    pub async fn create_offer(&self) -> Result<String> {
+        let offer = JsFuture::from(self.connection.create_offer())
+            .await
+            .map_err(js_error)?;
+        self.set_local_description(offer, RtcSdpType::Offer).await
+    }
+
+    /// Applies the encoded offer of the remote peer and returns the encoded answer.
+    pub async fn accept_offer(&self, offer: &str) -> Result<String> {
+        self.set_remote_description(offer, RtcSdpType::Offer)
+            .await?;
+        let answer = JsFuture::from(self.connection.create_answer())
+            .await
+            .map_err(js_error)?;
+        self.set_local_description(answer, RtcSdpType::Answer).await
+    }
+
+    /// Applies the encoded answer of the remote peer.
+    pub async fn accept_answer(&self, answer: &str) -> Result<()> {
+        self.set_remote_description(answer, RtcSdpType::Answer)
+            .await
+    }
+
+    async fn set_local_description(
+        &self,
+        description: JsValue,
+        kind: RtcSdpType,
+    ) -> Result<String> {
+        let sdp = js_sys::Reflect::get(&description, &JsValue::from_str("sdp"))
+            .map_err(js_error)?
+            .as_string()
+            .ok_or_else(|| anyhow::anyhow!("Missing SDP"))?;
+        let init = RtcSessionDescriptionInit::new(kind);
+        init.set_sdp(&sdp);
+        JsFuture::from(self.connection.set_local_description(&init))
+            .await
+            .map_err(js_error)?;
+
+        if self.connection.ice_gathering_state() != RtcIceGatheringState::Complete {
+            self.gathering_complete.notified().await;
+        }
+
+        let description = self
+            .connection
+            .local_description()
+            .ok_or_else(|| anyhow::anyhow!("Missing local description"))?;
+        Ok(encode_sdp(&description.sdp()))
+    }
+
+    async fn set_remote_description(&self, sdp: &str, kind: RtcSdpType) -> Result<()> {
+        let init = RtcSessionDescriptionInit::new(kind);
+        init.set_sdp(&decode_sdp(sdp)?);
+        JsFuture::from(self.connection.set_remote_description(&init))
+            .await
+            .map_err(js_error)?;
+        Ok(())
+    }
+}
+
+impl Drop for WebPeerConnection {
+    fn drop(&mut self) {
+        self.connection.set_onicegatheringstatechange(None);
+        self.connection.set_ondatachannel(None);
+        self.connection.close();
+    }
+}
@@ -2,7 +2,10 @@ use crate::crypto;
 use crate::crypto::token::{SigningTokenKey, VerifyingTokenKey};
 use crate::model::transfer::FileDto;
 use crate::util::base64;
-use crate::webrtc::signaling::{ManagedSignalingConnection, WsServerSdpMessage};
+pub use crate::webrtc::sdp::{decode_sdp, encode_sdp};
+use crate::webrtc::signaling::{
+    capability, ManagedSignalingConnection, OfferExpired, WsServerSdpMessage, DEFAULT_OFFER_TTL,
+};
 use anyhow::Result;
 use bytes::{Bytes, BytesMut};
 use flate2::read::ZlibDecoder;
@@ -12,26 +15,123 @@ use std::collections::{HashMap, HashSet};
 use std::future::Future;
 use std::io::{Read, Write};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tokio::sync::{mpsc, oneshot};
+use tokio::task::AbortHandle;
 use uuid::Uuid;
 use webrtc::api::APIBuilder;
 use webrtc::data_channel::data_channel_init::RTCDataChannelInit;
 use webrtc::data_channel::data_channel_message::DataChannelMessage;
 use webrtc::data_channel::RTCDataChannel;
+use webrtc::ice::candidate::CandidateType;
 use webrtc::ice_transport::ice_server::RTCIceServer;
 use webrtc::peer_connection::configuration::RTCConfiguration;
 use webrtc::peer_connection::peer_connection_state::RTCPeerConnectionState;
 use webrtc::peer_connection::sdp::session_description::RTCSessionDescription;
 use webrtc::peer_connection::RTCPeerConnection;
+use webrtc::stats::StatsReportType;
+
+/// The version of the data channel protocol announced in [`RTCNonceMessage`].
+/// Bumped on incompatible changes. Compatible additions are new optional fields
+/// which older peers ignore.
+pub const RTC_PROTOCOL_VERSION: u32 = 2;
+
+/// The oldest data channel protocol version this crate can speak.
+/// Peers announcing an older version are refused.
+pub const MIN_RTC_PROTOCOL_VERSION: u32 = 1;
+
+/// The first protocol version announcing capabilities in [`RTCNonceMessage`].
+const CAPABILITIES_PROTOCOL_VERSION: u32 = 2;
+
+/// Upper bound for decompressed messages, so a small message cannot exhaust the memory.
+const MAX_DECOMPRESSED_SIZE: u64 = 64 * 1024 * 1024;
 
 /// Nonce message exchanged by both peers
 /// starting with the sending peer.
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, PartialEq, Eq, Deserialize, Serialize)]
 struct RTCNonceMessage {
+    /// The data channel protocol version of the peer.
+    /// Missing for older peers which speak version 1.
+    #[serde(default = "legacy_protocol_version")]
+    version: u32,
+
     /// Nonce to be used to hash in combination with the public key.
     /// Encoded in base64 (url-safe without padding).
     nonce: String,
+
+    /// Bitset of optional protocol features supported by the peer.
+    /// Missing for older peers which is treated as no capabilities.
+    #[serde(default, skip_serializing_if = "capability::is_empty")]
+    capabilities: u32,
+}
+
+fn legacy_protocol_version() -> u32 {
+    1
+}
+
+/// The protocol both peers agreed on in the nonce exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Negotiated {
+    /// The lower of both protocol versions, i.e. a newer peer downgrades to ours.
+    version: u32,
+
+    /// The capabilities announced by both peers.
+    capabilities: u32,
+}
+
+impl Negotiated {
+    /// Fails if the remote peer is too old to be understood.
+    fn new(remote_version: u32, remote_capabilities: u32) -> Result<Self> {
+        if remote_version < MIN_RTC_PROTOCOL_VERSION {
+            return Err(anyhow::anyhow!(
+                "Unsupported protocol version {remote_version} of the remote peer, \
+                 expected at least {MIN_RTC_PROTOCOL_VERSION}"
+            ));
+        }
+
+        let version = remote_version.min(RTC_PROTOCOL_VERSION);
+        let capabilities = match version >= CAPABILITIES_PROTOCOL_VERSION {
+            true => remote_capabilities & capability::SUPPORTED,
+            false => 0,
+        };
+
+        Ok(Self {
+            version,
+            capabilities,
+        })
+    }
+
+    fn supports(&self, capability: u32) -> bool {
+        self.capabilities & capability == capability
+    }
+
+    /// Compresses a message sent in chunks if both peers support it.
+    fn encode_chunked(&self, message: String) -> Result<Bytes> {
+        if !self.supports(capability::COMPRESSION) {
+            return Ok(Bytes::from(message));
+        }
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(message.as_bytes())?;
+        Ok(Bytes::from(encoder.finish()?))
+    }
+
+    /// Reverts [`Negotiated::encode_chunked`].
+    fn decode_chunked(&self, data: Bytes) -> Result<Bytes> {
+        if !self.supports(capability::COMPRESSION) {
+            return Ok(data);
+        }
+
+        let mut decoded = Vec::new();
+        ZlibDecoder::new(&*data)
+            .take(MAX_DECOMPRESSED_SIZE + 1)
+            .read_to_end(&mut decoded)
+            .map_err(|e| anyhow::anyhow!("Failed to decompress message: {e}"))?;
+        if decoded.len() as u64 > MAX_DECOMPRESSED_SIZE {
+            return Err(anyhow::anyhow!("Decompressed message is too large"));
+        }
+        Ok(Bytes::from(decoded))
+    }
 }
 
 /// Sending peer sends the token.
@@ -137,7 +237,7 @@ pub struct RTCSendFileResponse {
     pub error: Option<String>,
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum RTCStatus {
     /// Received remote SDP offer/answer. Ready to start P2P connection.
     SdpExchanged,
@@ -162,8 +262,33 @@ pub enum RTCStatus {
 
     /// Error occurred. Connection is closed.
     Error(String),
+
+    /// No answer to the offer received in time. Connection is closed.
+    Timeout,
+
+    /// Paused locally. No file data is transferred until resumed.
+    Paused,
+
+    /// Resumed after [`RTCStatus::Paused`].
+    Resumed,
 }
 
+/// Statistics of the connection, sent every [`STATS_INTERVAL`] once connected.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RTCStats {
+    /// Bytes sent and received per second since the previous stats.
+    pub bytes_per_second: u64,
+
+    /// Round-trip time of the selected candidate pair in milliseconds.
+    /// `None` if not measured by the ICE agent.
+    pub round_trip_time_ms: Option<u32>,
+
+    /// Whether the connection goes through a TURN relay instead of a direct path.
+    pub relayed: bool,
+}
+
+pub const STATS_INTERVAL: Duration = Duration::from_secs(1);
+
 #[derive(Debug, Eq, PartialEq)]
 pub struct RTCFileError {
     pub file_id: String,
@@ -176,8 +301,103 @@ pub struct PinConfig {
     pub max_tries: u8,
 }
 
+/// How the local SDP offer is delivered to the receiving peer
+/// and how its SDP answer is obtained.
+pub enum OfferExchange<'a> {
+    /// Exchange via the signaling server.
+    Signaling {
+        connection: &'a ManagedSignalingConnection,
+        target_id: Uuid,
+
+        /// How long to wait for the answer. See [`DEFAULT_OFFER_TTL`].
+        offer_ttl: Duration,
+    },
+
+    /// Exchange out of band (copy-paste, QR code, etc.) without a signaling server.
+    /// The encoded offer is published on `offer_tx`,
+    /// the encoded answer of the receiving peer is expected on `answer_rx`.
+    /// Both are in the [`encode_sdp`] format.
+    Manual {
+        offer_tx: oneshot::Sender<String>,
+        answer_rx: oneshot::Receiver<String>,
+    },
+}
+
+impl OfferExchange<'_> {
+    /// Publishes the encoded offer and waits for the encoded answer.
+    async fn exchange(self, offer: String) -> Result<String> {
+        match self {
+            OfferExchange::Signaling {
+                connection,
+                target_id,
+                offer_ttl,
+            } => {
+                let session_id = Uuid::new_v4().to_string();
+
+                let answer = connection
+                    .send_offer_and_wait(session_id, target_id, offer, offer_ttl)
+                    .await?;
+
+                Ok(answer.sdp)
+            }
+            OfferExchange::Manual {
+                offer_tx,
+                answer_rx,
+            } => {
+                offer_tx
+                    .send(offer)
+                    .map_err(|_| anyhow::anyhow!("Failed to publish offer"))?;
+
+                Ok(answer_rx.await?)
+            }
+        }
+    }
+}
+
+/// Where the remote SDP offer comes from and how the local SDP answer is delivered back.
+pub enum AnswerExchange<'a> {
+    /// Offer received from the signaling server, answered via the same server.
+    Signaling {
+        connection: &'a ManagedSignalingConnection,
+        offer: &'a WsServerSdpMessage,
+    },
+
+    /// Offer obtained out of band (copy-paste, QR code, etc.) without a signaling server.
+    /// The encoded answer is published on `answer_tx`.
+    /// Both are in the [`encode_sdp`] format.
+    Manual {
+        offer: String,
+        answer_tx: oneshot::Sender<String>,
+    },
+}
+
+impl AnswerExchange<'_> {
+    /// The encoded SDP offer of the sending peer.
+    fn offer(&self) -> &str {
+        match self {
+            AnswerExchange::Signaling { offer, .. } => &offer.sdp,
+            AnswerExchange::Manual { offer, .. } => offer,
+        }
+    }
+
+    /// Delivers the encoded answer to the sending peer.
+    async fn reply(self, answer: String) -> Result<()> {
+        match self {
+            AnswerExchange::Signaling { connection, offer } => {
+                connection
+                    .send_answer(offer.session_id.clone(), offer.peer.id, answer)
+                    .await
+            }
+            AnswerExchange::Manual { answer_tx, .. } => answer_tx
+                .send(answer)
+                .map_err(|_| anyhow::anyhow!("Failed to publish answer")),
+        }
+    }
+}
+
 const CHANNEL_LABEL: &str = "data";
 
+#[allow(clippy::too_many_arguments)]
 pub async fn send_offer(
     signaling: &ManagedSignalingConnection,
     stun_servers: Vec<String>,
@@ -187,6 +407,47 @@ pub async fn send_offer(
     pin: Option<PinConfig>,
     files: Vec<FileDto>,
     status_tx: mpsc::Sender<RTCStatus>,
+    stats_tx: mpsc::Sender<RTCStats>,
+    selected_files_tx: oneshot::Sender<HashSet<String>>,
+    error_tx: mpsc::Sender<RTCFileError>,
+    pin_tx: mpsc::Sender<oneshot::Sender<String>>,
+    pair_tx: oneshot::Sender<oneshot::Sender<bool>>,
+    sending_rx: mpsc::Receiver<RTCFile>,
+) -> Result<()> {
+    send_offer_with(
+        OfferExchange::Signaling {
+            connection: signaling,
+            target_id,
+            offer_ttl: DEFAULT_OFFER_TTL,
+        },
+        stun_servers,
+        signing_key,
+        expecting_public_key,
+        pin,
+        files,
+        status_tx,
+        stats_tx,
+        selected_files_tx,
+        error_tx,
+        pin_tx,
+        pair_tx,
+        sending_rx,
+    )
+    .await
+}
+
+/// Same as [`send_offer`] but with a custom way to exchange the SDP offer and answer,
+/// e.g. [`OfferExchange::Manual`] to pair two devices without a signaling server.
+#[allow(clippy::too_many_arguments)]
+pub async fn send_offer_with(
+    exchange: OfferExchange<'_>,
+    stun_servers: Vec<String>,
+    signing_key: SigningTokenKey,
+    expecting_public_key: Option<Box<dyn VerifyingTokenKey + Send>>,
+    pin: Option<PinConfig>,
+    files: Vec<FileDto>,
+    status_tx: mpsc::Sender<RTCStatus>,
+    stats_tx: mpsc::Sender<RTCStats>,
     selected_files_tx: oneshot::Sender<HashSet<String>>,
     error_tx: mpsc::Sender<RTCFileError>,
     pin_tx: mpsc::Sender<oneshot::Sender<String>>,
@@ -231,20 +492,22 @@ pub async fn send_offer(
             tracing::debug!("Data channel opened. Exchanging nonce...");
 
             // Nonce exchange
-            let nonce = {
+            let (nonce, negotiated) = {
                 let mut local_nonce = crypto::nonce::generate_nonce();
                 data_channel
                     .send_text(&serde_json::to_string(&RTCNonceMessage {
+                        version: RTC_PROTOCOL_VERSION,
                         nonce: base64::encode(&local_nonce),
+                        capabilities: capability::SUPPORTED,
                     })?)
                     .await
                     .map_err(|e| anyhow::anyhow!("Failed to send nonce: {e}"))?;
 
-                let mut remote_nonce = receive_nonce(&mut receive_rx).await?;
+                let (mut remote_nonce, negotiated) = receive_nonce(&mut receive_rx).await?;
 
                 // Final nonce: sender_nonce || receiver_nonce
                 local_nonce.append(&mut remote_nonce);
-                local_nonce
+                (local_nonce, negotiated)
             };
 
             tracing::debug!("Nonce exchanged. Exchanging token...");
@@ -302,7 +565,7 @@ pub async fn send_offer(
                     &status_tx,
                     &pin_tx,
                     &mut receive_rx,
-                    false,
+                    None,
                     |data| {
                         let Ok(pin_res) = serde_json::from_slice::<RTCPinReceivingResponse>(&data)
                         else {
@@ -336,16 +599,15 @@ pub async fn send_offer(
                     |data_channel, result| {
                         let data_channel = Arc::clone(&data_channel);
                         async move {
-                            send_string_in_chunks(
+                            let response = serde_json::to_string(&match result {
+                                VerifyPinResult::PinRequired => RTCPinSendingResponse::PinRequired,
+                                VerifyPinResult::TooManyAttempts => {
+                                    RTCPinSendingResponse::TooManyAttempts
+                                }
+                            })?;
+                            send_bytes_in_chunks(
                                 &data_channel,
-                                serde_json::to_string(&match result {
-                                    VerifyPinResult::PinRequired => {
-                                        RTCPinSendingResponse::PinRequired
-                                    }
-                                    VerifyPinResult::TooManyAttempts => {
-                                        RTCPinSendingResponse::TooManyAttempts
-                                    }
-                                })?,
+                                negotiated.encode_chunked(response)?,
                                 |data_channel, chunk| async move {
                                     data_channel.send(&chunk).await?;
                                     Ok(data_channel)
@@ -366,9 +628,10 @@ pub async fn send_offer(
 
             {
                 // send file list message
-                let file_list_req = serde_json::to_string(&RTCPinSendingResponse::Ok { files })?;
+                let file_list_req = negotiated
+                    .encode_chunked(serde_json::to_string(&RTCPinSendingResponse::Ok { files })?)?;
 
-                let result = send_string_in_chunks(
+                let result = send_bytes_in_chunks(
                     Arc::clone(&data_channel),
                     file_list_req,
                     |data_channel, chunk| async move {
@@ -562,34 +825,33 @@ pub async fn send_offer(
         })
     };
 
+    let _guard = ConnectionGuard {
+        peer_connection: Arc::clone(&peer_connection),
+        task: send_task.abort_handle(),
+        stats_task: tokio::spawn(report_stats(Arc::clone(&peer_connection), stats_tx))
+            .abort_handle(),
+    };
+
     let offer = peer_connection.create_offer(None).await?;
     let mut gather_complete = peer_connection.gathering_complete_promise().await;
     peer_connection.set_local_description(offer).await?;
     let _ = gather_complete.recv().await;
 
-    let session_id = Uuid::new_v4().to_string();
     let local_description = peer_connection
         .local_description()
         .await
         .ok_or_else(|| anyhow::anyhow!("Could not generate local_description"))?;
 
-    signaling
-        .send_offer(
-            session_id.clone(),
-            target_id,
-            encode_sdp(&local_description.sdp),
-        )
-        .await?;
-
-    let (tx_answer, rx_answer) = oneshot::channel();
-
-    signaling
-        .on_answer(session_id, |message| {
-            tx_answer.send(message.sdp).unwrap();
-        })
-        .await;
-
-    let remote_desc = rx_answer.await?;
+    let remote_desc = match exchange.exchange(encode_sdp(&local_description.sdp)).await {
+        Ok(remote_desc) => remote_desc,
+        Err(e) if e.is::<OfferExpired>() => {
+            tracing::debug!("{e}");
+            let _ = status_tx.send(RTCStatus::Timeout).await;
+            peer_connection.close().await?;
+            return Ok(());
+        }
+        Err(e) => return Err(e),
+    };
 
     if let Err(e) = status_tx.send(RTCStatus::SdpExchanged).await {
         peer_connection.close().await?;
@@ -626,6 +888,7 @@ pub async fn send_offer(
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn accept_offer(
     signaling: &ManagedSignalingConnection,
     stun_servers: Vec<String>,
@@ -634,6 +897,46 @@ pub async fn accept_offer(
     expecting_public_key: Option<Box<dyn VerifyingTokenKey + Send>>,
     pin: Option<PinConfig>,
     status_tx: mpsc::Sender<RTCStatus>,
+    stats_tx: mpsc::Sender<RTCStats>,
+    files_tx: oneshot::Sender<Vec<FileDto>>,
+    selected_files_rx: oneshot::Receiver<Option<HashSet<String>>>,
+    error_tx: mpsc::Sender<RTCFileError>,
+    pin_tx: mpsc::Sender<oneshot::Sender<String>>,
+    receiving_tx: mpsc::Sender<RTCFile>,
+    user_error_tx: mpsc::Receiver<RTCSendFileResponse>,
+) -> Result<()> {
+    accept_offer_with(
+        AnswerExchange::Signaling {
+            connection: signaling,
+            offer,
+        },
+        stun_servers,
+        signing_key,
+        expecting_public_key,
+        pin,
+        status_tx,
+        stats_tx,
+        files_tx,
+        selected_files_rx,
+        error_tx,
+        pin_tx,
+        receiving_tx,
+        user_error_tx,
+    )
+    .await
+}
+
+/// Same as [`accept_offer`] but with a custom way to exchange the SDP offer and answer,
+/// e.g. [`AnswerExchange::Manual`] to pair two devices without a signaling server.
+#[allow(clippy::too_many_arguments)]
+pub async fn accept_offer_with(
+    exchange: AnswerExchange<'_>,
+    stun_servers: Vec<String>,
+    signing_key: SigningTokenKey,
+    expecting_public_key: Option<Box<dyn VerifyingTokenKey + Send>>,
+    pin: Option<PinConfig>,
+    status_tx: mpsc::Sender<RTCStatus>,
+    stats_tx: mpsc::Sender<RTCStats>,
     files_tx: oneshot::Sender<Vec<FileDto>>,
     selected_files_rx: oneshot::Receiver<Option<HashSet<String>>>,
     error_tx: mpsc::Sender<RTCFileError>,
@@ -675,20 +978,22 @@ pub async fn accept_offer(
             tracing::debug!("Data channel opened. Exchanging nonce...");
 
             // Nonce exchange
-            let nonce = {
-                let mut remote_nonce = receive_nonce(&mut receive_rx).await?;
+            let (nonce, negotiated) = {
+                let (mut remote_nonce, negotiated) = receive_nonce(&mut receive_rx).await?;
 
                 let mut local_nonce = crypto::nonce::generate_nonce();
                 data_channel
                     .send_text(&serde_json::to_string(&RTCNonceMessage {
+                        version: RTC_PROTOCOL_VERSION,
                         nonce: base64::encode(&local_nonce),
+                        capabilities: capability::SUPPORTED,
                     })?)
                     .await
                     .map_err(|e| anyhow::anyhow!("Failed to send nonce: {e}"))?;
 
                 // Final nonce: sender_nonce || receiver_nonce
                 remote_nonce.append(&mut local_nonce);
-                remote_nonce
+                (remote_nonce, negotiated)
             };
 
             tracing::debug!("Nonce exchanged. Exchanging token...");
@@ -773,7 +1078,8 @@ pub async fn accept_offer(
             tracing::debug!("Waiting for sender PIN status...");
 
             let pin_response = {
-                let bytes = receive_string_from_chunks(&mut receive_rx).await;
+                let bytes =
+                    negotiated.decode_chunked(receive_string_from_chunks(&mut receive_rx).await)?;
                 let parsed: RTCPinSendingResponse =
                     serde_json::from_slice(&*bytes).map_err(|e| {
                         anyhow::anyhow!("Failed to deserialize file list response: {e}")
@@ -790,7 +1096,7 @@ pub async fn accept_offer(
                         &status_tx,
                         &pin_tx,
                         &mut receive_rx,
-                        true,
+                        Some(negotiated),
                         |data| {
                             let Ok(pin_res) =
                                 serde_json::from_slice::<RTCPinSendingResponse>(&data)
@@ -1018,7 +1324,14 @@ pub async fn accept_offer(
         })
     };
 
-    let remote_desc_sdp = decode_sdp(&offer.sdp)?;
+    let _guard = ConnectionGuard {
+        peer_connection: Arc::clone(&peer_connection),
+        task: receive_task.abort_handle(),
+        stats_task: tokio::spawn(report_stats(Arc::clone(&peer_connection), stats_tx))
+            .abort_handle(),
+    };
+
+    let remote_desc_sdp = decode_sdp(exchange.offer())?;
     let remote_desc = RTCSessionDescription::offer(remote_desc_sdp)?;
     peer_connection.set_remote_description(remote_desc).await?;
 
@@ -1033,13 +1346,7 @@ pub async fn accept_offer(
         .await
         .ok_or_else(|| anyhow::anyhow!("generate local_description failed!"))?;
 
-    signaling
-        .send_answer(
-            offer.session_id.clone(),
-            offer.peer.id,
-            encode_sdp(&local_description.sdp),
-        )
-        .await?;
+    exchange.reply(encode_sdp(&local_description.sdp)).await?;
 
     if let Err(e) = status_tx.send(RTCStatus::SdpExchanged).await {
         peer_connection.close().await?;
@@ -1097,7 +1404,86 @@ async fn create_peer_connection(
     Ok((Arc::new(peer_connection), done_rx))
 }
 
-async fn receive_nonce(receive_rx: &mut mpsc::Receiver<DataChannelMessage>) -> Result<Vec<u8>> {
+/// Stops the data channel task and closes the peer connection when dropped,
+/// so aborting [`send_offer`] or [`accept_offer`] also disconnects the remote peer.
+/// Closing an already closed connection does nothing.
+struct ConnectionGuard {
+    peer_connection: Arc<RTCPeerConnection>,
+    task: AbortHandle,
+    stats_task: AbortHandle,
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.task.abort();
+        self.stats_task.abort();
+
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let peer_connection = Arc::clone(&self.peer_connection);
+        runtime.spawn(async move {
+            if let Err(e) = peer_connection.close().await {
+                tracing::error!("Failed to close peer connection: {e}");
+            }
+        });
+    }
+}
+
+/// Sends the [`RTCStats`] of the connection every [`STATS_INTERVAL`]
+/// once a candidate pair has been nominated.
+/// Stats are dropped while the previous ones have not been received yet.
+async fn report_stats(peer_connection: Arc<RTCPeerConnection>, stats_tx: mpsc::Sender<RTCStats>) {
+    let mut interval = tokio::time::interval(STATS_INTERVAL);
+    let mut previous: Option<(Instant, u64)> = None;
+
+    while !stats_tx.is_closed() {
+        interval.tick().await;
+
+        let report = peer_connection.get_stats().await;
+        let Some(pair) = report.reports.values().find_map(|stats| match stats {
+            StatsReportType::CandidatePair(pair) if pair.nominated => Some(pair),
+            _ => None,
+        }) else {
+            continue;
+        };
+
+        let relayed = [&pair.local_candidate_id, &pair.remote_candidate_id]
+            .into_iter()
+            .any(|id| {
+                matches!(
+                    report.reports.get(id),
+                    Some(StatsReportType::LocalCandidate(candidate) | StatsReportType::RemoteCandidate(candidate))
+                        if candidate.candidate_type == CandidateType::Relay
+                )
+            });
+
+        let bytes = match report.reports.get("ice_transport") {
+            Some(StatsReportType::Transport(transport)) => {
+                (transport.bytes_sent + transport.bytes_received) as u64
+            }
+            _ => 0,
+        };
+        let now = Instant::now();
+        let Some((previous_at, previous_bytes)) = previous.replace((now, bytes)) else {
+            continue;
+        };
+        let elapsed = now.duration_since(previous_at).as_secs_f64();
+
+        let _ = stats_tx.try_send(RTCStats {
+            bytes_per_second: (bytes.saturating_sub(previous_bytes) as f64 / elapsed) as u64,
+            round_trip_time_ms: (pair.current_round_trip_time > 0.0)
+                .then(|| (pair.current_round_trip_time * 1000.0).round() as u32),
+            relayed,
+        });
+    }
+}
+
+/// Receives the nonce of the remote peer and negotiates the protocol with it.
+async fn receive_nonce(
+    receive_rx: &mut mpsc::Receiver<DataChannelMessage>,
+) -> Result<(Vec<u8>, Negotiated)> {
     let remote_nonce = match receive_rx.recv().await {
         Some(msg) => {
             if !msg.is_string {
@@ -1105,6 +1491,7 @@ async fn receive_nonce(receive_rx: &mut mpsc::Receiver<DataChannelMessage>) -> R
             }
 
             let nonce_msg: RTCNonceMessage = serde_json::from_slice(&msg.data)?;
+            tracing::debug!("Remote protocol version: {}", nonce_msg.version);
             let remote_nonce = base64::decode(&nonce_msg.nonce)
                 .map_err(|e| anyhow::anyhow!("Failed to decode remote nonce: {e}"))?;
 
@@ -1112,7 +1499,14 @@ async fn receive_nonce(receive_rx: &mut mpsc::Receiver<DataChannelMessage>) -> R
                 return Err(anyhow::anyhow!("Invalid remote nonce"));
             }
 
-            remote_nonce
+            let negotiated = Negotiated::new(nonce_msg.version, nonce_msg.capabilities)?;
+            tracing::debug!(
+                "Negotiated protocol version {} with capabilities {:#b}",
+                negotiated.version,
+                negotiated.capabilities
+            );
+
+            (remote_nonce, negotiated)
         }
         None => {
             return Err(anyhow::anyhow!("Failed to receive nonce"));
@@ -1129,12 +1523,15 @@ enum ChallengePinResult<T> {
     ParseError(anyhow::Error),
 }
 
+/// Sends PINs until the remote peer accepts one.
+/// `receive_in_chunks` is set if the responses are sent in chunks,
+/// which are decoded according to the negotiated protocol.
 async fn handle_pin<T>(
     data_channel: &Arc<RTCDataChannel>,
     status_tx: &mpsc::Sender<RTCStatus>,
     pin_tx: &mpsc::Sender<oneshot::Sender<String>>,
     receive_rx: &mut mpsc::Receiver<DataChannelMessage>,
-    receive_in_chunks: bool,
+    receive_in_chunks: Option<Negotiated>,
     parse_response: fn(Bytes) -> ChallengePinResult<T>,
 ) -> Result<T> {
     loop {
@@ -1154,8 +1551,10 @@ async fn handle_pin<T>(
             .await?;
 
         let bytes = match receive_in_chunks {
-            true => receive_string_from_chunks(receive_rx).await,
-            false => {
+            Some(negotiated) => {
+                negotiated.decode_chunked(receive_string_from_chunks(receive_rx).await)?
+            }
+            None => {
                 let Some(init_status) = receive_rx.recv().await else {
                     return Err(anyhow::anyhow!("Failed to receive initial message"));
                 };
@@ -1247,28 +1646,6 @@ where
     }
 }
 
-fn encode_sdp(s: &str) -> String {
-    let mut e = ZlibEncoder::new(Vec::new(), flate2::Compression::best());
-    e.write_all(s.as_bytes())
-        .map_err(|e| anyhow::anyhow!("Failed to compress SDP: {e}"))
-        .unwrap();
-    let compressed = e
-        .finish()
-        .map_err(|e| anyhow::anyhow!("Failed to finish compression of SDP: {e}"))
-        .unwrap();
-    base64::encode(&compressed)
-}
-
-fn decode_sdp(s: &str) -> Result<String> {
-    let decoded_data =
-        base64::decode(s).map_err(|e| anyhow::anyhow!("Base64 decode of SDP failed: {e}"))?;
-    let mut d = ZlibDecoder::new(&*decoded_data);
-    let mut result = String::new();
-    d.read_to_string(&mut result)
-        .map_err(|e| anyhow::anyhow!("Failed to decompress SDP: {e}"))?;
-    Ok(result)
-}
-
 async fn send_delimiter(data_channel: &Arc<RTCDataChannel>) -> Result<()> {
     // Somehow, empty messages are not received by the other peer, so we send a non-empty message
     data_channel.send_text("0".to_string()).await?;
@@ -1327,6 +1704,15 @@ pub async fn send_string_in_chunks<T, F, Fut>(
     string: String,
     callback: F,
 ) -> Result<()>
+where
+    F: FnMut(T, Bytes) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    send_bytes_in_chunks(data_channel, Bytes::from(string), callback).await
+}
+
+/// Like [`send_string_in_chunks`] for data which is not necessarily UTF-8, e.g. compressed.
+async fn send_bytes_in_chunks<T, F, Fut>(data_channel: T, data: Bytes, callback: F) -> Result<()>
 where
     F: FnMut(T, Bytes) -> Fut,
     Fut: Future<Output = Result<T>>,
@@ -1334,7 +1720,7 @@ where
     let (tx, rx) = mpsc::channel(1);
 
     tokio::spawn(async move {
-        let _ = tx.send(Bytes::from(string)).await;
+        let _ = tx.send(data).await;
     });
 
     process_in_chunks(data_channel, rx, callback).await
@@ -1425,4 +1811,68 @@ mod tests {
 
         assert_eq!(response, decoded);
     }
+
+    #[test]
+    fn rtc_nonce_message_encoding() {
+        let message = RTCNonceMessage {
+            version: RTC_PROTOCOL_VERSION,
+            nonce: "abc".to_string(),
+            capabilities: 0,
+        };
+
+        let encoded = serde_json::to_string(&message).unwrap();
+        assert_eq!(encoded, r#"{"version":2,"nonce":"abc"}"#);
+
+        let decoded: RTCNonceMessage = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(message, decoded);
+
+        // Older peers don't send a version.
+        let decoded: RTCNonceMessage = serde_json::from_str(r#"{"nonce":"abc"}"#).unwrap();
+        assert_eq!(decoded.version, 1);
+
+        // Fields added in later versions must not break older peers.
+        let decoded: RTCNonceMessage =
+            serde_json::from_str(r#"{"version":3,"nonce":"abc","capabilities":1,"extra":[]}"#)
+                .unwrap();
+        assert_eq!(decoded.version, 3);
+        assert_eq!(decoded.capabilities, 1);
+    }
+
+    #[test]
+    fn negotiate_protocol() {
+        // Newer peers downgrade to our version.
+        let negotiated = Negotiated::new(RTC_PROTOCOL_VERSION + 1, u32::MAX).unwrap();
+        assert_eq!(negotiated.version, RTC_PROTOCOL_VERSION);
+        assert_eq!(negotiated.capabilities, capability::SUPPORTED);
+        assert!(negotiated.supports(capability::COMPRESSION));
+        assert!(!negotiated.supports(capability::DELTA_SYNC));
+
+        // Version 1 has no capabilities.
+        let negotiated = Negotiated::new(1, capability::COMPRESSION).unwrap();
+        assert_eq!(negotiated.version, 1);
+        assert!(!negotiated.supports(capability::COMPRESSION));
+
+        assert!(Negotiated::new(MIN_RTC_PROTOCOL_VERSION - 1, 0).is_err());
+    }
+
+    #[test]
+    fn encode_chunked() {
+        let message = serde_json::to_string(&RTCPinSendingResponse::PinRequired).unwrap();
+
+        let plain = Negotiated::new(1, 0).unwrap();
+        let encoded = plain.encode_chunked(message.clone()).unwrap();
+        assert_eq!(encoded, message.as_bytes());
+        assert_eq!(plain.decode_chunked(encoded).unwrap(), message.as_bytes());
+
+        let compressed = Negotiated::new(RTC_PROTOCOL_VERSION, capability::COMPRESSION).unwrap();
+        let encoded = compressed.encode_chunked(message.clone()).unwrap();
+        assert_ne!(encoded, message.as_bytes());
+        assert_eq!(
+            compressed.decode_chunked(encoded).unwrap(),
+            message.as_bytes()
+        );
+        assert!(compressed
+            .decode_chunked(Bytes::from_static(b"not compressed"))
+            .is_err());
+    }
 }
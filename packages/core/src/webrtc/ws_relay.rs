@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+/// Length of the peer ID at the start of a relay frame.
+pub const PEER_ID_LENGTH: usize = 16;
+
+/// Encodes a binary WebSocket frame relayed by the signaling server.
+///
+/// The client addresses the frame with the ID of the target peer.
+/// The server replaces it with the ID of the origin before delivering the frame,
+/// so the same format is used in both directions.
+/// Only available if both peers announce [`capability::WS_RELAY`].
+///
+/// [`capability::WS_RELAY`]: crate::webrtc::signaling::capability::WS_RELAY
+pub fn encode_frame(peer_id: Uuid, payload: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(PEER_ID_LENGTH + payload.len());
+    frame.extend_from_slice(peer_id.as_bytes());
+    frame.extend_from_slice(payload);
+    frame
+}
+
+/// Splits a relay frame into the peer ID and the payload.
+/// Returns `None` if the frame is too short to contain a peer ID.
+pub fn decode_frame(frame: &[u8]) -> Option<(Uuid, &[u8])> {
+    if frame.len() < PEER_ID_LENGTH {
+        return None;
+    }
+
+    let (peer_id, payload) = frame.split_at(PEER_ID_LENGTH);
+    Some((Uuid::from_slice(peer_id).ok()?, payload))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let peer_id = Uuid::new_v4();
+        let frame = encode_frame(peer_id, b"hello");
+        assert_eq!(frame.len(), PEER_ID_LENGTH + 5);
+        assert_eq!(decode_frame(&frame), Some((peer_id, &b"hello"[..])));
+        assert_eq!(decode_frame(peer_id.as_bytes()), Some((peer_id, &[][..])));
+        assert_eq!(decode_frame(&frame[..PEER_ID_LENGTH - 1]), None);
+    }
+}
@@ -0,0 +1,266 @@
+#![cfg(unix)]
+
+use localsend::control::{
+    start_control_socket, ControlConfig, ControlEvent, ControlPeer, ControlSocket, TransferUpdate,
+};
+use localsend::model::discovery::DeviceType;
+use serde_json::{json, Value};
+use std::net::{IpAddr, Ipv4Addr};
+use std::os::unix::fs::PermissionsExt;
+use std::path::PathBuf;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::unix::OwnedReadHalf;
+use tokio::net::UnixStream;
+use tokio::sync::{mpsc, oneshot};
+
+fn socket_path() -> PathBuf {
+    std::env::temp_dir().join(format!("localsend-control-{}.sock", uuid::Uuid::new_v4()))
+}
+
+/// Starts a control socket whose application lists a single peer
+/// and enqueues sends to it as transfer `t1`.
+async fn start_test_socket(path: PathBuf, stop_rx: oneshot::Receiver<()>) -> ControlSocket {
+    let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+    let (event_tx, mut event_rx) = mpsc::channel::<ControlEvent>(16);
+
+    tokio::spawn(async move {
+        while let Some(event) = event_rx.recv().await {
+            match event {
+                ControlEvent::ListPeers { peers_tx } => {
+                    let _ = peers_tx.send(vec![ControlPeer {
+                        id: "ABCD".to_string(),
+                        alias: "Nice Orange".to_string(),
+                        device_model: None,
+                        device_type: Some(DeviceType::Desktop),
+                        ip: Some(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 2))),
+                    }]);
+                }
+                ControlEvent::Send {
+                    peer_id,
+                    transfer_tx,
+                    ..
+                } => {
+                    let result = match peer_id.as_str() {
+                        "ABCD" => Ok("t1".to_string()),
+                        _ => Err("Unknown peer".to_string()),
+                    };
+                    let _ = transfer_tx.send(result);
+                }
+            }
+        }
+    });
+
+    start_control_socket(path, ControlConfig { event_tx }, stop_rx)
+        .await
+        .unwrap()
+}
+
+struct Client {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    write: tokio::net::unix::OwnedWriteHalf,
+}
+
+impl Client {
+    async fn connect(socket: &ControlSocket) -> Self {
+        let (read, write) = UnixStream::connect(socket.path())
+            .await
+            .unwrap()
+            .into_split();
+        Self {
+            lines: BufReader::new(read).lines(),
+            write,
+        }
+    }
+
+    async fn send(&mut self, line: &str) {
+        self.write
+            .write_all(format!("{line}\n").as_bytes())
+            .await
+            .unwrap();
+    }
+
+    async fn recv(&mut self) -> Value {
+        let line = self.lines.next_line().await.unwrap().unwrap();
+        serde_json::from_str(&line).unwrap()
+    }
+
+    async fn request(&mut self, request: Value) -> Value {
+        self.send(&request.to_string()).await;
+        self.recv().await
+    }
+}
+
+#[tokio::test]
+async fn test_list_peers_and_send() {
+    let (_stop_tx, stop_rx) = oneshot::channel();
+    let socket = start_test_socket(socket_path(), stop_rx).await;
+    let mut client = Client::connect(&socket).await;
+
+    let response = client
+        .request(json!({"jsonrpc": "2.0", "method": "listPeers", "id": 1}))
+        .await;
+    assert_eq!(
+        response,
+        json!({
+            "jsonrpc": "2.0",
+            "result": [{
+                "id": "ABCD",
+                "alias": "Nice Orange",
+                "deviceModel": null,
+                "deviceType": "DESKTOP",
+                "ip": "192.168.1.2",
+            }],
+            "id": 1,
+        })
+    );
+
+    let response = client
+        .request(json!({
+            "jsonrpc": "2.0",
+            "method": "send",
+            "params": {"peerId": "ABCD", "files": ["/tmp/photo.jpg"]},
+            "id": "a",
+        }))
+        .await;
+    assert_eq!(
+        response,
+        json!({"jsonrpc": "2.0", "result": {"transferId": "t1"}, "id": "a"})
+    );
+
+    let response = client
+        .request(json!({
+            "jsonrpc": "2.0",
+            "method": "send",
+            "params": {"peerId": "EFGH", "files": ["/tmp/photo.jpg"]},
+            "id": 2,
+        }))
+        .await;
+    assert_eq!(response["error"]["code"], -32000);
+    assert_eq!(response["error"]["message"], "Unknown peer");
+}
+
+#[tokio::test]
+async fn test_invalid_requests() {
+    let (_stop_tx, stop_rx) = oneshot::channel();
+    let socket = start_test_socket(socket_path(), stop_rx).await;
+    let mut client = Client::connect(&socket).await;
+
+    client.send("{not json").await;
+    let response = client.recv().await;
+    assert_eq!(response["error"]["code"], -32700);
+    assert_eq!(response["id"], Value::Null);
+
+    let response = client
+        .request(json!({"jsonrpc": "1.0", "method": "listPeers", "id": 1}))
+        .await;
+    assert_eq!(response["error"]["code"], -32600);
+
+    let response = client
+        .request(json!({"jsonrpc": "2.0", "method": "shutdown", "id": 2}))
+        .await;
+    assert_eq!(response["error"]["code"], -32601);
+
+    let response = client
+        .request(json!({
+            "jsonrpc": "2.0",
+            "method": "send",
+            "params": {"peerId": "ABCD", "files": ["photo.jpg"]},
+            "id": 3,
+        }))
+        .await;
+    assert_eq!(response["error"]["code"], -32602);
+
+    // Notifications are not answered, so the next response belongs to the next request.
+    client
+        .send(r#"{"jsonrpc":"2.0","method":"listPeers"}"#)
+        .await;
+    let response = client
+        .request(json!({"jsonrpc": "2.0", "method": "subscribe", "id": 4}))
+        .await;
+    assert_eq!(response, json!({"jsonrpc": "2.0", "result": true, "id": 4}));
+}
+
+#[tokio::test]
+async fn test_subscribe() {
+    let (_stop_tx, stop_rx) = oneshot::channel();
+    let socket = start_test_socket(socket_path(), stop_rx).await;
+    let mut subscriber = Client::connect(&socket).await;
+    let mut other = Client::connect(&socket).await;
+
+    subscriber
+        .request(json!({"jsonrpc": "2.0", "method": "subscribe", "id": 1}))
+        .await;
+
+    socket.publish(TransferUpdate::Progress {
+        transfer_id: "t1".to_string(),
+        sent_bytes: 5,
+        total_bytes: 10,
+    });
+    assert_eq!(
+        subscriber.recv().await,
+        json!({
+            "jsonrpc": "2.0",
+            "method": "transferUpdate",
+            "params": {"type": "progress", "transferId": "t1", "sentBytes": 5, "totalBytes": 10},
+        })
+    );
+
+    subscriber
+        .request(json!({"jsonrpc": "2.0", "method": "unsubscribe", "id": 2}))
+        .await;
+    socket.publish(TransferUpdate::Finished {
+        transfer_id: "t1".to_string(),
+    });
+
+    // Neither client receives the update.
+    let response = subscriber
+        .request(json!({"jsonrpc": "2.0", "method": "listPeers", "id": 3}))
+        .await;
+    assert_eq!(response["id"], 3);
+    let response = other
+        .request(json!({"jsonrpc": "2.0", "method": "listPeers", "id": 4}))
+        .await;
+    assert_eq!(response["id"], 4);
+}
+
+#[tokio::test]
+async fn test_socket_file() {
+    let dir = std::env::temp_dir().join(format!("localsend-control-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir(&dir).unwrap();
+    let path = dir.join("localsend.sock");
+
+    // A stale socket is replaced.
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+    let (stop_tx, stop_rx) = oneshot::channel();
+    let socket = start_test_socket(path.clone(), stop_rx).await;
+
+    // Only the owner can connect, and the directory it has been bound in is removed.
+    let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+    // A running instance is not.
+    let (event_tx, _event_rx) = mpsc::channel(1);
+    let (_, other_stop_rx) = oneshot::channel();
+    assert!(
+        start_control_socket(&path, ControlConfig { event_tx }, other_stop_rx)
+            .await
+            .is_err()
+    );
+
+    stop_tx.send(()).unwrap();
+    socket.wait_stopped().await;
+    assert!(!path.exists());
+
+    // Other files are never removed.
+    std::fs::write(&path, "data").unwrap();
+    let (event_tx, _event_rx) = mpsc::channel(1);
+    let (_, stop_rx) = oneshot::channel();
+    assert!(
+        start_control_socket(&path, ControlConfig { event_tx }, stop_rx)
+            .await
+            .is_err()
+    );
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "data");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
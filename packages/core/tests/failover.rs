@@ -0,0 +1,124 @@
+#![cfg(feature = "webrtc-signaling")]
+
+use futures_util::{SinkExt, StreamExt};
+use localsend::webrtc::failover::{FailoverConfig, FailoverSignalingConnection};
+use localsend::webrtc::signaling::{ClientInfo, ClientInfoWithoutId, WsServerMessage};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tungstenite::Message;
+use uuid::Uuid;
+
+/// Starts a minimal signaling server that greets every client with a hello
+/// and keeps the connection open.
+async fn start_fake_server(port: u16) -> tokio::task::JoinHandle<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port)).await.unwrap();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let Ok(mut ws) = tokio_tungstenite::accept_async(stream).await else {
+                    return;
+                };
+
+                let hello = WsServerMessage::Hello {
+                    client: ClientInfo::from(client_info(), Uuid::new_v4()),
+                    peers: vec![],
+                    resume_token: None,
+                    room: None,
+                };
+                let hello = serde_json::to_string(&hello).unwrap();
+                if ws.send(Message::Text(hello.into())).await.is_err() {
+                    return;
+                }
+
+                while let Some(Ok(_)) = ws.next().await {}
+            });
+        }
+    })
+}
+
+fn client_info() -> ClientInfoWithoutId {
+    ClientInfoWithoutId {
+        alias: "Cute Apple".to_string(),
+        version: "2.3".to_string(),
+        device_model: None,
+        device_type: None,
+        token: "123".to_string(),
+        capabilities: 0,
+    }
+}
+
+fn config(ports: &[u16]) -> FailoverConfig {
+    FailoverConfig {
+        connect_timeout: Duration::from_secs(2),
+        retry_interval: Duration::from_millis(100),
+        fail_back_interval: Duration::from_millis(100),
+        allow_insecure: true,
+        ..FailoverConfig::new(
+            ports
+                .iter()
+                .map(|port| format!("ws://127.0.0.1:{port}/v1/ws"))
+                .collect(),
+        )
+    }
+}
+
+#[tokio::test]
+async fn fails_over_to_reachable_server() {
+    let primary = free_port();
+    let secondary = free_port();
+    let _server = start_fake_server(secondary).await;
+
+    let (connection, mut rx) =
+        FailoverSignalingConnection::connect(config(&[primary, secondary]), client_info());
+
+    let hello = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(hello, WsServerMessage::Hello { .. }));
+    assert_eq!(connection.active().unwrap().index, 1);
+}
+
+#[tokio::test]
+async fn fails_back_to_primary() {
+    let primary = free_port();
+    let secondary = free_port();
+    let _secondary_server = start_fake_server(secondary).await;
+
+    let (connection, _rx) =
+        FailoverSignalingConnection::connect(config(&[primary, secondary]), client_info());
+    let mut active = connection.watch_active();
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        active
+            .wait_for(|server| server.as_ref().is_some_and(|s| s.index == 1))
+            .await
+            .unwrap();
+    })
+    .await
+    .unwrap();
+
+    let _primary_server = start_fake_server(primary).await;
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        active
+            .wait_for(|server| server.as_ref().is_some_and(|s| s.index == 0))
+            .await
+            .unwrap();
+    })
+    .await
+    .unwrap();
+}
+
+fn free_port() -> u16 {
+    static PORT_COUNTER: AtomicU16 = AtomicU16::new(43551);
+
+    loop {
+        let port = PORT_COUNTER.fetch_add(1, Ordering::SeqCst);
+        if std::net::TcpListener::bind(("127.0.0.1", port)).is_ok() {
+            return port;
+        }
+    }
+}
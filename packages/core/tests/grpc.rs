@@ -0,0 +1,205 @@
+#![cfg(feature = "grpc")]
+
+use localsend::grpc::proto::daemon_client::DaemonClient;
+use localsend::grpc::proto::{
+    transfer_event, AcceptMode, AcceptPolicy, AcceptPolicyRequest, DeviceType, ListPeersRequest,
+    Peer, SendFilesRequest, StreamEventsRequest, TransferEvent, TransferProgress,
+};
+use localsend::grpc::{start_grpc_server, GrpcConfig, GrpcEvent, GrpcServer};
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::sync::{mpsc, oneshot};
+use tonic::transport::Channel;
+use tonic::{Code, Request};
+
+const TOKEN: &str = "secret";
+
+/// Starts a gRPC server whose application lists a single peer
+/// and enqueues sends to it as transfer `t1`.
+async fn start_test_server(stop_rx: oneshot::Receiver<()>) -> GrpcServer {
+    let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+    let (event_tx, mut event_rx) = mpsc::channel::<GrpcEvent>(16);
+
+    tokio::spawn(async move {
+        while let Some(event) = event_rx.recv().await {
+            match event {
+                GrpcEvent::ListPeers { peers_tx } => {
+                    let _ = peers_tx.send(vec![Peer {
+                        id: "ABCD".to_string(),
+                        alias: "Nice Orange".to_string(),
+                        device_model: None,
+                        device_type: DeviceType::Desktop.into(),
+                        ip: Some("192.168.1.2".to_string()),
+                    }]);
+                }
+                GrpcEvent::SendFiles {
+                    peer_id,
+                    transfer_tx,
+                    ..
+                } => {
+                    let result = match peer_id.as_str() {
+                        "ABCD" => Ok("t1".to_string()),
+                        _ => Err("Unknown peer".to_string()),
+                    };
+                    let _ = transfer_tx.send(result);
+                }
+            }
+        }
+    });
+
+    let config = GrpcConfig {
+        token: TOKEN.to_string(),
+        tls: None,
+        accept_policy: AcceptPolicy::default(),
+        event_tx,
+    };
+    start_grpc_server(
+        SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0),
+        config,
+        stop_rx,
+    )
+    .await
+    .unwrap()
+}
+
+async fn connect(server: &GrpcServer) -> DaemonClient<Channel> {
+    DaemonClient::connect(format!("http://{}", server.local_addr()))
+        .await
+        .unwrap()
+}
+
+fn authorized<T>(message: T, token: &str) -> Request<T> {
+    let mut request = Request::new(message);
+    request
+        .metadata_mut()
+        .insert("authorization", format!("Bearer {token}").parse().unwrap());
+    request
+}
+
+#[tokio::test]
+async fn test_list_peers_and_send() {
+    let (_stop_tx, stop_rx) = oneshot::channel();
+    let server = start_test_server(stop_rx).await;
+    let mut client = connect(&server).await;
+
+    let peers = client
+        .list_peers(authorized(ListPeersRequest {}, TOKEN))
+        .await
+        .unwrap()
+        .into_inner()
+        .peers;
+    assert_eq!(peers.len(), 1);
+    assert_eq!(peers[0].alias, "Nice Orange");
+    assert_eq!(peers[0].device_type(), DeviceType::Desktop);
+
+    let response = client
+        .send_files(authorized(
+            SendFilesRequest {
+                peer_id: "ABCD".to_string(),
+                paths: vec!["/tmp/photo.jpg".to_string()],
+                pin: None,
+            },
+            TOKEN,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.into_inner().transfer_id, "t1");
+
+    let status = client
+        .send_files(authorized(
+            SendFilesRequest {
+                peer_id: "EFGH".to_string(),
+                paths: vec!["/tmp/photo.jpg".to_string()],
+                pin: None,
+            },
+            TOKEN,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::FailedPrecondition);
+    assert_eq!(status.message(), "Unknown peer");
+
+    let status = client
+        .send_files(authorized(
+            SendFilesRequest {
+                peer_id: "ABCD".to_string(),
+                paths: vec!["photo.jpg".to_string()],
+                pin: None,
+            },
+            TOKEN,
+        ))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_invalid_token() {
+    let (_stop_tx, stop_rx) = oneshot::channel();
+    let server = start_test_server(stop_rx).await;
+    let mut client = connect(&server).await;
+
+    let status = client
+        .list_peers(authorized(ListPeersRequest {}, "wrong"))
+        .await
+        .unwrap_err();
+    assert_eq!(status.code(), Code::Unauthenticated);
+}
+
+#[tokio::test]
+async fn test_accept_policy() {
+    let (_stop_tx, stop_rx) = oneshot::channel();
+    let server = start_test_server(stop_rx).await;
+    let mut client = connect(&server).await;
+    assert!(!server.accept_policy().accepts("ABCD"));
+
+    let policy = AcceptPolicy {
+        mode: AcceptMode::AcceptListed.into(),
+        fingerprints: vec!["ABCD".to_string()],
+    };
+    let response = client
+        .accept_policy(authorized(
+            AcceptPolicyRequest {
+                policy: Some(policy.clone()),
+            },
+            TOKEN,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.into_inner().policy, Some(policy.clone()));
+    assert!(server.accept_policy().accepts("ABCD"));
+
+    // Without a policy, the policy in effect is returned.
+    let response = client
+        .accept_policy(authorized(AcceptPolicyRequest { policy: None }, TOKEN))
+        .await
+        .unwrap();
+    assert_eq!(response.into_inner().policy, Some(policy));
+}
+
+#[tokio::test]
+async fn test_stream_events() {
+    let (stop_tx, stop_rx) = oneshot::channel();
+    let server = start_test_server(stop_rx).await;
+    let mut client = connect(&server).await;
+
+    let mut events = client
+        .stream_events(authorized(StreamEventsRequest {}, TOKEN))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let event = TransferEvent {
+        transfer_id: "t1".to_string(),
+        kind: Some(transfer_event::Kind::Progress(TransferProgress {
+            file_id: "f1".to_string(),
+            done_bytes: 5,
+        })),
+    };
+    server.publish(event.clone());
+    assert_eq!(events.message().await.unwrap(), Some(event));
+
+    // Stopping the server ends the stream.
+    stop_tx.send(()).unwrap();
+    server.wait_stopped().await;
+    assert_eq!(events.message().await.unwrap(), None);
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "http")]
+
+use bytes::Bytes;
+use localsend::http::server::share::{start_share_server, ShareConfig, ShareEvent, ShareLink};
+use localsend::model::transfer::{FileContent, FileDto};
+use localsend::reqwest::StatusCode;
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+
+const LOCALHOST: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+fn file_dto(id: &str, name: &str, size: u64) -> FileDto {
+    FileDto {
+        id: id.to_string(),
+        file_name: name.to_string(),
+        size,
+        file_type: "text/plain".to_string(),
+        sha256: None,
+        preview: None,
+        metadata: None,
+    }
+}
+
+/// Starts a share link serving `contents` (mapped by file ID).
+/// Returns the link and a receiver that gets a message when the link is closed.
+async fn start_test_share(
+    contents: HashMap<String, (String, Bytes)>,
+    expires_in: Option<Duration>,
+    max_downloads: Option<u32>,
+    stop_rx: oneshot::Receiver<()>,
+) -> (ShareLink, oneshot::Receiver<()>) {
+    let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+    let (event_tx, mut event_rx) = mpsc::channel::<ShareEvent>(16);
+    let (closed_tx, closed_rx) = oneshot::channel();
+
+    let files = contents
+        .iter()
+        .map(|(id, (name, bytes))| (id.clone(), file_dto(id, name, bytes.len() as u64)))
+        .collect();
+
+    tokio::spawn(async move {
+        let mut closed_tx = Some(closed_tx);
+        while let Some(event) = event_rx.recv().await {
+            match event {
+                ShareEvent::FileDownload {
+                    file_id,
+                    content_tx,
+                    ..
+                } => {
+                    let (tx, rx) = mpsc::channel(1);
+                    let _ = content_tx.send(FileContent::Stream(rx));
+                    let _ = tx.send(contents[&file_id].1.clone()).await;
+                }
+                ShareEvent::Closed => {
+                    if let Some(closed_tx) = closed_tx.take() {
+                        let _ = closed_tx.send(());
+                    }
+                }
+            }
+        }
+    });
+
+    let link = start_share_server(
+        0,
+        ShareConfig {
+            files,
+            expires_in,
+            max_downloads,
+            event_tx,
+        },
+        stop_rx,
+    )
+    .await
+    .expect("Failed to start share server");
+
+    (link, closed_rx)
+}
+
+fn client() -> localsend::reqwest::Client {
+    let _ = rustls::crypto::ring::default_provider().install_default();
+    localsend::reqwest::Client::new()
+}
+
+fn test_contents() -> HashMap<String, (String, Bytes)> {
+    HashMap::from([
+        (
+            "a".to_string(),
+            ("a.txt".to_string(), Bytes::from_static(b"hello")),
+        ),
+        (
+            "b/c".to_string(),
+            ("<b>.txt".to_string(), Bytes::from_static(b"world")),
+        ),
+    ])
+}
+
+#[tokio::test]
+async fn test_one_time_link() {
+    let (_stop_tx, stop_rx) = oneshot::channel();
+    let (link, closed_rx) = start_test_share(test_contents(), None, Some(1), stop_rx).await;
+    let url = link.url(LOCALHOST);
+    let client = client();
+
+    // The file list links to every file; names are escaped.
+    let page = client.get(&url).send().await.unwrap();
+    assert_eq!(page.status(), StatusCode::OK);
+    let page = page.text().await.unwrap();
+    assert!(page.contains(&format!("/share/{}/a\">a.txt</a>", link.token)));
+    assert!(page.contains(&format!("/share/{}/b%2Fc\">&lt;b&gt;.txt</a>", link.token)));
+
+    // A wrong token is not distinguishable from an unknown path.
+    let res = client
+        .get(format!("http://127.0.0.1:{}/share/wrong", link.port))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(res.status(), StatusCode::NOT_FOUND);
+
+    let res = client.get(format!("{url}/a")).send().await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(
+        res.headers()["content-disposition"],
+        "attachment; filename=\"a.txt\""
+    );
+    assert_eq!(res.bytes().await.unwrap(), "hello");
+
+    // The limit is per file.
+    let res = client.get(format!("{url}/a")).send().await.unwrap();
+    assert_eq!(res.status(), StatusCode::GONE);
+
+    // Downloading the last file closes the link.
+    let res = client.get(format!("{url}/b%2Fc")).send().await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+    assert_eq!(res.bytes().await.unwrap(), "world");
+
+    tokio::time::timeout(Duration::from_secs(5), closed_rx)
+        .await
+        .expect("Link was not closed")
+        .unwrap();
+    link.wait_stopped().await;
+}
+
+#[tokio::test]
+async fn test_link_expires() {
+    let (_stop_tx, stop_rx) = oneshot::channel();
+    let (link, closed_rx) = start_test_share(
+        test_contents(),
+        Some(Duration::from_millis(200)),
+        None,
+        stop_rx,
+    )
+    .await;
+    let url = link.url(LOCALHOST);
+
+    let res = client().get(format!("{url}/a")).send().await.unwrap();
+    assert_eq!(res.status(), StatusCode::OK);
+
+    tokio::time::timeout(Duration::from_secs(5), closed_rx)
+        .await
+        .expect("Link did not expire")
+        .unwrap();
+    link.wait_stopped().await;
+
+    // The server no longer accepts connections.
+    assert!(client().get(&url).send().await.is_err());
+}
+
+#[tokio::test]
+async fn test_stop_link() {
+    let (stop_tx, stop_rx) = oneshot::channel();
+    let (link, mut closed_rx) = start_test_share(test_contents(), None, None, stop_rx).await;
+
+    stop_tx.send(()).unwrap();
+    link.wait_stopped().await;
+
+    // Stopping on request is not reported as closed.
+    assert!(closed_rx.try_recv().is_err());
+    assert!(client().get(link.url(LOCALHOST)).send().await.is_err());
+}
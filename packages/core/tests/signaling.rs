@@ -0,0 +1,105 @@
+#![cfg(feature = "webrtc-signaling")]
+
+use futures_util::{SinkExt, StreamExt};
+use localsend::webrtc::signaling::{
+    ClientInfo, ClientInfoWithoutId, ConnectOptions, SignalingConnection, WsClientMessage,
+    WsServerMessage,
+};
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tungstenite::Message;
+use uuid::Uuid;
+
+/// Starts a minimal signaling server that greets every client with a hello
+/// and greets it again with the room code once it joins a room.
+async fn start_fake_server() -> u16 {
+    let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+
+    tokio::spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(async move {
+                let Ok(mut ws) = tokio_tungstenite::accept_async(stream).await else {
+                    return;
+                };
+
+                let client = ClientInfo::from(client_info(), Uuid::new_v4());
+                if ws.send(hello(&client, None)).await.is_err() {
+                    return;
+                }
+
+                while let Some(Ok(message)) = ws.next().await {
+                    let Message::Text(message) = message else {
+                        continue;
+                    };
+                    if let Ok(WsClientMessage::JoinRoom { code, .. }) =
+                        serde_json::from_str(&message)
+                    {
+                        if ws.send(hello(&client, Some(code))).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    port
+}
+
+fn hello(client: &ClientInfo, room: Option<String>) -> Message {
+    let hello = WsServerMessage::Hello {
+        client: client.clone(),
+        peers: vec![],
+        resume_token: None,
+        room,
+    };
+    Message::Text(serde_json::to_string(&hello).unwrap().into())
+}
+
+fn client_info() -> ClientInfoWithoutId {
+    ClientInfoWithoutId {
+        alias: "Cute Apple".to_string(),
+        version: "2.3".to_string(),
+        device_model: None,
+        device_type: None,
+        token: "123".to_string(),
+        capabilities: 0,
+    }
+}
+
+#[tokio::test]
+async fn delivers_hello_after_joining_room() {
+    let port = start_fake_server().await;
+    let options = ConnectOptions {
+        allow_insecure: true,
+        ..ConnectOptions::default()
+    };
+    let connection = SignalingConnection::connect_with_options(
+        format!("ws://127.0.0.1:{port}/v1/ws"),
+        &client_info(),
+        &options,
+    )
+    .await
+    .unwrap();
+
+    let (connection, mut rx) = connection.start_listener();
+    let hello = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(hello, WsServerMessage::Hello { room: None, .. }));
+
+    connection
+        .join_room("ABC123".to_string(), None)
+        .await
+        .unwrap();
+    let hello = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        hello,
+        WsServerMessage::Hello { room: Some(room), .. } if room == "ABC123"
+    ));
+}
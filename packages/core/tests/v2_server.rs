@@ -2,14 +2,16 @@
 
 use bytes::Bytes;
 use futures_util::StreamExt;
+use localsend::crypto::cert::generate_self_signed_cert;
+use localsend::crypto::token;
 use localsend::http::client::{ClientError, LsHttpClientV2};
 use localsend::http::dto::ProtocolType;
 use localsend::http::dto_v2::{PrepareUploadRequestDtoV2, ProtocolTypeV2, RegisterDtoV2};
 use localsend::http::server::common::save::FileUploadTarget;
 use localsend::http::server::v2::{PrepareUploadDecisionV2, ServerEventV2, SessionEndReasonV2};
-use localsend::http::server::{start_with_port, ServerConfigV2};
+use localsend::http::server::{start_with_port, ServerConfigV2, TlsConfig};
 use localsend::http::state::ClientInfo;
-use localsend::model::transfer::FileDto;
+use localsend::model::transfer::{FileDto, UNKNOWN_SIZE};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU16, AtomicU64, Ordering};
@@ -36,6 +38,16 @@ async fn start_test_server(
     pin: Option<String>,
     accept: bool,
     save_dir: Option<PathBuf>,
+) -> TestServer {
+    start_test_server_with_tls(pin, accept, save_dir, None).await
+}
+
+/// Starts a test server serving HTTPS if `tls_config` is given.
+async fn start_test_server_with_tls(
+    pin: Option<String>,
+    accept: bool,
+    save_dir: Option<PathBuf>,
+    tls_config: Option<TlsConfig>,
 ) -> TestServer {
     let _ = tracing_subscriber::fmt().with_test_writer().try_init();
     let port = free_port();
@@ -115,7 +127,7 @@ async fn start_test_server(
 
     start_with_port(
         port,
-        None, // plain HTTP
+        tls_config,
         ClientInfo {
             alias: "Test Server".to_string(),
             version: "2.1".to_string(),
@@ -281,6 +293,67 @@ async fn test_register_and_info() {
     assert_eq!(info.fingerprint, "server-fingerprint");
 }
 
+/// Creates a private key and a self-signed certificate, both in PEM format.
+fn self_signed_identity() -> (String, String) {
+    let key = token::generate_key();
+    let private_key = token::export_private_key(&key).unwrap().to_string();
+    let cert = generate_self_signed_cert(&private_key).unwrap();
+    (private_key, cert)
+}
+
+#[tokio::test]
+async fn test_upload_over_https() {
+    let (private_key, cert) = self_signed_identity();
+    let server =
+        start_test_server_with_tls(None, true, None, Some(TlsConfig { cert, private_key })).await;
+
+    // Legacy clients authenticate with their own self-signed certificate.
+    let (private_key, cert) = self_signed_identity();
+    let client = LsHttpClientV2::try_new(&private_key, &cert, None).unwrap();
+
+    let info = client
+        .info(ProtocolType::Https, "127.0.0.1", server.port)
+        .await
+        .unwrap();
+    assert_eq!(info.alias, "Test Server");
+
+    let result = client
+        .prepare_upload(
+            ProtocolType::Https,
+            "127.0.0.1",
+            server.port,
+            None,
+            prepare_upload_request(&[file_dto("file-a", "a.txt", 5)]),
+            None,
+        )
+        .await
+        .unwrap();
+    let response = result.response.unwrap();
+
+    client
+        .upload(
+            ProtocolType::Https,
+            "127.0.0.1",
+            server.port,
+            None,
+            &response.session_id,
+            "file-a",
+            &response.files["file-a"],
+            localsend::reqwest::Body::from("hello"),
+            CancellationToken::new(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(server.received.lock().await["file-a"], b"hello");
+
+    // Clients without a certificate are rejected during the handshake.
+    let client = LsHttpClientV2::try_new_without_cert().unwrap();
+    assert!(client
+        .info(ProtocolType::Https, "127.0.0.1", server.port)
+        .await
+        .is_err());
+}
+
 #[tokio::test]
 async fn test_register_over_ipv6() {
     let server = start_test_server(None, true, None).await;
@@ -433,6 +506,100 @@ async fn test_upload_saved_to_path_by_server() {
     let _ = tokio::fs::remove_dir_all(&save_dir).await;
 }
 
+#[tokio::test]
+async fn test_upload_of_unknown_size() {
+    let save_dir = std::env::temp_dir().join(format!("localsend-test-{}", uuid::Uuid::new_v4()));
+    tokio::fs::create_dir_all(&save_dir).await.unwrap();
+
+    let server = start_test_server(None, true, Some(save_dir.clone())).await;
+    let client = LsHttpClientV2::try_new_without_cert().unwrap();
+
+    let file = file_dto("file-a", "a.bin", UNKNOWN_SIZE);
+    let response = client
+        .prepare_upload(
+            ProtocolType::Http,
+            "127.0.0.1",
+            server.port,
+            None,
+            prepare_upload_request(&[file]),
+            None,
+        )
+        .await
+        .unwrap()
+        .response
+        .unwrap();
+
+    let bytes: Vec<u8> = (0..10_000u32).map(|i| i as u8).collect();
+    upload_bytes(
+        &client,
+        server.port,
+        &response.session_id,
+        "file-a",
+        &response.files["file-a"],
+        &bytes,
+    )
+    .await
+    .unwrap();
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert_eq!(server.received.lock().await["file-a"], bytes);
+
+    let _ = tokio::fs::remove_dir_all(&save_dir).await;
+}
+
+#[tokio::test]
+async fn test_interrupted_upload_of_unknown_size() {
+    let save_dir = std::env::temp_dir().join(format!("localsend-test-{}", uuid::Uuid::new_v4()));
+    tokio::fs::create_dir_all(&save_dir).await.unwrap();
+
+    let server = start_test_server(None, true, Some(save_dir.clone())).await;
+    let client = LsHttpClientV2::try_new_without_cert().unwrap();
+
+    let file = file_dto("file-a", "a.bin", UNKNOWN_SIZE);
+    let response = client
+        .prepare_upload(
+            ProtocolType::Http,
+            "127.0.0.1",
+            server.port,
+            None,
+            prepare_upload_request(&[file]),
+            None,
+        )
+        .await
+        .unwrap()
+        .response
+        .unwrap();
+
+    // The sender fails after the first chunk arrived, so the request ends without its last chunk.
+    let (tx, rx) = mpsc::channel::<Result<Bytes, std::io::Error>>(1);
+    tokio::spawn(async move {
+        let _ = tx.send(Ok(Bytes::from_static(b"partial"))).await;
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        let _ = tx.send(Err(std::io::Error::other("read failed"))).await;
+    });
+    let body = localsend::reqwest::Body::wrap_stream(ReceiverStream::new(rx));
+    let result = client
+        .upload(
+            ProtocolType::Http,
+            "127.0.0.1",
+            server.port,
+            None,
+            &response.session_id,
+            "file-a",
+            &response.files["file-a"],
+            body,
+            CancellationToken::new(),
+        )
+        .await;
+    assert!(result.is_err());
+
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(!server.received.lock().await.contains_key("file-a"));
+    assert!(!save_dir.join("file-a").exists());
+
+    let _ = tokio::fs::remove_dir_all(&save_dir).await;
+}
+
 #[tokio::test]
 async fn test_upload_with_invalid_token() {
     let server = start_test_server(None, true, None).await;
@@ -670,3 +837,125 @@ async fn test_pin_too_many_attempts() {
         .await;
     assert_status(result, 429);
 }
+
+#[cfg(feature = "webrtc")]
+#[tokio::test]
+async fn test_send_files_v2() {
+    use localsend::http::client::sender::{send_files_v2, UploadProgress};
+    use localsend::webrtc::webrtc::{RTCFile, RTCStatus};
+
+    let server = start_test_server(Some("123456".to_string()), true, None).await;
+    let client = LsHttpClientV2::try_new_without_cert().unwrap();
+
+    let bytes_a: Vec<u8> = (0..100_000u32).map(|i| i as u8).collect();
+    let bytes_b = b"hello".to_vec();
+    let files = vec![
+        file_dto("file-a", "a.bin", bytes_a.len() as u64),
+        file_dto("file-b", "b.bin", bytes_b.len() as u64),
+    ];
+
+    let (status_tx, mut status_rx) = mpsc::channel::<RTCStatus>(16);
+    let (progress_tx, mut progress_rx) = mpsc::channel::<UploadProgress>(256);
+    let (selected_files_tx, selected_files_rx) = oneshot::channel();
+    let (error_tx, mut error_rx) = mpsc::channel(4);
+    let (pin_tx, mut pin_rx) = mpsc::channel::<oneshot::Sender<String>>(1);
+    let (sending_tx, sending_rx) = mpsc::channel::<RTCFile>(1);
+
+    let send_task = tokio::spawn(async move {
+        send_files_v2(
+            &client,
+            ProtocolType::Http,
+            "127.0.0.1",
+            server.port,
+            None,
+            sender_info(),
+            files,
+            status_tx,
+            progress_tx,
+            selected_files_tx,
+            error_tx,
+            pin_tx,
+            sending_rx,
+            CancellationToken::new(),
+        )
+        .await
+        .map(|_| server)
+    });
+
+    pin_rx
+        .recv()
+        .await
+        .unwrap()
+        .send("123456".to_string())
+        .unwrap();
+
+    let mut selected: Vec<String> = selected_files_rx.await.unwrap().into_iter().collect();
+    selected.sort();
+    assert_eq!(selected, vec!["file-a", "file-b"]);
+
+    for (file_id, bytes) in [("file-a", &bytes_a), ("file-b", &bytes_b)] {
+        let (binary_tx, binary_rx) = mpsc::channel::<Bytes>(4);
+        sending_tx
+            .send(RTCFile {
+                file_id: file_id.to_string(),
+                binary_rx,
+            })
+            .await
+            .unwrap();
+        for chunk in bytes.chunks(16 * 1024) {
+            binary_tx.send(Bytes::copy_from_slice(chunk)).await.unwrap();
+        }
+    }
+
+    let server = send_task.await.unwrap().unwrap();
+
+    let mut statuses = Vec::new();
+    while let Ok(status) = status_rx.try_recv() {
+        statuses.push(status);
+    }
+    assert_eq!(
+        statuses,
+        vec![
+            RTCStatus::PinRequired,
+            RTCStatus::Sending,
+            RTCStatus::Finished
+        ]
+    );
+    assert!(error_rx.try_recv().is_err());
+
+    let mut last_progress = HashMap::new();
+    while let Ok(progress) = progress_rx.try_recv() {
+        last_progress.insert(progress.file_id, progress.sent);
+    }
+    assert_eq!(last_progress["file-a"], bytes_a.len() as u64);
+    assert_eq!(last_progress["file-b"], bytes_b.len() as u64);
+
+    let received = server.received.lock().await;
+    assert_eq!(received["file-a"], bytes_a);
+    assert_eq!(received["file-b"], bytes_b);
+}
+
+#[cfg(feature = "webrtc")]
+#[tokio::test]
+async fn test_select_transport() {
+    use localsend::transport::{select_transport, LanTarget, PeerTarget, Transport};
+
+    let server = start_test_server(None, true, None).await;
+    let client = LsHttpClientV2::try_new_without_cert().unwrap();
+    let lan_target = |port| PeerTarget {
+        lan: Some(LanTarget {
+            protocol: ProtocolType::Http,
+            ip: "127.0.0.1".to_string(),
+            port,
+            public_key: None,
+        }),
+        signaling: None,
+    };
+
+    let transport = select_transport(&client, &lan_target(server.port)).await;
+    assert_eq!(transport, Some(Transport::LanHttp));
+
+    // Nothing listens on this port and there is no signaling route to fall back to.
+    let transport = select_transport(&client, &lan_target(free_port())).await;
+    assert_eq!(transport, None);
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "http")]
+
+use localsend::model::discovery::DeviceType;
+use localsend::webhook::{
+    TransferDirection, WebhookEvent, WebhookEventType, WebhookFile, WebhookNotifier, WebhookPeer,
+};
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+/// Starts an HTTP endpoint that answers every request with `status`
+/// and forwards the request line and JSON body.
+async fn start_endpoint(status: u16) -> (String, mpsc::Receiver<(String, Value)>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/api/webhook/abc", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel(4);
+
+    tokio::spawn(async move {
+        loop {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).await.unwrap();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).await.unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).await.unwrap();
+
+            let response = format!("HTTP/1.1 {status} X\r\ncontent-length: 0\r\n\r\n");
+            reader
+                .get_mut()
+                .write_all(response.as_bytes())
+                .await
+                .unwrap();
+            let _ = tx
+                .send((
+                    request_line.trim().to_string(),
+                    serde_json::from_slice(&body).unwrap(),
+                ))
+                .await;
+        }
+    });
+
+    (url, rx)
+}
+
+fn event() -> WebhookEvent {
+    WebhookEvent::new(
+        WebhookEventType::SessionCompleted,
+        "s1".to_string(),
+        TransferDirection::Receive,
+        WebhookPeer {
+            alias: "Nice Orange".to_string(),
+            fingerprint: Some("ABCD".to_string()),
+            device_model: None,
+            device_type: Some(DeviceType::Mobile),
+        },
+        vec![WebhookFile {
+            file_name: "photo.jpg".to_string(),
+            size: 5,
+            file_type: "image/jpeg".to_string(),
+        }],
+        None,
+    )
+}
+
+#[tokio::test]
+async fn test_deliver() {
+    let _ = tracing_subscriber::fmt().with_test_writer().try_init();
+    let (first_url, mut first_rx) = start_endpoint(200).await;
+    let (second_url, mut second_rx) = start_endpoint(500).await;
+
+    let notifier = WebhookNotifier::try_new(&[first_url, second_url]).unwrap();
+    let event = event();
+    assert_eq!(notifier.deliver(&event).await, 1);
+
+    let (request_line, body) = first_rx.recv().await.unwrap();
+    assert_eq!(request_line, "POST /api/webhook/abc HTTP/1.1");
+    assert_eq!(
+        body,
+        json!({
+            "event": "sessionCompleted",
+            "sessionId": "s1",
+            "direction": "receive",
+            "peer": {
+                "alias": "Nice Orange",
+                "fingerprint": "ABCD",
+                "deviceModel": null,
+                "deviceType": "MOBILE",
+            },
+            "files": [{"fileName": "photo.jpg", "size": 5, "fileType": "image/jpeg"}],
+            "timestamp": event.timestamp,
+        })
+    );
+
+    // Failed endpoints still receive the event.
+    let (_, body) = second_rx.recv().await.unwrap();
+    assert_eq!(body["sessionId"], "s1");
+}
+
+#[tokio::test]
+async fn test_notify() {
+    let (url, mut rx) = start_endpoint(204).await;
+    let notifier = WebhookNotifier::try_new(&[url]).unwrap();
+
+    let mut event = event();
+    event.event = WebhookEventType::SessionFailed;
+    event.error = Some("Declined".to_string());
+    notifier.notify(event);
+
+    let (_, body) = rx.recv().await.unwrap();
+    assert_eq!(body["event"], "sessionFailed");
+    assert_eq!(body["error"], "Declined");
+}
@@ -0,0 +1,10 @@
+fn main() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+
+    println!("cargo:rerun-if-changed=src");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    cbindgen::generate(&crate_dir)
+        .expect("Failed to generate C bindings")
+        .write_to_file(format!("{crate_dir}/include/localsend.h"));
+}
@@ -0,0 +1,263 @@
+#ifndef LOCALSEND_H
+#define LOCALSEND_H
+
+/* Generated by cbindgen from packages/localsend_ffi. Do not edit manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of every function of the API.
+ */
+typedef enum LsResult {
+  LS_RESULT_OK = 0,
+  /**
+   * Nothing happened within the timeout. Poll again later.
+   */
+  LS_RESULT_TIMEOUT = 1,
+  /**
+   * The event source has ended, e.g. the connection or the file is closed.
+   */
+  LS_RESULT_CLOSED = 2,
+  /**
+   * See [`ls_last_error`].
+   */
+  LS_RESULT_ERROR = -1,
+} LsResult;
+
+/**
+ * A connection to a signaling server, created by [`ls_connection_create`].
+ */
+typedef struct LsConnection LsConnection;
+
+/**
+ * A transfer with a peer, created by [`ls_connection_send_offer`]
+ * or [`ls_connection_accept_offer`].
+ */
+typedef struct LsTransfer LsTransfer;
+
+/**
+ * A chunk of file content owned by the caller. Freed with [`ls_bytes_free`].
+ */
+typedef struct LsBytes {
+  uint8_t *data;
+  size_t len;
+} LsBytes;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Returns the message of the last error on the calling thread, or `NULL` if there was none.
+ * The string is owned by the library and valid until the next call on the same thread.
+ */
+const char *ls_last_error(void);
+
+/**
+ * Frees a string returned by the library. Does nothing if `s` is `NULL`.
+ *
+ * # Safety
+ * `s` must be a string returned by the library that has not been freed yet.
+ */
+void ls_string_free(char *s);
+
+/**
+ * Frees a chunk returned by the library. Does nothing if `bytes.data` is `NULL`.
+ *
+ * # Safety
+ * `bytes` must be a chunk returned by the library that has not been freed yet.
+ */
+void ls_bytes_free(struct LsBytes bytes);
+
+/**
+ * Connects to the signaling server at `uri` and writes the connection to `out`.
+ *
+ * `info_json` is `{"alias": "...", "version": "2.1", "deviceModel": "...", "deviceType": "DESKTOP"}`
+ * where `deviceModel` and `deviceType` are optional.
+ * `private_key` is the PEM encoded Ed25519 key of this device, used to sign its token
+ * and transfers. Plain `ws://` URIs require `allow_insecure`.
+ *
+ * The messages of the server must be polled with [`ls_connection_poll_message`],
+ * otherwise the connection stalls.
+ *
+ * # Safety
+ * The strings must be valid NUL-terminated strings; `out` must be valid for writes.
+ */
+enum LsResult ls_connection_create(const char *uri,
+                                   const char *info_json,
+                                   const char *private_key,
+                                   bool allow_insecure,
+                                   struct LsConnection **out);
+
+/**
+ * Writes the info of this client assigned by the server (`ClientInfo` as JSON) to `out`.
+ *
+ * # Safety
+ * `connection` must be a live connection; `out` must be valid for writes.
+ */
+enum LsResult ls_connection_client(const struct LsConnection *connection, char **out);
+
+/**
+ * Waits up to `timeout_ms` for the next message of the server and writes it
+ * (`WsServerMessage` as JSON, e.g. `{"type": "OFFER", ...}`) to `out`.
+ * Returns [`LsResult::Closed`] once the server has closed the connection.
+ *
+ * # Safety
+ * `connection` must be a live connection; `out` must be valid for writes.
+ */
+enum LsResult ls_connection_poll_message(const struct LsConnection *connection,
+                                         uint32_t timeout_ms,
+                                         char **out);
+
+/**
+ * Frees the connection. The server connection is closed once the transfers
+ * started on it are freed as well. Does nothing if `connection` is `NULL`.
+ *
+ * # Safety
+ * `connection` must be a connection that has not been freed yet,
+ * and must not be used by other threads anymore.
+ */
+void ls_connection_free(struct LsConnection *connection);
+
+/**
+ * Offers `files_json` (an array of `FileDto`) to the peer with the ID `target_id`
+ * and writes the transfer to `out`. `stun_servers_json` is an array of URLs.
+ *
+ * # Safety
+ * `connection` must be a live connection, the strings must be valid NUL-terminated strings
+ * and `out` must be valid for writes.
+ */
+enum LsResult ls_connection_send_offer(const struct LsConnection *connection,
+                                       const char *target_id,
+                                       const char *files_json,
+                                       const char *stun_servers_json,
+                                       struct LsTransfer **out);
+
+/**
+ * Accepts the offer `offer_json` (the `OFFER` message polled from the connection)
+ * and writes the transfer to `out`. `stun_servers_json` is an array of URLs.
+ *
+ * # Safety
+ * `connection` must be a live connection, the strings must be valid NUL-terminated strings
+ * and `out` must be valid for writes.
+ */
+enum LsResult ls_connection_accept_offer(const struct LsConnection *connection,
+                                         const char *offer_json,
+                                         const char *stun_servers_json,
+                                         struct LsTransfer **out);
+
+/**
+ * Waits up to `timeout_ms` for the next event of the transfer and writes it to `out` as JSON,
+ * e.g. `{"type": "status", "status": "SENDING"}`.
+ * Returns [`LsResult::Closed`] once the transfer has ended and all events have been polled.
+ *
+ * # Safety
+ * `transfer` must be a live transfer; `out` must be valid for writes.
+ */
+enum LsResult ls_transfer_poll_event(const struct LsTransfer *transfer,
+                                     uint32_t timeout_ms,
+                                     char **out);
+
+/**
+ * Answers the latest `pinRequested` event.
+ *
+ * # Safety
+ * `transfer` must be a live transfer; `pin` must be a valid NUL-terminated string.
+ */
+enum LsResult ls_transfer_send_pin(const struct LsTransfer *transfer, const char *pin);
+
+/**
+ * Sending: starts providing the content of an accepted file.
+ * The files are sent one at a time, so end each file before beginning the next one.
+ *
+ * # Safety
+ * `transfer` must be a live transfer; `file_id` must be a valid NUL-terminated string.
+ */
+enum LsResult ls_transfer_begin_file(const struct LsTransfer *transfer, const char *file_id);
+
+/**
+ * Sending: feeds the next `len` bytes of a file started with [`ls_transfer_begin_file`].
+ * Blocks while the data channel is busy.
+ *
+ * # Safety
+ * `transfer` must be a live transfer, `file_id` a valid NUL-terminated string
+ * and `data` valid for reads of `len` bytes.
+ */
+enum LsResult ls_transfer_feed_chunk(const struct LsTransfer *transfer,
+                                     const char *file_id,
+                                     const uint8_t *data,
+                                     size_t len);
+
+/**
+ * Sending: marks the content of a file as complete.
+ *
+ * # Safety
+ * `transfer` must be a live transfer; `file_id` must be a valid NUL-terminated string.
+ */
+enum LsResult ls_transfer_end_file(const struct LsTransfer *transfer, const char *file_id);
+
+/**
+ * Receiving: accepts the files with the IDs in `file_ids_json` (a JSON array),
+ * or declines the whole transfer if `file_ids_json` is `NULL`.
+ *
+ * # Safety
+ * `transfer` must be a live transfer; `file_ids_json` must be `NULL`
+ * or a valid NUL-terminated string.
+ */
+enum LsResult ls_transfer_select_files(const struct LsTransfer *transfer,
+                                       const char *file_ids_json);
+
+/**
+ * Receiving: waits up to `timeout_ms` for the next chunk of a file announced by a
+ * `receiving` event and writes it to `out`; free it with [`ls_bytes_free`].
+ * Returns [`LsResult::Closed`] once the file is complete,
+ * which must then be confirmed with [`ls_transfer_report_file`].
+ *
+ * # Safety
+ * `transfer` must be a live transfer, `file_id` a valid NUL-terminated string
+ * and `out` valid for writes.
+ */
+enum LsResult ls_transfer_consume_chunk(const struct LsTransfer *transfer,
+                                        const char *file_id,
+                                        uint32_t timeout_ms,
+                                        struct LsBytes *out);
+
+/**
+ * Receiving: tells the sender whether a file was stored successfully.
+ * `error` is an optional message shown to the sender.
+ *
+ * # Safety
+ * `transfer` must be a live transfer; the strings must be `NULL` (only `error`)
+ * or valid NUL-terminated strings.
+ */
+enum LsResult ls_transfer_report_file(const struct LsTransfer *transfer,
+                                      const char *file_id,
+                                      bool success,
+                                      const char *error);
+
+/**
+ * Aborts the transfer and closes the peer connection, which disconnects the remote peer.
+ *
+ * # Safety
+ * `transfer` must be a live transfer.
+ */
+enum LsResult ls_transfer_cancel(const struct LsTransfer *transfer);
+
+/**
+ * Cancels the transfer if still running and frees it. Does nothing if `transfer` is `NULL`.
+ *
+ * # Safety
+ * `transfer` must be a transfer that has not been freed yet,
+ * and must not be used by other threads anymore.
+ */
+void ls_transfer_free(struct LsTransfer *transfer);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* LOCALSEND_H */
@@ -0,0 +1,141 @@
+use crate::{
+    LsResult, block_on_timeout, ffi_call, handle_arg, json_arg, runtime, str_arg, write_json_out,
+    write_out,
+};
+use localsend::model::discovery::DeviceType;
+use localsend::webrtc::signaling::{
+    ClientInfoWithoutId, ConnectOptions, ManagedSignalingConnection, SignalingConnection,
+    WsServerMessage,
+};
+use serde::Deserialize;
+use std::ffi::c_char;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+
+/// A connection to a signaling server, created by [`ls_connection_create`].
+pub struct LsConnection {
+    pub(crate) inner: Arc<ManagedSignalingConnection>,
+
+    /// The PEM encoded key signing the transfers of this client.
+    pub(crate) private_key: String,
+    messages: Mutex<mpsc::Receiver<WsServerMessage>>,
+}
+
+/// The info announced to other peers, passed as `info_json` to [`ls_connection_create`].
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ProposingClientInfo {
+    alias: String,
+    version: String,
+    device_model: Option<String>,
+    device_type: Option<DeviceType>,
+}
+
+/// Connects to the signaling server at `uri` and writes the connection to `out`.
+///
+/// `info_json` is `{"alias": "...", "version": "2.1", "deviceModel": "...", "deviceType": "DESKTOP"}`
+/// where `deviceModel` and `deviceType` are optional.
+/// `private_key` is the PEM encoded Ed25519 key of this device, used to sign its token
+/// and transfers. Plain `ws://` URIs require `allow_insecure`.
+///
+/// The messages of the server must be polled with [`ls_connection_poll_message`],
+/// otherwise the connection stalls.
+///
+/// # Safety
+/// The strings must be valid NUL-terminated strings; `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_connection_create(
+    uri: *const c_char,
+    info_json: *const c_char,
+    private_key: *const c_char,
+    allow_insecure: bool,
+    out: *mut *mut LsConnection,
+) -> LsResult {
+    ffi_call(|| {
+        let uri = unsafe { str_arg(uri, "uri") }?;
+        let info: ProposingClientInfo = unsafe { json_arg(info_json, "info_json") }?;
+        let private_key = unsafe { str_arg(private_key, "private_key") }?;
+        let signing_key = localsend::crypto::token::parse_private_key(private_key)?;
+
+        let client_info = ClientInfoWithoutId {
+            alias: info.alias,
+            version: info.version,
+            device_model: info.device_model,
+            device_type: info.device_type,
+            token: localsend::crypto::token::generate_token_timestamp(&signing_key)?,
+            capabilities: localsend::webrtc::signaling::capability::SUPPORTED,
+        };
+        let options = ConnectOptions {
+            allow_insecure,
+            ..ConnectOptions::default()
+        };
+
+        let (inner, messages) = runtime().block_on(async {
+            let connection =
+                SignalingConnection::connect_with_options(uri, &client_info, &options).await?;
+            anyhow::Ok(connection.start_listener())
+        })?;
+
+        let connection = Box::new(LsConnection {
+            inner: Arc::new(inner),
+            private_key: private_key.to_owned(),
+            messages: Mutex::new(messages),
+        });
+        unsafe { write_out(out, Box::into_raw(connection)) }
+    })
+}
+
+/// Writes the info of this client assigned by the server (`ClientInfo` as JSON) to `out`.
+///
+/// # Safety
+/// `connection` must be a live connection; `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_connection_client(
+    connection: *const LsConnection,
+    out: *mut *mut c_char,
+) -> LsResult {
+    ffi_call(|| {
+        let connection = unsafe { handle_arg(connection) }?;
+        unsafe { write_json_out(out, &connection.inner.client) }
+    })
+}
+
+/// Waits up to `timeout_ms` for the next message of the server and writes it
+/// (`WsServerMessage` as JSON, e.g. `{"type": "OFFER", ...}`) to `out`.
+/// Returns [`LsResult::Closed`] once the server has closed the connection.
+///
+/// # Safety
+/// `connection` must be a live connection; `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_connection_poll_message(
+    connection: *const LsConnection,
+    timeout_ms: u32,
+    out: *mut *mut c_char,
+) -> LsResult {
+    ffi_call(|| {
+        let connection = unsafe { handle_arg(connection) }?;
+        let mut messages = connection
+            .messages
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Connection poisoned"))?;
+        match block_on_timeout(timeout_ms, messages.recv()) {
+            None => Ok(LsResult::Timeout),
+            Some(None) => Ok(LsResult::Closed),
+            Some(Some(message)) => unsafe { write_json_out(out, &message) },
+        }
+    })
+}
+
+/// Frees the connection. The server connection is closed once the transfers
+/// started on it are freed as well. Does nothing if `connection` is `NULL`.
+///
+/// # Safety
+/// `connection` must be a connection that has not been freed yet,
+/// and must not be used by other threads anymore.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_connection_free(connection: *mut LsConnection) {
+    if !connection.is_null() {
+        let _guard = runtime().enter();
+        drop(unsafe { Box::from_raw(connection) });
+    }
+}
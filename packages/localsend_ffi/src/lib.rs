@@ -0,0 +1,172 @@
+//! Stable C ABI of the LocalSend transfer engine, so that applications
+//! in C, C++, Go, etc. can embed it. The header `include/localsend.h`
+//! is generated by cbindgen when building this crate.
+//!
+//! Conventions of the API:
+//! * Functions return an [`LsResult`]. On [`LsResult::Error`],
+//!   [`ls_last_error`] describes the error.
+//! * Structured values are passed as UTF-8 JSON strings in the format of the
+//!   signaling protocol (e.g. `FileDto`, `WsServerMessage`).
+//! * Strings returned by the library are freed with [`ls_string_free`],
+//!   chunks with [`ls_bytes_free`] and handles with their `_free` function.
+//! * Functions block the calling thread. Events are polled with a timeout in milliseconds;
+//!   a timeout of `0` only returns what is already available.
+//! * Handles can be used from any thread.
+
+mod connection;
+mod transfer;
+
+pub use connection::*;
+pub use transfer::*;
+
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
+use std::panic::AssertUnwindSafe;
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::runtime::Runtime;
+
+/// Result of every function of the API.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LsResult {
+    Ok = 0,
+
+    /// Nothing happened within the timeout. Poll again later.
+    Timeout = 1,
+
+    /// The event source has ended, e.g. the connection or the file is closed.
+    Closed = 2,
+
+    /// See [`ls_last_error`].
+    Error = -1,
+}
+
+/// A chunk of file content owned by the caller. Freed with [`ls_bytes_free`].
+#[repr(C)]
+pub struct LsBytes {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(error: &str) {
+    let error = CString::new(error.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(error));
+}
+
+/// Returns the message of the last error on the calling thread, or `NULL` if there was none.
+/// The string is owned by the library and valid until the next call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn ls_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |error| error.as_ptr())
+    })
+}
+
+/// Frees a string returned by the library. Does nothing if `s` is `NULL`.
+///
+/// # Safety
+/// `s` must be a string returned by the library that has not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(unsafe { CString::from_raw(s) });
+    }
+}
+
+/// Frees a chunk returned by the library. Does nothing if `bytes.data` is `NULL`.
+///
+/// # Safety
+/// `bytes` must be a chunk returned by the library that has not been freed yet.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_bytes_free(bytes: LsBytes) {
+    if !bytes.data.is_null() {
+        drop(unsafe { Box::from_raw(std::ptr::slice_from_raw_parts_mut(bytes.data, bytes.len)) });
+    }
+}
+
+/// The runtime driving all connections and transfers.
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .expect("Failed to create runtime")
+    })
+}
+
+/// Runs `f`, turning errors and panics into [`LsResult::Error`],
+/// so that no panic unwinds into the caller.
+fn ffi_call(f: impl FnOnce() -> anyhow::Result<LsResult>) -> LsResult {
+    match std::panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(result)) => result,
+        Ok(Err(e)) => {
+            set_last_error(&format!("{e:#}"));
+            LsResult::Error
+        }
+        Err(_) => {
+            set_last_error("Unexpected panic in the LocalSend library");
+            LsResult::Error
+        }
+    }
+}
+
+/// Runs `future` on the runtime for at most `timeout_ms`. Returns `None` on timeout.
+fn block_on_timeout<F: Future>(timeout_ms: u32, future: F) -> Option<F::Output> {
+    let timeout = Duration::from_millis(timeout_ms.into());
+    runtime()
+        .block_on(async { tokio::time::timeout(timeout, future).await })
+        .ok()
+}
+
+/// # Safety
+/// `s` must be `NULL` or a valid NUL-terminated string.
+unsafe fn str_arg<'a>(s: *const c_char, name: &str) -> anyhow::Result<&'a str> {
+    if s.is_null() {
+        return Err(anyhow::anyhow!("{name} must not be NULL"));
+    }
+    unsafe { CStr::from_ptr(s) }
+        .to_str()
+        .map_err(|_| anyhow::anyhow!("{name} is not valid UTF-8"))
+}
+
+/// # Safety
+/// `s` must be `NULL` or a valid NUL-terminated string.
+unsafe fn json_arg<T: DeserializeOwned>(s: *const c_char, name: &str) -> anyhow::Result<T> {
+    let s = unsafe { str_arg(s, name) }?;
+    serde_json::from_str(s).map_err(|e| anyhow::anyhow!("Invalid {name}: {e}"))
+}
+
+/// # Safety
+/// `out` must be `NULL` or valid for writes.
+unsafe fn write_out<T>(out: *mut T, value: T) -> anyhow::Result<LsResult> {
+    if out.is_null() {
+        return Err(anyhow::anyhow!("Output pointer must not be NULL"));
+    }
+    unsafe { out.write(value) };
+    Ok(LsResult::Ok)
+}
+
+/// # Safety
+/// `out` must be `NULL` or valid for writes.
+unsafe fn write_json_out<T: serde::Serialize>(
+    out: *mut *mut c_char,
+    value: &T,
+) -> anyhow::Result<LsResult> {
+    let json = CString::new(serde_json::to_string(value)?)?;
+    unsafe { write_out(out, json.into_raw()) }
+}
+
+/// # Safety
+/// `handle` must be `NULL` or a pointer returned by the library that has not been freed yet.
+unsafe fn handle_arg<'a, T>(handle: *const T) -> anyhow::Result<&'a T> {
+    unsafe { handle.as_ref() }.ok_or_else(|| anyhow::anyhow!("Handle must not be NULL"))
+}
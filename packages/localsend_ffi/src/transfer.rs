@@ -0,0 +1,645 @@
+use crate::connection::LsConnection;
+use crate::{
+    LsBytes, LsResult, block_on_timeout, ffi_call, handle_arg, json_arg, runtime, str_arg,
+    write_json_out, write_out,
+};
+use bytes::Bytes;
+use localsend::model::transfer::FileDto;
+use localsend::webrtc::signaling::WsServerSdpMessage;
+use localsend::webrtc::webrtc::{RTCFile, RTCFileError, RTCSendFileResponse, RTCStats, RTCStatus};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::ffi::c_char;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot};
+use tokio::task::AbortHandle;
+use uuid::Uuid;
+
+/// Chunks of a file buffered between the application and the data channel.
+const FILE_CHANNEL_CAPACITY: usize = 4;
+
+/// An event of a transfer, polled as JSON with [`ls_transfer_poll_event`].
+#[derive(Debug, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+enum TransferEvent {
+    /// `status` is the name of the [`RTCStatus`], e.g. `SENDING`.
+    Status {
+        status: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    Stats {
+        bytes_per_second: u64,
+        round_trip_time_ms: Option<u32>,
+        relayed: bool,
+    },
+
+    /// The remote peer asks for a PIN. Answer with [`ls_transfer_send_pin`].
+    PinRequested,
+
+    /// Sending: the files accepted by the receiver.
+    /// Provide their content with [`ls_transfer_begin_file`].
+    SelectedFiles {
+        file_ids: Vec<String>,
+    },
+
+    /// Receiving: the files offered by the sender.
+    /// Answer with [`ls_transfer_select_files`].
+    Files {
+        files: Vec<FileDto>,
+    },
+
+    /// Receiving: the content of a file arrives.
+    /// Consume it with [`ls_transfer_consume_chunk`].
+    Receiving {
+        file_id: String,
+    },
+
+    FileError {
+        file_id: String,
+        error: String,
+    },
+}
+
+impl From<RTCStatus> for TransferEvent {
+    fn from(status: RTCStatus) -> Self {
+        let (status, error) = match status {
+            RTCStatus::SdpExchanged => ("SDP_EXCHANGED", None),
+            RTCStatus::Connected => ("CONNECTED", None),
+            RTCStatus::PinRequired => ("PIN_REQUIRED", None),
+            RTCStatus::TooManyAttempts => ("TOO_MANY_ATTEMPTS", None),
+            RTCStatus::Declined => ("DECLINED", None),
+            RTCStatus::Sending => ("SENDING", None),
+            RTCStatus::Finished => ("FINISHED", None),
+            RTCStatus::Error(error) => ("ERROR", Some(error)),
+            RTCStatus::Timeout => ("TIMEOUT", None),
+            RTCStatus::Paused => ("PAUSED", None),
+            RTCStatus::Resumed => ("RESUMED", None),
+        };
+        TransferEvent::Status { status, error }
+    }
+}
+
+impl From<RTCStats> for TransferEvent {
+    fn from(stats: RTCStats) -> Self {
+        TransferEvent::Stats {
+            bytes_per_second: stats.bytes_per_second,
+            round_trip_time_ms: stats.round_trip_time_ms,
+            relayed: stats.relayed,
+        }
+    }
+}
+
+impl From<RTCFileError> for TransferEvent {
+    fn from(error: RTCFileError) -> Self {
+        TransferEvent::FileError {
+            file_id: error.file_id,
+            error: error.error,
+        }
+    }
+}
+
+/// Senders of the files whose content is being provided, mapped by file ID.
+type OutgoingFiles = Mutex<HashMap<String, mpsc::Sender<Bytes>>>;
+
+enum Role {
+    Send {
+        sending_tx: mpsc::Sender<RTCFile>,
+        outgoing: OutgoingFiles,
+    },
+    Receive {
+        selected_tx: Mutex<Option<oneshot::Sender<Option<HashSet<String>>>>>,
+
+        /// Files whose content is arriving, mapped by file ID.
+        incoming: Arc<Mutex<HashMap<String, mpsc::Receiver<Bytes>>>>,
+        file_status_tx: mpsc::Sender<RTCSendFileResponse>,
+    },
+}
+
+/// A transfer with a peer, created by [`ls_connection_send_offer`]
+/// or [`ls_connection_accept_offer`].
+pub struct LsTransfer {
+    events: Mutex<mpsc::UnboundedReceiver<TransferEvent>>,
+    pin_tx: Arc<Mutex<Option<oneshot::Sender<String>>>>,
+    role: Role,
+    task: AbortHandle,
+}
+
+impl LsTransfer {
+    fn send_role(&self) -> anyhow::Result<(&mpsc::Sender<RTCFile>, &OutgoingFiles)> {
+        match &self.role {
+            Role::Send {
+                sending_tx,
+                outgoing,
+            } => Ok((sending_tx, outgoing)),
+            Role::Receive { .. } => Err(anyhow::anyhow!("Not a sending transfer")),
+        }
+    }
+}
+
+/// Publishes every message of `rx` as an event.
+fn forward<T: Send + 'static>(
+    mut rx: mpsc::Receiver<T>,
+    event_tx: mpsc::UnboundedSender<TransferEvent>,
+) where
+    TransferEvent: From<T>,
+{
+    tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            if event_tx.send(message.into()).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Keeps the latest PIN request of the peer for [`ls_transfer_send_pin`].
+fn forward_pin_requests(
+    mut pin_rx: mpsc::Receiver<oneshot::Sender<String>>,
+    pin_tx: Arc<Mutex<Option<oneshot::Sender<String>>>>,
+    event_tx: mpsc::UnboundedSender<TransferEvent>,
+) {
+    tokio::spawn(async move {
+        while let Some(request) = pin_rx.recv().await {
+            *pin_tx.lock().unwrap() = Some(request);
+            if event_tx.send(TransferEvent::PinRequested).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Offers `files_json` (an array of `FileDto`) to the peer with the ID `target_id`
+/// and writes the transfer to `out`. `stun_servers_json` is an array of URLs.
+///
+/// # Safety
+/// `connection` must be a live connection, the strings must be valid NUL-terminated strings
+/// and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_connection_send_offer(
+    connection: *const LsConnection,
+    target_id: *const c_char,
+    files_json: *const c_char,
+    stun_servers_json: *const c_char,
+    out: *mut *mut LsTransfer,
+) -> LsResult {
+    ffi_call(|| {
+        let connection = unsafe { handle_arg(connection) }?;
+        let target = Uuid::parse_str(unsafe { str_arg(target_id, "target_id") }?)?;
+        let files: Vec<FileDto> = unsafe { json_arg(files_json, "files_json") }?;
+        let stun_servers: Vec<String> =
+            unsafe { json_arg(stun_servers_json, "stun_servers_json") }?;
+        let signing_key = localsend::crypto::token::parse_private_key(&connection.private_key)?;
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (status_tx, status_rx) = mpsc::channel::<RTCStatus>(1);
+        let (stats_tx, stats_rx) = mpsc::channel::<RTCStats>(1);
+        let (selected_tx, selected_rx) = oneshot::channel::<HashSet<String>>();
+        let (error_tx, error_rx) = mpsc::channel::<RTCFileError>(1);
+        let (pin_tx, pin_rx) = mpsc::channel::<oneshot::Sender<String>>(1);
+        let (pair_tx, pair_rx) = oneshot::channel::<oneshot::Sender<bool>>();
+        let (sending_tx, sending_rx) = mpsc::channel::<RTCFile>(1);
+        let pin_sender = Arc::new(Mutex::new(None));
+
+        let _guard = runtime().enter();
+        forward(status_rx, event_tx.clone());
+        forward(stats_rx, event_tx.clone());
+        forward(error_rx, event_tx.clone());
+        forward_pin_requests(pin_rx, pin_sender.clone(), event_tx.clone());
+
+        tokio::spawn(async move {
+            if let Ok(selected) = selected_rx.await {
+                let _ = event_tx.send(TransferEvent::SelectedFiles {
+                    file_ids: selected.into_iter().collect(),
+                });
+            }
+        });
+
+        tokio::spawn(async move {
+            // Pairing is not supported via the C API.
+            if let Ok(pair_tx) = pair_rx.await {
+                let _ = pair_tx.send(false);
+            }
+        });
+
+        let managed_connection = connection.inner.clone();
+        let task = tokio::spawn(async move {
+            let result = localsend::webrtc::webrtc::send_offer(
+                &managed_connection,
+                stun_servers,
+                target,
+                signing_key,
+                None,
+                None,
+                files,
+                status_tx.clone(),
+                stats_tx,
+                selected_tx,
+                error_tx,
+                pin_tx,
+                pair_tx,
+                sending_rx,
+            )
+            .await;
+
+            if let Err(e) = result {
+                let _ = status_tx.send(RTCStatus::Error(e.to_string())).await;
+            }
+        });
+
+        let transfer = Box::new(LsTransfer {
+            events: Mutex::new(event_rx),
+            pin_tx: pin_sender,
+            role: Role::Send {
+                sending_tx,
+                outgoing: Mutex::new(HashMap::new()),
+            },
+            task: task.abort_handle(),
+        });
+        unsafe { write_out(out, Box::into_raw(transfer)) }
+    })
+}
+
+/// Accepts the offer `offer_json` (the `OFFER` message polled from the connection)
+/// and writes the transfer to `out`. `stun_servers_json` is an array of URLs.
+///
+/// # Safety
+/// `connection` must be a live connection, the strings must be valid NUL-terminated strings
+/// and `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_connection_accept_offer(
+    connection: *const LsConnection,
+    offer_json: *const c_char,
+    stun_servers_json: *const c_char,
+    out: *mut *mut LsTransfer,
+) -> LsResult {
+    ffi_call(|| {
+        let connection = unsafe { handle_arg(connection) }?;
+        let offer: WsServerSdpMessage = unsafe { json_arg(offer_json, "offer_json") }?;
+        let stun_servers: Vec<String> =
+            unsafe { json_arg(stun_servers_json, "stun_servers_json") }?;
+        let signing_key = localsend::crypto::token::parse_private_key(&connection.private_key)?;
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (status_tx, status_rx) = mpsc::channel::<RTCStatus>(1);
+        let (stats_tx, stats_rx) = mpsc::channel::<RTCStats>(1);
+        let (files_tx, files_rx) = oneshot::channel::<Vec<FileDto>>();
+        let (selected_tx, selected_rx) = oneshot::channel::<Option<HashSet<String>>>();
+        let (error_tx, error_rx) = mpsc::channel::<RTCFileError>(1);
+        let (pin_tx, pin_rx) = mpsc::channel::<oneshot::Sender<String>>(1);
+        let (receiving_tx, mut receiving_rx) = mpsc::channel::<RTCFile>(1);
+        let (file_status_tx, file_status_rx) = mpsc::channel::<RTCSendFileResponse>(1);
+        let pin_sender = Arc::new(Mutex::new(None));
+        let incoming = Arc::new(Mutex::new(HashMap::new()));
+
+        let _guard = runtime().enter();
+        forward(status_rx, event_tx.clone());
+        forward(stats_rx, event_tx.clone());
+        forward(error_rx, event_tx.clone());
+        forward_pin_requests(pin_rx, pin_sender.clone(), event_tx.clone());
+
+        tokio::spawn({
+            let event_tx = event_tx.clone();
+            async move {
+                if let Ok(files) = files_rx.await {
+                    let _ = event_tx.send(TransferEvent::Files { files });
+                }
+            }
+        });
+
+        tokio::spawn({
+            let incoming = incoming.clone();
+            async move {
+                while let Some(file) = receiving_rx.recv().await {
+                    // Registered before the event, so the content can be consumed right away.
+                    incoming
+                        .lock()
+                        .unwrap()
+                        .insert(file.file_id.clone(), file.binary_rx);
+                    let event = TransferEvent::Receiving {
+                        file_id: file.file_id,
+                    };
+                    if event_tx.send(event).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        let managed_connection = connection.inner.clone();
+        let task = tokio::spawn(async move {
+            let result = localsend::webrtc::webrtc::accept_offer(
+                &managed_connection,
+                stun_servers,
+                &offer,
+                signing_key,
+                None,
+                None,
+                status_tx.clone(),
+                stats_tx,
+                files_tx,
+                selected_rx,
+                error_tx,
+                pin_tx,
+                receiving_tx,
+                file_status_rx,
+            )
+            .await;
+
+            if let Err(e) = result {
+                let _ = status_tx.send(RTCStatus::Error(e.to_string())).await;
+            }
+        });
+
+        let transfer = Box::new(LsTransfer {
+            events: Mutex::new(event_rx),
+            pin_tx: pin_sender,
+            role: Role::Receive {
+                selected_tx: Mutex::new(Some(selected_tx)),
+                incoming,
+                file_status_tx,
+            },
+            task: task.abort_handle(),
+        });
+        unsafe { write_out(out, Box::into_raw(transfer)) }
+    })
+}
+
+/// Waits up to `timeout_ms` for the next event of the transfer and writes it to `out` as JSON,
+/// e.g. `{"type": "status", "status": "SENDING"}`.
+/// Returns [`LsResult::Closed`] once the transfer has ended and all events have been polled.
+///
+/// # Safety
+/// `transfer` must be a live transfer; `out` must be valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_poll_event(
+    transfer: *const LsTransfer,
+    timeout_ms: u32,
+    out: *mut *mut c_char,
+) -> LsResult {
+    ffi_call(|| {
+        let transfer = unsafe { handle_arg(transfer) }?;
+        let mut events = transfer
+            .events
+            .lock()
+            .map_err(|_| anyhow::anyhow!("Transfer poisoned"))?;
+        match block_on_timeout(timeout_ms, events.recv()) {
+            None => Ok(LsResult::Timeout),
+            Some(None) => Ok(LsResult::Closed),
+            Some(Some(event)) => unsafe { write_json_out(out, &event) },
+        }
+    })
+}
+
+/// Answers the latest `pinRequested` event.
+///
+/// # Safety
+/// `transfer` must be a live transfer; `pin` must be a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_send_pin(
+    transfer: *const LsTransfer,
+    pin: *const c_char,
+) -> LsResult {
+    ffi_call(|| {
+        let transfer = unsafe { handle_arg(transfer) }?;
+        let pin = unsafe { str_arg(pin, "pin") }?;
+        let Some(pin_tx) = transfer.pin_tx.lock().unwrap().take() else {
+            return Err(anyhow::anyhow!("No PIN requested"));
+        };
+        pin_tx
+            .send(pin.to_owned())
+            .map_err(|_| anyhow::anyhow!("PIN channel closed"))?;
+        Ok(LsResult::Ok)
+    })
+}
+
+/// Sending: starts providing the content of an accepted file.
+/// The files are sent one at a time, so end each file before beginning the next one.
+///
+/// # Safety
+/// `transfer` must be a live transfer; `file_id` must be a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_begin_file(
+    transfer: *const LsTransfer,
+    file_id: *const c_char,
+) -> LsResult {
+    ffi_call(|| {
+        let transfer = unsafe { handle_arg(transfer) }?;
+        let file_id = unsafe { str_arg(file_id, "file_id") }?;
+        let (sending_tx, outgoing) = transfer.send_role()?;
+
+        let (binary_tx, binary_rx) = mpsc::channel(FILE_CHANNEL_CAPACITY);
+        let file = RTCFile {
+            file_id: file_id.to_owned(),
+            binary_rx,
+        };
+        runtime()
+            .block_on(sending_tx.send(file))
+            .map_err(|_| anyhow::anyhow!("Transfer closed"))?;
+        outgoing
+            .lock()
+            .unwrap()
+            .insert(file_id.to_owned(), binary_tx);
+        Ok(LsResult::Ok)
+    })
+}
+
+/// Sending: feeds the next `len` bytes of a file started with [`ls_transfer_begin_file`].
+/// Blocks while the data channel is busy.
+///
+/// # Safety
+/// `transfer` must be a live transfer, `file_id` a valid NUL-terminated string
+/// and `data` valid for reads of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_feed_chunk(
+    transfer: *const LsTransfer,
+    file_id: *const c_char,
+    data: *const u8,
+    len: usize,
+) -> LsResult {
+    ffi_call(|| {
+        let transfer = unsafe { handle_arg(transfer) }?;
+        let file_id = unsafe { str_arg(file_id, "file_id") }?;
+        if data.is_null() && len > 0 {
+            return Err(anyhow::anyhow!("data must not be NULL"));
+        }
+        let chunk = match len {
+            0 => Bytes::new(),
+            _ => Bytes::copy_from_slice(unsafe { std::slice::from_raw_parts(data, len) }),
+        };
+
+        let (_, outgoing) = transfer.send_role()?;
+        let Some(binary_tx) = outgoing.lock().unwrap().get(file_id).cloned() else {
+            return Err(anyhow::anyhow!("File {file_id} has not begun"));
+        };
+        match runtime().block_on(binary_tx.send(chunk)) {
+            Ok(()) => Ok(LsResult::Ok),
+            Err(_) => Ok(LsResult::Closed),
+        }
+    })
+}
+
+/// Sending: marks the content of a file as complete.
+///
+/// # Safety
+/// `transfer` must be a live transfer; `file_id` must be a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_end_file(
+    transfer: *const LsTransfer,
+    file_id: *const c_char,
+) -> LsResult {
+    ffi_call(|| {
+        let transfer = unsafe { handle_arg(transfer) }?;
+        let file_id = unsafe { str_arg(file_id, "file_id") }?;
+        let (_, outgoing) = transfer.send_role()?;
+        match outgoing.lock().unwrap().remove(file_id) {
+            Some(_) => Ok(LsResult::Ok),
+            None => Err(anyhow::anyhow!("File {file_id} has not begun")),
+        }
+    })
+}
+
+/// Receiving: accepts the files with the IDs in `file_ids_json` (a JSON array),
+/// or declines the whole transfer if `file_ids_json` is `NULL`.
+///
+/// # Safety
+/// `transfer` must be a live transfer; `file_ids_json` must be `NULL`
+/// or a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_select_files(
+    transfer: *const LsTransfer,
+    file_ids_json: *const c_char,
+) -> LsResult {
+    ffi_call(|| {
+        let transfer = unsafe { handle_arg(transfer) }?;
+        let Role::Receive { selected_tx, .. } = &transfer.role else {
+            return Err(anyhow::anyhow!("Not a receiving transfer"));
+        };
+        let selection: Option<HashSet<String>> = match file_ids_json.is_null() {
+            true => None,
+            false => Some(unsafe { json_arg(file_ids_json, "file_ids_json") }?),
+        };
+
+        let Some(selected_tx) = selected_tx.lock().unwrap().take() else {
+            return Err(anyhow::anyhow!("Selected files already sent"));
+        };
+        selected_tx
+            .send(selection)
+            .map_err(|_| anyhow::anyhow!("Selected files channel closed"))?;
+        Ok(LsResult::Ok)
+    })
+}
+
+/// Receiving: waits up to `timeout_ms` for the next chunk of a file announced by a
+/// `receiving` event and writes it to `out`; free it with [`ls_bytes_free`].
+/// Returns [`LsResult::Closed`] once the file is complete,
+/// which must then be confirmed with [`ls_transfer_report_file`].
+///
+/// # Safety
+/// `transfer` must be a live transfer, `file_id` a valid NUL-terminated string
+/// and `out` valid for writes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_consume_chunk(
+    transfer: *const LsTransfer,
+    file_id: *const c_char,
+    timeout_ms: u32,
+    out: *mut LsBytes,
+) -> LsResult {
+    ffi_call(|| {
+        let transfer = unsafe { handle_arg(transfer) }?;
+        let file_id = unsafe { str_arg(file_id, "file_id") }?;
+        let Role::Receive { incoming, .. } = &transfer.role else {
+            return Err(anyhow::anyhow!("Not a receiving transfer"));
+        };
+
+        // Taken out while waiting, so other files can be consumed concurrently.
+        let Some(mut binary_rx) = incoming.lock().unwrap().remove(file_id) else {
+            return Err(anyhow::anyhow!("File {file_id} is not being received"));
+        };
+        match block_on_timeout(timeout_ms, binary_rx.recv()) {
+            None => {
+                incoming
+                    .lock()
+                    .unwrap()
+                    .insert(file_id.to_owned(), binary_rx);
+                Ok(LsResult::Timeout)
+            }
+            Some(None) => Ok(LsResult::Closed),
+            Some(Some(chunk)) => {
+                incoming
+                    .lock()
+                    .unwrap()
+                    .insert(file_id.to_owned(), binary_rx);
+                let chunk = Box::into_raw(Vec::from(chunk).into_boxed_slice());
+                let bytes = LsBytes {
+                    data: chunk.cast::<u8>(),
+                    len: chunk.len(),
+                };
+                unsafe { write_out(out, bytes) }
+            }
+        }
+    })
+}
+
+/// Receiving: tells the sender whether a file was stored successfully.
+/// `error` is an optional message shown to the sender.
+///
+/// # Safety
+/// `transfer` must be a live transfer; the strings must be `NULL` (only `error`)
+/// or valid NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_report_file(
+    transfer: *const LsTransfer,
+    file_id: *const c_char,
+    success: bool,
+    error: *const c_char,
+) -> LsResult {
+    ffi_call(|| {
+        let transfer = unsafe { handle_arg(transfer) }?;
+        let Role::Receive { file_status_tx, .. } = &transfer.role else {
+            return Err(anyhow::anyhow!("Not a receiving transfer"));
+        };
+        let response = RTCSendFileResponse {
+            id: unsafe { str_arg(file_id, "file_id") }?.to_owned(),
+            success,
+            error: match error.is_null() {
+                true => None,
+                false => Some(unsafe { str_arg(error, "error") }?.to_owned()),
+            },
+        };
+        runtime()
+            .block_on(file_status_tx.send(response))
+            .map_err(|_| anyhow::anyhow!("Transfer closed"))?;
+        Ok(LsResult::Ok)
+    })
+}
+
+/// Aborts the transfer and closes the peer connection, which disconnects the remote peer.
+///
+/// # Safety
+/// `transfer` must be a live transfer.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_cancel(transfer: *const LsTransfer) -> LsResult {
+    ffi_call(|| {
+        let transfer = unsafe { handle_arg(transfer) }?;
+        transfer.task.abort();
+        Ok(LsResult::Ok)
+    })
+}
+
+/// Cancels the transfer if still running and frees it. Does nothing if `transfer` is `NULL`.
+///
+/// # Safety
+/// `transfer` must be a transfer that has not been freed yet,
+/// and must not be used by other threads anymore.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn ls_transfer_free(transfer: *mut LsTransfer) {
+    if !transfer.is_null() {
+        let transfer = unsafe { Box::from_raw(transfer) };
+        transfer.task.abort();
+    }
+}
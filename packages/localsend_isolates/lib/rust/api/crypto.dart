@@ -4,6 +4,7 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:localsend_isolates/rust/api/http.dart';
 import 'package:localsend_isolates/rust/frb_generated.dart';
 
 Future<void> verifyCert({required String cert, required String publicKey}) =>
@@ -11,6 +12,15 @@ Future<void> verifyCert({required String cert, required String publicKey}) =>
 
 Future<KeyPair> generateKeyPair() => RustLib.instance.api.crateApiCryptoGenerateKeyPair();
 
+/// Hashes the file at `path` as lowercase hex, the format of `FileDto::sha256`.
+/// Reports the progress from 0 to 1 to `on_progress`, at most every 100 ms and once at the end.
+/// Fails once `cancel_token` is cancelled.
+Future<String> computeSha256({
+  required String path,
+  required RsCancellationToken cancelToken,
+  required FutureOr<void> Function(double) onProgress,
+}) => RustLib.instance.api.crateApiCryptoComputeSha256(path: path, cancelToken: cancelToken, onProgress: onProgress);
+
 class KeyPair {
   final String privateKey;
   final String publicKey;
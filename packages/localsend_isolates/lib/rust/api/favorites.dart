@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:localsend_isolates/rust/api/webrtc.dart';
+import 'package:localsend_isolates/rust/frb_generated.dart';
+
+/// Loads the favorites at `path`, or none if the file does not exist yet.
+Future<RsFavoriteStore> openFavoriteStore({required String path}) => RustLib.instance.api.crateApiFavoritesOpenFavoriteStore(path: path);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsFavoriteStore>>
+abstract class RsFavoriteStore implements RustOpaqueInterface {
+  /// Marks a discovered peer as favorite, keeping the notes if `notes` is `None`.
+  Future<FavoriteDevice> addPeer({required ClientInfo peer, String? notes});
+
+  FavoriteDevice? get({required String fingerprint});
+
+  /// All favorites sorted by alias.
+  List<FavoriteDevice> list();
+
+  /// Returns the favorite matching a peer of [crate::api::webrtc::WsServerMessage::Joined]
+  /// or [crate::api::webrtc::WsServerMessage::Hello] and records that it was seen.
+  Future<FavoriteDevice?> matchPeer({required ClientInfo peer});
+
+  Future<void> remove({required String fingerprint});
+
+  Future<void> setNotes({required String fingerprint, required String notes});
+}
+
+class FavoriteDevice {
+  final String fingerprint;
+  final String alias;
+  final BigInt? lastSeen;
+  final String notes;
+
+  const FavoriteDevice({
+    required this.fingerprint,
+    required this.alias,
+    this.lastSeen,
+    required this.notes,
+  });
+
+  @override
+  int get hashCode => fingerprint.hashCode ^ alias.hashCode ^ lastSeen.hashCode ^ notes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FavoriteDevice &&
+          runtimeType == other.runtimeType &&
+          fingerprint == other.fingerprint &&
+          alias == other.alias &&
+          lastSeen == other.lastSeen &&
+          notes == other.notes;
+}
@@ -7,3 +7,50 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:localsend_isolates/rust/frb_generated.dart';
 
 Future<void> enableDebugLogging() => RustLib.instance.api.crateApiLoggingEnableDebugLogging();
+
+/// Streams the events of at least `min_level` to the returned stream, e.g. for a debug console.
+/// Only events whose target starts with one of `targets` are sent, all if empty.
+/// Replaces the stream of the previous call, which ends.
+Stream<LogEntry> listenLogs({required LogLevel minLevel, required List<String> targets}) =>
+    RustLib.instance.api.crateApiLoggingListenLogs(minLevel: minLevel, targets: targets);
+
+/// An event emitted by the core crate or its dependencies.
+class LogEntry {
+  /// Unix timestamp in milliseconds.
+  final BigInt timestampMs;
+  final LogLevel level;
+
+  /// Module path the event was emitted from, e.g. `localsend::http::server`.
+  final String target;
+
+  /// The message followed by the other fields, e.g. `Client connected peer=192.168.1.2`.
+  final String message;
+
+  const LogEntry({
+    required this.timestampMs,
+    required this.level,
+    required this.target,
+    required this.message,
+  });
+
+  @override
+  int get hashCode => timestampMs.hashCode ^ level.hashCode ^ target.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LogEntry &&
+          runtimeType == other.runtimeType &&
+          timestampMs == other.timestampMs &&
+          level == other.level &&
+          target == other.target &&
+          message == other.message;
+}
+
+enum LogLevel {
+  trace,
+  debug,
+  info,
+  warn,
+  error,
+}
@@ -6,6 +6,40 @@
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:localsend_isolates/rust/frb_generated.dart';
 
+/// Describes the file at `path` with a new ID, its MIME type guessed from the extension and its timestamps.
+/// The SHA-256 and the preview are only computed if requested, a failing preview is logged and left out.
+Future<FileDto> createFileDto({required String path, required bool withHash, required bool withPreview}) =>
+    RustLib.instance.api.crateApiModelCreateFileDto(path: path, withHash: withHash, withPreview: withPreview);
+
+/// Returns the lowercase hex SHA-256 of the first `length` bytes received to `path` so far,
+/// to store with the progress of an interrupted transfer.
+Future<String> partialSha256({required String path, required BigInt length}) =>
+    RustLib.instance.api.crateApiModelPartialSha256(path: path, length: length);
+
+/// Infers the MIME type of the file at `path` from its extension and its first bytes,
+/// e.g. to warn before opening a received file whose content does not match its name.
+Future<InferredMimeType> inferMimeType({required String path}) => RustLib.instance.api.crateApiModelInferMimeType(path: path);
+
+/// Removes the `.part` files in `directory` left by transfers that were interrupted,
+/// e.g. because the app was killed. Subdirectories are not searched.
+/// Must not be called while files are received to `directory`, returns the removed paths.
+Future<List<String>> removePartFiles({required String directory}) =>
+    RustLib.instance.api.crateApiModelRemovePartFiles(directory: directory);
+
+/// Turns the name of a received file into a relative path that stays inside the directory it is joined to,
+/// e.g. `../photo.jpg` becomes `photo.jpg`. Fails if no usable name is left.
+String sanitizeFileName({required String fileName}) => RustLib.instance.api.crateApiModelSanitizeFileName(fileName: fileName);
+
+/// Lists the files in `path` and its subdirectories for sending the directory, sorted by file name.
+/// Globs are matched against the path relative to `path`, e.g. `*.jpg` or `**/.git`.
+/// Files must match one of `include` unless it is empty, excluded directories are not entered.
+Future<List<ScannedFile>> scanDirectory({
+  required String path,
+  required List<String> include,
+  required List<String> exclude,
+  required SymlinkPolicy symlinks,
+}) => RustLib.instance.api.crateApiModelScanDirectory(path: path, include: include, exclude: exclude, symlinks: symlinks);
+
 enum DeviceType {
   mobile,
   desktop,
@@ -53,18 +87,76 @@ class FileDto {
 class FileMetadata {
   final String? modified;
   final String? accessed;
+  final int? mode;
+  final MediaInfo? media;
 
   const FileMetadata({
     this.modified,
     this.accessed,
+    this.mode,
+    this.media,
   });
 
   @override
-  int get hashCode => modified.hashCode ^ accessed.hashCode;
+  int get hashCode => modified.hashCode ^ accessed.hashCode ^ mode.hashCode ^ media.hashCode;
 
   @override
   bool operator ==(Object other) =>
-      identical(this, other) || other is FileMetadata && runtimeType == other.runtimeType && modified == other.modified && accessed == other.accessed;
+      identical(this, other) ||
+      other is FileMetadata &&
+          runtimeType == other.runtimeType &&
+          modified == other.modified &&
+          accessed == other.accessed &&
+          mode == other.mode &&
+          media == other.media;
+}
+
+/// The MIME type of a file inferred by [`infer_mime_type`].
+class InferredMimeType {
+  final String mimeType;
+
+  /// The content does not match the extension, e.g. an executable named `photo.jpg`.
+  /// `mime_type` is the type of the content then.
+  final bool mismatch;
+
+  const InferredMimeType({
+    required this.mimeType,
+    required this.mismatch,
+  });
+
+  @override
+  int get hashCode => mimeType.hashCode ^ mismatch.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) || other is InferredMimeType && runtimeType == other.runtimeType && mimeType == other.mimeType && mismatch == other.mismatch;
+}
+
+class MediaInfo {
+  final int? width;
+  final int? height;
+  final int? orientation;
+  final BigInt? durationMs;
+
+  const MediaInfo({
+    this.width,
+    this.height,
+    this.orientation,
+    this.durationMs,
+  });
+
+  @override
+  int get hashCode => width.hashCode ^ height.hashCode ^ orientation.hashCode ^ durationMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaInfo &&
+          runtimeType == other.runtimeType &&
+          width == other.width &&
+          height == other.height &&
+          orientation == other.orientation &&
+          durationMs == other.durationMs;
 }
 
 class PrepareUploadRequestDto {
@@ -186,3 +278,30 @@ class RegisterResponseDto {
           token == other.token &&
           hasWebInterface == other.hasWebInterface;
 }
+
+/// A file found by [`scan_directory`].
+class ScannedFile {
+  /// Path the content of the file is read from.
+  final String path;
+
+  /// The file name is the path relative to the parent of the scanned directory,
+  /// separated by `/`, so the receiver recreates the directory.
+  final FileDto file;
+
+  const ScannedFile({
+    required this.path,
+    required this.file,
+  });
+
+  @override
+  int get hashCode => path.hashCode ^ file.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) || other is ScannedFile && runtimeType == other.runtimeType && path == other.path && file == other.file;
+}
+
+enum SymlinkPolicy {
+  skip,
+  follow,
+}
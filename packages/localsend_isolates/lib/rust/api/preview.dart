@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:localsend_isolates/rust/api/model.dart';
+import 'package:localsend_isolates/rust/frb_generated.dart';
+
+/// Returns the preview embedded in the image or video file at `path` as a `data:` URI,
+/// or `None` if the file has no embedded preview.
+/// The image itself is never decoded.
+Future<String?> extractEmbeddedPreview({required String path, required String fileType}) =>
+    RustLib.instance.api.crateApiPreviewExtractEmbeddedPreview(path: path, fileType: fileType);
+
+/// Sets the embedded preview of the files that do not have one yet.
+/// `paths` maps the file IDs to their paths, files without a path are skipped.
+/// Files whose preview fails are logged and left unchanged.
+Future<List<FileDto>> fillPreviews({required List<FileDto> files, required Map<String, String> paths}) =>
+    RustLib.instance.api.crateApiPreviewFillPreviews(files: files, paths: paths);
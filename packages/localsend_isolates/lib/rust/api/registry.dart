@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:localsend_isolates/rust/api/webrtc.dart';
+import 'package:localsend_isolates/rust/frb_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `register`
+
+/// Registers the controller and returns its handle ID.
+/// Streams that are already listened to keep emitting to the listening isolate.
+String registerSendController({required RtcSendController controller}) =>
+    RustLib.instance.api.crateApiRegistryRegisterSendController(controller: controller);
+
+/// Registers the controller and returns its handle ID.
+/// Streams that are already listened to keep emitting to the listening isolate.
+String registerReceiveController({required RtcReceiveController controller}) =>
+    RustLib.instance.api.crateApiRegistryRegisterReceiveController(controller: controller);
+
+/// Registers the connection and returns its handle ID.
+String registerSignalingConnection({required LsSignalingConnection connection}) =>
+    RustLib.instance.api.crateApiRegistryRegisterSignalingConnection(connection: connection);
+
+/// Returns the controller registered with [`register_send_controller`] to the calling isolate.
+RtcSendController getSendController({required String id}) => RustLib.instance.api.crateApiRegistryGetSendController(id: id);
+
+/// Returns the controller registered with [`register_receive_controller`] to the calling isolate.
+RtcReceiveController getReceiveController({required String id}) => RustLib.instance.api.crateApiRegistryGetReceiveController(id: id);
+
+/// Returns the connection registered with [`register_signaling_connection`] to the calling isolate.
+LsSignalingConnection getSignalingConnection({required String id}) => RustLib.instance.api.crateApiRegistryGetSignalingConnection(id: id);
+
+/// Releases the handle, e.g. once the transfer has ended.
+/// References already returned to isolates stay valid.
+void unregisterHandle({required String id}) => RustLib.instance.api.crateApiRegistryUnregisterHandle(id: id);
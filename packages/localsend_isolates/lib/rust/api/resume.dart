@@ -0,0 +1,119 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:localsend_isolates/rust/frb_generated.dart';
+
+/// Loads the sessions at `path`, or none if the file does not exist yet.
+Future<RsResumeStore> openResumeStore({required String path}) => RustLib.instance.api.crateApiResumeOpenResumeStore(path: path);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsResumeStore>>
+abstract class RsResumeStore implements RustOpaqueInterface {
+  ResumeSession? get({required String sessionId});
+
+  /// All sessions, most recently updated first.
+  List<ResumeSession> list();
+
+  /// The sessions with a peer, e.g. to offer resuming them once it is discovered.
+  List<ResumeSession> listForPeer({required String fingerprint});
+
+  Future<void> remove({required String sessionId});
+
+  /// Adds or replaces the session and returns it with the updated timestamp.
+  Future<ResumeSession> save({required ResumeSession session});
+
+  /// Records the progress of a file of a saved session.
+  Future<void> setProgress({required String sessionId, required String fileId, required BigInt bytesCompleted, String? partialSha256});
+}
+
+enum ResumeDirection {
+  send,
+  receive,
+}
+
+/// The progress of a single file of a [`ResumeSession`].
+class ResumeFile {
+  final String fileId;
+
+  /// Token issued by the receiver for the file, if any.
+  final String? token;
+
+  /// Path the file is read from or, when receiving, written to.
+  final String path;
+
+  final BigInt size;
+
+  /// Number of bytes transferred so far, i.e. the offset to resume from.
+  final BigInt bytesCompleted;
+
+  /// Lowercase hex SHA-256 of the first `bytes_completed` bytes,
+  /// to detect that the partial data changed before resuming.
+  final String? partialSha256;
+
+  const ResumeFile({
+    required this.fileId,
+    this.token,
+    required this.path,
+    required this.size,
+    required this.bytesCompleted,
+    this.partialSha256,
+  });
+
+  @override
+  int get hashCode => fileId.hashCode ^ token.hashCode ^ path.hashCode ^ size.hashCode ^ bytesCompleted.hashCode ^ partialSha256.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ResumeFile &&
+          runtimeType == other.runtimeType &&
+          fileId == other.fileId &&
+          token == other.token &&
+          path == other.path &&
+          size == other.size &&
+          bytesCompleted == other.bytesCompleted &&
+          partialSha256 == other.partialSha256;
+}
+
+/// A transfer that was interrupted, e.g. because the app was closed.
+class ResumeSession {
+  final String sessionId;
+
+  /// Fingerprint of the peer, so resuming can be offered once it is seen again.
+  final String peerFingerprint;
+
+  final String peerAlias;
+
+  final ResumeDirection direction;
+
+  final List<ResumeFile> files;
+
+  /// Unix timestamp in seconds of the last change.
+  final BigInt updatedAt;
+
+  const ResumeSession({
+    required this.sessionId,
+    required this.peerFingerprint,
+    required this.peerAlias,
+    required this.direction,
+    required this.files,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode => sessionId.hashCode ^ peerFingerprint.hashCode ^ peerAlias.hashCode ^ direction.hashCode ^ files.hashCode ^ updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ResumeSession &&
+          runtimeType == other.runtimeType &&
+          sessionId == other.sessionId &&
+          peerFingerprint == other.peerFingerprint &&
+          peerAlias == other.peerAlias &&
+          direction == other.direction &&
+          files == other.files &&
+          updatedAt == other.updatedAt;
+}
@@ -89,6 +89,9 @@ abstract class RsHttpServer implements RustOpaqueInterface {
   /// the file should be saved to (either a path or a file descriptor)
   /// and waits until the file has been received completely.
   ///
+  /// The destination passed to [RsHttpServer::respond_prepare_upload] for the file,
+  /// if any, is applied to [path].
+  ///
   /// The progress (fraction of [file_size]) is emitted on [sink]
   /// while the file is being received.
   Stream<double> respondFileUpload({required String sessionId, required String fileId, String? path, int? fileDescriptor, required BigInt fileSize});
@@ -102,7 +105,12 @@ abstract class RsHttpServer implements RustOpaqueInterface {
   ///
   /// Passing the accepted file IDs (a subset of the offered files) accepts the request.
   /// Passing `None` declines the request.
-  Future<void> respondPrepareUpload({List<String>? acceptedFileIds});
+  ///
+  /// `destinations` maps accepted file IDs to a new name or path chosen by the user,
+  /// which [RsHttpServer::respond_file_upload] applies to the path it is given.
+  /// A name or relative path is resolved against the directory of that path,
+  /// an absolute path replaces it.
+  Future<void> respondPrepareUpload({List<String>? acceptedFileIds, required Map<String, String> destinations});
 
   /// Stops the server.
   /// Returns after the listeners are closed, so the port can be bound again.
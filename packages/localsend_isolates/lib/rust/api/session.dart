@@ -0,0 +1,84 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:localsend_isolates/rust/api/webrtc.dart';
+import 'package:localsend_isolates/rust/frb_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add`
+
+SessionManager createSessionManager() => RustLib.instance.api.crateApiSessionCreateSessionManager();
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SessionManager>>
+abstract class SessionManager implements RustOpaqueInterface {
+  /// Number of tracked sessions that are still running.
+  Future<int> activeCount();
+
+  /// Starts tracking the transfer and returns its session ID.
+  Future<String> addReceive({required RtcReceiveController controller});
+
+  /// Starts tracking the transfer and returns its session ID.
+  Future<String> addSend({required RtcSendController controller});
+
+  /// Aborts the transfer like [`RTCSendController::cancel`] and [`RTCReceiveController::cancel`].
+  Future<void> cancel({required String sessionId});
+
+  /// Aborts all tracked transfers.
+  Future<void> cancelAll();
+
+  /// Removes the callback registered with [`Self::set_end_callback`].
+  Future<void> clearEndCallback();
+
+  /// Returns all tracked sessions in the order they were added.
+  /// Sessions are removed once they have ended and the end callback has returned.
+  Future<List<SessionInfo>> listSessions();
+
+  /// Stops tracking the session without cancelling it.
+  Future<void> remove({required String sessionId});
+
+  /// Registers a callback invoked once a tracked transfer has finished, failed or been cancelled,
+  /// replacing the previous one.
+  ///
+  /// It is called from Rust even if the status stream of the transfer is not listened to,
+  /// e.g. to show a notification from a background isolate.
+  /// The status is [`RTCStatus::Finished`] if the transfer completed.
+  Future<void> setEndCallback({required FutureOr<void> Function(SessionInfo) callback});
+}
+
+enum SessionDirection {
+  send,
+  receive,
+}
+
+class SessionInfo {
+  final String sessionId;
+  final SessionDirection direction;
+
+  /// Latest status of the transfer. `None` until the first status.
+  final RTCStatus? status;
+
+  /// False once the transfer has finished, failed or been cancelled.
+  final bool active;
+
+  const SessionInfo({
+    required this.sessionId,
+    required this.direction,
+    this.status,
+    required this.active,
+  });
+
+  @override
+  int get hashCode => sessionId.hashCode ^ direction.hashCode ^ status.hashCode ^ active.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SessionInfo &&
+          runtimeType == other.runtimeType &&
+          sessionId == other.sessionId &&
+          direction == other.direction &&
+          status == other.status &&
+          active == other.active;
+}
@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:localsend_isolates/rust/frb_generated.dart';
+
+/// Loads the settings at `path`, or the defaults if the file does not exist yet.
+Future<RsSettingsStore> openSettings({required String path}) => RustLib.instance.api.crateApiSettingsOpenSettings(path: path);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsSettingsStore>>
+abstract class RsSettingsStore implements RustOpaqueInterface {
+  String? alias();
+
+  /// Maximum transfer speed in KiB per second. Unlimited if `None`.
+  int? bandwidthLimit();
+
+  String? downloadDir();
+
+  String? pin();
+
+  Future<void> setAlias({String? alias});
+
+  Future<void> setBandwidthLimit({int? bandwidthLimit});
+
+  Future<void> setDownloadDir({String? downloadDir});
+
+  Future<void> setPin({String? pin});
+}
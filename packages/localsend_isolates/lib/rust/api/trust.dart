@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:localsend_isolates/rust/api/server.dart';
+import 'package:localsend_isolates/rust/frb_generated.dart';
+
+/// Loads the trusted devices at `path`, or none if the file does not exist yet.
+Future<RsTrustStore> openTrustStore({required String path}) => RustLib.instance.api.crateApiTrustOpenTrustStore(path: path);
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RsTrustStore>>
+abstract class RsTrustStore implements RustOpaqueInterface {
+  TrustStatus check({required String alias, required String fingerprint});
+
+  /// Checks the sender of a [crate::api::server::RsServerEvent::PrepareUpload].
+  /// The fingerprint of the client certificate is preferred over the announced one.
+  TrustStatus checkOffer({required RegisterDtoV2 info, String? certFingerprint});
+
+  /// The pinned fingerprint of the device, if any.
+  String? fingerprint({required String alias});
+
+  Future<void> forget({required String alias});
+
+  /// Pins the fingerprint for the device, replacing the previous one.
+  Future<void> trust({required String alias, required String fingerprint});
+}
+
+enum TrustStatus {
+  trusted,
+  new_,
+  changed,
+}
@@ -17,8 +17,15 @@ Stream<WsServerMessage> connect({
   required String uri,
   required ProposingClientInfo info,
   required String privateKey,
+  required bool allowInsecure,
   required FutureOr<void> Function(LsSignalingConnection) onConnection,
-}) => RustLib.instance.api.crateApiWebrtcConnect(uri: uri, info: info, privateKey: privateKey, onConnection: onConnection);
+}) => RustLib.instance.api.crateApiWebrtcConnect(
+  uri: uri,
+  info: info,
+  privateKey: privateKey,
+  allowInsecure: allowInsecure,
+  onConnection: onConnection,
+);
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<LsSignalingConnection>>
 abstract class LsSignalingConnection implements RustOpaqueInterface {
@@ -28,8 +35,14 @@ abstract class LsSignalingConnection implements RustOpaqueInterface {
     required String privateKey,
     ExpectingPublicKey? expectingPublicKey,
     PinConfig? pin,
+    AutoAcceptConfig? autoAccept,
   });
 
+  /// Emits the peers in the room whenever they change, starting with the current ones.
+  /// Peers with the same token are listed once, e.g. a client that reconnected
+  /// before its previous session was closed by the server.
+  Stream<List<ClientInfo>> listenPeers();
+
   Future<RtcSendController> sendOffer({
     required List<String> stunServers,
     required UuidValue target,
@@ -46,7 +59,24 @@ abstract class LsSignalingConnection implements RustOpaqueInterface {
 abstract class RtcFileReceiver implements RustOpaqueInterface {
   Future<String> getFileId();
 
-  Stream<Uint8List> receive();
+  /// Emits the content of the file.
+  /// Chunks are combined until 1 MB is buffered unless `buffer` is set.
+  Stream<Uint8List> receive({ReceiveBufferConfig? buffer});
+
+  /// Writes the file to `path` without passing its chunks through Dart.
+  /// The data is written to `<path>.part` which is renamed once complete,
+  /// so `path` never contains a partial file.
+  /// If `expected_sha256` is set (see [FileDto::sha256]), a mismatching file is discarded.
+  /// The destination passed to [`RTCReceiveController::send_selection`], if any, is applied to `path`.
+  ///
+  /// If a file with the name exists, `collision` decides where the data goes.
+  /// [CollisionPolicy::Ask] fails before receiving anything, so this can be called again.
+  Future<RTCReceivedFile> receiveToPath({
+    required String path,
+    required bool withHash,
+    String? expectedSha256,
+    required CollisionPolicy collision,
+  });
 }
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RTCFileSender>>
@@ -56,36 +86,134 @@ abstract class RtcFileSender implements RustOpaqueInterface {
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RTCReceiveController>>
 abstract class RtcReceiveController implements RustOpaqueInterface {
+  /// Aborts the transfer and closes the connection, which disconnects the remote peer.
+  /// The status stream ends without [`RTCStatus::Finished`].
+  Future<void> cancel();
+
   Future<void> decline();
 
   Stream<RTCFileError> listenError();
 
   Future<List<FileDto>> listenFiles();
 
+  /// Emits the bytes received of every file that made progress.
+  /// Ends once the transfer has finished.
+  Stream<RTCFileProgress> listenProgress();
+
   Stream<RtcFileReceiver> listenReceiving();
 
+  /// Emits the speed, round-trip time and path of the connection
+  /// every [`localsend::webrtc::webrtc::STATS_INTERVAL`] once connected.
+  /// Ends once the connection is closed.
+  Stream<RTCStats> listenStats();
+
   Stream<RTCStatus> listenStatus();
 
+  /// Stops reading file data from the data channel until [`Self::resume`] is called.
+  /// Emits [`RTCStatus::Paused`] on the status stream.
+  Future<void> pause();
+
+  /// Emits [`RTCStatus::Resumed`] on the status stream.
+  Future<void> resume();
+
   Future<void> sendFileStatus({required RTCSendFileResponse status});
 
   Future<void> sendPin({required String pin});
 
-  Future<void> sendSelection({required Set<String> selection});
+  /// Accepts the files with the IDs in `selection`.
+  ///
+  /// `destinations` maps file IDs to a new name or path chosen by the user,
+  /// which [`RTCFileReceiver::receive_to_path`] applies to the path it is given.
+  /// A name or relative path is resolved against the directory of that path,
+  /// an absolute path replaces it.
+  Future<void> sendSelection({required Set<String> selection, required Map<String, String> destinations});
 }
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<RTCSendController>>
 abstract class RtcSendController implements RustOpaqueInterface {
+  /// Aborts the transfer and closes the connection, which disconnects the remote peer.
+  /// The status stream ends without [`RTCStatus::Finished`].
+  Future<void> cancel();
+
   Stream<RTCFileError> listenError();
 
+  /// Emits the bytes sent of every file that made progress.
+  /// Ends once the transfer has finished.
+  Stream<RTCFileProgress> listenProgress();
+
   Future<Set<String>> listenSelectedFiles();
 
+  /// Emits the speed, round-trip time and path of the connection
+  /// every [`localsend::webrtc::webrtc::STATS_INTERVAL`] once connected.
+  /// Ends once the connection is closed.
+  Stream<RTCStats> listenStats();
+
   Stream<RTCStatus> listenStatus();
 
+  /// Stops handing file data to the data channel until [`Self::resume`] is called.
+  /// Emits [`RTCStatus::Paused`] on the status stream.
+  Future<void> pause();
+
+  /// Emits [`RTCStatus::Resumed`] on the status stream.
+  Future<void> resume();
+
   Future<RtcFileSender> sendFile({required String fileId});
 
+  /// Streams the file at `path` without copying its chunks across the FFI boundary.
+  /// Returns once the whole file has been handed to the data channel.
+  Future<void> sendFileFromPath({required String fileId, required String path});
+
+  /// Sends the files with `file_ids` in order, each read from its path in `paths`,
+  /// so Dart does not have to call [`Self::send_file`] for every file.
+  /// Pass the IDs returned by [`Self::listen_selected_files`].
+  ///
+  /// Emits the result of every file once it has been handed to the data channel.
+  /// Ends after the last file.
+  Stream<RTCSendFileResponse> sendFilesFromPaths({required List<String> fileIds, required Map<String, String> paths});
+
   Future<void> sendPin({required String pin});
 }
 
+enum AnnouncementKind {
+  info,
+  maintenance,
+  deprecation,
+  other,
+}
+
+/// Accepts an offer without asking the user which files to receive.
+/// Used for trusted devices ("quick save").
+class AutoAcceptConfig {
+  /// Directory the files are written to.
+  final String directory;
+
+  /// Lowercase file extensions without dot (e.g. "jpg") of the files to accept.
+  /// All files are accepted if empty.
+  final List<String> extensions;
+
+  /// Applied to files whose name exists in the directory.
+  /// [CollisionPolicy::Ask] fails these files.
+  final CollisionPolicy collision;
+
+  const AutoAcceptConfig({
+    required this.directory,
+    required this.extensions,
+    required this.collision,
+  });
+
+  @override
+  int get hashCode => directory.hashCode ^ extensions.hashCode ^ collision.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AutoAcceptConfig &&
+          runtimeType == other.runtimeType &&
+          directory == other.directory &&
+          extensions == other.extensions &&
+          collision == other.collision;
+}
+
 class ClientInfo {
   final UuidValue id;
   final String alias;
@@ -93,6 +221,7 @@ class ClientInfo {
   final String? deviceModel;
   final DeviceType? deviceType;
   final String token;
+  final int capabilities;
 
   const ClientInfo({
     required this.id,
@@ -101,10 +230,12 @@ class ClientInfo {
     this.deviceModel,
     this.deviceType,
     required this.token,
+    required this.capabilities,
   });
 
   @override
-  int get hashCode => id.hashCode ^ alias.hashCode ^ version.hashCode ^ deviceModel.hashCode ^ deviceType.hashCode ^ token.hashCode;
+  int get hashCode =>
+      id.hashCode ^ alias.hashCode ^ version.hashCode ^ deviceModel.hashCode ^ deviceType.hashCode ^ token.hashCode ^ capabilities.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -116,7 +247,8 @@ class ClientInfo {
           version == other.version &&
           deviceModel == other.deviceModel &&
           deviceType == other.deviceType &&
-          token == other.token;
+          token == other.token &&
+          capabilities == other.capabilities;
 }
 
 class ClientInfoWithoutId {
@@ -125,6 +257,7 @@ class ClientInfoWithoutId {
   final String? deviceModel;
   final DeviceType? deviceType;
   final String token;
+  final int capabilities;
 
   const ClientInfoWithoutId({
     required this.alias,
@@ -132,10 +265,11 @@ class ClientInfoWithoutId {
     this.deviceModel,
     this.deviceType,
     required this.token,
+    required this.capabilities,
   });
 
   @override
-  int get hashCode => alias.hashCode ^ version.hashCode ^ deviceModel.hashCode ^ deviceType.hashCode ^ token.hashCode;
+  int get hashCode => alias.hashCode ^ version.hashCode ^ deviceModel.hashCode ^ deviceType.hashCode ^ token.hashCode ^ capabilities.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -146,7 +280,15 @@ class ClientInfoWithoutId {
           version == other.version &&
           deviceModel == other.deviceModel &&
           deviceType == other.deviceType &&
-          token == other.token;
+          token == other.token &&
+          capabilities == other.capabilities;
+}
+
+enum CollisionPolicy {
+  rename,
+  overwrite,
+  skip,
+  ask,
 }
 
 class ExpectingPublicKey {
@@ -229,6 +371,52 @@ class RTCFileError {
       identical(this, other) || other is RTCFileError && runtimeType == other.runtimeType && fileId == other.fileId && error == other.error;
 }
 
+/// Bytes transferred of one file.
+class RTCFileProgress {
+  final String fileId;
+
+  /// Total bytes sent or received so far.
+  final BigInt bytes;
+
+  const RTCFileProgress({
+    required this.fileId,
+    required this.bytes,
+  });
+
+  @override
+  int get hashCode => fileId.hashCode ^ bytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) || other is RTCFileProgress && runtimeType == other.runtimeType && fileId == other.fileId && bytes == other.bytes;
+}
+
+/// Result of [`RTCFileReceiver::receive_to_path`].
+class RTCReceivedFile {
+  final BigInt size;
+
+  /// Lowercase hex SHA-256 of the content if requested or verified, and written.
+  final String? sha256;
+
+  /// Path the file was written to, which differs from the requested one if it was renamed.
+  /// `None` if it was skipped.
+  final String? path;
+
+  const RTCReceivedFile({
+    required this.size,
+    this.sha256,
+    this.path,
+  });
+
+  @override
+  int get hashCode => size.hashCode ^ sha256.hashCode ^ path.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RTCReceivedFile && runtimeType == other.runtimeType && size == other.size && sha256 == other.sha256 && path == other.path;
+}
+
 class RTCSendFileResponse {
   final String id;
   final bool success;
@@ -249,6 +437,30 @@ class RTCSendFileResponse {
       other is RTCSendFileResponse && runtimeType == other.runtimeType && id == other.id && success == other.success && error == other.error;
 }
 
+class RTCStats {
+  final BigInt bytesPerSecond;
+  final int? roundTripTimeMs;
+  final bool relayed;
+
+  const RTCStats({
+    required this.bytesPerSecond,
+    this.roundTripTimeMs,
+    required this.relayed,
+  });
+
+  @override
+  int get hashCode => bytesPerSecond.hashCode ^ roundTripTimeMs.hashCode ^ relayed.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RTCStats &&
+          runtimeType == other.runtimeType &&
+          bytesPerSecond == other.bytesPerSecond &&
+          roundTripTimeMs == other.roundTripTimeMs &&
+          relayed == other.relayed;
+}
+
 @freezed
 sealed class RTCStatus with _$RTCStatus {
   const RTCStatus._();
@@ -263,6 +475,34 @@ sealed class RTCStatus with _$RTCStatus {
   const factory RTCStatus.error(
     String field0,
   ) = RTCStatus_Error;
+  const factory RTCStatus.timeout() = RTCStatus_Timeout;
+  const factory RTCStatus.paused() = RTCStatus_Paused;
+  const factory RTCStatus.resumed() = RTCStatus_Resumed;
+}
+
+/// How [`RTCFileReceiver::receive`] combines the received chunks before passing them to Dart.
+/// Larger chunks mean fewer calls into Dart on fast links,
+/// a flush interval bounds the latency, e.g. when streaming a preview.
+class ReceiveBufferConfig {
+  /// Chunks are combined until at least this many bytes are buffered.
+  final int targetSize;
+
+  /// Buffered bytes are passed on after this long even if `target_size` is not reached.
+  /// `None` waits for `target_size` or the end of the file.
+  final int? flushIntervalMs;
+
+  const ReceiveBufferConfig({
+    required this.targetSize,
+    this.flushIntervalMs,
+  });
+
+  @override
+  int get hashCode => targetSize.hashCode ^ flushIntervalMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReceiveBufferConfig && runtimeType == other.runtimeType && targetSize == other.targetSize && flushIntervalMs == other.flushIntervalMs;
 }
 
 @freezed
@@ -272,6 +512,8 @@ sealed class WsServerMessage with _$WsServerMessage {
   const factory WsServerMessage.hello({
     required ClientInfo client,
     required List<ClientInfo> peers,
+    String? resumeToken,
+    String? room,
   }) = WsServerMessage_Hello;
   const factory WsServerMessage.join({
     required ClientInfo peer,
@@ -291,6 +533,10 @@ sealed class WsServerMessage with _$WsServerMessage {
   const factory WsServerMessage.error({
     required int code,
   }) = WsServerMessage_Error;
+  const factory WsServerMessage.announcement({
+    required AnnouncementKind kind,
+    required String message,
+  }) = WsServerMessage_Announcement;
 }
 
 class WsServerSdpMessage {
@@ -55,7 +55,7 @@ extension RTCStatusPatterns on RTCStatus {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( RTCStatus_SdpExchanged value)?  sdpExchanged,TResult Function( RTCStatus_Connected value)?  connected,TResult Function( RTCStatus_PinRequired value)?  pinRequired,TResult Function( RTCStatus_TooManyAttempts value)?  tooManyAttempts,TResult Function( RTCStatus_Declined value)?  declined,TResult Function( RTCStatus_Sending value)?  sending,TResult Function( RTCStatus_Finished value)?  finished,TResult Function( RTCStatus_Error value)?  error,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( RTCStatus_SdpExchanged value)?  sdpExchanged,TResult Function( RTCStatus_Connected value)?  connected,TResult Function( RTCStatus_PinRequired value)?  pinRequired,TResult Function( RTCStatus_TooManyAttempts value)?  tooManyAttempts,TResult Function( RTCStatus_Declined value)?  declined,TResult Function( RTCStatus_Sending value)?  sending,TResult Function( RTCStatus_Finished value)?  finished,TResult Function( RTCStatus_Error value)?  error,TResult Function( RTCStatus_Timeout value)?  timeout,TResult Function( RTCStatus_Paused value)?  paused,TResult Function( RTCStatus_Resumed value)?  resumed,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case RTCStatus_SdpExchanged() when sdpExchanged != null:
@@ -66,7 +66,10 @@ return tooManyAttempts(_that);case RTCStatus_Declined() when declined != null:
 return declined(_that);case RTCStatus_Sending() when sending != null:
 return sending(_that);case RTCStatus_Finished() when finished != null:
 return finished(_that);case RTCStatus_Error() when error != null:
-return error(_that);case _:
+return error(_that);case RTCStatus_Timeout() when timeout != null:
+return timeout(_that);case RTCStatus_Paused() when paused != null:
+return paused(_that);case RTCStatus_Resumed() when resumed != null:
+return resumed(_that);case _:
   return orElse();
 
 }
@@ -84,7 +87,7 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( RTCStatus_SdpExchanged value)  sdpExchanged,required TResult Function( RTCStatus_Connected value)  connected,required TResult Function( RTCStatus_PinRequired value)  pinRequired,required TResult Function( RTCStatus_TooManyAttempts value)  tooManyAttempts,required TResult Function( RTCStatus_Declined value)  declined,required TResult Function( RTCStatus_Sending value)  sending,required TResult Function( RTCStatus_Finished value)  finished,required TResult Function( RTCStatus_Error value)  error,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( RTCStatus_SdpExchanged value)  sdpExchanged,required TResult Function( RTCStatus_Connected value)  connected,required TResult Function( RTCStatus_PinRequired value)  pinRequired,required TResult Function( RTCStatus_TooManyAttempts value)  tooManyAttempts,required TResult Function( RTCStatus_Declined value)  declined,required TResult Function( RTCStatus_Sending value)  sending,required TResult Function( RTCStatus_Finished value)  finished,required TResult Function( RTCStatus_Error value)  error,required TResult Function( RTCStatus_Timeout value)  timeout,required TResult Function( RTCStatus_Paused value)  paused,required TResult Function( RTCStatus_Resumed value)  resumed,}){
 final _that = this;
 switch (_that) {
 case RTCStatus_SdpExchanged():
@@ -95,7 +98,10 @@ return tooManyAttempts(_that);case RTCStatus_Declined():
 return declined(_that);case RTCStatus_Sending():
 return sending(_that);case RTCStatus_Finished():
 return finished(_that);case RTCStatus_Error():
-return error(_that);}
+return error(_that);case RTCStatus_Timeout():
+return timeout(_that);case RTCStatus_Paused():
+return paused(_that);case RTCStatus_Resumed():
+return resumed(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -109,7 +115,7 @@ return error(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( RTCStatus_SdpExchanged value)?  sdpExchanged,TResult? Function( RTCStatus_Connected value)?  connected,TResult? Function( RTCStatus_PinRequired value)?  pinRequired,TResult? Function( RTCStatus_TooManyAttempts value)?  tooManyAttempts,TResult? Function( RTCStatus_Declined value)?  declined,TResult? Function( RTCStatus_Sending value)?  sending,TResult? Function( RTCStatus_Finished value)?  finished,TResult? Function( RTCStatus_Error value)?  error,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( RTCStatus_SdpExchanged value)?  sdpExchanged,TResult? Function( RTCStatus_Connected value)?  connected,TResult? Function( RTCStatus_PinRequired value)?  pinRequired,TResult? Function( RTCStatus_TooManyAttempts value)?  tooManyAttempts,TResult? Function( RTCStatus_Declined value)?  declined,TResult? Function( RTCStatus_Sending value)?  sending,TResult? Function( RTCStatus_Finished value)?  finished,TResult? Function( RTCStatus_Error value)?  error,TResult? Function( RTCStatus_Timeout value)?  timeout,TResult? Function( RTCStatus_Paused value)?  paused,TResult? Function( RTCStatus_Resumed value)?  resumed,}){
 final _that = this;
 switch (_that) {
 case RTCStatus_SdpExchanged() when sdpExchanged != null:
@@ -120,7 +126,10 @@ return tooManyAttempts(_that);case RTCStatus_Declined() when declined != null:
 return declined(_that);case RTCStatus_Sending() when sending != null:
 return sending(_that);case RTCStatus_Finished() when finished != null:
 return finished(_that);case RTCStatus_Error() when error != null:
-return error(_that);case _:
+return error(_that);case RTCStatus_Timeout() when timeout != null:
+return timeout(_that);case RTCStatus_Paused() when paused != null:
+return paused(_that);case RTCStatus_Resumed() when resumed != null:
+return resumed(_that);case _:
   return null;
 
 }
@@ -137,7 +146,7 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  sdpExchanged,TResult Function()?  connected,TResult Function()?  pinRequired,TResult Function()?  tooManyAttempts,TResult Function()?  declined,TResult Function()?  sending,TResult Function()?  finished,TResult Function( String field0)?  error,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  sdpExchanged,TResult Function()?  connected,TResult Function()?  pinRequired,TResult Function()?  tooManyAttempts,TResult Function()?  declined,TResult Function()?  sending,TResult Function()?  finished,TResult Function( String field0)?  error,TResult Function()?  timeout,TResult Function()?  paused,TResult Function()?  resumed,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case RTCStatus_SdpExchanged() when sdpExchanged != null:
 return sdpExchanged();case RTCStatus_Connected() when connected != null:
@@ -147,7 +156,10 @@ return tooManyAttempts();case RTCStatus_Declined() when declined != null:
 return declined();case RTCStatus_Sending() when sending != null:
 return sending();case RTCStatus_Finished() when finished != null:
 return finished();case RTCStatus_Error() when error != null:
-return error(_that.field0);case _:
+return error(_that.field0);case RTCStatus_Timeout() when timeout != null:
+return timeout();case RTCStatus_Paused() when paused != null:
+return paused();case RTCStatus_Resumed() when resumed != null:
+return resumed();case _:
   return orElse();
 
 }
@@ -165,7 +177,7 @@ return error(_that.field0);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  sdpExchanged,required TResult Function()  connected,required TResult Function()  pinRequired,required TResult Function()  tooManyAttempts,required TResult Function()  declined,required TResult Function()  sending,required TResult Function()  finished,required TResult Function( String field0)  error,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  sdpExchanged,required TResult Function()  connected,required TResult Function()  pinRequired,required TResult Function()  tooManyAttempts,required TResult Function()  declined,required TResult Function()  sending,required TResult Function()  finished,required TResult Function( String field0)  error,required TResult Function()  timeout,required TResult Function()  paused,required TResult Function()  resumed,}) {final _that = this;
 switch (_that) {
 case RTCStatus_SdpExchanged():
 return sdpExchanged();case RTCStatus_Connected():
@@ -175,7 +187,10 @@ return tooManyAttempts();case RTCStatus_Declined():
 return declined();case RTCStatus_Sending():
 return sending();case RTCStatus_Finished():
 return finished();case RTCStatus_Error():
-return error(_that.field0);}
+return error(_that.field0);case RTCStatus_Timeout():
+return timeout();case RTCStatus_Paused():
+return paused();case RTCStatus_Resumed():
+return resumed();}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -189,7 +204,7 @@ return error(_that.field0);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  sdpExchanged,TResult? Function()?  connected,TResult? Function()?  pinRequired,TResult? Function()?  tooManyAttempts,TResult? Function()?  declined,TResult? Function()?  sending,TResult? Function()?  finished,TResult? Function( String field0)?  error,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  sdpExchanged,TResult? Function()?  connected,TResult? Function()?  pinRequired,TResult? Function()?  tooManyAttempts,TResult? Function()?  declined,TResult? Function()?  sending,TResult? Function()?  finished,TResult? Function( String field0)?  error,TResult? Function()?  timeout,TResult? Function()?  paused,TResult? Function()?  resumed,}) {final _that = this;
 switch (_that) {
 case RTCStatus_SdpExchanged() when sdpExchanged != null:
 return sdpExchanged();case RTCStatus_Connected() when connected != null:
@@ -199,7 +214,10 @@ return tooManyAttempts();case RTCStatus_Declined() when declined != null:
 return declined();case RTCStatus_Sending() when sending != null:
 return sending();case RTCStatus_Finished() when finished != null:
 return finished();case RTCStatus_Error() when error != null:
-return error(_that.field0);case _:
+return error(_that.field0);case RTCStatus_Timeout() when timeout != null:
+return timeout();case RTCStatus_Paused() when paused != null:
+return paused();case RTCStatus_Resumed() when resumed != null:
+return resumed();case _:
   return null;
 
 }
@@ -495,6 +513,93 @@ as String,
 }
 
 
+}
+
+/// @nodoc
+
+
+class RTCStatus_Timeout extends RTCStatus {
+  const RTCStatus_Timeout(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RTCStatus_Timeout);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RTCStatus.timeout()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RTCStatus_Paused extends RTCStatus {
+  const RTCStatus_Paused(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RTCStatus_Paused);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RTCStatus.paused()';
+}
+
+
+}
+
+/// @nodoc
+
+
+class RTCStatus_Resumed extends RTCStatus {
+  const RTCStatus_Resumed(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is RTCStatus_Resumed);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'RTCStatus.resumed()';
+}
+
+
 }
 
 /// @nodoc
@@ -541,7 +646,7 @@ extension WsServerMessagePatterns on WsServerMessage {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( WsServerMessage_Hello value)?  hello,TResult Function( WsServerMessage_Join value)?  join,TResult Function( WsServerMessage_Update value)?  update,TResult Function( WsServerMessage_Left value)?  left,TResult Function( WsServerMessage_Offer value)?  offer,TResult Function( WsServerMessage_Answer value)?  answer,TResult Function( WsServerMessage_Error value)?  error,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( WsServerMessage_Hello value)?  hello,TResult Function( WsServerMessage_Join value)?  join,TResult Function( WsServerMessage_Update value)?  update,TResult Function( WsServerMessage_Left value)?  left,TResult Function( WsServerMessage_Offer value)?  offer,TResult Function( WsServerMessage_Answer value)?  answer,TResult Function( WsServerMessage_Error value)?  error,TResult Function( WsServerMessage_Announcement value)?  announcement,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case WsServerMessage_Hello() when hello != null:
@@ -551,7 +656,8 @@ return update(_that);case WsServerMessage_Left() when left != null:
 return left(_that);case WsServerMessage_Offer() when offer != null:
 return offer(_that);case WsServerMessage_Answer() when answer != null:
 return answer(_that);case WsServerMessage_Error() when error != null:
-return error(_that);case _:
+return error(_that);case WsServerMessage_Announcement() when announcement != null:
+return announcement(_that);case _:
   return orElse();
 
 }
@@ -569,7 +675,7 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( WsServerMessage_Hello value)  hello,required TResult Function( WsServerMessage_Join value)  join,required TResult Function( WsServerMessage_Update value)  update,required TResult Function( WsServerMessage_Left value)  left,required TResult Function( WsServerMessage_Offer value)  offer,required TResult Function( WsServerMessage_Answer value)  answer,required TResult Function( WsServerMessage_Error value)  error,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( WsServerMessage_Hello value)  hello,required TResult Function( WsServerMessage_Join value)  join,required TResult Function( WsServerMessage_Update value)  update,required TResult Function( WsServerMessage_Left value)  left,required TResult Function( WsServerMessage_Offer value)  offer,required TResult Function( WsServerMessage_Answer value)  answer,required TResult Function( WsServerMessage_Error value)  error,required TResult Function( WsServerMessage_Announcement value)  announcement,}){
 final _that = this;
 switch (_that) {
 case WsServerMessage_Hello():
@@ -579,7 +685,8 @@ return update(_that);case WsServerMessage_Left():
 return left(_that);case WsServerMessage_Offer():
 return offer(_that);case WsServerMessage_Answer():
 return answer(_that);case WsServerMessage_Error():
-return error(_that);}
+return error(_that);case WsServerMessage_Announcement():
+return announcement(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -593,7 +700,7 @@ return error(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( WsServerMessage_Hello value)?  hello,TResult? Function( WsServerMessage_Join value)?  join,TResult? Function( WsServerMessage_Update value)?  update,TResult? Function( WsServerMessage_Left value)?  left,TResult? Function( WsServerMessage_Offer value)?  offer,TResult? Function( WsServerMessage_Answer value)?  answer,TResult? Function( WsServerMessage_Error value)?  error,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( WsServerMessage_Hello value)?  hello,TResult? Function( WsServerMessage_Join value)?  join,TResult? Function( WsServerMessage_Update value)?  update,TResult? Function( WsServerMessage_Left value)?  left,TResult? Function( WsServerMessage_Offer value)?  offer,TResult? Function( WsServerMessage_Answer value)?  answer,TResult? Function( WsServerMessage_Error value)?  error,TResult? Function( WsServerMessage_Announcement value)?  announcement,}){
 final _that = this;
 switch (_that) {
 case WsServerMessage_Hello() when hello != null:
@@ -603,7 +710,8 @@ return update(_that);case WsServerMessage_Left() when left != null:
 return left(_that);case WsServerMessage_Offer() when offer != null:
 return offer(_that);case WsServerMessage_Answer() when answer != null:
 return answer(_that);case WsServerMessage_Error() when error != null:
-return error(_that);case _:
+return error(_that);case WsServerMessage_Announcement() when announcement != null:
+return announcement(_that);case _:
   return null;
 
 }
@@ -620,16 +728,17 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( ClientInfo client,  List<ClientInfo> peers)?  hello,TResult Function( ClientInfo peer)?  join,TResult Function( ClientInfo peer)?  update,TResult Function( UuidValue peerId)?  left,TResult Function( WsServerSdpMessage field0)?  offer,TResult Function( WsServerSdpMessage field0)?  answer,TResult Function( int code)?  error,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( ClientInfo client,  List<ClientInfo> peers,  String? resumeToken,  String? room)?  hello,TResult Function( ClientInfo peer)?  join,TResult Function( ClientInfo peer)?  update,TResult Function( UuidValue peerId)?  left,TResult Function( WsServerSdpMessage field0)?  offer,TResult Function( WsServerSdpMessage field0)?  answer,TResult Function( int code)?  error,TResult Function( AnnouncementKind kind,  String message)?  announcement,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case WsServerMessage_Hello() when hello != null:
-return hello(_that.client,_that.peers);case WsServerMessage_Join() when join != null:
+return hello(_that.client,_that.peers,_that.resumeToken,_that.room);case WsServerMessage_Join() when join != null:
 return join(_that.peer);case WsServerMessage_Update() when update != null:
 return update(_that.peer);case WsServerMessage_Left() when left != null:
 return left(_that.peerId);case WsServerMessage_Offer() when offer != null:
 return offer(_that.field0);case WsServerMessage_Answer() when answer != null:
 return answer(_that.field0);case WsServerMessage_Error() when error != null:
-return error(_that.code);case _:
+return error(_that.code);case WsServerMessage_Announcement() when announcement != null:
+return announcement(_that.kind,_that.message);case _:
   return orElse();
 
 }
@@ -647,16 +756,17 @@ return error(_that.code);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( ClientInfo client,  List<ClientInfo> peers)  hello,required TResult Function( ClientInfo peer)  join,required TResult Function( ClientInfo peer)  update,required TResult Function( UuidValue peerId)  left,required TResult Function( WsServerSdpMessage field0)  offer,required TResult Function( WsServerSdpMessage field0)  answer,required TResult Function( int code)  error,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( ClientInfo client,  List<ClientInfo> peers,  String? resumeToken,  String? room)  hello,required TResult Function( ClientInfo peer)  join,required TResult Function( ClientInfo peer)  update,required TResult Function( UuidValue peerId)  left,required TResult Function( WsServerSdpMessage field0)  offer,required TResult Function( WsServerSdpMessage field0)  answer,required TResult Function( int code)  error,required TResult Function( AnnouncementKind kind,  String message)  announcement,}) {final _that = this;
 switch (_that) {
 case WsServerMessage_Hello():
-return hello(_that.client,_that.peers);case WsServerMessage_Join():
+return hello(_that.client,_that.peers,_that.resumeToken,_that.room);case WsServerMessage_Join():
 return join(_that.peer);case WsServerMessage_Update():
 return update(_that.peer);case WsServerMessage_Left():
 return left(_that.peerId);case WsServerMessage_Offer():
 return offer(_that.field0);case WsServerMessage_Answer():
 return answer(_that.field0);case WsServerMessage_Error():
-return error(_that.code);}
+return error(_that.code);case WsServerMessage_Announcement():
+return announcement(_that.kind,_that.message);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -670,16 +780,17 @@ return error(_that.code);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( ClientInfo client,  List<ClientInfo> peers)?  hello,TResult? Function( ClientInfo peer)?  join,TResult? Function( ClientInfo peer)?  update,TResult? Function( UuidValue peerId)?  left,TResult? Function( WsServerSdpMessage field0)?  offer,TResult? Function( WsServerSdpMessage field0)?  answer,TResult? Function( int code)?  error,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( ClientInfo client,  List<ClientInfo> peers,  String? resumeToken,  String? room)?  hello,TResult? Function( ClientInfo peer)?  join,TResult? Function( ClientInfo peer)?  update,TResult? Function( UuidValue peerId)?  left,TResult? Function( WsServerSdpMessage field0)?  offer,TResult? Function( WsServerSdpMessage field0)?  answer,TResult? Function( int code)?  error,TResult? Function( AnnouncementKind kind,  String message)?  announcement,}) {final _that = this;
 switch (_that) {
 case WsServerMessage_Hello() when hello != null:
-return hello(_that.client,_that.peers);case WsServerMessage_Join() when join != null:
+return hello(_that.client,_that.peers,_that.resumeToken,_that.room);case WsServerMessage_Join() when join != null:
 return join(_that.peer);case WsServerMessage_Update() when update != null:
 return update(_that.peer);case WsServerMessage_Left() when left != null:
 return left(_that.peerId);case WsServerMessage_Offer() when offer != null:
 return offer(_that.field0);case WsServerMessage_Answer() when answer != null:
 return answer(_that.field0);case WsServerMessage_Error() when error != null:
-return error(_that.code);case _:
+return error(_that.code);case WsServerMessage_Announcement() when announcement != null:
+return announcement(_that.kind,_that.message);case _:
   return null;
 
 }
@@ -691,7 +802,7 @@ return error(_that.code);case _:
 
 
 class WsServerMessage_Hello extends WsServerMessage {
-  const WsServerMessage_Hello({required this.client, required final  List<ClientInfo> peers}): _peers = peers,super._();
+  const WsServerMessage_Hello({required this.client, required final  List<ClientInfo> peers, this.resumeToken, this.room}): _peers = peers,super._();
   
 
  final  ClientInfo client;
@@ -702,6 +813,9 @@ class WsServerMessage_Hello extends WsServerMessage {
   return EqualUnmodifiableListView(_peers);
 }
 
+ final  String? resumeToken;
+ final  String? room;
+
 
 /// Create a copy of WsServerMessage
 /// with the given fields replaced by the non-null parameter values.
@@ -713,16 +827,16 @@ $WsServerMessage_HelloCopyWith<WsServerMessage_Hello> get copyWith => _$WsServer
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is WsServerMessage_Hello&&(identical(other.client, client) || other.client == client)&&const DeepCollectionEquality().equals(other._peers, _peers));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WsServerMessage_Hello&&(identical(other.client, client) || other.client == client)&&const DeepCollectionEquality().equals(other._peers, _peers)&&(identical(other.resumeToken, resumeToken) || other.resumeToken == resumeToken)&&(identical(other.room, room) || other.room == room));
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,client,const DeepCollectionEquality().hash(_peers));
+int get hashCode => Object.hash(runtimeType,client,const DeepCollectionEquality().hash(_peers),resumeToken,room);
 
 @override
 String toString() {
-  return 'WsServerMessage.hello(client: $client, peers: $peers)';
+  return 'WsServerMessage.hello(client: $client, peers: $peers, resumeToken: $resumeToken, room: $room)';
 }
 
 
@@ -733,7 +847,7 @@ abstract mixin class $WsServerMessage_HelloCopyWith<$Res> implements $WsServerMe
   factory $WsServerMessage_HelloCopyWith(WsServerMessage_Hello value, $Res Function(WsServerMessage_Hello) _then) = _$WsServerMessage_HelloCopyWithImpl;
 @useResult
 $Res call({
- ClientInfo client, List<ClientInfo> peers
+ ClientInfo client, List<ClientInfo> peers, String? resumeToken, String? room
 });
 
 
@@ -750,11 +864,13 @@ class _$WsServerMessage_HelloCopyWithImpl<$Res>
 
 /// Create a copy of WsServerMessage
 /// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') $Res call({Object? client = null,Object? peers = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? client = null,Object? peers = null,Object? resumeToken = freezed,Object? room = freezed,}) {
   return _then(WsServerMessage_Hello(
 client: null == client ? _self.client : client // ignore: cast_nullable_to_non_nullable
 as ClientInfo,peers: null == peers ? _self._peers : peers // ignore: cast_nullable_to_non_nullable
-as List<ClientInfo>,
+as List<ClientInfo>,resumeToken: freezed == resumeToken ? _self.resumeToken : resumeToken // ignore: cast_nullable_to_non_nullable
+as String?,room: freezed == room ? _self.room : room // ignore: cast_nullable_to_non_nullable
+as String?,
   ));
 }
 
@@ -1155,6 +1271,74 @@ as int,
 }
 
 
+}
+
+/// @nodoc
+
+
+class WsServerMessage_Announcement extends WsServerMessage {
+  const WsServerMessage_Announcement({required this.kind, required this.message}): super._();
+  
+
+ final  AnnouncementKind kind;
+ final  String message;
+
+/// Create a copy of WsServerMessage
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$WsServerMessage_AnnouncementCopyWith<WsServerMessage_Announcement> get copyWith => _$WsServerMessage_AnnouncementCopyWithImpl<WsServerMessage_Announcement>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is WsServerMessage_Announcement&&(identical(other.kind, kind) || other.kind == kind)&&(identical(other.message, message) || other.message == message));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,kind,message);
+
+@override
+String toString() {
+  return 'WsServerMessage.announcement(kind: $kind, message: $message)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $WsServerMessage_AnnouncementCopyWith<$Res> implements $WsServerMessageCopyWith<$Res> {
+  factory $WsServerMessage_AnnouncementCopyWith(WsServerMessage_Announcement value, $Res Function(WsServerMessage_Announcement) _then) = _$WsServerMessage_AnnouncementCopyWithImpl;
+@useResult
+$Res call({
+ AnnouncementKind kind, String message
+});
+
+
+
+
+}
+/// @nodoc
+class _$WsServerMessage_AnnouncementCopyWithImpl<$Res>
+    implements $WsServerMessage_AnnouncementCopyWith<$Res> {
+  _$WsServerMessage_AnnouncementCopyWithImpl(this._self, this._then);
+
+  final WsServerMessage_Announcement _self;
+  final $Res Function(WsServerMessage_Announcement) _then;
+
+/// Create a copy of WsServerMessage
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? kind = null,Object? message = null,}) {
+  return _then(WsServerMessage_Announcement(
+kind: null == kind ? _self.kind : kind // ignore: cast_nullable_to_non_nullable
+as AnnouncementKind,message: null == message ? _self.message : message // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
 }
 
 // dart format on
@@ -8,11 +8,18 @@ import 'dart:convert';
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:localsend_isolates/rust/api/crypto.dart';
+import 'package:localsend_isolates/rust/api/favorites.dart';
 import 'package:localsend_isolates/rust/api/http.dart';
 import 'package:localsend_isolates/rust/api/logging.dart';
 import 'package:localsend_isolates/rust/api/model.dart';
+import 'package:localsend_isolates/rust/api/preview.dart';
+import 'package:localsend_isolates/rust/api/registry.dart';
+import 'package:localsend_isolates/rust/api/resume.dart';
 import 'package:localsend_isolates/rust/api/server.dart';
+import 'package:localsend_isolates/rust/api/session.dart';
+import 'package:localsend_isolates/rust/api/settings.dart';
 import 'package:localsend_isolates/rust/api/stream.dart';
+import 'package:localsend_isolates/rust/api/trust.dart';
 import 'package:localsend_isolates/rust/api/webrtc.dart';
 import 'package:localsend_isolates/rust/frb_generated.dart';
 import 'package:localsend_isolates/rust/frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
@@ -94,8 +101,11 @@ abstract class RustLibApi extends BaseApi {
     required String privateKey,
     ExpectingPublicKey? expectingPublicKey,
     PinConfig? pin,
+    AutoAcceptConfig? autoAccept,
   });
 
+  Stream<List<ClientInfo>> crateApiWebrtcLsSignalingConnectionListenPeers({required LsSignalingConnection that});
+
   Future<RtcSendController> crateApiWebrtcLsSignalingConnectionSendOffer({
     required LsSignalingConnection that,
     required List<String> stunServers,
@@ -110,6 +120,18 @@ abstract class RustLibApi extends BaseApi {
 
   void crateApiHttpRsCancellationTokenCancel({required RsCancellationToken that});
 
+  Future<FavoriteDevice> crateApiFavoritesRsFavoriteStoreAddPeer({required RsFavoriteStore that, required ClientInfo peer, String? notes});
+
+  FavoriteDevice? crateApiFavoritesRsFavoriteStoreGet({required RsFavoriteStore that, required String fingerprint});
+
+  List<FavoriteDevice> crateApiFavoritesRsFavoriteStoreList({required RsFavoriteStore that});
+
+  Future<FavoriteDevice?> crateApiFavoritesRsFavoriteStoreMatchPeer({required RsFavoriteStore that, required ClientInfo peer});
+
+  Future<void> crateApiFavoritesRsFavoriteStoreRemove({required RsFavoriteStore that, required String fingerprint});
+
+  Future<void> crateApiFavoritesRsFavoriteStoreSetNotes({required RsFavoriteStore that, required String fingerprint, required String notes});
+
   Future<void> crateApiHttpRsHttpClientCancel({
     required RsHttpClient that,
     required ProtocolType protocol,
@@ -179,46 +201,159 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiServerRsHttpServerRespondPrepareDownload({required RsHttpServer that, required String sessionId, required bool accept});
 
-  Future<void> crateApiServerRsHttpServerRespondPrepareUpload({required RsHttpServer that, List<String>? acceptedFileIds});
+  Future<void> crateApiServerRsHttpServerRespondPrepareUpload({
+    required RsHttpServer that,
+    List<String>? acceptedFileIds,
+    required Map<String, String> destinations,
+  });
 
   Future<void> crateApiServerRsHttpServerStop({required RsHttpServer that});
 
+  ResumeSession? crateApiResumeRsResumeStoreGet({required RsResumeStore that, required String sessionId});
+
+  List<ResumeSession> crateApiResumeRsResumeStoreList({required RsResumeStore that});
+
+  List<ResumeSession> crateApiResumeRsResumeStoreListForPeer({required RsResumeStore that, required String fingerprint});
+
+  Future<void> crateApiResumeRsResumeStoreRemove({required RsResumeStore that, required String sessionId});
+
+  Future<ResumeSession> crateApiResumeRsResumeStoreSave({required RsResumeStore that, required ResumeSession session});
+
+  Future<void> crateApiResumeRsResumeStoreSetProgress({
+    required RsResumeStore that,
+    required String sessionId,
+    required String fileId,
+    required BigInt bytesCompleted,
+    String? partialSha256,
+  });
+
+  String? crateApiSettingsRsSettingsStoreAlias({required RsSettingsStore that});
+
+  int? crateApiSettingsRsSettingsStoreBandwidthLimit({required RsSettingsStore that});
+
+  String? crateApiSettingsRsSettingsStoreDownloadDir({required RsSettingsStore that});
+
+  String? crateApiSettingsRsSettingsStorePin({required RsSettingsStore that});
+
+  Future<void> crateApiSettingsRsSettingsStoreSetAlias({required RsSettingsStore that, String? alias});
+
+  Future<void> crateApiSettingsRsSettingsStoreSetBandwidthLimit({required RsSettingsStore that, int? bandwidthLimit});
+
+  Future<void> crateApiSettingsRsSettingsStoreSetDownloadDir({required RsSettingsStore that, String? downloadDir});
+
+  Future<void> crateApiSettingsRsSettingsStoreSetPin({required RsSettingsStore that, String? pin});
+
+  TrustStatus crateApiTrustRsTrustStoreCheck({required RsTrustStore that, required String alias, required String fingerprint});
+
+  TrustStatus crateApiTrustRsTrustStoreCheckOffer({required RsTrustStore that, required RegisterDtoV2 info, String? certFingerprint});
+
+  String? crateApiTrustRsTrustStoreFingerprint({required RsTrustStore that, required String alias});
+
+  Future<void> crateApiTrustRsTrustStoreForget({required RsTrustStore that, required String alias});
+
+  Future<void> crateApiTrustRsTrustStoreTrust({required RsTrustStore that, required String alias, required String fingerprint});
+
   Future<String> crateApiWebrtcRtcFileReceiverGetFileId({required RtcFileReceiver that});
 
-  Stream<Uint8List> crateApiWebrtcRtcFileReceiverReceive({required RtcFileReceiver that});
+  Stream<Uint8List> crateApiWebrtcRtcFileReceiverReceive({required RtcFileReceiver that, ReceiveBufferConfig? buffer});
+
+  Future<RTCReceivedFile> crateApiWebrtcRtcFileReceiverReceiveToPath({
+    required RtcFileReceiver that,
+    required String path,
+    required bool withHash,
+    String? expectedSha256,
+    required CollisionPolicy collision,
+  });
 
   Future<void> crateApiWebrtcRtcFileSenderSend({required RtcFileSender that, required List<int> data});
 
+  Future<void> crateApiWebrtcRtcReceiveControllerCancel({required RtcReceiveController that});
+
   Future<void> crateApiWebrtcRtcReceiveControllerDecline({required RtcReceiveController that});
 
   Stream<RTCFileError> crateApiWebrtcRtcReceiveControllerListenError({required RtcReceiveController that});
 
   Future<List<FileDto>> crateApiWebrtcRtcReceiveControllerListenFiles({required RtcReceiveController that});
 
+  Stream<RTCFileProgress> crateApiWebrtcRtcReceiveControllerListenProgress({required RtcReceiveController that});
+
   Stream<RtcFileReceiver> crateApiWebrtcRtcReceiveControllerListenReceiving({required RtcReceiveController that});
 
+  Stream<RTCStats> crateApiWebrtcRtcReceiveControllerListenStats({required RtcReceiveController that});
+
   Stream<RTCStatus> crateApiWebrtcRtcReceiveControllerListenStatus({required RtcReceiveController that});
 
+  Future<void> crateApiWebrtcRtcReceiveControllerPause({required RtcReceiveController that});
+
+  Future<void> crateApiWebrtcRtcReceiveControllerResume({required RtcReceiveController that});
+
   Future<void> crateApiWebrtcRtcReceiveControllerSendFileStatus({required RtcReceiveController that, required RTCSendFileResponse status});
 
   Future<void> crateApiWebrtcRtcReceiveControllerSendPin({required RtcReceiveController that, required String pin});
 
-  Future<void> crateApiWebrtcRtcReceiveControllerSendSelection({required RtcReceiveController that, required Set<String> selection});
+  Future<void> crateApiWebrtcRtcReceiveControllerSendSelection({
+    required RtcReceiveController that,
+    required Set<String> selection,
+    required Map<String, String> destinations,
+  });
+
+  Future<void> crateApiWebrtcRtcSendControllerCancel({required RtcSendController that});
 
   Stream<RTCFileError> crateApiWebrtcRtcSendControllerListenError({required RtcSendController that});
 
+  Stream<RTCFileProgress> crateApiWebrtcRtcSendControllerListenProgress({required RtcSendController that});
+
   Future<Set<String>> crateApiWebrtcRtcSendControllerListenSelectedFiles({required RtcSendController that});
 
+  Stream<RTCStats> crateApiWebrtcRtcSendControllerListenStats({required RtcSendController that});
+
   Stream<RTCStatus> crateApiWebrtcRtcSendControllerListenStatus({required RtcSendController that});
 
+  Future<void> crateApiWebrtcRtcSendControllerPause({required RtcSendController that});
+
+  Future<void> crateApiWebrtcRtcSendControllerResume({required RtcSendController that});
+
   Future<RtcFileSender> crateApiWebrtcRtcSendControllerSendFile({required RtcSendController that, required String fileId});
 
+  Future<void> crateApiWebrtcRtcSendControllerSendFileFromPath({required RtcSendController that, required String fileId, required String path});
+
+  Stream<RTCSendFileResponse> crateApiWebrtcRtcSendControllerSendFilesFromPaths({
+    required RtcSendController that,
+    required List<String> fileIds,
+    required Map<String, String> paths,
+  });
+
   Future<void> crateApiWebrtcRtcSendControllerSendPin({required RtcSendController that, required String pin});
 
+  Future<int> crateApiSessionSessionManagerActiveCount({required SessionManager that});
+
+  Future<String> crateApiSessionSessionManagerAddReceive({required SessionManager that, required RtcReceiveController controller});
+
+  Future<String> crateApiSessionSessionManagerAddSend({required SessionManager that, required RtcSendController controller});
+
+  Future<void> crateApiSessionSessionManagerCancel({required SessionManager that, required String sessionId});
+
+  Future<void> crateApiSessionSessionManagerCancelAll({required SessionManager that});
+
+  Future<void> crateApiSessionSessionManagerClearEndCallback({required SessionManager that});
+
+  Future<List<SessionInfo>> crateApiSessionSessionManagerListSessions({required SessionManager that});
+
+  Future<void> crateApiSessionSessionManagerRemove({required SessionManager that, required String sessionId});
+
+  Future<void> crateApiSessionSessionManagerSetEndCallback({required SessionManager that, required FutureOr<void> Function(SessionInfo) callback});
+
+  Future<String> crateApiCryptoComputeSha256({
+    required String path,
+    required RsCancellationToken cancelToken,
+    required FutureOr<void> Function(double) onProgress,
+  });
+
   Stream<WsServerMessage> crateApiWebrtcConnect({
     required String uri,
     required ProposingClientInfo info,
     required String privateKey,
+    required bool allowInsecure,
     required FutureOr<void> Function(LsSignalingConnection) onConnection,
   });
 
@@ -226,12 +361,57 @@ abstract class RustLibApi extends BaseApi {
 
   RsHttpClient crateApiHttpCreateClient({required String privateKey, required String cert, required LsHttpClientVersion version, int? timeoutMs});
 
+  Future<FileDto> crateApiModelCreateFileDto({required String path, required bool withHash, required bool withPreview});
+
+  SessionManager crateApiSessionCreateSessionManager();
+
   Future<(Dart2RustStreamSink, Dart2RustStreamReceiver)> crateApiStreamCreateStream();
 
   Future<void> crateApiLoggingEnableDebugLogging();
 
+  Future<List<FileDto>> crateApiPreviewFillPreviews({required List<FileDto> files, required Map<String, String> paths});
+
   Future<KeyPair> crateApiCryptoGenerateKeyPair();
 
+  Future<String?> crateApiPreviewExtractEmbeddedPreview({required String path, required String fileType});
+
+  RtcReceiveController crateApiRegistryGetReceiveController({required String id});
+
+  RtcSendController crateApiRegistryGetSendController({required String id});
+
+  LsSignalingConnection crateApiRegistryGetSignalingConnection({required String id});
+
+  Future<InferredMimeType> crateApiModelInferMimeType({required String path});
+
+  Stream<LogEntry> crateApiLoggingListenLogs({required LogLevel minLevel, required List<String> targets});
+
+  Future<RsFavoriteStore> crateApiFavoritesOpenFavoriteStore({required String path});
+
+  Future<RsResumeStore> crateApiResumeOpenResumeStore({required String path});
+
+  Future<RsSettingsStore> crateApiSettingsOpenSettings({required String path});
+
+  Future<RsTrustStore> crateApiTrustOpenTrustStore({required String path});
+
+  Future<String> crateApiModelPartialSha256({required String path, required BigInt length});
+
+  String crateApiRegistryRegisterReceiveController({required RtcReceiveController controller});
+
+  String crateApiRegistryRegisterSendController({required RtcSendController controller});
+
+  String crateApiRegistryRegisterSignalingConnection({required LsSignalingConnection connection});
+
+  Future<List<String>> crateApiModelRemovePartFiles({required String directory});
+
+  String crateApiModelSanitizeFileName({required String fileName});
+
+  Future<List<ScannedFile>> crateApiModelScanDirectory({
+    required String path,
+    required List<String> include,
+    required List<String> exclude,
+    required SymlinkPolicy symlinks,
+  });
+
   Future<RsHttpServer> crateApiServerStartServer({
     required int port,
     TlsConfig? tls,
@@ -245,6 +425,8 @@ abstract class RustLibApi extends BaseApi {
     String? showToken,
   });
 
+  void crateApiRegistryUnregisterHandle({required String id});
+
   Future<void> crateApiCryptoVerifyCert({required String cert, required String publicKey});
 
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_Dart2RustStreamReceiver;
@@ -295,6 +477,12 @@ abstract class RustLibApi extends BaseApi {
 
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsCancellationTokenPtr;
 
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsFavoriteStore;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsFavoriteStore;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsFavoriteStorePtr;
+
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsHttpClient;
 
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsHttpClient;
@@ -306,6 +494,30 @@ abstract class RustLibApi extends BaseApi {
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsHttpServer;
 
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsHttpServerPtr;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsResumeStore;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsResumeStore;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsResumeStorePtr;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsSettingsStore;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsSettingsStore;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsSettingsStorePtr;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsTrustStore;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsTrustStore;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsTrustStorePtr;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SessionManager;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SessionManager;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionManagerPtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -375,6 +587,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     required String privateKey,
     ExpectingPublicKey? expectingPublicKey,
     PinConfig? pin,
+    AutoAcceptConfig? autoAccept,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -386,6 +599,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(privateKey, serializer);
           sse_encode_opt_box_autoadd_expecting_public_key(expectingPublicKey, serializer);
           sse_encode_opt_box_autoadd_pin_config(pin, serializer);
+          sse_encode_opt_box_autoadd_auto_accept_config(autoAccept, serializer);
           pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
         },
         codec: SseCodec(
@@ -393,7 +607,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_AnyhowException,
         ),
         constMeta: kCrateApiWebrtcLsSignalingConnectionAcceptOfferConstMeta,
-        argValues: [that, stunServers, offer, privateKey, expectingPublicKey, pin],
+        argValues: [that, stunServers, offer, privateKey, expectingPublicKey, pin, autoAccept],
         apiImpl: this,
       ),
     );
@@ -401,7 +615,37 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiWebrtcLsSignalingConnectionAcceptOfferConstMeta => const TaskConstMeta(
     debugName: 'LsSignalingConnection_accept_offer',
-    argNames: ['that', 'stunServers', 'offer', 'privateKey', 'expectingPublicKey', 'pin'],
+    argNames: ['that', 'stunServers', 'offer', 'privateKey', 'expectingPublicKey', 'pin', 'autoAccept'],
+  );
+
+  @override
+  Stream<List<ClientInfo>> crateApiWebrtcLsSignalingConnectionListenPeers({required LsSignalingConnection that}) {
+    final sink = RustStreamSink<List<ClientInfo>>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection(that, serializer);
+            sse_encode_StreamSink_list_client_info_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcLsSignalingConnectionListenPeersConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcLsSignalingConnectionListenPeersConstMeta => const TaskConstMeta(
+    debugName: 'LsSignalingConnection_listen_peers',
+    argNames: ['that', 'sink'],
   );
 
   @override
@@ -425,7 +669,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_opt_box_autoadd_expecting_public_key(expectingPublicKey, serializer);
           sse_encode_opt_box_autoadd_pin_config(pin, serializer);
           sse_encode_list_file_dto(files, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController,
@@ -451,7 +695,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection(that, serializer);
           sse_encode_box_autoadd_client_info_without_id(info, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -476,7 +720,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(that, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -494,6 +738,163 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['that'],
   );
 
+  @override
+  Future<FavoriteDevice> crateApiFavoritesRsFavoriteStoreAddPeer({required RsFavoriteStore that, required ClientInfo peer, String? notes}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(that, serializer);
+          sse_encode_box_autoadd_client_info(peer, serializer);
+          sse_encode_opt_String(notes, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_favorite_device,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFavoritesRsFavoriteStoreAddPeerConstMeta,
+        argValues: [that, peer, notes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFavoritesRsFavoriteStoreAddPeerConstMeta => const TaskConstMeta(
+    debugName: 'RsFavoriteStore_add_peer',
+    argNames: ['that', 'peer', 'notes'],
+  );
+
+  @override
+  FavoriteDevice? crateApiFavoritesRsFavoriteStoreGet({required RsFavoriteStore that, required String fingerprint}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(that, serializer);
+          sse_encode_String(fingerprint, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_favorite_device,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiFavoritesRsFavoriteStoreGetConstMeta,
+        argValues: [that, fingerprint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFavoritesRsFavoriteStoreGetConstMeta => const TaskConstMeta(
+    debugName: 'RsFavoriteStore_get',
+    argNames: ['that', 'fingerprint'],
+  );
+
+  @override
+  List<FavoriteDevice> crateApiFavoritesRsFavoriteStoreList({required RsFavoriteStore that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_favorite_device,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiFavoritesRsFavoriteStoreListConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFavoritesRsFavoriteStoreListConstMeta => const TaskConstMeta(
+    debugName: 'RsFavoriteStore_list',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<FavoriteDevice?> crateApiFavoritesRsFavoriteStoreMatchPeer({required RsFavoriteStore that, required ClientInfo peer}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(that, serializer);
+          sse_encode_box_autoadd_client_info(peer, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_favorite_device,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFavoritesRsFavoriteStoreMatchPeerConstMeta,
+        argValues: [that, peer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFavoritesRsFavoriteStoreMatchPeerConstMeta => const TaskConstMeta(
+    debugName: 'RsFavoriteStore_match_peer',
+    argNames: ['that', 'peer'],
+  );
+
+  @override
+  Future<void> crateApiFavoritesRsFavoriteStoreRemove({required RsFavoriteStore that, required String fingerprint}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(that, serializer);
+          sse_encode_String(fingerprint, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFavoritesRsFavoriteStoreRemoveConstMeta,
+        argValues: [that, fingerprint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFavoritesRsFavoriteStoreRemoveConstMeta => const TaskConstMeta(
+    debugName: 'RsFavoriteStore_remove',
+    argNames: ['that', 'fingerprint'],
+  );
+
+  @override
+  Future<void> crateApiFavoritesRsFavoriteStoreSetNotes({required RsFavoriteStore that, required String fingerprint, required String notes}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(that, serializer);
+          sse_encode_String(fingerprint, serializer);
+          sse_encode_String(notes, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiFavoritesRsFavoriteStoreSetNotesConstMeta,
+        argValues: [that, fingerprint, notes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiFavoritesRsFavoriteStoreSetNotesConstMeta => const TaskConstMeta(
+    debugName: 'RsFavoriteStore_set_notes',
+    argNames: ['that', 'fingerprint', 'notes'],
+  );
+
   @override
   Future<void> crateApiHttpRsHttpClientCancel({
     required RsHttpClient that,
@@ -511,7 +912,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(ip, serializer);
           sse_encode_u_16(port, serializer);
           sse_encode_String(sessionId, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -550,7 +951,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_box_autoadd_prepare_upload_request_dto(payload, serializer);
           sse_encode_opt_String(publicKey, serializer);
           sse_encode_opt_String(pin, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_prepare_upload_result,
@@ -585,7 +986,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(ip, serializer);
           sse_encode_u_16(port, serializer);
           sse_encode_box_autoadd_register_dto(payload, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_result_with_public_key_register_response_dto,
@@ -642,7 +1043,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             sse_encode_opt_box_autoadd_i_32(fileDescriptor, serializer);
             sse_encode_u_64(contentLength, serializer);
             sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(cancelToken, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
           },
           codec: SseCodec(
             decodeSuccessData: sse_decode_unit,
@@ -685,7 +1086,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(that, serializer);
           sse_encode_String(sessionId, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -713,7 +1114,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             final serializer = SseSerializer(generalizedFrbRustBinding);
             sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(that, serializer);
             sse_encode_StreamSink_rs_server_event_Sse(sink, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
           },
           codec: SseCodec(
             decodeSuccessData: sse_decode_unit,
@@ -742,7 +1143,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(that, serializer);
           sse_encode_String(sessionId, serializer);
           sse_encode_String(fileId, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -769,7 +1170,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(that, serializer);
           sse_encode_String(sessionId, serializer);
           sse_encode_String(fileId, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -804,7 +1205,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_String(fileId, serializer);
           sse_encode_opt_String(path, serializer);
           sse_encode_opt_box_autoadd_i_32(fileDescriptor, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -844,7 +1245,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             sse_encode_opt_String(path, serializer);
             sse_encode_opt_box_autoadd_i_32(fileDescriptor, serializer);
             sse_encode_u_64(fileSize, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
           },
           codec: SseCodec(
             decodeSuccessData: sse_decode_unit,
@@ -873,7 +1274,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(that, serializer);
           sse_encode_String(sessionId, serializer);
           sse_encode_bool(accept, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -892,21 +1293,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<void> crateApiServerRsHttpServerRespondPrepareUpload({required RsHttpServer that, List<String>? acceptedFileIds}) {
+  Future<void> crateApiServerRsHttpServerRespondPrepareUpload({
+    required RsHttpServer that,
+    List<String>? acceptedFileIds,
+    required Map<String, String> destinations,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(that, serializer);
           sse_encode_opt_list_String(acceptedFileIds, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+          sse_encode_Map_String_String_None(destinations, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
         constMeta: kCrateApiServerRsHttpServerRespondPrepareUploadConstMeta,
-        argValues: [that, acceptedFileIds],
+        argValues: [that, acceptedFileIds, destinations],
         apiImpl: this,
       ),
     );
@@ -914,7 +1320,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   TaskConstMeta get kCrateApiServerRsHttpServerRespondPrepareUploadConstMeta => const TaskConstMeta(
     debugName: 'RsHttpServer_respond_prepare_upload',
-    argNames: ['that', 'acceptedFileIds'],
+    argNames: ['that', 'acceptedFileIds', 'destinations'],
   );
 
   @override
@@ -924,7 +1330,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(that, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -943,48 +1349,1608 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   );
 
   @override
-  Future<String> crateApiWebrtcRtcFileReceiverGetFileId({required RtcFileReceiver that}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  ResumeSession? crateApiResumeRsResumeStoreGet({required RsResumeStore that, required String sessionId}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileReceiver(that, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(that, serializer);
+          sse_encode_String(sessionId, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_opt_box_autoadd_resume_session,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiWebrtcRtcFileReceiverGetFileIdConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiResumeRsResumeStoreGetConstMeta,
+        argValues: [that, sessionId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcFileReceiverGetFileIdConstMeta => const TaskConstMeta(
-    debugName: 'RtcFileReceiver_get_file_id',
+  TaskConstMeta get kCrateApiResumeRsResumeStoreGetConstMeta => const TaskConstMeta(
+    debugName: 'RsResumeStore_get',
+    argNames: ['that', 'sessionId'],
+  );
+
+  @override
+  List<ResumeSession> crateApiResumeRsResumeStoreList({required RsResumeStore that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_resume_session,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiResumeRsResumeStoreListConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResumeRsResumeStoreListConstMeta => const TaskConstMeta(
+    debugName: 'RsResumeStore_list',
+    argNames: ['that'],
+  );
+
+  @override
+  List<ResumeSession> crateApiResumeRsResumeStoreListForPeer({required RsResumeStore that, required String fingerprint}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(that, serializer);
+          sse_encode_String(fingerprint, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_resume_session,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiResumeRsResumeStoreListForPeerConstMeta,
+        argValues: [that, fingerprint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResumeRsResumeStoreListForPeerConstMeta => const TaskConstMeta(
+    debugName: 'RsResumeStore_list_for_peer',
+    argNames: ['that', 'fingerprint'],
+  );
+
+  @override
+  Future<void> crateApiResumeRsResumeStoreRemove({required RsResumeStore that, required String sessionId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(that, serializer);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiResumeRsResumeStoreRemoveConstMeta,
+        argValues: [that, sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResumeRsResumeStoreRemoveConstMeta => const TaskConstMeta(
+    debugName: 'RsResumeStore_remove',
+    argNames: ['that', 'sessionId'],
+  );
+
+  @override
+  Future<ResumeSession> crateApiResumeRsResumeStoreSave({required RsResumeStore that, required ResumeSession session}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(that, serializer);
+          sse_encode_box_autoadd_resume_session(session, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_resume_session,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiResumeRsResumeStoreSaveConstMeta,
+        argValues: [that, session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResumeRsResumeStoreSaveConstMeta => const TaskConstMeta(
+    debugName: 'RsResumeStore_save',
+    argNames: ['that', 'session'],
+  );
+
+  @override
+  Future<void> crateApiResumeRsResumeStoreSetProgress({
+    required RsResumeStore that,
+    required String sessionId,
+    required String fileId,
+    required BigInt bytesCompleted,
+    String? partialSha256,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(that, serializer);
+          sse_encode_String(sessionId, serializer);
+          sse_encode_String(fileId, serializer);
+          sse_encode_u_64(bytesCompleted, serializer);
+          sse_encode_opt_String(partialSha256, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiResumeRsResumeStoreSetProgressConstMeta,
+        argValues: [that, sessionId, fileId, bytesCompleted, partialSha256],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiResumeRsResumeStoreSetProgressConstMeta => const TaskConstMeta(
+    debugName: 'RsResumeStore_set_progress',
+    argNames: ['that', 'sessionId', 'fileId', 'bytesCompleted', 'partialSha256'],
+  );
+
+  @override
+  String? crateApiSettingsRsSettingsStoreAlias({required RsSettingsStore that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsRsSettingsStoreAliasConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsRsSettingsStoreAliasConstMeta => const TaskConstMeta(
+    debugName: 'RsSettingsStore_alias',
+    argNames: ['that'],
+  );
+
+  @override
+  int? crateApiSettingsRsSettingsStoreBandwidthLimit({required RsSettingsStore that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsRsSettingsStoreBandwidthLimitConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsRsSettingsStoreBandwidthLimitConstMeta => const TaskConstMeta(
+    debugName: 'RsSettingsStore_bandwidth_limit',
+    argNames: ['that'],
+  );
+
+  @override
+  String? crateApiSettingsRsSettingsStoreDownloadDir({required RsSettingsStore that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsRsSettingsStoreDownloadDirConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsRsSettingsStoreDownloadDirConstMeta => const TaskConstMeta(
+    debugName: 'RsSettingsStore_download_dir',
+    argNames: ['that'],
+  );
+
+  @override
+  String? crateApiSettingsRsSettingsStorePin({required RsSettingsStore that}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(that, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsRsSettingsStorePinConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsRsSettingsStorePinConstMeta => const TaskConstMeta(
+    debugName: 'RsSettingsStore_pin',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<void> crateApiSettingsRsSettingsStoreSetAlias({required RsSettingsStore that, String? alias}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(that, serializer);
+          sse_encode_opt_String(alias, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsRsSettingsStoreSetAliasConstMeta,
+        argValues: [that, alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsRsSettingsStoreSetAliasConstMeta => const TaskConstMeta(
+    debugName: 'RsSettingsStore_set_alias',
+    argNames: ['that', 'alias'],
+  );
+
+  @override
+  Future<void> crateApiSettingsRsSettingsStoreSetBandwidthLimit({required RsSettingsStore that, int? bandwidthLimit}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(that, serializer);
+          sse_encode_opt_box_autoadd_u_32(bandwidthLimit, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsRsSettingsStoreSetBandwidthLimitConstMeta,
+        argValues: [that, bandwidthLimit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsRsSettingsStoreSetBandwidthLimitConstMeta => const TaskConstMeta(
+    debugName: 'RsSettingsStore_set_bandwidth_limit',
+    argNames: ['that', 'bandwidthLimit'],
+  );
+
+  @override
+  Future<void> crateApiSettingsRsSettingsStoreSetDownloadDir({required RsSettingsStore that, String? downloadDir}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(that, serializer);
+          sse_encode_opt_String(downloadDir, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsRsSettingsStoreSetDownloadDirConstMeta,
+        argValues: [that, downloadDir],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsRsSettingsStoreSetDownloadDirConstMeta => const TaskConstMeta(
+    debugName: 'RsSettingsStore_set_download_dir',
+    argNames: ['that', 'downloadDir'],
+  );
+
+  @override
+  Future<void> crateApiSettingsRsSettingsStoreSetPin({required RsSettingsStore that, String? pin}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(that, serializer);
+          sse_encode_opt_String(pin, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsRsSettingsStoreSetPinConstMeta,
+        argValues: [that, pin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsRsSettingsStoreSetPinConstMeta => const TaskConstMeta(
+    debugName: 'RsSettingsStore_set_pin',
+    argNames: ['that', 'pin'],
+  );
+
+  @override
+  TrustStatus crateApiTrustRsTrustStoreCheck({required RsTrustStore that, required String alias, required String fingerprint}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(that, serializer);
+          sse_encode_String(alias, serializer);
+          sse_encode_String(fingerprint, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_trust_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrustRsTrustStoreCheckConstMeta,
+        argValues: [that, alias, fingerprint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustRsTrustStoreCheckConstMeta => const TaskConstMeta(
+    debugName: 'RsTrustStore_check',
+    argNames: ['that', 'alias', 'fingerprint'],
+  );
+
+  @override
+  TrustStatus crateApiTrustRsTrustStoreCheckOffer({required RsTrustStore that, required RegisterDtoV2 info, String? certFingerprint}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(that, serializer);
+          sse_encode_box_autoadd_register_dto_v_2(info, serializer);
+          sse_encode_opt_String(certFingerprint, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_trust_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrustRsTrustStoreCheckOfferConstMeta,
+        argValues: [that, info, certFingerprint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustRsTrustStoreCheckOfferConstMeta => const TaskConstMeta(
+    debugName: 'RsTrustStore_check_offer',
+    argNames: ['that', 'info', 'certFingerprint'],
+  );
+
+  @override
+  String? crateApiTrustRsTrustStoreFingerprint({required RsTrustStore that, required String alias}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(that, serializer);
+          sse_encode_String(alias, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiTrustRsTrustStoreFingerprintConstMeta,
+        argValues: [that, alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustRsTrustStoreFingerprintConstMeta => const TaskConstMeta(
+    debugName: 'RsTrustStore_fingerprint',
+    argNames: ['that', 'alias'],
+  );
+
+  @override
+  Future<void> crateApiTrustRsTrustStoreForget({required RsTrustStore that, required String alias}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(that, serializer);
+          sse_encode_String(alias, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustRsTrustStoreForgetConstMeta,
+        argValues: [that, alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustRsTrustStoreForgetConstMeta => const TaskConstMeta(
+    debugName: 'RsTrustStore_forget',
+    argNames: ['that', 'alias'],
+  );
+
+  @override
+  Future<void> crateApiTrustRsTrustStoreTrust({required RsTrustStore that, required String alias, required String fingerprint}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(that, serializer);
+          sse_encode_String(alias, serializer);
+          sse_encode_String(fingerprint, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiTrustRsTrustStoreTrustConstMeta,
+        argValues: [that, alias, fingerprint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiTrustRsTrustStoreTrustConstMeta => const TaskConstMeta(
+    debugName: 'RsTrustStore_trust',
+    argNames: ['that', 'alias', 'fingerprint'],
+  );
+
+  @override
+  Future<String> crateApiWebrtcRtcFileReceiverGetFileId({required RtcFileReceiver that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileReceiver(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWebrtcRtcFileReceiverGetFileIdConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcFileReceiverGetFileIdConstMeta => const TaskConstMeta(
+    debugName: 'RtcFileReceiver_get_file_id',
+    argNames: ['that'],
+  );
+
+  @override
+  Stream<Uint8List> crateApiWebrtcRtcFileReceiverReceive({required RtcFileReceiver that, ReceiveBufferConfig? buffer}) {
+    final sink = RustStreamSink<Uint8List>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileReceiver(that, serializer);
+            sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
+            sse_encode_opt_box_autoadd_receive_buffer_config(buffer, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_AnyhowException,
+          ),
+          constMeta: kCrateApiWebrtcRtcFileReceiverReceiveConstMeta,
+          argValues: [that, sink, buffer],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcFileReceiverReceiveConstMeta => const TaskConstMeta(
+    debugName: 'RtcFileReceiver_receive',
+    argNames: ['that', 'sink', 'buffer'],
+  );
+
+  @override
+  Future<RTCReceivedFile> crateApiWebrtcRtcFileReceiverReceiveToPath({
+    required RtcFileReceiver that,
+    required String path,
+    required bool withHash,
+    String? expectedSha256,
+    required CollisionPolicy collision,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileReceiver(that, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_bool(withHash, serializer);
+          sse_encode_opt_String(expectedSha256, serializer);
+          sse_encode_collision_policy(collision, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rtc_received_file,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcFileReceiverReceiveToPathConstMeta,
+        argValues: [that, path, withHash, expectedSha256, collision],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcFileReceiverReceiveToPathConstMeta => const TaskConstMeta(
+    debugName: 'RtcFileReceiver_receive_to_path',
+    argNames: ['that', 'path', 'withHash', 'expectedSha256', 'collision'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcFileSenderSend({required RtcFileSender that, required List<int> data}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileSender(that, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcFileSenderSendConstMeta,
+        argValues: [that, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcFileSenderSendConstMeta => const TaskConstMeta(
+    debugName: 'RtcFileSender_send',
+    argNames: ['that', 'data'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcReceiveControllerCancel({required RtcReceiveController that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWebrtcRtcReceiveControllerCancelConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerCancelConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_cancel',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcReceiveControllerDecline({required RtcReceiveController that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcReceiveControllerDeclineConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerDeclineConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_decline',
+    argNames: ['that'],
+  );
+
+  @override
+  Stream<RTCFileError> crateApiWebrtcRtcReceiveControllerListenError({required RtcReceiveController that}) {
+    final sink = RustStreamSink<RTCFileError>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+            sse_encode_StreamSink_rtc_file_error_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcReceiveControllerListenErrorConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenErrorConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_listen_error',
+    argNames: ['that', 'sink'],
+  );
+
+  @override
+  Future<List<FileDto>> crateApiWebrtcRtcReceiveControllerListenFiles({required RtcReceiveController that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_file_dto,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcReceiveControllerListenFilesConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenFilesConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_listen_files',
+    argNames: ['that'],
+  );
+
+  @override
+  Stream<RTCFileProgress> crateApiWebrtcRtcReceiveControllerListenProgress({required RtcReceiveController that}) {
+    final sink = RustStreamSink<RTCFileProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+            sse_encode_StreamSink_rtc_file_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcReceiveControllerListenProgressConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenProgressConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_listen_progress',
+    argNames: ['that', 'sink'],
+  );
+
+  @override
+  Stream<RtcFileReceiver> crateApiWebrtcRtcReceiveControllerListenReceiving({required RtcReceiveController that}) {
+    final sink = RustStreamSink<RtcFileReceiver>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+            sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileReceiver_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcReceiveControllerListenReceivingConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenReceivingConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_listen_receiving',
+    argNames: ['that', 'sink'],
+  );
+
+  @override
+  Stream<RTCStats> crateApiWebrtcRtcReceiveControllerListenStats({required RtcReceiveController that}) {
+    final sink = RustStreamSink<RTCStats>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+            sse_encode_StreamSink_rtc_stats_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcReceiveControllerListenStatsConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenStatsConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_listen_stats',
+    argNames: ['that', 'sink'],
+  );
+
+  @override
+  Stream<RTCStatus> crateApiWebrtcRtcReceiveControllerListenStatus({required RtcReceiveController that}) {
+    final sink = RustStreamSink<RTCStatus>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+            sse_encode_StreamSink_rtc_status_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcReceiveControllerListenStatusConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenStatusConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_listen_status',
+    argNames: ['that', 'sink'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcReceiveControllerPause({required RtcReceiveController that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWebrtcRtcReceiveControllerPauseConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerPauseConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_pause',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcReceiveControllerResume({required RtcReceiveController that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWebrtcRtcReceiveControllerResumeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerResumeConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_resume',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcReceiveControllerSendFileStatus({required RtcReceiveController that, required RTCSendFileResponse status}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+          sse_encode_box_autoadd_rtc_send_file_response(status, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcReceiveControllerSendFileStatusConstMeta,
+        argValues: [that, status],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerSendFileStatusConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_send_file_status',
+    argNames: ['that', 'status'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcReceiveControllerSendPin({required RtcReceiveController that, required String pin}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+          sse_encode_String(pin, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcReceiveControllerSendPinConstMeta,
+        argValues: [that, pin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerSendPinConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_send_pin',
+    argNames: ['that', 'pin'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcReceiveControllerSendSelection({
+    required RtcReceiveController that,
+    required Set<String> selection,
+    required Map<String, String> destinations,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
+          sse_encode_Set_String_None(selection, serializer);
+          sse_encode_Map_String_String_None(destinations, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcReceiveControllerSendSelectionConstMeta,
+        argValues: [that, selection, destinations],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerSendSelectionConstMeta => const TaskConstMeta(
+    debugName: 'RtcReceiveController_send_selection',
+    argNames: ['that', 'selection', 'destinations'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcSendControllerCancel({required RtcSendController that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWebrtcRtcSendControllerCancelConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerCancelConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_cancel',
+    argNames: ['that'],
+  );
+
+  @override
+  Stream<RTCFileError> crateApiWebrtcRtcSendControllerListenError({required RtcSendController that}) {
+    final sink = RustStreamSink<RTCFileError>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+            sse_encode_StreamSink_rtc_file_error_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcSendControllerListenErrorConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerListenErrorConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_listen_error',
+    argNames: ['that', 'sink'],
+  );
+
+  @override
+  Stream<RTCFileProgress> crateApiWebrtcRtcSendControllerListenProgress({required RtcSendController that}) {
+    final sink = RustStreamSink<RTCFileProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+            sse_encode_StreamSink_rtc_file_progress_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcSendControllerListenProgressConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerListenProgressConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_listen_progress',
+    argNames: ['that', 'sink'],
+  );
+
+  @override
+  Future<Set<String>> crateApiWebrtcRtcSendControllerListenSelectedFiles({required RtcSendController that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Set_String_None,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcSendControllerListenSelectedFilesConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerListenSelectedFilesConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_listen_selected_files',
+    argNames: ['that'],
+  );
+
+  @override
+  Stream<RTCStats> crateApiWebrtcRtcSendControllerListenStats({required RtcSendController that}) {
+    final sink = RustStreamSink<RTCStats>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+            sse_encode_StreamSink_rtc_stats_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcSendControllerListenStatsConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerListenStatsConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_listen_stats',
+    argNames: ['that', 'sink'],
+  );
+
+  @override
+  Stream<RTCStatus> crateApiWebrtcRtcSendControllerListenStatus({required RtcSendController that}) {
+    final sink = RustStreamSink<RTCStatus>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+            sse_encode_StreamSink_rtc_status_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcSendControllerListenStatusConstMeta,
+          argValues: [that, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerListenStatusConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_listen_status',
+    argNames: ['that', 'sink'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcSendControllerPause({required RtcSendController that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWebrtcRtcSendControllerPauseConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerPauseConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_pause',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcSendControllerResume({required RtcSendController that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiWebrtcRtcSendControllerResumeConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerResumeConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_resume',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<RtcFileSender> crateApiWebrtcRtcSendControllerSendFile({required RtcSendController that, required String fileId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+          sse_encode_String(fileId, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileSender,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcSendControllerSendFileConstMeta,
+        argValues: [that, fileId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerSendFileConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_send_file',
+    argNames: ['that', 'fileId'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcSendControllerSendFileFromPath({required RtcSendController that, required String fileId, required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+          sse_encode_String(fileId, serializer);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcSendControllerSendFileFromPathConstMeta,
+        argValues: [that, fileId, path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerSendFileFromPathConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_send_file_from_path',
+    argNames: ['that', 'fileId', 'path'],
+  );
+
+  @override
+  Stream<RTCSendFileResponse> crateApiWebrtcRtcSendControllerSendFilesFromPaths({
+    required RtcSendController that,
+    required List<String> fileIds,
+    required Map<String, String> paths,
+  }) {
+    final sink = RustStreamSink<RTCSendFileResponse>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+            sse_encode_StreamSink_rtc_send_file_response_Sse(sink, serializer);
+            sse_encode_list_String(fileIds, serializer);
+            sse_encode_Map_String_String_None(paths, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiWebrtcRtcSendControllerSendFilesFromPathsConstMeta,
+          argValues: [that, sink, fileIds, paths],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerSendFilesFromPathsConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_send_files_from_paths',
+    argNames: ['that', 'sink', 'fileIds', 'paths'],
+  );
+
+  @override
+  Future<void> crateApiWebrtcRtcSendControllerSendPin({required RtcSendController that, required String pin}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
+          sse_encode_String(pin, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebrtcRtcSendControllerSendPinConstMeta,
+        argValues: [that, pin],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebrtcRtcSendControllerSendPinConstMeta => const TaskConstMeta(
+    debugName: 'RtcSendController_send_pin',
+    argNames: ['that', 'pin'],
+  );
+
+  @override
+  Future<int> crateApiSessionSessionManagerActiveCount({required SessionManager that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionManagerActiveCountConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionManagerActiveCountConstMeta => const TaskConstMeta(
+    debugName: 'SessionManager_active_count',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<String> crateApiSessionSessionManagerAddReceive({required SessionManager that, required RtcReceiveController controller}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(that, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(controller, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionManagerAddReceiveConstMeta,
+        argValues: [that, controller],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionManagerAddReceiveConstMeta => const TaskConstMeta(
+    debugName: 'SessionManager_add_receive',
+    argNames: ['that', 'controller'],
+  );
+
+  @override
+  Future<String> crateApiSessionSessionManagerAddSend({required SessionManager that, required RtcSendController controller}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(that, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(controller, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionManagerAddSendConstMeta,
+        argValues: [that, controller],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionManagerAddSendConstMeta => const TaskConstMeta(
+    debugName: 'SessionManager_add_send',
+    argNames: ['that', 'controller'],
+  );
+
+  @override
+  Future<void> crateApiSessionSessionManagerCancel({required SessionManager that, required String sessionId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(that, serializer);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSessionSessionManagerCancelConstMeta,
+        argValues: [that, sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionManagerCancelConstMeta => const TaskConstMeta(
+    debugName: 'SessionManager_cancel',
+    argNames: ['that', 'sessionId'],
+  );
+
+  @override
+  Future<void> crateApiSessionSessionManagerCancelAll({required SessionManager that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionManagerCancelAllConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionManagerCancelAllConstMeta => const TaskConstMeta(
+    debugName: 'SessionManager_cancel_all',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<void> crateApiSessionSessionManagerClearEndCallback({required SessionManager that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionManagerClearEndCallbackConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionManagerClearEndCallbackConstMeta => const TaskConstMeta(
+    debugName: 'SessionManager_clear_end_callback',
     argNames: ['that'],
   );
 
   @override
-  Stream<Uint8List> crateApiWebrtcRtcFileReceiverReceive({required RtcFileReceiver that}) {
-    final sink = RustStreamSink<Uint8List>();
+  Future<List<SessionInfo>> crateApiSessionSessionManagerListSessions({required SessionManager that}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(that, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_session_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionManagerListSessionsConstMeta,
+        argValues: [that],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionManagerListSessionsConstMeta => const TaskConstMeta(
+    debugName: 'SessionManager_list_sessions',
+    argNames: ['that'],
+  );
+
+  @override
+  Future<void> crateApiSessionSessionManagerRemove({required SessionManager that, required String sessionId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(that, serializer);
+          sse_encode_String(sessionId, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionManagerRemoveConstMeta,
+        argValues: [that, sessionId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionManagerRemoveConstMeta => const TaskConstMeta(
+    debugName: 'SessionManager_remove',
+    argNames: ['that', 'sessionId'],
+  );
+
+  @override
+  Future<void> crateApiSessionSessionManagerSetEndCallback({required SessionManager that, required FutureOr<void> Function(SessionInfo) callback}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(that, serializer);
+          sse_encode_DartFn_Inputs_session_info_Output_unit_AnyhowException(callback, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionSessionManagerSetEndCallbackConstMeta,
+        argValues: [that, callback],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionSessionManagerSetEndCallbackConstMeta => const TaskConstMeta(
+    debugName: 'SessionManager_set_end_callback',
+    argNames: ['that', 'callback'],
+  );
+
+  @override
+  Future<String> crateApiCryptoComputeSha256({
+    required String path,
+    required RsCancellationToken cancelToken,
+    required FutureOr<void> Function(double) onProgress,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(cancelToken, serializer);
+          sse_encode_DartFn_Inputs_f_64_Output_unit_AnyhowException(onProgress, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiCryptoComputeSha256ConstMeta,
+        argValues: [path, cancelToken, onProgress],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiCryptoComputeSha256ConstMeta => const TaskConstMeta(
+    debugName: 'compute_sha256',
+    argNames: ['path', 'cancelToken', 'onProgress'],
+  );
+
+  @override
+  Stream<WsServerMessage> crateApiWebrtcConnect({
+    required String uri,
+    required ProposingClientInfo info,
+    required String privateKey,
+    required bool allowInsecure,
+    required FutureOr<void> Function(LsSignalingConnection) onConnection,
+  }) {
+    final sink = RustStreamSink<WsServerMessage>();
     unawaited(
       handler.executeNormal(
         NormalTask(
           callFfi: (port_) {
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileReceiver(that, serializer);
-            sse_encode_StreamSink_list_prim_u_8_strict_Sse(sink, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            sse_encode_StreamSink_ws_server_message_Sse(sink, serializer);
+            sse_encode_String(uri, serializer);
+            sse_encode_box_autoadd_proposing_client_info(info, serializer);
+            sse_encode_String(privateKey, serializer);
+            sse_encode_bool(allowInsecure, serializer);
+            sse_encode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection_Output_unit_AnyhowException(
+              onConnection,
+              serializer,
+            );
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
           },
           codec: SseCodec(
             decodeSuccessData: sse_decode_unit,
-            decodeErrorData: sse_decode_AnyhowException,
+            decodeErrorData: null,
           ),
-          constMeta: kCrateApiWebrtcRtcFileReceiverReceiveConstMeta,
-          argValues: [that, sink],
+          constMeta: kCrateApiWebrtcConnectConstMeta,
+          argValues: [sink, uri, info, privateKey, allowInsecure, onConnection],
           apiImpl: this,
         ),
       ),
@@ -992,273 +2958,358 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcFileReceiverReceiveConstMeta => const TaskConstMeta(
-    debugName: 'RtcFileReceiver_receive',
-    argNames: ['that', 'sink'],
+  TaskConstMeta get kCrateApiWebrtcConnectConstMeta => const TaskConstMeta(
+    debugName: 'connect',
+    argNames: ['sink', 'uri', 'info', 'privateKey', 'allowInsecure', 'onConnection'],
   );
 
   @override
-  Future<void> crateApiWebrtcRtcFileSenderSend({required RtcFileSender that, required List<int> data}) {
+  RsCancellationToken crateApiHttpCreateCancellationToken() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiHttpCreateCancellationTokenConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpCreateCancellationTokenConstMeta => const TaskConstMeta(
+    debugName: 'create_cancellation_token',
+    argNames: [],
+  );
+
+  @override
+  RsHttpClient crateApiHttpCreateClient({required String privateKey, required String cert, required LsHttpClientVersion version, int? timeoutMs}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(privateKey, serializer);
+          sse_encode_String(cert, serializer);
+          sse_encode_ls_http_client_version(version, serializer);
+          sse_encode_opt_box_autoadd_u_32(timeoutMs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient,
+          decodeErrorData: sse_decode_rs_http_client_error,
+        ),
+        constMeta: kCrateApiHttpCreateClientConstMeta,
+        argValues: [privateKey, cert, version, timeoutMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHttpCreateClientConstMeta => const TaskConstMeta(
+    debugName: 'create_client',
+    argNames: ['privateKey', 'cert', 'version', 'timeoutMs'],
+  );
+
+  @override
+  Future<FileDto> crateApiModelCreateFileDto({required String path, required bool withHash, required bool withPreview}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileSender(that, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+          sse_encode_String(path, serializer);
+          sse_encode_bool(withHash, serializer);
+          sse_encode_bool(withPreview, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_file_dto,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebrtcRtcFileSenderSendConstMeta,
-        argValues: [that, data],
+        constMeta: kCrateApiModelCreateFileDtoConstMeta,
+        argValues: [path, withHash, withPreview],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcFileSenderSendConstMeta => const TaskConstMeta(
-    debugName: 'RtcFileSender_send',
-    argNames: ['that', 'data'],
+  TaskConstMeta get kCrateApiModelCreateFileDtoConstMeta => const TaskConstMeta(
+    debugName: 'create_file_dto',
+    argNames: ['path', 'withHash', 'withPreview'],
   );
 
   @override
-  Future<void> crateApiWebrtcRtcReceiveControllerDecline({required RtcReceiveController that}) {
+  SessionManager crateApiSessionCreateSessionManager() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSessionCreateSessionManagerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSessionCreateSessionManagerConstMeta => const TaskConstMeta(
+    debugName: 'create_session_manager',
+    argNames: [],
+  );
+
+  @override
+  Future<(Dart2RustStreamSink, Dart2RustStreamReceiver)> crateApiStreamCreateStream() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dart_2_rust_stream_sink_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dart_2_rust_stream_receiver,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiStreamCreateStreamConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiStreamCreateStreamConstMeta => const TaskConstMeta(
+    debugName: 'create_stream',
+    argNames: [],
+  );
+
+  @override
+  Future<void> crateApiLoggingEnableDebugLogging() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebrtcRtcReceiveControllerDeclineConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiLoggingEnableDebugLoggingConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerDeclineConstMeta => const TaskConstMeta(
-    debugName: 'RtcReceiveController_decline',
-    argNames: ['that'],
+  TaskConstMeta get kCrateApiLoggingEnableDebugLoggingConstMeta => const TaskConstMeta(
+    debugName: 'enable_debug_logging',
+    argNames: [],
   );
 
   @override
-  Stream<RTCFileError> crateApiWebrtcRtcReceiveControllerListenError({required RtcReceiveController that}) {
-    final sink = RustStreamSink<RTCFileError>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
-            sse_encode_StreamSink_rtc_file_error_Sse(sink, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiWebrtcRtcReceiveControllerListenErrorConstMeta,
-          argValues: [that, sink],
-          apiImpl: this,
+  Future<List<FileDto>> crateApiPreviewFillPreviews({required List<FileDto> files, required Map<String, String> paths}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_file_dto(files, serializer);
+          sse_encode_Map_String_String_None(paths, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_file_dto,
+          decodeErrorData: null,
         ),
+        constMeta: kCrateApiPreviewFillPreviewsConstMeta,
+        argValues: [files, paths],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenErrorConstMeta => const TaskConstMeta(
-    debugName: 'RtcReceiveController_listen_error',
-    argNames: ['that', 'sink'],
+  TaskConstMeta get kCrateApiPreviewFillPreviewsConstMeta => const TaskConstMeta(
+    debugName: 'fill_previews',
+    argNames: ['files', 'paths'],
   );
 
   @override
-  Future<List<FileDto>> crateApiWebrtcRtcReceiveControllerListenFiles({required RtcReceiveController that}) {
+  Future<KeyPair> crateApiCryptoGenerateKeyPair() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_file_dto,
+          decodeSuccessData: sse_decode_key_pair,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebrtcRtcReceiveControllerListenFilesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiCryptoGenerateKeyPairConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenFilesConstMeta => const TaskConstMeta(
-    debugName: 'RtcReceiveController_listen_files',
-    argNames: ['that'],
+  TaskConstMeta get kCrateApiCryptoGenerateKeyPairConstMeta => const TaskConstMeta(
+    debugName: 'generate_key_pair',
+    argNames: [],
   );
 
   @override
-  Stream<RtcFileReceiver> crateApiWebrtcRtcReceiveControllerListenReceiving({required RtcReceiveController that}) {
-    final sink = RustStreamSink<RtcFileReceiver>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
-            sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileReceiver_Sse(sink, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiWebrtcRtcReceiveControllerListenReceivingConstMeta,
-          argValues: [that, sink],
-          apiImpl: this,
+  Future<String?> crateApiPreviewExtractEmbeddedPreview({required String path, required String fileType}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_String(fileType, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
+        constMeta: kCrateApiPreviewExtractEmbeddedPreviewConstMeta,
+        argValues: [path, fileType],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenReceivingConstMeta => const TaskConstMeta(
-    debugName: 'RtcReceiveController_listen_receiving',
-    argNames: ['that', 'sink'],
+  TaskConstMeta get kCrateApiPreviewExtractEmbeddedPreviewConstMeta => const TaskConstMeta(
+    debugName: 'extract_embedded_preview',
+    argNames: ['path', 'fileType'],
   );
 
   @override
-  Stream<RTCStatus> crateApiWebrtcRtcReceiveControllerListenStatus({required RtcReceiveController that}) {
-    final sink = RustStreamSink<RTCStatus>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
-            sse_encode_StreamSink_rtc_status_Sse(sink, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiWebrtcRtcReceiveControllerListenStatusConstMeta,
-          argValues: [that, sink],
-          apiImpl: this,
+  RtcReceiveController crateApiRegistryGetReceiveController({required String id}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
+        constMeta: kCrateApiRegistryGetReceiveControllerConstMeta,
+        argValues: [id],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerListenStatusConstMeta => const TaskConstMeta(
-    debugName: 'RtcReceiveController_listen_status',
-    argNames: ['that', 'sink'],
+  TaskConstMeta get kCrateApiRegistryGetReceiveControllerConstMeta => const TaskConstMeta(
+    debugName: 'get_receive_controller',
+    argNames: ['id'],
   );
 
   @override
-  Future<void> crateApiWebrtcRtcReceiveControllerSendFileStatus({required RtcReceiveController that, required RTCSendFileResponse status}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  RtcSendController crateApiRegistryGetSendController({required String id}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
-          sse_encode_box_autoadd_rtc_send_file_response(status, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+          sse_encode_String(id, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebrtcRtcReceiveControllerSendFileStatusConstMeta,
-        argValues: [that, status],
+        constMeta: kCrateApiRegistryGetSendControllerConstMeta,
+        argValues: [id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerSendFileStatusConstMeta => const TaskConstMeta(
-    debugName: 'RtcReceiveController_send_file_status',
-    argNames: ['that', 'status'],
+  TaskConstMeta get kCrateApiRegistryGetSendControllerConstMeta => const TaskConstMeta(
+    debugName: 'get_send_controller',
+    argNames: ['id'],
   );
 
   @override
-  Future<void> crateApiWebrtcRtcReceiveControllerSendPin({required RtcReceiveController that, required String pin}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  LsSignalingConnection crateApiRegistryGetSignalingConnection({required String id}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
-          sse_encode_String(pin, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+          sse_encode_String(id, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebrtcRtcReceiveControllerSendPinConstMeta,
-        argValues: [that, pin],
+        constMeta: kCrateApiRegistryGetSignalingConnectionConstMeta,
+        argValues: [id],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerSendPinConstMeta => const TaskConstMeta(
-    debugName: 'RtcReceiveController_send_pin',
-    argNames: ['that', 'pin'],
+  TaskConstMeta get kCrateApiRegistryGetSignalingConnectionConstMeta => const TaskConstMeta(
+    debugName: 'get_signaling_connection',
+    argNames: ['id'],
   );
 
   @override
-  Future<void> crateApiWebrtcRtcReceiveControllerSendSelection({required RtcReceiveController that, required Set<String> selection}) {
+  Future<InferredMimeType> crateApiModelInferMimeType({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(that, serializer);
-          sse_encode_Set_String_None(selection, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_inferred_mime_type,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebrtcRtcReceiveControllerSendSelectionConstMeta,
-        argValues: [that, selection],
+        constMeta: kCrateApiModelInferMimeTypeConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcReceiveControllerSendSelectionConstMeta => const TaskConstMeta(
-    debugName: 'RtcReceiveController_send_selection',
-    argNames: ['that', 'selection'],
+  TaskConstMeta get kCrateApiModelInferMimeTypeConstMeta => const TaskConstMeta(
+    debugName: 'infer_mime_type',
+    argNames: ['path'],
   );
 
   @override
-  Stream<RTCFileError> crateApiWebrtcRtcSendControllerListenError({required RtcSendController that}) {
-    final sink = RustStreamSink<RTCFileError>();
+  Stream<LogEntry> crateApiLoggingListenLogs({required LogLevel minLevel, required List<String> targets}) {
+    final sink = RustStreamSink<LogEntry>();
     unawaited(
       handler.executeNormal(
         NormalTask(
           callFfi: (port_) {
             final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
-            sse_encode_StreamSink_rtc_file_error_Sse(sink, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            sse_encode_StreamSink_log_entry_Sse(sink, serializer);
+            sse_encode_log_level(minLevel, serializer);
+            sse_encode_list_String(targets, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
           },
           codec: SseCodec(
             decodeSuccessData: sse_decode_unit,
             decodeErrorData: null,
           ),
-          constMeta: kCrateApiWebrtcRtcSendControllerListenErrorConstMeta,
-          argValues: [that, sink],
+          constMeta: kCrateApiLoggingListenLogsConstMeta,
+          argValues: [sink, minLevel, targets],
           apiImpl: this,
         ),
       ),
@@ -1266,282 +3317,293 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcSendControllerListenErrorConstMeta => const TaskConstMeta(
-    debugName: 'RtcSendController_listen_error',
-    argNames: ['that', 'sink'],
+  TaskConstMeta get kCrateApiLoggingListenLogsConstMeta => const TaskConstMeta(
+    debugName: 'listen_logs',
+    argNames: ['sink', 'minLevel', 'targets'],
   );
 
   @override
-  Future<Set<String>> crateApiWebrtcRtcSendControllerListenSelectedFiles({required RtcSendController that}) {
+  Future<RsFavoriteStore> crateApiFavoritesOpenFavoriteStore({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_Set_String_None,
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebrtcRtcSendControllerListenSelectedFilesConstMeta,
-        argValues: [that],
+        constMeta: kCrateApiFavoritesOpenFavoriteStoreConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcSendControllerListenSelectedFilesConstMeta => const TaskConstMeta(
-    debugName: 'RtcSendController_listen_selected_files',
-    argNames: ['that'],
+  TaskConstMeta get kCrateApiFavoritesOpenFavoriteStoreConstMeta => const TaskConstMeta(
+    debugName: 'open_favorite_store',
+    argNames: ['path'],
   );
 
   @override
-  Stream<RTCStatus> crateApiWebrtcRtcSendControllerListenStatus({required RtcSendController that}) {
-    final sink = RustStreamSink<RTCStatus>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
-            sse_encode_StreamSink_rtc_status_Sse(sink, serializer);
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiWebrtcRtcSendControllerListenStatusConstMeta,
-          argValues: [that, sink],
-          apiImpl: this,
+  Future<RsResumeStore> crateApiResumeOpenResumeStore({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
+        constMeta: kCrateApiResumeOpenResumeStoreConstMeta,
+        argValues: [path],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcSendControllerListenStatusConstMeta => const TaskConstMeta(
-    debugName: 'RtcSendController_listen_status',
-    argNames: ['that', 'sink'],
+  TaskConstMeta get kCrateApiResumeOpenResumeStoreConstMeta => const TaskConstMeta(
+    debugName: 'open_resume_store',
+    argNames: ['path'],
   );
 
   @override
-  Future<RtcFileSender> crateApiWebrtcRtcSendControllerSendFile({required RtcSendController that, required String fileId}) {
+  Future<RsSettingsStore> crateApiSettingsOpenSettings({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
-          sse_encode_String(fileId, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102, port: port_);
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCFileSender,
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebrtcRtcSendControllerSendFileConstMeta,
-        argValues: [that, fileId],
+        constMeta: kCrateApiSettingsOpenSettingsConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcSendControllerSendFileConstMeta => const TaskConstMeta(
-    debugName: 'RtcSendController_send_file',
-    argNames: ['that', 'fileId'],
+  TaskConstMeta get kCrateApiSettingsOpenSettingsConstMeta => const TaskConstMeta(
+    debugName: 'open_settings',
+    argNames: ['path'],
   );
 
   @override
-  Future<void> crateApiWebrtcRtcSendControllerSendPin({required RtcSendController that, required String pin}) {
+  Future<RsTrustStore> crateApiTrustOpenTrustStore({required String path}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(that, serializer);
-          sse_encode_String(pin, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebrtcRtcSendControllerSendPinConstMeta,
-        argValues: [that, pin],
+        constMeta: kCrateApiTrustOpenTrustStoreConstMeta,
+        argValues: [path],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebrtcRtcSendControllerSendPinConstMeta => const TaskConstMeta(
-    debugName: 'RtcSendController_send_pin',
-    argNames: ['that', 'pin'],
+  TaskConstMeta get kCrateApiTrustOpenTrustStoreConstMeta => const TaskConstMeta(
+    debugName: 'open_trust_store',
+    argNames: ['path'],
   );
 
   @override
-  Stream<WsServerMessage> crateApiWebrtcConnect({
-    required String uri,
-    required ProposingClientInfo info,
-    required String privateKey,
-    required FutureOr<void> Function(LsSignalingConnection) onConnection,
-  }) {
-    final sink = RustStreamSink<WsServerMessage>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_StreamSink_ws_server_message_Sse(sink, serializer);
-            sse_encode_String(uri, serializer);
-            sse_encode_box_autoadd_proposing_client_info(info, serializer);
-            sse_encode_String(privateKey, serializer);
-            sse_encode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection_Output_unit_AnyhowException(
-              onConnection,
-              serializer,
-            );
-            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiWebrtcConnectConstMeta,
-          argValues: [sink, uri, info, privateKey, onConnection],
-          apiImpl: this,
+  Future<String> crateApiModelPartialSha256({required String path, required BigInt length}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_u_64(length, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
+        constMeta: kCrateApiModelPartialSha256ConstMeta,
+        argValues: [path, length],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiWebrtcConnectConstMeta => const TaskConstMeta(
-    debugName: 'connect',
-    argNames: ['sink', 'uri', 'info', 'privateKey', 'onConnection'],
+  TaskConstMeta get kCrateApiModelPartialSha256ConstMeta => const TaskConstMeta(
+    debugName: 'partial_sha256',
+    argNames: ['path', 'length'],
   );
 
   @override
-  RsCancellationToken crateApiHttpCreateCancellationToken() {
+  String crateApiRegistryRegisterReceiveController({required RtcReceiveController controller}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCReceiveController(controller, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiHttpCreateCancellationTokenConstMeta,
-        argValues: [],
+        constMeta: kCrateApiRegistryRegisterReceiveControllerConstMeta,
+        argValues: [controller],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHttpCreateCancellationTokenConstMeta => const TaskConstMeta(
-    debugName: 'create_cancellation_token',
-    argNames: [],
+  TaskConstMeta get kCrateApiRegistryRegisterReceiveControllerConstMeta => const TaskConstMeta(
+    debugName: 'register_receive_controller',
+    argNames: ['controller'],
   );
 
   @override
-  RsHttpClient crateApiHttpCreateClient({required String privateKey, required String cert, required LsHttpClientVersion version, int? timeoutMs}) {
+  String crateApiRegistryRegisterSendController({required RtcSendController controller}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(privateKey, serializer);
-          sse_encode_String(cert, serializer);
-          sse_encode_ls_http_client_version(version, serializer);
-          sse_encode_opt_box_autoadd_u_32(timeoutMs, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(controller, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient,
-          decodeErrorData: sse_decode_rs_http_client_error,
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiHttpCreateClientConstMeta,
-        argValues: [privateKey, cert, version, timeoutMs],
+        constMeta: kCrateApiRegistryRegisterSendControllerConstMeta,
+        argValues: [controller],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiHttpCreateClientConstMeta => const TaskConstMeta(
-    debugName: 'create_client',
-    argNames: ['privateKey', 'cert', 'version', 'timeoutMs'],
+  TaskConstMeta get kCrateApiRegistryRegisterSendControllerConstMeta => const TaskConstMeta(
+    debugName: 'register_send_controller',
+    argNames: ['controller'],
   );
 
   @override
-  Future<(Dart2RustStreamSink, Dart2RustStreamReceiver)> crateApiStreamCreateStream() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  String crateApiRegistryRegisterSignalingConnection({required LsSignalingConnection connection}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection(connection, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dart_2_rust_stream_sink_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_dart_2_rust_stream_receiver,
+          decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiStreamCreateStreamConstMeta,
-        argValues: [],
+        constMeta: kCrateApiRegistryRegisterSignalingConnectionConstMeta,
+        argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiStreamCreateStreamConstMeta => const TaskConstMeta(
-    debugName: 'create_stream',
-    argNames: [],
+  TaskConstMeta get kCrateApiRegistryRegisterSignalingConnectionConstMeta => const TaskConstMeta(
+    debugName: 'register_signaling_connection',
+    argNames: ['connection'],
   );
 
   @override
-  Future<void> crateApiLoggingEnableDebugLogging() {
+  Future<List<String>> crateApiModelRemovePartFiles({required String directory}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+          sse_encode_String(directory, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiLoggingEnableDebugLoggingConstMeta,
-        argValues: [],
+        constMeta: kCrateApiModelRemovePartFilesConstMeta,
+        argValues: [directory],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiLoggingEnableDebugLoggingConstMeta => const TaskConstMeta(
-    debugName: 'enable_debug_logging',
-    argNames: [],
+  TaskConstMeta get kCrateApiModelRemovePartFilesConstMeta => const TaskConstMeta(
+    debugName: 'remove_part_files',
+    argNames: ['directory'],
   );
 
   @override
-  Future<KeyPair> crateApiCryptoGenerateKeyPair() {
+  String crateApiModelSanitizeFileName({required String fileName}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(fileName, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiModelSanitizeFileNameConstMeta,
+        argValues: [fileName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiModelSanitizeFileNameConstMeta => const TaskConstMeta(
+    debugName: 'sanitize_file_name',
+    argNames: ['fileName'],
+  );
+
+  @override
+  Future<List<ScannedFile>> crateApiModelScanDirectory({
+    required String path,
+    required List<String> include,
+    required List<String> exclude,
+    required SymlinkPolicy symlinks,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+          sse_encode_String(path, serializer);
+          sse_encode_list_String(include, serializer);
+          sse_encode_list_String(exclude, serializer);
+          sse_encode_symlink_policy(symlinks, serializer);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_key_pair,
+          decodeSuccessData: sse_decode_list_scanned_file,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiCryptoGenerateKeyPairConstMeta,
-        argValues: [],
+        constMeta: kCrateApiModelScanDirectoryConstMeta,
+        argValues: [path, include, exclude, symlinks],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiCryptoGenerateKeyPairConstMeta => const TaskConstMeta(
-    debugName: 'generate_key_pair',
-    argNames: [],
+  TaskConstMeta get kCrateApiModelScanDirectoryConstMeta => const TaskConstMeta(
+    debugName: 'scan_directory',
+    argNames: ['path', 'include', 'exclude', 'symlinks'],
   );
 
   @override
@@ -1571,7 +3633,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           sse_encode_opt_String(pin, serializer);
           sse_encode_opt_box_autoadd_web_send_params(webSend, serializer);
           sse_encode_opt_String(showToken, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer,
@@ -1589,6 +3651,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     argNames: ['port', 'tls', 'alias', 'version', 'deviceModel', 'deviceType', 'fingerprint', 'pin', 'webSend', 'showToken'],
   );
 
+  @override
+  void crateApiRegistryUnregisterHandle({required String id}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiRegistryUnregisterHandleConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiRegistryUnregisterHandleConstMeta => const TaskConstMeta(
+    debugName: 'unregister_handle',
+    argNames: ['id'],
+  );
+
   @override
   Future<void> crateApiCryptoVerifyCert({required String cert, required String publicKey}) {
     return handler.executeNormal(
@@ -1597,7 +3684,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(cert, serializer);
           sse_encode_String(publicKey, serializer);
-          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+          pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_unit,
@@ -1610,17 +3697,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
-  TaskConstMeta get kCrateApiCryptoVerifyCertConstMeta => const TaskConstMeta(
-    debugName: 'verify_cert',
-    argNames: ['cert', 'publicKey'],
-  );
+  TaskConstMeta get kCrateApiCryptoVerifyCertConstMeta => const TaskConstMeta(
+    debugName: 'verify_cert',
+    argNames: ['cert', 'publicKey'],
+  );
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection_Output_unit_AnyhowException(
+    FutureOr<void> Function(LsSignalingConnection) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException('$e\n\n$s'));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic) encode_DartFn_Inputs_f_64_Output_unit_AnyhowException(FutureOr<void> Function(double) raw) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_f_64(rawArg0);
 
-  Future<void> Function(int, dynamic)
-  encode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection_Output_unit_AnyhowException(
-    FutureOr<void> Function(LsSignalingConnection) raw,
-  ) {
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException('$e\n\n$s'));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+    };
+  }
+
+  Future<void> Function(int, dynamic) encode_DartFn_Inputs_session_info_Output_unit_AnyhowException(FutureOr<void> Function(SessionInfo) raw) {
     return (callId, rawArg0) async {
-      final arg0 = dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection(rawArg0);
+      final arg0 = dco_decode_session_info(rawArg0);
 
       Box<void>? rawOutput;
       Box<AnyhowException>? rawError;
@@ -1693,6 +3834,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsCancellationToken =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken;
 
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsFavoriteStore =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsFavoriteStore =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore;
+
   RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsHttpClient =>
       wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient;
 
@@ -1705,6 +3852,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsHttpServer =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer;
 
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsResumeStore =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsResumeStore =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsSettingsStore =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsSettingsStore =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_RsTrustStore =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_RsTrustStore =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore;
+
+  RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SessionManager =>
+      wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager;
+
+  RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SessionManager =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager;
+
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1759,6 +3930,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsCancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  RsFavoriteStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsFavoriteStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   RsHttpClient dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1772,15 +3949,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Dart2RustStreamSink dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(dynamic raw) {
+  RsResumeStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Dart2RustStreamSinkImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return RsResumeStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
-  RtcSendController dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(dynamic raw) {
+  RsSettingsStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return RtcSendControllerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    return RsSettingsStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsTrustStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsTrustStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SessionManager dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SessionManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Dart2RustStreamSink dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Dart2RustStreamSinkImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
   @protected
@@ -1819,6 +4014,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsCancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  RsFavoriteStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsFavoriteStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   RsHttpClient dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1831,6 +4032,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsHttpServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  RsResumeStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsResumeStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsSettingsStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsSettingsStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsTrustStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsTrustStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SessionManager dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SessionManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   FutureOr<void> Function(LsSignalingConnection)
   dco_decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection_Output_unit_AnyhowException(
@@ -1840,6 +4065,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('');
   }
 
+  @protected
+  FutureOr<void> Function(double) dco_decode_DartFn_Inputs_f_64_Output_unit_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(SessionInfo) dco_decode_DartFn_Inputs_session_info_Output_unit_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
   @protected
   Object dco_decode_DartOpaque(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1906,6 +4143,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsCancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  RsFavoriteStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsFavoriteStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   RsHttpClient dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1918,6 +4161,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsHttpServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
   }
 
+  @protected
+  RsResumeStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsResumeStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsSettingsStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsSettingsStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RsTrustStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RsTrustStoreImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SessionManager dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SessionManagerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
   @protected
   Set<String> dco_decode_Set_String_None(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1944,6 +4211,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<List<ClientInfo>> dco_decode_StreamSink_list_client_info_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<RsServerEvent> dco_decode_StreamSink_rs_server_event_Sse(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1956,6 +4235,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError();
   }
 
+  @protected
+  RustStreamSink<RTCFileProgress> dco_decode_StreamSink_rtc_file_progress_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<RTCSendFileResponse> dco_decode_StreamSink_rtc_send_file_response_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<RTCStats> dco_decode_StreamSink_rtc_stats_Sse(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   RustStreamSink<RTCStatus> dco_decode_StreamSink_rtc_status_Sse(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1980,6 +4277,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return UuidValue.fromByteList(dco_decode_list_prim_u_8_strict(raw));
   }
 
+  @protected
+  AnnouncementKind dco_decode_announcement_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnnouncementKind.values[raw as int];
+  }
+
+  @protected
+  AutoAcceptConfig dco_decode_auto_accept_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AutoAcceptConfig(
+      directory: dco_decode_String(arr[0]),
+      extensions: dco_decode_list_String(arr[1]),
+      collision: dco_decode_collision_policy(arr[2]),
+    );
+  }
+
   @protected
   bool dco_decode_bool(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1994,6 +4309,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(raw);
   }
 
+  @protected
+  AutoAcceptConfig dco_decode_box_autoadd_auto_accept_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_auto_accept_config(raw);
+  }
+
   @protected
   ClientInfo dco_decode_box_autoadd_client_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2018,6 +4339,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_expecting_public_key(raw);
   }
 
+  @protected
+  FavoriteDevice dco_decode_box_autoadd_favorite_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_favorite_device(raw);
+  }
+
   @protected
   FileDto dco_decode_box_autoadd_file_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2036,6 +4363,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as int;
   }
 
+  @protected
+  MediaInfo dco_decode_box_autoadd_media_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_media_info(raw);
+  }
+
   @protected
   PinConfig dco_decode_box_autoadd_pin_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2060,6 +4393,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_proposing_client_info(raw);
   }
 
+  @protected
+  ReceiveBufferConfig dco_decode_box_autoadd_receive_buffer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_receive_buffer_config(raw);
+  }
+
   @protected
   RegisterDto dco_decode_box_autoadd_register_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2072,24 +4411,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_register_dto_v_2(raw);
   }
 
+  @protected
+  ResumeSession dco_decode_box_autoadd_resume_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_resume_session(raw);
+  }
+
   @protected
   RTCSendFileResponse dco_decode_box_autoadd_rtc_send_file_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_rtc_send_file_response(raw);
   }
 
+  @protected
+  RTCStatus dco_decode_box_autoadd_rtc_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_rtc_status(raw);
+  }
+
   @protected
   TlsConfig dco_decode_box_autoadd_tls_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_tls_config(raw);
   }
 
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw as int;
   }
 
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
   @protected
   WebSendParams dco_decode_box_autoadd_web_send_params(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2106,7 +4469,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ClientInfo dco_decode_client_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
     return ClientInfo(
       id: dco_decode_Uuid(arr[0]),
       alias: dco_decode_String(arr[1]),
@@ -2114,6 +4477,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       deviceModel: dco_decode_opt_String(arr[3]),
       deviceType: dco_decode_opt_box_autoadd_device_type(arr[4]),
       token: dco_decode_String(arr[5]),
+      capabilities: dco_decode_u_32(arr[6]),
     );
   }
 
@@ -2121,16 +4485,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   ClientInfoWithoutId dco_decode_client_info_without_id(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
     return ClientInfoWithoutId(
       alias: dco_decode_String(arr[0]),
       version: dco_decode_String(arr[1]),
       deviceModel: dco_decode_opt_String(arr[2]),
       deviceType: dco_decode_opt_box_autoadd_device_type(arr[3]),
       token: dco_decode_String(arr[4]),
+      capabilities: dco_decode_u_32(arr[5]),
     );
   }
 
+  @protected
+  CollisionPolicy dco_decode_collision_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CollisionPolicy.values[raw as int];
+  }
+
   @protected
   DeviceType dco_decode_device_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2154,6 +4525,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  FavoriteDevice dco_decode_favorite_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FavoriteDevice(
+      fingerprint: dco_decode_String(arr[0]),
+      alias: dco_decode_String(arr[1]),
+      lastSeen: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      notes: dco_decode_String(arr[3]),
+    );
+  }
+
   @protected
   FileDto dco_decode_file_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2174,10 +4558,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   FileMetadata dco_decode_file_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
     return FileMetadata(
       modified: dco_decode_opt_String(arr[0]),
       accessed: dco_decode_opt_String(arr[1]),
+      mode: dco_decode_opt_box_autoadd_u_32(arr[2]),
+      media: dco_decode_opt_box_autoadd_media_info(arr[3]),
     );
   }
 
@@ -2193,6 +4579,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeI64(raw);
   }
 
+  @protected
+  InferredMimeType dco_decode_inferred_mime_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return InferredMimeType(
+      mimeType: dco_decode_String(arr[0]),
+      mismatch: dco_decode_bool(arr[1]),
+    );
+  }
+
   @protected
   KeyPair dco_decode_key_pair(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2216,6 +4613,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_client_info).toList();
   }
 
+  @protected
+  List<FavoriteDevice> dco_decode_list_favorite_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_favorite_device).toList();
+  }
+
   @protected
   List<FileDto> dco_decode_list_file_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2235,21 +4638,77 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  List<(String, FileDto)> dco_decode_list_record_string_file_dto(dynamic raw) {
+  List<(String, FileDto)> dco_decode_list_record_string_file_dto(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_file_dto).toList();
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<ResumeFile> dco_decode_list_resume_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_resume_file).toList();
+  }
+
+  @protected
+  List<ResumeSession> dco_decode_list_resume_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_resume_session).toList();
+  }
+
+  @protected
+  List<ScannedFile> dco_decode_list_scanned_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_scanned_file).toList();
+  }
+
+  @protected
+  List<SessionInfo> dco_decode_list_session_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_session_info).toList();
+  }
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LogEntry(
+      timestampMs: dco_decode_u_64(arr[0]),
+      level: dco_decode_log_level(arr[1]),
+      target: dco_decode_String(arr[2]),
+      message: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_file_dto).toList();
+    return LogLevel.values[raw as int];
   }
 
   @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+  LsHttpClientVersion dco_decode_ls_http_client_version(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+    return LsHttpClientVersion.values[raw as int];
   }
 
   @protected
-  LsHttpClientVersion dco_decode_ls_http_client_version(dynamic raw) {
+  MediaInfo dco_decode_media_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return LsHttpClientVersion.values[raw as int];
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MediaInfo(
+      width: dco_decode_opt_box_autoadd_u_32(arr[0]),
+      height: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      orientation: dco_decode_opt_box_autoadd_u_16(arr[2]),
+      durationMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+    );
   }
 
   @protected
@@ -2267,6 +4726,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(raw);
   }
 
+  @protected
+  AutoAcceptConfig? dco_decode_opt_box_autoadd_auto_accept_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_auto_accept_config(raw);
+  }
+
   @protected
   DeviceType? dco_decode_opt_box_autoadd_device_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2279,6 +4744,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_expecting_public_key(raw);
   }
 
+  @protected
+  FavoriteDevice? dco_decode_opt_box_autoadd_favorite_device(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_favorite_device(raw);
+  }
+
   @protected
   FileMetadata? dco_decode_opt_box_autoadd_file_metadata(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2291,6 +4762,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
   }
 
+  @protected
+  MediaInfo? dco_decode_opt_box_autoadd_media_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_media_info(raw);
+  }
+
   @protected
   PinConfig? dco_decode_opt_box_autoadd_pin_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2303,18 +4780,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_prepare_upload_response_dto(raw);
   }
 
+  @protected
+  ReceiveBufferConfig? dco_decode_opt_box_autoadd_receive_buffer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_receive_buffer_config(raw);
+  }
+
+  @protected
+  ResumeSession? dco_decode_opt_box_autoadd_resume_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_resume_session(raw);
+  }
+
+  @protected
+  RTCStatus? dco_decode_opt_box_autoadd_rtc_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_rtc_status(raw);
+  }
+
   @protected
   TlsConfig? dco_decode_opt_box_autoadd_tls_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_tls_config(raw);
   }
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
   }
 
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
   @protected
   WebSendParams? dco_decode_opt_box_autoadd_web_send_params(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2412,6 +4919,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ReceiveBufferConfig dco_decode_receive_buffer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReceiveBufferConfig(
+      targetSize: dco_decode_u_32(arr[0]),
+      flushIntervalMs: dco_decode_opt_box_autoadd_u_32(arr[1]),
+    );
+  }
+
   @protected
   (String, FileDto) dco_decode_record_string_file_dto(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2498,6 +5016,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  ResumeDirection dco_decode_resume_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ResumeDirection.values[raw as int];
+  }
+
+  @protected
+  ResumeFile dco_decode_resume_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ResumeFile(
+      fileId: dco_decode_String(arr[0]),
+      token: dco_decode_opt_String(arr[1]),
+      path: dco_decode_String(arr[2]),
+      size: dco_decode_u_64(arr[3]),
+      bytesCompleted: dco_decode_u_64(arr[4]),
+      partialSha256: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  ResumeSession dco_decode_resume_session(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ResumeSession(
+      sessionId: dco_decode_String(arr[0]),
+      peerFingerprint: dco_decode_String(arr[1]),
+      peerAlias: dco_decode_String(arr[2]),
+      direction: dco_decode_resume_direction(arr[3]),
+      files: dco_decode_list_resume_file(arr[4]),
+      updatedAt: dco_decode_u_64(arr[5]),
+    );
+  }
+
   @protected
   RsHttpClientError dco_decode_rs_http_client_error(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2597,6 +5151,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RTCFileProgress dco_decode_rtc_file_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return RTCFileProgress(
+      fileId: dco_decode_String(arr[0]),
+      bytes: dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  RTCReceivedFile dco_decode_rtc_received_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RTCReceivedFile(
+      size: dco_decode_u_64(arr[0]),
+      sha256: dco_decode_opt_String(arr[1]),
+      path: dco_decode_opt_String(arr[2]),
+    );
+  }
+
   @protected
   RTCSendFileResponse dco_decode_rtc_send_file_response(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2609,6 +5186,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  RTCStats dco_decode_rtc_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RTCStats(
+      bytesPerSecond: dco_decode_u_64(arr[0]),
+      roundTripTimeMs: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      relayed: dco_decode_bool(arr[2]),
+    );
+  }
+
   @protected
   RTCStatus dco_decode_rtc_status(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2631,17 +5220,59 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return RTCStatus_Error(
           dco_decode_String(raw[1]),
         );
+      case 8:
+        return RTCStatus_Timeout();
+      case 9:
+        return RTCStatus_Paused();
+      case 10:
+        return RTCStatus_Resumed();
       default:
         throw Exception('unreachable');
     }
   }
 
+  @protected
+  ScannedFile dco_decode_scanned_file(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ScannedFile(
+      path: dco_decode_String(arr[0]),
+      file: dco_decode_file_dto(arr[1]),
+    );
+  }
+
+  @protected
+  SessionDirection dco_decode_session_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SessionDirection.values[raw as int];
+  }
+
   @protected
   SessionEndReasonV2 dco_decode_session_end_reason_v_2(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return SessionEndReasonV2.values[raw as int];
   }
 
+  @protected
+  SessionInfo dco_decode_session_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SessionInfo(
+      sessionId: dco_decode_String(arr[0]),
+      direction: dco_decode_session_direction(arr[1]),
+      status: dco_decode_opt_box_autoadd_rtc_status(arr[2]),
+      active: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  SymlinkPolicy dco_decode_symlink_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SymlinkPolicy.values[raw as int];
+  }
+
   @protected
   TlsConfig dco_decode_tls_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2653,6 +5284,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TrustStatus dco_decode_trust_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TrustStatus.values[raw as int];
+  }
+
   @protected
   int dco_decode_u_16(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -2726,6 +5363,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return WsServerMessage_Hello(
           client: dco_decode_box_autoadd_client_info(raw[1]),
           peers: dco_decode_list_client_info(raw[2]),
+          resumeToken: dco_decode_opt_String(raw[3]),
+          room: dco_decode_opt_String(raw[4]),
         );
       case 1:
         return WsServerMessage_Join(
@@ -2751,6 +5390,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return WsServerMessage_Error(
           code: dco_decode_u_16(raw[1]),
         );
+      case 7:
+        return WsServerMessage_Announcement(
+          kind: dco_decode_announcement_kind(raw[1]),
+          message: dco_decode_String(raw[2]),
+        );
       default:
         throw Exception('unreachable');
     }
@@ -2835,6 +5479,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsCancellationTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  RsFavoriteStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsFavoriteStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   RsHttpClient sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2848,19 +5498,35 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  Dart2RustStreamSink sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(
-    SseDeserializer deserializer,
-  ) {
+  RsResumeStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return Dart2RustStreamSinkImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    return RsResumeStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RsSettingsStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsSettingsStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RsTrustStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsTrustStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SessionManager sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SessionManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
   @protected
-  RtcSendController sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(
+  Dart2RustStreamSink sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(
     SseDeserializer deserializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return RtcSendControllerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+    return Dart2RustStreamSinkImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
   @protected
@@ -2907,6 +5573,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsCancellationTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  RsFavoriteStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsFavoriteStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   RsHttpClient sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2919,6 +5591,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsHttpServerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  RsResumeStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsResumeStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RsSettingsStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsSettingsStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RsTrustStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsTrustStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SessionManager sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SessionManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   Object sse_decode_DartOpaque(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2990,6 +5686,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsCancellationTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  RsFavoriteStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsFavoriteStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   RsHttpClient sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3002,6 +5704,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RsHttpServerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
   }
 
+  @protected
+  RsResumeStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsResumeStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RsSettingsStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsSettingsStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  RsTrustStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return RsTrustStoreImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  SessionManager sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SessionManagerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer));
+  }
+
   @protected
   Set<String> sse_decode_Set_String_None(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3029,6 +5755,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<List<ClientInfo>> sse_decode_StreamSink_list_client_info_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<RsServerEvent> sse_decode_StreamSink_rs_server_event_Sse(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3041,6 +5779,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     throw UnimplementedError('Unreachable ()');
   }
 
+  @protected
+  RustStreamSink<RTCFileProgress> sse_decode_StreamSink_rtc_file_progress_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<RTCSendFileResponse> sse_decode_StreamSink_rtc_send_file_response_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<RTCStats> sse_decode_StreamSink_rtc_stats_Sse(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   RustStreamSink<RTCStatus> sse_decode_StreamSink_rtc_status_Sse(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3067,6 +5823,22 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return UuidValue.fromByteList(inner);
   }
 
+  @protected
+  AnnouncementKind sse_decode_announcement_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AnnouncementKind.values[inner];
+  }
+
+  @protected
+  AutoAcceptConfig sse_decode_auto_accept_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_directory = sse_decode_String(deserializer);
+    var var_extensions = sse_decode_list_String(deserializer);
+    var var_collision = sse_decode_collision_policy(deserializer);
+    return AutoAcceptConfig(directory: var_directory, extensions: var_extensions, collision: var_collision);
+  }
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3081,6 +5853,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(deserializer));
   }
 
+  @protected
+  AutoAcceptConfig sse_decode_box_autoadd_auto_accept_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_auto_accept_config(deserializer));
+  }
+
   @protected
   ClientInfo sse_decode_box_autoadd_client_info(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3105,6 +5883,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_expecting_public_key(deserializer));
   }
 
+  @protected
+  FavoriteDevice sse_decode_box_autoadd_favorite_device(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_favorite_device(deserializer));
+  }
+
   @protected
   FileDto sse_decode_box_autoadd_file_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3123,6 +5907,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_i_32(deserializer));
   }
 
+  @protected
+  MediaInfo sse_decode_box_autoadd_media_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_media_info(deserializer));
+  }
+
   @protected
   PinConfig sse_decode_box_autoadd_pin_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3147,6 +5937,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_proposing_client_info(deserializer));
   }
 
+  @protected
+  ReceiveBufferConfig sse_decode_box_autoadd_receive_buffer_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_receive_buffer_config(deserializer));
+  }
+
   @protected
   RegisterDto sse_decode_box_autoadd_register_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3159,24 +5955,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_register_dto_v_2(deserializer));
   }
 
+  @protected
+  ResumeSession sse_decode_box_autoadd_resume_session(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_resume_session(deserializer));
+  }
+
   @protected
   RTCSendFileResponse sse_decode_box_autoadd_rtc_send_file_response(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_rtc_send_file_response(deserializer));
   }
 
+  @protected
+  RTCStatus sse_decode_box_autoadd_rtc_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_rtc_status(deserializer));
+  }
+
   @protected
   TlsConfig sse_decode_box_autoadd_tls_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_tls_config(deserializer));
   }
 
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     return (sse_decode_u_32(deserializer));
   }
 
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
   @protected
   WebSendParams sse_decode_box_autoadd_web_send_params(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3198,7 +6018,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_deviceModel = sse_decode_opt_String(deserializer);
     var var_deviceType = sse_decode_opt_box_autoadd_device_type(deserializer);
     var var_token = sse_decode_String(deserializer);
-    return ClientInfo(id: var_id, alias: var_alias, version: var_version, deviceModel: var_deviceModel, deviceType: var_deviceType, token: var_token);
+    var var_capabilities = sse_decode_u_32(deserializer);
+    return ClientInfo(
+        id: var_id,
+        alias: var_alias,
+        version: var_version,
+        deviceModel: var_deviceModel,
+        deviceType: var_deviceType,
+        token: var_token,
+        capabilities: var_capabilities);
   }
 
   @protected
@@ -3209,7 +6037,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_deviceModel = sse_decode_opt_String(deserializer);
     var var_deviceType = sse_decode_opt_box_autoadd_device_type(deserializer);
     var var_token = sse_decode_String(deserializer);
-    return ClientInfoWithoutId(alias: var_alias, version: var_version, deviceModel: var_deviceModel, deviceType: var_deviceType, token: var_token);
+    var var_capabilities = sse_decode_u_32(deserializer);
+    return ClientInfoWithoutId(
+        alias: var_alias, version: var_version, deviceModel: var_deviceModel, deviceType: var_deviceType, token: var_token, capabilities: var_capabilities);
+  }
+
+  @protected
+  CollisionPolicy sse_decode_collision_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CollisionPolicy.values[inner];
   }
 
   @protected
@@ -3233,6 +6070,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  FavoriteDevice sse_decode_favorite_device(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fingerprint = sse_decode_String(deserializer);
+    var var_alias = sse_decode_String(deserializer);
+    var var_lastSeen = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_notes = sse_decode_String(deserializer);
+    return FavoriteDevice(fingerprint: var_fingerprint, alias: var_alias, lastSeen: var_lastSeen, notes: var_notes);
+  }
+
   @protected
   FileDto sse_decode_file_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3259,7 +6106,9 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_modified = sse_decode_opt_String(deserializer);
     var var_accessed = sse_decode_opt_String(deserializer);
-    return FileMetadata(modified: var_modified, accessed: var_accessed);
+    var var_mode = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_media = sse_decode_opt_box_autoadd_media_info(deserializer);
+    return FileMetadata(modified: var_modified, accessed: var_accessed, mode: var_mode, media: var_media);
   }
 
   @protected
@@ -3274,6 +6123,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getPlatformInt64();
   }
 
+  @protected
+  InferredMimeType sse_decode_inferred_mime_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_mismatch = sse_decode_bool(deserializer);
+    return InferredMimeType(mimeType: var_mimeType, mismatch: var_mismatch);
+  }
+
   @protected
   KeyPair sse_decode_key_pair(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3306,6 +6163,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<FavoriteDevice> sse_decode_list_favorite_device(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FavoriteDevice>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_favorite_device(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<FileDto> sse_decode_list_file_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3356,6 +6225,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ResumeFile> sse_decode_list_resume_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ResumeFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_resume_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ResumeSession> sse_decode_list_resume_session(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ResumeSession>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_resume_session(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ScannedFile> sse_decode_list_scanned_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ScannedFile>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_scanned_file(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SessionInfo> sse_decode_list_session_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SessionInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_session_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timestampMs = sse_decode_u_64(deserializer);
+    var var_level = sse_decode_log_level(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return LogEntry(timestampMs: var_timestampMs, level: var_level, target: var_target, message: var_message);
+  }
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LogLevel.values[inner];
+  }
+
   @protected
   LsHttpClientVersion sse_decode_ls_http_client_version(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3363,6 +6297,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return LsHttpClientVersion.values[inner];
   }
 
+  @protected
+  MediaInfo sse_decode_media_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_width = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_height = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_orientation = sse_decode_opt_box_autoadd_u_16(deserializer);
+    var var_durationMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return MediaInfo(width: var_width, height: var_height, orientation: var_orientation, durationMs: var_durationMs);
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3388,6 +6332,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  AutoAcceptConfig? sse_decode_opt_box_autoadd_auto_accept_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_auto_accept_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   DeviceType? sse_decode_opt_box_autoadd_device_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3410,6 +6365,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  FavoriteDevice? sse_decode_opt_box_autoadd_favorite_device(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_favorite_device(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   FileMetadata? sse_decode_opt_box_autoadd_file_metadata(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3432,6 +6398,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  MediaInfo? sse_decode_opt_box_autoadd_media_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_media_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   PinConfig? sse_decode_opt_box_autoadd_pin_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3454,6 +6431,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  ReceiveBufferConfig? sse_decode_opt_box_autoadd_receive_buffer_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_receive_buffer_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ResumeSession? sse_decode_opt_box_autoadd_resume_session(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_resume_session(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RTCStatus? sse_decode_opt_box_autoadd_rtc_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_rtc_status(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   TlsConfig? sse_decode_opt_box_autoadd_tls_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3465,6 +6475,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3476,6 +6497,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   WebSendParams? sse_decode_opt_box_autoadd_web_send_params(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3565,6 +6597,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (var_field0, var_field1);
   }
 
+  @protected
+  ReceiveBufferConfig sse_decode_receive_buffer_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_targetSize = sse_decode_u_32(deserializer);
+    var var_flushIntervalMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return ReceiveBufferConfig(targetSize: var_targetSize, flushIntervalMs: var_flushIntervalMs);
+  }
+
   @protected
   (String, FileDto) sse_decode_record_string_file_dto(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3654,6 +6694,51 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ResultWithPublicKeyRegisterResponseDto(publicKey: var_publicKey, body: var_body);
   }
 
+  @protected
+  ResumeDirection sse_decode_resume_direction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ResumeDirection.values[inner];
+  }
+
+  @protected
+  ResumeFile sse_decode_resume_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fileId = sse_decode_String(deserializer);
+    var var_token = sse_decode_opt_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_bytesCompleted = sse_decode_u_64(deserializer);
+    var var_partialSha256 = sse_decode_opt_String(deserializer);
+    return ResumeFile(
+      fileId: var_fileId,
+      token: var_token,
+      path: var_path,
+      size: var_size,
+      bytesCompleted: var_bytesCompleted,
+      partialSha256: var_partialSha256,
+    );
+  }
+
+  @protected
+  ResumeSession sse_decode_resume_session(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sessionId = sse_decode_String(deserializer);
+    var var_peerFingerprint = sse_decode_String(deserializer);
+    var var_peerAlias = sse_decode_String(deserializer);
+    var var_direction = sse_decode_resume_direction(deserializer);
+    var var_files = sse_decode_list_resume_file(deserializer);
+    var var_updatedAt = sse_decode_u_64(deserializer);
+    return ResumeSession(
+      sessionId: var_sessionId,
+      peerFingerprint: var_peerFingerprint,
+      peerAlias: var_peerAlias,
+      direction: var_direction,
+      files: var_files,
+      updatedAt: var_updatedAt,
+    );
+  }
+
   @protected
   RsHttpClientError sse_decode_rs_http_client_error(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3746,6 +6831,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RTCFileError(fileId: var_fileId, error: var_error);
   }
 
+  @protected
+  RTCFileProgress sse_decode_rtc_file_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fileId = sse_decode_String(deserializer);
+    var var_bytes = sse_decode_u_64(deserializer);
+    return RTCFileProgress(fileId: var_fileId, bytes: var_bytes);
+  }
+
+  @protected
+  RTCReceivedFile sse_decode_rtc_received_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_size = sse_decode_u_64(deserializer);
+    var var_sha256 = sse_decode_opt_String(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    return RTCReceivedFile(size: var_size, sha256: var_sha256, path: var_path);
+  }
+
   @protected
   RTCSendFileResponse sse_decode_rtc_send_file_response(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3755,6 +6857,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return RTCSendFileResponse(id: var_id, success: var_success, error: var_error);
   }
 
+  @protected
+  RTCStats sse_decode_rtc_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytesPerSecond = sse_decode_u_64(deserializer);
+    var var_roundTripTimeMs = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_relayed = sse_decode_bool(deserializer);
+    return RTCStats(bytesPerSecond: var_bytesPerSecond, roundTripTimeMs: var_roundTripTimeMs, relayed: var_relayed);
+  }
+
   @protected
   RTCStatus sse_decode_rtc_status(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3778,11 +6889,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 7:
         var var_field0 = sse_decode_String(deserializer);
         return RTCStatus_Error(var_field0);
+      case 8:
+        return RTCStatus_Timeout();
+      case 9:
+        return RTCStatus_Paused();
+      case 10:
+        return RTCStatus_Resumed();
       default:
         throw UnimplementedError('');
     }
   }
 
+  @protected
+  ScannedFile sse_decode_scanned_file(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_file = sse_decode_file_dto(deserializer);
+    return ScannedFile(path: var_path, file: var_file);
+  }
+
+  @protected
+  SessionDirection sse_decode_session_direction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SessionDirection.values[inner];
+  }
+
   @protected
   SessionEndReasonV2 sse_decode_session_end_reason_v_2(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3790,6 +6922,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return SessionEndReasonV2.values[inner];
   }
 
+  @protected
+  SessionInfo sse_decode_session_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sessionId = sse_decode_String(deserializer);
+    var var_direction = sse_decode_session_direction(deserializer);
+    var var_status = sse_decode_opt_box_autoadd_rtc_status(deserializer);
+    var var_active = sse_decode_bool(deserializer);
+    return SessionInfo(sessionId: var_sessionId, direction: var_direction, status: var_status, active: var_active);
+  }
+
+  @protected
+  SymlinkPolicy sse_decode_symlink_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SymlinkPolicy.values[inner];
+  }
+
   @protected
   TlsConfig sse_decode_tls_config(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3798,6 +6947,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return TlsConfig(cert: var_cert, privateKey: var_privateKey);
   }
 
+  @protected
+  TrustStatus sse_decode_trust_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TrustStatus.values[inner];
+  }
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3874,7 +7030,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 0:
         var var_client = sse_decode_box_autoadd_client_info(deserializer);
         var var_peers = sse_decode_list_client_info(deserializer);
-        return WsServerMessage_Hello(client: var_client, peers: var_peers);
+        var var_resumeToken = sse_decode_opt_String(deserializer);
+        var var_room = sse_decode_opt_String(deserializer);
+        return WsServerMessage_Hello(
+          client: var_client,
+          peers: var_peers,
+          resumeToken: var_resumeToken,
+          room: var_room,
+        );
       case 1:
         var var_peer = sse_decode_box_autoadd_client_info(deserializer);
         return WsServerMessage_Join(peer: var_peer);
@@ -3893,6 +7056,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 6:
         var var_code = sse_decode_u_16(deserializer);
         return WsServerMessage_Error(code: var_code);
+      case 7:
+        var var_kind = sse_decode_announcement_kind(deserializer);
+        var var_message = sse_decode_String(deserializer);
+        return WsServerMessage_Announcement(kind: var_kind, message: var_message);
       default:
         throw UnimplementedError('');
     }
@@ -3985,6 +7152,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize((self as RsCancellationTokenImpl).frbInternalSseEncode(move: true), serializer);
   }
 
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(RsFavoriteStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsFavoriteStoreImpl).frbInternalSseEncode(move: true), serializer);
+  }
+
   @protected
   void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(RsHttpClient self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3998,21 +7171,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(
-    Dart2RustStreamSink self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(RsResumeStore self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as Dart2RustStreamSinkImpl).frbInternalSseEncode(move: false), serializer);
+    sse_encode_usize((self as RsResumeStoreImpl).frbInternalSseEncode(move: true), serializer);
   }
 
   @protected
-  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(
-    RtcSendController self,
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(RsSettingsStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsSettingsStoreImpl).frbInternalSseEncode(move: true), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(RsTrustStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsTrustStoreImpl).frbInternalSseEncode(move: true), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SessionManager self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as SessionManagerImpl).frbInternalSseEncode(move: true), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(
+    Dart2RustStreamSink self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize((self as RtcSendControllerImpl).frbInternalSseEncode(move: false), serializer);
+    sse_encode_usize((self as Dart2RustStreamSinkImpl).frbInternalSseEncode(move: false), serializer);
   }
 
   @protected
@@ -4066,6 +7254,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize((self as RsCancellationTokenImpl).frbInternalSseEncode(move: false), serializer);
   }
 
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(RsFavoriteStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsFavoriteStoreImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
   @protected
   void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(RsHttpClient self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4078,6 +7272,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize((self as RsHttpServerImpl).frbInternalSseEncode(move: false), serializer);
   }
 
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(RsResumeStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsResumeStoreImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(RsSettingsStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsSettingsStoreImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(RsTrustStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsTrustStoreImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SessionManager self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as SessionManagerImpl).frbInternalSseEncode(move: false), serializer);
+  }
+
   @protected
   void
   sse_encode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection_Output_unit_AnyhowException(
@@ -4093,6 +7311,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_DartFn_Inputs_f_64_Output_unit_AnyhowException(FutureOr<void> Function(double) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(encode_DartFn_Inputs_f_64_Output_unit_AnyhowException(self), serializer);
+  }
+
+  @protected
+  void sse_encode_DartFn_Inputs_session_info_Output_unit_AnyhowException(FutureOr<void> Function(SessionInfo) self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(encode_DartFn_Inputs_session_info_Output_unit_AnyhowException(self), serializer);
+  }
+
   @protected
   void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4177,6 +7407,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize((self as RsCancellationTokenImpl).frbInternalSseEncode(move: null), serializer);
   }
 
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(RsFavoriteStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsFavoriteStoreImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
   @protected
   void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(RsHttpClient self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4189,6 +7425,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize((self as RsHttpServerImpl).frbInternalSseEncode(move: null), serializer);
   }
 
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(RsResumeStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsResumeStoreImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(RsSettingsStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsSettingsStoreImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(RsTrustStore self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as RsTrustStoreImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SessionManager self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize((self as SessionManagerImpl).frbInternalSseEncode(move: null), serializer);
+  }
+
   @protected
   void sse_encode_Set_String_None(Set<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4240,6 +7500,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_list_client_info_Sse(RustStreamSink<List<ClientInfo>> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_client_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_log_entry_Sse(RustStreamSink<LogEntry> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_log_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_rs_server_event_Sse(RustStreamSink<RsServerEvent> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4268,6 +7556,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void sse_encode_StreamSink_rtc_file_progress_Sse(RustStreamSink<RTCFileProgress> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rtc_file_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_rtc_send_file_response_Sse(RustStreamSink<RTCSendFileResponse> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rtc_send_file_response,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_rtc_stats_Sse(RustStreamSink<RTCStats> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_rtc_stats,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
   @protected
   void sse_encode_StreamSink_rtc_status_Sse(RustStreamSink<RTCStatus> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4308,6 +7638,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(self.toBytes(), serializer);
   }
 
+  @protected
+  void sse_encode_announcement_kind(AnnouncementKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_auto_accept_config(AutoAcceptConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.directory, serializer);
+    sse_encode_list_String(self.extensions, serializer);
+    sse_encode_collision_policy(self.collision, serializer);
+  }
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4323,6 +7667,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_auto_accept_config(AutoAcceptConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_auto_accept_config(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_client_info(ClientInfo self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4347,6 +7697,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_expecting_public_key(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_favorite_device(FavoriteDevice self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_favorite_device(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_file_dto(FileDto self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4365,6 +7721,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_i_32(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_media_info(MediaInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_media_info(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_pin_config(PinConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4389,6 +7751,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_proposing_client_info(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_receive_buffer_config(ReceiveBufferConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_receive_buffer_config(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_register_dto(RegisterDto self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4401,24 +7769,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_register_dto_v_2(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_resume_session(ResumeSession self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_resume_session(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_rtc_send_file_response(RTCSendFileResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_rtc_send_file_response(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_rtc_status(RTCStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rtc_status(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_tls_config(TlsConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_tls_config(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_u_32(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_web_send_params(WebSendParams self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4440,6 +7832,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.deviceModel, serializer);
     sse_encode_opt_box_autoadd_device_type(self.deviceType, serializer);
     sse_encode_String(self.token, serializer);
+    sse_encode_u_32(self.capabilities, serializer);
   }
 
   @protected
@@ -4450,6 +7843,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.deviceModel, serializer);
     sse_encode_opt_box_autoadd_device_type(self.deviceType, serializer);
     sse_encode_String(self.token, serializer);
+    sse_encode_u_32(self.capabilities, serializer);
+  }
+
+  @protected
+  void sse_encode_collision_policy(CollisionPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -4471,6 +7871,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_favorite_device(FavoriteDevice self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fingerprint, serializer);
+    sse_encode_String(self.alias, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.lastSeen, serializer);
+    sse_encode_String(self.notes, serializer);
+  }
+
   @protected
   void sse_encode_file_dto(FileDto self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4488,6 +7897,8 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_opt_String(self.modified, serializer);
     sse_encode_opt_String(self.accessed, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.mode, serializer);
+    sse_encode_opt_box_autoadd_media_info(self.media, serializer);
   }
 
   @protected
@@ -4502,6 +7913,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putPlatformInt64(self);
   }
 
+  @protected
+  void sse_encode_inferred_mime_type(InferredMimeType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_bool(self.mismatch, serializer);
+  }
+
   @protected
   void sse_encode_key_pair(KeyPair self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4527,6 +7945,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_favorite_device(List<FavoriteDevice> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_favorite_device(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_file_dto(List<FileDto> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4569,101 +7996,231 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_ls_http_client_version(LsHttpClientVersion self, SseSerializer serializer) {
+  void sse_encode_list_resume_file(List<ResumeFile> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_resume_file(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_resume_session(List<ResumeSession> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_resume_session(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_scanned_file(List<ScannedFile> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_scanned_file(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_session_info(List<SessionInfo> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_session_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.timestampMs, serializer);
+    sse_encode_log_level(self.level, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_ls_http_client_version(LsHttpClientVersion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_media_info(MediaInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_32(self.width, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.height, serializer);
+    sse_encode_opt_box_autoadd_u_16(self.orientation, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.durationMs, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(
+    Dart2RustStreamReceiver? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_auto_accept_config(AutoAcceptConfig? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_auto_accept_config(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_device_type(DeviceType? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_device_type(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_expecting_public_key(ExpectingPublicKey? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_expecting_public_key(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_favorite_device(FavoriteDevice? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_favorite_device(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_file_metadata(FileMetadata? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_file_metadata(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_box_autoadd_i_32(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(
-    Dart2RustStreamReceiver? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_media_info(MediaInfo? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(self, serializer);
+      sse_encode_box_autoadd_media_info(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_device_type(DeviceType? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_pin_config(PinConfig? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_device_type(self, serializer);
+      sse_encode_box_autoadd_pin_config(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_expecting_public_key(ExpectingPublicKey? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_prepare_upload_response_dto(PrepareUploadResponseDto? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_expecting_public_key(self, serializer);
+      sse_encode_box_autoadd_prepare_upload_response_dto(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_file_metadata(FileMetadata? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_receive_buffer_config(ReceiveBufferConfig? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_file_metadata(self, serializer);
+      sse_encode_box_autoadd_receive_buffer_config(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_resume_session(ResumeSession? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_i_32(self, serializer);
+      sse_encode_box_autoadd_resume_session(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_pin_config(PinConfig? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_rtc_status(RTCStatus? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_pin_config(self, serializer);
+      sse_encode_box_autoadd_rtc_status(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_prepare_upload_response_dto(PrepareUploadResponseDto? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_tls_config(TlsConfig? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_prepare_upload_response_dto(self, serializer);
+      sse_encode_box_autoadd_tls_config(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_tls_config(TlsConfig? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_box_autoadd_tls_config(self, serializer);
+      sse_encode_box_autoadd_u_16(self, serializer);
     }
   }
 
@@ -4677,6 +8234,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_web_send_params(WebSendParams? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4757,6 +8324,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(self.$2, serializer);
   }
 
+  @protected
+  void sse_encode_receive_buffer_config(ReceiveBufferConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.targetSize, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.flushIntervalMs, serializer);
+  }
+
   @protected
   void sse_encode_record_string_file_dto((String, FileDto) self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4815,6 +8389,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_register_response_dto(self.body, serializer);
   }
 
+  @protected
+  void sse_encode_resume_direction(ResumeDirection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_resume_file(ResumeFile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fileId, serializer);
+    sse_encode_opt_String(self.token, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_u_64(self.bytesCompleted, serializer);
+    sse_encode_opt_String(self.partialSha256, serializer);
+  }
+
+  @protected
+  void sse_encode_resume_session(ResumeSession self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sessionId, serializer);
+    sse_encode_String(self.peerFingerprint, serializer);
+    sse_encode_String(self.peerAlias, serializer);
+    sse_encode_resume_direction(self.direction, serializer);
+    sse_encode_list_resume_file(self.files, serializer);
+    sse_encode_u_64(self.updatedAt, serializer);
+  }
+
   @protected
   void sse_encode_rs_http_client_error(RsHttpClientError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4898,6 +8500,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.error, serializer);
   }
 
+  @protected
+  void sse_encode_rtc_file_progress(RTCFileProgress self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.fileId, serializer);
+    sse_encode_u_64(self.bytes, serializer);
+  }
+
+  @protected
+  void sse_encode_rtc_received_file(RTCReceivedFile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_opt_String(self.sha256, serializer);
+    sse_encode_opt_String(self.path, serializer);
+  }
+
   @protected
   void sse_encode_rtc_send_file_response(RTCSendFileResponse self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4906,6 +8523,14 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.error, serializer);
   }
 
+  @protected
+  void sse_encode_rtc_stats(RTCStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.bytesPerSecond, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.roundTripTimeMs, serializer);
+    sse_encode_bool(self.relayed, serializer);
+  }
+
   @protected
   void sse_encode_rtc_status(RTCStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4927,15 +8552,49 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case RTCStatus_Error(field0: final field0):
         sse_encode_i_32(7, serializer);
         sse_encode_String(field0, serializer);
+      case RTCStatus_Timeout():
+        sse_encode_i_32(8, serializer);
+      case RTCStatus_Paused():
+        sse_encode_i_32(9, serializer);
+      case RTCStatus_Resumed():
+        sse_encode_i_32(10, serializer);
     }
   }
 
+  @protected
+  void sse_encode_scanned_file(ScannedFile self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_file_dto(self.file, serializer);
+  }
+
+  @protected
+  void sse_encode_session_direction(SessionDirection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_session_end_reason_v_2(SessionEndReasonV2 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_session_info(SessionInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sessionId, serializer);
+    sse_encode_session_direction(self.direction, serializer);
+    sse_encode_opt_box_autoadd_rtc_status(self.status, serializer);
+    sse_encode_bool(self.active, serializer);
+  }
+
+  @protected
+  void sse_encode_symlink_policy(SymlinkPolicy self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_tls_config(TlsConfig self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4943,6 +8602,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.privateKey, serializer);
   }
 
+  @protected
+  void sse_encode_trust_status(TrustStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -5003,10 +8668,17 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   void sse_encode_ws_server_message(WsServerMessage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     switch (self) {
-      case WsServerMessage_Hello(client: final client, peers: final peers):
+      case WsServerMessage_Hello(
+        client: final client,
+        peers: final peers,
+        resumeToken: final resumeToken,
+        room: final room,
+      ):
         sse_encode_i_32(0, serializer);
         sse_encode_box_autoadd_client_info(client, serializer);
         sse_encode_list_client_info(peers, serializer);
+        sse_encode_opt_String(resumeToken, serializer);
+        sse_encode_opt_String(room, serializer);
       case WsServerMessage_Join(peer: final peer):
         sse_encode_i_32(1, serializer);
         sse_encode_box_autoadd_client_info(peer, serializer);
@@ -5025,6 +8697,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case WsServerMessage_Error(code: final code):
         sse_encode_i_32(6, serializer);
         sse_encode_u_16(code, serializer);
+      case WsServerMessage_Announcement(kind: final kind, message: final message):
+        sse_encode_i_32(7, serializer);
+        sse_encode_announcement_kind(kind, serializer);
+        sse_encode_String(message, serializer);
     }
   }
 
@@ -5097,6 +8773,7 @@ class LsSignalingConnectionImpl extends RustOpaque implements LsSignalingConnect
     required String privateKey,
     ExpectingPublicKey? expectingPublicKey,
     PinConfig? pin,
+    AutoAcceptConfig? autoAccept,
   }) => RustLib.instance.api.crateApiWebrtcLsSignalingConnectionAcceptOffer(
     that: this,
     stunServers: stunServers,
@@ -5104,6 +8781,14 @@ class LsSignalingConnectionImpl extends RustOpaque implements LsSignalingConnect
     privateKey: privateKey,
     expectingPublicKey: expectingPublicKey,
     pin: pin,
+    autoAccept: autoAccept,
+  );
+
+  /// Emits the peers in the room whenever they change, starting with the current ones.
+  /// Peers with the same token are listed once, e.g. a client that reconnected
+  /// before its previous session was closed by the server.
+  Stream<List<ClientInfo>> listenPeers() => RustLib.instance.api.crateApiWebrtcLsSignalingConnectionListenPeers(
+    that: this,
   );
 
   Future<RtcSendController> sendOffer({
@@ -5147,6 +8832,43 @@ class RsCancellationTokenImpl extends RustOpaque implements RsCancellationToken
   );
 }
 
+@sealed
+class RsFavoriteStoreImpl extends RustOpaque implements RsFavoriteStore {
+  // Not to be used by end users
+  RsFavoriteStoreImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  RsFavoriteStoreImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RsFavoriteStore,
+    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RsFavoriteStore,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RsFavoriteStorePtr,
+  );
+
+  /// Marks a discovered peer as favorite, keeping the notes if `notes` is `None`.
+  Future<FavoriteDevice> addPeer({required ClientInfo peer, String? notes}) =>
+      RustLib.instance.api.crateApiFavoritesRsFavoriteStoreAddPeer(that: this, peer: peer, notes: notes);
+
+  FavoriteDevice? get({required String fingerprint}) =>
+      RustLib.instance.api.crateApiFavoritesRsFavoriteStoreGet(that: this, fingerprint: fingerprint);
+
+  /// All favorites sorted by alias.
+  List<FavoriteDevice> list() => RustLib.instance.api.crateApiFavoritesRsFavoriteStoreList(that: this);
+
+  /// Returns the favorite matching a peer of [crate::api::webrtc::WsServerMessage::Joined]
+  /// or [crate::api::webrtc::WsServerMessage::Hello] and records that it was seen.
+  Future<FavoriteDevice?> matchPeer({required ClientInfo peer}) =>
+      RustLib.instance.api.crateApiFavoritesRsFavoriteStoreMatchPeer(that: this, peer: peer);
+
+  Future<void> remove({required String fingerprint}) =>
+      RustLib.instance.api.crateApiFavoritesRsFavoriteStoreRemove(that: this, fingerprint: fingerprint);
+
+  Future<void> setNotes({required String fingerprint, required String notes}) =>
+      RustLib.instance.api.crateApiFavoritesRsFavoriteStoreSetNotes(that: this, fingerprint: fingerprint, notes: notes);
+}
+
 @sealed
 class RsHttpClientImpl extends RustOpaque implements RsHttpClient {
   // Not to be used by end users
@@ -5278,6 +9000,9 @@ class RsHttpServerImpl extends RustOpaque implements RsHttpServer {
   /// the file should be saved to (either a path or a file descriptor)
   /// and waits until the file has been received completely.
   ///
+  /// The destination passed to [RsHttpServer::respond_prepare_upload] for the file,
+  /// if any, is applied to [path].
+  ///
   /// The progress (fraction of [file_size]) is emitted on [sink]
   /// while the file is being received.
   Stream<double> respondFileUpload({
@@ -5305,8 +9030,13 @@ class RsHttpServerImpl extends RustOpaque implements RsHttpServer {
   ///
   /// Passing the accepted file IDs (a subset of the offered files) accepts the request.
   /// Passing `None` declines the request.
-  Future<void> respondPrepareUpload({List<String>? acceptedFileIds}) =>
-      RustLib.instance.api.crateApiServerRsHttpServerRespondPrepareUpload(that: this, acceptedFileIds: acceptedFileIds);
+  ///
+  /// `destinations` maps accepted file IDs to a new name or path chosen by the user,
+  /// which [RsHttpServer::respond_file_upload] applies to the path it is given.
+  /// A name or relative path is resolved against the directory of that path,
+  /// an absolute path replaces it.
+  Future<void> respondPrepareUpload({List<String>? acceptedFileIds, required Map<String, String> destinations}) => RustLib.instance.api
+      .crateApiServerRsHttpServerRespondPrepareUpload(that: this, acceptedFileIds: acceptedFileIds, destinations: destinations);
 
   /// Stops the server.
   /// Returns after the listeners are closed, so the port can be bound again.
@@ -5315,6 +9045,121 @@ class RsHttpServerImpl extends RustOpaque implements RsHttpServer {
   );
 }
 
+@sealed
+class RsResumeStoreImpl extends RustOpaque implements RsResumeStore {
+  // Not to be used by end users
+  RsResumeStoreImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  RsResumeStoreImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RsResumeStore,
+    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RsResumeStore,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RsResumeStorePtr,
+  );
+
+  ResumeSession? get({required String sessionId}) => RustLib.instance.api.crateApiResumeRsResumeStoreGet(that: this, sessionId: sessionId);
+
+  /// All sessions, most recently updated first.
+  List<ResumeSession> list() => RustLib.instance.api.crateApiResumeRsResumeStoreList(that: this);
+
+  /// The sessions with a peer, e.g. to offer resuming them once it is discovered.
+  List<ResumeSession> listForPeer({required String fingerprint}) =>
+      RustLib.instance.api.crateApiResumeRsResumeStoreListForPeer(that: this, fingerprint: fingerprint);
+
+  Future<void> remove({required String sessionId}) => RustLib.instance.api.crateApiResumeRsResumeStoreRemove(that: this, sessionId: sessionId);
+
+  /// Adds or replaces the session and returns it with the updated timestamp.
+  Future<ResumeSession> save({required ResumeSession session}) => RustLib.instance.api.crateApiResumeRsResumeStoreSave(that: this, session: session);
+
+  /// Records the progress of a file of a saved session.
+  Future<void> setProgress({required String sessionId, required String fileId, required BigInt bytesCompleted, String? partialSha256}) => RustLib.instance.api.crateApiResumeRsResumeStoreSetProgress(
+    that: this,
+    sessionId: sessionId,
+    fileId: fileId,
+    bytesCompleted: bytesCompleted,
+    partialSha256: partialSha256,
+  );
+}
+
+@sealed
+class RsSettingsStoreImpl extends RustOpaque implements RsSettingsStore {
+  // Not to be used by end users
+  RsSettingsStoreImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  RsSettingsStoreImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RsSettingsStore,
+    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RsSettingsStore,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RsSettingsStorePtr,
+  );
+
+  String? alias() => RustLib.instance.api.crateApiSettingsRsSettingsStoreAlias(
+    that: this,
+  );
+
+  /// Maximum transfer speed in KiB per second. Unlimited if `None`.
+  int? bandwidthLimit() => RustLib.instance.api.crateApiSettingsRsSettingsStoreBandwidthLimit(
+    that: this,
+  );
+
+  String? downloadDir() => RustLib.instance.api.crateApiSettingsRsSettingsStoreDownloadDir(
+    that: this,
+  );
+
+  String? pin() => RustLib.instance.api.crateApiSettingsRsSettingsStorePin(
+    that: this,
+  );
+
+  Future<void> setAlias({String? alias}) => RustLib.instance.api.crateApiSettingsRsSettingsStoreSetAlias(that: this, alias: alias);
+
+  Future<void> setBandwidthLimit({int? bandwidthLimit}) =>
+      RustLib.instance.api.crateApiSettingsRsSettingsStoreSetBandwidthLimit(that: this, bandwidthLimit: bandwidthLimit);
+
+  Future<void> setDownloadDir({String? downloadDir}) =>
+      RustLib.instance.api.crateApiSettingsRsSettingsStoreSetDownloadDir(that: this, downloadDir: downloadDir);
+
+  Future<void> setPin({String? pin}) => RustLib.instance.api.crateApiSettingsRsSettingsStoreSetPin(that: this, pin: pin);
+}
+
+@sealed
+class RsTrustStoreImpl extends RustOpaque implements RsTrustStore {
+  // Not to be used by end users
+  RsTrustStoreImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  RsTrustStoreImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_RsTrustStore,
+    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_RsTrustStore,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RsTrustStorePtr,
+  );
+
+  TrustStatus check({required String alias, required String fingerprint}) =>
+      RustLib.instance.api.crateApiTrustRsTrustStoreCheck(that: this, alias: alias, fingerprint: fingerprint);
+
+  /// Checks the sender of a [crate::api::server::RsServerEvent::PrepareUpload].
+  /// The fingerprint of the client certificate is preferred over the announced one.
+  TrustStatus checkOffer({required RegisterDtoV2 info, String? certFingerprint}) =>
+      RustLib.instance.api.crateApiTrustRsTrustStoreCheckOffer(that: this, info: info, certFingerprint: certFingerprint);
+
+  /// The pinned fingerprint of the device, if any.
+  String? fingerprint({required String alias}) => RustLib.instance.api.crateApiTrustRsTrustStoreFingerprint(that: this, alias: alias);
+
+  Future<void> forget({required String alias}) => RustLib.instance.api.crateApiTrustRsTrustStoreForget(that: this, alias: alias);
+
+  /// Pins the fingerprint for the device, replacing the previous one.
+  Future<void> trust({required String alias, required String fingerprint}) =>
+      RustLib.instance.api.crateApiTrustRsTrustStoreTrust(that: this, alias: alias, fingerprint: fingerprint);
+}
+
 @sealed
 class RtcFileReceiverImpl extends RustOpaque implements RtcFileReceiver {
   // Not to be used by end users
@@ -5334,8 +9179,30 @@ class RtcFileReceiverImpl extends RustOpaque implements RtcFileReceiver {
     that: this,
   );
 
-  Stream<Uint8List> receive() => RustLib.instance.api.crateApiWebrtcRtcFileReceiverReceive(
+  /// Emits the content of the file.
+  /// Chunks are combined until 1 MB is buffered unless `buffer` is set.
+  Stream<Uint8List> receive({ReceiveBufferConfig? buffer}) =>
+      RustLib.instance.api.crateApiWebrtcRtcFileReceiverReceive(that: this, buffer: buffer);
+
+  /// Writes the file to `path` without passing its chunks through Dart.
+  /// The data is written to `<path>.part` which is renamed once complete,
+  /// so `path` never contains a partial file.
+  /// If `expected_sha256` is set (see [FileDto::sha256]), a mismatching file is discarded.
+  /// The destination passed to [`RTCReceiveController::send_selection`], if any, is applied to `path`.
+  ///
+  /// If a file with the name exists, `collision` decides where the data goes.
+  /// [CollisionPolicy::Ask] fails before receiving anything, so this can be called again.
+  Future<RTCReceivedFile> receiveToPath({
+    required String path,
+    required bool withHash,
+    String? expectedSha256,
+    required CollisionPolicy collision,
+  }) => RustLib.instance.api.crateApiWebrtcRtcFileReceiverReceiveToPath(
     that: this,
+    path: path,
+    withHash: withHash,
+    expectedSha256: expectedSha256,
+    collision: collision,
   );
 }
 
@@ -5371,6 +9238,12 @@ class RtcReceiveControllerImpl extends RustOpaque implements RtcReceiveControlle
     rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RtcReceiveControllerPtr,
   );
 
+  /// Aborts the transfer and closes the connection, which disconnects the remote peer.
+  /// The status stream ends without [`RTCStatus::Finished`].
+  Future<void> cancel() => RustLib.instance.api.crateApiWebrtcRtcReceiveControllerCancel(
+    that: this,
+  );
+
   Future<void> decline() => RustLib.instance.api.crateApiWebrtcRtcReceiveControllerDecline(
     that: this,
   );
@@ -5383,21 +9256,51 @@ class RtcReceiveControllerImpl extends RustOpaque implements RtcReceiveControlle
     that: this,
   );
 
+  /// Emits the bytes received of every file that made progress.
+  /// Ends once the transfer has finished.
+  Stream<RTCFileProgress> listenProgress() => RustLib.instance.api.crateApiWebrtcRtcReceiveControllerListenProgress(
+    that: this,
+  );
+
   Stream<RtcFileReceiver> listenReceiving() => RustLib.instance.api.crateApiWebrtcRtcReceiveControllerListenReceiving(
     that: this,
   );
 
+  /// Emits the speed, round-trip time and path of the connection
+  /// every [`localsend::webrtc::webrtc::STATS_INTERVAL`] once connected.
+  /// Ends once the connection is closed.
+  Stream<RTCStats> listenStatus() => RustLib.instance.api.crateApiWebrtcRtcReceiveControllerListenStats(
+    that: this,
+  );
+
   Stream<RTCStatus> listenStatus() => RustLib.instance.api.crateApiWebrtcRtcReceiveControllerListenStatus(
     that: this,
   );
 
+  /// Stops reading file data from the data channel until [`Self::resume`] is called.
+  /// Emits [`RTCStatus::Paused`] on the status stream.
+  Future<void> pause() => RustLib.instance.api.crateApiWebrtcRtcReceiveControllerPause(
+    that: this,
+  );
+
+  /// Emits [`RTCStatus::Resumed`] on the status stream.
+  Future<void> resume() => RustLib.instance.api.crateApiWebrtcRtcReceiveControllerResume(
+    that: this,
+  );
+
   Future<void> sendFileStatus({required RTCSendFileResponse status}) =>
       RustLib.instance.api.crateApiWebrtcRtcReceiveControllerSendFileStatus(that: this, status: status);
 
   Future<void> sendPin({required String pin}) => RustLib.instance.api.crateApiWebrtcRtcReceiveControllerSendPin(that: this, pin: pin);
 
-  Future<void> sendSelection({required Set<String> selection}) =>
-      RustLib.instance.api.crateApiWebrtcRtcReceiveControllerSendSelection(that: this, selection: selection);
+  /// Accepts the files with the IDs in `selection`.
+  ///
+  /// `destinations` maps file IDs to a new name or path chosen by the user,
+  /// which [`RTCFileReceiver::receive_to_path`] applies to the path it is given.
+  /// A name or relative path is resolved against the directory of that path,
+  /// an absolute path replaces it.
+  Future<void> sendSelection({required Set<String> selection, required Map<String, String> destinations}) =>
+      RustLib.instance.api.crateApiWebrtcRtcReceiveControllerSendSelection(that: this, selection: selection, destinations: destinations);
 }
 
 @sealed
@@ -5415,20 +9318,123 @@ class RtcSendControllerImpl extends RustOpaque implements RtcSendController {
     rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_RtcSendControllerPtr,
   );
 
+  /// Aborts the transfer and closes the connection, which disconnects the remote peer.
+  /// The status stream ends without [`RTCStatus::Finished`].
+  Future<void> cancel() => RustLib.instance.api.crateApiWebrtcRtcSendControllerCancel(
+    that: this,
+  );
+
   Stream<RTCFileError> listenError() => RustLib.instance.api.crateApiWebrtcRtcSendControllerListenError(
     that: this,
   );
 
+  /// Emits the bytes sent of every file that made progress.
+  /// Ends once the transfer has finished.
+  Stream<RTCFileProgress> listenProgress() => RustLib.instance.api.crateApiWebrtcRtcSendControllerListenProgress(
+    that: this,
+  );
+
   Future<Set<String>> listenSelectedFiles() => RustLib.instance.api.crateApiWebrtcRtcSendControllerListenSelectedFiles(
     that: this,
   );
 
+  /// Emits the speed, round-trip time and path of the connection
+  /// every [`localsend::webrtc::webrtc::STATS_INTERVAL`] once connected.
+  /// Ends once the connection is closed.
+  Stream<RTCStats> listenStatus() => RustLib.instance.api.crateApiWebrtcRtcSendControllerListenStats(
+    that: this,
+  );
+
   Stream<RTCStatus> listenStatus() => RustLib.instance.api.crateApiWebrtcRtcSendControllerListenStatus(
     that: this,
   );
 
+  /// Stops handing file data to the data channel until [`Self::resume`] is called.
+  /// Emits [`RTCStatus::Paused`] on the status stream.
+  Future<void> pause() => RustLib.instance.api.crateApiWebrtcRtcSendControllerPause(
+    that: this,
+  );
+
+  /// Emits [`RTCStatus::Resumed`] on the status stream.
+  Future<void> resume() => RustLib.instance.api.crateApiWebrtcRtcSendControllerResume(
+    that: this,
+  );
+
   Future<RtcFileSender> sendFile({required String fileId}) =>
       RustLib.instance.api.crateApiWebrtcRtcSendControllerSendFile(that: this, fileId: fileId);
 
+  /// Streams the file at `path` without copying its chunks across the FFI boundary.
+  /// Returns once the whole file has been handed to the data channel.
+  Future<void> sendFileFromPath({required String fileId, required String path}) =>
+      RustLib.instance.api.crateApiWebrtcRtcSendControllerSendFileFromPath(that: this, fileId: fileId, path: path);
+
+  /// Sends the files with `file_ids` in order, each read from its path in `paths`,
+  /// so Dart does not have to call [`Self::send_file`] for every file.
+  /// Pass the IDs returned by [`Self::listen_selected_files`].
+  ///
+  /// Emits the result of every file once it has been handed to the data channel.
+  /// Ends after the last file.
+  Stream<RTCSendFileResponse> sendFilesFromPaths({required List<String> fileIds, required Map<String, String> paths}) =>
+      RustLib.instance.api.crateApiWebrtcRtcSendControllerSendFilesFromPaths(that: this, fileIds: fileIds, paths: paths);
+
   Future<void> sendPin({required String pin}) => RustLib.instance.api.crateApiWebrtcRtcSendControllerSendPin(that: this, pin: pin);
 }
+
+@sealed
+class SessionManagerImpl extends RustOpaque implements SessionManager {
+  // Not to be used by end users
+  SessionManagerImpl.frbInternalDcoDecode(List<dynamic> wire) : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  SessionManagerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SessionManager,
+    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SessionManager,
+    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SessionManagerPtr,
+  );
+
+  /// Number of tracked sessions that are still running.
+  Future<int> activeCount() => RustLib.instance.api.crateApiSessionSessionManagerActiveCount(
+    that: this,
+  );
+
+  /// Starts tracking the transfer and returns its session ID.
+  Future<String> addReceive({required RtcReceiveController controller}) =>
+      RustLib.instance.api.crateApiSessionSessionManagerAddReceive(that: this, controller: controller);
+
+  /// Starts tracking the transfer and returns its session ID.
+  Future<String> addSend({required RtcSendController controller}) =>
+      RustLib.instance.api.crateApiSessionSessionManagerAddSend(that: this, controller: controller);
+
+  /// Aborts the transfer like [`RTCSendController::cancel`] and [`RTCReceiveController::cancel`].
+  Future<void> cancel({required String sessionId}) => RustLib.instance.api.crateApiSessionSessionManagerCancel(that: this, sessionId: sessionId);
+
+  /// Aborts all tracked transfers.
+  Future<void> cancelAll() => RustLib.instance.api.crateApiSessionSessionManagerCancelAll(
+    that: this,
+  );
+
+  /// Removes the callback registered with [`Self::set_end_callback`].
+  Future<void> clearEndCallback() => RustLib.instance.api.crateApiSessionSessionManagerClearEndCallback(
+    that: this,
+  );
+
+  /// Returns all tracked sessions in the order they were added.
+  Future<List<SessionInfo>> listSessions() => RustLib.instance.api.crateApiSessionSessionManagerListSessions(
+    that: this,
+  );
+
+  /// Stops tracking the session without cancelling it.
+  Future<void> remove({required String sessionId}) => RustLib.instance.api.crateApiSessionSessionManagerRemove(that: this, sessionId: sessionId);
+
+  /// Registers a callback invoked once a tracked transfer has finished, failed or been cancelled,
+  /// replacing the previous one.
+  ///
+  /// It is called from Rust even if the status stream of the transfer is not listened to,
+  /// e.g. to show a notification from a background isolate.
+  /// The status is [`RTCStatus::Finished`] if the transfer completed.
+  Future<void> setEndCallback({required FutureOr<void> Function(SessionInfo) callback}) =>
+      RustLib.instance.api.crateApiSessionSessionManagerSetEndCallback(that: this, callback: callback);
+}
@@ -11,11 +11,18 @@ import 'dart:convert';
 
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 import 'package:localsend_isolates/rust/api/crypto.dart';
+import 'package:localsend_isolates/rust/api/favorites.dart';
 import 'package:localsend_isolates/rust/api/http.dart';
 import 'package:localsend_isolates/rust/api/logging.dart';
 import 'package:localsend_isolates/rust/api/model.dart';
+import 'package:localsend_isolates/rust/api/preview.dart';
+import 'package:localsend_isolates/rust/api/registry.dart';
+import 'package:localsend_isolates/rust/api/resume.dart';
 import 'package:localsend_isolates/rust/api/server.dart';
+import 'package:localsend_isolates/rust/api/session.dart';
+import 'package:localsend_isolates/rust/api/settings.dart';
 import 'package:localsend_isolates/rust/api/stream.dart';
+import 'package:localsend_isolates/rust/api/trust.dart';
 import 'package:localsend_isolates/rust/api/webrtc.dart';
 import 'package:localsend_isolates/rust/frb_generated.dart';
 import 'package:uuid/uuid.dart';
@@ -52,12 +59,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsCancellationTokenPtr =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken;
 
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsFavoriteStorePtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore;
+
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsHttpClientPtr =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient;
 
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsHttpServerPtr =>
       wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer;
 
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsResumeStorePtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsSettingsStorePtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_RsTrustStorePtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SessionManagerPtr =>
+      wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager;
+
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
@@ -85,6 +107,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RsCancellationToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(dynamic raw);
 
+  @protected
+  RsFavoriteStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(dynamic raw);
+
   @protected
   RsHttpClient dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(dynamic raw);
 
@@ -92,10 +117,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RsHttpServer dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(dynamic raw);
 
   @protected
-  Dart2RustStreamSink dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(dynamic raw);
+  RsResumeStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(dynamic raw);
+
+  @protected
+  RsSettingsStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(dynamic raw);
+
+  @protected
+  RsTrustStore dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(dynamic raw);
+
+  @protected
+  SessionManager dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(dynamic raw);
 
   @protected
-  RtcSendController dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(dynamic raw);
+  Dart2RustStreamSink dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(dynamic raw);
 
   @protected
   LsSignalingConnection dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection(dynamic raw);
@@ -115,18 +149,39 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RsCancellationToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(dynamic raw);
 
+  @protected
+  RsFavoriteStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(dynamic raw);
+
   @protected
   RsHttpClient dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(dynamic raw);
 
   @protected
   RsHttpServer dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(dynamic raw);
 
+  @protected
+  RsResumeStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(dynamic raw);
+
+  @protected
+  RsSettingsStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(dynamic raw);
+
+  @protected
+  RsTrustStore dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(dynamic raw);
+
+  @protected
+  SessionManager dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(dynamic raw);
+
   @protected
   FutureOr<void> Function(LsSignalingConnection)
   dco_decode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection_Output_unit_AnyhowException(
     dynamic raw,
   );
 
+  @protected
+  FutureOr<void> Function(double) dco_decode_DartFn_Inputs_f_64_Output_unit_AnyhowException(dynamic raw);
+
+  @protected
+  FutureOr<void> Function(SessionInfo) dco_decode_DartFn_Inputs_session_info_Output_unit_AnyhowException(dynamic raw);
+
   @protected
   Object dco_decode_DartOpaque(dynamic raw);
 
@@ -160,12 +215,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RsCancellationToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(dynamic raw);
 
+  @protected
+  RsFavoriteStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(dynamic raw);
+
   @protected
   RsHttpClient dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(dynamic raw);
 
   @protected
   RsHttpServer dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(dynamic raw);
 
+  @protected
+  RsResumeStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(dynamic raw);
+
+  @protected
+  RsSettingsStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(dynamic raw);
+
+  @protected
+  RsTrustStore dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(dynamic raw);
+
+  @protected
+  SessionManager dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(dynamic raw);
+
   @protected
   Set<String> dco_decode_Set_String_None(dynamic raw);
 
@@ -180,12 +250,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<Uint8List> dco_decode_StreamSink_list_prim_u_8_strict_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<List<ClientInfo>> dco_decode_StreamSink_list_client_info_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<LogEntry> dco_decode_StreamSink_log_entry_Sse(dynamic raw);
+
   @protected
   RustStreamSink<RsServerEvent> dco_decode_StreamSink_rs_server_event_Sse(dynamic raw);
 
   @protected
   RustStreamSink<RTCFileError> dco_decode_StreamSink_rtc_file_error_Sse(dynamic raw);
 
+  @protected
+  RustStreamSink<RTCFileProgress> dco_decode_StreamSink_rtc_file_progress_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<RTCSendFileResponse> dco_decode_StreamSink_rtc_send_file_response_Sse(dynamic raw);
+
+  @protected
+  RustStreamSink<RTCStats> dco_decode_StreamSink_rtc_stats_Sse(dynamic raw);
+
   @protected
   RustStreamSink<RTCStatus> dco_decode_StreamSink_rtc_status_Sse(dynamic raw);
 
@@ -198,6 +283,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UuidValue dco_decode_Uuid(dynamic raw);
 
+  @protected
+  AnnouncementKind dco_decode_announcement_kind(dynamic raw);
+
+  @protected
+  AutoAcceptConfig dco_decode_auto_accept_config(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
@@ -206,6 +297,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  AutoAcceptConfig dco_decode_box_autoadd_auto_accept_config(dynamic raw);
+
   @protected
   ClientInfo dco_decode_box_autoadd_client_info(dynamic raw);
 
@@ -218,6 +312,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ExpectingPublicKey dco_decode_box_autoadd_expecting_public_key(dynamic raw);
 
+  @protected
+  FavoriteDevice dco_decode_box_autoadd_favorite_device(dynamic raw);
+
   @protected
   FileDto dco_decode_box_autoadd_file_dto(dynamic raw);
 
@@ -227,6 +324,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int dco_decode_box_autoadd_i_32(dynamic raw);
 
+  @protected
+  MediaInfo dco_decode_box_autoadd_media_info(dynamic raw);
+
   @protected
   PinConfig dco_decode_box_autoadd_pin_config(dynamic raw);
 
@@ -239,21 +339,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ProposingClientInfo dco_decode_box_autoadd_proposing_client_info(dynamic raw);
 
+  @protected
+  ReceiveBufferConfig dco_decode_box_autoadd_receive_buffer_config(dynamic raw);
+
   @protected
   RegisterDto dco_decode_box_autoadd_register_dto(dynamic raw);
 
   @protected
   RegisterDtoV2 dco_decode_box_autoadd_register_dto_v_2(dynamic raw);
 
+  @protected
+  ResumeSession dco_decode_box_autoadd_resume_session(dynamic raw);
+
   @protected
   RTCSendFileResponse dco_decode_box_autoadd_rtc_send_file_response(dynamic raw);
 
+  @protected
+  RTCStatus dco_decode_box_autoadd_rtc_status(dynamic raw);
+
   @protected
   TlsConfig dco_decode_box_autoadd_tls_config(dynamic raw);
 
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
   @protected
   int dco_decode_box_autoadd_u_32(dynamic raw);
 
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
   @protected
   WebSendParams dco_decode_box_autoadd_web_send_params(dynamic raw);
 
@@ -266,6 +381,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ClientInfoWithoutId dco_decode_client_info_without_id(dynamic raw);
 
+  @protected
+  CollisionPolicy dco_decode_collision_policy(dynamic raw);
+
   @protected
   DeviceType dco_decode_device_type(dynamic raw);
 
@@ -275,6 +393,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  FavoriteDevice dco_decode_favorite_device(dynamic raw);
+
   @protected
   FileDto dco_decode_file_dto(dynamic raw);
 
@@ -287,6 +408,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 dco_decode_isize(dynamic raw);
 
+  @protected
+  InferredMimeType dco_decode_inferred_mime_type(dynamic raw);
+
   @protected
   KeyPair dco_decode_key_pair(dynamic raw);
 
@@ -296,6 +420,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<ClientInfo> dco_decode_list_client_info(dynamic raw);
 
+  @protected
+  List<FavoriteDevice> dco_decode_list_favorite_device(dynamic raw);
+
   @protected
   List<FileDto> dco_decode_list_file_dto(dynamic raw);
 
@@ -311,9 +438,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
 
+  @protected
+  List<ResumeFile> dco_decode_list_resume_file(dynamic raw);
+
+  @protected
+  List<ResumeSession> dco_decode_list_resume_session(dynamic raw);
+
+  @protected
+  List<ScannedFile> dco_decode_list_scanned_file(dynamic raw);
+
+  @protected
+  List<SessionInfo> dco_decode_list_session_info(dynamic raw);
+
+  @protected
+  LogEntry dco_decode_log_entry(dynamic raw);
+
+  @protected
+  LogLevel dco_decode_log_level(dynamic raw);
+
   @protected
   LsHttpClientVersion dco_decode_ls_http_client_version(dynamic raw);
 
+  @protected
+  MediaInfo dco_decode_media_info(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
@@ -321,30 +469,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   Dart2RustStreamReceiver?
   dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamReceiver(dynamic raw);
 
+  @protected
+  AutoAcceptConfig? dco_decode_opt_box_autoadd_auto_accept_config(dynamic raw);
+
   @protected
   DeviceType? dco_decode_opt_box_autoadd_device_type(dynamic raw);
 
   @protected
   ExpectingPublicKey? dco_decode_opt_box_autoadd_expecting_public_key(dynamic raw);
 
+  @protected
+  FavoriteDevice? dco_decode_opt_box_autoadd_favorite_device(dynamic raw);
+
   @protected
   FileMetadata? dco_decode_opt_box_autoadd_file_metadata(dynamic raw);
 
   @protected
   int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
 
+  @protected
+  MediaInfo? dco_decode_opt_box_autoadd_media_info(dynamic raw);
+
   @protected
   PinConfig? dco_decode_opt_box_autoadd_pin_config(dynamic raw);
 
   @protected
   PrepareUploadResponseDto? dco_decode_opt_box_autoadd_prepare_upload_response_dto(dynamic raw);
 
+  @protected
+  ReceiveBufferConfig? dco_decode_opt_box_autoadd_receive_buffer_config(dynamic raw);
+
+  @protected
+  ResumeSession? dco_decode_opt_box_autoadd_resume_session(dynamic raw);
+
+  @protected
+  RTCStatus? dco_decode_opt_box_autoadd_rtc_status(dynamic raw);
+
   @protected
   TlsConfig? dco_decode_opt_box_autoadd_tls_config(dynamic raw);
 
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
   @protected
   int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
 
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
   @protected
   WebSendParams? dco_decode_opt_box_autoadd_web_send_params(dynamic raw);
 
@@ -378,6 +550,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     dynamic raw,
   );
 
+  @protected
+  ReceiveBufferConfig dco_decode_receive_buffer_config(dynamic raw);
+
   @protected
   (String, FileDto) dco_decode_record_string_file_dto(dynamic raw);
 
@@ -396,6 +571,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ResultWithPublicKeyRegisterResponseDto dco_decode_result_with_public_key_register_response_dto(dynamic raw);
 
+  @protected
+  ResumeDirection dco_decode_resume_direction(dynamic raw);
+
+  @protected
+  ResumeFile dco_decode_resume_file(dynamic raw);
+
+  @protected
+  ResumeSession dco_decode_resume_session(dynamic raw);
+
   @protected
   RsHttpClientError dco_decode_rs_http_client_error(dynamic raw);
 
@@ -405,18 +589,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RTCFileError dco_decode_rtc_file_error(dynamic raw);
 
+  @protected
+  RTCFileProgress dco_decode_rtc_file_progress(dynamic raw);
+
+  @protected
+  RTCReceivedFile dco_decode_rtc_received_file(dynamic raw);
+
   @protected
   RTCSendFileResponse dco_decode_rtc_send_file_response(dynamic raw);
 
+  @protected
+  RTCStats dco_decode_rtc_stats(dynamic raw);
+
   @protected
   RTCStatus dco_decode_rtc_status(dynamic raw);
 
+  @protected
+  ScannedFile dco_decode_scanned_file(dynamic raw);
+
+  @protected
+  SessionDirection dco_decode_session_direction(dynamic raw);
+
   @protected
   SessionEndReasonV2 dco_decode_session_end_reason_v_2(dynamic raw);
 
+  @protected
+  SessionInfo dco_decode_session_info(dynamic raw);
+
+  @protected
+  SymlinkPolicy dco_decode_symlink_policy(dynamic raw);
+
   @protected
   TlsConfig dco_decode_tls_config(dynamic raw);
 
+  @protected
+  TrustStatus dco_decode_trust_status(dynamic raw);
+
   @protected
   int dco_decode_u_16(dynamic raw);
 
@@ -486,6 +694,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RsFavoriteStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(SseDeserializer deserializer);
+
   @protected
   RsHttpClient sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(SseDeserializer deserializer);
 
@@ -493,12 +704,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RsHttpServer sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(SseDeserializer deserializer);
 
   @protected
-  Dart2RustStreamSink sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(
-    SseDeserializer deserializer,
-  );
+  RsResumeStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(SseDeserializer deserializer);
+
+  @protected
+  RsSettingsStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(SseDeserializer deserializer);
 
   @protected
-  RtcSendController sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(
+  RsTrustStore sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(SseDeserializer deserializer);
+
+  @protected
+  SessionManager sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SseDeserializer deserializer);
+
+  @protected
+  Dart2RustStreamSink sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(
     SseDeserializer deserializer,
   );
 
@@ -526,12 +744,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  RsFavoriteStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(SseDeserializer deserializer);
+
   @protected
   RsHttpClient sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(SseDeserializer deserializer);
 
   @protected
   RsHttpServer sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(SseDeserializer deserializer);
 
+  @protected
+  RsResumeStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(SseDeserializer deserializer);
+
+  @protected
+  RsSettingsStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(SseDeserializer deserializer);
+
+  @protected
+  RsTrustStore sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(SseDeserializer deserializer);
+
+  @protected
+  SessionManager sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SseDeserializer deserializer);
+
   @protected
   Object sse_decode_DartOpaque(SseDeserializer deserializer);
 
@@ -567,12 +800,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RsCancellationToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(SseDeserializer deserializer);
 
+  @protected
+  RsFavoriteStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(SseDeserializer deserializer);
+
   @protected
   RsHttpClient sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(SseDeserializer deserializer);
 
   @protected
   RsHttpServer sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(SseDeserializer deserializer);
 
+  @protected
+  RsResumeStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(SseDeserializer deserializer);
+
+  @protected
+  RsSettingsStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(SseDeserializer deserializer);
+
+  @protected
+  RsTrustStore sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(SseDeserializer deserializer);
+
+  @protected
+  SessionManager sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SseDeserializer deserializer);
+
   @protected
   Set<String> sse_decode_Set_String_None(SseDeserializer deserializer);
 
@@ -587,12 +835,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RustStreamSink<Uint8List> sse_decode_StreamSink_list_prim_u_8_strict_Sse(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<List<ClientInfo>> sse_decode_StreamSink_list_client_info_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<LogEntry> sse_decode_StreamSink_log_entry_Sse(SseDeserializer deserializer);
+
   @protected
   RustStreamSink<RsServerEvent> sse_decode_StreamSink_rs_server_event_Sse(SseDeserializer deserializer);
 
   @protected
   RustStreamSink<RTCFileError> sse_decode_StreamSink_rtc_file_error_Sse(SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<RTCFileProgress> sse_decode_StreamSink_rtc_file_progress_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<RTCSendFileResponse> sse_decode_StreamSink_rtc_send_file_response_Sse(SseDeserializer deserializer);
+
+  @protected
+  RustStreamSink<RTCStats> sse_decode_StreamSink_rtc_stats_Sse(SseDeserializer deserializer);
+
   @protected
   RustStreamSink<RTCStatus> sse_decode_StreamSink_rtc_status_Sse(SseDeserializer deserializer);
 
@@ -605,6 +868,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   UuidValue sse_decode_Uuid(SseDeserializer deserializer);
 
+  @protected
+  AnnouncementKind sse_decode_announcement_kind(SseDeserializer deserializer);
+
+  @protected
+  AutoAcceptConfig sse_decode_auto_accept_config(SseDeserializer deserializer);
+
   @protected
   bool sse_decode_bool(SseDeserializer deserializer);
 
@@ -613,6 +882,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  AutoAcceptConfig sse_decode_box_autoadd_auto_accept_config(SseDeserializer deserializer);
+
   @protected
   ClientInfo sse_decode_box_autoadd_client_info(SseDeserializer deserializer);
 
@@ -625,6 +897,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ExpectingPublicKey sse_decode_box_autoadd_expecting_public_key(SseDeserializer deserializer);
 
+  @protected
+  FavoriteDevice sse_decode_box_autoadd_favorite_device(SseDeserializer deserializer);
+
   @protected
   FileDto sse_decode_box_autoadd_file_dto(SseDeserializer deserializer);
 
@@ -634,6 +909,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
 
+  @protected
+  MediaInfo sse_decode_box_autoadd_media_info(SseDeserializer deserializer);
+
   @protected
   PinConfig sse_decode_box_autoadd_pin_config(SseDeserializer deserializer);
 
@@ -646,21 +924,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ProposingClientInfo sse_decode_box_autoadd_proposing_client_info(SseDeserializer deserializer);
 
+  @protected
+  ReceiveBufferConfig sse_decode_box_autoadd_receive_buffer_config(SseDeserializer deserializer);
+
   @protected
   RegisterDto sse_decode_box_autoadd_register_dto(SseDeserializer deserializer);
 
   @protected
   RegisterDtoV2 sse_decode_box_autoadd_register_dto_v_2(SseDeserializer deserializer);
 
+  @protected
+  ResumeSession sse_decode_box_autoadd_resume_session(SseDeserializer deserializer);
+
   @protected
   RTCSendFileResponse sse_decode_box_autoadd_rtc_send_file_response(SseDeserializer deserializer);
 
+  @protected
+  RTCStatus sse_decode_box_autoadd_rtc_status(SseDeserializer deserializer);
+
   @protected
   TlsConfig sse_decode_box_autoadd_tls_config(SseDeserializer deserializer);
 
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
   @protected
   WebSendParams sse_decode_box_autoadd_web_send_params(SseDeserializer deserializer);
 
@@ -673,6 +966,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ClientInfoWithoutId sse_decode_client_info_without_id(SseDeserializer deserializer);
 
+  @protected
+  CollisionPolicy sse_decode_collision_policy(SseDeserializer deserializer);
+
   @protected
   DeviceType sse_decode_device_type(SseDeserializer deserializer);
 
@@ -682,6 +978,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  FavoriteDevice sse_decode_favorite_device(SseDeserializer deserializer);
+
   @protected
   FileDto sse_decode_file_dto(SseDeserializer deserializer);
 
@@ -694,6 +993,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
 
+  @protected
+  InferredMimeType sse_decode_inferred_mime_type(SseDeserializer deserializer);
+
   @protected
   KeyPair sse_decode_key_pair(SseDeserializer deserializer);
 
@@ -703,6 +1005,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<ClientInfo> sse_decode_list_client_info(SseDeserializer deserializer);
 
+  @protected
+  List<FavoriteDevice> sse_decode_list_favorite_device(SseDeserializer deserializer);
+
   @protected
   List<FileDto> sse_decode_list_file_dto(SseDeserializer deserializer);
 
@@ -718,9 +1023,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<(String, String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
 
+  @protected
+  List<ResumeFile> sse_decode_list_resume_file(SseDeserializer deserializer);
+
+  @protected
+  List<ResumeSession> sse_decode_list_resume_session(SseDeserializer deserializer);
+
+  @protected
+  List<ScannedFile> sse_decode_list_scanned_file(SseDeserializer deserializer);
+
+  @protected
+  List<SessionInfo> sse_decode_list_session_info(SseDeserializer deserializer);
+
+  @protected
+  LogEntry sse_decode_log_entry(SseDeserializer deserializer);
+
+  @protected
+  LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
   @protected
   LsHttpClientVersion sse_decode_ls_http_client_version(SseDeserializer deserializer);
 
+  @protected
+  MediaInfo sse_decode_media_info(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -730,30 +1056,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  AutoAcceptConfig? sse_decode_opt_box_autoadd_auto_accept_config(SseDeserializer deserializer);
+
   @protected
   DeviceType? sse_decode_opt_box_autoadd_device_type(SseDeserializer deserializer);
 
   @protected
   ExpectingPublicKey? sse_decode_opt_box_autoadd_expecting_public_key(SseDeserializer deserializer);
 
+  @protected
+  FavoriteDevice? sse_decode_opt_box_autoadd_favorite_device(SseDeserializer deserializer);
+
   @protected
   FileMetadata? sse_decode_opt_box_autoadd_file_metadata(SseDeserializer deserializer);
 
   @protected
   int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
 
+  @protected
+  MediaInfo? sse_decode_opt_box_autoadd_media_info(SseDeserializer deserializer);
+
   @protected
   PinConfig? sse_decode_opt_box_autoadd_pin_config(SseDeserializer deserializer);
 
   @protected
   PrepareUploadResponseDto? sse_decode_opt_box_autoadd_prepare_upload_response_dto(SseDeserializer deserializer);
 
+  @protected
+  ReceiveBufferConfig? sse_decode_opt_box_autoadd_receive_buffer_config(SseDeserializer deserializer);
+
+  @protected
+  ResumeSession? sse_decode_opt_box_autoadd_resume_session(SseDeserializer deserializer);
+
+  @protected
+  RTCStatus? sse_decode_opt_box_autoadd_rtc_status(SseDeserializer deserializer);
+
   @protected
   TlsConfig? sse_decode_opt_box_autoadd_tls_config(SseDeserializer deserializer);
 
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
   @protected
   int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
 
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
   @protected
   WebSendParams? sse_decode_opt_box_autoadd_web_send_params(SseDeserializer deserializer);
 
@@ -787,6 +1137,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseDeserializer deserializer,
   );
 
+  @protected
+  ReceiveBufferConfig sse_decode_receive_buffer_config(SseDeserializer deserializer);
+
   @protected
   (String, FileDto) sse_decode_record_string_file_dto(SseDeserializer deserializer);
 
@@ -805,6 +1158,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   ResultWithPublicKeyRegisterResponseDto sse_decode_result_with_public_key_register_response_dto(SseDeserializer deserializer);
 
+  @protected
+  ResumeDirection sse_decode_resume_direction(SseDeserializer deserializer);
+
+  @protected
+  ResumeFile sse_decode_resume_file(SseDeserializer deserializer);
+
+  @protected
+  ResumeSession sse_decode_resume_session(SseDeserializer deserializer);
+
   @protected
   RsHttpClientError sse_decode_rs_http_client_error(SseDeserializer deserializer);
 
@@ -814,18 +1176,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   RTCFileError sse_decode_rtc_file_error(SseDeserializer deserializer);
 
+  @protected
+  RTCFileProgress sse_decode_rtc_file_progress(SseDeserializer deserializer);
+
+  @protected
+  RTCReceivedFile sse_decode_rtc_received_file(SseDeserializer deserializer);
+
   @protected
   RTCSendFileResponse sse_decode_rtc_send_file_response(SseDeserializer deserializer);
 
+  @protected
+  RTCStats sse_decode_rtc_stats(SseDeserializer deserializer);
+
   @protected
   RTCStatus sse_decode_rtc_status(SseDeserializer deserializer);
 
+  @protected
+  ScannedFile sse_decode_scanned_file(SseDeserializer deserializer);
+
+  @protected
+  SessionDirection sse_decode_session_direction(SseDeserializer deserializer);
+
   @protected
   SessionEndReasonV2 sse_decode_session_end_reason_v_2(SseDeserializer deserializer);
 
+  @protected
+  SessionInfo sse_decode_session_info(SseDeserializer deserializer);
+
+  @protected
+  SymlinkPolicy sse_decode_symlink_policy(SseDeserializer deserializer);
+
   @protected
   TlsConfig sse_decode_tls_config(SseDeserializer deserializer);
 
+  @protected
+  TrustStatus sse_decode_trust_status(SseDeserializer deserializer);
+
   @protected
   int sse_decode_u_16(SseDeserializer deserializer);
 
@@ -907,6 +1293,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(RsFavoriteStore self, SseSerializer serializer);
+
   @protected
   void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(RsHttpClient self, SseSerializer serializer);
 
@@ -914,14 +1303,20 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(RsHttpServer self, SseSerializer serializer);
 
   @protected
-  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(
-    Dart2RustStreamSink self,
-    SseSerializer serializer,
-  );
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(RsResumeStore self, SseSerializer serializer);
 
   @protected
-  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRTCSendController(
-    RtcSendController self,
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(RsSettingsStore self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(RsTrustStore self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SessionManager self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDart2RustStreamSink(
+    Dart2RustStreamSink self,
     SseSerializer serializer,
   );
 
@@ -958,12 +1353,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(RsFavoriteStore self, SseSerializer serializer);
+
   @protected
   void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(RsHttpClient self, SseSerializer serializer);
 
   @protected
   void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(RsHttpServer self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(RsResumeStore self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(RsSettingsStore self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(RsTrustStore self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SessionManager self, SseSerializer serializer);
+
   @protected
   void
   sse_encode_DartFn_Inputs_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerLsSignalingConnection_Output_unit_AnyhowException(
@@ -971,6 +1381,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_DartFn_Inputs_f_64_Output_unit_AnyhowException(FutureOr<void> Function(double) self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_DartFn_Inputs_session_info_Output_unit_AnyhowException(FutureOr<void> Function(SessionInfo) self, SseSerializer serializer);
+
   @protected
   void sse_encode_DartOpaque(Object self, SseSerializer serializer);
 
@@ -1019,12 +1435,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(RsFavoriteStore self, SseSerializer serializer);
+
   @protected
   void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(RsHttpClient self, SseSerializer serializer);
 
   @protected
   void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(RsHttpServer self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(RsResumeStore self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(RsSettingsStore self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(RsTrustStore self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(SessionManager self, SseSerializer serializer);
+
   @protected
   void sse_encode_Set_String_None(Set<String> self, SseSerializer serializer);
 
@@ -1040,12 +1471,27 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_StreamSink_list_prim_u_8_strict_Sse(RustStreamSink<Uint8List> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_StreamSink_list_client_info_Sse(RustStreamSink<List<ClientInfo>> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_log_entry_Sse(RustStreamSink<LogEntry> self, SseSerializer serializer);
+
   @protected
   void sse_encode_StreamSink_rs_server_event_Sse(RustStreamSink<RsServerEvent> self, SseSerializer serializer);
 
   @protected
   void sse_encode_StreamSink_rtc_file_error_Sse(RustStreamSink<RTCFileError> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_StreamSink_rtc_file_progress_Sse(RustStreamSink<RTCFileProgress> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_rtc_send_file_response_Sse(RustStreamSink<RTCSendFileResponse> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_StreamSink_rtc_stats_Sse(RustStreamSink<RTCStats> self, SseSerializer serializer);
+
   @protected
   void sse_encode_StreamSink_rtc_status_Sse(RustStreamSink<RTCStatus> self, SseSerializer serializer);
 
@@ -1058,6 +1504,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_Uuid(UuidValue self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_announcement_kind(AnnouncementKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_auto_accept_config(AutoAcceptConfig self, SseSerializer serializer);
+
   @protected
   void sse_encode_bool(bool self, SseSerializer serializer);
 
@@ -1067,6 +1519,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_box_autoadd_auto_accept_config(AutoAcceptConfig self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_client_info(ClientInfo self, SseSerializer serializer);
 
@@ -1079,6 +1534,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_expecting_public_key(ExpectingPublicKey self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_favorite_device(FavoriteDevice self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_file_dto(FileDto self, SseSerializer serializer);
 
@@ -1088,6 +1546,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_media_info(MediaInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_pin_config(PinConfig self, SseSerializer serializer);
 
@@ -1100,21 +1561,36 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_proposing_client_info(ProposingClientInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_receive_buffer_config(ReceiveBufferConfig self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_register_dto(RegisterDto self, SseSerializer serializer);
 
   @protected
   void sse_encode_box_autoadd_register_dto_v_2(RegisterDtoV2 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_resume_session(ResumeSession self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_rtc_send_file_response(RTCSendFileResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_rtc_status(RTCStatus self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_tls_config(TlsConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_web_send_params(WebSendParams self, SseSerializer serializer);
 
@@ -1127,6 +1603,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_client_info_without_id(ClientInfoWithoutId self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_collision_policy(CollisionPolicy self, SseSerializer serializer);
+
   @protected
   void sse_encode_device_type(DeviceType self, SseSerializer serializer);
 
@@ -1136,6 +1615,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_favorite_device(FavoriteDevice self, SseSerializer serializer);
+
   @protected
   void sse_encode_file_dto(FileDto self, SseSerializer serializer);
 
@@ -1148,6 +1630,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_inferred_mime_type(InferredMimeType self, SseSerializer serializer);
+
   @protected
   void sse_encode_key_pair(KeyPair self, SseSerializer serializer);
 
@@ -1157,6 +1642,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_client_info(List<ClientInfo> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_favorite_device(List<FavoriteDevice> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_file_dto(List<FileDto> self, SseSerializer serializer);
 
@@ -1172,9 +1660,30 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_record_string_string(List<(String, String)> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_resume_file(List<ResumeFile> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_resume_session(List<ResumeSession> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_scanned_file(List<ScannedFile> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_session_info(List<SessionInfo> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_entry(LogEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
   @protected
   void sse_encode_ls_http_client_version(LsHttpClientVersion self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_media_info(MediaInfo self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -1184,30 +1693,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_opt_box_autoadd_auto_accept_config(AutoAcceptConfig? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_device_type(DeviceType? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_expecting_public_key(ExpectingPublicKey? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_favorite_device(FavoriteDevice? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_file_metadata(FileMetadata? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_media_info(MediaInfo? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_pin_config(PinConfig? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_prepare_upload_response_dto(PrepareUploadResponseDto? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_receive_buffer_config(ReceiveBufferConfig? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_resume_session(ResumeSession? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_rtc_status(RTCStatus? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_tls_config(TlsConfig? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_web_send_params(WebSendParams? self, SseSerializer serializer);
 
@@ -1242,6 +1775,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     SseSerializer serializer,
   );
 
+  @protected
+  void sse_encode_receive_buffer_config(ReceiveBufferConfig self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_string_file_dto((String, FileDto) self, SseSerializer serializer);
 
@@ -1260,6 +1796,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_result_with_public_key_register_response_dto(ResultWithPublicKeyRegisterResponseDto self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_resume_direction(ResumeDirection self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_resume_file(ResumeFile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_resume_session(ResumeSession self, SseSerializer serializer);
+
   @protected
   void sse_encode_rs_http_client_error(RsHttpClientError self, SseSerializer serializer);
 
@@ -1269,18 +1814,42 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_rtc_file_error(RTCFileError self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_rtc_file_progress(RTCFileProgress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_rtc_received_file(RTCReceivedFile self, SseSerializer serializer);
+
   @protected
   void sse_encode_rtc_send_file_response(RTCSendFileResponse self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_rtc_stats(RTCStats self, SseSerializer serializer);
+
   @protected
   void sse_encode_rtc_status(RTCStatus self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_scanned_file(ScannedFile self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_session_direction(SessionDirection self, SseSerializer serializer);
+
   @protected
   void sse_encode_session_end_reason_v_2(SessionEndReasonV2 self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_session_info(SessionInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_symlink_policy(SymlinkPolicy self, SseSerializer serializer);
+
   @protected
   void sse_encode_tls_config(TlsConfig self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_trust_status(TrustStatus self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_16(int self, SseSerializer serializer);
 
@@ -1365,6 +1934,12 @@ class RustLibWire implements BaseWire {
   void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(int ptr) =>
       wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(ptr);
 
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(ptr);
+
   void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(int ptr) =>
       wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(ptr);
 
@@ -1376,6 +1951,30 @@ class RustLibWire implements BaseWire {
 
   void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(int ptr) =>
       wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(ptr);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(ptr);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(ptr);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(ptr);
+
+  void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(int ptr) =>
+      wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(ptr);
+
+  void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(int ptr) =>
+      wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(ptr);
 }
 
 @JS('wasm_bindgen')
@@ -1416,6 +2015,10 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
 
   external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsCancellationToken(int ptr);
 
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(int ptr);
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsFavoriteStore(int ptr);
+
   external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(int ptr);
 
   external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpClient(int ptr);
@@ -1423,4 +2026,20 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(int ptr);
 
   external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsHttpServer(int ptr);
+
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(int ptr);
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsResumeStore(int ptr);
+
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(int ptr);
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsSettingsStore(int ptr);
+
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(int ptr);
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerRsTrustStore(int ptr);
+
+  external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(int ptr);
+
+  external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSessionManager(int ptr);
 }
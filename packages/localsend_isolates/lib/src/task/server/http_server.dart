@@ -47,8 +47,10 @@ class HttpServerService {
 
   /// Answers a pending prepare-upload request.
   /// [acceptedFileIds] is the subset of the offered files to accept; `null` declines the request.
-  Future<void> respondPrepareUpload({required List<String>? acceptedFileIds}) async {
-    await _requireServer().respondPrepareUpload(acceptedFileIds: acceptedFileIds);
+  /// [destinations] maps file IDs to a new name or path chosen by the user,
+  /// applied to the path later passed to [respondFileUpload].
+  Future<void> respondPrepareUpload({required List<String>? acceptedFileIds, Map<String, String> destinations = const {}}) async {
+    await _requireServer().respondPrepareUpload(acceptedFileIds: acceptedFileIds, destinations: destinations);
   }
 
   /// Answers a pending file upload with the target the file should be saved to
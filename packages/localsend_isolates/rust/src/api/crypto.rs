@@ -1,3 +1,15 @@
+use crate::api::http::RsCancellationToken;
+use flutter_rust_bridge::DartFnFuture;
+use localsend::crypto::hash::StreamingSha256;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncReadExt;
+
+/// Bytes hashed at once by [`compute_sha256`].
+const HASH_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Minimum interval between two progress reports of [`compute_sha256`].
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
 pub fn verify_cert(cert: String, public_key: String) -> anyhow::Result<()> {
     localsend::crypto::cert::verify_cert_from_pem(cert, Some(&public_key))
 }
@@ -13,6 +25,42 @@ pub fn generate_key_pair() -> anyhow::Result<KeyPair> {
     })
 }
 
+/// Hashes the file at `path` as lowercase hex, the format of `FileDto::sha256`.
+/// Reports the progress from 0 to 1 to `on_progress`, at most every 100 ms and once at the end.
+/// Fails once `cancel_token` is cancelled.
+pub async fn compute_sha256(
+    path: String,
+    cancel_token: &RsCancellationToken,
+    on_progress: impl Fn(f64) -> DartFnFuture<()>,
+) -> anyhow::Result<String> {
+    let mut file = tokio::fs::File::open(&path).await?;
+    let size = file.metadata().await?.len();
+    let mut hasher = StreamingSha256::default();
+    let mut buffer = vec![0; HASH_CHUNK_SIZE];
+    let mut hashed = 0;
+    let mut last_emit = Instant::now();
+
+    loop {
+        let read = tokio::select! {
+            _ = cancel_token.inner.cancelled() => anyhow::bail!("Hashing cancelled"),
+            read = file.read(&mut buffer) => read?,
+        };
+        if read == 0 {
+            break;
+        }
+
+        hasher.update(&buffer[..read]);
+        hashed += read as u64;
+        if last_emit.elapsed() >= PROGRESS_INTERVAL {
+            last_emit = Instant::now();
+            on_progress((hashed as f64 / size as f64).min(1.0)).await;
+        }
+    }
+
+    on_progress(1.0).await;
+    Ok(hasher.finalize_hex())
+}
+
 pub struct KeyPair {
     pub private_key: String,
     pub public_key: String,
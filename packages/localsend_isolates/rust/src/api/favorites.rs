@@ -0,0 +1,62 @@
+use crate::api::webrtc::ClientInfo;
+use flutter_rust_bridge::frb;
+pub use localsend::favorites::FavoriteDevice;
+use localsend::favorites::FavoriteStore;
+
+/// Devices marked as favorite by the user, persisted as JSON.
+///
+/// Devices are identified by their fingerprint, or their alias if it changed.
+pub struct RsFavoriteStore {
+    inner: FavoriteStore,
+}
+
+/// Loads the favorites at `path`, or none if the file does not exist yet.
+pub async fn open_favorite_store(path: String) -> anyhow::Result<RsFavoriteStore> {
+    Ok(RsFavoriteStore {
+        inner: FavoriteStore::open(path).await?,
+    })
+}
+
+impl RsFavoriteStore {
+    /// All favorites sorted by alias.
+    #[frb(sync)]
+    pub fn list(&self) -> Vec<FavoriteDevice> {
+        self.inner.list()
+    }
+
+    #[frb(sync)]
+    pub fn get(&self, fingerprint: String) -> Option<FavoriteDevice> {
+        self.inner.get(&fingerprint)
+    }
+
+    /// Marks a discovered peer as favorite, keeping the notes if `notes` is `None`.
+    pub async fn add_peer(
+        &self,
+        peer: ClientInfo,
+        notes: Option<String>,
+    ) -> anyhow::Result<FavoriteDevice> {
+        self.inner.add(&peer.token, &peer.alias, notes).await
+    }
+
+    /// Returns the favorite matching a peer of [crate::api::webrtc::WsServerMessage::Joined]
+    /// or [crate::api::webrtc::WsServerMessage::Hello] and records that it was seen.
+    pub async fn match_peer(&self, peer: ClientInfo) -> anyhow::Result<Option<FavoriteDevice>> {
+        self.inner.mark_seen(&peer.token, &peer.alias).await
+    }
+
+    pub async fn set_notes(&self, fingerprint: String, notes: String) -> anyhow::Result<()> {
+        self.inner.set_notes(&fingerprint, notes).await
+    }
+
+    pub async fn remove(&self, fingerprint: String) -> anyhow::Result<()> {
+        self.inner.remove(&fingerprint).await
+    }
+}
+
+#[frb(mirror(FavoriteDevice))]
+pub struct _FavoriteDevice {
+    pub fingerprint: String,
+    pub alias: String,
+    pub last_seen: Option<u64>,
+    pub notes: String,
+}
@@ -30,7 +30,7 @@ pub fn create_client(
 }
 
 pub struct RsCancellationToken {
-    inner: tokio_util::sync::CancellationToken,
+    pub(crate) inner: tokio_util::sync::CancellationToken,
 }
 
 #[frb(sync)]
@@ -1,24 +1,180 @@
+use crate::frb_generated::StreamSink;
 use anyhow::Result;
+use std::fmt::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::filter_fn;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::util::SubscriberInitExt;
+
+/// Whether events are written to stdout (logcat on Android).
+static DEBUG_LOGGING: AtomicBool = AtomicBool::new(false);
+
+/// The stream registered by [`listen_logs`].
+static LOG_LISTENER: RwLock<Option<Arc<LogListener>>> = RwLock::new(None);
+
+/// An event emitted by the core crate or its dependencies.
+pub struct LogEntry {
+    /// Unix timestamp in milliseconds.
+    pub timestamp_ms: u64,
+    pub level: LogLevel,
+
+    /// Module path the event was emitted from, e.g. `localsend::http::server`.
+    pub target: String,
+
+    /// The message followed by the other fields, e.g. `Client connected peer=192.168.1.2`.
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
 
 pub fn enable_debug_logging() -> Result<()> {
-    #[cfg(target_os = "android")]
-    {
-        use tracing_subscriber::layer::SubscriberExt;
-        use tracing_subscriber::util::SubscriberInitExt;
+    init_subscriber()?;
+    DEBUG_LOGGING.store(true, Ordering::Relaxed);
+    Ok(())
+}
 
+/// Streams the events of at least `min_level` to the returned stream, e.g. for a debug console.
+/// Only events whose target starts with one of `targets` are sent, all if empty.
+/// Replaces the stream of the previous call, which ends.
+pub fn listen_logs(sink: StreamSink<LogEntry>, min_level: LogLevel, targets: Vec<String>) {
+    if let Err(e) = init_subscriber() {
+        let _ = sink.add_error(e);
+        return;
+    }
+
+    *LOG_LISTENER.write().unwrap() = Some(Arc::new(LogListener {
+        sink,
+        min_level,
+        targets,
+    }));
+}
+
+/// Installs the global subscriber once, which feeds both stdout and [`listen_logs`].
+fn init_subscriber() -> Result<()> {
+    static INIT: OnceLock<Result<(), String>> = OnceLock::new();
+
+    INIT.get_or_init(|| {
         // Android discards native stdout/stderr, so route tracing to logcat.
+        #[cfg(target_os = "android")]
+        let stdout = tracing_android::layer("localsend_rust").map_err(|e| e.to_string())?;
+
+        #[cfg(not(target_os = "android"))]
+        let stdout = tracing_subscriber::fmt::layer();
+
         tracing_subscriber::registry()
-            .with(tracing_subscriber::filter::LevelFilter::DEBUG)
-            .with(tracing_android::layer("localsend_rust")?)
+            .with(stdout.with_filter(filter_fn(|metadata| {
+                DEBUG_LOGGING.load(Ordering::Relaxed) && *metadata.level() <= Level::DEBUG
+            })))
+            .with(DartLogLayer)
             .try_init()
-            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+            .map_err(|e| e.to_string())
+    })
+    .clone()
+    .map_err(|e| anyhow::anyhow!(e))
+}
+
+struct LogListener {
+    sink: StreamSink<LogEntry>,
+    min_level: LogLevel,
+    targets: Vec<String>,
+}
+
+impl LogListener {
+    fn accepts_target(&self, target: &str) -> bool {
+        self.targets.is_empty()
+            || self
+                .targets
+                .iter()
+                .any(|prefix| target.starts_with(prefix.as_str()))
     }
+}
 
-    #[cfg(not(target_os = "android"))]
-    tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::DEBUG)
-        .try_init()
-        .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+/// Forwards the events to the [`LOG_LISTENER`].
+struct DartLogLayer;
 
-    Ok(())
+impl<S: Subscriber> Layer<S> for DartLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let Some(listener) = LOG_LISTENER.read().unwrap().clone() else {
+            return;
+        };
+
+        let metadata = event.metadata();
+        let level = log_level(metadata.level());
+        if level < listener.min_level {
+            return;
+        }
+
+        let mut visitor = EventVisitor::default();
+        event.record(&mut visitor);
+
+        // Events of the `log` crate carry their actual target as a field.
+        let target = visitor
+            .log_target
+            .unwrap_or_else(|| metadata.target().to_string());
+        if !listener.accepts_target(&target) {
+            return;
+        }
+
+        let timestamp_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or_default();
+
+        let _ = listener.sink.add(LogEntry {
+            timestamp_ms,
+            level,
+            target,
+            message: visitor.message + &visitor.fields,
+        });
+    }
+}
+
+fn log_level(level: &Level) -> LogLevel {
+    match *level {
+        Level::ERROR => LogLevel::Error,
+        Level::WARN => LogLevel::Warn,
+        Level::INFO => LogLevel::Info,
+        Level::DEBUG => LogLevel::Debug,
+        _ => LogLevel::Trace,
+    }
+}
+
+#[derive(Default)]
+struct EventVisitor {
+    message: String,
+    fields: String,
+    log_target: Option<String>,
+}
+
+impl Visit for EventVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message = value.to_string(),
+            "log.target" => self.log_target = Some(value.to_string()),
+            name if name.starts_with("log.") => {}
+            name => {
+                let _ = write!(self.fields, " {name}={value}");
+            }
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        match field.name() {
+            "message" => self.message = format!("{value:?}"),
+            name if name.starts_with("log.") => {}
+            name => {
+                let _ = write!(self.fields, " {name}={value:?}");
+            }
+        }
+    }
 }
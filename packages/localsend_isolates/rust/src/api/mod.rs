@@ -1,7 +1,14 @@
 pub mod crypto;
+pub mod favorites;
 pub mod http;
 pub mod logging;
 pub mod model;
+pub mod preview;
+pub mod registry;
+pub mod resume;
 pub mod server;
+pub mod session;
+pub mod settings;
 pub mod stream;
+pub mod trust;
 pub mod webrtc;
@@ -1,11 +1,118 @@
+use crate::api::preview::extract_embedded_preview;
 use flutter_rust_bridge::frb;
 pub use localsend::http::dto::{
     PrepareUploadRequestDto, PrepareUploadResponseDto, ProtocolType, RegisterDto,
     RegisterResponseDto,
 };
 pub use localsend::model::discovery::DeviceType;
-pub use localsend::model::transfer::{FileDto, FileMetadata};
+pub use localsend::model::file::{FileMetadata, MediaInfo};
+use localsend::model::scan::ScanOptions;
+pub use localsend::model::scan::SymlinkPolicy;
+pub use localsend::model::transfer::FileDto;
 use std::collections::HashMap;
+use std::path::Path;
+
+/// Describes the file at `path` with a new ID, its MIME type guessed from the extension and its timestamps.
+/// The SHA-256 and the preview are only computed if requested, a failing preview is logged and left out.
+pub async fn create_file_dto(
+    path: String,
+    with_hash: bool,
+    with_preview: bool,
+) -> anyhow::Result<FileDto> {
+    let mut file = FileDto::from_path(&path, with_hash).await?;
+    if with_preview {
+        file.preview = extract_embedded_preview(path.clone(), file.file_type.clone())
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to extract the preview of {path}: {e}");
+                None
+            });
+    }
+
+    Ok(file)
+}
+
+/// Turns the name of a received file into a relative path that stays inside the directory it is joined to,
+/// e.g. `../photo.jpg` becomes `photo.jpg`. Fails if no usable name is left.
+#[frb(sync)]
+pub fn sanitize_file_name(file_name: String) -> anyhow::Result<String> {
+    let path = localsend::model::file::sanitize_file_name(&file_name)?;
+
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// A file found by [`scan_directory`].
+pub struct ScannedFile {
+    /// Path the content of the file is read from.
+    pub path: String,
+
+    /// The file name is the path relative to the parent of the scanned directory,
+    /// separated by `/`, so the receiver recreates the directory.
+    pub file: FileDto,
+}
+
+/// Lists the files in `path` and its subdirectories for sending the directory, sorted by file name.
+/// Globs are matched against the path relative to `path`, e.g. `*.jpg` or `**/.git`.
+/// Files must match one of `include` unless it is empty, excluded directories are not entered.
+pub async fn scan_directory(
+    path: String,
+    include: Vec<String>,
+    exclude: Vec<String>,
+    symlinks: SymlinkPolicy,
+) -> anyhow::Result<Vec<ScannedFile>> {
+    let options = ScanOptions {
+        include,
+        exclude,
+        symlinks,
+    };
+    let files = localsend::model::scan::scan_dir(path, options).await?;
+
+    Ok(files
+        .into_iter()
+        .map(|scanned| ScannedFile {
+            path: scanned.path.to_string_lossy().into_owned(),
+            file: scanned.file,
+        })
+        .collect())
+}
+
+/// The MIME type of a file inferred by [`infer_mime_type`].
+pub struct InferredMimeType {
+    pub mime_type: String,
+
+    /// The content does not match the extension, e.g. an executable named `photo.jpg`.
+    /// `mime_type` is the type of the content then.
+    pub mismatch: bool,
+}
+
+/// Infers the MIME type of the file at `path` from its extension and its first bytes,
+/// e.g. to warn before opening a received file whose content does not match its name.
+pub async fn infer_mime_type(path: String) -> anyhow::Result<InferredMimeType> {
+    let inferred = localsend::util::mime::infer_from_path(path).await?;
+
+    Ok(InferredMimeType {
+        mime_type: inferred.mime_type.to_string(),
+        mismatch: inferred.mismatch,
+    })
+}
+
+/// Removes the `.part` files in `directory` left by transfers that were interrupted,
+/// e.g. because the app was killed. Subdirectories are not searched.
+/// Must not be called while files are received to `directory`, returns the removed paths.
+pub async fn remove_part_files(directory: String) -> anyhow::Result<Vec<String>> {
+    let removed = localsend::model::part::remove_part_files(directory).await?;
+
+    Ok(removed
+        .into_iter()
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect())
+}
+
+/// Returns the lowercase hex SHA-256 of the first `length` bytes received to `path` so far,
+/// to store with the progress of an interrupted transfer.
+pub async fn partial_sha256(path: String, length: u64) -> anyhow::Result<String> {
+    localsend::model::part::partial_sha256(Path::new(&path), length).await
+}
 
 #[frb(mirror(RegisterDto))]
 pub struct _RegisterDto {
@@ -38,6 +145,12 @@ pub enum _DeviceType {
     Server,
 }
 
+#[frb(mirror(SymlinkPolicy))]
+pub enum _SymlinkPolicy {
+    Skip,
+    Follow,
+}
+
 #[frb(mirror(ProtocolType))]
 pub enum _ProtocolType {
     Http,
@@ -59,6 +172,16 @@ pub struct _FileDto {
 pub struct _FileMetadata {
     pub modified: Option<String>,
     pub accessed: Option<String>,
+    pub mode: Option<u32>,
+    pub media: Option<MediaInfo>,
+}
+
+#[frb(mirror(MediaInfo))]
+pub struct _MediaInfo {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub orientation: Option<u16>,
+    pub duration_ms: Option<u64>,
 }
 
 #[frb(mirror(PrepareUploadRequestDto))]
@@ -0,0 +1,36 @@
+use crate::api::model::FileDto;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Returns the preview embedded in the image or video file at `path` as a `data:` URI,
+/// or `None` if the file has no embedded preview.
+/// The image itself is never decoded.
+pub async fn extract_embedded_preview(
+    path: String,
+    file_type: String,
+) -> anyhow::Result<Option<String>> {
+    let preview =
+        localsend::model::preview::extract_embedded_preview(Path::new(&path), &file_type).await?;
+    Ok(preview.map(|preview| preview.to_data_uri()))
+}
+
+/// Sets the embedded preview of the files that do not have one yet.
+/// `paths` maps the file IDs to their paths, files without a path are skipped.
+/// Files whose preview fails are logged and left unchanged.
+pub async fn fill_previews(
+    mut files: Vec<FileDto>,
+    paths: HashMap<String, String>,
+) -> Vec<FileDto> {
+    for file in files.iter_mut().filter(|file| file.preview.is_none()) {
+        let Some(path) = paths.get(&file.id) else {
+            continue;
+        };
+
+        match extract_embedded_preview(path.clone(), file.file_type.clone()).await {
+            Ok(preview) => file.preview = preview,
+            Err(e) => tracing::warn!("Failed to extract the preview of {path}: {e}"),
+        }
+    }
+
+    files
+}
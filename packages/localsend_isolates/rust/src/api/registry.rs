@@ -0,0 +1,79 @@
+use crate::api::webrtc::{LsSignalingConnection, RTCReceiveController, RTCSendController};
+use flutter_rust_bridge::frb;
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use uuid::Uuid;
+
+/// Objects shared between Dart isolates, keyed by handle ID.
+///
+/// An object passed to Dart can only be used by the isolate that received it.
+/// Registering it keeps it alive in Rust, so another isolate (e.g. a background isolate,
+/// or the UI isolate after it was rebuilt) can get its own reference by the ID
+/// while the transfer keeps running.
+static HANDLES: LazyLock<Mutex<HashMap<String, Handle>>> = LazyLock::new(Default::default);
+
+enum Handle {
+    Send(RTCSendController),
+    Receive(RTCReceiveController),
+    Signaling(LsSignalingConnection),
+}
+
+fn register(handle: Handle) -> String {
+    let id = Uuid::new_v4().to_string();
+    HANDLES.lock().unwrap().insert(id.clone(), handle);
+    id
+}
+
+/// Registers the controller and returns its handle ID.
+/// Streams that are already listened to keep emitting to the listening isolate.
+#[frb(sync)]
+pub fn register_send_controller(controller: &RTCSendController) -> String {
+    register(Handle::Send(controller.clone()))
+}
+
+/// Registers the controller and returns its handle ID.
+/// Streams that are already listened to keep emitting to the listening isolate.
+#[frb(sync)]
+pub fn register_receive_controller(controller: &RTCReceiveController) -> String {
+    register(Handle::Receive(controller.clone()))
+}
+
+/// Registers the connection and returns its handle ID.
+#[frb(sync)]
+pub fn register_signaling_connection(connection: &LsSignalingConnection) -> String {
+    register(Handle::Signaling(connection.clone()))
+}
+
+/// Returns the controller registered with [`register_send_controller`] to the calling isolate.
+#[frb(sync)]
+pub fn get_send_controller(id: String) -> anyhow::Result<RTCSendController> {
+    match HANDLES.lock().unwrap().get(&id) {
+        Some(Handle::Send(controller)) => Ok(controller.clone()),
+        _ => Err(anyhow::anyhow!("Send controller not found")),
+    }
+}
+
+/// Returns the controller registered with [`register_receive_controller`] to the calling isolate.
+#[frb(sync)]
+pub fn get_receive_controller(id: String) -> anyhow::Result<RTCReceiveController> {
+    match HANDLES.lock().unwrap().get(&id) {
+        Some(Handle::Receive(controller)) => Ok(controller.clone()),
+        _ => Err(anyhow::anyhow!("Receive controller not found")),
+    }
+}
+
+/// Returns the connection registered with [`register_signaling_connection`] to the calling isolate.
+#[frb(sync)]
+pub fn get_signaling_connection(id: String) -> anyhow::Result<LsSignalingConnection> {
+    match HANDLES.lock().unwrap().get(&id) {
+        Some(Handle::Signaling(connection)) => Ok(connection.clone()),
+        _ => Err(anyhow::anyhow!("Signaling connection not found")),
+    }
+}
+
+/// Releases the handle, e.g. once the transfer has ended.
+/// References already returned to isolates stay valid.
+#[frb(sync)]
+pub fn unregister_handle(id: String) {
+    HANDLES.lock().unwrap().remove(&id);
+}
@@ -0,0 +1,82 @@
+use flutter_rust_bridge::frb;
+use localsend::resume::ResumeStore;
+pub use localsend::resume::{ResumeDirection, ResumeFile, ResumeSession};
+
+/// Interrupted transfers persisted as JSON, so resuming them can be offered after the app restarts.
+pub struct RsResumeStore {
+    inner: ResumeStore,
+}
+
+/// Loads the sessions at `path`, or none if the file does not exist yet.
+pub async fn open_resume_store(path: String) -> anyhow::Result<RsResumeStore> {
+    Ok(RsResumeStore {
+        inner: ResumeStore::open(path).await?,
+    })
+}
+
+impl RsResumeStore {
+    /// All sessions, most recently updated first.
+    #[frb(sync)]
+    pub fn list(&self) -> Vec<ResumeSession> {
+        self.inner.list()
+    }
+
+    /// The sessions with a peer, e.g. to offer resuming them once it is discovered.
+    #[frb(sync)]
+    pub fn list_for_peer(&self, fingerprint: String) -> Vec<ResumeSession> {
+        self.inner.list_for_peer(&fingerprint)
+    }
+
+    #[frb(sync)]
+    pub fn get(&self, session_id: String) -> Option<ResumeSession> {
+        self.inner.get(&session_id)
+    }
+
+    /// Adds or replaces the session and returns it with the updated timestamp.
+    pub async fn save(&self, session: ResumeSession) -> anyhow::Result<ResumeSession> {
+        self.inner.save(session).await
+    }
+
+    /// Records the progress of a file of a saved session.
+    pub async fn set_progress(
+        &self,
+        session_id: String,
+        file_id: String,
+        bytes_completed: u64,
+        partial_sha256: Option<String>,
+    ) -> anyhow::Result<()> {
+        self.inner
+            .set_progress(&session_id, &file_id, bytes_completed, partial_sha256)
+            .await
+    }
+
+    pub async fn remove(&self, session_id: String) -> anyhow::Result<()> {
+        self.inner.remove(&session_id).await
+    }
+}
+
+#[frb(mirror(ResumeDirection))]
+pub enum _ResumeDirection {
+    Send,
+    Receive,
+}
+
+#[frb(mirror(ResumeSession))]
+pub struct _ResumeSession {
+    pub session_id: String,
+    pub peer_fingerprint: String,
+    pub peer_alias: String,
+    pub direction: ResumeDirection,
+    pub files: Vec<ResumeFile>,
+    pub updated_at: u64,
+}
+
+#[frb(mirror(ResumeFile))]
+pub struct _ResumeFile {
+    pub file_id: String,
+    pub token: Option<String>,
+    pub path: String,
+    pub size: u64,
+    pub bytes_completed: u64,
+    pub partial_sha256: Option<String>,
+}
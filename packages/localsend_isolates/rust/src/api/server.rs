@@ -10,9 +10,11 @@ use localsend::http::server::v2::{PrepareUploadDecisionV2, ServerEventV2};
 pub use localsend::http::server::web::WebSendI18n;
 use localsend::http::server::web::{WebSendConfig, WebSendEvent};
 use localsend::http::state::ClientInfo;
+use localsend::model::destination;
 use localsend::model::discovery::DeviceType;
 use localsend::model::transfer::{FileContent, FileDto};
 use std::collections::HashMap;
+use std::path::Path;
 use tokio::sync::{Mutex, mpsc, oneshot};
 
 /// Events emitted by the HTTP server that must be handled by the application.
@@ -98,6 +100,8 @@ pub struct RsHttpServer {
     stop_tx: Mutex<Option<oneshot::Sender<()>>>,
     pending_decision: Mutex<Option<(String, oneshot::Sender<PrepareUploadDecisionV2>)>>,
     pending_uploads: Mutex<HashMap<(String, String), oneshot::Sender<FileUploadTarget>>>,
+    /// Names or paths chosen for accepted files, by session ID and file ID.
+    destinations: Mutex<HashMap<(String, String), String>>,
     web_event_rx: Mutex<Option<mpsc::Receiver<WebSendEvent>>>,
     pending_download_decisions: Mutex<HashMap<String, oneshot::Sender<bool>>>,
     pending_downloads: Mutex<HashMap<(String, String), oneshot::Sender<FileContent>>>,
@@ -195,6 +199,7 @@ pub async fn start_server(
         stop_tx: Mutex::new(Some(stop_tx)),
         pending_decision: Mutex::new(None),
         pending_uploads: Mutex::new(HashMap::new()),
+        destinations: Mutex::new(HashMap::new()),
         web_event_rx: Mutex::new(web_event_rx),
         pending_download_decisions: Mutex::new(HashMap::new()),
         pending_downloads: Mutex::new(HashMap::new()),
@@ -294,6 +299,10 @@ impl RsHttpServer {
                     .lock()
                     .await
                     .retain(|(sid, _), _| sid != &session_id);
+                self.destinations
+                    .lock()
+                    .await
+                    .retain(|(sid, _), _| sid != &session_id);
                 let _ = sink.add(RsServerEvent::SessionEnd { session_id, reason });
             }
             ServerEventV2::PrepareUploadAborted { session_id } => {
@@ -348,7 +357,7 @@ impl RsHttpServer {
                 let _ = sink.add(RsServerEvent::WebFileDownload {
                     session_id,
                     file_id,
-                    file,
+                    file: *file,
                 });
             }
         }
@@ -358,16 +367,29 @@ impl RsHttpServer {
     ///
     /// Passing the accepted file IDs (a subset of the offered files) accepts the request.
     /// Passing `None` declines the request.
+    ///
+    /// `destinations` maps accepted file IDs to a new name or path chosen by the user,
+    /// which [RsHttpServer::respond_file_upload] applies to the path it is given.
+    /// A name or relative path is resolved against the directory of that path,
+    /// an absolute path replaces it.
     pub async fn respond_prepare_upload(
         &self,
         accepted_file_ids: Option<Vec<String>>,
+        destinations: HashMap<String, String>,
     ) -> anyhow::Result<()> {
-        let Some((_, decision_tx)) = self.pending_decision.lock().await.take() else {
+        let Some((session_id, decision_tx)) = self.pending_decision.lock().await.take() else {
             return Err(anyhow::anyhow!("No pending prepare-upload request"));
         };
 
         let decision = match accepted_file_ids {
-            Some(ids) => PrepareUploadDecisionV2::Accept(ids.into_iter().collect()),
+            Some(ids) => {
+                self.destinations.lock().await.extend(
+                    destinations
+                        .into_iter()
+                        .map(|(file_id, destination)| ((session_id.clone(), file_id), destination)),
+                );
+                PrepareUploadDecisionV2::Accept(ids.into_iter().collect())
+            }
             None => PrepareUploadDecisionV2::Decline,
         };
 
@@ -382,6 +404,9 @@ impl RsHttpServer {
     /// the file should be saved to (either a path or a file descriptor)
     /// and waits until the file has been received completely.
     ///
+    /// The destination passed to [RsHttpServer::respond_prepare_upload] for the file,
+    /// if any, is applied to [path].
+    ///
     /// The progress (fraction of [file_size]) is emitted on [sink]
     /// while the file is being received.
     pub async fn respond_file_upload(
@@ -393,15 +418,23 @@ impl RsHttpServer {
         file_descriptor: Option<i32>,
         file_size: u64,
     ) -> anyhow::Result<()> {
-        let Some(target_tx) = self
-            .pending_uploads
-            .lock()
-            .await
-            .remove(&(session_id, file_id))
-        else {
+        let key = (session_id, file_id);
+        let destination = self.destinations.lock().await.remove(&key);
+        let Some(target_tx) = self.pending_uploads.lock().await.remove(&key) else {
             return Err(anyhow::anyhow!("No pending file upload for this file"));
         };
 
+        let path = match (path, destination) {
+            (Some(path), Some(destination)) => {
+                let path = destination::apply_destination(Path::new(&path), &destination)?;
+                if let Some(parent) = path.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+                Some(path.to_string_lossy().into_owned())
+            }
+            (path, _) => path,
+        };
+
         let (progress_tx, mut progress_rx) = mpsc::channel::<u64>(16);
         tokio::spawn(async move {
             let mut last_emit = None::<std::time::Instant>;
@@ -534,6 +567,10 @@ impl RsHttpServer {
             .lock()
             .await
             .retain(|(sid, _), _| sid != &session_id);
+        self.destinations
+            .lock()
+            .await
+            .retain(|(sid, _), _| sid != &session_id);
     }
 
     /// Stops the server.
@@ -0,0 +1,180 @@
+use crate::api::webrtc::{RTCReceiveController, RTCSendController, RTCStatus};
+use crate::util::transfer::TransferHandle;
+use flutter_rust_bridge::{DartFnFuture, frb};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use uuid::Uuid;
+
+/// Tracks the WebRTC transfers by session ID,
+/// so they can be queried and controlled together.
+pub struct SessionManager {
+    sessions: Arc<Mutex<Vec<Session>>>,
+    on_end: Arc<Mutex<Option<EndCallback>>>,
+}
+
+type EndCallback = Arc<dyn Fn(SessionInfo) -> DartFnFuture<()> + Send + Sync>;
+
+struct Session {
+    session_id: String,
+    direction: SessionDirection,
+    transfer: TransferHandle,
+}
+
+#[derive(Clone, Copy)]
+pub enum SessionDirection {
+    Send,
+    Receive,
+}
+
+pub struct SessionInfo {
+    pub session_id: String,
+    pub direction: SessionDirection,
+
+    /// Latest status of the transfer. `None` until the first status.
+    pub status: Option<RTCStatus>,
+
+    /// False once the transfer has finished, failed or been cancelled.
+    pub active: bool,
+}
+
+#[frb(sync)]
+pub fn create_session_manager() -> SessionManager {
+    SessionManager {
+        sessions: Arc::new(Mutex::new(Vec::new())),
+        on_end: Arc::new(Mutex::new(None)),
+    }
+}
+
+impl SessionManager {
+    /// Starts tracking the transfer and returns its session ID.
+    pub async fn add_send(&self, controller: &RTCSendController) -> String {
+        self.add(SessionDirection::Send, controller.transfer.clone())
+            .await
+    }
+
+    /// Starts tracking the transfer and returns its session ID.
+    pub async fn add_receive(&self, controller: &RTCReceiveController) -> String {
+        self.add(SessionDirection::Receive, controller.transfer.clone())
+            .await
+    }
+
+    async fn add(&self, direction: SessionDirection, transfer: TransferHandle) -> String {
+        let session_id = Uuid::new_v4().to_string();
+        self.sessions.lock().await.push(Session {
+            session_id: session_id.clone(),
+            direction,
+            transfer: transfer.clone(),
+        });
+
+        tokio::spawn({
+            let sessions = Arc::clone(&self.sessions);
+            let on_end = Arc::clone(&self.on_end);
+            let session_id = session_id.clone();
+            async move {
+                let status = transfer.wait_ended().await;
+
+                // Removed sessions are no longer reported.
+                if !sessions
+                    .lock()
+                    .await
+                    .iter()
+                    .any(|session| session.session_id == session_id)
+                {
+                    return;
+                }
+
+                let on_end = on_end.lock().await.clone();
+                if let Some(on_end) = on_end {
+                    on_end(SessionInfo {
+                        session_id: session_id.clone(),
+                        direction,
+                        status,
+                        active: false,
+                    })
+                    .await;
+                }
+
+                // Finished sessions are not kept forever.
+                sessions
+                    .lock()
+                    .await
+                    .retain(|session| session.session_id != session_id);
+            }
+        });
+
+        session_id
+    }
+
+    /// Registers a callback invoked once a tracked transfer has finished, failed or been cancelled,
+    /// replacing the previous one.
+    ///
+    /// It is called from Rust even if the status stream of the transfer is not listened to,
+    /// e.g. to show a notification from a background isolate.
+    /// The status is [`RTCStatus::Finished`] if the transfer completed.
+    pub async fn set_end_callback(
+        &self,
+        callback: impl Fn(SessionInfo) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) {
+        *self.on_end.lock().await = Some(Arc::new(callback));
+    }
+
+    /// Removes the callback registered with [`Self::set_end_callback`].
+    pub async fn clear_end_callback(&self) {
+        *self.on_end.lock().await = None;
+    }
+
+    /// Stops tracking the session without cancelling it.
+    pub async fn remove(&self, session_id: String) {
+        self.sessions
+            .lock()
+            .await
+            .retain(|session| session.session_id != session_id);
+    }
+
+    /// Returns all tracked sessions in the order they were added.
+    /// Sessions are removed once they have ended and the end callback has returned.
+    pub async fn list_sessions(&self) -> Vec<SessionInfo> {
+        self.sessions
+            .lock()
+            .await
+            .iter()
+            .map(|session| SessionInfo {
+                session_id: session.session_id.clone(),
+                direction: session.direction,
+                status: session.transfer.latest_status(),
+                active: !session.transfer.is_finished(),
+            })
+            .collect()
+    }
+
+    /// Number of tracked sessions that are still running.
+    pub async fn active_count(&self) -> u32 {
+        self.sessions
+            .lock()
+            .await
+            .iter()
+            .filter(|session| !session.transfer.is_finished())
+            .count() as u32
+    }
+
+    /// Aborts the transfer like [`RTCSendController::cancel`] and [`RTCReceiveController::cancel`].
+    pub async fn cancel(&self, session_id: String) -> anyhow::Result<()> {
+        let sessions = self.sessions.lock().await;
+        let Some(session) = sessions
+            .iter()
+            .find(|session| session.session_id == session_id)
+        else {
+            return Err(anyhow::anyhow!("Session not found"));
+        };
+
+        session.transfer.cancel();
+        Ok(())
+    }
+
+    /// Aborts all tracked transfers.
+    pub async fn cancel_all(&self) {
+        for session in self.sessions.lock().await.iter() {
+            session.transfer.cancel();
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use flutter_rust_bridge::frb;
+use localsend::settings::SettingsStore;
+
+/// Settings shared with the headless mode, persisted as JSON.
+pub struct RsSettingsStore {
+    inner: SettingsStore,
+}
+
+/// Loads the settings at `path`, or the defaults if the file does not exist yet.
+pub async fn open_settings(path: String) -> anyhow::Result<RsSettingsStore> {
+    Ok(RsSettingsStore {
+        inner: SettingsStore::open(path).await?,
+    })
+}
+
+impl RsSettingsStore {
+    #[frb(sync)]
+    pub fn alias(&self) -> Option<String> {
+        self.inner.get().alias
+    }
+
+    pub async fn set_alias(&self, alias: Option<String>) -> anyhow::Result<()> {
+        self.inner.update(|settings| settings.alias = alias).await
+    }
+
+    #[frb(sync)]
+    pub fn download_dir(&self) -> Option<String> {
+        self.inner.get().download_dir
+    }
+
+    pub async fn set_download_dir(&self, download_dir: Option<String>) -> anyhow::Result<()> {
+        self.inner
+            .update(|settings| settings.download_dir = download_dir)
+            .await
+    }
+
+    #[frb(sync)]
+    pub fn pin(&self) -> Option<String> {
+        self.inner.get().pin
+    }
+
+    pub async fn set_pin(&self, pin: Option<String>) -> anyhow::Result<()> {
+        self.inner.update(|settings| settings.pin = pin).await
+    }
+
+    /// Maximum transfer speed in KiB per second. Unlimited if `None`.
+    #[frb(sync)]
+    pub fn bandwidth_limit(&self) -> Option<u32> {
+        self.inner.get().bandwidth_limit
+    }
+
+    pub async fn set_bandwidth_limit(&self, bandwidth_limit: Option<u32>) -> anyhow::Result<()> {
+        self.inner
+            .update(|settings| settings.bandwidth_limit = bandwidth_limit)
+            .await
+    }
+}
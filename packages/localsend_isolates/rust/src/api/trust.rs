@@ -0,0 +1,60 @@
+use crate::api::server::RegisterDtoV2;
+use flutter_rust_bridge::frb;
+pub use localsend::trust::TrustStatus;
+use localsend::trust::TrustStore;
+
+/// Pinned fingerprints of trusted devices, persisted as JSON.
+///
+/// Devices are identified by their alias. A [TrustStatus::Changed] result
+/// should be shown as a warning before accepting anything from the device.
+pub struct RsTrustStore {
+    inner: TrustStore,
+}
+
+/// Loads the trusted devices at `path`, or none if the file does not exist yet.
+pub async fn open_trust_store(path: String) -> anyhow::Result<RsTrustStore> {
+    Ok(RsTrustStore {
+        inner: TrustStore::open(path).await?,
+    })
+}
+
+impl RsTrustStore {
+    #[frb(sync)]
+    pub fn check(&self, alias: String, fingerprint: String) -> TrustStatus {
+        self.inner.check(&alias, &fingerprint)
+    }
+
+    /// Checks the sender of a [crate::api::server::RsServerEvent::PrepareUpload].
+    /// The fingerprint of the client certificate is preferred over the announced one.
+    #[frb(sync)]
+    pub fn check_offer(
+        &self,
+        info: RegisterDtoV2,
+        cert_fingerprint: Option<String>,
+    ) -> TrustStatus {
+        let fingerprint = cert_fingerprint.unwrap_or(info.fingerprint);
+        self.inner.check(&info.alias, &fingerprint)
+    }
+
+    /// The pinned fingerprint of the device, if any.
+    #[frb(sync)]
+    pub fn fingerprint(&self, alias: String) -> Option<String> {
+        self.inner.get(&alias).map(|device| device.fingerprint)
+    }
+
+    /// Pins the fingerprint for the device, replacing the previous one.
+    pub async fn trust(&self, alias: String, fingerprint: String) -> anyhow::Result<()> {
+        self.inner.trust(&alias, &fingerprint).await
+    }
+
+    pub async fn forget(&self, alias: String) -> anyhow::Result<()> {
+        self.inner.forget(&alias).await
+    }
+}
+
+#[frb(mirror(TrustStatus))]
+pub enum _TrustStatus {
+    Trusted,
+    New,
+    Changed,
+}
@@ -1,21 +1,39 @@
 use crate::frb_generated::StreamSink;
-use bytes::Bytes;
+use crate::util::bytes::BufferOptions;
+use crate::util::progress::{self, Progress, ProgressTx};
+use crate::util::transfer::{self, TransferHandle};
+use bytes::{Bytes, BytesMut};
 use flutter_rust_bridge::{DartFnFuture, frb};
 use localsend::crypto::token::SigningTokenKey;
+pub use localsend::model::collision::CollisionPolicy;
+use localsend::model::collision::{self, Reservation};
+use localsend::model::destination;
 use localsend::model::discovery::DeviceType;
+use localsend::model::file;
+use localsend::model::part::PartFile;
 use localsend::model::transfer::FileDto;
 pub use localsend::webrtc::signaling::{
-    ClientInfo, ClientInfoWithoutId, ManagedSignalingConnection, SignalingConnection,
-    WsServerMessage, WsServerSdpMessage,
+    AnnouncementKind, ClientInfo, ClientInfoWithoutId, ConnectOptions, ManagedSignalingConnection,
+    SignalingConnection, WsServerMessage, WsServerSdpMessage,
 };
 pub use localsend::webrtc::webrtc::{
-    PinConfig, RTCFile, RTCFileError, RTCSendFileResponse, RTCStatus,
+    PinConfig, RTCFile, RTCFileError, RTCSendFileResponse, RTCStats, RTCStatus,
 };
-use std::collections::HashSet;
-use std::sync::Arc;
-use tokio::sync::{Mutex, mpsc, oneshot};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::sync::{Arc, Weak};
+use tokio::io::AsyncReadExt;
+use tokio::sync::{Mutex, mpsc, oneshot, watch};
+use tokio::task::JoinHandle;
 use uuid::Uuid;
 
+/// Bytes read at once by [`RTCSendController::send_file_from_path`].
+const FILE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Files [`RTCSendController::send_files_from_paths`] reads from at once,
+/// so the next file is opened while the previous one is still being sent.
+const SEND_PIPELINE_DEPTH: usize = 2;
+
 pub struct ProposingClientInfo {
     pub alias: String,
     pub version: String,
@@ -31,6 +49,7 @@ impl ProposingClientInfo {
             device_model: self.device_model.clone(),
             device_type: self.device_type.clone(),
             token: localsend::crypto::token::generate_token_timestamp(&signing_key)?,
+            capabilities: localsend::webrtc::signaling::capability::SUPPORTED,
         })
     }
 }
@@ -40,6 +59,7 @@ pub async fn connect(
     uri: String,
     info: ProposingClientInfo,
     private_key: String,
+    allow_insecure: bool,
     on_connection: impl Fn(LsSignalingConnection) -> DartFnFuture<()>,
 ) {
     let Ok(signing_key) = localsend::crypto::token::parse_private_key(&private_key) else {
@@ -52,14 +72,20 @@ pub async fn connect(
         return;
     };
 
-    let connection = match SignalingConnection::connect(uri, &client_info).await {
-        Ok(connection) => connection,
-        Err(e) => {
-            let _ = sink.add_error(e.to_string());
-            return;
-        }
+    let options = ConnectOptions {
+        allow_insecure,
+        ..ConnectOptions::default()
     };
 
+    let connection =
+        match SignalingConnection::connect_with_options(uri, &client_info, &options).await {
+            Ok(connection) => connection,
+            Err(e) => {
+                let _ = sink.add_error(e.to_string());
+                return;
+            }
+        };
+
     let (managed_connection, mut rx) = connection.start_listener();
     on_connection(LsSignalingConnection {
         inner: Arc::new(managed_connection),
@@ -71,11 +97,25 @@ pub async fn connect(
     }
 }
 
+#[derive(Clone)]
 pub struct LsSignalingConnection {
     inner: Arc<ManagedSignalingConnection>,
 }
 
 impl LsSignalingConnection {
+    /// Emits the peers in the room whenever they change, starting with the current ones.
+    /// Peers with the same token are listed once, e.g. a client that reconnected
+    /// before its previous session was closed by the server.
+    pub async fn listen_peers(&self, sink: StreamSink<Vec<ClientInfo>>) {
+        let mut peers_rx = self.inner.watch_peers().await;
+        loop {
+            let peers = peers_rx.borrow_and_update().clone();
+            if sink.add(peers).is_err() || peers_rx.changed().await.is_err() {
+                break;
+            }
+        }
+    }
+
     pub async fn update_info(&self, info: ClientInfoWithoutId) -> anyhow::Result<()> {
         self.inner.send_update(info).await?;
         Ok(())
@@ -91,11 +131,18 @@ impl LsSignalingConnection {
         files: Vec<FileDto>,
     ) -> anyhow::Result<RTCSendController> {
         let (status_tx, status_rx) = mpsc::channel::<RTCStatus>(1);
+        let (task_alive, task_alive_rx) = watch::channel(());
+        let (status_tx, latest_status, ended) = transfer::track_status(status_tx, task_alive_rx);
+        let (stats_tx, stats_rx) = mpsc::channel::<RTCStats>(1);
         let (selected_tx, selected_rx) = oneshot::channel::<HashSet<String>>();
         let (error_tx, error_rx) = mpsc::channel::<RTCFileError>(1);
         let (pin_tx, mut pin_rx) = mpsc::channel::<oneshot::Sender<String>>(1);
         let (pair_tx, pair_rx) = oneshot::channel::<oneshot::Sender<bool>>();
         let (send_tx, send_rx) = mpsc::channel::<RTCFile>(1);
+        let (progress_tx, progress_rx) = watch::channel(Progress::new());
+        let (paused_tx, _) = watch::channel(false);
+        let progress_tx = Arc::new(progress_tx);
+        let progress_weak = Arc::downgrade(&progress_tx);
 
         let managed_connection = self.inner.clone();
 
@@ -108,7 +155,11 @@ impl LsSignalingConnection {
             None => None,
         };
 
-        tokio::spawn(async move {
+        let status_weak = status_tx.downgrade();
+        let task = tokio::spawn(async move {
+            // Dropped when the task ends or is aborted.
+            let _task_alive = task_alive;
+
             let result = localsend::webrtc::webrtc::send_offer(
                 &managed_connection,
                 stun_servers,
@@ -118,6 +169,7 @@ impl LsSignalingConnection {
                 pin,
                 files,
                 status_tx.clone(),
+                stats_tx,
                 selected_tx,
                 error_tx,
                 pin_tx,
@@ -126,6 +178,8 @@ impl LsSignalingConnection {
             )
             .await;
 
+            drop(progress_tx);
+
             if let Err(e) = result {
                 let _ = status_tx.send(RTCStatus::Error(e.to_string())).await;
             }
@@ -152,11 +206,21 @@ impl LsSignalingConnection {
         });
 
         Ok(RTCSendController {
-            status_rx,
+            status_rx: Arc::new(Mutex::new(Some(status_rx))),
+            stats_rx: Arc::new(Mutex::new(Some(stats_rx))),
             selected_rx: Arc::new(Mutex::new(Some(selected_rx))),
-            error_rx,
+            error_rx: Arc::new(Mutex::new(Some(error_rx))),
             pin_tx: pin_sender,
             send_tx,
+            progress_tx: progress_weak,
+            progress_rx,
+            transfer: TransferHandle::new(
+                task.abort_handle(),
+                status_weak,
+                paused_tx,
+                latest_status,
+                ended,
+            ),
         })
     }
 
@@ -167,14 +231,22 @@ impl LsSignalingConnection {
         private_key: &str,
         expecting_public_key: Option<ExpectingPublicKey>,
         pin: Option<PinConfig>,
+        auto_accept: Option<AutoAcceptConfig>,
     ) -> anyhow::Result<RTCReceiveController> {
         let (status_tx, status_rx) = mpsc::channel::<RTCStatus>(1);
+        let (task_alive, task_alive_rx) = watch::channel(());
+        let (status_tx, latest_status, ended) = transfer::track_status(status_tx, task_alive_rx);
+        let (stats_tx, stats_rx) = mpsc::channel::<RTCStats>(1);
         let (files_tx, files_rx) = oneshot::channel::<Vec<FileDto>>();
         let (selected_tx, selected_rx) = oneshot::channel::<Option<HashSet<String>>>();
         let (error_tx, error_rx) = mpsc::channel::<RTCFileError>(1);
         let (receiving_tx, receiving_rx) = mpsc::channel::<RTCFile>(1);
         let (pin_tx, mut pin_rx) = mpsc::channel::<oneshot::Sender<String>>(1);
         let (file_status_tx, file_status_rx) = mpsc::channel::<RTCSendFileResponse>(1);
+        let (progress_tx, progress_rx) = watch::channel(Progress::new());
+        let (paused_tx, _) = watch::channel(false);
+        let progress_tx = Arc::new(progress_tx);
+        let progress_weak = Arc::downgrade(&progress_tx);
 
         let managed_connection = self.inner.clone();
 
@@ -187,7 +259,31 @@ impl LsSignalingConnection {
             None => None,
         };
 
-        tokio::spawn(async move {
+        let (files_tx, selected_tx, receiving_rx) = match auto_accept {
+            Some(config) => {
+                let (core_files_tx, core_files_rx) = oneshot::channel::<Vec<FileDto>>();
+                tokio::spawn(auto_accept_files(
+                    config,
+                    core_files_rx,
+                    files_tx,
+                    selected_tx,
+                    receiving_rx,
+                    file_status_tx.clone(),
+                    progress_weak.clone(),
+                    paused_tx.subscribe(),
+                ));
+
+                // Selection and receiving are handled by the task.
+                (core_files_tx, None, None)
+            }
+            None => (files_tx, Some(selected_tx), Some(receiving_rx)),
+        };
+
+        let status_weak = status_tx.downgrade();
+        let task = tokio::spawn(async move {
+            // Dropped when the task ends or is aborted.
+            let _task_alive = task_alive;
+
             let result = localsend::webrtc::webrtc::accept_offer(
                 &managed_connection,
                 stun_servers,
@@ -196,6 +292,7 @@ impl LsSignalingConnection {
                 expecting_public_key,
                 pin,
                 status_tx.clone(),
+                stats_tx,
                 files_tx,
                 selected_rx,
                 error_tx,
@@ -205,6 +302,8 @@ impl LsSignalingConnection {
             )
             .await;
 
+            drop(progress_tx);
+
             if let Err(e) = result {
                 let _ = status_tx.send(RTCStatus::Error(e.to_string())).await;
             }
@@ -223,12 +322,23 @@ impl LsSignalingConnection {
 
         Ok(RTCReceiveController {
             status_rx: Arc::new(Mutex::new(Some(status_rx))),
+            stats_rx: Arc::new(Mutex::new(Some(stats_rx))),
             files_rx: Arc::new(Mutex::new(Some(files_rx))),
-            selected_tx: Arc::new(Mutex::new(Some(selected_tx))),
+            selected_tx: Arc::new(Mutex::new(selected_tx)),
             error_rx: Arc::new(Mutex::new(Some(error_rx))),
             pin_tx: pin_sender,
-            receiving_rx: Arc::new(Mutex::new(Some(receiving_rx))),
+            receiving_rx: Arc::new(Mutex::new(receiving_rx)),
+            destinations: Arc::new(Mutex::new(HashMap::new())),
             file_status_tx,
+            progress_tx: progress_weak,
+            progress_rx,
+            transfer: TransferHandle::new(
+                task.abort_handle(),
+                status_weak,
+                paused_tx,
+                latest_status,
+                ended,
+            ),
         })
     }
 }
@@ -240,21 +350,89 @@ pub struct ExpectingPublicKey {
     pub kind: String,
 }
 
+/// Accepts an offer without asking the user which files to receive.
+/// Used for trusted devices ("quick save").
+pub struct AutoAcceptConfig {
+    /// Directory the files are written to.
+    pub directory: String,
+
+    /// Lowercase file extensions without dot (e.g. "jpg") of the files to accept.
+    /// All files are accepted if empty.
+    pub extensions: Vec<String>,
+
+    /// Applied to files whose name exists in the directory.
+    /// [CollisionPolicy::Ask] fails these files.
+    pub collision: CollisionPolicy,
+}
+
+impl AutoAcceptConfig {
+    fn accepts(&self, file_name: &str) -> bool {
+        if self.extensions.is_empty() {
+            return true;
+        }
+
+        Path::new(file_name)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| {
+                self.extensions
+                    .iter()
+                    .any(|e| e.eq_ignore_ascii_case(extension))
+            })
+    }
+}
+
+/// Bytes transferred of one file.
+pub struct RTCFileProgress {
+    pub file_id: String,
+
+    /// Total bytes sent or received so far.
+    pub bytes: u64,
+}
+
+#[derive(Clone)]
 pub struct RTCSendController {
-    status_rx: mpsc::Receiver<RTCStatus>,
+    status_rx: Arc<Mutex<Option<mpsc::Receiver<RTCStatus>>>>,
+    stats_rx: Arc<Mutex<Option<mpsc::Receiver<RTCStats>>>>,
     selected_rx: Arc<Mutex<Option<oneshot::Receiver<HashSet<String>>>>>,
-    error_rx: mpsc::Receiver<RTCFileError>,
+    error_rx: Arc<Mutex<Option<mpsc::Receiver<RTCFileError>>>>,
     pin_tx: Arc<Mutex<Option<oneshot::Sender<String>>>>,
     send_tx: mpsc::Sender<RTCFile>,
+    progress_tx: Weak<ProgressTx>,
+    progress_rx: watch::Receiver<Progress>,
+    pub(crate) transfer: TransferHandle,
 }
 
 impl RTCSendController {
-    pub async fn listen_status(&mut self, sink: StreamSink<RTCStatus>) {
-        while let Some(status) = self.status_rx.recv().await {
+    /// Aborts the transfer and closes the connection, which disconnects the remote peer.
+    /// The status stream ends without [`RTCStatus::Finished`].
+    pub fn cancel(&self) {
+        self.transfer.cancel();
+    }
+
+    pub async fn listen_status(&self, sink: StreamSink<RTCStatus>) {
+        let Some(mut status_rx) = self.status_rx.lock().await.take() else {
+            let _ = sink.add_error(anyhow::anyhow!("Status stream already listened to"));
+            return;
+        };
+        while let Some(status) = status_rx.recv().await {
             let _ = sink.add(status);
         }
     }
 
+    /// Emits the speed, round-trip time and path of the connection
+    /// every [`localsend::webrtc::webrtc::STATS_INTERVAL`] once connected.
+    /// Ends once the connection is closed.
+    pub async fn listen_stats(&self, sink: StreamSink<RTCStats>) {
+        let Some(mut stats_rx) = self.stats_rx.lock().await.take() else {
+            let _ = sink.add_error(anyhow::anyhow!("Stats stream already listened to"));
+            return;
+        };
+        while let Some(stats) = stats_rx.recv().await {
+            let _ = sink.add(stats);
+        }
+    }
+
     pub async fn listen_selected_files(&self) -> anyhow::Result<HashSet<String>> {
         let Some(selected_rx) = self.selected_rx.lock().await.take() else {
             return Err(anyhow::anyhow!("Selected files already received"));
@@ -267,12 +445,33 @@ impl RTCSendController {
         Ok(selected)
     }
 
-    pub async fn listen_error(&mut self, sink: StreamSink<RTCFileError>) {
-        while let Some(error) = self.error_rx.recv().await {
+    pub async fn listen_error(&self, sink: StreamSink<RTCFileError>) {
+        let Some(mut error_rx) = self.error_rx.lock().await.take() else {
+            let _ = sink.add_error(anyhow::anyhow!("Error stream already listened to"));
+            return;
+        };
+        while let Some(error) = error_rx.recv().await {
             let _ = sink.add(error);
         }
     }
 
+    /// Emits the bytes sent of every file that made progress.
+    /// Ends once the transfer has finished.
+    pub async fn listen_progress(&self, sink: StreamSink<RTCFileProgress>) {
+        listen_progress(self.progress_rx.clone(), sink).await;
+    }
+
+    /// Stops handing file data to the data channel until [`Self::resume`] is called.
+    /// Emits [`RTCStatus::Paused`] on the status stream.
+    pub async fn pause(&self) {
+        self.transfer.set_paused(true).await;
+    }
+
+    /// Emits [`RTCStatus::Resumed`] on the status stream.
+    pub async fn resume(&self) {
+        self.transfer.set_paused(false).await;
+    }
+
     pub async fn send_pin(&self, pin: String) -> anyhow::Result<()> {
         let Some(pin_tx) = self.pin_tx.lock().await.take() else {
             return Err(anyhow::anyhow!("Pin already sent"));
@@ -289,37 +488,158 @@ impl RTCSendController {
         let (tx, rx) = mpsc::channel::<Bytes>(1);
         self.send_tx
             .send(RTCFile {
-                file_id,
+                file_id: file_id.clone(),
                 binary_rx: rx,
             })
             .await?;
 
-        Ok(RTCFileSender { binary_tx: tx })
+        Ok(RTCFileSender {
+            file_id,
+            binary_tx: tx,
+            progress_tx: self.progress_tx.clone(),
+            paused_rx: self.transfer.paused_rx(),
+        })
+    }
+
+    /// Streams the file at `path` without copying its chunks across the FFI boundary.
+    /// Returns once the whole file has been handed to the data channel.
+    pub async fn send_file_from_path(&self, file_id: String, path: String) -> anyhow::Result<()> {
+        let (file, sender) = self.open_file_from_path(file_id, &path).await?;
+        sender.send_from_file(file).await
+    }
+
+    /// Sends the files with `file_ids` in order, each read from its path in `paths`,
+    /// so Dart does not have to call [`Self::send_file`] for every file.
+    /// Pass the IDs returned by [`Self::listen_selected_files`].
+    ///
+    /// Emits the result of every file once it has been handed to the data channel.
+    /// Ends after the last file.
+    pub async fn send_files_from_paths(
+        &self,
+        sink: StreamSink<RTCSendFileResponse>,
+        file_ids: Vec<String>,
+        paths: HashMap<String, String>,
+    ) {
+        let mut sending = VecDeque::with_capacity(SEND_PIPELINE_DEPTH);
+        for file_id in file_ids {
+            if sending.len() == SEND_PIPELINE_DEPTH
+                && let Some((file_id, task)) = sending.pop_front()
+            {
+                let _ = sink.add(send_result(file_id, task).await);
+            }
+
+            let opened = match paths.get(&file_id) {
+                Some(path) => self.open_file_from_path(file_id.clone(), path).await,
+                None => Err(anyhow::anyhow!("No path for file {file_id}")),
+            };
+            match opened {
+                Ok((file, sender)) => {
+                    let task = tokio::spawn(async move { sender.send_from_file(file).await });
+                    sending.push_back((file_id, task));
+                }
+                Err(e) => {
+                    let _ = sink.add(RTCSendFileResponse {
+                        id: file_id,
+                        success: false,
+                        error: Some(e.to_string()),
+                    });
+                }
+            }
+        }
+
+        while let Some((file_id, task)) = sending.pop_front() {
+            let _ = sink.add(send_result(file_id, task).await);
+        }
+    }
+
+    async fn open_file_from_path(
+        &self,
+        file_id: String,
+        path: &str,
+    ) -> anyhow::Result<(tokio::fs::File, RTCFileSender)> {
+        // Opened first, so the peer is not notified about a file that cannot be read.
+        let file = tokio::fs::File::open(path).await?;
+        let sender = self.send_file(file_id).await?;
+        Ok((file, sender))
+    }
+}
+
+async fn send_result(file_id: String, task: JoinHandle<anyhow::Result<()>>) -> RTCSendFileResponse {
+    let error = match task.await {
+        Ok(Ok(())) => None,
+        Ok(Err(e)) => Some(e.to_string()),
+        Err(e) => Some(e.to_string()),
+    };
+
+    RTCSendFileResponse {
+        id: file_id,
+        success: error.is_none(),
+        error,
     }
 }
 
 pub struct RTCFileSender {
+    file_id: String,
     binary_tx: mpsc::Sender<Bytes>,
+    progress_tx: Weak<ProgressTx>,
+    paused_rx: watch::Receiver<bool>,
 }
 
 impl RTCFileSender {
     pub async fn send(&self, data: Vec<u8>) -> anyhow::Result<()> {
-        self.binary_tx.send(Bytes::from(data)).await?;
+        self.send_bytes(Bytes::from(data)).await
+    }
+
+    async fn send_from_file(&self, mut file: tokio::fs::File) -> anyhow::Result<()> {
+        let mut buffer = BytesMut::with_capacity(FILE_CHUNK_SIZE);
+        loop {
+            buffer.reserve(FILE_CHUNK_SIZE);
+            if file.read_buf(&mut buffer).await? == 0 {
+                break;
+            }
+            self.send_bytes(buffer.split().freeze()).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn send_bytes(&self, data: Bytes) -> anyhow::Result<()> {
+        wait_resumed(&self.paused_rx).await;
+
+        // Upgraded before sending, so the transfer cannot finish before the bytes are counted.
+        let progress_tx = self.progress_tx.upgrade();
+        let len = data.len();
+        self.binary_tx.send(data).await?;
+        if let Some(progress_tx) = progress_tx {
+            progress::add_bytes(&progress_tx, &self.file_id, len);
+        }
         Ok(())
     }
 }
 
+#[derive(Clone)]
 pub struct RTCReceiveController {
     status_rx: Arc<Mutex<Option<mpsc::Receiver<RTCStatus>>>>,
+    stats_rx: Arc<Mutex<Option<mpsc::Receiver<RTCStats>>>>,
     files_rx: Arc<Mutex<Option<oneshot::Receiver<Vec<FileDto>>>>>,
     selected_tx: Arc<Mutex<Option<oneshot::Sender<Option<HashSet<String>>>>>>,
     error_rx: Arc<Mutex<Option<mpsc::Receiver<RTCFileError>>>>,
     pin_tx: Arc<Mutex<Option<oneshot::Sender<String>>>>,
     receiving_rx: Arc<Mutex<Option<mpsc::Receiver<RTCFile>>>>,
+    destinations: Arc<Mutex<HashMap<String, String>>>,
     file_status_tx: mpsc::Sender<RTCSendFileResponse>,
+    progress_tx: Weak<ProgressTx>,
+    progress_rx: watch::Receiver<Progress>,
+    pub(crate) transfer: TransferHandle,
 }
 
 impl RTCReceiveController {
+    /// Aborts the transfer and closes the connection, which disconnects the remote peer.
+    /// The status stream ends without [`RTCStatus::Finished`].
+    pub fn cancel(&self) {
+        self.transfer.cancel();
+    }
+
     pub async fn listen_status(&self, sink: StreamSink<RTCStatus>) {
         let Some(mut status_rx) = self.status_rx.lock().await.take() else {
             let _ = sink.add_error(anyhow::anyhow!("Status stream already listened to"));
@@ -330,6 +650,19 @@ impl RTCReceiveController {
         }
     }
 
+    /// Emits the speed, round-trip time and path of the connection
+    /// every [`localsend::webrtc::webrtc::STATS_INTERVAL`] once connected.
+    /// Ends once the connection is closed.
+    pub async fn listen_stats(&self, sink: StreamSink<RTCStats>) {
+        let Some(mut stats_rx) = self.stats_rx.lock().await.take() else {
+            let _ = sink.add_error(anyhow::anyhow!("Stats stream already listened to"));
+            return;
+        };
+        while let Some(stats) = stats_rx.recv().await {
+            let _ = sink.add(stats);
+        }
+    }
+
     pub async fn listen_files(&self) -> anyhow::Result<Vec<FileDto>> {
         let Some(files_rx) = self.files_rx.lock().await.take() else {
             return Err(anyhow::anyhow!("Files already received"));
@@ -354,11 +687,23 @@ impl RTCReceiveController {
         Ok(())
     }
 
-    pub async fn send_selection(&self, selection: HashSet<String>) -> anyhow::Result<()> {
+    /// Accepts the files with the IDs in `selection`.
+    ///
+    /// `destinations` maps file IDs to a new name or path chosen by the user,
+    /// which [`RTCFileReceiver::receive_to_path`] applies to the path it is given.
+    /// A name or relative path is resolved against the directory of that path,
+    /// an absolute path replaces it.
+    pub async fn send_selection(
+        &self,
+        selection: HashSet<String>,
+        destinations: HashMap<String, String>,
+    ) -> anyhow::Result<()> {
         let Some(selected_tx) = self.selected_tx.lock().await.take() else {
             return Err(anyhow::anyhow!("Selected files already sent"));
         };
 
+        *self.destinations.lock().await = destinations;
+
         selected_tx
             .send(Some(selection))
             .map_err(|_| anyhow::anyhow!("Selected files channel closed"))?;
@@ -388,28 +733,78 @@ impl RTCReceiveController {
         }
     }
 
+    /// Emits the bytes received of every file that made progress.
+    /// Ends once the transfer has finished.
+    pub async fn listen_progress(&self, sink: StreamSink<RTCFileProgress>) {
+        listen_progress(self.progress_rx.clone(), sink).await;
+    }
+
     pub async fn listen_receiving(&self, sink: StreamSink<RTCFileReceiver>) {
         let Some(mut receiving_rx) = self.receiving_rx.lock().await.take() else {
             let _ = sink.add_error(anyhow::anyhow!("Receiving stream already listened to"));
             return;
         };
         while let Some(file) = receiving_rx.recv().await {
+            let destination = self.destinations.lock().await.remove(&file.file_id);
             let _ = sink.add(RTCFileReceiver {
                 file_id: file.file_id,
+                destination,
                 binary_rx: Arc::new(Mutex::new(Some(file.binary_rx))),
+                progress_tx: self.progress_tx.clone(),
+                paused_rx: self.transfer.paused_rx(),
             });
         }
     }
 
+    /// Stops reading file data from the data channel until [`Self::resume`] is called.
+    /// Emits [`RTCStatus::Paused`] on the status stream.
+    pub async fn pause(&self) {
+        self.transfer.set_paused(true).await;
+    }
+
+    /// Emits [`RTCStatus::Resumed`] on the status stream.
+    pub async fn resume(&self) {
+        self.transfer.set_paused(false).await;
+    }
+
     pub async fn send_file_status(&self, status: RTCSendFileResponse) -> anyhow::Result<()> {
         self.file_status_tx.send(status).await?;
         Ok(())
     }
 }
 
+/// Result of [`RTCFileReceiver::receive_to_path`].
+pub struct RTCReceivedFile {
+    pub size: u64,
+
+    /// Lowercase hex SHA-256 of the content if requested or verified, and written.
+    pub sha256: Option<String>,
+
+    /// Path the file was written to, which differs from the requested one if it was renamed.
+    /// `None` if it was skipped.
+    pub path: Option<String>,
+}
+
+/// How [`RTCFileReceiver::receive`] combines the received chunks before passing them to Dart.
+/// Larger chunks mean fewer calls into Dart on fast links,
+/// a flush interval bounds the latency, e.g. when streaming a preview.
+pub struct ReceiveBufferConfig {
+    /// Chunks are combined until at least this many bytes are buffered.
+    pub target_size: u32,
+
+    /// Buffered bytes are passed on after this long even if `target_size` is not reached.
+    /// `None` waits for `target_size` or the end of the file.
+    pub flush_interval_ms: Option<u32>,
+}
+
 pub struct RTCFileReceiver {
     file_id: String,
+
+    /// Name or path chosen for the file in [`RTCReceiveController::send_selection`].
+    destination: Option<String>,
     binary_rx: Arc<Mutex<Option<mpsc::Receiver<Bytes>>>>,
+    progress_tx: Weak<ProgressTx>,
+    paused_rx: watch::Receiver<bool>,
 }
 
 impl RTCFileReceiver {
@@ -417,19 +812,268 @@ impl RTCFileReceiver {
         self.file_id.to_owned()
     }
 
-    pub async fn receive(&self, sink: StreamSink<Vec<u8>>) -> anyhow::Result<()> {
+    /// Emits the content of the file.
+    /// Chunks are combined until 1 MB is buffered unless `buffer` is set.
+    pub async fn receive(
+        &self,
+        sink: StreamSink<Vec<u8>>,
+        buffer: Option<ReceiveBufferConfig>,
+    ) -> anyhow::Result<()> {
         let Some(rx) = self.binary_rx.lock().await.take() else {
             return Err(anyhow::anyhow!("File receiver listened to"));
         };
 
-        let mut rx = crate::util::bytes::buffer_receiver(rx).await;
+        let options = match buffer {
+            Some(config) => BufferOptions {
+                target_size: config.target_size as usize,
+                flush_interval: config
+                    .flush_interval_ms
+                    .map(|ms| std::time::Duration::from_millis(ms as u64)),
+            },
+            None => BufferOptions::default(),
+        };
 
-        while let Some(data) = rx.recv().await {
+        // Held until the last buffered bytes are counted.
+        let progress_tx = self.progress_tx.upgrade();
+        let mut rx = crate::util::bytes::buffer_receiver(rx, options).await;
+
+        loop {
+            wait_resumed(&self.paused_rx).await;
+            let Some(data) = rx.recv().await else {
+                break;
+            };
+            if let Some(progress_tx) = &progress_tx {
+                progress::add_bytes(progress_tx, &self.file_id, data.len());
+            }
             let _ = sink.add(data);
         }
 
         Ok(())
     }
+
+    /// Writes the file to `path` without passing its chunks through Dart.
+    /// The data is written to `<path>.part` which is renamed once complete,
+    /// so `path` never contains a partial file.
+    /// If `expected_sha256` is set (see [FileDto::sha256]), a mismatching file is discarded.
+    /// The destination passed to [`RTCReceiveController::send_selection`], if any, is applied to `path`.
+    ///
+    /// If a file with the name exists, `collision` decides where the data goes.
+    /// [CollisionPolicy::Ask] fails before receiving anything, so this can be called again.
+    pub async fn receive_to_path(
+        &self,
+        path: String,
+        with_hash: bool,
+        expected_sha256: Option<String>,
+        collision: CollisionPolicy,
+    ) -> anyhow::Result<RTCReceivedFile> {
+        self.write_to_path(path, with_hash, None, expected_sha256, collision)
+            .await
+    }
+
+    /// Fails without creating `path` if the content does not have the expected size,
+    /// e.g. because the connection was closed.
+    async fn write_to_path(
+        &self,
+        path: String,
+        with_hash: bool,
+        expected_size: Option<u64>,
+        expected_sha256: Option<String>,
+        collision: CollisionPolicy,
+    ) -> anyhow::Result<RTCReceivedFile> {
+        let path = match &self.destination {
+            Some(destination) => {
+                let path = destination::apply_destination(Path::new(&path), destination)?;
+                if let Some(parent) = path.parent() {
+                    tokio::fs::create_dir_all(parent).await?;
+                }
+                path
+            }
+            None => path.into(),
+        };
+
+        let Some(mut rx) = self.binary_rx.lock().await.take() else {
+            return Err(anyhow::anyhow!("File receiver listened to"));
+        };
+
+        let reservation = match collision::reserve_path(path, collision).await {
+            Ok(reservation) => reservation,
+            Err(e) => {
+                // Nothing was received yet, so the call can be repeated, e.g. after asking the user.
+                *self.binary_rx.lock().await = Some(rx);
+                return Err(e);
+            }
+        };
+
+        let progress_tx = self.progress_tx.upgrade();
+        let (path, reserved) = match reservation {
+            Reservation::Reserved(path) => (path, true),
+            Reservation::Overwrite(path) => (path, false),
+            Reservation::Skip => {
+                // Still consumed, so the sender can continue with the next file.
+                let mut size = 0;
+                loop {
+                    wait_resumed(&self.paused_rx).await;
+                    let Some(data) = rx.recv().await else {
+                        break;
+                    };
+                    if let Some(progress_tx) = &progress_tx {
+                        progress::add_bytes(progress_tx, &self.file_id, data.len());
+                    }
+                    size += data.len() as u64;
+                }
+                return Ok(RTCReceivedFile {
+                    size,
+                    sha256: None,
+                    path: None,
+                });
+            }
+        };
+
+        let written = async {
+            let mut file = PartFile::create(&path, with_hash || expected_sha256.is_some()).await?;
+            if let Some(size) = expected_size.filter(|size| *size > 0)
+                && let Err(e) = file.preallocate(size).await
+            {
+                file.cleanup().await;
+                return Err(anyhow::anyhow!(
+                    "Not enough space for {}: {e}",
+                    path.display()
+                ));
+            }
+            loop {
+                wait_resumed(&self.paused_rx).await;
+                let Some(data) = rx.recv().await else {
+                    break;
+                };
+                if let Err(e) = file.write(&data).await {
+                    file.cleanup().await;
+                    return Err(e.into());
+                }
+                if let Some(progress_tx) = &progress_tx {
+                    progress::add_bytes(progress_tx, &self.file_id, data.len());
+                }
+            }
+            file.finalize(expected_size, expected_sha256.as_deref())
+                .await
+        }
+        .await;
+
+        let file = match written {
+            Ok(file) => file,
+            Err(e) => {
+                if reserved {
+                    let _ = tokio::fs::remove_file(&path).await;
+                }
+                return Err(e);
+            }
+        };
+
+        Ok(RTCReceivedFile {
+            size: file.size,
+            sha256: file.sha256,
+            path: Some(file.path.to_string_lossy().into_owned()),
+        })
+    }
+}
+
+/// Selects the files matching the config and writes them to its directory.
+/// The file list is still forwarded to [`RTCReceiveController::listen_files`].
+#[allow(clippy::too_many_arguments)]
+async fn auto_accept_files(
+    config: AutoAcceptConfig,
+    files_rx: oneshot::Receiver<Vec<FileDto>>,
+    files_tx: oneshot::Sender<Vec<FileDto>>,
+    selected_tx: oneshot::Sender<Option<HashSet<String>>>,
+    mut receiving_rx: mpsc::Receiver<RTCFile>,
+    file_status_tx: mpsc::Sender<RTCSendFileResponse>,
+    progress_tx: Weak<ProgressTx>,
+    paused_rx: watch::Receiver<bool>,
+) {
+    let Ok(files) = files_rx.await else {
+        return;
+    };
+
+    let selection = files
+        .iter()
+        .filter(|file| config.accepts(&file.file_name))
+        .map(|file| file.id.clone())
+        .collect::<HashSet<String>>();
+    let _ = files_tx.send(files.clone());
+    if selected_tx.send(Some(selection)).is_err() {
+        return;
+    }
+
+    while let Some(file) = receiving_rx.recv().await {
+        let Some(dto) = files.iter().find(|f| f.id == file.file_id) else {
+            continue;
+        };
+
+        let receiver = RTCFileReceiver {
+            file_id: file.file_id,
+            destination: None,
+            binary_rx: Arc::new(Mutex::new(Some(file.binary_rx))),
+            progress_tx: progress_tx.clone(),
+            paused_rx: paused_rx.clone(),
+        };
+
+        let result = match target_path(&config.directory, &dto.file_name).await {
+            Ok(path) => {
+                receiver
+                    .write_to_path(
+                        path,
+                        false,
+                        Some(dto.size),
+                        dto.sha256.clone(),
+                        config.collision,
+                    )
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+
+        let _ = file_status_tx
+            .send(RTCSendFileResponse {
+                id: dto.id.clone(),
+                success: result.is_ok(),
+                error: result.err().map(|e| e.to_string()),
+            })
+            .await;
+    }
+}
+
+/// Returns the path of `file_name` in `directory`, creating the directories on the way.
+/// The name is sanitized, so the sender cannot write outside of `directory`.
+async fn target_path(directory: &str, file_name: &str) -> anyhow::Result<String> {
+    let path = Path::new(directory).join(file::sanitize_file_name(file_name)?);
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+
+    Ok(path.to_string_lossy().into_owned())
+}
+
+/// Waits until the transfer is not paused.
+/// Returns immediately if the controller has been dropped.
+async fn wait_resumed(paused_rx: &watch::Receiver<bool>) {
+    let _ = paused_rx.clone().wait_for(|paused| !paused).await;
+}
+
+async fn listen_progress(rx: watch::Receiver<Progress>, sink: StreamSink<RTCFileProgress>) {
+    progress::listen_changes(rx, |file_id, bytes| {
+        let _ = sink.add(RTCFileProgress {
+            file_id: file_id.to_owned(),
+            bytes,
+        });
+    })
+    .await;
+}
+
+#[frb(mirror(CollisionPolicy))]
+pub enum _CollisionPolicy {
+    Rename,
+    Overwrite,
+    Skip,
+    Ask,
 }
 
 #[frb(mirror(PinConfig))]
@@ -443,6 +1087,8 @@ pub enum _WsServerMessage {
     Hello {
         client: ClientInfo,
         peers: Vec<ClientInfo>,
+        resume_token: Option<String>,
+        room: Option<String>,
     },
     Join {
         peer: ClientInfo,
@@ -458,6 +1104,18 @@ pub enum _WsServerMessage {
     Error {
         code: u16,
     },
+    Announcement {
+        kind: AnnouncementKind,
+        message: String,
+    },
+}
+
+#[frb(mirror(AnnouncementKind))]
+pub enum _AnnouncementKind {
+    Info,
+    Maintenance,
+    Deprecation,
+    Other,
 }
 
 #[frb(mirror(ClientInfo))]
@@ -468,6 +1126,7 @@ pub struct _ClientInfo {
     pub device_model: Option<String>,
     pub device_type: Option<DeviceType>,
     pub token: String,
+    pub capabilities: u32,
 }
 
 #[frb(mirror(ClientInfoWithoutId))]
@@ -477,6 +1136,7 @@ pub struct _ClientInfoWithoutId {
     pub device_model: Option<String>,
     pub device_type: Option<DeviceType>,
     pub token: String,
+    pub capabilities: u32,
 }
 
 #[frb(mirror(WsServerSdpMessage))]
@@ -496,6 +1156,16 @@ pub enum _RTCStatus {
     Sending,
     Finished,
     Error(String),
+    Timeout,
+    Paused,
+    Resumed,
+}
+
+#[frb(mirror(RTCStats))]
+pub struct _RTCStats {
+    pub bytes_per_second: u64,
+    pub round_trip_time_ms: Option<u32>,
+    pub relayed: bool,
 }
 
 #[frb(mirror(RTCFileError))]
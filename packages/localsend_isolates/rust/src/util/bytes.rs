@@ -1,25 +1,71 @@
 use bytes::{Bytes, BytesMut};
+use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+/// How [`buffer_receiver`] coalesces the incoming data.
+pub(crate) struct BufferOptions {
+    /// The buffer is sent once it holds at least this many bytes.
+    pub(crate) target_size: usize,
+
+    /// The buffer is also sent this long after its first bytes arrived,
+    /// even if it is smaller than `target_size`.
+    pub(crate) flush_interval: Option<Duration>,
+}
+
+impl Default for BufferOptions {
+    fn default() -> Self {
+        Self {
+            target_size: 1024 * 1024, // 1 MB
+            flush_interval: None,
+        }
+    }
+}
 
 /// Converts a stream of Bytes into a stream of Vec<u8>.
 /// Also buffers the incoming data to reduce the number of
 /// messages sent to the receiver.
 pub(crate) async fn buffer_receiver(
     mut rx_input: mpsc::Receiver<Bytes>,
+    options: BufferOptions,
 ) -> mpsc::Receiver<Vec<u8>> {
-    const BUFFER_SIZE: usize = 1024 * 1024; // 1 MB
-    let mut buffer = BytesMut::with_capacity(BUFFER_SIZE);
+    let mut buffer = BytesMut::with_capacity(options.target_size);
 
     let (tx, rx) = mpsc::channel(1);
 
     tokio::spawn(async move {
-        while let Some(data) = rx_input.recv().await {
+        // Set while the buffer holds data that has not been sent yet.
+        let mut deadline = None;
+
+        loop {
+            let received = match deadline {
+                Some(deadline) => tokio::time::timeout_at(deadline, rx_input.recv()).await,
+                None => Ok(rx_input.recv().await),
+            };
+
+            let Ok(received) = received else {
+                let data = buffer.to_vec();
+                buffer.clear();
+                deadline = None;
+                tx.send(data).await?;
+                continue;
+            };
+
+            let Some(data) = received else {
+                break;
+            };
+
             buffer.extend_from_slice(&data);
 
-            if buffer.len() >= BUFFER_SIZE {
+            if buffer.len() >= options.target_size {
                 let data = buffer.to_vec();
                 buffer.clear();
+                deadline = None;
                 tx.send(data).await?;
+            } else if deadline.is_none() {
+                deadline = options
+                    .flush_interval
+                    .map(|interval| Instant::now() + interval);
             }
         }
 
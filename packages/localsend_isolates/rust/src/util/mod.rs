@@ -1 +1,3 @@
 pub(crate) mod bytes;
+pub(crate) mod progress;
+pub(crate) mod transfer;
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+use tokio::sync::watch;
+
+/// Transferred bytes per file ID.
+pub(crate) type Progress = HashMap<String, u64>;
+
+/// Only the transfer task holds a strong reference,
+/// so the progress receivers end once the transfer has finished.
+pub(crate) type ProgressTx = watch::Sender<Progress>;
+
+/// Adds the bytes to the file.
+pub(crate) fn add_bytes(progress: &ProgressTx, file_id: &str, bytes: usize) {
+    progress.send_modify(|progress| match progress.get_mut(file_id) {
+        Some(count) => *count += bytes as u64,
+        None => {
+            progress.insert(file_id.to_owned(), bytes as u64);
+        }
+    });
+}
+
+/// Calls `emit` for every file whose byte count changed since the last call.
+/// Returns once the transfer has finished.
+pub(crate) async fn listen_changes(
+    mut rx: watch::Receiver<Progress>,
+    mut emit: impl FnMut(&str, u64),
+) {
+    let mut emitted = Progress::new();
+    while rx.changed().await.is_ok() {
+        let progress = rx.borrow_and_update().clone();
+        for (file_id, bytes) in progress {
+            if emitted.get(&file_id) != Some(&bytes) {
+                emit(&file_id, bytes);
+                emitted.insert(file_id, bytes);
+            }
+        }
+    }
+}
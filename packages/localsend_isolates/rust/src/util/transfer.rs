@@ -0,0 +1,133 @@
+use localsend::webrtc::webrtc::RTCStatus;
+use tokio::sync::{mpsc, watch};
+use tokio::task::AbortHandle;
+
+/// Controls a running transfer.
+/// Shared by its controller and the session manager.
+#[derive(Clone)]
+pub(crate) struct TransferHandle {
+    task: AbortHandle,
+
+    /// Weak, so the status stream still ends with the transfer.
+    status_tx: mpsc::WeakSender<RTCStatus>,
+    paused_tx: watch::Sender<bool>,
+    latest_status: watch::Receiver<Option<RTCStatus>>,
+    ended: watch::Receiver<bool>,
+}
+
+impl TransferHandle {
+    pub(crate) fn new(
+        task: AbortHandle,
+        status_tx: mpsc::WeakSender<RTCStatus>,
+        paused_tx: watch::Sender<bool>,
+        latest_status: watch::Receiver<Option<RTCStatus>>,
+        ended: watch::Receiver<bool>,
+    ) -> Self {
+        Self {
+            task,
+            status_tx,
+            paused_tx,
+            latest_status,
+            ended,
+        }
+    }
+
+    /// Aborts the transfer task.
+    pub(crate) fn cancel(&self) {
+        self.task.abort();
+    }
+
+    /// Whether the transfer has finished, failed or been cancelled.
+    pub(crate) fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    pub(crate) fn latest_status(&self) -> Option<RTCStatus> {
+        self.latest_status.borrow().clone()
+    }
+
+    /// Waits until the transfer task has ended, also if it was cancelled,
+    /// and returns the last status it sent.
+    pub(crate) async fn wait_ended(&self) -> Option<RTCStatus> {
+        let mut ended = self.ended.clone();
+        let _ = ended.wait_for(|ended| *ended).await;
+        self.latest_status()
+    }
+
+    pub(crate) fn paused_rx(&self) -> watch::Receiver<bool> {
+        self.paused_tx.subscribe()
+    }
+
+    /// Emits the status if the paused state changed.
+    pub(crate) async fn set_paused(&self, paused: bool) {
+        if self.paused_tx.send_replace(paused) == paused {
+            return;
+        }
+
+        if let Some(status_tx) = self.status_tx.upgrade() {
+            let status = if paused {
+                RTCStatus::Paused
+            } else {
+                RTCStatus::Resumed
+            };
+            let _ = status_tx.send(status).await;
+        }
+    }
+}
+
+/// Forwards the statuses sent to the returned sender to `status_tx`
+/// and keeps the latest one.
+/// `status_tx` is dropped once all returned senders are dropped.
+///
+/// `task_alive` must be dropped by the transfer task, so it is also dropped if the task
+/// is aborted. The returned `ended` receiver changes to `true` once it is,
+/// after the statuses sent by the task have been applied to the latest status.
+/// Statuses are buffered until the stream is listened to, so the latest status
+/// stays up to date without a listener.
+pub(crate) fn track_status(
+    status_tx: mpsc::Sender<RTCStatus>,
+    mut task_alive: watch::Receiver<()>,
+) -> (
+    mpsc::Sender<RTCStatus>,
+    watch::Receiver<Option<RTCStatus>>,
+    watch::Receiver<bool>,
+) {
+    let (tx, mut rx) = mpsc::channel::<RTCStatus>(1);
+    let (forward_tx, mut forward_rx) = mpsc::unbounded_channel::<RTCStatus>();
+    let (latest_tx, latest_rx) = watch::channel(None);
+    let (ended_tx, ended_rx) = watch::channel(false);
+
+    tokio::spawn(async move {
+        while let Some(status) = forward_rx.recv().await {
+            let _ = status_tx.send(status).await;
+        }
+    });
+
+    tokio::spawn(async move {
+        let mut task_ended = false;
+        loop {
+            tokio::select! {
+                status = rx.recv() => {
+                    let Some(status) = status else {
+                        break;
+                    };
+                    latest_tx.send_replace(Some(status.clone()));
+                    let _ = forward_tx.send(status);
+                }
+                _ = task_alive.changed(), if !task_ended => {
+                    task_ended = true;
+
+                    // Statuses sent right before the task ended may not have been received yet.
+                    while let Ok(status) = rx.try_recv() {
+                        latest_tx.send_replace(Some(status.clone()));
+                        let _ = forward_tx.send(status);
+                    }
+                    ended_tx.send_replace(true);
+                }
+            }
+        }
+        ended_tx.send_replace(true);
+    });
+
+    (tx, latest_rx, ended_rx)
+}
@@ -0,0 +1,23 @@
+// Types of the helpers added by localsend.js to the generated bindings.
+export * from './index'
+
+declare module './index' {
+  interface SignalingConnection {
+    /** Messages of the server until the connection is closed. */
+    messages(): AsyncIterable<Record<string, any>>
+  }
+
+  interface Transfer {
+    /** Events of the transfer until it has ended. */
+    events(): AsyncIterable<Record<string, any>>
+
+    /** Receiving: the content of a file announced by a `receiving` event. */
+    chunks(fileId: string): AsyncIterable<Buffer>
+
+    /**
+     * Sending: sends the content of an accepted file, e.g. from `fs.createReadStream`.
+     * Resolves to `false` if the file was aborted.
+     */
+    sendFile(fileId: string, source: AsyncIterable<Uint8Array> | Iterable<Uint8Array>): Promise<boolean>
+  }
+}
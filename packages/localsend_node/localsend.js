@@ -0,0 +1,47 @@
+'use strict'
+
+// Adds async iterables on top of the `next*` methods of the native classes.
+const native = require('./index.js')
+const { SignalingConnection, Transfer } = native
+
+/** Iterates over the values resolved by `next` until it resolves to `null`. */
+function iterate(next) {
+  return {
+    [Symbol.asyncIterator]() {
+      return {
+        async next() {
+          const value = await next()
+          return value === null ? { done: true, value: undefined } : { done: false, value }
+        },
+      }
+    },
+  }
+}
+
+SignalingConnection.prototype.messages = function () {
+  return iterate(() => this.nextMessage())
+}
+
+Transfer.prototype.events = function () {
+  return iterate(() => this.nextEvent())
+}
+
+Transfer.prototype.chunks = function (fileId) {
+  return iterate(() => this.nextChunk(fileId))
+}
+
+Transfer.prototype.sendFile = async function (fileId, source) {
+  await this.beginFile(fileId)
+  try {
+    for await (const chunk of source) {
+      if (!(await this.feedChunk(fileId, Buffer.from(chunk)))) {
+        return false
+      }
+    }
+  } finally {
+    await this.endFile(fileId)
+  }
+  return true
+}
+
+module.exports = native
@@ -0,0 +1,102 @@
+use crate::js_error;
+use localsend::model::discovery::DeviceType;
+use localsend::webrtc::signaling::{
+    ClientInfoWithoutId, ConnectOptions, ManagedSignalingConnection, WsServerMessage,
+};
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use serde_json::Value;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+
+/// The info announced to other peers.
+#[napi(object)]
+pub struct ProposingClientInfo {
+    pub alias: String,
+    pub version: String,
+    pub device_model: Option<String>,
+
+    /// `MOBILE`, `DESKTOP`, `WEB`, `HEADLESS` or `SERVER`.
+    pub device_type: Option<String>,
+}
+
+/// A connection to a signaling server, created by [`connect`].
+#[napi]
+pub struct SignalingConnection {
+    pub(crate) inner: Arc<ManagedSignalingConnection>,
+
+    /// The PEM encoded key signing the transfers of this client.
+    pub(crate) private_key: String,
+    messages: Arc<Mutex<mpsc::Receiver<WsServerMessage>>>,
+}
+
+/// Connects to the signaling server at `uri`.
+///
+/// `privateKey` is the PEM encoded Ed25519 key of this device, used to sign its token
+/// and transfers. Plain `ws://` URIs require `allowInsecure`.
+///
+/// The messages of the server must be consumed, otherwise the connection stalls.
+#[napi]
+pub async fn connect(
+    uri: String,
+    info: ProposingClientInfo,
+    private_key: String,
+    allow_insecure: Option<bool>,
+) -> Result<SignalingConnection> {
+    let signing_key =
+        localsend::crypto::token::parse_private_key(&private_key).map_err(js_error)?;
+    let device_type = match info.device_type {
+        Some(device_type) => Some(serde_json::from_value::<DeviceType>(Value::String(
+            device_type,
+        ))?),
+        None => None,
+    };
+
+    let client_info = ClientInfoWithoutId {
+        alias: info.alias,
+        version: info.version,
+        device_model: info.device_model,
+        device_type,
+        token: localsend::crypto::token::generate_token_timestamp(&signing_key)
+            .map_err(js_error)?,
+        capabilities: localsend::webrtc::signaling::capability::SUPPORTED,
+    };
+    let options = ConnectOptions {
+        allow_insecure: allow_insecure.unwrap_or(false),
+        ..ConnectOptions::default()
+    };
+
+    let connection = localsend::webrtc::signaling::SignalingConnection::connect_with_options(
+        uri,
+        &client_info,
+        &options,
+    )
+    .await
+    .map_err(js_error)?;
+    let (inner, messages) = connection.start_listener();
+
+    Ok(SignalingConnection {
+        inner: Arc::new(inner),
+        private_key,
+        messages: Arc::new(Mutex::new(messages)),
+    })
+}
+
+#[napi]
+impl SignalingConnection {
+    /// The info of this client assigned by the server (`ClientInfo`).
+    #[napi(getter)]
+    pub fn client(&self) -> Result<Value> {
+        Ok(serde_json::to_value(&self.inner.client)?)
+    }
+
+    /// Resolves to the next message of the server (`WsServerMessage`, e.g. `{type: 'OFFER', ...}`),
+    /// or `null` once the server has closed the connection.
+    #[napi]
+    pub async fn next_message(&self) -> Result<Option<Value>> {
+        match self.messages.lock().await.recv().await {
+            Some(message) => Ok(Some(serde_json::to_value(message)?)),
+            None => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Node.js bindings of the LocalSend transfer engine via N-API,
+//! so that Electron apps and Node-based tools can send and receive files.
+//!
+//! The native classes expose `next*` methods resolving to `null` once a source has ended.
+//! `localsend.js` turns them into async iterables, e.g.
+//! `for await (const message of connection.messages())`.
+//! Structured values are plain objects in the format of the signaling protocol.
+
+mod connection;
+mod transfer;
+
+pub use connection::*;
+pub use transfer::*;
+
+/// Converts an error of the engine into a JavaScript error describing the whole cause chain.
+fn js_error(e: impl Into<anyhow::Error>) -> napi::Error {
+    napi::Error::from_reason(format!("{:#}", e.into()))
+}
@@ -0,0 +1,476 @@
+use crate::connection::SignalingConnection;
+use crate::js_error;
+use bytes::Bytes;
+use localsend::model::transfer::FileDto;
+use localsend::webrtc::signaling::WsServerSdpMessage;
+use localsend::webrtc::webrtc::{RTCFile, RTCFileError, RTCSendFileResponse, RTCStats, RTCStatus};
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::sync::{mpsc, oneshot, Mutex};
+use tokio::task::AbortHandle;
+use uuid::Uuid;
+
+/// Chunks of a file buffered between JavaScript and the data channel.
+const FILE_CHANNEL_CAPACITY: usize = 4;
+
+/// An event of a transfer, emitted as a plain object by [`Transfer::next_event`].
+#[derive(Debug, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+enum TransferEvent {
+    /// `status` is the name of the [`RTCStatus`], e.g. `SENDING`.
+    Status {
+        status: &'static str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    Stats {
+        bytes_per_second: u64,
+        round_trip_time_ms: Option<u32>,
+        relayed: bool,
+    },
+
+    /// The remote peer asks for a PIN. Answer with [`Transfer::send_pin`].
+    PinRequested,
+
+    /// Sending: the files accepted by the receiver.
+    /// Provide their content with `sendFile` of `localsend.js`.
+    SelectedFiles {
+        file_ids: Vec<String>,
+    },
+
+    /// Receiving: the files offered by the sender.
+    /// Answer with [`Transfer::select_files`].
+    Files {
+        files: Vec<FileDto>,
+    },
+
+    /// Receiving: the content of a file arrives. Consume it with `chunks` of `localsend.js`.
+    Receiving {
+        file_id: String,
+    },
+
+    FileError {
+        file_id: String,
+        error: String,
+    },
+}
+
+impl From<RTCStatus> for TransferEvent {
+    fn from(status: RTCStatus) -> Self {
+        let (status, error) = match status {
+            RTCStatus::SdpExchanged => ("SDP_EXCHANGED", None),
+            RTCStatus::Connected => ("CONNECTED", None),
+            RTCStatus::PinRequired => ("PIN_REQUIRED", None),
+            RTCStatus::TooManyAttempts => ("TOO_MANY_ATTEMPTS", None),
+            RTCStatus::Declined => ("DECLINED", None),
+            RTCStatus::Sending => ("SENDING", None),
+            RTCStatus::Finished => ("FINISHED", None),
+            RTCStatus::Error(error) => ("ERROR", Some(error)),
+            RTCStatus::Timeout => ("TIMEOUT", None),
+            RTCStatus::Paused => ("PAUSED", None),
+            RTCStatus::Resumed => ("RESUMED", None),
+        };
+        TransferEvent::Status { status, error }
+    }
+}
+
+impl From<RTCStats> for TransferEvent {
+    fn from(stats: RTCStats) -> Self {
+        TransferEvent::Stats {
+            bytes_per_second: stats.bytes_per_second,
+            round_trip_time_ms: stats.round_trip_time_ms,
+            relayed: stats.relayed,
+        }
+    }
+}
+
+impl From<RTCFileError> for TransferEvent {
+    fn from(error: RTCFileError) -> Self {
+        TransferEvent::FileError {
+            file_id: error.file_id,
+            error: error.error,
+        }
+    }
+}
+
+/// Senders of the files whose content is being provided, mapped by file ID.
+type OutgoingFiles = Mutex<HashMap<String, mpsc::Sender<Bytes>>>;
+
+/// Receivers of the files whose content is arriving, mapped by file ID.
+type IncomingFiles = Arc<Mutex<HashMap<String, Arc<Mutex<mpsc::Receiver<Bytes>>>>>>;
+
+enum Role {
+    Send {
+        sending_tx: mpsc::Sender<RTCFile>,
+        outgoing: OutgoingFiles,
+    },
+    Receive {
+        selected_tx: Mutex<Option<oneshot::Sender<Option<HashSet<String>>>>>,
+        incoming: IncomingFiles,
+        file_status_tx: mpsc::Sender<RTCSendFileResponse>,
+    },
+}
+
+/// A transfer with a peer, created by `sendOffer` or `acceptOffer` of a connection.
+#[napi]
+pub struct Transfer {
+    events: Mutex<mpsc::UnboundedReceiver<TransferEvent>>,
+    pin_tx: Arc<Mutex<Option<oneshot::Sender<String>>>>,
+    role: Role,
+    task: AbortHandle,
+}
+
+impl Transfer {
+    fn send_role(&self) -> Result<(&mpsc::Sender<RTCFile>, &OutgoingFiles)> {
+        match &self.role {
+            Role::Send {
+                sending_tx,
+                outgoing,
+            } => Ok((sending_tx, outgoing)),
+            Role::Receive { .. } => Err(Error::from_reason("Not a sending transfer")),
+        }
+    }
+}
+
+impl Drop for Transfer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Publishes every message of `rx` as an event.
+fn forward<T: Send + 'static>(
+    mut rx: mpsc::Receiver<T>,
+    event_tx: mpsc::UnboundedSender<TransferEvent>,
+) where
+    TransferEvent: From<T>,
+{
+    tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            if event_tx.send(message.into()).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Keeps the latest PIN request of the peer for [`Transfer::send_pin`].
+fn forward_pin_requests(
+    mut pin_rx: mpsc::Receiver<oneshot::Sender<String>>,
+    pin_tx: Arc<Mutex<Option<oneshot::Sender<String>>>>,
+    event_tx: mpsc::UnboundedSender<TransferEvent>,
+) {
+    tokio::spawn(async move {
+        while let Some(request) = pin_rx.recv().await {
+            *pin_tx.lock().await = Some(request);
+            if event_tx.send(TransferEvent::PinRequested).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+#[napi]
+impl SignalingConnection {
+    /// Offers `files` (`FileDto[]`) to the peer with the ID `target`.
+    #[napi]
+    pub async fn send_offer(
+        &self,
+        target: String,
+        files: Value,
+        stun_servers: Vec<String>,
+    ) -> Result<Transfer> {
+        let target = Uuid::parse_str(&target).map_err(js_error)?;
+        let files: Vec<FileDto> = serde_json::from_value(files)?;
+        let signing_key =
+            localsend::crypto::token::parse_private_key(&self.private_key).map_err(js_error)?;
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (status_tx, status_rx) = mpsc::channel::<RTCStatus>(1);
+        let (stats_tx, stats_rx) = mpsc::channel::<RTCStats>(1);
+        let (selected_tx, selected_rx) = oneshot::channel::<HashSet<String>>();
+        let (error_tx, error_rx) = mpsc::channel::<RTCFileError>(1);
+        let (pin_tx, pin_rx) = mpsc::channel::<oneshot::Sender<String>>(1);
+        let (pair_tx, pair_rx) = oneshot::channel::<oneshot::Sender<bool>>();
+        let (sending_tx, sending_rx) = mpsc::channel::<RTCFile>(1);
+        let pin_sender = Arc::new(Mutex::new(None));
+
+        forward(status_rx, event_tx.clone());
+        forward(stats_rx, event_tx.clone());
+        forward(error_rx, event_tx.clone());
+        forward_pin_requests(pin_rx, pin_sender.clone(), event_tx.clone());
+
+        tokio::spawn(async move {
+            if let Ok(selected) = selected_rx.await {
+                let _ = event_tx.send(TransferEvent::SelectedFiles {
+                    file_ids: selected.into_iter().collect(),
+                });
+            }
+        });
+
+        tokio::spawn(async move {
+            // Pairing is not supported by the Node.js bindings.
+            if let Ok(pair_tx) = pair_rx.await {
+                let _ = pair_tx.send(false);
+            }
+        });
+
+        let managed_connection = self.inner.clone();
+        let task = tokio::spawn(async move {
+            let result = localsend::webrtc::webrtc::send_offer(
+                &managed_connection,
+                stun_servers,
+                target,
+                signing_key,
+                None,
+                None,
+                files,
+                status_tx.clone(),
+                stats_tx,
+                selected_tx,
+                error_tx,
+                pin_tx,
+                pair_tx,
+                sending_rx,
+            )
+            .await;
+
+            if let Err(e) = result {
+                let _ = status_tx.send(RTCStatus::Error(e.to_string())).await;
+            }
+        });
+
+        Ok(Transfer {
+            events: Mutex::new(event_rx),
+            pin_tx: pin_sender,
+            role: Role::Send {
+                sending_tx,
+                outgoing: Mutex::new(HashMap::new()),
+            },
+            task: task.abort_handle(),
+        })
+    }
+
+    /// Accepts an `OFFER` message received from the server.
+    #[napi]
+    pub async fn accept_offer(&self, offer: Value, stun_servers: Vec<String>) -> Result<Transfer> {
+        let offer: WsServerSdpMessage = serde_json::from_value(offer)?;
+        let signing_key =
+            localsend::crypto::token::parse_private_key(&self.private_key).map_err(js_error)?;
+
+        let (event_tx, event_rx) = mpsc::unbounded_channel();
+        let (status_tx, status_rx) = mpsc::channel::<RTCStatus>(1);
+        let (stats_tx, stats_rx) = mpsc::channel::<RTCStats>(1);
+        let (files_tx, files_rx) = oneshot::channel::<Vec<FileDto>>();
+        let (selected_tx, selected_rx) = oneshot::channel::<Option<HashSet<String>>>();
+        let (error_tx, error_rx) = mpsc::channel::<RTCFileError>(1);
+        let (pin_tx, pin_rx) = mpsc::channel::<oneshot::Sender<String>>(1);
+        let (receiving_tx, mut receiving_rx) = mpsc::channel::<RTCFile>(1);
+        let (file_status_tx, file_status_rx) = mpsc::channel::<RTCSendFileResponse>(1);
+        let pin_sender = Arc::new(Mutex::new(None));
+        let incoming: IncomingFiles = Arc::new(Mutex::new(HashMap::new()));
+
+        forward(status_rx, event_tx.clone());
+        forward(stats_rx, event_tx.clone());
+        forward(error_rx, event_tx.clone());
+        forward_pin_requests(pin_rx, pin_sender.clone(), event_tx.clone());
+
+        tokio::spawn({
+            let event_tx = event_tx.clone();
+            async move {
+                if let Ok(files) = files_rx.await {
+                    let _ = event_tx.send(TransferEvent::Files { files });
+                }
+            }
+        });
+
+        tokio::spawn({
+            let incoming = incoming.clone();
+            async move {
+                while let Some(file) = receiving_rx.recv().await {
+                    // Registered before the event, so the content can be consumed right away.
+                    incoming
+                        .lock()
+                        .await
+                        .insert(file.file_id.clone(), Arc::new(Mutex::new(file.binary_rx)));
+                    let event = TransferEvent::Receiving {
+                        file_id: file.file_id,
+                    };
+                    if event_tx.send(event).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+
+        let managed_connection = self.inner.clone();
+        let task = tokio::spawn(async move {
+            let result = localsend::webrtc::webrtc::accept_offer(
+                &managed_connection,
+                stun_servers,
+                &offer,
+                signing_key,
+                None,
+                None,
+                status_tx.clone(),
+                stats_tx,
+                files_tx,
+                selected_rx,
+                error_tx,
+                pin_tx,
+                receiving_tx,
+                file_status_rx,
+            )
+            .await;
+
+            if let Err(e) = result {
+                let _ = status_tx.send(RTCStatus::Error(e.to_string())).await;
+            }
+        });
+
+        Ok(Transfer {
+            events: Mutex::new(event_rx),
+            pin_tx: pin_sender,
+            role: Role::Receive {
+                selected_tx: Mutex::new(Some(selected_tx)),
+                incoming,
+                file_status_tx,
+            },
+            task: task.abort_handle(),
+        })
+    }
+}
+
+#[napi]
+impl Transfer {
+    /// Resolves to the next event, e.g. `{type: 'status', status: 'SENDING'}`,
+    /// or `null` once the transfer has ended.
+    #[napi]
+    pub async fn next_event(&self) -> Result<Option<Value>> {
+        match self.events.lock().await.recv().await {
+            Some(event) => Ok(Some(serde_json::to_value(event)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Answers the latest `pinRequested` event.
+    #[napi]
+    pub async fn send_pin(&self, pin: String) -> Result<()> {
+        let Some(pin_tx) = self.pin_tx.lock().await.take() else {
+            return Err(Error::from_reason("No PIN requested"));
+        };
+        pin_tx
+            .send(pin)
+            .map_err(|_| Error::from_reason("PIN channel closed"))
+    }
+
+    /// Sending: starts providing the content of an accepted file.
+    /// The files are sent one at a time, so end each file before beginning the next one.
+    #[napi]
+    pub async fn begin_file(&self, file_id: String) -> Result<()> {
+        let (sending_tx, outgoing) = self.send_role()?;
+        let (binary_tx, binary_rx) = mpsc::channel(FILE_CHANNEL_CAPACITY);
+        let file = RTCFile {
+            file_id: file_id.clone(),
+            binary_rx,
+        };
+        sending_tx
+            .send(file)
+            .await
+            .map_err(|_| Error::from_reason("Transfer closed"))?;
+        outgoing.lock().await.insert(file_id, binary_tx);
+        Ok(())
+    }
+
+    /// Sending: feeds the next chunk of a file started with [`Self::begin_file`].
+    /// Resolves once the chunk is queued; resolves to `false` if the file was aborted.
+    #[napi]
+    pub async fn feed_chunk(&self, file_id: String, chunk: Buffer) -> Result<bool> {
+        let (_, outgoing) = self.send_role()?;
+        let Some(binary_tx) = outgoing.lock().await.get(&file_id).cloned() else {
+            return Err(Error::from_reason(format!("File {file_id} has not begun")));
+        };
+        Ok(binary_tx.send(Bytes::from(Vec::from(chunk))).await.is_ok())
+    }
+
+    /// Sending: marks the content of a file as complete.
+    #[napi]
+    pub async fn end_file(&self, file_id: String) -> Result<()> {
+        let (_, outgoing) = self.send_role()?;
+        match outgoing.lock().await.remove(&file_id) {
+            Some(_) => Ok(()),
+            None => Err(Error::from_reason(format!("File {file_id} has not begun"))),
+        }
+    }
+
+    /// Receiving: accepts the files with the given IDs, or declines the transfer with `null`.
+    #[napi]
+    pub async fn select_files(&self, file_ids: Option<Vec<String>>) -> Result<()> {
+        let Role::Receive { selected_tx, .. } = &self.role else {
+            return Err(Error::from_reason("Not a receiving transfer"));
+        };
+        let Some(selected_tx) = selected_tx.lock().await.take() else {
+            return Err(Error::from_reason("Selected files already sent"));
+        };
+        selected_tx
+            .send(file_ids.map(HashSet::from_iter))
+            .map_err(|_| Error::from_reason("Selected files channel closed"))
+    }
+
+    /// Receiving: resolves to the next chunk of a file announced by a `receiving` event,
+    /// or `null` once the file is complete, which must then be confirmed with
+    /// [`Self::report_file`].
+    #[napi]
+    pub async fn next_chunk(&self, file_id: String) -> Result<Option<Buffer>> {
+        let Role::Receive { incoming, .. } = &self.role else {
+            return Err(Error::from_reason("Not a receiving transfer"));
+        };
+        let Some(binary_rx) = incoming.lock().await.get(&file_id).cloned() else {
+            return Err(Error::from_reason(format!(
+                "File {file_id} is not being received"
+            )));
+        };
+
+        let chunk = binary_rx.lock().await.recv().await;
+        if chunk.is_none() {
+            incoming.lock().await.remove(&file_id);
+        }
+        Ok(chunk.map(|chunk| Buffer::from(Vec::from(chunk))))
+    }
+
+    /// Receiving: tells the sender whether a file was stored successfully.
+    /// `error` is an optional message shown to the sender.
+    #[napi]
+    pub async fn report_file(
+        &self,
+        file_id: String,
+        success: bool,
+        error: Option<String>,
+    ) -> Result<()> {
+        let Role::Receive { file_status_tx, .. } = &self.role else {
+            return Err(Error::from_reason("Not a receiving transfer"));
+        };
+        file_status_tx
+            .send(RTCSendFileResponse {
+                id: file_id,
+                success,
+                error,
+            })
+            .await
+            .map_err(|_| Error::from_reason("Transfer closed"))
+    }
+
+    /// Aborts the transfer and closes the peer connection, which disconnects the remote peer.
+    #[napi]
+    pub fn cancel(&self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,352 @@
+//! Simulates many peers to validate the capacity limits and DDoS protections of a server.
+//!
+//! Peers are split into groups, each connecting with its own fake address in `X-Forwarded-For`,
+//! so the server has to trust the host running the benchmark as a proxy (true for localhost by default).
+//! Every peer periodically sends offers to the other peers of its group, which answer immediately.
+//! Configured via environment variables, see the README.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use futures_util::{SinkExt, StreamExt};
+use localsend::webrtc::signaling::{
+    ClientInfoWithoutId, WsClientMessage, WsClientSdpMessage, WsServerMessage,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinSet;
+use tokio::time::{Instant, MissedTickBehavior};
+use uuid::Uuid;
+use yawc::{Frame, HttpRequestBuilder, OpCode, WebSocket, WebSocketError};
+
+const DEFAULT_URL: &str = "ws://127.0.0.1:3000/v1/ws";
+
+/// Smallest SDP accepted by the server.
+const SDP: &str = "v=0\r\no=- 0 0 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\n";
+
+/// How long a rejected peer waits before connecting again.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+struct Config {
+    url: String,
+    access_token: Option<String>,
+    peers: usize,
+    peers_per_group: usize,
+
+    /// New peers per second while ramping up.
+    connect_rate: f64,
+
+    duration: Duration,
+
+    /// How often each peer sends an offer.
+    offer_interval: Duration,
+
+    /// Average time until a peer disconnects and connects again. `None` keeps the peers connected.
+    churn: Option<Duration>,
+}
+
+impl Config {
+    fn from_env() -> anyhow::Result<Self> {
+        let churn_seconds: f64 = env_or("BENCH_CHURN_SECONDS", 0.0)?;
+        let config = Self {
+            url: std::env::var("BENCH_URL").unwrap_or_else(|_| DEFAULT_URL.to_string()),
+            access_token: std::env::var("BENCH_ACCESS_TOKEN")
+                .ok()
+                .filter(|v| !v.is_empty()),
+            peers: env_or("BENCH_PEERS", 1000)?,
+            peers_per_group: env_or("BENCH_PEERS_PER_GROUP", 5)?,
+            connect_rate: env_or("BENCH_CONNECT_RATE", 200.0)?,
+            duration: Duration::from_secs_f64(env_or("BENCH_DURATION_SECONDS", 30.0)?),
+            offer_interval: Duration::from_secs_f64(env_or("BENCH_OFFER_INTERVAL_SECONDS", 1.0)?),
+            churn: (churn_seconds > 0.0).then(|| Duration::from_secs_f64(churn_seconds)),
+        };
+
+        if config.peers == 0 || config.peers_per_group == 0 {
+            anyhow::bail!("BENCH_PEERS and BENCH_PEERS_PER_GROUP must be positive");
+        }
+        if config.connect_rate <= 0.0 || config.offer_interval.is_zero() {
+            anyhow::bail!("BENCH_CONNECT_RATE and BENCH_OFFER_INTERVAL_SECONDS must be positive");
+        }
+        Ok(config)
+    }
+}
+
+fn env_or<T: FromStr>(key: &str, default: T) -> anyhow::Result<T>
+where
+    T::Err: std::fmt::Display,
+{
+    match std::env::var(key) {
+        Ok(v) => v
+            .parse()
+            .map_err(|e| anyhow::anyhow!("Invalid value of {key}: {e}")),
+        Err(_) => Ok(default),
+    }
+}
+
+#[derive(Default)]
+struct Stats {
+    /// Time from starting the upgrade until the hello.
+    connect_latencies: Vec<Duration>,
+
+    /// Time from sending an offer until its answer arrives.
+    answer_latencies: Vec<Duration>,
+
+    /// HTTP status -> Rejected upgrades.
+    rejections: BTreeMap<u16, u64>,
+
+    /// Error code -> Error messages received by connected peers.
+    errors: BTreeMap<u16, u64>,
+
+    /// Connections that failed without a status, e.g. because the server was unreachable.
+    failures: u64,
+
+    /// Connections closed by the server.
+    dropped: u64,
+
+    offers_sent: u64,
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let config = Arc::new(Config::from_env()?);
+    let stats = Arc::new(Mutex::new(Stats::default()));
+    let sdp = Arc::new(compress_sdp());
+
+    let groups = config.peers.div_ceil(config.peers_per_group);
+    println!(
+        "Simulating {} peers in {groups} groups against {} for {:?}",
+        config.peers, config.url, config.duration
+    );
+
+    let start = Instant::now();
+    let deadline = start + config.duration;
+    let mut ramp_up = tokio::time::interval(Duration::from_secs_f64(1.0 / config.connect_rate));
+    ramp_up.set_missed_tick_behavior(MissedTickBehavior::Burst);
+
+    let mut peers = JoinSet::new();
+    for index in 0..config.peers {
+        ramp_up.tick().await;
+        peers.spawn(run_peer(
+            config.clone(),
+            stats.clone(),
+            sdp.clone(),
+            index,
+            deadline,
+        ));
+    }
+    peers.join_all().await;
+
+    print_report(&stats.lock().unwrap(), start.elapsed());
+    Ok(())
+}
+
+/// Connects the peer again and again until the deadline.
+async fn run_peer(
+    config: Arc<Config>,
+    stats: Arc<Mutex<Stats>>,
+    sdp: Arc<String>,
+    index: usize,
+    deadline: Instant,
+) {
+    let address = group_address(index / config.peers_per_group);
+    let info = ClientInfoWithoutId {
+        alias: format!("Bench {index}"),
+        version: "2.3".to_string(),
+        device_model: None,
+        device_type: None,
+        token: Uuid::new_v4().to_string(),
+        capabilities: 0,
+    };
+
+    // Spreads the disconnects so that the peers do not churn in lockstep.
+    let lifetime = config
+        .churn
+        .map(|churn| churn.mul_f64(0.5 + (index as f64 * 0.618_034).fract()));
+
+    while Instant::now() < deadline {
+        let end = lifetime.map_or(deadline, |lifetime| deadline.min(Instant::now() + lifetime));
+        if !run_session(&config, &stats, &sdp, address, &info, end).await {
+            tokio::time::sleep_until(deadline.min(Instant::now() + RETRY_DELAY)).await;
+        }
+    }
+}
+
+/// One connection of a peer.
+/// Returns `false` if the connection could not be established or has been closed by the server.
+async fn run_session(
+    config: &Config,
+    stats: &Mutex<Stats>,
+    sdp: &str,
+    address: Ipv4Addr,
+    info: &ClientInfoWithoutId,
+    end: Instant,
+) -> bool {
+    let start = Instant::now();
+    let encoded_info = URL_SAFE_NO_PAD.encode(serde_json::to_string(info).unwrap());
+    let Ok(url) = format!("{}?d={encoded_info}", config.url).parse() else {
+        stats.lock().unwrap().failures += 1;
+        return false;
+    };
+
+    let mut request = HttpRequestBuilder::new()
+        .header("X-Forwarded-For", address.to_string())
+        .header("Sec-WebSocket-Protocol", "localsend.v2");
+    if let Some(token) = &config.access_token {
+        request = request.header("Authorization", format!("Bearer {token}"));
+    }
+
+    let mut ws = match WebSocket::connect(url).with_request(request).await {
+        Ok(ws) => ws,
+        Err(WebSocketError::InvalidStatusCode(status)) => {
+            *stats.lock().unwrap().rejections.entry(status).or_default() += 1;
+            return false;
+        }
+        Err(_) => {
+            stats.lock().unwrap().failures += 1;
+            return false;
+        }
+    };
+
+    let mut peers: Vec<Uuid> = Vec::new();
+    let mut pending: HashMap<String, Instant> = HashMap::new();
+    let mut offer = tokio::time::interval(config.offer_interval);
+    offer.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    offer.reset();
+    let mut next_target = 0;
+
+    loop {
+        tokio::select! {
+            frame = ws.next() => {
+                let Some(frame) = frame else {
+                    stats.lock().unwrap().dropped += 1;
+                    return false;
+                };
+                if frame.opcode() != OpCode::Text {
+                    continue;
+                }
+                let Ok(message) = serde_json::from_str::<WsServerMessage>(frame.as_str()) else {
+                    continue;
+                };
+
+                match message {
+                    WsServerMessage::Hello { peers: others, .. } => {
+                        stats.lock().unwrap().connect_latencies.push(start.elapsed());
+                        peers = others.into_iter().map(|peer| peer.id).collect();
+                    }
+                    WsServerMessage::Join { peer } => peers.push(peer.id),
+                    WsServerMessage::Left { peer_id } => peers.retain(|id| *id != peer_id),
+                    WsServerMessage::Offer(offer) => {
+                        let answer = WsClientMessage::Answer(WsClientSdpMessage {
+                            session_id: offer.session_id,
+                            target: offer.peer.id,
+                            sdp: sdp.to_string(),
+                        });
+                        let _ = ws.send(Frame::text(serde_json::to_string(&answer).unwrap())).await;
+                    }
+                    WsServerMessage::Answer(answer) => {
+                        if let Some(sent_at) = pending.remove(&answer.session_id) {
+                            stats.lock().unwrap().answer_latencies.push(sent_at.elapsed());
+                        }
+                    }
+                    WsServerMessage::Error { code } => {
+                        *stats.lock().unwrap().errors.entry(code).or_default() += 1;
+                    }
+                    _ => (),
+                }
+            }
+            _ = offer.tick() => {
+                if peers.is_empty() {
+                    continue;
+                }
+                next_target = (next_target + 1) % peers.len();
+                let session_id = Uuid::new_v4().simple().to_string();
+                let message = WsClientMessage::Offer(WsClientSdpMessage {
+                    session_id: session_id.clone(),
+                    target: peers[next_target],
+                    sdp: sdp.to_string(),
+                });
+                pending.insert(session_id, Instant::now());
+                stats.lock().unwrap().offers_sent += 1;
+                let _ = ws.send(Frame::text(serde_json::to_string(&message).unwrap())).await;
+            }
+            _ = tokio::time::sleep_until(end) => {
+                let _ = ws.close().await;
+                return true;
+            }
+        }
+    }
+}
+
+/// A fake client address of the benchmarking network (198.18.0.0/15, RFC 2544).
+fn group_address(group: usize) -> Ipv4Addr {
+    let [_, a, b, c] = (group as u32).to_be_bytes();
+    Ipv4Addr::new(198, 18 + (a & 1), b, c)
+}
+
+fn compress_sdp() -> String {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(SDP.as_bytes()).unwrap();
+    URL_SAFE_NO_PAD.encode(encoder.finish().unwrap())
+}
+
+fn print_report(stats: &Stats, elapsed: Duration) {
+    let connections = stats.connect_latencies.len();
+    println!();
+    println!("Finished after {elapsed:.1?}");
+    println!(
+        "Connections:    {connections} established, {} rejected, {} failed, {} closed by the server",
+        stats.rejections.values().sum::<u64>(),
+        stats.failures,
+        stats.dropped
+    );
+    println!("Rejections:     {}", format_counts(&stats.rejections));
+    println!("Errors:         {}", format_counts(&stats.errors));
+    println!(
+        "Offers:         {} sent, {} answered",
+        stats.offers_sent,
+        stats.answer_latencies.len()
+    );
+    println!(
+        "Connect:        {}",
+        format_percentiles(&stats.connect_latencies)
+    );
+    println!(
+        "Offer → answer: {}",
+        format_percentiles(&stats.answer_latencies)
+    );
+}
+
+/// Formats code -> count, e.g. `429 × 3, 430 × 1`.
+fn format_counts(counts: &BTreeMap<u16, u64>) -> String {
+    if counts.is_empty() {
+        return "none".to_string();
+    }
+
+    counts
+        .iter()
+        .map(|(code, count)| format!("{code} × {count}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_percentiles(latencies: &[Duration]) -> String {
+    if latencies.is_empty() {
+        return "no samples".to_string();
+    }
+
+    let mut sorted = latencies.to_vec();
+    sorted.sort();
+    let percentile = |p: f64| sorted[((sorted.len() - 1) as f64 * p).round() as usize];
+    format!(
+        "p50 {:.1?}, p90 {:.1?}, p99 {:.1?}, max {:.1?}",
+        percentile(0.5),
+        percentile(0.9),
+        percentile(0.99),
+        sorted[sorted.len() - 1]
+    )
+}
@@ -0,0 +1,234 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
+use rusqlite::{params, Connection, OptionalExtension, ToSql};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What a ban applies to.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BanKind {
+    /// The `token` of the peer.
+    Fingerprint,
+
+    /// All peers connecting from the IP group.
+    IpGroup,
+}
+
+impl ToSql for BanKind {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(match self {
+            BanKind::Fingerprint => "fingerprint",
+            BanKind::IpGroup => "ipGroup",
+        }
+        .into())
+    }
+}
+
+impl FromSql for BanKind {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value.as_str()? {
+            "fingerprint" => Ok(BanKind::Fingerprint),
+            "ipGroup" => Ok(BanKind::IpGroup),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ban {
+    pub kind: BanKind,
+    pub value: String,
+
+    /// Unix timestamp in seconds. `None` if the ban is permanent.
+    pub expires_at: Option<u64>,
+}
+
+/// Banned fingerprints and IP groups stored in SQLite.
+/// Expired bans are ignored and removed periodically.
+pub struct Blocklist {
+    connection: Mutex<Connection>,
+}
+
+impl Blocklist {
+    /// Opens the database at `path` or an in-memory database if `None`.
+    pub fn open(path: Option<&Path>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            connection: Mutex::new(connect(path)?),
+        })
+    }
+
+    /// Switches to the database at `path`, e.g. after the file has been replaced.
+    /// The current database stays in use if the new one cannot be opened.
+    pub fn reopen(&self, path: Option<&Path>) -> rusqlite::Result<()> {
+        let connection = connect(path)?;
+        *self.connection.lock().unwrap() = connection;
+        Ok(())
+    }
+
+    /// Whether the fingerprint or the IP group has an active ban.
+    pub fn is_banned(&self, fingerprint: &str, ip_group: &str) -> rusqlite::Result<bool> {
+        let connection = self.connection.lock().unwrap();
+        connection
+            .query_row(
+                "SELECT 1 FROM bans
+                WHERE ((kind = ?1 AND value = ?2) OR (kind = ?3 AND value = ?4))
+                AND (expires_at IS NULL OR expires_at > ?5)",
+                params![
+                    BanKind::Fingerprint,
+                    fingerprint,
+                    BanKind::IpGroup,
+                    ip_group,
+                    now(),
+                ],
+                |_| Ok(()),
+            )
+            .optional()
+            .map(|row| row.is_some())
+    }
+
+    /// Adds the ban or replaces the expiration of an existing one.
+    pub fn insert(&self, ban: &Ban) -> rusqlite::Result<()> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "INSERT OR REPLACE INTO bans (kind, value, expires_at) VALUES (?1, ?2, ?3)",
+            params![ban.kind, ban.value, ban.expires_at],
+        )?;
+        Ok(())
+    }
+
+    /// Returns `false` if there is no such ban.
+    pub fn remove(&self, kind: BanKind, value: &str) -> rusqlite::Result<bool> {
+        let connection = self.connection.lock().unwrap();
+        let removed = connection.execute(
+            "DELETE FROM bans WHERE kind = ?1 AND value = ?2",
+            params![kind, value],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// Returns the active bans.
+    pub fn list(&self) -> rusqlite::Result<Vec<Ban>> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection.prepare(
+            "SELECT kind, value, expires_at FROM bans
+            WHERE expires_at IS NULL OR expires_at > ?1
+            ORDER BY kind, value",
+        )?;
+
+        let bans = statement.query_map([now()], |row| {
+            Ok(Ban {
+                kind: row.get(0)?,
+                value: row.get(1)?,
+                expires_at: row.get(2)?,
+            })
+        })?;
+
+        bans.collect()
+    }
+
+    /// Deletes expired bans. Returns the number of deleted bans.
+    pub fn remove_expired(&self) -> rusqlite::Result<usize> {
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "DELETE FROM bans WHERE expires_at IS NOT NULL AND expires_at <= ?1",
+            [now()],
+        )
+    }
+}
+
+/// Opens the database and creates the table if needed.
+fn connect(path: Option<&Path>) -> rusqlite::Result<Connection> {
+    let connection = match path {
+        Some(path) => Connection::open(path)?,
+        None => Connection::open_in_memory()?,
+    };
+
+    connection.execute(
+        "CREATE TABLE IF NOT EXISTS bans (
+            kind TEXT NOT NULL,
+            value TEXT NOT NULL,
+            expires_at INTEGER,
+            PRIMARY KEY (kind, value)
+        )",
+        (),
+    )?;
+
+    Ok(connection)
+}
+
+/// The current unix timestamp in seconds.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ban(kind: BanKind, value: &str, expires_at: Option<u64>) -> Ban {
+        Ban {
+            kind,
+            value: value.to_string(),
+            expires_at,
+        }
+    }
+
+    #[test]
+    fn test_blocklist() {
+        let blocklist = Blocklist::open(None).unwrap();
+        let active = ban(BanKind::Fingerprint, "abc", None);
+        let expired = ban(BanKind::IpGroup, "1.2.3.4", Some(now() - 1));
+
+        blocklist.insert(&active).unwrap();
+        blocklist.insert(&expired).unwrap();
+
+        assert!(blocklist.is_banned("abc", "5.6.7.8").unwrap());
+        assert!(!blocklist.is_banned("def", "1.2.3.4").unwrap());
+        assert_eq!(blocklist.list().unwrap(), vec![active.clone()]);
+
+        // Extending the ban replaces the expiration.
+        let extended = ban(BanKind::IpGroup, "1.2.3.4", Some(now() + 60));
+        blocklist.insert(&extended).unwrap();
+        assert!(blocklist.is_banned("def", "1.2.3.4").unwrap());
+
+        assert!(blocklist.remove(BanKind::Fingerprint, "abc").unwrap());
+        assert!(!blocklist.remove(BanKind::Fingerprint, "abc").unwrap());
+        assert_eq!(blocklist.list().unwrap(), vec![extended]);
+    }
+
+    #[test]
+    fn test_reopen() {
+        let path = std::env::temp_dir().join(format!("blocklist-{}.db", uuid::Uuid::new_v4()));
+        Blocklist::open(Some(&path))
+            .unwrap()
+            .insert(&ban(BanKind::Fingerprint, "abc", None))
+            .unwrap();
+
+        let blocklist = Blocklist::open(None).unwrap();
+        assert!(!blocklist.is_banned("abc", "1.2.3.4").unwrap());
+
+        blocklist.reopen(Some(&path)).unwrap();
+        assert!(blocklist.is_banned("abc", "1.2.3.4").unwrap());
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_remove_expired() {
+        let blocklist = Blocklist::open(None).unwrap();
+        blocklist
+            .insert(&ban(BanKind::Fingerprint, "abc", Some(now() - 1)))
+            .unwrap();
+        blocklist
+            .insert(&ban(BanKind::Fingerprint, "def", None))
+            .unwrap();
+
+        assert_eq!(blocklist.remove_expired().unwrap(), 1);
+        assert_eq!(blocklist.remove_expired().unwrap(), 0);
+    }
+}
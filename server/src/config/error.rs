@@ -1,5 +1,10 @@
-use axum::http::StatusCode;
+use axum::http::{header, StatusCode};
 use axum::response::{IntoResponse, Response};
+use axum::Json;
+use localsend::webrtc::proof_of_work::ProofOfWorkChallenge;
+use localsend::webrtc::signaling::SignalingErrorCode;
+use serde::Serialize;
+use std::time::Duration;
 
 // https://github.com/tokio-rs/axum/blob/main/examples/oauth/src/main.rs
 
@@ -8,6 +13,28 @@ pub struct AppError {
     status: StatusCode,
     message: Option<String>,
     error: Option<anyhow::Error>,
+
+    /// Sent as JSON body, so that clients can tell rejected upgrades apart.
+    code: Option<SignalingErrorCode>,
+    retry_after: Option<Duration>,
+    challenge: Option<ProofOfWorkChallenge>,
+}
+
+/// The body of responses with a signaling error code.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ErrorDto {
+    /// The signaling error code, usually the same as the HTTP status code.
+    /// See [`SignalingErrorCode::http_status`].
+    code: u16,
+
+    /// Same as the `Retry-After` header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retry_after_seconds: Option<u64>,
+
+    /// The challenge to solve before connecting again.
+    #[serde(flatten)]
+    challenge: Option<ProofOfWorkChallenge>,
 }
 
 impl AppError {
@@ -16,6 +43,43 @@ impl AppError {
             status,
             message,
             error: None,
+            code: None,
+            retry_after: None,
+            challenge: None,
+        }
+    }
+
+    /// Responds with the code in the JSON body and its HTTP status.
+    pub fn signaling(code: SignalingErrorCode) -> Self {
+        let status = StatusCode::from_u16(code.http_status()).unwrap_or(StatusCode::BAD_REQUEST);
+        Self {
+            code: Some(code),
+            ..Self::status(status, None)
+        }
+    }
+
+    /// Too many requests. The client may try again after the given duration.
+    pub fn rate_limited(retry_after: Duration) -> Self {
+        Self {
+            retry_after: Some(retry_after),
+            ..Self::signaling(SignalingErrorCode::RateLimited)
+        }
+    }
+
+    /// The IP group of the client has too many open connections.
+    /// The client may try again after the given duration.
+    pub fn too_many_connections(retry_after: Duration) -> Self {
+        Self {
+            retry_after: Some(retry_after),
+            ..Self::signaling(SignalingErrorCode::TooManyConnections)
+        }
+    }
+
+    /// The client has to solve the challenge before connecting again.
+    pub fn proof_of_work_required(challenge: ProofOfWorkChallenge) -> Self {
+        Self {
+            challenge: Some(challenge),
+            ..Self::signaling(SignalingErrorCode::ProofOfWorkRequired)
         }
     }
 }
@@ -36,6 +100,24 @@ impl IntoResponse for AppError {
             );
         }
 
+        if let Some(code) = self.code {
+            // Rounded up, so that clients do not retry too early.
+            let retry_after_seconds = self
+                .retry_after
+                .map(|d| (d.as_secs() + u64::from(d.subsec_nanos() > 0)).max(1));
+            let body = Json(ErrorDto {
+                code: code.code(),
+                retry_after_seconds,
+                challenge: self.challenge,
+            });
+            return match retry_after_seconds {
+                Some(seconds) => {
+                    (self.status, [(header::RETRY_AFTER, seconds)], body).into_response()
+                }
+                None => (self.status, body).into_response(),
+            };
+        }
+
         // return status code with message
         if let Some(message) = self.message {
             (self.status, message).into_response()
@@ -54,6 +136,9 @@ where
             status: StatusCode::INTERNAL_SERVER_ERROR,
             message: None,
             error: Some(err.into()),
+            code: None,
+            retry_after: None,
+            challenge: None,
         }
     }
 }
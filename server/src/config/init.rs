@@ -1,20 +1,74 @@
+use crate::blocklist::Blocklist;
+#[cfg(unix)]
+use crate::config::reload::reload_on_sighup;
 use crate::config::scheduler;
+use crate::config::settings::{LogFormat, SETTINGS};
 use crate::config::state::AppState;
-use tracing::Level;
+use crate::federation::Federation;
+use crate::offline_queue::OfflineQueue;
+use crate::proof_of_work::ProofOfWork;
+use crate::registry::Registry;
+use crate::stats::DailyStats;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, reload};
 
 pub async fn init() -> AppState {
+    let settings = SETTINGS.load_full();
+
     // Set up tracing / logging
-    tracing_subscriber::fmt().with_max_level(Level::INFO).init();
+    // The level can be changed on SIGHUP.
+    let log_level = settings.log_level().expect("Error loading config");
+    let (level_filter, log_level_handle) = reload::Layer::new(LevelFilter::from_level(log_level));
+    let subscriber = tracing_subscriber::registry().with(level_filter);
+    match settings.log_format().expect("Error loading config") {
+        LogFormat::Json => subscriber.with(fmt::layer().json()).init(),
+        LogFormat::Text => subscriber.with(fmt::layer()).init(),
+    }
 
     tracing::info!("Starting LocalSend WebRTC signaling server...");
 
+    // Used for TLS and the requests to federated servers.
+    rustls::crypto::ring::default_provider()
+        .install_default()
+        .expect("Error installing crypto provider");
+
     // Initialize the AppState
-    let app_state = AppState::new();
+    let mut app_state = AppState::new();
+    app_state.registry = Registry::init(app_state.tx_map.clone()).await;
+    app_state.blocklist = Arc::new(
+        Blocklist::open(settings.blocklist.path.as_deref()).expect("Error opening blocklist"),
+    );
+    app_state.stats =
+        Arc::new(DailyStats::open(settings.stats.path.as_deref()).expect("Error opening stats"));
+    app_state.federation = Arc::new(Federation::new(settings.federation.nodes.clone()));
+    if app_state.federation.is_enabled() {
+        tracing::info!(
+            nodes = settings.federation.nodes.len(),
+            "Federation enabled"
+        );
+    }
+
+    app_state.offline_queue = Arc::new(OfflineQueue::new(Duration::from_secs(
+        settings.limits.offline_queue_ttl_seconds,
+    )));
+    app_state.proof_of_work = Arc::new(ProofOfWork::new(settings.proof_of_work.secret.as_deref()));
 
     // Setup scheduler
-    scheduler::configure_scheduling(app_state.request_count_map.clone())
+    scheduler::configure_scheduling(&app_state)
         .await
         .expect("Error configuring scheduler");
 
+    #[cfg(unix)]
+    tokio::spawn(reload_on_sighup(
+        app_state.blocklist.clone(),
+        log_level_handle,
+    ));
+    #[cfg(not(unix))]
+    drop(log_level_handle);
+
     app_state
 }
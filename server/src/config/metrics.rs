@@ -0,0 +1,340 @@
+use crate::config::state::TxMap;
+use localsend::webrtc::signaling::{WsClientMessage, WsServerMessage};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// The message types counted per IP group, indexed by [`message_type`].
+const MESSAGE_TYPES: [&str; 8] = [
+    "hello",
+    "join",
+    "update",
+    "left",
+    "offer",
+    "answer",
+    "error",
+    "announcement",
+];
+const OFFER: usize = 4;
+const ANSWER: usize = 5;
+
+/// Counters exposed via `/metrics` in the Prometheus text format.
+/// Gauges are derived from the `TxMap` when rendering.
+#[derive(Default)]
+pub struct Metrics {
+    pub offers_relayed: AtomicU64,
+    pub answers_relayed: AtomicU64,
+    pub rate_limit_rejections: AtomicU64,
+    pub connection_limit_rejections: AtomicU64,
+    pub proof_of_work_challenges: AtomicU64,
+    pub relayed_frames: AtomicU64,
+    pub relayed_bytes: AtomicU64,
+    pub websocket_errors: AtomicU64,
+
+    /// IP group -> Counters of the messages of its peers.
+    /// Peers in rooms are counted in the IP group they connected from.
+    /// Only locked when a peer connects, the counters themselves are atomic.
+    messages: Mutex<HashMap<String, Arc<GroupCounters>>>,
+}
+
+/// The messages of the peers of an IP group by type.
+/// Offers and answers are counted for the peer sending them,
+/// the other messages for the peer the server sends them to.
+#[derive(Default)]
+pub struct GroupCounters {
+    count: [AtomicU64; MESSAGE_TYPES.len()],
+    bytes: [AtomicU64; MESSAGE_TYPES.len()],
+}
+
+impl GroupCounters {
+    /// Counts a message of `bytes` (serialized) sent to a peer.
+    /// Offers and answers are skipped, they are counted for their origin when received.
+    pub fn record_sent(&self, message: &WsServerMessage, bytes: usize) {
+        let index = message_type(message);
+        if index != OFFER && index != ANSWER {
+            self.record(index, bytes);
+        }
+    }
+
+    /// Counts an offer or answer of `bytes` (serialized) received from a peer.
+    pub fn record_received(&self, message: &WsClientMessage, bytes: usize) {
+        match message {
+            WsClientMessage::Offer(_) => self.record(OFFER, bytes),
+            WsClientMessage::Answer(_) => self.record(ANSWER, bytes),
+            _ => {}
+        }
+    }
+
+    fn record(&self, index: usize, bytes: usize) {
+        self.count[index].fetch_add(1, Ordering::Relaxed);
+        self.bytes[index].fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+}
+
+impl Metrics {
+    pub fn inc(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// The message counters of the IP group, held by its connections.
+    pub fn group_counters(&self, ip_group: &str) -> Arc<GroupCounters> {
+        self.messages
+            .lock()
+            .unwrap()
+            .entry(ip_group.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Removes the message counters of IP groups without connections,
+    /// so that the number of series does not grow with every group ever seen.
+    pub fn remove_inactive_groups(&self) {
+        self.messages
+            .lock()
+            .unwrap()
+            .retain(|_, counters| Arc::strong_count(counters) > 1);
+    }
+
+    pub async fn render(&self, tx_map: &TxMap) -> String {
+        // IP group -> number of peers.
+        // Peers in rooms are counted in the IP group they connected from.
+        let mut peers_per_ip_group: BTreeMap<String, usize> = BTreeMap::new();
+        {
+            let tx_map = tx_map.lock().await;
+            for state in tx_map.values().flat_map(|m| m.values()) {
+                *peers_per_ip_group
+                    .entry(state.ip_group.clone())
+                    .or_insert(0) += 1;
+            }
+        }
+        let connected_peers: usize = peers_per_ip_group.values().sum();
+
+        let mut out = String::new();
+
+        write_header(
+            &mut out,
+            "localsend_connected_peers",
+            "gauge",
+            "Number of connected peers.",
+        );
+        let _ = writeln!(out, "localsend_connected_peers {connected_peers}");
+
+        write_header(
+            &mut out,
+            "localsend_ip_group_peers",
+            "gauge",
+            "Number of connected peers per IP group.",
+        );
+        for (ip_group, count) in &peers_per_ip_group {
+            let _ = writeln!(
+                out,
+                "localsend_ip_group_peers{{ip_group=\"{}\"}} {count}",
+                escape_label(ip_group)
+            );
+        }
+
+        for (name, help, counter) in [
+            (
+                "localsend_offers_relayed_total",
+                "Number of offers relayed to a peer.",
+                &self.offers_relayed,
+            ),
+            (
+                "localsend_answers_relayed_total",
+                "Number of answers relayed to a peer.",
+                &self.answers_relayed,
+            ),
+            (
+                "localsend_rate_limit_rejections_total",
+                "Number of connections and messages rejected by the rate limit.",
+                &self.rate_limit_rejections,
+            ),
+            (
+                "localsend_connection_limit_rejections_total",
+                "Number of connections rejected because their IP group had too many open connections.",
+                &self.connection_limit_rejections,
+            ),
+            (
+                "localsend_proof_of_work_challenges_total",
+                "Number of connections rejected with a proof-of-work challenge.",
+                &self.proof_of_work_challenges,
+            ),
+            (
+                "localsend_relayed_frames_total",
+                "Number of binary frames relayed between peers.",
+                &self.relayed_frames,
+            ),
+            (
+                "localsend_relayed_bytes_total",
+                "Number of bytes relayed between peers in binary frames.",
+                &self.relayed_bytes,
+            ),
+            (
+                "localsend_websocket_errors_total",
+                "Number of WebSocket errors.",
+                &self.websocket_errors,
+            ),
+        ] {
+            write_header(&mut out, name, "counter", help);
+            let _ = writeln!(out, "{name} {}", counter.load(Ordering::Relaxed));
+        }
+
+        let messages: BTreeMap<String, Arc<GroupCounters>> = self
+            .messages
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(ip_group, counters)| (ip_group.clone(), counters.clone()))
+            .collect();
+        for (name, help, bytes) in [
+            (
+                "localsend_messages_total",
+                "Number of messages per IP group and type. Offers and answers are counted for the sending IP group.",
+                false,
+            ),
+            (
+                "localsend_message_bytes_total",
+                "Bytes of the messages per IP group and type. Offers and answers are counted for the sending IP group.",
+                true,
+            ),
+        ] {
+            write_header(&mut out, name, "counter", help);
+            for (ip_group, counters) in &messages {
+                let values = if bytes { &counters.bytes } else { &counters.count };
+                for (message_type, value) in MESSAGE_TYPES.iter().zip(values) {
+                    let value = value.load(Ordering::Relaxed);
+                    if value == 0 {
+                        continue;
+                    }
+                    let _ = writeln!(
+                        out,
+                        "{name}{{ip_group=\"{}\",type=\"{message_type}\"}} {value}",
+                        escape_label(ip_group)
+                    );
+                }
+            }
+        }
+
+        out
+    }
+}
+
+/// The index in [`MESSAGE_TYPES`].
+fn message_type(message: &WsServerMessage) -> usize {
+    match message {
+        WsServerMessage::Hello { .. } => 0,
+        WsServerMessage::Join { .. } => 1,
+        WsServerMessage::Update { .. } => 2,
+        WsServerMessage::Left { .. } => 3,
+        WsServerMessage::Offer(_) => OFFER,
+        WsServerMessage::Answer(_) => ANSWER,
+        WsServerMessage::Error { .. } => 6,
+        WsServerMessage::Announcement { .. } => 7,
+    }
+}
+
+fn write_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {name} {help}");
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::state::ClientState;
+    use localsend::webrtc::signaling::{ClientInfo, WsClientSdpMessage, WsServerSdpMessage};
+    use tokio::sync::Mutex;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_render() {
+        let tx_map: TxMap = Arc::new(Mutex::new(HashMap::from([
+            (
+                "1.2.3.4".to_string(),
+                HashMap::from([(Uuid::new_v4(), ClientState::mock("123", "1.2.3.4").0)]),
+            ),
+            (
+                "room:ABC234".to_string(),
+                HashMap::from([
+                    (Uuid::new_v4(), ClientState::mock("123", "1.2.3.4").0),
+                    (Uuid::new_v4(), ClientState::mock("123", "5.6.7.8").0),
+                ]),
+            ),
+        ])));
+
+        let metrics = Metrics::default();
+        Metrics::inc(&metrics.offers_relayed);
+        let error = WsServerMessage::Error { code: 429 };
+        let counters = metrics.group_counters("1.2.3.4");
+        counters.record_sent(&error, 25);
+        counters.record_sent(&error, 25);
+        metrics.group_counters("9.9.9.9").record_sent(&error, 25);
+        metrics.remove_inactive_groups();
+
+        let rendered = metrics.render(&tx_map).await;
+        assert!(rendered.contains("localsend_connected_peers 3\n"));
+        assert!(rendered.contains("localsend_ip_group_peers{ip_group=\"1.2.3.4\"} 2\n"));
+        assert!(rendered.contains("localsend_ip_group_peers{ip_group=\"5.6.7.8\"} 1\n"));
+        assert!(rendered.contains("localsend_offers_relayed_total 1\n"));
+        assert!(rendered.contains("localsend_answers_relayed_total 0\n"));
+        assert!(
+            rendered.contains("localsend_messages_total{ip_group=\"1.2.3.4\",type=\"error\"} 2\n")
+        );
+        assert!(rendered
+            .contains("localsend_message_bytes_total{ip_group=\"1.2.3.4\",type=\"error\"} 50\n"));
+        assert!(!rendered.contains("9.9.9.9"));
+        assert!(!rendered.contains("room:"));
+    }
+
+    #[test]
+    fn test_group_counters() {
+        let counters = GroupCounters::default();
+        let sdp = WsClientSdpMessage {
+            session_id: "session".to_string(),
+            target: Uuid::new_v4(),
+            sdp: "sdp".to_string(),
+        };
+
+        // Offers are counted once, for the sender.
+        counters.record_received(&WsClientMessage::Offer(sdp), 100);
+        counters.record_received(&WsClientMessage::LeaveRoom, 10);
+        counters.record_sent(
+            &WsServerMessage::Left {
+                peer_id: Uuid::new_v4(),
+            },
+            50,
+        );
+        counters.record_sent(
+            &WsServerMessage::Offer(WsServerSdpMessage {
+                peer: ClientInfo::from(
+                    ClientState::mock("123", "1.2.3.4").0.client,
+                    Uuid::new_v4(),
+                ),
+                session_id: "session".to_string(),
+                sdp: "sdp".to_string(),
+            }),
+            200,
+        );
+
+        let count: Vec<u64> = counters
+            .count
+            .iter()
+            .map(|c| c.load(Ordering::Relaxed))
+            .collect();
+        let bytes: Vec<u64> = counters
+            .bytes
+            .iter()
+            .map(|c| c.load(Ordering::Relaxed))
+            .collect();
+        assert_eq!(count, [0, 0, 0, 1, 1, 0, 0, 0]);
+        assert_eq!(bytes, [0, 0, 0, 50, 100, 0, 0, 0]);
+    }
+}
@@ -1,4 +1,8 @@
 pub(crate) mod error;
 pub(crate) mod init;
+pub(crate) mod metrics;
+#[cfg(unix)]
+mod reload;
 mod scheduler;
+pub(crate) mod settings;
 pub(crate) mod state;
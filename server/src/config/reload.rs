@@ -0,0 +1,37 @@
+use crate::blocklist::Blocklist;
+use crate::config::settings::{Settings, SETTINGS};
+use std::sync::Arc;
+use tokio::signal::unix::{signal, SignalKind};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::{reload, Registry};
+
+/// Changes the log level at runtime.
+pub type LogLevelHandle = reload::Handle<LevelFilter, Registry>;
+
+/// Reloads the config file on every SIGHUP. Connected peers are not affected.
+/// See [`Settings::reloaded`] for what can be changed without a restart.
+pub async fn reload_on_sighup(blocklist: Arc<Blocklist>, log_level: LogLevelHandle) {
+    let mut sighup = signal(SignalKind::hangup()).expect("Error installing SIGHUP handler");
+    while sighup.recv().await.is_some() {
+        if let Err(e) = reload(&blocklist, &log_level) {
+            tracing::warn!(error = %e, "Error reloading config, keeping the current config");
+        }
+    }
+}
+
+fn reload(blocklist: &Blocklist, log_level: &LogLevelHandle) -> anyhow::Result<()> {
+    let current = SETTINGS.load_full();
+    let settings = current.reloaded(Settings::from_env()?);
+    let level = settings.log_level()?;
+
+    // A blocklist file might have been replaced.
+    if settings.blocklist.path.is_some() || settings.blocklist.path != current.blocklist.path {
+        blocklist.reopen(settings.blocklist.path.as_deref())?;
+    }
+
+    log_level.reload(LevelFilter::from_level(level))?;
+    SETTINGS.store(Arc::new(settings));
+
+    tracing::info!(%level, "Reloaded config");
+    Ok(())
+}
@@ -1,18 +1,118 @@
+use std::time::{Duration, Instant};
 use tokio_cron_scheduler::{Job, JobScheduler};
 
-use crate::config::state::IpRequestCountMap;
+use crate::config::settings::SETTINGS;
+use crate::config::state::AppState;
+use crate::controller::ws_controller;
+use crate::federation::ROUTE_TTL;
+use crate::util::rate_limit::REQUEST_WINDOW;
 
-pub async fn configure_scheduling(
-    ip_request_count_map: IpRequestCountMap,
-) -> Result<(), Box<dyn std::error::Error>> {
+pub async fn configure_scheduling(state: &AppState) -> Result<(), Box<dyn std::error::Error>> {
+    let AppState {
+        request_count_map: ip_request_count_map,
+        blocklist,
+        tx_map,
+        resume_map,
+        registry,
+        federation,
+        offline_queue,
+        proof_of_work,
+        metrics,
+        stats,
+        ..
+    } = state.clone();
     let scheduler = JobScheduler::new().await?;
 
+    // Remove windows that no longer affect the rate limit.
     scheduler
-        .add(Job::new_async("0 0 * * * *", move |_uuid, _l| {
+        .add(Job::new_async("0 * * * * *", move |_uuid, _l| {
             Box::pin({
                 let value = ip_request_count_map.clone();
                 async move {
-                    value.lock().await.clear();
+                    let now = Instant::now();
+                    value
+                        .lock()
+                        .await
+                        .retain(|_, window| !window.is_stale(now, REQUEST_WINDOW));
+                }
+            })
+        })?)
+        .await?;
+
+    // Remove expired bans.
+    scheduler
+        .add(Job::new("0 * * * * *", move |_uuid, _l| {
+            if let Err(e) = blocklist.remove_expired() {
+                tracing::warn!(error = ?e, "Error removing expired bans");
+            }
+        })?)
+        .await?;
+
+    // Remove routes to peers of other servers that are no longer used.
+    scheduler
+        .add(Job::new("0 * * * * *", move |_uuid, _l| {
+            federation.remove_stale_routes(ROUTE_TTL);
+        })?)
+        .await?;
+
+    // Remove resume tokens of peers that did not reconnect in time.
+    scheduler
+        .add(Job::new_async("0 * * * * *", move |_uuid, _l| {
+            Box::pin({
+                let resume_map = resume_map.clone();
+                async move {
+                    ws_controller::remove_expired_resume_tokens(&resume_map).await;
+                }
+            })
+        })?)
+        .await?;
+
+    // Remove queued messages of peers that did not resume their session.
+    scheduler
+        .add(Job::new("0 * * * * *", move |_uuid, _l| {
+            offline_queue.remove_expired();
+        })?)
+        .await?;
+
+    // Forget solved challenges that can no longer be used.
+    scheduler
+        .add(Job::new("0 * * * * *", move |_uuid, _l| {
+            proof_of_work.remove_expired();
+        })?)
+        .await?;
+
+    // Write the daily aggregates.
+    let stats_metrics = metrics.clone();
+    scheduler
+        .add(Job::new("0 * * * * *", move |_uuid, _l| {
+            let retention_days = SETTINGS.load().stats.retention_days;
+            if let Err(e) = stats.flush(&stats_metrics, retention_days) {
+                tracing::warn!(error = ?e, "Error writing stats");
+            }
+        })?)
+        .await?;
+
+    // Remove the message counters of IP groups that have left.
+    scheduler
+        .add(Job::new("0 0 * * * *", move |_uuid, _l| {
+            metrics.remove_inactive_groups();
+        })?)
+        .await?;
+
+    // Remove peers whose connection ended without cleanup or stopped responding.
+    // The connection itself closes after the idle timeout, so this only catches leftovers.
+    let stale_ttl = Duration::from_secs(2 * SETTINGS.load().limits.idle_timeout_seconds);
+    scheduler
+        .add(Job::new_async("30 * * * * *", move |_uuid, _l| {
+            Box::pin({
+                let tx_map = tx_map.clone();
+                let registry = registry.clone();
+                async move {
+                    let removed =
+                        ws_controller::remove_stale_peers(&tx_map, &registry, stale_ttl).await;
+                    if removed > 0 {
+                        tracing::info!(removed, "Removed stale peers");
+                    }
                 }
             })
         })?)
@@ -0,0 +1,1013 @@
+use crate::util::sdp::MAX_ENCODED_SDP_LENGTH;
+use arc_swap::ArcSwap;
+use axum::http::{HeaderValue, Method};
+use ipnet::IpNet;
+use localsend::webrtc::proof_of_work::MAX_DIFFICULTY;
+use localsend::webrtc::ws_relay::PEER_ID_LENGTH;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::net::{IpAddr, SocketAddr, ToSocketAddrs};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use tracing::Level;
+
+/// Default path of the config file. Can be changed with `CONFIG_PATH`.
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+
+/// Relay frames must not be larger than the other messages of a peer.
+const MAX_RELAY_FRAME_SIZE: usize = MAX_ENCODED_SDP_LENGTH;
+
+/// Loaded at startup. Parts of it are replaced on SIGHUP, see [`Settings::reloaded`].
+/// Environment variables take precedence over the config file.
+pub static SETTINGS: LazyLock<ArcSwap<Settings>> =
+    LazyLock::new(|| ArcSwap::from_pointee(Settings::from_env().expect("Error loading config")));
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Settings {
+    pub server: ServerSettings,
+    pub tls: Option<TlsSettings>,
+    pub listeners: Vec<ListenerSettings>,
+    pub proxy: ProxySettings,
+    pub cors: CorsSettings,
+    pub websocket: WebSocketSettings,
+    pub access: AccessSettings,
+    pub proof_of_work: ProofOfWorkSettings,
+    pub limits: LimitSettings,
+    pub allowlist: AllowlistSettings,
+    pub ip_group: IpGroupSettings,
+    pub rooms: RoomSettings,
+    pub alias: AliasSettings,
+    pub relay: RelaySettings,
+    pub metrics: MetricsSettings,
+    pub admin: AdminSettings,
+    pub blocklist: BlocklistSettings,
+    pub stats: StatsSettings,
+    pub redis: RedisSettings,
+    pub federation: FederationSettings,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerSettings {
+    pub ip: String,
+    pub port: u16,
+
+    /// One of `trace`, `debug`, `info`, `warn` or `error`.
+    pub log_level: String,
+
+    /// `json` or `text`.
+    pub log_format: String,
+
+    /// If set, plain HTTP is additionally served on this unix socket, e.g. for a reverse proxy on the same host.
+    pub unix_socket: Option<PathBuf>,
+}
+
+impl Default for ServerSettings {
+    fn default() -> Self {
+        Self {
+            ip: "0.0.0.0".to_string(),
+            port: 3000,
+            log_level: "info".to_string(),
+            log_format: "json".to_string(),
+            unix_socket: None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum LogFormat {
+    /// One JSON object per line including the fields of the spans.
+    Json,
+
+    /// Human readable, for development.
+    Text,
+}
+
+/// If set, the server is served via HTTPS / WSS.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TlsSettings {
+    /// PEM encoded certificate chain.
+    pub cert: PathBuf,
+
+    /// PEM encoded private key.
+    pub key: PathBuf,
+}
+
+/// A TCP address the server listens on.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ListenerSettings {
+    /// e.g. `0.0.0.0:3000` or `[::]:3000`.
+    pub address: SocketAddr,
+
+    /// Served via HTTPS / WSS if set.
+    pub tls: Option<TlsSettings>,
+}
+
+/// Cross-origin requests of browser-based clients.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct CorsSettings {
+    /// Origins allowed to call the server, e.g. `https://web.localsend.org`.
+    /// `*` allows every origin. CORS is disabled if empty.
+    pub allowed_origins: Vec<String>,
+
+    /// Methods allowed in cross-origin requests.
+    pub allowed_methods: Vec<String>,
+}
+
+impl Default for CorsSettings {
+    fn default() -> Self {
+        Self {
+            allowed_origins: Vec::new(),
+            allowed_methods: ["GET", "POST", "DELETE"]
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+}
+
+impl CorsSettings {
+    pub fn is_enabled(&self) -> bool {
+        !self.allowed_origins.is_empty()
+    }
+
+    pub fn allows_any_origin(&self) -> bool {
+        self.allowed_origins.iter().any(|origin| origin == "*")
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct WebSocketSettings {
+    /// Origins of websites allowed to open a WebSocket connection, e.g. `https://web.localsend.org`.
+    /// Connections without an `Origin` header (native apps) are always allowed.
+    /// Every origin is allowed if empty.
+    pub allowed_origins: Vec<String>,
+}
+
+impl WebSocketSettings {
+    pub fn is_origin_allowed(&self, origin: Option<&str>) -> bool {
+        match origin {
+            None => true,
+            Some(_) if self.allowed_origins.is_empty() => true,
+            Some(origin) => self
+                .allowed_origins
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(origin)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct LimitSettings {
+    /// Maximum number of peers in an IP group or room, counting the peers of all federated
+    /// instances. Checked when joining, so it limits the size of peer lists.
+    /// Exceeding peers receive [`RoomFull`](localsend::webrtc::signaling::SignalingErrorCode::RoomFull).
+    pub max_connections_per_ip: usize,
+
+    /// Maximum number of open connections of an IP group to this instance,
+    /// including the peers that have moved into rooms.
+    /// Checked before the upgrade, so it limits the resources a network can hold.
+    /// Exceeding upgrades are rejected with status `429` and the code
+    /// [`TooManyConnections`](localsend::webrtc::signaling::SignalingErrorCode::TooManyConnections).
+    pub max_concurrent_connections_per_ip_group: usize,
+
+    pub max_requests_per_ip_per_hour: u32,
+
+    /// How long a disconnected peer can resume its session.
+    pub resume_token_ttl_seconds: u64,
+
+    /// How long offers and answers for a disconnected peer are kept until it resumes its session.
+    /// `0` drops them immediately.
+    pub offline_queue_ttl_seconds: u64,
+
+    /// Peers that have not sent anything (including pongs) for this long are disconnected.
+    /// Pings are sent every third of this time.
+    pub idle_timeout_seconds: u64,
+}
+
+impl Default for LimitSettings {
+    fn default() -> Self {
+        Self {
+            max_connections_per_ip: 10,
+            max_concurrent_connections_per_ip_group: 50,
+            max_requests_per_ip_per_hour: 1000,
+            resume_token_ttl_seconds: 60,
+            offline_queue_ttl_seconds: 10,
+            idle_timeout_seconds: 90,
+        }
+    }
+}
+
+/// Peers exempt from `max_requests_per_ip_per_hour`, e.g. the operator's own devices or a kiosk.
+/// The other limits still apply.
+///
+/// Peers are only matched by the network they connect from. Fingerprints are not supported,
+/// as the server cannot verify the `token` a peer sends, so anyone could claim one.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AllowlistSettings {
+    /// Networks the peers connect from.
+    pub networks: Vec<IpNet>,
+}
+
+impl AllowlistSettings {
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        self.networks.iter().any(|net| net.contains(&ip))
+    }
+}
+
+/// Reverse proxies and load balancers in front of the server.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProxySettings {
+    /// Networks whose `X-Forwarded-For` headers and PROXY protocol headers are trusted.
+    pub trusted: Vec<IpNet>,
+
+    /// Whether every connection starts with a PROXY protocol header (version 1 or 2).
+    pub protocol: bool,
+}
+
+impl Default for ProxySettings {
+    /// Only proxies on the same host are trusted.
+    /// Other peers in a private network could otherwise spoof their address.
+    fn default() -> Self {
+        Self {
+            trusted: ["127.0.0.0/8", "::1/128"]
+                .into_iter()
+                .map(|net| net.parse().unwrap())
+                .collect(),
+            protocol: false,
+        }
+    }
+}
+
+impl ProxySettings {
+    pub fn is_trusted(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        self.trusted.iter().any(|net| net.contains(&ip))
+    }
+}
+
+/// Peers in the same network can see each other.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct IpGroupSettings {
+    /// Lower values group more peers, e.g. households behind the same CGNAT.
+    pub ipv4_prefix_length: u8,
+
+    /// Lower values group more peers, e.g. if the ISP assigns a /56 network.
+    pub ipv6_prefix_length: u8,
+}
+
+impl Default for IpGroupSettings {
+    fn default() -> Self {
+        Self {
+            ipv4_prefix_length: 32,
+            ipv6_prefix_length: 64,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RoomSettings {
+    /// Whether peers can create and join rooms.
+    pub enabled: bool,
+}
+
+impl Default for RoomSettings {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Rules for the alias of connecting peers.
+/// Peers violating a rule are rejected.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AliasSettings {
+    /// Maximum number of characters.
+    pub max_length: Option<usize>,
+
+    /// Case-insensitive words the alias must not contain.
+    pub blocked_words: Vec<String>,
+
+    /// Appends a number to aliases already used in the group (e.g. `Galaxy S23 (2)`),
+    /// so that devices of the same model can be told apart.
+    pub unique: bool,
+}
+
+impl Default for AliasSettings {
+    fn default() -> Self {
+        Self {
+            max_length: None,
+            blocked_words: Vec::new(),
+            unique: true,
+        }
+    }
+}
+
+impl AliasSettings {
+    /// Returns the alias, with a suffix if it is one of `taken`.
+    pub fn make_unique<'a>(&self, alias: &str, taken: impl Iterator<Item = &'a str>) -> String {
+        let taken: HashSet<&str> = taken.collect();
+        if !self.unique || !taken.contains(alias) {
+            return alias.to_string();
+        }
+
+        (2..)
+            .map(|n| format!("{alias} ({n})"))
+            .find(|candidate| !taken.contains(candidate.as_str()))
+            .unwrap()
+    }
+
+    pub fn is_allowed(&self, alias: &str) -> bool {
+        if self
+            .max_length
+            .is_some_and(|max_length| alias.chars().count() > max_length)
+        {
+            return false;
+        }
+
+        let alias = alias.to_lowercase();
+        !self
+            .blocked_words
+            .iter()
+            .any(|word| alias.contains(&word.to_lowercase()))
+    }
+}
+
+/// Binary frames relayed between peers whose direct connection failed.
+/// See [`localsend::webrtc::ws_relay`].
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RelaySettings {
+    pub enabled: bool,
+
+    /// Maximum size of a frame including the peer ID.
+    pub max_frame_size: usize,
+
+    /// Maximum number of bytes a peer can relay per second. Faster peers are slowed down.
+    pub max_bytes_per_second: usize,
+}
+
+impl Default for RelaySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_frame_size: 16 * 1024,
+            max_bytes_per_second: 256 * 1024,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AccessSettings {
+    /// If set, WebSocket upgrades require `Authorization: Bearer <token>` or the query parameter `t=<token>`.
+    /// Also protects `/metrics` if `metrics.token` is not set.
+    pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ProofOfWorkSettings {
+    /// Number of leading zero bits the hash of a solved challenge must have.
+    /// Zero disables the proof of work.
+    pub difficulty: u8,
+
+    /// Signs the challenges. Must be the same for all instances behind a load balancer.
+    /// Random if not set.
+    pub secret: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct MetricsSettings {
+    /// If set, `/metrics` requires `Authorization: Bearer <token>`.
+    pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct AdminSettings {
+    /// If set, the admin API at `/admin` is enabled and requires `Authorization: Bearer <token>`.
+    pub token: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct BlocklistSettings {
+    /// SQLite database of the bans.
+    /// If not set, bans are lost on restart.
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct StatsSettings {
+    /// SQLite database of the daily aggregates shown at `/stats`.
+    /// If not set, they are lost on restart.
+    pub path: Option<PathBuf>,
+
+    /// Days after which the aggregates are deleted.
+    pub retention_days: u64,
+}
+
+impl Default for StatsSettings {
+    fn default() -> Self {
+        Self {
+            path: None,
+            retention_days: 365,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RedisSettings {
+    /// If set, peers are shared with other instances via Redis.
+    pub url: Option<String>,
+}
+
+/// Other servers whose peers can exchange offers with the peers of this server.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(default, deny_unknown_fields)]
+pub struct FederationSettings {
+    pub nodes: Vec<FederatedNodeSettings>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct FederatedNodeSettings {
+    /// Base URL of the other server, e.g. `https://office.example.com`.
+    pub url: String,
+
+    /// Shared secret of the link. Configured on both servers.
+    /// Identifies the other server in its requests.
+    pub token: String,
+}
+
+impl Settings {
+    /// Loads the config file at `CONFIG_PATH` and applies the environment variables.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let path = std::env::var("CONFIG_PATH").ok();
+        Self::load(path.as_deref(), |key| std::env::var(key).ok())
+    }
+
+    /// Takes the settings that can change at runtime from `new`.
+    /// Everything else keeps its current value until the next restart.
+    pub fn reloaded(&self, new: Settings) -> Settings {
+        Settings {
+            server: ServerSettings {
+                log_level: new.server.log_level,
+                ..self.server.clone()
+            },
+            limits: LimitSettings {
+                // The queue is created at startup.
+                offline_queue_ttl_seconds: self.limits.offline_queue_ttl_seconds,
+                ..new.limits
+            },
+            allowlist: new.allowlist,
+            access: new.access,
+            proof_of_work: ProofOfWorkSettings {
+                // The secret is used at startup.
+                secret: self.proof_of_work.secret.clone(),
+                ..new.proof_of_work
+            },
+            rooms: new.rooms,
+            alias: new.alias,
+            relay: new.relay,
+            blocklist: new.blocklist,
+            ..self.clone()
+        }
+    }
+
+    /// Reads the config file (if it exists) and applies the environment variable overrides.
+    /// A missing file is only an error if the path has been set explicitly.
+    pub fn load(path: Option<&str>, env: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        let mut settings = match path {
+            Some(path) => toml::from_str(&std::fs::read_to_string(path)?)?,
+            None => match std::fs::read_to_string(DEFAULT_CONFIG_PATH) {
+                Ok(content) => toml::from_str(&content)?,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => Settings::default(),
+                Err(e) => return Err(e.into()),
+            },
+        };
+
+        settings.apply_env(env)?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    fn apply_env(&mut self, env: impl Fn(&str) -> Option<String>) -> anyhow::Result<()> {
+        if let Some(v) = env("SERVER_IP") {
+            self.server.ip = v;
+        }
+        if let Some(v) = env("SERVER_PORT") {
+            self.server.port = v.parse()?;
+        }
+        if let Some(v) = env("UNIX_SOCKET_PATH") {
+            self.server.unix_socket = Some(v.into());
+        }
+        if let Some(v) = env("LOG_LEVEL") {
+            self.server.log_level = v;
+        }
+        if let Some(v) = env("LOG_FORMAT") {
+            self.server.log_format = v;
+        }
+        if let (Some(cert), Some(key)) = (env("TLS_CERT_PATH"), env("TLS_KEY_PATH")) {
+            self.tls = Some(TlsSettings {
+                cert: cert.into(),
+                key: key.into(),
+            });
+        }
+        if let Some(v) = env("TRUSTED_PROXIES") {
+            self.proxy.trusted = v
+                .split(',')
+                .map(str::trim)
+                .filter(|net| !net.is_empty())
+                .map(IpNet::from_str)
+                .collect::<Result<_, _>>()?;
+        }
+        if let Some(v) = env("PROXY_PROTOCOL") {
+            self.proxy.protocol = v.parse()?;
+        }
+        if let Some(v) = env("CORS_ALLOWED_ORIGINS") {
+            self.cors.allowed_origins = split_list(&v);
+        }
+        if let Some(v) = env("CORS_ALLOWED_METHODS") {
+            self.cors.allowed_methods = split_list(&v);
+        }
+        if let Some(v) = env("WS_ALLOWED_ORIGINS") {
+            self.websocket.allowed_origins = split_list(&v);
+        }
+        if let Some(v) = env("ACCESS_TOKEN").filter(|v| !v.is_empty()) {
+            self.access.token = Some(v);
+        }
+        if let Some(v) = env("POW_DIFFICULTY") {
+            self.proof_of_work.difficulty = v.parse()?;
+        }
+        if let Some(v) = env("POW_SECRET").filter(|v| !v.is_empty()) {
+            self.proof_of_work.secret = Some(v);
+        }
+        if let Some(v) = env("MAX_CONNECTIONS_PER_IP") {
+            self.limits.max_connections_per_ip = v.parse()?;
+        }
+        if let Some(v) = env("MAX_CONCURRENT_CONNECTIONS_PER_IP_GROUP") {
+            self.limits.max_concurrent_connections_per_ip_group = v.parse()?;
+        }
+        if let Some(v) = env("MAX_REQUESTS_PER_IP_PER_HOUR") {
+            self.limits.max_requests_per_ip_per_hour = v.parse()?;
+        }
+        if let Some(v) = env("RESUME_TOKEN_TTL_SECONDS") {
+            self.limits.resume_token_ttl_seconds = v.parse()?;
+        }
+        if let Some(v) = env("OFFLINE_QUEUE_TTL_SECONDS") {
+            self.limits.offline_queue_ttl_seconds = v.parse()?;
+        }
+        if let Some(v) = env("IDLE_TIMEOUT_SECONDS") {
+            self.limits.idle_timeout_seconds = v.parse()?;
+        }
+        if let Some(v) = env("IPV4_PREFIX_LENGTH") {
+            self.ip_group.ipv4_prefix_length = v.parse()?;
+        }
+        if let Some(v) = env("IPV6_PREFIX_LENGTH") {
+            self.ip_group.ipv6_prefix_length = v.parse()?;
+        }
+        if let Some(v) = env("ROOMS_ENABLED") {
+            self.rooms.enabled = v.parse()?;
+        }
+        if let Some(v) = env("ALIAS_MAX_LENGTH") {
+            self.alias.max_length = Some(v.parse()?);
+        }
+        if let Some(v) = env("ALIAS_UNIQUE") {
+            self.alias.unique = v.parse()?;
+        }
+        if let Some(v) = env("RELAY_ENABLED") {
+            self.relay.enabled = v.parse()?;
+        }
+        if let Some(v) = env("RELAY_MAX_FRAME_SIZE") {
+            self.relay.max_frame_size = v.parse()?;
+        }
+        if let Some(v) = env("RELAY_MAX_BYTES_PER_SECOND") {
+            self.relay.max_bytes_per_second = v.parse()?;
+        }
+        if let Some(v) = env("METRICS_TOKEN").filter(|v| !v.is_empty()) {
+            self.metrics.token = Some(v);
+        }
+        if let Some(v) = env("ADMIN_TOKEN").filter(|v| !v.is_empty()) {
+            self.admin.token = Some(v);
+        }
+        if let Some(v) = env("BLOCKLIST_PATH") {
+            self.blocklist.path = Some(v.into());
+        }
+        if let Some(v) = env("STATS_PATH") {
+            self.stats.path = Some(v.into());
+        }
+        if let Some(v) = env("STATS_RETENTION_DAYS") {
+            self.stats.retention_days = v.parse()?;
+        }
+        if let Some(v) = env("REDIS_URL") {
+            self.redis.url = Some(v);
+        }
+        Ok(())
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        #[cfg(not(unix))]
+        if self.server.unix_socket.is_some() {
+            anyhow::bail!("Unix sockets are not supported on this platform");
+        }
+        let listeners = self.listeners()?;
+        for (i, listener) in listeners.iter().enumerate() {
+            if listeners[..i]
+                .iter()
+                .any(|other| other.address == listener.address)
+            {
+                anyhow::bail!("Duplicate listener address: {}", listener.address);
+            }
+        }
+        if self.limits.idle_timeout_seconds == 0 {
+            anyhow::bail!("Invalid idle timeout: 0");
+        }
+        if self.proof_of_work.difficulty > MAX_DIFFICULTY {
+            anyhow::bail!(
+                "Invalid proof of work difficulty: {}",
+                self.proof_of_work.difficulty
+            );
+        }
+        if self.relay.max_frame_size <= PEER_ID_LENGTH
+            || self.relay.max_frame_size > MAX_RELAY_FRAME_SIZE
+        {
+            anyhow::bail!("Invalid relay frame size: {}", self.relay.max_frame_size);
+        }
+        if self.relay.max_bytes_per_second < self.relay.max_frame_size {
+            anyhow::bail!(
+                "Invalid relay rate: {} (must be at least the frame size)",
+                self.relay.max_bytes_per_second
+            );
+        }
+        if self.ip_group.ipv4_prefix_length > 32 {
+            anyhow::bail!(
+                "Invalid IPv4 prefix length: {}",
+                self.ip_group.ipv4_prefix_length
+            );
+        }
+        if self.ip_group.ipv6_prefix_length > 128 {
+            anyhow::bail!(
+                "Invalid IPv6 prefix length: {}",
+                self.ip_group.ipv6_prefix_length
+            );
+        }
+        if self.cors.allows_any_origin() && self.cors.allowed_origins.len() > 1 {
+            anyhow::bail!("CORS origin `*` cannot be combined with other origins");
+        }
+        for origin in &self.cors.allowed_origins {
+            if origin != "*" && !origin.starts_with("https://") && !origin.starts_with("http://") {
+                anyhow::bail!("Invalid CORS origin: {origin}");
+            }
+            HeaderValue::from_str(origin)
+                .map_err(|_| anyhow::anyhow!("Invalid CORS origin: {origin}"))?;
+        }
+        for origin in &self.websocket.allowed_origins {
+            if !origin.starts_with("https://") && !origin.starts_with("http://") {
+                anyhow::bail!("Invalid WebSocket origin: {origin}");
+            }
+        }
+        for method in &self.cors.allowed_methods {
+            Method::from_str(method)
+                .map_err(|_| anyhow::anyhow!("Invalid CORS method: {method}"))?;
+        }
+        for (i, node) in self.federation.nodes.iter().enumerate() {
+            if !node.url.starts_with("https://") && !node.url.starts_with("http://") {
+                anyhow::bail!("Invalid federated node URL: {}", node.url);
+            }
+            if node.token.is_empty() {
+                anyhow::bail!("Missing token of federated node {}", node.url);
+            }
+            if self.federation.nodes[..i]
+                .iter()
+                .any(|other| other.token == node.token)
+            {
+                anyhow::bail!("Federated nodes must have different tokens");
+            }
+        }
+        Ok(())
+    }
+
+    /// The TCP listeners.
+    /// Without `listeners`, the server listens on `server.ip` and `server.port` using `tls`.
+    pub fn listeners(&self) -> anyhow::Result<Vec<ListenerSettings>> {
+        if !self.listeners.is_empty() {
+            return Ok(self.listeners.clone());
+        }
+
+        // Host names like `localhost` are resolved once.
+        let address = (self.server.ip.as_str(), self.server.port)
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addresses| addresses.next())
+            .ok_or_else(|| anyhow::anyhow!("Invalid server IP: {}", self.server.ip))?;
+        Ok(vec![ListenerSettings {
+            address,
+            tls: self.tls.clone(),
+        }])
+    }
+
+    pub fn log_level(&self) -> anyhow::Result<Level> {
+        Level::from_str(&self.server.log_level)
+            .map_err(|_| anyhow::anyhow!("Invalid log level: {}", self.server.log_level))
+    }
+
+    pub fn log_format(&self) -> anyhow::Result<LogFormat> {
+        match self.server.log_format.as_str() {
+            "json" => Ok(LogFormat::Json),
+            "text" => Ok(LogFormat::Text),
+            format => Err(anyhow::anyhow!("Invalid log format: {format}")),
+        }
+    }
+}
+
+/// Splits a comma separated environment variable.
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_example_config() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/config.example.toml");
+        let settings = Settings::load(Some(path), |_| None).unwrap();
+
+        assert_eq!(settings.server, ServerSettings::default());
+        assert_eq!(settings.limits, LimitSettings::default());
+        assert_eq!(settings.ip_group, IpGroupSettings::default());
+        assert_eq!(settings.tls, None);
+        assert!(settings.listeners.is_empty());
+        assert_eq!(settings.proxy, ProxySettings::default());
+        assert_eq!(settings.admin.token, None);
+        assert_eq!(settings.access.token, None);
+        assert_eq!(settings.proof_of_work, ProofOfWorkSettings::default());
+        assert_eq!(settings.federation, FederationSettings::default());
+        assert_eq!(settings.alias.max_length, Some(64));
+        assert!(settings.alias.unique);
+        assert_eq!(settings.relay, RelaySettings::default());
+        assert_eq!(settings.stats, StatsSettings::default());
+        assert!(settings.rooms.enabled);
+        assert_eq!(settings.log_level().unwrap(), Level::INFO);
+        assert_eq!(settings.log_format().unwrap(), LogFormat::Json);
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let env = HashMap::from([
+            ("SERVER_PORT", "8080"),
+            ("MAX_REQUESTS_PER_IP_PER_HOUR", "5"),
+            ("MAX_CONCURRENT_CONNECTIONS_PER_IP_GROUP", "20"),
+            ("ROOMS_ENABLED", "false"),
+            ("METRICS_TOKEN", ""),
+            ("ACCESS_TOKEN", "secret"),
+            ("TRUSTED_PROXIES", "203.0.113.0/24, 2001:db8::1/128"),
+            (
+                "CORS_ALLOWED_ORIGINS",
+                "https://web.localsend.org, http://localhost:8080",
+            ),
+        ]);
+
+        let mut settings: Settings = toml::from_str("[server]\nport = 4000\n").unwrap();
+        settings
+            .apply_env(|key| env.get(key).map(|v| v.to_string()))
+            .unwrap();
+
+        assert_eq!(settings.server.port, 8080);
+        assert_eq!(settings.limits.max_requests_per_ip_per_hour, 5);
+        assert_eq!(settings.limits.max_connections_per_ip, 10);
+        assert_eq!(settings.limits.max_concurrent_connections_per_ip_group, 20);
+        assert!(!settings.rooms.enabled);
+        assert_eq!(settings.metrics.token, None);
+        assert_eq!(settings.access.token.as_deref(), Some("secret"));
+        assert_eq!(settings.proxy.trusted.len(), 2);
+        assert_eq!(
+            settings.cors.allowed_origins,
+            ["https://web.localsend.org", "http://localhost:8080"]
+        );
+    }
+
+    #[test]
+    fn test_allowlist() {
+        let allowlist: AllowlistSettings = toml::from_str(
+            r#"
+            networks = ["203.0.113.0/24", "2001:db8::/32"]
+            "#,
+        )
+        .unwrap();
+
+        assert!(allowlist.contains(IpAddr::from_str("203.0.113.7").unwrap()));
+        assert!(allowlist.contains(IpAddr::from_str("::ffff:203.0.113.7").unwrap()));
+        assert!(allowlist.contains(IpAddr::from_str("2001:db8::1").unwrap()));
+        assert!(!allowlist.contains(IpAddr::from_str("198.51.100.1").unwrap()));
+
+        // Fingerprints cannot be verified, so they are rejected instead of silently ignored.
+        assert!(toml::from_str::<AllowlistSettings>(r#"fingerprints = ["kiosk"]"#).is_err());
+    }
+
+    #[test]
+    fn test_trusted_proxies() {
+        let proxy = ProxySettings::default();
+
+        assert!(proxy.is_trusted(IpAddr::from_str("127.0.0.1").unwrap()));
+        assert!(proxy.is_trusted(IpAddr::from_str("::ffff:127.0.0.2").unwrap()));
+        assert!(proxy.is_trusted(IpAddr::from_str("::1").unwrap()));
+        assert!(!proxy.is_trusted(IpAddr::from_str("172.17.0.2").unwrap()));
+        assert!(!proxy.is_trusted(IpAddr::from_str("::ffff:10.1.2.3").unwrap()));
+        assert!(!proxy.is_trusted(IpAddr::from_str("203.0.113.7").unwrap()));
+        assert!(!proxy.is_trusted(IpAddr::from_str("2001:db8::1").unwrap()));
+    }
+
+    #[test]
+    fn test_listeners() {
+        let settings: Settings = toml::from_str("[server]\nip = \"::\"\nport = 4000\n").unwrap();
+        let listeners = settings.listeners().unwrap();
+        assert_eq!(listeners.len(), 1);
+        assert_eq!(listeners[0].address, "[::]:4000".parse().unwrap());
+        assert_eq!(listeners[0].tls, None);
+
+        let settings: Settings = toml::from_str(
+            r#"
+            [[listeners]]
+            address = "0.0.0.0:3000"
+
+            [[listeners]]
+            address = "[::]:3443"
+            tls = { cert = "cert.pem", key = "key.pem" }
+            "#,
+        )
+        .unwrap();
+        assert!(settings.validate().is_ok());
+        let listeners = settings.listeners().unwrap();
+        assert_eq!(listeners.len(), 2);
+        assert!(listeners[1].tls.is_some());
+
+        let settings: Settings = toml::from_str(
+            "[[listeners]]\naddress = \"0.0.0.0:3000\"\n\n[[listeners]]\naddress = \"0.0.0.0:3000\"\n",
+        )
+        .unwrap();
+        assert!(settings.validate().is_err());
+
+        let settings: Settings = toml::from_str("[server]\nip = \"not an ip\"\n").unwrap();
+        assert!(settings.validate().is_err());
+    }
+
+    #[test]
+    fn test_reloaded() {
+        let current: Settings = toml::from_str("[server]\nport = 4000\n").unwrap();
+        let new: Settings = toml::from_str(
+            r#"
+            [server]
+            port = 5000
+            log_level = "debug"
+
+            [limits]
+            max_requests_per_ip_per_hour = 5
+            offline_queue_ttl_seconds = 30
+
+            [access]
+            token = "new"
+
+            [proof_of_work]
+            difficulty = 16
+            secret = "new"
+
+            [rooms]
+            enabled = false
+
+            [alias]
+            blocked_words = ["spam"]
+
+            [relay]
+            enabled = true
+
+            [allowlist]
+            networks = ["203.0.113.0/24"]
+            "#,
+        )
+        .unwrap();
+
+        let reloaded = current.reloaded(new);
+        assert_eq!(reloaded.server.port, 4000);
+        assert_eq!(reloaded.log_level().unwrap(), Level::DEBUG);
+        assert_eq!(reloaded.limits.max_requests_per_ip_per_hour, 5);
+        assert_eq!(reloaded.limits.offline_queue_ttl_seconds, 10);
+        assert_eq!(reloaded.access.token.as_deref(), Some("new"));
+        assert_eq!(reloaded.proof_of_work.difficulty, 16);
+        assert_eq!(reloaded.proof_of_work.secret, None);
+        assert!(!reloaded.rooms.enabled);
+        assert!(!reloaded.alias.is_allowed("Spammer"));
+        assert!(reloaded.relay.enabled);
+        assert_eq!(
+            reloaded.allowlist.networks,
+            [IpNet::from_str("203.0.113.0/24").unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_cors_validation() {
+        let config =
+            |cors: &str| -> Settings { toml::from_str(&format!("[cors]\n{cors}\n")).unwrap() };
+
+        assert!(config(r#"allowed_origins = ["https://web.localsend.org"]"#)
+            .validate()
+            .is_ok());
+        assert!(config(r#"allowed_origins = ["*"]"#).validate().is_ok());
+        assert!(
+            config(r#"allowed_origins = ["*", "https://web.localsend.org"]"#)
+                .validate()
+                .is_err()
+        );
+        assert!(config(r#"allowed_origins = ["web.localsend.org"]"#)
+            .validate()
+            .is_err());
+        assert!(config(r#"allowed_methods = ["GET", "NOT A METHOD"]"#)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_websocket_origins() {
+        let mut websocket = WebSocketSettings::default();
+        assert!(websocket.is_origin_allowed(Some("https://evil.example")));
+
+        websocket.allowed_origins = vec!["https://web.localsend.org".to_string()];
+        assert!(websocket.is_origin_allowed(None));
+        assert!(websocket.is_origin_allowed(Some("https://Web.LocalSend.org")));
+        assert!(!websocket.is_origin_allowed(Some("https://evil.example")));
+        assert!(!websocket.is_origin_allowed(Some("null")));
+    }
+
+    #[test]
+    fn test_federation_validation() {
+        let config = |nodes: &str| -> Settings {
+            toml::from_str(&format!("[federation]\nnodes = [{nodes}]\n")).unwrap()
+        };
+
+        let settings = config(r#"{ url = "https://a.example.com", token = "a" }"#);
+        assert!(settings.validate().is_ok());
+        assert_eq!(settings.federation.nodes[0].url, "https://a.example.com");
+
+        assert!(config(r#"{ url = "a.example.com", token = "a" }"#)
+            .validate()
+            .is_err());
+        assert!(config(r#"{ url = "https://a.example.com", token = "" }"#)
+            .validate()
+            .is_err());
+        assert!(config(
+            r#"{ url = "https://a.example.com", token = "a" }, { url = "https://b.example.com", token = "a" }"#
+        )
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn test_alias_rules() {
+        let rules = AliasSettings {
+            max_length: Some(5),
+            blocked_words: vec!["Admin".to_string()],
+            unique: true,
+        };
+
+        assert!(rules.is_allowed("Apple"));
+        assert!(!rules.is_allowed("Banana"));
+        assert!(!rules.is_allowed("admin"));
+
+        let taken = ["Galaxy S23", "Galaxy S23 (2)", "Pixel"];
+        assert_eq!(rules.make_unique("iPhone", taken.into_iter()), "iPhone");
+        assert_eq!(
+            rules.make_unique("Galaxy S23", taken.into_iter()),
+            "Galaxy S23 (3)"
+        );
+
+        let rules = AliasSettings {
+            unique: false,
+            ..rules
+        };
+        assert_eq!(rules.make_unique("Pixel", taken.into_iter()), "Pixel");
+    }
+}
@@ -1,26 +1,162 @@
-use localsend::webrtc::signaling::{ClientInfoWithoutId, WsServerMessage};
-use std::collections::HashMap;
+use crate::blocklist::Blocklist;
+use crate::config::metrics::Metrics;
+use crate::federation::Federation;
+use crate::offline_queue::OfflineQueue;
+use crate::proof_of_work::ProofOfWork;
+use crate::registry::Registry;
+use crate::stats::DailyStats;
+use crate::util::password::PasswordHash;
+use crate::util::rate_limit::RequestWindow;
+use localsend::webrtc::signaling::{ClientInfoWithoutId, SignalingProtocol, WsServerMessage};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::{mpsc, Mutex};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
 use uuid::Uuid;
 
-/// IP -> Peer ID -> PeerInfo + WebSocket message sender.
+/// Group (IP group or room) -> Peer ID -> PeerInfo + WebSocket message sender.
+/// Only contains the peers connected to this instance.
 pub type TxMap = Arc<Mutex<HashMap<String, HashMap<Uuid, ClientState>>>>;
 
 pub struct ClientState {
+    /// The info shown to other peers.
+    /// The alias may have a suffix to be unique in the group, see [`AliasSettings::unique`].
+    ///
+    /// [`AliasSettings::unique`]: crate::config::settings::AliasSettings::unique
     pub client: ClientInfoWithoutId,
+
+    /// The alias as chosen by the client.
+    pub alias: String,
+
+    /// The IP group the peer connected from, even if it is currently in a room.
+    pub ip_group: String,
+
+    /// The negotiated protocol version.
+    /// Messages introduced in newer versions are not sent to the peer.
+    pub protocol: SignalingProtocol,
+
+    /// The password of the room the peer is in.
+    /// Every member holds it, so joins can be verified as long as the room exists.
+    pub room_password: Option<PasswordHash>,
+
     pub tx: mpsc::Sender<WsServerMessage>,
+
+    /// Binary frames relayed to the peer, see [`RelaySettings`].
+    ///
+    /// [`RelaySettings`]: crate::config::settings::RelaySettings
+    pub relay_tx: mpsc::Sender<Vec<u8>>,
+
+    /// Peers this peer exchanged an offer and answer with via this instance.
+    /// Only these can relay frames to each other.
+    pub relay_peers: HashSet<Uuid>,
+
+    /// Target -> Session ID of the last offer this peer sent to the target via this instance.
+    /// Only an answer to this offer adds the target to [`Self::relay_peers`].
+    pub relay_offers: HashMap<Uuid, String>,
+
+    pub connected_since: SystemTime,
+
+    /// When the last frame (including pongs) has been received.
+    pub last_seen: Arc<std::sync::Mutex<Instant>>,
+
+    /// Cancelled to disconnect the peer.
+    pub kick: CancellationToken,
+}
+
+#[cfg(test)]
+impl ClientState {
+    /// A peer for tests. The receiver gets the messages sent to the peer.
+    pub fn mock(token: &str, ip_group: &str) -> (Self, mpsc::Receiver<WsServerMessage>) {
+        let (tx, rx) = mpsc::channel(4);
+        let state = Self {
+            alias: "Cute Apple".to_string(),
+            client: ClientInfoWithoutId {
+                alias: "Cute Apple".to_string(),
+                version: "2.3".to_string(),
+                device_model: None,
+                device_type: None,
+                token: token.to_string(),
+                capabilities: 0,
+            },
+            ip_group: ip_group.to_string(),
+            protocol: SignalingProtocol::V2,
+            room_password: None,
+            tx,
+            relay_tx: mpsc::channel(1).0,
+            relay_peers: HashSet::new(),
+            relay_offers: HashMap::new(),
+            connected_since: SystemTime::now(),
+            last_seen: Arc::new(std::sync::Mutex::new(Instant::now())),
+            kick: CancellationToken::new(),
+        };
+        (state, rx)
+    }
 }
 
-pub type IpRequestCountMap = Arc<Mutex<HashMap<String, u32>>>;
+/// IP group -> Requests in the sliding window.
+pub type IpRequestCountMap = Arc<Mutex<HashMap<String, RequestWindow>>>;
+
+/// IP group -> Open connections to this instance.
+pub type ConnectionCountMap = Arc<std::sync::Mutex<HashMap<String, usize>>>;
+
+/// Resume token -> Peer the token has been issued to.
+pub type ResumeMap = Arc<Mutex<HashMap<String, ResumeState>>>;
+
+pub struct ResumeState {
+    pub ip_group: String,
+    pub peer_id: Uuid,
+
+    /// When the token becomes invalid.
+    /// `None` while the peer is still connected.
+    pub expires_at: Option<Instant>,
+}
 
 #[derive(Clone)]
 pub struct AppState {
     /// Map of peer IDs to WebSocket message senders.
     pub tx_map: TxMap,
 
-    /// Map of IP addresses to the number of requests.
+    /// Map of IP groups to their requests in the sliding window.
     pub request_count_map: IpRequestCountMap,
+
+    /// Map of IP groups to their open connections.
+    pub connection_count_map: ConnectionCountMap,
+
+    /// Map of resume tokens to the peers they have been issued to.
+    pub resume_map: ResumeMap,
+
+    /// Banned fingerprints and IP groups.
+    pub blocklist: Arc<Blocklist>,
+
+    /// Counters exposed via `/metrics`.
+    pub metrics: Arc<Metrics>,
+
+    /// Daily aggregates exposed via `/stats`.
+    pub stats: Arc<DailyStats>,
+
+    /// Whether the listener is bound and accepting connections.
+    pub ready: Arc<AtomicBool>,
+
+    /// Shares peers with other instances.
+    pub registry: Registry,
+
+    /// Relays offers and answers to peers connected to other servers.
+    pub federation: Arc<Federation>,
+
+    /// Offers and answers for peers that are expected to resume their session.
+    pub offline_queue: Arc<OfflineQueue>,
+
+    /// Challenges clients must solve before connecting.
+    pub proof_of_work: Arc<ProofOfWork>,
+
+    /// Cancelled when the server is shutting down.
+    pub shutdown: CancellationToken,
+
+    /// Tasks of the connected peers. Awaited before the server exits.
+    pub socket_tasks: TaskTracker,
 }
 
 impl AppState {
@@ -28,6 +164,18 @@ impl AppState {
         Self {
             tx_map: Arc::new(Mutex::new(HashMap::new())),
             request_count_map: Arc::new(Mutex::new(HashMap::new())),
+            connection_count_map: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            resume_map: Arc::new(Mutex::new(HashMap::new())),
+            blocklist: Arc::new(Blocklist::open(None).expect("Error opening blocklist")),
+            metrics: Arc::new(Metrics::default()),
+            stats: Arc::new(DailyStats::open(None).expect("Error opening stats")),
+            ready: Arc::new(AtomicBool::new(false)),
+            registry: Registry::Memory,
+            federation: Arc::new(Federation::new(Vec::new())),
+            offline_queue: Arc::new(OfflineQueue::new(Duration::ZERO)),
+            proof_of_work: Arc::new(ProofOfWork::new(None)),
+            shutdown: CancellationToken::new(),
+            socket_tasks: TaskTracker::new(),
         }
     }
 }
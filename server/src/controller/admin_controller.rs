@@ -0,0 +1,286 @@
+use crate::blocklist::{self, Ban, BanKind};
+use crate::config::error::AppError;
+use crate::config::settings::SETTINGS;
+use crate::config::state::{AppState, TxMap};
+use crate::registry::{self, RelayMessage, ALL_GROUPS};
+use crate::util::auth::is_bearer_authorized;
+use axum::extract::{Path, Request, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::routing::{delete, get, post};
+use axum::{Json, Router};
+use localsend::webrtc::signaling::{AnnouncementKind, WsServerMessage};
+use serde::{Deserialize, Serialize};
+use std::time::UNIX_EPOCH;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupDto {
+    /// The IP group or `room:<code>`.
+    pub group: String,
+    pub peers: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerDto {
+    pub id: Uuid,
+    pub alias: String,
+    pub fingerprint: String,
+
+    /// The IP group the peer connected from, even if it is currently in a room.
+    pub ip_group: String,
+
+    /// Unix timestamp in seconds.
+    pub connected_since: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnouncementDto {
+    pub kind: AnnouncementKind,
+    pub message: String,
+}
+
+/// Maximum length of an announcement in bytes.
+const MAX_ANNOUNCEMENT_LENGTH: usize = 1024;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BanDto {
+    pub kind: BanKind,
+    pub value: String,
+
+    /// If not set, the ban is permanent.
+    pub duration_seconds: Option<u64>,
+}
+
+/// Routes to inspect and disconnect peers on public instances.
+/// Only the peers connected to this instance are visible.
+#[rustfmt::skip]
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/groups", get(list_groups))
+        .route("/groups/{group}/peers", get(list_peers))
+        .route("/groups/{group}/peers/{peer_id}", delete(kick_peer))
+        .route("/bans", get(list_bans).post(ban))
+        .route("/bans/{kind}/{value}", delete(unban))
+        .route("/announcements", post(announce))
+        .route_layer(middleware::from_fn(authorize))
+}
+
+/// The admin API is disabled unless a token is configured.
+async fn authorize(headers: HeaderMap, request: Request, next: Next) -> Result<Response, AppError> {
+    let authorized = match SETTINGS.load().admin.token.as_deref() {
+        Some(token) => is_bearer_authorized(&headers, token),
+        None => return Err(AppError::status(StatusCode::NOT_FOUND, None)),
+    };
+
+    if !authorized {
+        return Err(AppError::status(StatusCode::UNAUTHORIZED, None));
+    }
+
+    Ok(next.run(request).await)
+}
+
+async fn list_groups(State(state): State<AppState>) -> Json<Vec<GroupDto>> {
+    let tx_map = state.tx_map.lock().await;
+    let mut groups: Vec<GroupDto> = tx_map
+        .iter()
+        .map(|(group, peers)| GroupDto {
+            group: group.clone(),
+            peers: peers.len(),
+        })
+        .collect();
+
+    groups.sort_by(|a, b| a.group.cmp(&b.group));
+    Json(groups)
+}
+
+async fn list_peers(
+    State(state): State<AppState>,
+    Path(group): Path<String>,
+) -> Result<Json<Vec<PeerDto>>, AppError> {
+    let tx_map = state.tx_map.lock().await;
+    let Some(tx_local_map) = tx_map.get(&group) else {
+        return Err(AppError::status(StatusCode::NOT_FOUND, None));
+    };
+
+    let mut peers: Vec<PeerDto> = tx_local_map
+        .iter()
+        .map(|(id, state)| PeerDto {
+            id: *id,
+            alias: state.client.alias.clone(),
+            fingerprint: state.client.token.clone(),
+            ip_group: state.ip_group.clone(),
+            connected_since: state
+                .connected_since
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        })
+        .collect();
+
+    peers.sort_by_key(|p| p.connected_since);
+    Ok(Json(peers))
+}
+
+async fn kick_peer(
+    State(state): State<AppState>,
+    Path((group, peer_id)): Path<(String, Uuid)>,
+) -> StatusCode {
+    let tx_map = state.tx_map.lock().await;
+    let Some(peer_state) = tx_map.get(&group).and_then(|m| m.get(&peer_id)) else {
+        return StatusCode::NOT_FOUND;
+    };
+
+    tracing::info!(%group, %peer_id, "Kick");
+    peer_state.kick.cancel();
+    StatusCode::NO_CONTENT
+}
+
+async fn list_bans(State(state): State<AppState>) -> Result<Json<Vec<Ban>>, AppError> {
+    Ok(Json(state.blocklist.list()?))
+}
+
+/// Bans the fingerprint or IP group and disconnects all affected peers.
+async fn ban(
+    State(state): State<AppState>,
+    Json(payload): Json<BanDto>,
+) -> Result<StatusCode, AppError> {
+    let ban = Ban {
+        kind: payload.kind,
+        value: payload.value,
+        expires_at: payload
+            .duration_seconds
+            .map(|duration| blocklist::now() + duration),
+    };
+    state.blocklist.insert(&ban)?;
+
+    let kicked = kick_banned(&state.tx_map, &ban).await;
+    tracing::info!(kind = ?ban.kind, value = %ban.value, kicked, "Ban");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn unban(
+    State(state): State<AppState>,
+    Path((kind, value)): Path<(BanKind, String)>,
+) -> Result<StatusCode, AppError> {
+    if state.blocklist.remove(kind, &value)? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Ok(StatusCode::NOT_FOUND)
+    }
+}
+
+/// Sends the announcement to all peers, including those connected to other instances.
+async fn announce(
+    State(state): State<AppState>,
+    Json(payload): Json<AnnouncementDto>,
+) -> Result<StatusCode, AppError> {
+    if payload.message.is_empty() || payload.message.len() > MAX_ANNOUNCEMENT_LENGTH {
+        return Err(AppError::status(
+            StatusCode::BAD_REQUEST,
+            Some(format!(
+                "The message must have between 1 and {MAX_ANNOUNCEMENT_LENGTH} bytes"
+            )),
+        ));
+    }
+
+    let kind = payload.kind;
+    let relay_message = RelayMessage {
+        group: ALL_GROUPS.to_string(),
+        target: None,
+        exclude: None,
+        message: WsServerMessage::Announcement {
+            kind,
+            message: payload.message,
+        },
+    };
+
+    let peers = registry::deliver(&state.tx_map, relay_message.clone()).await;
+    state.registry.publish(relay_message).await;
+
+    tracing::info!(?kind, peers, "Announcement");
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Disconnects all peers affected by the ban.
+/// Returns the number of disconnected peers.
+async fn kick_banned(tx_map: &TxMap, ban: &Ban) -> usize {
+    let tx_map = tx_map.lock().await;
+    tx_map
+        .values()
+        .flat_map(|m| m.values())
+        .filter(|state| match ban.kind {
+            BanKind::Fingerprint => state.client.token == ban.value,
+            BanKind::IpGroup => state.ip_group == ban.value,
+        })
+        .inspect(|state| state.kick.cancel())
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::state::ClientState;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    fn ban(kind: BanKind, value: &str) -> Ban {
+        Ban {
+            kind,
+            value: value.to_string(),
+            expires_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_kick_banned() {
+        let (a, b, c, d) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        let tx_map: TxMap = Arc::new(Mutex::new(HashMap::from([
+            (
+                "1.2.3.4".to_string(),
+                HashMap::from([
+                    (a, ClientState::mock("bad", "1.2.3.4").0),
+                    (b, ClientState::mock("good", "1.2.3.4").0),
+                ]),
+            ),
+            (
+                "room:ABCDEF".to_string(),
+                HashMap::from([
+                    (c, ClientState::mock("bad", "5.6.7.8").0),
+                    (d, ClientState::mock("good", "5.6.7.8").0),
+                ]),
+            ),
+        ])));
+
+        assert_eq!(
+            kick_banned(&tx_map, &ban(BanKind::Fingerprint, "bad")).await,
+            2
+        );
+        {
+            let tx_map = tx_map.lock().await;
+            assert!(tx_map["1.2.3.4"][&a].kick.is_cancelled());
+            assert!(!tx_map["1.2.3.4"][&b].kick.is_cancelled());
+            assert!(tx_map["room:ABCDEF"][&c].kick.is_cancelled());
+            assert!(!tx_map["room:ABCDEF"][&d].kick.is_cancelled());
+        }
+
+        // Peers in rooms are matched by the IP group they connected from.
+        assert_eq!(
+            kick_banned(&tx_map, &ban(BanKind::IpGroup, "5.6.7.8")).await,
+            2
+        );
+        let tx_map = tx_map.lock().await;
+        assert!(!tx_map["1.2.3.4"][&b].kick.is_cancelled());
+        assert!(tx_map["room:ABCDEF"][&d].kick.is_cancelled());
+    }
+}
@@ -0,0 +1,163 @@
+use crate::config::error::AppError;
+use crate::config::settings::SETTINGS;
+use crate::config::state::{AppState, TxMap};
+use crate::federation::{LookupDto, RelayDto};
+use crate::registry::{self, RelayMessage, ALL_GROUPS};
+use crate::util::auth::is_bearer_authorized;
+use crate::util::sdp::MAX_ENCODED_SDP_LENGTH;
+use axum::extract::{DefaultBodyLimit, Request, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::Response;
+use axum::routing::post;
+use axum::{Extension, Json, Router};
+use localsend::webrtc::signaling::{ClientInfo, WsServerMessage};
+
+/// Relayed messages contain an SDP of up to `MAX_ENCODED_SDP_LENGTH` and the info of its origin.
+const MAX_RELAY_BODY_SIZE: usize = 2 * MAX_ENCODED_SDP_LENGTH;
+
+/// The federated server sending the request.
+/// Index in `federation.nodes`.
+#[derive(Clone, Copy)]
+struct FederatedNode(usize);
+
+/// Routes called by federated servers.
+#[rustfmt::skip]
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/lookup", post(lookup))
+        .route("/relay", post(relay).layer(DefaultBodyLimit::max(MAX_RELAY_BODY_SIZE)))
+        .route_layer(middleware::from_fn(authorize))
+}
+
+/// The token identifies the federated server.
+async fn authorize(
+    headers: HeaderMap,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let node = {
+        let settings = SETTINGS.load();
+        if settings.federation.nodes.is_empty() {
+            return Err(AppError::status(StatusCode::NOT_FOUND, None));
+        }
+
+        settings
+            .federation
+            .nodes
+            .iter()
+            .position(|node| is_bearer_authorized(&headers, &node.token))
+    };
+
+    let Some(node) = node else {
+        return Err(AppError::status(StatusCode::UNAUTHORIZED, None));
+    };
+
+    request.extensions_mut().insert(FederatedNode(node));
+    Ok(next.run(request).await)
+}
+
+/// Returns the peers connected to this instance with the fingerprint.
+/// The node may relay messages to them afterwards.
+async fn lookup(
+    State(state): State<AppState>,
+    Extension(FederatedNode(node)): Extension<FederatedNode>,
+    Json(payload): Json<LookupDto>,
+) -> Json<Vec<ClientInfo>> {
+    let peers = find_peers(&state.tx_map, &payload.fingerprint).await;
+    state
+        .federation
+        .expose(node, peers.iter().map(|peer| peer.id));
+    Json(peers)
+}
+
+/// Delivers an offer or answer of a peer of the federated server.
+async fn relay(
+    State(state): State<AppState>,
+    Extension(FederatedNode(node)): Extension<FederatedNode>,
+    Json(payload): Json<RelayDto>,
+) -> StatusCode {
+    let (WsServerMessage::Offer(sdp) | WsServerMessage::Answer(sdp)) = &payload.message else {
+        return StatusCode::BAD_REQUEST;
+    };
+
+    // Peers the node has not found or heard from are not reachable, whatever it claims.
+    // The answer to an offer takes the same way back.
+    if !state
+        .federation
+        .accept_relay(node, payload.target, &payload.message)
+    {
+        return StatusCode::NOT_FOUND;
+    }
+
+    let session_id = sdp.session_id.clone();
+    let message = RelayMessage {
+        group: ALL_GROUPS.to_string(),
+        target: Some(payload.target),
+        exclude: None,
+        message: payload.message,
+    };
+
+    // The target might be connected to another instance.
+    let delivered = registry::deliver(&state.tx_map, message.clone()).await > 0
+        || state.registry.publish(message).await;
+
+    tracing::debug!(%session_id, target = %payload.target, delivered, "Federated relay");
+
+    if delivered {
+        StatusCode::NO_CONTENT
+    } else {
+        StatusCode::NOT_FOUND
+    }
+}
+
+/// Peers of all groups with the fingerprint.
+async fn find_peers(tx_map: &TxMap, fingerprint: &str) -> Vec<ClientInfo> {
+    let tx_map = tx_map.lock().await;
+    tx_map
+        .values()
+        .flat_map(|m| m.iter())
+        .filter(|(_, state)| state.client.token == fingerprint)
+        .map(|(id, state)| ClientInfo::from(state.client.clone(), *id))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::state::ClientState;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn test_find_peers() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let tx_map: TxMap = Arc::new(Mutex::new(HashMap::from([
+            (
+                "1.2.3.4".to_string(),
+                HashMap::from([
+                    (a, ClientState::mock("laptop", "1.2.3.4").0),
+                    (b, ClientState::mock("phone", "1.2.3.4").0),
+                ]),
+            ),
+            (
+                "room:ABCDEF".to_string(),
+                HashMap::from([(c, ClientState::mock("phone", "5.6.7.8").0)]),
+            ),
+        ])));
+
+        let mut peers: Vec<Uuid> = find_peers(&tx_map, "phone")
+            .await
+            .into_iter()
+            .map(|peer| peer.id)
+            .collect();
+        peers.sort();
+        let mut expected = vec![b, c];
+        expected.sort();
+        assert_eq!(peers, expected);
+
+        assert!(find_peers(&tx_map, "tablet").await.is_empty());
+    }
+}
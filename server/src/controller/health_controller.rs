@@ -0,0 +1,45 @@
+use crate::config::state::AppState;
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::future::Future;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+/// How long a state map may stay locked before the server is considered unhealthy.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Liveness probe.
+/// Fails if one of the state maps cannot be locked, e.g. because of a deadlock.
+pub async fn health_handler(State(state): State<AppState>) -> (StatusCode, &'static str) {
+    match check_locks(&state).await {
+        Ok(()) => (StatusCode::OK, "ok"),
+        Err(message) => (StatusCode::SERVICE_UNAVAILABLE, message),
+    }
+}
+
+/// Readiness probe.
+/// Additionally fails until the listener is bound.
+pub async fn ready_handler(State(state): State<AppState>) -> (StatusCode, &'static str) {
+    if !state.ready.load(Ordering::Relaxed) {
+        return (StatusCode::SERVICE_UNAVAILABLE, "listener not ready");
+    }
+
+    health_handler(State(state)).await
+}
+
+async fn check_locks(state: &AppState) -> Result<(), &'static str> {
+    check_lock(state.tx_map.lock(), "tx map locked").await?;
+    check_lock(state.request_count_map.lock(), "request count map locked").await?;
+    check_lock(state.resume_map.lock(), "resume map locked").await?;
+    Ok(())
+}
+
+async fn check_lock<T>(
+    lock: impl Future<Output = T>,
+    message: &'static str,
+) -> Result<(), &'static str> {
+    tokio::time::timeout(LOCK_TIMEOUT, lock)
+        .await
+        .map(|_| ())
+        .map_err(|_| message)
+}
@@ -0,0 +1,36 @@
+use crate::config::error::AppError;
+use crate::config::settings::SETTINGS;
+use crate::config::state::AppState;
+use crate::util::auth::is_bearer_authorized;
+use axum::extract::State;
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+
+pub async fn metrics_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    // The metrics contain IP groups of connected peers.
+    if !is_authorized(&headers) {
+        return Err(AppError::status(StatusCode::UNAUTHORIZED, None));
+    }
+
+    let body = state.metrics.render(&state.tx_map).await;
+
+    Ok(([(header::CONTENT_TYPE, "text/plain; version=0.0.4")], body).into_response())
+}
+
+/// Whether the request presents `metrics.token`, or `access.token` if that is not set.
+/// Open to everyone if neither is set.
+pub(crate) fn is_authorized(headers: &HeaderMap) -> bool {
+    let settings = SETTINGS.load();
+    match settings
+        .metrics
+        .token
+        .as_ref()
+        .or(settings.access.token.as_ref())
+    {
+        Some(token) => is_bearer_authorized(headers, token),
+        None => true,
+    }
+}
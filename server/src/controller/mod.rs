@@ -1 +1,6 @@
+pub(crate) mod admin_controller;
+pub(crate) mod federation_controller;
+pub(crate) mod health_controller;
+pub(crate) mod metrics_controller;
+pub(crate) mod stats_controller;
 pub(crate) mod ws_controller;
@@ -0,0 +1,36 @@
+use crate::config::error::AppError;
+use crate::config::settings::SETTINGS;
+use crate::config::state::AppState;
+use crate::controller::metrics_controller;
+use crate::stats::DayStats;
+use axum::extract::{Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::Json;
+use serde::Deserialize;
+
+/// Number of days returned if the query does not specify it.
+const DEFAULT_DAYS: u64 = 30;
+
+#[derive(Deserialize)]
+pub struct StatsQuery {
+    days: Option<u64>,
+}
+
+/// Returns the daily aggregates, newest first.
+/// Protected like `/metrics`.
+pub async fn stats_handler(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<StatsQuery>,
+) -> Result<Json<Vec<DayStats>>, AppError> {
+    if !metrics_controller::is_authorized(&headers) {
+        return Err(AppError::status(StatusCode::UNAUTHORIZED, None));
+    }
+
+    // Includes the counts of the current minute.
+    let retention_days = SETTINGS.load().stats.retention_days;
+    state.stats.flush(&state.metrics, retention_days)?;
+
+    let days = query.days.unwrap_or(DEFAULT_DAYS).min(retention_days);
+    Ok(Json(state.stats.list(days)?))
+}
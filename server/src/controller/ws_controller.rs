@@ -1,127 +1,323 @@
 use crate::config::error::AppError;
-use crate::config::state::{AppState, ClientState, IpRequestCountMap, TxMap};
+use crate::config::metrics::Metrics;
+use crate::config::settings::{RelaySettings, SETTINGS};
+use crate::config::state::{
+    AppState, ClientState, IpRequestCountMap, ResumeMap, ResumeState, TxMap,
+};
+use crate::federation::Federation;
+use crate::offline_queue::OfflineQueue;
+use crate::registry::{Registry, RelayMessage};
 use crate::util;
-use crate::util::ip::get_ip_group;
+use crate::util::auth::is_bearer_authorized;
+use crate::util::connection_limit::ConnectionSlot;
+use crate::util::ip::{get_ip_group, ClientIp};
+use crate::util::password::{PasswordHash, MAX_PASSWORD_LENGTH};
+use crate::util::rate_limit::{ByteBucket, RequestWindow, REQUEST_WINDOW};
+use crate::util::sdp::{validate_sdp_message, MAX_ENCODED_SDP_LENGTH};
 use axum::body::Body;
-use axum::extract::ws::{Message, WebSocket};
-use axum::extract::{ConnectInfo, Query, State, WebSocketUpgrade};
-use axum::http::{HeaderMap, StatusCode};
+use axum::extract::{Query, State};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
 use axum::response::Response;
-use futures_util::stream::StreamExt;
+use futures_util::stream::{SplitSink, StreamExt};
 use futures_util::SinkExt;
+use localsend::util::token::is_token_equal;
 use localsend::webrtc::signaling::{
-    ClientInfo, ClientInfoWithoutId, WsClientMessage, WsClientSdpMessage, WsServerMessage,
-    WsServerSdpMessage,
+    capability, ClientInfo, ClientInfoWithoutId, SignalingErrorCode, SignalingProtocol,
+    WsClientMessage, WsClientSdpMessage, WsServerMessage, WsServerSdpMessage,
 };
+use localsend::webrtc::ws_relay;
 use serde::Deserialize;
-use std::collections::HashMap;
-use std::net::{IpAddr, SocketAddr};
-use std::str::FromStr;
-use std::sync::LazyLock;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::mpsc;
+use tokio::time::MissedTickBehavior;
+use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
 use uuid::Uuid;
+use yawc::close::CloseCode;
+use yawc::{Frame, HttpWebSocket, IncomingUpgrade, OpCode, Options, WebSocketError};
+
+/// Prefix of the group key of rooms to separate them from IP groups.
+const ROOM_GROUP_PREFIX: &str = "room:";
+
+/// Characters used in room codes. Ambiguous characters (0, O, 1, I) are left out.
+const ROOM_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+const ROOM_CODE_LENGTH: usize = 6;
+
+/// Maximum size of a received message. Larger messages close the connection.
+/// SDPs above `MAX_ENCODED_SDP_LENGTH` but below this limit are rejected with an error instead.
+const MAX_MESSAGE_SIZE: usize = 2 * MAX_ENCODED_SDP_LENGTH;
 
-static MAX_CONNECTIONS: LazyLock<usize> = LazyLock::new(|| {
-    std::env::var("MAX_CONNECTIONS_PER_IP")
-        .unwrap_or_else(|_| "10".to_string())
-        .parse::<usize>()
-        .unwrap()
-});
+/// Longer fingerprints are not sent to other servers.
+const MAX_FINGERPRINT_LENGTH: usize = 256;
 
-static MAX_REQUESTS: LazyLock<u32> = LazyLock::new(|| {
-    std::env::var("MAX_REQUESTS_PER_IP_PER_HOUR")
-        .unwrap_or_else(|_| "1000".to_string())
-        .parse::<u32>()
-        .unwrap()
-});
+/// Sent as `Retry-After` if the IP group has too many open connections.
+/// Connections are long-lived, so a slot is unlikely to be free much earlier.
+const TOO_MANY_CONNECTIONS_RETRY_AFTER: Duration = Duration::from_secs(60);
 
 #[derive(Deserialize)]
 pub struct WsQuery {
     /// `PeerRegisterDto` encoded as base64.
     pub d: String,
+
+    /// The resume token received in the hello of a previous connection.
+    pub r: Option<String>,
+
+    /// The access token of a private instance, for clients that cannot set the `Authorization` header.
+    pub t: Option<String>,
+
+    /// The solved proof-of-work challenge and its nonce.
+    pub c: Option<String>,
+    pub n: Option<String>,
 }
 
 pub async fn ws_handler(
     State(state): State<AppState>,
-    ws: WebSocketUpgrade,
+    ws: IncomingUpgrade,
     Query(payload): Query<WsQuery>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    ClientIp(ip): ClientIp,
     headers: HeaderMap,
 ) -> Result<Response<Body>, AppError> {
-    let peer_info = {
+    if state.shutdown.is_cancelled() {
+        return Err(AppError::status(StatusCode::SERVICE_UNAVAILABLE, None));
+    }
+
+    if !is_access_authorized(&headers, payload.t.as_deref()) {
+        return Err(AppError::status(StatusCode::UNAUTHORIZED, None));
+    }
+
+    // Browsers always send the origin, so other websites cannot make visitors connect.
+    let origin = headers
+        .get(header::ORIGIN)
+        .map(|v| v.to_str().unwrap_or_default());
+    if !SETTINGS.load().websocket.is_origin_allowed(origin) {
+        tracing::debug!(origin, "Rejected WebSocket origin");
+        return Err(AppError::status(StatusCode::FORBIDDEN, None));
+    }
+
+    let register_dto = {
         let base64_decoded: Vec<u8> = util::base64::decode(&payload.d)
             .map_err(|_| AppError::status(StatusCode::BAD_REQUEST, None))?;
 
         let base64_decoded: String = String::from_utf8(base64_decoded)
             .map_err(|_| AppError::status(StatusCode::BAD_REQUEST, None))?;
 
-        let register_dto = serde_json::from_str::<ClientInfoWithoutId>(&base64_decoded)
-            .map_err(|_| AppError::status(StatusCode::BAD_REQUEST, None))?;
+        serde_json::from_str::<ClientInfoWithoutId>(&base64_decoded)
+            .map_err(|_| AppError::status(StatusCode::BAD_REQUEST, None))?
+    };
+
+    if !SETTINGS.load().alias.is_allowed(&register_dto.alias) {
+        return Err(AppError::status(StatusCode::BAD_REQUEST, None));
+    }
 
-        ClientInfo::from(register_dto.clone(), Uuid::new_v4())
+    // Clients that do not offer a subprotocol speak the first version.
+    let offered: Vec<&str> = headers
+        .get_all(header::SEC_WEBSOCKET_PROTOCOL)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .collect();
+    let protocol = match SignalingProtocol::negotiate(offered.iter().copied()) {
+        Some(protocol) => protocol,
+        None if offered.is_empty() => SignalingProtocol::V1,
+        None => return Err(AppError::status(StatusCode::UPGRADE_REQUIRED, None)),
     };
 
-    let ip = {
-        // Prefer the forwarded IP if available.
-        let raw_forwarded = headers
-            .get("x-forwarded-for")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| v.split(',').last()) // Get the last component
-            .map(|v| v.trim().to_string())
-            .and_then(|v| IpAddr::from_str(&v).ok());
+    let ip_group = get_ip_group(
+        ip,
+        SETTINGS.load().ip_group.ipv4_prefix_length,
+        SETTINGS.load().ip_group.ipv6_prefix_length,
+    );
+
+    if state.blocklist.is_banned(&register_dto.token, &ip_group)? {
+        return Err(AppError::status(StatusCode::FORBIDDEN, None));
+    }
 
-        raw_forwarded.unwrap_or(addr.ip())
+    // Unsolved upgrades receive a challenge. Issuing it is cheap, so it does not count towards the rate limit.
+    let difficulty = SETTINGS.load().proof_of_work.difficulty;
+    if difficulty > 0 {
+        let solved = match (&payload.c, &payload.n) {
+            (Some(challenge), Some(nonce)) => {
+                state.proof_of_work.verify(challenge, nonce, difficulty)
+            }
+            _ => false,
+        };
+        if !solved {
+            Metrics::inc(&state.metrics.proof_of_work_challenges);
+            return Err(AppError::proof_of_work_required(
+                state.proof_of_work.issue(difficulty),
+            ));
+        }
+    }
+
+    // Checked before the upgrade, so that the client receives the Retry-After header.
+    let rate_limited = !SETTINGS.load().allowlist.contains(ip);
+    if rate_limited {
+        if let Err(e) = protect_ddos_request_count(&state.request_count_map, &ip_group).await {
+            Metrics::inc(&state.metrics.rate_limit_rejections);
+            return Err(e);
+        }
+    }
+
+    // Held until the connection is closed.
+    let Some(slot) = ConnectionSlot::try_acquire(
+        &state.connection_count_map,
+        &ip_group,
+        SETTINGS
+            .load()
+            .limits
+            .max_concurrent_connections_per_ip_group,
+    ) else {
+        Metrics::inc(&state.metrics.connection_limit_rejections);
+        return Err(AppError::too_many_connections(
+            TOO_MANY_CONNECTIONS_RETRY_AFTER,
+        ));
     };
 
-    Ok(ws.on_upgrade(move |socket| {
+    // Peer lists and the JSON envelopes compress well.
+    // permessage-deflate is only used if the client offers it.
+    let (mut response, upgrade) = ws
+        .upgrade(
+            Options::default()
+                .with_balanced_compression()
+                .with_utf8()
+                .with_limits(MAX_MESSAGE_SIZE, 2 * MAX_MESSAGE_SIZE),
+        )
+        .map_err(|_| AppError::status(StatusCode::BAD_REQUEST, None))?;
+
+    if !offered.is_empty() {
+        response.headers_mut().insert(
+            header::SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_static(protocol.subprotocol()),
+        );
+    }
+
+    // Child of the request span, so the logs of the connection also contain the request ID.
+    let span = tracing::info_span!(
+        "connection",
+        connection_id = %Uuid::new_v4(),
+        ip_group = %ip_group,
+        protocol = protocol.subprotocol(),
+        peer_id = tracing::field::Empty,
+    );
+
+    let socket_tasks = state.socket_tasks.clone();
+    let connection = async move {
+        let socket = match upgrade.await {
+            Ok(socket) => socket,
+            Err(e) => {
+                tracing::warn!(error = ?e, "Error upgrading connection");
+                return;
+            }
+        };
+
         handle_socket(
-            state.tx_map,
-            state.request_count_map,
+            state,
             socket,
-            get_ip_group(ip),
-            peer_info,
+            ip_group,
+            protocol,
+            register_dto,
+            payload.r,
+            rate_limited,
         )
-    }))
+        .await;
+        drop(slot);
+    };
+    tokio::spawn(socket_tasks.track_future(connection.instrument(span)));
+
+    Ok(response.map(Body::new))
 }
 
 /// The websocket context (one per connected device) is handled here.
+/// Peers on the allowlist are not `rate_limited`.
 async fn handle_socket(
-    tx_map: TxMap,
-    request_count_map: IpRequestCountMap,
-    socket: WebSocket,
+    state: AppState,
+    socket: HttpWebSocket,
     ip_group: String,
-    peer: ClientInfo,
+    protocol: SignalingProtocol,
+    mut info: ClientInfoWithoutId,
+    previous_resume_token: Option<String>,
+    rate_limited: bool,
 ) {
-    let peer_id = peer.id;
+    let AppState {
+        tx_map,
+        request_count_map,
+        resume_map,
+        metrics,
+        stats,
+        registry,
+        federation,
+        offline_queue,
+        shutdown,
+        ..
+    } = state;
+
     let (tx, mut rx) = mpsc::channel(4);
-    {
+    let (relay_tx, mut relay_rx) = mpsc::channel(4);
+    let kick = CancellationToken::new();
+    let last_seen = Arc::new(std::sync::Mutex::new(Instant::now()));
+    let (peer, resume_token, queued) = {
+        // Peers in the IP group connected to other instances.
+        let remote_peers = registry.remote_peers(&ip_group).await;
+
         // Tx of other peers in the IP group.
         let mut peers_tx: Vec<mpsc::Sender<WsServerMessage>> = Vec::new();
 
         // Peers in the IP group including the current user.
         let mut peers: Vec<ClientInfo> = Vec::new();
 
-        // If the limit of connections is reached.
+        // The peer, its resume token and the messages queued while it was disconnected.
+        // The error code if a limit is reached.
         // Used to break out of the lock as early as possible.
-        let mut limit_reached = false;
-
-        'lock: {
+        let session = 'lock: {
             let mut tx_map = tx_map.lock().await;
 
             let tx_local_map = tx_map.entry(ip_group.clone()).or_insert_with(HashMap::new);
-            if tx_local_map.len() >= *MAX_CONNECTIONS {
-                limit_reached = true;
-                break 'lock;
-            }
-
-            if protect_ddos_request_count(&request_count_map, &ip_group)
-                .await
-                .is_err()
+            if tx_local_map.len() + remote_peers.len()
+                >= SETTINGS.load().limits.max_connections_per_ip
             {
-                limit_reached = true;
-                break 'lock;
+                break 'lock Err(SignalingErrorCode::RoomFull);
             }
 
+            let (peer_id, resume_token, queued) = {
+                let mut resume_map = resume_map.lock().await;
+                let resumed_peer_id = previous_resume_token
+                    .and_then(|token| take_resumable_peer_id(&mut resume_map, &ip_group, &token));
+
+                let peer_id = resumed_peer_id.unwrap_or_else(Uuid::new_v4);
+                tracing::Span::current().record("peer_id", tracing::field::display(peer_id));
+
+                let queued = match resumed_peer_id {
+                    Some(peer_id) => {
+                        tracing::info!("Resume");
+                        offline_queue.take(peer_id)
+                    }
+                    None => Vec::new(),
+                };
+                let resume_token = Uuid::new_v4().simple().to_string();
+                resume_map.insert(
+                    resume_token.clone(),
+                    ResumeState {
+                        ip_group: ip_group.clone(),
+                        peer_id,
+                        expires_at: None,
+                    },
+                );
+
+                (peer_id, resume_token, queued)
+            };
+            let alias = std::mem::take(&mut info.alias);
+            info.alias = SETTINGS.load().alias.make_unique(
+                &alias,
+                tx_local_map
+                    .values()
+                    .map(|p| p.client.alias.as_str())
+                    .chain(remote_peers.iter().map(|p| p.alias.as_str())),
+            );
+            let peer = ClientInfo::from(info, peer_id);
+
             peers_tx = tx_local_map.values().map(|p| p.tx.clone()).collect();
 
             peers = tx_local_map
@@ -133,21 +329,40 @@ async fn handle_socket(
                 peer_id,
                 ClientState {
                     client: ClientInfoWithoutId::from(peer.clone()),
+                    alias,
+                    ip_group: ip_group.clone(),
+                    protocol,
+                    room_password: None,
                     tx: tx.clone(),
+                    relay_tx,
+                    relay_peers: HashSet::new(),
+                    relay_offers: HashMap::new(),
+                    connected_since: SystemTime::now(),
+                    last_seen: last_seen.clone(),
+                    kick: kick.clone(),
                 },
             );
 
-            let debug_active_connections = tx_map.len();
-            let debug_total_active_connections: usize = tx_map.values().map(|m| m.len()).sum();
-            tracing::info!("Connect: {ip_group} / {peer_id} (active: {debug_active_connections}, total active: {debug_total_active_connections})");
-        }
+            tracing::info!(
+                active_groups = tx_map.len(),
+                active_peers = tx_map.values().map(|m| m.len()).sum::<usize>(),
+                "Connect"
+            );
 
-        if limit_reached {
-            let (mut sender, _) = socket.split();
-            let _ = sender.send(Message::Text("Limit reached".into())).await;
-            let _ = sender.close().await;
-            return;
-        }
+            Ok((peer, resume_token, queued))
+        };
+
+        let (peer, resume_token, queued) = match session {
+            Ok(session) => session,
+            Err(code) => {
+                let (mut sender, _) = socket.split();
+                let message = serde_json::to_string(&WsServerMessage::from(code)).unwrap();
+                let _ = sender.send(Frame::text(message)).await;
+                let _ = sender.close().await;
+                return;
+            }
+        };
+        stats.record_connection(&peer.token);
 
         for peer_tx in peers_tx {
             let _ = peer_tx
@@ -155,106 +370,325 @@ async fn handle_socket(
                 .await;
         }
 
+        registry.add_peer(&ip_group, &peer, None).await;
+        registry
+            .publish(RelayMessage {
+                group: ip_group.clone(),
+                target: None,
+                exclude: Some(peer.id),
+                message: WsServerMessage::Join { peer: peer.clone() },
+            })
+            .await;
+        peers.extend(remote_peers);
+
         let _ = tx
             .send(WsServerMessage::Hello {
                 client: peer.clone(),
                 peers,
+                resume_token: Some(resume_token.clone()),
+                room: None,
             })
             .await;
-    }
+
+        (peer, resume_token, queued)
+    };
+    let peer_id = peer.id;
+
+    // The group the peer is currently in (its IP group or a room).
+    let group = Arc::new(std::sync::Mutex::new(ip_group.clone()));
 
     let (mut sender, mut receiver) = socket.split();
 
-    let mut send_task = tokio::spawn(async move {
-        while let Some(msg) = rx.recv().await {
-            let serialized = serde_json::to_string(&msg).unwrap();
-            drop(msg);
+    let metrics_clone = metrics.clone();
+    let counters = metrics.group_counters(&ip_group);
+    let counters_clone = counters.clone();
+    let idle_timeout = Duration::from_secs(SETTINGS.load().limits.idle_timeout_seconds);
+    let mut ping = tokio::time::interval(idle_timeout / 3);
+    ping.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    ping.reset();
+
+    let last_seen_clone = last_seen.clone();
+    let send_future = async move {
+        loop {
+            let msg = tokio::select! {
+                msg = rx.recv() => msg,
+                _ = ping.tick() => {
+                    if last_seen_clone.lock().unwrap().elapsed() > idle_timeout {
+                        tracing::info!("Idle timeout");
+                        let _ = sender.send(Frame::close(CloseCode::Away, "Idle timeout")).await;
+                        return;
+                    }
+
+                    if sender.send(Frame::ping("")).await.is_err() {
+                        Metrics::inc(&metrics_clone.websocket_errors);
+                        return;
+                    }
+                    continue;
+                }
+                _ = kick.cancelled() => {
+                    let _ =
+                        send_message(&mut sender, &SignalingErrorCode::Forbidden.into()).await;
+                    let _ = sender
+                        .send(Frame::close(
+                            CloseCode::Policy,
+                            "Disconnected by the operator",
+                        ))
+                        .await;
+                    tracing::info!("Kicked");
+                    return;
+                }
+                Some(frame) = relay_rx.recv() => {
+                    if sender.send(Frame::binary(frame)).await.is_err() {
+                        Metrics::inc(&metrics_clone.websocket_errors);
+                        return;
+                    }
+                    continue;
+                }
+                _ = shutdown.cancelled() => break,
+            };
+
+            let Some(msg) = msg else {
+                return;
+            };
+
+            if let WsServerMessage::Offer(sdp) | WsServerMessage::Answer(sdp) = &msg {
+                tracing::debug!(
+                    session_id = %sdp.session_id,
+                    origin = %sdp.peer.id,
+                    "Deliver SDP"
+                );
+            }
+
+            match send_message(&mut sender, &msg).await {
+                Ok(bytes) => counters_clone.record_sent(&msg, bytes),
+                Err(_) => {
+                    Metrics::inc(&metrics_clone.websocket_errors);
+                    return;
+                }
+            }
+        }
 
-            if sender.send(Message::Text(serialized.into())).await.is_err() {
-                break;
+        // The server is shutting down.
+        // Flush the messages already relayed to this peer, then ask it to reconnect.
+        rx.close();
+        while let Some(msg) = rx.recv().await {
+            if send_message(&mut sender, &msg).await.is_err() {
+                return;
             }
         }
-    });
+
+        let _ = send_message(&mut sender, &SignalingErrorCode::ServerShutdown.into()).await;
+        let _ = sender
+            .send(Frame::close(CloseCode::Away, "Server shutting down"))
+            .await;
+    };
+    let mut send_task = tokio::spawn(send_future.in_current_span());
+
+    // Messages for the peer while it was disconnected.
+    // Sent once the send task is running because they might exceed the channel capacity.
+    if !queued.is_empty() {
+        tracing::debug!(count = queued.len(), "Deliver queued messages");
+        for message in queued {
+            let _ = tx.send(message).await;
+        }
+    }
 
     let tx_map_clone = tx_map.clone();
     let ip_group_clone = ip_group.clone();
-    let mut recv_task = tokio::spawn(async move {
-        while let Some(Ok(msg)) = receiver.next().await {
-            if let Message::Text(text) = msg {
-                if let Ok(msg) = serde_json::from_str::<WsClientMessage>(&text) {
-                    if protect_ddos_request_count(&request_count_map, &ip_group_clone)
+    let group_clone = group.clone();
+    let resume_token_clone = resume_token.clone();
+    let metrics_clone = metrics.clone();
+    let registry_clone = registry.clone();
+    let offline_queue_clone = offline_queue.clone();
+    let mut relay_bucket =
+        ByteBucket::new(Instant::now(), SETTINGS.load().relay.max_bytes_per_second);
+    let recv_future = async move {
+        // The stream ends on errors and close frames. Pings are answered automatically.
+        while let Some(frame) = receiver.next().await {
+            *last_seen.lock().unwrap() = Instant::now();
+
+            if frame.opcode() == OpCode::Text {
+                if rate_limited
+                    && protect_ddos_request_count(&request_count_map, &ip_group_clone)
                         .await
                         .is_err()
-                    {
-                        let _ = tx
-                            .send(WsServerMessage::Error {
-                                code: StatusCode::TOO_MANY_REQUESTS.as_u16(),
-                            })
-                            .await;
-                        return;
+                {
+                    Metrics::inc(&metrics_clone.rate_limit_rejections);
+                    let _ = tx.send(SignalingErrorCode::RateLimited.into()).await;
+                    return;
+                }
+
+                let Ok(msg) = serde_json::from_str::<WsClientMessage>(frame.as_str()) else {
+                    let _ = tx.send(SignalingErrorCode::InvalidMessage.into()).await;
+                    continue;
+                };
+                counters.record_received(&msg, frame.as_str().len());
+
+                if let WsClientMessage::Offer(sdp) | WsClientMessage::Answer(sdp) = &msg {
+                    if let Err(code) = validate_sdp_message(sdp) {
+                        let _ = tx.send(code.into()).await;
+                        continue;
                     }
+                }
 
-                    match msg {
-                        WsClientMessage::Update { info } => {
-                            send_update_to_other_peers_with_lock(
-                                &tx_map_clone,
-                                &ip_group_clone,
-                                peer_id,
-                                info,
-                            )
-                            .await
-                        }
-                        WsClientMessage::Offer(sdp) => {
-                            send_to_peer_with_lock(
-                                &tx_map_clone,
-                                &ip_group_clone,
-                                peer.clone(),
-                                WsClientSdpMessageWrapper::Offer(sdp),
-                            )
-                            .await
+                let current_group = group_clone.lock().unwrap().clone();
+
+                let room_target = match msg {
+                    WsClientMessage::Update { info } => {
+                        if !SETTINGS.load().alias.is_allowed(&info.alias) {
+                            let _ = tx.send(SignalingErrorCode::InvalidMessage.into()).await;
+                            continue;
                         }
-                        WsClientMessage::Answer(sdp) => {
-                            send_to_peer_with_lock(
-                                &tx_map_clone,
-                                &ip_group_clone,
-                                peer.clone(),
-                                WsClientSdpMessageWrapper::Answer(sdp),
-                            )
-                            .await
+
+                        send_update_to_other_peers_with_lock(
+                            &tx_map_clone,
+                            &registry_clone,
+                            &current_group,
+                            peer_id,
+                            info,
+                        )
+                        .await;
+                        continue;
+                    }
+                    WsClientMessage::Offer(sdp) => {
+                        send_to_peer_with_lock(
+                            &tx_map_clone,
+                            &registry_clone,
+                            &federation,
+                            &offline_queue_clone,
+                            &current_group,
+                            peer.clone(),
+                            WsClientSdpMessageWrapper::Offer(sdp),
+                            &metrics_clone,
+                        )
+                        .await;
+                        continue;
+                    }
+                    WsClientMessage::Answer(sdp) => {
+                        send_to_peer_with_lock(
+                            &tx_map_clone,
+                            &registry_clone,
+                            &federation,
+                            &offline_queue_clone,
+                            &current_group,
+                            peer.clone(),
+                            WsClientSdpMessageWrapper::Answer(sdp),
+                            &metrics_clone,
+                        )
+                        .await;
+                        continue;
+                    }
+                    WsClientMessage::Lookup { fingerprint } => {
+                        if !federation.is_enabled() || fingerprint.len() > MAX_FINGERPRINT_LENGTH {
+                            let _ = tx.send(SignalingErrorCode::InvalidMessage.into()).await;
+                            continue;
                         }
+
+                        // Do not block other messages while waiting for the other servers.
+                        let federation = federation.clone();
+                        let tx = tx.clone();
+                        tokio::spawn(
+                            async move {
+                                for peer in federation.lookup(&fingerprint).await {
+                                    let _ = tx.send(WsServerMessage::Join { peer }).await;
+                                }
+                            }
+                            .in_current_span(),
+                        );
+                        continue;
+                    }
+                    WsClientMessage::CreateRoom { password } => RoomTarget::Create { password },
+                    WsClientMessage::JoinRoom { code, password } => {
+                        RoomTarget::Join { code, password }
+                    }
+                    WsClientMessage::LeaveRoom => RoomTarget::Leave,
+                };
+
+                let result = move_peer_with_lock(
+                    &tx_map_clone,
+                    &registry_clone,
+                    &group_clone,
+                    &ip_group_clone,
+                    peer_id,
+                    room_target,
+                )
+                .await;
+
+                let message = match result {
+                    Ok((client, peers, room)) => WsServerMessage::Hello {
+                        client,
+                        peers,
+                        resume_token: Some(resume_token_clone.clone()),
+                        room,
+                    },
+                    Err(code) => code.into(),
+                };
+
+                let _ = tx.send(message).await;
+            } else if frame.opcode() == OpCode::Binary {
+                let current_group = group_clone.lock().unwrap().clone();
+                let settings = SETTINGS.load().relay.clone();
+                let result = relay_frame_with_lock(
+                    &tx_map_clone,
+                    &current_group,
+                    peer_id,
+                    frame.payload(),
+                    &settings,
+                    &mut relay_bucket,
+                )
+                .await;
+
+                match result {
+                    Ok(true) => {
+                        Metrics::inc(&metrics_clone.relayed_frames);
+                        metrics_clone
+                            .relayed_bytes
+                            .fetch_add(frame.payload().len() as u64, Ordering::Relaxed);
+                    }
+                    Ok(false) => (),
+                    Err(code) => {
+                        let _ = tx.send(code.into()).await;
                     }
                 }
             }
         }
-    });
+    };
+    let mut recv_task = tokio::spawn(recv_future.in_current_span());
 
     // If any one of the tasks exit, abort the other.
     tokio::select! {
         rv_a = &mut send_task => {
             match rv_a {
                 Ok(_) => (),
-                Err(e) => tracing::warn!("Error sending messages {e:?}")
+                Err(e) => {
+                    Metrics::inc(&metrics.websocket_errors);
+                    tracing::warn!(error = ?e, "Error sending messages")
+                }
             }
             recv_task.abort();
         },
         rv_b = &mut recv_task => {
             match rv_b {
                 Ok(_) => (),
-                Err(e) => tracing::warn!("Error receiving messages {e:?}")
+                Err(e) => {
+                    Metrics::inc(&metrics.websocket_errors);
+                    tracing::warn!(error = ?e, "Error receiving messages")
+                }
             }
             send_task.abort();
         }
     }
 
     let mut remaining_tx: Vec<mpsc::Sender<WsServerMessage>> = Vec::new();
+    let group = group.lock().unwrap().clone();
 
     {
         let mut tx_map = tx_map.lock().await;
-        let final_active_connections = match tx_map.get_mut(&ip_group) {
+        let final_active_connections = match tx_map.get_mut(&group) {
             Some(tx_local_map) => {
                 tx_local_map.remove(&peer_id);
                 if tx_local_map.is_empty() {
-                    tx_map.remove(&ip_group);
+                    tx_map.remove(&group);
                     0
                 } else {
                     remaining_tx = tx_local_map.values().map(|p| p.tx.clone()).collect();
@@ -264,38 +698,344 @@ async fn handle_socket(
             None => 0,
         };
 
-        tracing::info!("Disconnect: {peer_id} (active: {final_active_connections})");
+        // Keep the peer ID reserved for a while so that the client can resume the session.
+        if let Some(state) = resume_map.lock().await.get_mut(&resume_token) {
+            let expires_at = Instant::now()
+                + Duration::from_secs(SETTINGS.load().limits.resume_token_ttl_seconds);
+            state.expires_at = Some(expires_at);
+            offline_queue.open(peer_id, &group, expires_at);
+        }
+
+        tracing::info!(group_peers = final_active_connections, "Disconnect");
     }
 
     for tx in remaining_tx {
         let _ = tx.send(WsServerMessage::Left { peer_id }).await;
     }
+
+    registry.remove_peer(&group, peer_id).await;
+    registry
+        .publish(RelayMessage {
+            group,
+            target: None,
+            exclude: None,
+            message: WsServerMessage::Left { peer_id },
+        })
+        .await;
+}
+
+/// Returns the size of the serialized message.
+async fn send_message(
+    sender: &mut SplitSink<HttpWebSocket, Frame>,
+    message: &WsServerMessage,
+) -> Result<usize, WebSocketError> {
+    let serialized = serde_json::to_string(message).unwrap();
+    let bytes = serialized.len();
+    sender.send(Frame::text(serialized)).await?;
+    Ok(bytes)
+}
+
+/// Removes peers whose connection is gone or that have not been seen for `ttl`, as well as empty groups.
+/// Connections normally clean up after themselves (idle ones are closed after the idle timeout),
+/// so this only removes state that would otherwise show up as ghost peers in hello lists.
+///
+/// Returns the number of removed peers.
+pub async fn remove_stale_peers(tx_map: &TxMap, registry: &Registry, ttl: Duration) -> usize {
+    // Group -> removed peers and tx of the remaining peers.
+    let mut removed: Vec<(String, Vec<Uuid>, Vec<mpsc::Sender<WsServerMessage>>)> = Vec::new();
+    {
+        let mut tx_map = tx_map.lock().await;
+        for (group, tx_local_map) in tx_map.iter_mut() {
+            let stale: Vec<Uuid> = tx_local_map
+                .iter()
+                .filter(|(_, state)| {
+                    state.tx.is_closed() || state.last_seen.lock().unwrap().elapsed() > ttl
+                })
+                .map(|(k, _)| *k)
+                .collect();
+
+            if stale.is_empty() {
+                continue;
+            }
+
+            for peer_id in &stale {
+                tx_local_map.remove(peer_id);
+            }
+            let remaining_tx = tx_local_map.values().map(|p| p.tx.clone()).collect();
+            removed.push((group.clone(), stale, remaining_tx));
+        }
+
+        tx_map.retain(|_, tx_local_map| !tx_local_map.is_empty());
+    }
+
+    let mut count = 0;
+    for (group, peer_ids, remaining_tx) in removed {
+        for peer_id in peer_ids {
+            tracing::info!(%group, %peer_id, "Remove stale peer");
+            count += 1;
+
+            for tx in &remaining_tx {
+                let _ = tx.send(WsServerMessage::Left { peer_id }).await;
+            }
+
+            registry.remove_peer(&group, peer_id).await;
+            registry
+                .publish(RelayMessage {
+                    group: group.clone(),
+                    target: None,
+                    exclude: None,
+                    message: WsServerMessage::Left { peer_id },
+                })
+                .await;
+        }
+    }
+
+    count
+}
+
+enum RoomTarget {
+    Create {
+        password: Option<String>,
+    },
+    Join {
+        code: String,
+        password: Option<String>,
+    },
+    Leave,
+}
+
+/// Moves the peer from its current group into a room or back into its IP group.
+/// The members of both groups are notified.
+///
+/// Returns the peer, the members of the new group and the room code.
+async fn move_peer_with_lock(
+    tx_map: &TxMap,
+    registry: &Registry,
+    group: &std::sync::Mutex<String>,
+    ip_group: &str,
+    peer_id: Uuid,
+    target: RoomTarget,
+) -> Result<(ClientInfo, Vec<ClientInfo>, Option<String>), SignalingErrorCode> {
+    if !SETTINGS.load().rooms.enabled {
+        return Err(SignalingErrorCode::InvalidMessage);
+    }
+
+    // Tx of the remaining peers in the old group.
+    let old_peers_tx: Vec<mpsc::Sender<WsServerMessage>>;
+
+    // Tx of the other peers in the new group.
+    let new_peers_tx: Vec<mpsc::Sender<WsServerMessage>>;
+
+    // Peers in the new group excluding the current user.
+    let mut peers: Vec<ClientInfo>;
+
+    let client: ClientInfo;
+    let old_group: String;
+    let room_password: Option<PasswordHash>;
+
+    let must_exist = matches!(target, RoomTarget::Join { .. });
+    let (new_group, room, password) = match target {
+        RoomTarget::Create { password } => {
+            let code = loop {
+                let code = generate_room_code();
+                let new_group = room_group(&code);
+                if !tx_map.lock().await.contains_key(&new_group)
+                    && registry.remote_peers(&new_group).await.is_empty()
+                {
+                    break code;
+                }
+            };
+            (room_group(&code), Some(code), password)
+        }
+        RoomTarget::Join { code, password } => {
+            let code = code.to_uppercase();
+            (room_group(&code), Some(code), password)
+        }
+        RoomTarget::Leave => (ip_group.to_string(), None, None),
+    };
+
+    if password
+        .as_ref()
+        .is_some_and(|p| p.len() > MAX_PASSWORD_LENGTH)
+    {
+        return Err(SignalingErrorCode::InvalidMessage);
+    }
+
+    // Peers in the new group connected to other instances.
+    let remote_peers = registry.remote_peers(&new_group).await;
+    let remote_password = if must_exist {
+        registry.remote_room_password(&new_group).await
+    } else {
+        None
+    };
+    {
+        let mut tx_map = tx_map.lock().await;
+
+        if must_exist && !tx_map.contains_key(&new_group) && remote_peers.is_empty() {
+            return Err(SignalingErrorCode::RoomNotFound);
+        }
+
+        // The joining peer takes over the password of the room from its members.
+        room_password = if must_exist {
+            let room_password = match tx_map.get(&new_group).and_then(|m| m.values().next()) {
+                Some(member) => member.room_password.clone(),
+                None => remote_password,
+            };
+            if let Some(hash) = &room_password {
+                if !password.as_deref().is_some_and(|p| hash.verify(p)) {
+                    return Err(SignalingErrorCode::WrongPassword);
+                }
+            }
+            room_password
+        } else {
+            password.as_deref().map(PasswordHash::new)
+        };
+
+        let mut group = group.lock().unwrap();
+        if *group == new_group {
+            return Err(SignalingErrorCode::InvalidMessage);
+        }
+
+        let local_count = tx_map.get(&new_group).map_or(0, |m| m.len());
+        if local_count + remote_peers.len() >= SETTINGS.load().limits.max_connections_per_ip {
+            return Err(SignalingErrorCode::RoomFull);
+        }
+
+        let Some(old_local_map) = tx_map.get_mut(&*group) else {
+            return Err(SignalingErrorCode::InvalidMessage);
+        };
+        let Some(mut state) = old_local_map.remove(&peer_id) else {
+            return Err(SignalingErrorCode::InvalidMessage);
+        };
+        state.room_password = room_password.clone();
+        old_peers_tx = old_local_map.values().map(|p| p.tx.clone()).collect();
+        if old_local_map.is_empty() {
+            tx_map.remove(&*group);
+        }
+
+        let new_local_map = tx_map.entry(new_group.clone()).or_insert_with(HashMap::new);
+        new_peers_tx = new_local_map.values().map(|p| p.tx.clone()).collect();
+        peers = new_local_map
+            .iter()
+            .map(|(k, v)| ClientInfo::from(v.client.clone(), *k))
+            .collect();
+        state.client.alias = SETTINGS.load().alias.make_unique(
+            &state.alias,
+            peers
+                .iter()
+                .chain(remote_peers.iter())
+                .map(|p| p.alias.as_str()),
+        );
+        client = ClientInfo::from(state.client.clone(), peer_id);
+        new_local_map.insert(peer_id, state);
+
+        tracing::info!(from = %*group, to = %new_group, "Move");
+        old_group = std::mem::replace(&mut *group, new_group.clone());
+    }
+
+    for tx in old_peers_tx {
+        let _ = tx.send(WsServerMessage::Left { peer_id }).await;
+    }
+
+    for tx in new_peers_tx {
+        let _ = tx
+            .send(WsServerMessage::Join {
+                peer: client.clone(),
+            })
+            .await;
+    }
+
+    registry.remove_peer(&old_group, peer_id).await;
+    registry
+        .add_peer(&new_group, &client, room_password.as_ref())
+        .await;
+    registry
+        .publish(RelayMessage {
+            group: old_group,
+            target: None,
+            exclude: None,
+            message: WsServerMessage::Left { peer_id },
+        })
+        .await;
+    registry
+        .publish(RelayMessage {
+            group: new_group,
+            target: None,
+            exclude: Some(peer_id),
+            message: WsServerMessage::Join {
+                peer: client.clone(),
+            },
+        })
+        .await;
+    peers.extend(remote_peers);
+
+    Ok((client, peers, room))
+}
+
+fn room_group(code: &str) -> String {
+    format!("{ROOM_GROUP_PREFIX}{code}")
+}
+
+fn generate_room_code() -> String {
+    // The alphabet has 32 characters, so every byte maps uniformly.
+    Uuid::new_v4().as_bytes()[..ROOM_CODE_LENGTH]
+        .iter()
+        .map(|b| ROOM_CODE_ALPHABET[*b as usize % ROOM_CODE_ALPHABET.len()] as char)
+        .collect()
 }
 
 async fn send_update_to_other_peers_with_lock(
     tx_map: &TxMap,
+    registry: &Registry,
     ip_group: &str,
     peer_id: Uuid,
-    info: ClientInfoWithoutId,
+    mut info: ClientInfoWithoutId,
 ) {
+    // Peers in the group connected to other instances, whose aliases are taken as well.
+    let remote_peers = if SETTINGS.load().alias.unique {
+        registry.remote_peers(ip_group).await
+    } else {
+        Vec::new()
+    };
+
     // Tx of other peers in the IP group.
-    let mut peers_tx: Vec<mpsc::Sender<WsServerMessage>> = Vec::new();
-    let response_info = ClientInfo::from(info.clone(), peer_id);
+    // `None` if the peer is not in the group.
+    let mut peers_tx: Option<Vec<mpsc::Sender<WsServerMessage>>> = None;
+    let mut room_password: Option<PasswordHash> = None;
+    let alias = std::mem::take(&mut info.alias);
     {
         let mut tx_map = tx_map.lock().await;
         if let Some(tx_local_map) = tx_map.get_mut(ip_group) {
-            if let Some(peer_state) = tx_local_map.get_mut(&peer_id) {
-                peer_state.client = info;
-
-                peers_tx = tx_local_map
+            info.alias = SETTINGS.load().alias.make_unique(
+                &alias,
+                tx_local_map
                     .iter()
                     .filter(|(k, _)| *k != &peer_id)
-                    .map(|(_, v)| v.tx.clone())
-                    .collect();
+                    .map(|(_, v)| v.client.alias.as_str())
+                    .chain(remote_peers.iter().map(|p| p.alias.as_str())),
+            );
+
+            if let Some(peer_state) = tx_local_map.get_mut(&peer_id) {
+                peer_state.client = info.clone();
+                peer_state.alias = alias;
+                room_password = peer_state.room_password.clone();
+
+                peers_tx = Some(
+                    tx_local_map
+                        .iter()
+                        .filter(|(k, _)| *k != &peer_id)
+                        .map(|(_, v)| v.tx.clone())
+                        .collect(),
+                );
             }
         }
     }
 
+    let Some(peers_tx) = peers_tx else {
+        return;
+    };
+    let response_info = ClientInfo::from(info, peer_id);
+
     for peer_tx in peers_tx {
         let _ = peer_tx
             .send(WsServerMessage::Update {
@@ -303,6 +1043,20 @@ async fn send_update_to_other_peers_with_lock(
             })
             .await;
     }
+
+    registry
+        .add_peer(ip_group, &response_info, room_password.as_ref())
+        .await;
+    registry
+        .publish(RelayMessage {
+            group: ip_group.to_string(),
+            target: None,
+            exclude: Some(peer_id),
+            message: WsServerMessage::Update {
+                peer: response_info,
+            },
+        })
+        .await;
 }
 
 enum WsClientSdpMessageWrapper {
@@ -310,60 +1064,478 @@ enum WsClientSdpMessageWrapper {
     Answer(WsClientSdpMessage),
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn send_to_peer_with_lock(
     tx_map: &TxMap,
+    registry: &Registry,
+    federation: &Federation,
+    offline_queue: &OfflineQueue,
     ip_group: &str,
     origin_peer: ClientInfo,
     message: WsClientSdpMessageWrapper,
+    metrics: &Metrics,
 ) {
-    let target = match &message {
-        WsClientSdpMessageWrapper::Offer(inner) => inner.target,
-        WsClientSdpMessageWrapper::Answer(inner) => inner.target,
+    let (target, session_id, relayed_counter) = match &message {
+        WsClientSdpMessageWrapper::Offer(inner) => (
+            inner.target,
+            inner.session_id.clone(),
+            &metrics.offers_relayed,
+        ),
+        WsClientSdpMessageWrapper::Answer(inner) => (
+            inner.target,
+            inner.session_id.clone(),
+            &metrics.answers_relayed,
+        ),
     };
 
     let mut target_peer_tx: Option<mpsc::Sender<WsServerMessage>> = None;
     {
-        let tx_map = tx_map.lock().await;
-        if let Some(tx_local_map) = tx_map.get(ip_group) {
-            if let Some(peer_state) = tx_local_map.get(&target) {
+        let mut tx_map = tx_map.lock().await;
+        if let Some(tx_local_map) = tx_map.get_mut(ip_group) {
+            if let Some(peer_state) = tx_local_map.get_mut(&target) {
                 target_peer_tx = Some(peer_state.tx.clone());
+
+                let relay = origin_peer.supports(capability::WS_RELAY)
+                    && peer_state.client.capabilities & capability::WS_RELAY != 0;
+                match &message {
+                    WsClientSdpMessageWrapper::Offer(_) if relay => {
+                        if let Some(origin_state) = tx_local_map.get_mut(&origin_peer.id) {
+                            origin_state.relay_offers.insert(target, session_id.clone());
+                        }
+                    }
+                    // Both agreed to a session, so they may fall back to relaying frames.
+                    // An answer to an offer the target has not sent does not count.
+                    WsClientSdpMessageWrapper::Answer(_)
+                        if relay
+                            && peer_state.relay_offers.get(&origin_peer.id)
+                                == Some(&session_id) =>
+                    {
+                        peer_state.relay_offers.remove(&origin_peer.id);
+                        peer_state.relay_peers.insert(origin_peer.id);
+                        if let Some(origin_state) = tx_local_map.get_mut(&origin_peer.id) {
+                            origin_state.relay_peers.insert(target);
+                        }
+                    }
+                    _ => {}
+                }
             }
         }
     }
 
-    if let Some(tx) = target_peer_tx {
-        let server_message = match message {
-            WsClientSdpMessageWrapper::Offer(inner) => {
-                let sdp_message = WsServerSdpMessage {
-                    peer: origin_peer,
-                    session_id: inner.session_id,
-                    sdp: inner.sdp,
-                };
-                WsServerMessage::Offer(sdp_message)
-            }
-            WsClientSdpMessageWrapper::Answer(inner) => {
-                let sdp_message = WsServerSdpMessage {
-                    peer: origin_peer,
-                    session_id: inner.session_id,
-                    sdp: inner.sdp,
-                };
-                WsServerMessage::Answer(sdp_message)
+    let server_message = match message {
+        WsClientSdpMessageWrapper::Offer(inner) => {
+            let sdp_message = WsServerSdpMessage {
+                peer: origin_peer,
+                session_id: inner.session_id,
+                sdp: inner.sdp,
+            };
+            WsServerMessage::Offer(sdp_message)
+        }
+        WsClientSdpMessageWrapper::Answer(inner) => {
+            let sdp_message = WsServerSdpMessage {
+                peer: origin_peer,
+                session_id: inner.session_id,
+                sdp: inner.sdp,
+            };
+            WsServerMessage::Answer(sdp_message)
+        }
+    };
+
+    // The target might have disconnected briefly and resume its session.
+    if target_peer_tx.is_none() && offline_queue.push(target, ip_group, &server_message) {
+        tracing::debug!(%session_id, %target, "Queue SDP");
+        return;
+    }
+
+    // The target might be connected to another instance.
+    let local = target_peer_tx.is_some();
+    let relayed = match target_peer_tx {
+        Some(tx) => tx.send(server_message).await.is_ok(),
+        None => {
+            federation.relay(target, server_message.clone()).await
+                || registry
+                    .publish(RelayMessage {
+                        group: ip_group.to_string(),
+                        target: Some(target),
+                        exclude: None,
+                        message: server_message,
+                    })
+                    .await
+        }
+    };
+
+    // Logged in the span of the origin. The target logs the delivery with the same session ID.
+    tracing::debug!(%session_id, %target, local, relayed, "Relay SDP");
+
+    if relayed {
+        Metrics::inc(relayed_counter);
+    }
+}
+
+/// Relays a binary frame to a peer in the same group, see [`ws_relay`].
+/// Waits if the origin exceeds its rate.
+/// Returns `false` if the target disconnected in the meantime.
+async fn relay_frame_with_lock(
+    tx_map: &TxMap,
+    group: &str,
+    origin: Uuid,
+    frame: &[u8],
+    settings: &RelaySettings,
+    bucket: &mut ByteBucket,
+) -> Result<bool, SignalingErrorCode> {
+    if !settings.enabled {
+        return Err(SignalingErrorCode::InvalidMessage);
+    }
+    if frame.len() > settings.max_frame_size {
+        return Err(SignalingErrorCode::PayloadTooLarge);
+    }
+    let Some((target, payload)) = ws_relay::decode_frame(frame) else {
+        return Err(SignalingErrorCode::InvalidMessage);
+    };
+
+    let delay = bucket.acquire(Instant::now(), frame.len(), settings.max_bytes_per_second);
+    if !delay.is_zero() {
+        tokio::time::sleep(delay).await;
+    }
+
+    let target_tx = {
+        let tx_map = tx_map.lock().await;
+        let peers = tx_map.get(group);
+        let origin_state = peers.and_then(|peers| peers.get(&origin));
+        let target_state = peers.and_then(|peers| peers.get(&target));
+        match (origin_state, target_state) {
+            (Some(origin_state), Some(target_state))
+                if origin_state.relay_peers.contains(&target) =>
+            {
+                target_state.relay_tx.clone()
             }
-        };
+            _ => return Err(SignalingErrorCode::InvalidMessage),
+        }
+    };
 
-        let _ = tx.send(server_message).await;
+    Ok(target_tx
+        .send(ws_relay::encode_frame(origin, payload))
+        .await
+        .is_ok())
+}
+
+/// Whether the client presented the access token of a private instance (if one is set),
+/// either in the `Authorization` header or in the query parameter `t`.
+fn is_access_authorized(headers: &HeaderMap, query_token: Option<&str>) -> bool {
+    match SETTINGS.load().access.token.as_deref() {
+        Some(token) => {
+            is_bearer_authorized(headers, token)
+                || query_token.is_some_and(|query_token| is_token_equal(query_token, token))
+        }
+        None => true,
+    }
+}
+
+/// Consumes the resume token and returns the peer ID it has been issued to.
+/// Returns `None` if the token is unknown, expired, still in use or has been issued to another IP group.
+fn take_resumable_peer_id(
+    resume_map: &mut HashMap<String, ResumeState>,
+    ip_group: &str,
+    token: &str,
+) -> Option<Uuid> {
+    retain_unexpired(resume_map, Instant::now());
+
+    match resume_map.get(token) {
+        Some(state) if state.expires_at.is_some() && state.ip_group == ip_group => {
+            resume_map.remove(token).map(|state| state.peer_id)
+        }
+        _ => None,
     }
 }
 
+/// Removes the resume tokens of peers that did not reconnect in time.
+///
+/// Returns the number of removed tokens.
+pub async fn remove_expired_resume_tokens(resume_map: &ResumeMap) -> usize {
+    let mut resume_map = resume_map.lock().await;
+    let before = resume_map.len();
+    retain_unexpired(&mut resume_map, Instant::now());
+    before - resume_map.len()
+}
+
+fn retain_unexpired(resume_map: &mut HashMap<String, ResumeState>, now: Instant) {
+    resume_map.retain(|_, state| state.expires_at.is_none_or(|expires_at| expires_at > now));
+}
+
 async fn protect_ddos_request_count(
     request_count_map: &IpRequestCountMap,
     ip_group: &str,
 ) -> Result<(), AppError> {
+    let now = Instant::now();
     let mut request_count_map = request_count_map.lock().await;
-    let count = request_count_map.entry(ip_group.to_string()).or_insert(0);
-    if *count >= *MAX_REQUESTS {
-        return Err(AppError::status(StatusCode::TOO_MANY_REQUESTS, None));
+    let window = request_count_map
+        .entry(ip_group.to_string())
+        .or_insert_with(|| RequestWindow::new(now));
+    window
+        .try_acquire(
+            now,
+            SETTINGS.load().limits.max_requests_per_ip_per_hour,
+            REQUEST_WINDOW,
+        )
+        .map_err(AppError::rate_limited)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_remove_stale_peers() {
+        let (a, b, c) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let (state_a, mut rx_a) = ClientState::mock("a", "1.2.3.4");
+        let (state_b, rx_b) = ClientState::mock("b", "1.2.3.4");
+        let (state_c, rx_c) = ClientState::mock("c", "5.6.7.8");
+
+        // b stopped responding, the connection of c ended without cleanup.
+        *state_b.last_seen.lock().unwrap() = Instant::now() - Duration::from_secs(120);
+        drop(rx_c);
+
+        let tx_map: TxMap = Arc::new(Mutex::new(HashMap::from([
+            (
+                "1.2.3.4".to_string(),
+                HashMap::from([(a, state_a), (b, state_b)]),
+            ),
+            ("5.6.7.8".to_string(), HashMap::from([(c, state_c)])),
+        ])));
+
+        let removed = remove_stale_peers(&tx_map, &Registry::Memory, Duration::from_secs(60)).await;
+        assert_eq!(removed, 2);
+
+        {
+            let tx_map = tx_map.lock().await;
+            assert_eq!(tx_map.len(), 1);
+            assert!(tx_map["1.2.3.4"].contains_key(&a));
+        }
+        assert_eq!(
+            rx_a.try_recv().unwrap(),
+            WsServerMessage::Left { peer_id: b }
+        );
+        drop(rx_b);
+
+        assert_eq!(
+            remove_stale_peers(&tx_map, &Registry::Memory, Duration::from_secs(60)).await,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remove_expired_resume_tokens() {
+        let now = Instant::now();
+        let state = |expires_at| ResumeState {
+            ip_group: "1.2.3.4".to_string(),
+            peer_id: Uuid::new_v4(),
+            expires_at,
+        };
+        let resume_map: ResumeMap = Arc::new(Mutex::new(HashMap::from([
+            ("connected".to_string(), state(None)),
+            (
+                "expired".to_string(),
+                state(Some(now - Duration::from_secs(1))),
+            ),
+            (
+                "valid".to_string(),
+                state(Some(now + Duration::from_secs(60))),
+            ),
+        ])));
+
+        assert_eq!(remove_expired_resume_tokens(&resume_map).await, 1);
+
+        let mut resume_map = resume_map.lock().await;
+        assert!(!resume_map.contains_key("expired"));
+        assert_eq!(
+            take_resumable_peer_id(&mut resume_map, "1.2.3.4", "connected"),
+            None
+        );
+        assert_eq!(
+            take_resumable_peer_id(&mut resume_map, "5.6.7.8", "valid"),
+            None
+        );
+        assert!(take_resumable_peer_id(&mut resume_map, "1.2.3.4", "valid").is_some());
+        assert!(resume_map.contains_key("connected"));
+    }
+
+    #[tokio::test]
+    async fn test_room_password() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let (state_a, _rx_a) = ClientState::mock("a", "1.2.3.4");
+        let (state_b, _rx_b) = ClientState::mock("b", "1.2.3.4");
+        let tx_map: TxMap = Arc::new(Mutex::new(HashMap::from([(
+            "1.2.3.4".to_string(),
+            HashMap::from([(a, state_a), (b, state_b)]),
+        )])));
+        let group_a = std::sync::Mutex::new("1.2.3.4".to_string());
+        let group_b = std::sync::Mutex::new("1.2.3.4".to_string());
+
+        let (_, _, room) = move_peer_with_lock(
+            &tx_map,
+            &Registry::Memory,
+            &group_a,
+            "1.2.3.4",
+            a,
+            RoomTarget::Create {
+                password: Some("secret".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+        let code = room.unwrap();
+
+        for password in [None, Some("wrong".to_string())] {
+            let result = move_peer_with_lock(
+                &tx_map,
+                &Registry::Memory,
+                &group_b,
+                "1.2.3.4",
+                b,
+                RoomTarget::Join {
+                    code: code.clone(),
+                    password,
+                },
+            )
+            .await;
+            assert_eq!(result.unwrap_err(), SignalingErrorCode::WrongPassword);
+        }
+
+        let (client, peers, _) = move_peer_with_lock(
+            &tx_map,
+            &Registry::Memory,
+            &group_b,
+            "1.2.3.4",
+            b,
+            RoomTarget::Join {
+                code: code.to_lowercase(),
+                password: Some("secret".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(peers.len(), 1);
+        assert_eq!(peers[0].id, a);
+
+        // Both peers use the same alias.
+        assert_eq!(client.alias, "Cute Apple (2)");
+
+        // The joined peer holds the password, so the room stays protected after the creator left.
+        let tx_map = tx_map.lock().await;
+        assert!(tx_map[&room_group(&code)][&b]
+            .room_password
+            .as_ref()
+            .is_some_and(|hash| hash.verify("secret")));
+    }
+
+    #[tokio::test]
+    async fn test_relay_pairing() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let (mut state_a, _rx_a) = ClientState::mock("a", "1.2.3.4");
+        let (mut state_b, _rx_b) = ClientState::mock("b", "1.2.3.4");
+        state_a.client.capabilities = capability::WS_RELAY;
+        state_b.client.capabilities = capability::WS_RELAY;
+        let (info_a, info_b) = (
+            ClientInfo::from(state_a.client.clone(), a),
+            ClientInfo::from(state_b.client.clone(), b),
+        );
+        let tx_map: TxMap = Arc::new(Mutex::new(HashMap::from([(
+            "1.2.3.4".to_string(),
+            HashMap::from([(a, state_a), (b, state_b)]),
+        )])));
+
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let federation = Federation::new(Vec::new());
+        let offline_queue = OfflineQueue::new(Duration::ZERO);
+        let metrics = Metrics::default();
+        let sdp = |session_id: &str, target: Uuid| WsClientSdpMessage {
+            session_id: session_id.to_string(),
+            target,
+            sdp: String::new(),
+        };
+        let send = |origin: &ClientInfo, message: WsClientSdpMessageWrapper| {
+            send_to_peer_with_lock(
+                &tx_map,
+                &Registry::Memory,
+                &federation,
+                &offline_queue,
+                "1.2.3.4",
+                origin.clone(),
+                message,
+                &metrics,
+            )
+        };
+
+        // An unsolicited answer does not pair the peers.
+        send(&info_b, WsClientSdpMessageWrapper::Answer(sdp("s1", a))).await;
+        assert!(tx_map.lock().await["1.2.3.4"][&a].relay_peers.is_empty());
+
+        // Neither does an answer to another session.
+        send(&info_a, WsClientSdpMessageWrapper::Offer(sdp("s1", b))).await;
+        send(&info_b, WsClientSdpMessageWrapper::Answer(sdp("s2", a))).await;
+        assert!(tx_map.lock().await["1.2.3.4"][&a].relay_peers.is_empty());
+
+        send(&info_b, WsClientSdpMessageWrapper::Answer(sdp("s1", a))).await;
+        let tx_map = tx_map.lock().await;
+        assert!(tx_map["1.2.3.4"][&a].relay_peers.contains(&b));
+        assert!(tx_map["1.2.3.4"][&b].relay_peers.contains(&a));
+    }
+
+    #[tokio::test]
+    async fn test_relay_frame() {
+        let (a, b) = (Uuid::new_v4(), Uuid::new_v4());
+        let (mut state_a, _rx_a) = ClientState::mock("a", "1.2.3.4");
+        let (mut state_b, _rx_b) = ClientState::mock("b", "1.2.3.4");
+        let (relay_tx, mut relay_rx) = mpsc::channel(1);
+        state_b.relay_tx = relay_tx;
+        state_a.relay_peers.insert(b);
+        let tx_map: TxMap = Arc::new(Mutex::new(HashMap::from([(
+            "1.2.3.4".to_string(),
+            HashMap::from([(a, state_a), (b, state_b)]),
+        )])));
+
+        let settings = RelaySettings {
+            enabled: true,
+            ..RelaySettings::default()
+        };
+        let mut bucket = ByteBucket::new(Instant::now(), settings.max_bytes_per_second);
+
+        let frame = ws_relay::encode_frame(b, b"hello");
+        assert_eq!(
+            relay_frame_with_lock(&tx_map, "1.2.3.4", a, &frame, &settings, &mut bucket).await,
+            Ok(true)
+        );
+        assert_eq!(
+            ws_relay::decode_frame(&relay_rx.recv().await.unwrap()),
+            Some((a, &b"hello"[..]))
+        );
+
+        // b did not exchange an answer with a via this instance.
+        let frame = ws_relay::encode_frame(a, b"hello");
+        assert_eq!(
+            relay_frame_with_lock(&tx_map, "1.2.3.4", b, &frame, &settings, &mut bucket).await,
+            Err(SignalingErrorCode::InvalidMessage)
+        );
+
+        let frame = ws_relay::encode_frame(b, &vec![0; settings.max_frame_size]);
+        assert_eq!(
+            relay_frame_with_lock(&tx_map, "1.2.3.4", a, &frame, &settings, &mut bucket).await,
+            Err(SignalingErrorCode::PayloadTooLarge)
+        );
+
+        let frame = ws_relay::encode_frame(b, b"hello");
+        assert_eq!(
+            relay_frame_with_lock(
+                &tx_map,
+                "1.2.3.4",
+                a,
+                &frame,
+                &RelaySettings::default(),
+                &mut bucket
+            )
+            .await,
+            Err(SignalingErrorCode::InvalidMessage)
+        );
     }
-    *count += 1;
-    Ok(())
 }
@@ -0,0 +1,300 @@
+use crate::config::settings::FederatedNodeSettings;
+use futures_util::future::join_all;
+use localsend::webrtc::signaling::{ClientInfo, WsServerMessage};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// How long the route to a peer of another server is kept after its last use.
+pub const ROUTE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Timeout of the requests to other servers.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LookupDto {
+    pub fingerprint: String,
+}
+
+/// An offer or answer for a peer connected to the receiving server.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayDto {
+    pub target: Uuid,
+    pub message: WsServerMessage,
+}
+
+/// Links this server with the servers configured in `federation.nodes`.
+/// Peers of other servers are only found by their fingerprint, so peer lists are never shared.
+///
+/// The peer IDs in relayed messages are chosen by the other servers, so they are not trusted:
+/// routes are only learned from lookups, and relayed messages are only delivered to peers
+/// the sending node has found by a lookup or received a message from.
+pub struct Federation {
+    client: reqwest::Client,
+    nodes: Vec<FederatedNodeSettings>,
+
+    /// Peer ID -> Route to the server the peer is connected to, learned from lookups.
+    routes: Mutex<HashMap<Uuid, Route>>,
+
+    /// (Index in `nodes`, local peer ID) -> Last use.
+    /// The local peers a node may relay messages to.
+    exposed: Mutex<HashMap<(usize, Uuid), Instant>>,
+
+    /// (Local peer ID, peer ID of another server) -> Route of the offer the local peer received.
+    /// Lets the local peer answer peers it could not look up.
+    replies: Mutex<HashMap<(Uuid, Uuid), Route>>,
+}
+
+struct Route {
+    /// Index in `nodes`.
+    node: usize,
+    last_used: Instant,
+}
+
+impl Federation {
+    pub fn new(nodes: Vec<FederatedNodeSettings>) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(REQUEST_TIMEOUT)
+                .build()
+                .expect("Error creating HTTP client"),
+            nodes,
+            routes: Mutex::new(HashMap::new()),
+            exposed: Mutex::new(HashMap::new()),
+            replies: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.nodes.is_empty()
+    }
+
+    /// Asks all nodes for their peers with the fingerprint.
+    /// Unreachable nodes are skipped.
+    pub async fn lookup(&self, fingerprint: &str) -> Vec<ClientInfo> {
+        let requests = self.nodes.iter().enumerate().map(|(i, node)| async move {
+            let result = async {
+                self.client
+                    .post(format!("{}/federation/lookup", node.url))
+                    .bearer_auth(&node.token)
+                    .json(&LookupDto {
+                        fingerprint: fingerprint.to_string(),
+                    })
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<Vec<ClientInfo>>()
+                    .await
+            }
+            .await;
+
+            match result {
+                Ok(peers) => peers.into_iter().map(|peer| (i, peer)).collect(),
+                Err(e) => {
+                    tracing::warn!(node = %node.url, error = ?e, "Error looking up peers");
+                    Vec::new()
+                }
+            }
+        });
+
+        join_all(requests)
+            .await
+            .into_iter()
+            .flatten()
+            .map(|(node, peer)| {
+                self.learn_route(peer.id, node);
+                peer
+            })
+            .collect()
+    }
+
+    /// Remembers the node a peer found by a lookup is connected to.
+    /// `node` is the index in `federation.nodes`.
+    fn learn_route(&self, peer_id: Uuid, node: usize) {
+        self.routes.lock().unwrap().insert(
+            peer_id,
+            Route {
+                node,
+                last_used: Instant::now(),
+            },
+        );
+    }
+
+    /// Allows the node to relay messages to the local peers,
+    /// e.g. because they were returned by its lookup.
+    pub fn expose(&self, node: usize, peer_ids: impl IntoIterator<Item = Uuid>) {
+        let now = Instant::now();
+        let mut exposed = self.exposed.lock().unwrap();
+        for peer_id in peer_ids {
+            exposed.insert((node, peer_id), now);
+        }
+    }
+
+    /// Checks a message relayed by the node before it is delivered to the local target.
+    /// Returns `false` if the node has not found the target by a lookup
+    /// or received a message from it.
+    ///
+    /// The target of an offer may answer its origin via the node.
+    pub fn accept_relay(&self, node: usize, target: Uuid, message: &WsServerMessage) -> bool {
+        let now = Instant::now();
+        match self.exposed.lock().unwrap().get_mut(&(node, target)) {
+            Some(last_used) => *last_used = now,
+            None => return false,
+        }
+
+        if let WsServerMessage::Offer(sdp) = message {
+            self.replies.lock().unwrap().insert(
+                (target, sdp.peer.id),
+                Route {
+                    node,
+                    last_used: now,
+                },
+            );
+        }
+        true
+    }
+
+    /// Sends the message to the server the target is connected to.
+    /// Returns `false` if the target is not a known peer of another server or the request failed.
+    pub async fn relay(&self, target: Uuid, message: WsServerMessage) -> bool {
+        let origin = match &message {
+            WsServerMessage::Offer(sdp) | WsServerMessage::Answer(sdp) => sdp.peer.id,
+            _ => return false,
+        };
+        let Some(index) = self.find_route(origin, target) else {
+            return false;
+        };
+        let node = &self.nodes[index];
+
+        let result = self
+            .client
+            .post(format!("{}/federation/relay", node.url))
+            .bearer_auth(&node.token)
+            .json(&RelayDto { target, message })
+            .send()
+            .await
+            .and_then(|response| response.error_for_status());
+
+        match result {
+            Ok(_) => {
+                // The target may respond.
+                self.expose(index, [origin]);
+                true
+            }
+            Err(e) => {
+                tracing::warn!(node = %node.url, %target, error = ?e, "Error relaying message");
+                false
+            }
+        }
+    }
+
+    /// Returns the index of the node the target is connected to,
+    /// either found by a lookup or the node of an offer the origin received from the target.
+    fn find_route(&self, origin: Uuid, target: Uuid) -> Option<usize> {
+        let now = Instant::now();
+        let mut routes = self.routes.lock().unwrap();
+        let mut replies = self.replies.lock().unwrap();
+        let route = match routes.get_mut(&target) {
+            Some(route) => route,
+            None => replies.get_mut(&(origin, target))?,
+        };
+        route.last_used = now;
+        Some(route.node)
+    }
+
+    /// Removes the routes and exposed peers that have not been used within the TTL.
+    /// Returns the number of removed routes.
+    pub fn remove_stale_routes(&self, ttl: Duration) -> usize {
+        let now = Instant::now();
+        self.exposed
+            .lock()
+            .unwrap()
+            .retain(|_, last_used| now.duration_since(*last_used) < ttl);
+
+        let mut routes = self.routes.lock().unwrap();
+        let mut replies = self.replies.lock().unwrap();
+        let before = routes.len() + replies.len();
+        routes.retain(|_, route| now.duration_since(route.last_used) < ttl);
+        replies.retain(|_, route| now.duration_since(route.last_used) < ttl);
+        before - routes.len() - replies.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::state::ClientState;
+    use localsend::webrtc::signaling::WsServerSdpMessage;
+
+    fn federation() -> Federation {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        Federation::new(vec![
+            FederatedNodeSettings {
+                url: "http://127.0.0.1:1".to_string(),
+                token: "a".to_string(),
+            },
+            FederatedNodeSettings {
+                url: "http://127.0.0.1:2".to_string(),
+                token: "b".to_string(),
+            },
+        ])
+    }
+
+    fn offer(origin: Uuid) -> WsServerMessage {
+        WsServerMessage::Offer(WsServerSdpMessage {
+            peer: ClientInfo::from(ClientState::mock("laptop", "1.2.3.4").0.client, origin),
+            session_id: "session".to_string(),
+            sdp: "sdp".to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_routes() {
+        let federation = federation();
+        assert!(federation.is_enabled());
+
+        let origin = Uuid::new_v4();
+
+        // Unknown peers are not relayed.
+        let peer_id = Uuid::new_v4();
+        assert!(!federation.relay(peer_id, offer(origin)).await);
+
+        // The node is unreachable.
+        federation.learn_route(peer_id, 1);
+        assert!(!federation.relay(peer_id, offer(origin)).await);
+
+        // Only offers and answers are relayed.
+        let left = WsServerMessage::Left { peer_id: origin };
+        assert!(!federation.relay(peer_id, left).await);
+
+        assert_eq!(federation.remove_stale_routes(ROUTE_TTL), 0);
+        assert_eq!(federation.remove_stale_routes(Duration::ZERO), 1);
+    }
+
+    #[test]
+    fn test_accept_relay() {
+        let federation = federation();
+        let (local, remote) = (Uuid::new_v4(), Uuid::new_v4());
+
+        // Only peers found by a lookup of the node can be reached.
+        assert!(!federation.accept_relay(0, local, &offer(remote)));
+        federation.expose(0, [local]);
+        assert!(!federation.accept_relay(1, local, &offer(remote)));
+        assert!(federation.accept_relay(0, local, &offer(remote)));
+
+        // The offer lets the local peer answer, but no one else.
+        assert_eq!(federation.find_route(local, remote), Some(0));
+        assert_eq!(federation.find_route(Uuid::new_v4(), remote), None);
+
+        // Routes learned from lookups take precedence over the claims of other nodes.
+        federation.learn_route(remote, 1);
+        assert_eq!(federation.find_route(local, remote), Some(1));
+
+        assert_eq!(federation.remove_stale_routes(Duration::ZERO), 2);
+        assert!(!federation.accept_relay(0, local, &offer(remote)));
+    }
+}
@@ -1,33 +1,207 @@
-use crate::controller::ws_controller;
+use crate::config::settings::SETTINGS;
+use crate::controller::{
+    admin_controller, federation_controller, health_controller, metrics_controller,
+    stats_controller, ws_controller,
+};
+use axum::extract::connect_info::IntoMakeServiceWithConnectInfo;
+use axum::extract::DefaultBodyLimit;
+use axum::middleware;
 use axum::routing::get;
 use axum::Router;
+use axum_server::accept::DefaultAcceptor;
+use axum_server::tls_rustls::{RustlsAcceptor, RustlsConfig};
+use axum_server::{Handle, Server};
+use futures_util::future::join_all;
 use std::net::SocketAddr;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use util::ip::PeerAddr;
+use util::proxy_protocol::ProxyProtocolAcceptor;
 
+mod blocklist;
 mod config;
 mod controller;
+mod federation;
+mod offline_queue;
+mod proof_of_work;
+mod registry;
+mod stats;
 mod util;
 
+/// Maximum size of request bodies unless the route sets its own limit.
+/// Larger bodies are rejected with 413 before they are buffered.
+const MAX_BODY_SIZE: usize = 16 * 1024;
+
+/// How long to wait for the WebSocket connections to close on shutdown.
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
 #[tokio::main]
 async fn main() {
     let app_state = config::init::init().await;
+    let settings = SETTINGS.load_full();
+
+    let ready = app_state.ready.clone();
+    let shutdown = shutdown_signal(app_state.clone());
+
+    let router = configure_routes().with_state(app_state.clone());
+
+    #[cfg(unix)]
+    if let Some(path) = &settings.server.unix_socket {
+        let listener = util::unix_socket::bind(path).expect("Error binding unix socket");
+        tracing::info!(path = %path.display(), "Listening via unix socket");
+
+        // TLS is terminated by the reverse proxy. Stops accepting when the server shuts down.
+        let app = router
+            .clone()
+            .into_make_service_with_connect_info::<PeerAddr>();
+        let shutdown = app_state.shutdown.clone();
+        tokio::spawn(async move {
+            axum::serve(listener, app)
+                .with_graceful_shutdown(shutdown.cancelled_owned())
+                .await
+                .unwrap();
+        });
+    }
 
-    let app = configure_routes()
-        .with_state(app_state)
-        .into_make_service_with_connect_info::<SocketAddr>();
+    let app = router.into_make_service_with_connect_info::<PeerAddr>();
+
+    let listeners = settings.listeners().expect("Error loading config");
+    let mut servers = Vec::new();
+    let mut handles = Vec::new();
+    for settings in &listeners {
+        // An IPv6 listener next to an IPv4 listener on the same port only accepts IPv6.
+        let only_v6 = listeners.iter().any(|other| {
+            other.address.is_ipv4() && other.address.port() == settings.address.port()
+        });
+        let listener = util::listener::bind(settings.address, only_v6)
+            .unwrap_or_else(|e| panic!("Error binding {}: {e}", settings.address));
+
+        let tls_config = match &settings.tls {
+            Some(tls) => {
+                let tls_config = RustlsConfig::from_pem_file(&tls.cert, &tls.key)
+                    .await
+                    .expect("Error loading TLS certificate");
+                tokio::spawn(util::tls::reload_on_change(tls_config.clone(), tls.clone()));
+                Some(tls_config)
+            }
+            None => None,
+        };
+
+        let handle = Handle::new();
+        handles.push(handle.clone());
+        servers.push(serve(
+            axum_server::from_tcp(listener).handle(handle),
+            settings.address,
+            tls_config,
+            app.clone(),
+        ));
+    }
+
+    tokio::spawn(async move {
+        shutdown.await;
+        for handle in handles {
+            handle.graceful_shutdown(None);
+        }
+    });
+
+    ready.store(true, Ordering::Relaxed);
+    join_all(servers).await;
+}
 
-    let server_ip = std::env::var("SERVER_IP").unwrap_or_else(|_| "0.0.0.0".to_string());
-    let server_port = std::env::var("SERVER_PORT").unwrap_or_else(|_| "3000".to_string());
-    let bind_address = format!("{server_ip}:{server_port}");
+/// Serves the app via HTTPS if `tls_config` is set, otherwise via HTTP.
+async fn serve(
+    server: Server,
+    address: SocketAddr,
+    tls_config: Option<RustlsConfig>,
+    app: IntoMakeServiceWithConnectInfo<Router, PeerAddr>,
+) {
+    let Some(tls_config) = tls_config else {
+        tracing::info!(
+            %address,
+            proxy_protocol = SETTINGS.load().proxy.protocol,
+            "Listening via HTTP"
+        );
+        server
+            .acceptor(ProxyProtocolAcceptor::new(DefaultAcceptor))
+            .serve(app)
+            .await
+            .unwrap();
+        return;
+    };
 
-    let listener = tokio::net::TcpListener::bind(bind_address.clone())
+    tracing::info!(
+        %address,
+        proxy_protocol = SETTINGS.load().proxy.protocol,
+        "Listening via HTTPS"
+    );
+
+    // The PROXY protocol header precedes the TLS handshake.
+    server
+        .acceptor(ProxyProtocolAcceptor::new(RustlsAcceptor::new(tls_config)))
+        .serve(app)
         .await
         .unwrap();
-    tracing::info!("Listening on http://{bind_address}");
-    axum::serve(listener, app).await.unwrap();
+}
+
+/// Waits for SIGTERM or Ctrl+C, then closes all WebSocket connections.
+/// Peers are asked to reconnect and other peers are notified that they left.
+async fn shutdown_signal(app_state: config::state::AppState) {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("Error installing Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Error installing SIGTERM handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+
+    tracing::info!("Shutting down...");
+    app_state.ready.store(false, Ordering::Relaxed);
+    app_state.shutdown.cancel();
+    app_state.socket_tasks.close();
+
+    if tokio::time::timeout(SHUTDOWN_TIMEOUT, app_state.socket_tasks.wait())
+        .await
+        .is_err()
+    {
+        tracing::warn!("Timeout while closing WebSocket connections");
+    }
+
+    let retention_days = SETTINGS.load().stats.retention_days;
+    if let Err(e) = app_state.stats.flush(&app_state.metrics, retention_days) {
+        tracing::warn!(error = ?e, "Error writing stats");
+    }
 }
 
 #[rustfmt::skip]
 fn configure_routes() -> Router<config::state::AppState> {
-    Router::new()
+    let router = Router::new()
         .route("/v1/ws", get(ws_controller::ws_handler))
+        .route("/metrics", get(metrics_controller::metrics_handler))
+        .route("/stats", get(stats_controller::stats_handler))
+        .route("/healthz", get(health_controller::health_handler))
+        .route("/readyz", get(health_controller::ready_handler))
+        .nest("/admin", admin_controller::router())
+        .nest("/federation", federation_controller::router())
+        .layer(DefaultBodyLimit::max(MAX_BODY_SIZE))
+        .layer(middleware::from_fn(util::request_id::with_request_id));
+
+    // Outermost so that preflight requests are answered before authorization.
+    match util::cors::layer(&SETTINGS.load().cors) {
+        Some(cors) => router.layer(cors),
+        None => router,
+    }
 }
@@ -0,0 +1,145 @@
+use localsend::webrtc::signaling::WsServerMessage;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Maximum number of messages queued for one peer. Further messages are dropped.
+const MAX_QUEUED_MESSAGES: usize = 16;
+
+/// Holds offers and answers for peers that disconnected and are expected to resume their session.
+/// The messages are delivered after the hello of the resumed connection.
+pub struct OfflineQueue {
+    /// How long a message is kept. Zero disables the queue.
+    ttl: Duration,
+
+    /// Peer ID -> Queue of the disconnected peer.
+    queues: Mutex<HashMap<Uuid, PeerQueue>>,
+}
+
+struct PeerQueue {
+    /// The group the peer was in when it disconnected.
+    group: String,
+
+    /// When the resume token of the peer expires.
+    expires_at: Instant,
+
+    /// The messages and when they have been queued.
+    messages: Vec<(Instant, WsServerMessage)>,
+}
+
+impl OfflineQueue {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            queues: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero()
+    }
+
+    /// Starts queueing messages for a disconnected peer until its resume token expires.
+    pub fn open(&self, peer_id: Uuid, group: &str, expires_at: Instant) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        self.queues.lock().unwrap().insert(
+            peer_id,
+            PeerQueue {
+                group: group.to_string(),
+                expires_at,
+                messages: Vec::new(),
+            },
+        );
+    }
+
+    /// Queues the message if the peer disconnected from the group.
+    /// Returns `false` if the peer is unknown or its queue is full.
+    pub fn push(&self, peer_id: Uuid, group: &str, message: &WsServerMessage) -> bool {
+        let mut queues = self.queues.lock().unwrap();
+        let Some(queue) = queues.get_mut(&peer_id) else {
+            return false;
+        };
+
+        let now = Instant::now();
+        if queue.group != group || queue.expires_at <= now {
+            return false;
+        }
+
+        queue
+            .messages
+            .retain(|(queued_at, _)| now.duration_since(*queued_at) < self.ttl);
+        if queue.messages.len() >= MAX_QUEUED_MESSAGES {
+            return false;
+        }
+
+        queue.messages.push((now, message.clone()));
+        true
+    }
+
+    /// Stops queueing for the resumed peer and returns the messages that have not expired yet.
+    pub fn take(&self, peer_id: Uuid) -> Vec<WsServerMessage> {
+        let Some(queue) = self.queues.lock().unwrap().remove(&peer_id) else {
+            return Vec::new();
+        };
+
+        let now = Instant::now();
+        queue
+            .messages
+            .into_iter()
+            .filter(|(queued_at, _)| now.duration_since(*queued_at) < self.ttl)
+            .map(|(_, message)| message)
+            .collect()
+    }
+
+    /// Removes the queues of peers that can no longer resume their session.
+    /// Returns the number of removed queues.
+    pub fn remove_expired(&self) -> usize {
+        let now = Instant::now();
+        let mut queues = self.queues.lock().unwrap();
+        let before = queues.len();
+        queues.retain(|_, queue| queue.expires_at > now);
+        before - queues.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offline_queue() {
+        let queue = OfflineQueue::new(Duration::from_secs(10));
+        let expires_at = Instant::now() + Duration::from_secs(60);
+        let (peer_id, other_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let message = WsServerMessage::Left { peer_id: other_id };
+
+        // Connected or unknown peers are not queued.
+        assert!(!queue.push(peer_id, "1.2.3.4", &message));
+
+        queue.open(peer_id, "1.2.3.4", expires_at);
+        assert!(!queue.push(peer_id, "5.6.7.8", &message));
+        for _ in 0..MAX_QUEUED_MESSAGES {
+            assert!(queue.push(peer_id, "1.2.3.4", &message));
+        }
+        assert!(!queue.push(peer_id, "1.2.3.4", &message));
+
+        assert_eq!(queue.take(peer_id).len(), MAX_QUEUED_MESSAGES);
+        assert!(queue.take(peer_id).is_empty());
+        assert!(!queue.push(peer_id, "1.2.3.4", &message));
+
+        queue.open(other_id, "1.2.3.4", Instant::now());
+        assert_eq!(queue.remove_expired(), 1);
+    }
+
+    #[test]
+    fn test_disabled() {
+        let queue = OfflineQueue::new(Duration::ZERO);
+        let peer_id = Uuid::new_v4();
+        queue.open(peer_id, "1.2.3.4", Instant::now() + Duration::from_secs(60));
+        assert!(!queue.push(peer_id, "1.2.3.4", &WsServerMessage::Left { peer_id }));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::util::base64;
+use localsend::webrtc::proof_of_work::{hash_input, leading_zero_bits, ProofOfWorkChallenge};
+use ring::digest::{digest, SHA256};
+use ring::hmac;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+/// How long a challenge can be solved.
+const CHALLENGE_TTL: Duration = Duration::from_secs(60);
+
+/// Issues and verifies the challenges of upgrades when `proof_of_work.difficulty` is set.
+///
+/// Challenges are signed instead of stored, so issuing them costs no memory.
+/// Only solved challenges are remembered until they expire, so that each can be used once.
+pub struct ProofOfWork {
+    key: hmac::Key,
+
+    /// Solved challenge -> When it expires (UNIX seconds).
+    solved: Mutex<HashMap<String, u64>>,
+}
+
+impl ProofOfWork {
+    /// Instances behind the same load balancer need the same secret to accept each other's challenges.
+    /// A random one is used if not set.
+    pub fn new(secret: Option<&str>) -> Self {
+        let key = match secret {
+            Some(secret) => secret.as_bytes().to_vec(),
+            None => [*Uuid::new_v4().as_bytes(), *Uuid::new_v4().as_bytes()].concat(),
+        };
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, &key),
+            solved: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Creates a challenge of the form `<expires_at>.<random>.<signature>`.
+    pub fn issue(&self, difficulty: u8) -> ProofOfWorkChallenge {
+        let expires_at = now() + CHALLENGE_TTL.as_secs();
+        let payload = format!("{expires_at}.{}", Uuid::new_v4().simple());
+        let signature = base64::encode(hmac::sign(&self.key, payload.as_bytes()).as_ref());
+        ProofOfWorkChallenge {
+            challenge: format!("{payload}.{signature}"),
+            difficulty,
+        }
+    }
+
+    /// Whether the nonce solves a valid challenge of this server that has not been used before.
+    pub fn verify(&self, challenge: &str, nonce: &str, difficulty: u8) -> bool {
+        let Some((payload, signature)) = challenge.rsplit_once('.') else {
+            return false;
+        };
+        let Ok(signature) = base64::decode(signature) else {
+            return false;
+        };
+        if hmac::verify(&self.key, payload.as_bytes(), &signature).is_err() {
+            return false;
+        }
+
+        let Some(expires_at) = payload
+            .split_once('.')
+            .and_then(|(expires_at, _)| expires_at.parse::<u64>().ok())
+        else {
+            return false;
+        };
+        if expires_at <= now() {
+            return false;
+        }
+
+        let Ok(nonce) = nonce.parse::<u64>() else {
+            return false;
+        };
+        let hash = digest(&SHA256, hash_input(challenge, nonce).as_bytes());
+        if leading_zero_bits(hash.as_ref()) < u32::from(difficulty) {
+            return false;
+        }
+
+        self.solved
+            .lock()
+            .unwrap()
+            .insert(challenge.to_string(), expires_at)
+            .is_none()
+    }
+
+    /// Forgets the solved challenges that can no longer be used.
+    /// Returns the number of removed challenges.
+    pub fn remove_expired(&self) -> usize {
+        let now = now();
+        let mut solved = self.solved.lock().unwrap();
+        let before = solved.len();
+        solved.retain(|_, expires_at| *expires_at > now);
+        before - solved.len()
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(challenge: &ProofOfWorkChallenge) -> String {
+        (0u64..)
+            .find(|nonce| {
+                let hash = digest(&SHA256, hash_input(&challenge.challenge, *nonce).as_bytes());
+                leading_zero_bits(hash.as_ref()) >= u32::from(challenge.difficulty)
+            })
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_verify() {
+        let pow = ProofOfWork::new(None);
+        let challenge = pow.issue(8);
+        let nonce = solve(&challenge);
+
+        // A higher difficulty has been set in the meantime.
+        assert!(!pow.verify(&challenge.challenge, &nonce, 32));
+
+        assert!(pow.verify(&challenge.challenge, &nonce, 8));
+
+        // Each challenge can only be used once.
+        assert!(!pow.verify(&challenge.challenge, &nonce, 8));
+        assert_eq!(pow.remove_expired(), 0);
+
+        // Challenges of other servers are rejected.
+        let other = ProofOfWork::new(Some("secret")).issue(8);
+        assert!(!pow.verify(&other.challenge, &solve(&other), 8));
+
+        // Tampered challenges are rejected.
+        let challenge = pow.issue(0);
+        let tampered = challenge.challenge.replacen('1', "2", 1);
+        assert!(!pow.verify(&tampered, "0", 0));
+    }
+
+    #[test]
+    fn test_shared_secret() {
+        let challenge = ProofOfWork::new(Some("secret")).issue(4);
+        let nonce = solve(&challenge);
+        assert!(ProofOfWork::new(Some("secret")).verify(&challenge.challenge, &nonce, 4));
+    }
+}
@@ -0,0 +1,262 @@
+#[cfg(feature = "redis")]
+mod redis;
+
+use crate::config::settings::SETTINGS;
+use crate::config::state::TxMap;
+use crate::util::password::PasswordHash;
+use localsend::webrtc::signaling::{ClientInfo, WsServerMessage};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "redis")]
+use std::sync::Arc;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+/// Shares peers and messages with other server instances behind the same load balancer.
+/// The WebSocket senders always stay in the local `TxMap`.
+#[derive(Clone)]
+pub enum Registry {
+    /// A single instance. Nothing is shared.
+    Memory,
+
+    /// Peers are stored in Redis and messages are relayed via Redis pub/sub.
+    #[cfg(feature = "redis")]
+    Redis(Arc<redis::RedisRegistry>),
+}
+
+/// The group of a [`RelayMessage`] that is delivered to the peers of all groups.
+pub const ALL_GROUPS: &str = "*";
+
+/// A message for peers that might be connected to another instance.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RelayMessage {
+    /// The IP group, `room:<code>` or [`ALL_GROUPS`].
+    pub group: String,
+
+    /// If set, the message is only delivered to this peer.
+    /// Otherwise, it is delivered to all peers of the group.
+    pub target: Option<Uuid>,
+
+    /// If set, the message is not delivered to this peer.
+    pub exclude: Option<Uuid>,
+
+    pub message: WsServerMessage,
+}
+
+// Without the redis feature, the arguments are only used by the Redis backend.
+#[cfg_attr(not(feature = "redis"), allow(unused_variables))]
+impl Registry {
+    /// Uses Redis if a Redis URL is configured.
+    /// Messages from other instances are delivered to the peers in `tx_map`.
+    pub async fn init(tx_map: TxMap) -> Self {
+        let Some(url) = SETTINGS.load().redis.url.clone() else {
+            return Registry::Memory;
+        };
+
+        #[cfg(feature = "redis")]
+        {
+            let registry = redis::RedisRegistry::connect(&url, tx_map)
+                .await
+                .expect("Error connecting to Redis");
+            tracing::info!("Using Redis peer registry");
+            Registry::Redis(Arc::new(registry))
+        }
+
+        #[cfg(not(feature = "redis"))]
+        {
+            tracing::warn!(
+                "A Redis URL is set but the server has been built without the redis feature"
+            );
+            Registry::Memory
+        }
+    }
+
+    /// Returns the peers of the group that are connected to other instances.
+    pub async fn remote_peers(&self, group: &str) -> Vec<ClientInfo> {
+        match self {
+            Registry::Memory => Vec::new(),
+            #[cfg(feature = "redis")]
+            Registry::Redis(registry) => registry.remote_peers(group).await,
+        }
+    }
+
+    /// Returns the room password held by the peers of the group connected to other instances.
+    pub async fn remote_room_password(&self, group: &str) -> Option<PasswordHash> {
+        match self {
+            Registry::Memory => None,
+            #[cfg(feature = "redis")]
+            Registry::Redis(registry) => registry.remote_room_password(group).await,
+        }
+    }
+
+    /// Adds the peer to the group or updates its info.
+    pub async fn add_peer(
+        &self,
+        group: &str,
+        peer: &ClientInfo,
+        room_password: Option<&PasswordHash>,
+    ) {
+        match self {
+            Registry::Memory => {}
+            #[cfg(feature = "redis")]
+            Registry::Redis(registry) => registry.add_peer(group, peer, room_password).await,
+        }
+    }
+
+    pub async fn remove_peer(&self, group: &str, peer_id: Uuid) {
+        match self {
+            Registry::Memory => {}
+            #[cfg(feature = "redis")]
+            Registry::Redis(registry) => registry.remove_peer(group, peer_id).await,
+        }
+    }
+
+    /// Sends the message to the other instances.
+    /// Returns `false` if there are no other instances or the message could not be sent.
+    pub async fn publish(&self, message: RelayMessage) -> bool {
+        match self {
+            Registry::Memory => false,
+            #[cfg(feature = "redis")]
+            Registry::Redis(registry) => registry.publish(message).await,
+        }
+    }
+}
+
+/// Delivers a message to the local peers, e.g. one received from another instance.
+/// Returns the number of peers the message was sent to.
+pub async fn deliver(tx_map: &TxMap, message: RelayMessage) -> usize {
+    let peers_tx: Vec<mpsc::Sender<WsServerMessage>> = {
+        let tx_map = tx_map.lock().await;
+        tx_map
+            .iter()
+            .filter(|(group, _)| message.group == ALL_GROUPS || **group == message.group)
+            .flat_map(|(_, tx_local_map)| tx_local_map.iter())
+            .filter(|(k, _)| message.target.is_none_or(|target| target == **k))
+            .filter(|(k, _)| message.exclude != Some(**k))
+            .filter(|(_, v)| v.protocol >= message.message.min_protocol())
+            .map(|(_, v)| v.tx.clone())
+            .collect()
+    };
+
+    let count = peers_tx.len();
+    for tx in peers_tx {
+        let _ = tx.send(message.message.clone()).await;
+    }
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::state::ClientState;
+    use localsend::webrtc::signaling::{AnnouncementKind, SignalingProtocol};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    #[tokio::test]
+    async fn test_deliver() {
+        let (a, b, c, d) = (
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        );
+        let (state_a, mut rx_a) = ClientState::mock("123", "1.2.3.4");
+        let (state_b, mut rx_b) = ClientState::mock("123", "1.2.3.4");
+        let (mut state_c, mut rx_c) = ClientState::mock("123", "1.2.3.4");
+        state_c.protocol = SignalingProtocol::V1;
+        let (state_d, mut rx_d) = ClientState::mock("123", "5.6.7.8");
+        let tx_map: TxMap = Arc::new(Mutex::new(HashMap::from([
+            (
+                "1.2.3.4".to_string(),
+                HashMap::from([(a, state_a), (b, state_b), (c, state_c)]),
+            ),
+            ("room:ABCDEF".to_string(), HashMap::from([(d, state_d)])),
+        ])));
+
+        let left = WsServerMessage::Left {
+            peer_id: Uuid::new_v4(),
+        };
+
+        // Broadcast excluding a.
+        deliver(
+            &tx_map,
+            RelayMessage {
+                group: "1.2.3.4".to_string(),
+                target: None,
+                exclude: Some(a),
+                message: left.clone(),
+            },
+        )
+        .await;
+        assert!(rx_a.try_recv().is_err());
+        assert_eq!(rx_b.try_recv().unwrap(), left);
+        assert_eq!(rx_c.try_recv().unwrap(), left);
+
+        // Directed to b.
+        deliver(
+            &tx_map,
+            RelayMessage {
+                group: "1.2.3.4".to_string(),
+                target: Some(b),
+                exclude: None,
+                message: left.clone(),
+            },
+        )
+        .await;
+        assert!(rx_a.try_recv().is_err());
+        assert_eq!(rx_b.try_recv().unwrap(), left);
+        assert!(rx_c.try_recv().is_err());
+
+        // Other group.
+        deliver(
+            &tx_map,
+            RelayMessage {
+                group: "5.6.7.8".to_string(),
+                target: None,
+                exclude: None,
+                message: left.clone(),
+            },
+        )
+        .await;
+        assert!(rx_a.try_recv().is_err());
+        assert!(rx_b.try_recv().is_err());
+        assert!(rx_c.try_recv().is_err());
+        assert!(rx_d.try_recv().is_err());
+
+        // All groups.
+        let delivered = deliver(
+            &tx_map,
+            RelayMessage {
+                group: ALL_GROUPS.to_string(),
+                target: None,
+                exclude: None,
+                message: left.clone(),
+            },
+        )
+        .await;
+        assert_eq!(delivered, 4);
+        assert_eq!(rx_a.try_recv().unwrap(), left);
+        assert_eq!(rx_c.try_recv().unwrap(), left);
+        assert_eq!(rx_d.try_recv().unwrap(), left);
+
+        // Not sent to peers with an older protocol.
+        let announcement = WsServerMessage::Announcement {
+            kind: AnnouncementKind::Info,
+            message: "Hello".to_string(),
+        };
+        let delivered = deliver(
+            &tx_map,
+            RelayMessage {
+                group: ALL_GROUPS.to_string(),
+                target: None,
+                exclude: None,
+                message: announcement.clone(),
+            },
+        )
+        .await;
+        assert_eq!(delivered, 3);
+        assert!(rx_c.try_recv().is_err());
+        assert_eq!(rx_d.try_recv().unwrap(), announcement);
+    }
+}
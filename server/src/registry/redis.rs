@@ -0,0 +1,229 @@
+use crate::config::state::TxMap;
+use crate::registry::{deliver, RelayMessage};
+use crate::util::password::PasswordHash;
+use futures_util::stream::StreamExt;
+use localsend::webrtc::signaling::ClientInfo;
+use redis::aio::ConnectionManager;
+use redis::{AsyncCommands, Client, RedisResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use uuid::Uuid;
+
+/// The pub/sub channel all instances subscribe to.
+const RELAY_CHANNEL: &str = "localsend:relay";
+
+/// How long an instance is considered alive without a heartbeat.
+/// Peers of dead instances are removed lazily.
+const INSTANCE_TTL: Duration = Duration::from_secs(30);
+
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+pub struct RedisRegistry {
+    /// Random ID of this instance to ignore its own messages.
+    instance_id: String,
+    connection: ConnectionManager,
+}
+
+/// Value of a peer in the group hash.
+#[derive(Deserialize, Serialize)]
+struct PeerEntry {
+    instance: String,
+    peer: ClientInfo,
+    #[serde(default)]
+    room_password: Option<PasswordHash>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Envelope {
+    instance: String,
+    message: RelayMessage,
+}
+
+impl RedisRegistry {
+    pub async fn connect(url: &str, tx_map: TxMap) -> RedisResult<Self> {
+        let client = Client::open(url)?;
+        let mut connection = client.get_connection_manager().await?;
+        let instance_id = Uuid::new_v4().simple().to_string();
+
+        heartbeat(&mut connection, &instance_id).await?;
+
+        tokio::spawn({
+            let mut connection = connection.clone();
+            let instance_id = instance_id.clone();
+            async move {
+                loop {
+                    tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+                    if let Err(e) = heartbeat(&mut connection, &instance_id).await {
+                        tracing::warn!(error = ?e, "Error sending Redis heartbeat");
+                    }
+                }
+            }
+        });
+
+        tokio::spawn({
+            let instance_id = instance_id.clone();
+            async move {
+                loop {
+                    if let Err(e) = subscribe(&client, &instance_id, &tx_map).await {
+                        tracing::warn!(error = ?e, "Error receiving Redis messages");
+                    }
+                    tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+                }
+            }
+        });
+
+        Ok(Self {
+            instance_id,
+            connection,
+        })
+    }
+
+    pub async fn remote_peers(&self, group: &str) -> Vec<ClientInfo> {
+        self.remote_entries(group)
+            .await
+            .into_iter()
+            .map(|entry| entry.peer)
+            .collect()
+    }
+
+    pub async fn remote_room_password(&self, group: &str) -> Option<PasswordHash> {
+        self.remote_entries(group)
+            .await
+            .into_iter()
+            .next()
+            .and_then(|entry| entry.room_password)
+    }
+
+    async fn remote_entries(&self, group: &str) -> Vec<PeerEntry> {
+        match self.try_remote_entries(group).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                tracing::warn!(error = ?e, "Error reading peers from Redis");
+                Vec::new()
+            }
+        }
+    }
+
+    async fn try_remote_entries(&self, group: &str) -> RedisResult<Vec<PeerEntry>> {
+        let mut connection = self.connection.clone();
+        let entries: HashMap<String, String> = connection.hgetall(group_key(group)).await?;
+
+        let entries: Vec<(String, PeerEntry)> = entries
+            .into_iter()
+            .filter_map(|(k, v)| Some((k, serde_json::from_str::<PeerEntry>(&v).ok()?)))
+            .filter(|(_, entry)| entry.instance != self.instance_id)
+            .collect();
+
+        let mut alive_instances: HashSet<String> = HashSet::new();
+        for instance in entries.iter().map(|(_, entry)| &entry.instance) {
+            if !alive_instances.contains(instance)
+                && connection.exists(instance_key(instance)).await?
+            {
+                alive_instances.insert(instance.clone());
+            }
+        }
+
+        let mut alive_entries = Vec::new();
+        for (field, entry) in entries {
+            if alive_instances.contains(&entry.instance) {
+                alive_entries.push(entry);
+            } else {
+                let _: () = connection.hdel(group_key(group), field).await?;
+            }
+        }
+
+        Ok(alive_entries)
+    }
+
+    pub async fn add_peer(
+        &self,
+        group: &str,
+        peer: &ClientInfo,
+        room_password: Option<&PasswordHash>,
+    ) {
+        let entry = PeerEntry {
+            instance: self.instance_id.clone(),
+            peer: peer.clone(),
+            room_password: room_password.cloned(),
+        };
+
+        let mut connection = self.connection.clone();
+        let result: RedisResult<()> = connection
+            .hset(
+                group_key(group),
+                peer.id.to_string(),
+                serde_json::to_string(&entry).unwrap(),
+            )
+            .await;
+
+        if let Err(e) = result {
+            tracing::warn!(error = ?e, "Error adding peer to Redis");
+        }
+    }
+
+    pub async fn remove_peer(&self, group: &str, peer_id: Uuid) {
+        let mut connection = self.connection.clone();
+        let result: RedisResult<()> = connection.hdel(group_key(group), peer_id.to_string()).await;
+
+        if let Err(e) = result {
+            tracing::warn!(error = ?e, "Error removing peer from Redis");
+        }
+    }
+
+    pub async fn publish(&self, message: RelayMessage) -> bool {
+        let envelope = Envelope {
+            instance: self.instance_id.clone(),
+            message,
+        };
+
+        let mut connection = self.connection.clone();
+        let result: RedisResult<()> = connection
+            .publish(RELAY_CHANNEL, serde_json::to_string(&envelope).unwrap())
+            .await;
+
+        match result {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!(error = ?e, "Error publishing message to Redis");
+                false
+            }
+        }
+    }
+}
+
+async fn heartbeat(connection: &mut ConnectionManager, instance_id: &str) -> RedisResult<()> {
+    connection
+        .set_ex(instance_key(instance_id), 1, INSTANCE_TTL.as_secs())
+        .await
+}
+
+/// Delivers messages of other instances until the subscription is lost.
+async fn subscribe(client: &Client, instance_id: &str, tx_map: &TxMap) -> RedisResult<()> {
+    let mut pubsub = client.get_async_pubsub().await?;
+    pubsub.subscribe(RELAY_CHANNEL).await?;
+
+    let mut stream = pubsub.into_on_message();
+    while let Some(msg) = stream.next().await {
+        let payload: String = msg.get_payload()?;
+        let Ok(envelope) = serde_json::from_str::<Envelope>(&payload) else {
+            continue;
+        };
+
+        if envelope.instance != instance_id {
+            deliver(tx_map, envelope.message).await;
+        }
+    }
+
+    Ok(())
+}
+
+fn group_key(group: &str) -> String {
+    format!("localsend:group:{group}")
+}
+
+fn instance_key(instance_id: &str) -> String {
+    format!("localsend:instance:{instance_id}")
+}
@@ -0,0 +1,195 @@
+use crate::blocklist::now;
+use crate::config::metrics::Metrics;
+use rusqlite::{params, Connection};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Aggregates of one day (UTC) as returned by `/stats`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DayStats {
+    /// `YYYY-MM-DD`
+    pub date: String,
+
+    /// Number of WebSocket connections, including reconnects.
+    pub connections: u64,
+
+    /// Number of distinct client tokens.
+    pub unique_peers: u64,
+
+    /// Number of answers relayed, i.e. sessions brokered between two peers.
+    pub sessions: u64,
+
+    /// Number of connections and messages rejected by the rate and connection limits.
+    pub rejections: u64,
+}
+
+/// Daily aggregates stored in SQLite.
+/// Counted in memory and written periodically, so only the latest minute is lost on a crash.
+/// Unique peers of the current day are only counted since the start of the server.
+pub struct DailyStats {
+    connection: Mutex<Connection>,
+    today: Mutex<Today>,
+}
+
+/// The counts since the last flush.
+struct Today {
+    /// Days since the unix epoch.
+    day: u64,
+    connections: u64,
+
+    /// Tokens of the peers connected today. Never written to the database.
+    tokens: HashSet<String>,
+
+    /// Values of the metrics at the last flush.
+    flushed_sessions: u64,
+    flushed_rejections: u64,
+}
+
+impl DailyStats {
+    /// Opens the database at `path` or an in-memory database if `None`.
+    pub fn open(path: Option<&Path>) -> rusqlite::Result<Self> {
+        Ok(Self {
+            connection: Mutex::new(connect(path)?),
+            today: Mutex::new(Today {
+                day: now() / SECONDS_PER_DAY,
+                connections: 0,
+                tokens: HashSet::new(),
+                flushed_sessions: 0,
+                flushed_rejections: 0,
+            }),
+        })
+    }
+
+    /// Counts a connection of the peer with the token.
+    pub fn record_connection(&self, token: &str) {
+        let mut today = self.today.lock().unwrap();
+        today.connections += 1;
+        if !today.tokens.contains(token) {
+            today.tokens.insert(token.to_string());
+        }
+    }
+
+    /// Adds the counts since the last flush to the database
+    /// and deletes the days older than `retention_days`.
+    pub fn flush(&self, metrics: &Metrics, retention_days: u64) -> rusqlite::Result<()> {
+        let sessions = metrics.answers_relayed.load(Ordering::Relaxed);
+        let rejections = metrics.rate_limit_rejections.load(Ordering::Relaxed)
+            + metrics.connection_limit_rejections.load(Ordering::Relaxed);
+
+        let (day, connections, unique_peers, sessions, rejections) = {
+            let mut today = self.today.lock().unwrap();
+            let row = (
+                today.day,
+                std::mem::take(&mut today.connections),
+                today.tokens.len() as u64,
+                sessions - std::mem::replace(&mut today.flushed_sessions, sessions),
+                rejections - std::mem::replace(&mut today.flushed_rejections, rejections),
+            );
+
+            let current_day = now() / SECONDS_PER_DAY;
+            if current_day != today.day {
+                today.day = current_day;
+                today.tokens.clear();
+            }
+            row
+        };
+
+        let connection = self.connection.lock().unwrap();
+        connection.execute(
+            "INSERT INTO daily_stats (day, connections, unique_peers, sessions, rejections)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            ON CONFLICT (day) DO UPDATE SET
+                connections = connections + excluded.connections,
+                unique_peers = MAX(unique_peers, excluded.unique_peers),
+                sessions = sessions + excluded.sessions,
+                rejections = rejections + excluded.rejections",
+            params![day, connections, unique_peers, sessions, rejections],
+        )?;
+        connection.execute(
+            "DELETE FROM daily_stats WHERE day <= ?1",
+            [day.saturating_sub(retention_days)],
+        )?;
+        Ok(())
+    }
+
+    /// Returns the latest days, newest first.
+    pub fn list(&self, days: u64) -> rusqlite::Result<Vec<DayStats>> {
+        let connection = self.connection.lock().unwrap();
+        let mut statement = connection.prepare(
+            "SELECT date(day * 86400, 'unixepoch'), connections, unique_peers, sessions, rejections
+            FROM daily_stats
+            ORDER BY day DESC
+            LIMIT ?1",
+        )?;
+
+        let days = statement.query_map([days], |row| {
+            Ok(DayStats {
+                date: row.get(0)?,
+                connections: row.get(1)?,
+                unique_peers: row.get(2)?,
+                sessions: row.get(3)?,
+                rejections: row.get(4)?,
+            })
+        })?;
+
+        days.collect()
+    }
+}
+
+/// Opens the database and creates the table if needed.
+fn connect(path: Option<&Path>) -> rusqlite::Result<Connection> {
+    let connection = match path {
+        Some(path) => Connection::open(path)?,
+        None => Connection::open_in_memory()?,
+    };
+
+    connection.execute(
+        "CREATE TABLE IF NOT EXISTS daily_stats (
+            day INTEGER PRIMARY KEY,
+            connections INTEGER NOT NULL,
+            unique_peers INTEGER NOT NULL,
+            sessions INTEGER NOT NULL,
+            rejections INTEGER NOT NULL
+        )",
+        (),
+    )?;
+
+    Ok(connection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_daily_stats() {
+        let stats = DailyStats::open(None).unwrap();
+        let metrics = Metrics::default();
+
+        stats.record_connection("a");
+        stats.record_connection("a");
+        stats.record_connection("b");
+        Metrics::inc(&metrics.answers_relayed);
+        Metrics::inc(&metrics.rate_limit_rejections);
+        stats.flush(&metrics, 30).unwrap();
+
+        // Only the counts since the last flush are added.
+        stats.record_connection("a");
+        Metrics::inc(&metrics.connection_limit_rejections);
+        stats.flush(&metrics, 30).unwrap();
+
+        let days = stats.list(30).unwrap();
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].connections, 4);
+        assert_eq!(days[0].unique_peers, 2);
+        assert_eq!(days[0].sessions, 1);
+        assert_eq!(days[0].rejections, 2);
+        assert_eq!(days[0].date.len(), "2025-01-01".len());
+    }
+}
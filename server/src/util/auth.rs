@@ -0,0 +1,11 @@
+use axum::http::{header, HeaderMap};
+use localsend::util::token::is_token_equal;
+
+/// Whether the request has the header `Authorization: Bearer <token>`.
+pub fn is_bearer_authorized(headers: &HeaderMap, token: &str) -> bool {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .is_some_and(|v| is_token_equal(v, token))
+}
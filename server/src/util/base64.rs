@@ -7,3 +7,7 @@ const BASE_64_ENGINE: GeneralPurpose = URL_SAFE_NO_PAD;
 pub(crate) fn decode(data: &str) -> Result<Vec<u8>, DecodeError> {
     BASE_64_ENGINE.decode(data)
 }
+
+pub(crate) fn encode(data: &[u8]) -> String {
+    BASE_64_ENGINE.encode(data)
+}
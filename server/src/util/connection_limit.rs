@@ -0,0 +1,65 @@
+use crate::config::state::ConnectionCountMap;
+
+/// An open connection of an IP group. Released when dropped.
+pub struct ConnectionSlot {
+    connection_count_map: ConnectionCountMap,
+    ip_group: String,
+}
+
+impl ConnectionSlot {
+    /// Returns `None` if the IP group already has `limit` open connections.
+    pub fn try_acquire(
+        connection_count_map: &ConnectionCountMap,
+        ip_group: &str,
+        limit: usize,
+    ) -> Option<Self> {
+        let mut map = connection_count_map.lock().unwrap();
+        let count = map.entry(ip_group.to_string()).or_insert(0);
+        if *count >= limit {
+            return None;
+        }
+
+        *count += 1;
+        Some(Self {
+            connection_count_map: connection_count_map.clone(),
+            ip_group: ip_group.to_string(),
+        })
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        let mut map = self.connection_count_map.lock().unwrap();
+        if let Some(count) = map.get_mut(&self.ip_group) {
+            *count -= 1;
+            if *count == 0 {
+                map.remove(&self.ip_group);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_connection_slot() {
+        let map: ConnectionCountMap = Arc::new(Mutex::new(HashMap::new()));
+
+        let a = ConnectionSlot::try_acquire(&map, "1.2.3.4", 2).unwrap();
+        let b = ConnectionSlot::try_acquire(&map, "1.2.3.4", 2).unwrap();
+        assert!(ConnectionSlot::try_acquire(&map, "1.2.3.4", 2).is_none());
+
+        // Other groups are not affected.
+        let c = ConnectionSlot::try_acquire(&map, "5.6.7.8", 2).unwrap();
+
+        drop(a);
+        let a = ConnectionSlot::try_acquire(&map, "1.2.3.4", 2).unwrap();
+
+        drop((a, b, c));
+        assert!(map.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::config::settings::CorsSettings;
+use axum::http::{header, HeaderValue, Method};
+use std::str::FromStr;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// Answers preflight requests and adds the CORS headers for the allowed origins.
+/// Returns `None` if CORS is disabled. The settings must have been validated.
+pub fn layer(settings: &CorsSettings) -> Option<CorsLayer> {
+    if !settings.is_enabled() {
+        return None;
+    }
+
+    let origin = if settings.allows_any_origin() {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(
+            settings
+                .allowed_origins
+                .iter()
+                .map(|origin| HeaderValue::from_str(origin).unwrap()),
+        )
+    };
+
+    let methods: Vec<Method> = settings
+        .allowed_methods
+        .iter()
+        .map(|method| Method::from_str(method).unwrap())
+        .collect();
+
+    Some(
+        CorsLayer::new()
+            .allow_origin(origin)
+            .allow_methods(methods)
+            .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE])
+            .expose_headers([header::RETRY_AFTER, header::SEC_WEBSOCKET_PROTOCOL]),
+    )
+}
@@ -1,18 +1,131 @@
-use std::net::IpAddr;
+use crate::config::settings::{ProxySettings, SETTINGS};
+use crate::util::proxy_protocol::ProxiedAddr;
+use axum::extract::connect_info::Connected;
+use axum::extract::{ConnectInfo, FromRequestParts};
+use axum::http::request::Parts;
+use axum::http::HeaderMap;
+#[cfg(unix)]
+use axum::serve::IncomingStream;
+use std::convert::Infallible;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+#[cfg(unix)]
+use tokio::net::UnixListener;
 
-pub(crate) fn get_ip_group(ip: IpAddr) -> String {
+/// The address of a connection, added as `ConnectInfo` by the TCP and the unix socket listener.
+#[derive(Clone, Copy, Debug)]
+pub enum PeerAddr {
+    Tcp(SocketAddr),
+
+    /// Connected via the unix socket, i.e. a reverse proxy on the same host.
+    Unix,
+}
+
+impl PeerAddr {
+    /// Unix socket connections count as loopback, so their `X-Forwarded-For` is trusted by default.
+    pub fn ip(&self) -> IpAddr {
+        match self {
+            PeerAddr::Tcp(addr) => addr.ip(),
+            PeerAddr::Unix => Ipv4Addr::LOCALHOST.into(),
+        }
+    }
+}
+
+impl Connected<SocketAddr> for PeerAddr {
+    fn connect_info(addr: SocketAddr) -> Self {
+        PeerAddr::Tcp(addr)
+    }
+}
+
+#[cfg(unix)]
+impl Connected<IncomingStream<'_, UnixListener>> for PeerAddr {
+    fn connect_info(_: IncomingStream<'_, UnixListener>) -> Self {
+        PeerAddr::Unix
+    }
+}
+
+/// The IP address of the client, even behind a trusted reverse proxy.
+/// See [`client_ip`].
+#[derive(Clone, Copy, Debug)]
+pub struct ClientIp(pub IpAddr);
+
+impl<S: Send + Sync> FromRequestParts<S> for ClientIp {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        // The address sent via the PROXY protocol replaces the address of the connection.
+        let peer = match parts.extensions.get::<ProxiedAddr>() {
+            Some(ProxiedAddr(Some(addr))) => addr.ip(),
+            _ => parts
+                .extensions
+                .get::<ConnectInfo<PeerAddr>>()
+                .map(|ConnectInfo(addr)| addr.ip())
+                .unwrap_or(Ipv4Addr::UNSPECIFIED.into()),
+        };
+
+        Ok(ClientIp(client_ip(
+            peer,
+            &parts.headers,
+            &SETTINGS.load().proxy,
+        )))
+    }
+}
+
+/// Returns the IP address of the client that connected via `peer`.
+///
+/// `X-Forwarded-For` is only used if `peer` is a trusted proxy.
+/// Every proxy appends the address it received the request from,
+/// so the header is read from right to left until the first address that is not a trusted proxy.
+pub(crate) fn client_ip(peer: IpAddr, headers: &HeaderMap, proxy: &ProxySettings) -> IpAddr {
+    let mut ip = peer.to_canonical();
+    if !proxy.is_trusted(ip) {
+        return ip;
+    }
+
+    let forwarded: Vec<&str> = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .collect();
+
+    for address in forwarded.into_iter().rev() {
+        let Ok(address) = address.trim().parse::<IpAddr>() else {
+            break;
+        };
+
+        ip = address.to_canonical();
+        if !proxy.is_trusted(ip) {
+            break;
+        }
+    }
+
+    ip
+}
+
+/// Returns the network of the IP address with the given prefix length.
+/// Peers in the same network can see each other.
+///
+/// IPv6 groups only contain the segments covered by the prefix,
+/// e.g. `1:2:3:4` for a /64 network.
+pub(crate) fn get_ip_group(ip: IpAddr, ipv4_prefix_length: u8, ipv6_prefix_length: u8) -> String {
     match ip {
         IpAddr::V4(ip) => {
-            // IPv4: Each IP address is already a group.
-            ip.to_string()
+            let mask = u32::MAX
+                .checked_shl(32 - ipv4_prefix_length as u32)
+                .unwrap_or(0);
+            Ipv4Addr::from(u32::from(ip) & mask).to_string()
         }
         IpAddr::V6(ip) => {
-            // IPv6: We treat /64 as a group.
-            let segments = ip.segments();
-            format!(
-                "{:x}:{:x}:{:x}:{:x}",
-                segments[0], segments[1], segments[2], segments[3]
-            )
+            let mask = u128::MAX
+                .checked_shl(128 - ipv6_prefix_length as u32)
+                .unwrap_or(0);
+            let segments = Ipv6Addr::from(u128::from(ip) & mask).segments();
+            let segment_count = (ipv6_prefix_length as usize).div_ceil(16).max(1);
+            segments[..segment_count]
+                .iter()
+                .map(|s| format!("{s:x}"))
+                .collect::<Vec<_>>()
+                .join(":")
         }
     }
 }
@@ -20,21 +133,107 @@ pub(crate) fn get_ip_group(ip: IpAddr) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axum::http::HeaderValue;
     use std::str::FromStr;
 
+    fn ip(ip: &str) -> IpAddr {
+        IpAddr::from_str(ip).unwrap()
+    }
+
+    fn forwarded_for(values: &[&str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for value in values {
+            headers.append("x-forwarded-for", HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn test_client_ip() {
+        let proxy = ProxySettings::default();
+
+        // Directly connected.
+        assert_eq!(
+            client_ip(ip("203.0.113.7"), &HeaderMap::new(), &proxy),
+            ip("203.0.113.7")
+        );
+
+        // Spoofed header of a client that is not a proxy.
+        let headers = forwarded_for(&["1.2.3.4"]);
+        assert_eq!(
+            client_ip(ip("203.0.113.7"), &headers, &proxy),
+            ip("203.0.113.7")
+        );
+
+        // Behind a reverse proxy on the same host.
+        assert_eq!(client_ip(ip("127.0.0.1"), &headers, &proxy), ip("1.2.3.4"));
+
+        // Proxies in a private network are only trusted if configured.
+        let headers = forwarded_for(&["6.6.6.6, 1.2.3.4", "10.0.0.2"]);
+        assert_eq!(
+            client_ip(ip("::ffff:127.0.0.1"), &headers, &proxy),
+            ip("10.0.0.2")
+        );
+
+        // Behind two proxies. The leftmost entry was set by the client.
+        let proxy = ProxySettings {
+            trusted: vec![
+                "127.0.0.0/8".parse().unwrap(),
+                "10.0.0.0/8".parse().unwrap(),
+            ],
+            ..ProxySettings::default()
+        };
+        assert_eq!(
+            client_ip(ip("::ffff:127.0.0.1"), &headers, &proxy),
+            ip("1.2.3.4")
+        );
+
+        // Malformed entries stop the search.
+        let headers = forwarded_for(&["1.2.3.4, unknown, 10.0.0.2"]);
+        assert_eq!(client_ip(ip("127.0.0.1"), &headers, &proxy), ip("10.0.0.2"));
+    }
+
     #[test]
     fn test_get_ip_group() {
         assert_eq!(
-            get_ip_group(IpAddr::from_str("1.2.3.4").unwrap()),
+            get_ip_group(IpAddr::from_str("1.2.3.4").unwrap(), 32, 64),
             "1.2.3.4"
         );
         assert_eq!(
-            get_ip_group(IpAddr::from_str("1:2:3:4:5:6:7:8").unwrap()),
+            get_ip_group(IpAddr::from_str("1:2:3:4:5:6:7:8").unwrap(), 32, 64),
             "1:2:3:4"
         );
         assert_eq!(
-            get_ip_group(IpAddr::from_str("a:b:c:d:e:f:0:1").unwrap()),
+            get_ip_group(IpAddr::from_str("a:b:c:d:e:f:0:1").unwrap(), 32, 64),
             "a:b:c:d"
         );
     }
+
+    #[test]
+    fn test_get_ip_group_prefix_length() {
+        assert_eq!(
+            get_ip_group(IpAddr::from_str("1.2.3.4").unwrap(), 24, 64),
+            "1.2.3.0"
+        );
+        assert_eq!(
+            get_ip_group(IpAddr::from_str("1.2.3.4").unwrap(), 16, 64),
+            "1.2.0.0"
+        );
+        assert_eq!(
+            get_ip_group(IpAddr::from_str("1.2.3.4").unwrap(), 0, 64),
+            "0.0.0.0"
+        );
+        assert_eq!(
+            get_ip_group(IpAddr::from_str("1:2:3:4ff:5:6:7:8").unwrap(), 32, 56),
+            "1:2:3:400"
+        );
+        assert_eq!(
+            get_ip_group(IpAddr::from_str("1:2:3:4:5:6:7:8").unwrap(), 32, 48),
+            "1:2:3"
+        );
+        assert_eq!(
+            get_ip_group(IpAddr::from_str("1:2:3:4:5:6:7:8").unwrap(), 32, 128),
+            "1:2:3:4:5:6:7:8"
+        );
+    }
 }
@@ -0,0 +1,45 @@
+use socket2::{Domain, Protocol, Socket, Type};
+use std::io;
+use std::net::{SocketAddr, TcpListener};
+
+/// Maximum number of pending connections.
+const BACKLOG: i32 = 1024;
+
+/// Binds a non-blocking TCP listener.
+/// With `only_v6`, an IPv6 listener does not accept IPv4 connections,
+/// so that an IPv4 listener can use the same port.
+pub fn bind(address: SocketAddr, only_v6: bool) -> io::Result<TcpListener> {
+    let socket = Socket::new(
+        Domain::for_address(address),
+        Type::STREAM,
+        Some(Protocol::TCP),
+    )?;
+    if address.is_ipv6() {
+        socket.set_only_v6(only_v6)?;
+    }
+    #[cfg(unix)]
+    socket.set_reuse_address(true)?;
+    socket.set_nonblocking(true)?;
+    socket.bind(&address.into())?;
+    socket.listen(BACKLOG)?;
+    Ok(socket.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dual_stack() {
+        let v4 = bind("0.0.0.0:0".parse().unwrap(), false).unwrap();
+        let port = v4.local_addr().unwrap().port();
+
+        // Without IPv6 support on the host there is nothing to test.
+        if Socket::new(Domain::IPV6, Type::STREAM, None).is_err() {
+            return;
+        }
+
+        let v6 = bind(SocketAddr::new("::".parse().unwrap(), port), true).unwrap();
+        assert_eq!(v6.local_addr().unwrap().port(), port);
+    }
+}
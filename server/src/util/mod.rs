@@ -1,2 +1,14 @@
+pub(crate) mod auth;
 pub(crate) mod base64;
+pub(crate) mod connection_limit;
+pub(crate) mod cors;
 pub(crate) mod ip;
+pub(crate) mod listener;
+pub(crate) mod password;
+pub(crate) mod proxy_protocol;
+pub(crate) mod rate_limit;
+pub(crate) mod request_id;
+pub(crate) mod sdp;
+pub(crate) mod tls;
+#[cfg(unix)]
+pub(crate) mod unix_socket;
@@ -0,0 +1,60 @@
+use ring::digest::{Context, SHA256, SHA256_OUTPUT_LEN};
+use serde::{Deserialize, Serialize};
+use subtle::ConstantTimeEq;
+use uuid::Uuid;
+
+/// Maximum length of a room password in bytes.
+pub const MAX_PASSWORD_LENGTH: usize = 256;
+
+/// A salted SHA-256 hash of a room password.
+/// The password itself is never stored or shared with other instances.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PasswordHash {
+    salt: [u8; 16],
+    hash: [u8; SHA256_OUTPUT_LEN],
+}
+
+impl PasswordHash {
+    pub fn new(password: &str) -> Self {
+        let salt = *Uuid::new_v4().as_bytes();
+        Self {
+            salt,
+            hash: hash(&salt, password),
+        }
+    }
+
+    /// Compares the password in constant time.
+    pub fn verify(&self, password: &str) -> bool {
+        hash(&self.salt, password).ct_eq(&self.hash).into()
+    }
+}
+
+fn hash(salt: &[u8], password: &str) -> [u8; SHA256_OUTPUT_LEN] {
+    let mut context = Context::new(&SHA256);
+    context.update(salt);
+    context.update(password.as_bytes());
+
+    let mut hash = [0; SHA256_OUTPUT_LEN];
+    hash.copy_from_slice(context.finish().as_ref());
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let hash = PasswordHash::new("secret");
+        assert!(hash.verify("secret"));
+        assert!(!hash.verify("Secret"));
+        assert!(!hash.verify(""));
+
+        // The same password is salted differently.
+        assert_ne!(PasswordHash::new("secret").hash, hash.hash);
+
+        let decoded: PasswordHash =
+            serde_json::from_str(&serde_json::to_string(&hash).unwrap()).unwrap();
+        assert!(decoded.verify("secret"));
+    }
+}
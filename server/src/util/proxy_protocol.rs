@@ -0,0 +1,245 @@
+use crate::config::settings::SETTINGS;
+use axum::http::Request;
+use axum_server::accept::Accept;
+use futures_util::future::BoxFuture;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::task::{Context, Poll};
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::net::TcpStream;
+use tower::Service;
+
+/// Time the proxy has to send the header after opening the connection.
+const HEADER_TIMEOUT: Duration = Duration::from_secs(5);
+
+const V1_PREFIX: &[u8] = b"PROXY ";
+
+/// Including the prefix and the trailing CRLF.
+const V1_MAX_LENGTH: usize = 107;
+
+const V2_SIGNATURE: &[u8] = b"\r\n\r\n\0\r\nQUIT\n";
+
+/// The client address received via the PROXY protocol.
+/// `None` if the proxy did not forward an address, e.g. for its own health checks.
+#[derive(Clone, Copy, Debug)]
+pub struct ProxiedAddr(pub Option<SocketAddr>);
+
+/// Reads the PROXY protocol header before handing the connection to the inner acceptor.
+/// Does nothing unless the PROXY protocol is enabled.
+#[derive(Clone)]
+pub struct ProxyProtocolAcceptor<A> {
+    inner: A,
+}
+
+impl<A> ProxyProtocolAcceptor<A> {
+    pub fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+impl<A, S> Accept<TcpStream, S> for ProxyProtocolAcceptor<A>
+where
+    A: Accept<TcpStream, ProxiedService<S>> + Clone + Send + 'static,
+    A::Future: Send,
+    S: Send + 'static,
+{
+    type Stream = A::Stream;
+    type Service = A::Service;
+    type Future = BoxFuture<'static, io::Result<(Self::Stream, Self::Service)>>;
+
+    fn accept(&self, mut stream: TcpStream, service: S) -> Self::Future {
+        let inner = self.inner.clone();
+        Box::pin(async move {
+            let proxied = if SETTINGS.load().proxy.protocol {
+                let peer = stream.peer_addr()?;
+                if !SETTINGS.load().proxy.is_trusted(peer.ip()) {
+                    tracing::debug!(%peer, "Rejected PROXY protocol connection of untrusted peer");
+                    return Err(io::Error::new(
+                        io::ErrorKind::PermissionDenied,
+                        "Untrusted proxy",
+                    ));
+                }
+
+                tokio::time::timeout(HEADER_TIMEOUT, read_header(&mut stream))
+                    .await
+                    .map_err(|_| io::Error::from(io::ErrorKind::TimedOut))??
+            } else {
+                None
+            };
+
+            let service = ProxiedService {
+                inner: service,
+                addr: ProxiedAddr(proxied),
+            };
+            inner.accept(stream, service).await
+        })
+    }
+}
+
+/// Adds the [`ProxiedAddr`] of the connection to every request.
+#[derive(Clone)]
+pub struct ProxiedService<S> {
+    inner: S,
+    addr: ProxiedAddr,
+}
+
+impl<S, B> Service<Request<B>> for ProxiedService<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<B>) -> Self::Future {
+        request.extensions_mut().insert(self.addr);
+        self.inner.call(request)
+    }
+}
+
+/// Reads the PROXY protocol header (version 1 or 2) from the start of the stream.
+/// The stream is left at the first byte after the header.
+pub async fn read_header<R: AsyncRead + Unpin>(stream: &mut R) -> io::Result<Option<SocketAddr>> {
+    let mut prefix = [0; 6];
+    stream.read_exact(&mut prefix).await?;
+
+    if prefix == V1_PREFIX {
+        read_v1(stream).await
+    } else if prefix == V2_SIGNATURE[..6] {
+        read_v2(stream).await
+    } else {
+        Err(invalid_header())
+    }
+}
+
+/// Reads the rest of a text header, e.g. `TCP4 1.2.3.4 10.0.0.1 56324 443\r\n`.
+async fn read_v1<R: AsyncRead + Unpin>(stream: &mut R) -> io::Result<Option<SocketAddr>> {
+    // Read byte by byte so that nothing after the header is consumed.
+    let mut line = Vec::new();
+    while !line.ends_with(b"\r\n") {
+        if V1_PREFIX.len() + line.len() >= V1_MAX_LENGTH {
+            return Err(invalid_header());
+        }
+        line.push(stream.read_u8().await?);
+    }
+
+    let line = std::str::from_utf8(&line[..line.len() - 2]).map_err(|_| invalid_header())?;
+    let parts: Vec<&str> = line.split(' ').collect();
+    match parts.as_slice() {
+        ["TCP4" | "TCP6", source, _destination, source_port, _destination_port] => {
+            let ip: IpAddr = source.parse().map_err(|_| invalid_header())?;
+            let port: u16 = source_port.parse().map_err(|_| invalid_header())?;
+            Ok(Some(SocketAddr::new(ip, port)))
+        }
+        ["UNKNOWN", ..] => Ok(None),
+        _ => Err(invalid_header()),
+    }
+}
+
+/// Reads the rest of a binary header.
+async fn read_v2<R: AsyncRead + Unpin>(stream: &mut R) -> io::Result<Option<SocketAddr>> {
+    let mut header = [0; 10];
+    stream.read_exact(&mut header).await?;
+    if header[..6] != V2_SIGNATURE[6..] || header[6] >> 4 != 2 {
+        return Err(invalid_header());
+    }
+
+    let command = header[6] & 0x0f;
+    let family = header[7] >> 4;
+    let length = u16::from_be_bytes([header[8], header[9]]) as usize;
+
+    let mut addresses = vec![0; length];
+    stream.read_exact(&mut addresses).await?;
+
+    match (command, family) {
+        // LOCAL, e.g. health checks of the proxy.
+        (0, _) => Ok(None),
+        (1, 1) if length >= 12 => {
+            let ip = Ipv4Addr::new(addresses[0], addresses[1], addresses[2], addresses[3]);
+            let port = u16::from_be_bytes([addresses[8], addresses[9]]);
+            Ok(Some(SocketAddr::new(ip.into(), port)))
+        }
+        (1, 2) if length >= 36 => {
+            let ip: [u8; 16] = addresses[..16].try_into().unwrap();
+            let port = u16::from_be_bytes([addresses[32], addresses[33]]);
+            Ok(Some(SocketAddr::new(Ipv6Addr::from(ip).into(), port)))
+        }
+        // Unix sockets or unspecified.
+        (1, _) => Ok(None),
+        _ => Err(invalid_header()),
+    }
+}
+
+fn invalid_header() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "Invalid PROXY protocol header")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn read(data: &[u8]) -> (io::Result<Option<SocketAddr>>, Vec<u8>) {
+        let mut stream = data;
+        let result = read_header(&mut stream).await;
+        (result, stream.to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_read_v1() {
+        let (result, rest) = read(b"PROXY TCP4 1.2.3.4 10.0.0.1 56324 443\r\nGET /").await;
+        assert_eq!(result.unwrap(), Some("1.2.3.4:56324".parse().unwrap()));
+        assert_eq!(rest, b"GET /");
+
+        let (result, _) = read(b"PROXY TCP6 2001:db8::1 ::1 56324 443\r\n").await;
+        assert_eq!(
+            result.unwrap(),
+            Some("[2001:db8::1]:56324".parse().unwrap())
+        );
+
+        let (result, rest) = read(b"PROXY UNKNOWN\r\nGET /").await;
+        assert_eq!(result.unwrap(), None);
+        assert_eq!(rest, b"GET /");
+
+        for invalid in [
+            &b"GET / HTTP/1.1\r\n"[..],
+            b"PROXY TCP4 1.2.3.4\r\n",
+            b"PROXY TCP4 1.2.3.4 10.0.0.1 99999 443\r\n",
+            &[b"PROXY ".as_slice(), &[b'A'; 200]].concat(),
+        ] {
+            assert!(read(invalid).await.0.is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_v2() {
+        let mut data = V2_SIGNATURE.to_vec();
+        data.extend([0x21, 0x11, 0, 12]);
+        data.extend([1, 2, 3, 4, 10, 0, 0, 1]);
+        data.extend(56324u16.to_be_bytes());
+        data.extend(443u16.to_be_bytes());
+        data.extend(b"GET /");
+
+        let (result, rest) = read(&data).await;
+        assert_eq!(result.unwrap(), Some("1.2.3.4:56324".parse().unwrap()));
+        assert_eq!(rest, b"GET /");
+
+        // LOCAL with additional TLVs.
+        let mut data = V2_SIGNATURE.to_vec();
+        data.extend([0x20, 0x00, 0, 3, 1, 2, 3]);
+        data.extend(b"GET /");
+
+        let (result, rest) = read(&data).await;
+        assert_eq!(result.unwrap(), None);
+        assert_eq!(rest, b"GET /");
+
+        // Unsupported version.
+        let mut data = V2_SIGNATURE.to_vec();
+        data.extend([0x31, 0x11, 0, 0]);
+        assert!(read(&data).await.0.is_err());
+    }
+}
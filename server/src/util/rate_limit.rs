@@ -0,0 +1,175 @@
+use std::time::{Duration, Instant};
+
+/// The window of `max_requests_per_ip_per_hour`.
+pub const REQUEST_WINDOW: Duration = Duration::from_secs(60 * 60);
+
+/// Sliding window counter.
+/// The count of the previous window is weighted by how much of it still overlaps
+/// with the sliding window, so the count decays instead of resetting at fixed times.
+#[derive(Debug)]
+pub struct RequestWindow {
+    /// Start of the current fixed window.
+    start: Instant,
+    current: u32,
+    previous: u32,
+}
+
+impl RequestWindow {
+    pub fn new(now: Instant) -> Self {
+        Self {
+            start: now,
+            current: 0,
+            previous: 0,
+        }
+    }
+
+    /// Counts the request if the limit has not been reached.
+    /// Otherwise, returns the time until the next request would be accepted.
+    pub fn try_acquire(
+        &mut self,
+        now: Instant,
+        limit: u32,
+        window: Duration,
+    ) -> Result<(), Duration> {
+        self.advance(now, window);
+
+        let elapsed = now.duration_since(self.start).as_secs_f64() / window.as_secs_f64();
+        let estimated = self.previous as f64 * (1.0 - elapsed) + self.current as f64;
+        if estimated >= limit as f64 {
+            return Err(self.retry_after(elapsed, limit, window));
+        }
+
+        self.current += 1;
+        Ok(())
+    }
+
+    /// Whether the window has no effect anymore and can be removed.
+    pub fn is_stale(&self, now: Instant, window: Duration) -> bool {
+        now.duration_since(self.start) >= window * 2
+    }
+
+    /// Time until the estimated count drops below the limit.
+    /// `elapsed` is the fraction of the current fixed window that has passed.
+    fn retry_after(&self, elapsed: f64, limit: u32, window: Duration) -> Duration {
+        let (limit, current, previous) = (limit as f64, self.current as f64, self.previous as f64);
+        let fraction = if current < limit {
+            // The weight of the previous window has to decay far enough.
+            1.0 - (limit - current) / previous - elapsed
+        } else {
+            // Only the next window helps, in which the current window decays in the same way.
+            (1.0 - elapsed) + (1.0 - limit / current).max(0.0)
+        };
+
+        window.mul_f64(fraction.max(0.0))
+    }
+
+    fn advance(&mut self, now: Instant, window: Duration) {
+        let elapsed = now.duration_since(self.start);
+        if elapsed < window {
+            return;
+        }
+
+        if elapsed < window * 2 {
+            self.previous = self.current;
+            self.start += window;
+        } else {
+            self.previous = 0;
+            self.start = now;
+        }
+        self.current = 0;
+    }
+}
+
+/// Token bucket limiting the throughput of a peer.
+/// Holds up to one second of the rate, so short bursts are not slowed down.
+#[derive(Debug)]
+pub struct ByteBucket {
+    /// Negative if the peer has to wait.
+    available: f64,
+    updated: Instant,
+}
+
+impl ByteBucket {
+    pub fn new(now: Instant, bytes_per_second: usize) -> Self {
+        Self {
+            available: bytes_per_second as f64,
+            updated: now,
+        }
+    }
+
+    /// Takes the bytes from the bucket.
+    /// Returns how long to wait before sending them to keep the rate.
+    pub fn acquire(&mut self, now: Instant, bytes: usize, bytes_per_second: usize) -> Duration {
+        let rate = bytes_per_second as f64;
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.available = (self.available + elapsed * rate).min(rate) - bytes as f64;
+        self.updated = now;
+
+        if self.available >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.available / rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Duration = Duration::from_secs(100);
+
+    fn assert_retry_after(result: Result<(), Duration>, seconds: f64) {
+        let retry_after = result.unwrap_err();
+        assert!((retry_after.as_secs_f64() - seconds).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_sliding_window() {
+        let start = Instant::now();
+        let mut window = RequestWindow::new(start);
+
+        for _ in 0..10 {
+            assert!(window.try_acquire(start, 10, WINDOW).is_ok());
+        }
+        // The full window counts until it has ended.
+        assert_retry_after(window.try_acquire(start, 10, WINDOW), 100.0);
+
+        // Three quarters of the previous window still count.
+        let later = start + WINDOW + WINDOW / 4;
+        for _ in 0..3 {
+            assert!(window.try_acquire(later, 10, WINDOW).is_ok());
+        }
+        // The previous window has to decay from 7.5 to 7.
+        assert_retry_after(window.try_acquire(later, 10, WINDOW), 5.0);
+
+        assert!(!window.is_stale(later, WINDOW));
+        assert!(window.is_stale(start + WINDOW * 3, WINDOW));
+
+        // Everything has decayed.
+        let much_later = start + WINDOW * 5;
+        for _ in 0..10 {
+            assert!(window.try_acquire(much_later, 10, WINDOW).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_byte_bucket() {
+        let start = Instant::now();
+        let mut bucket = ByteBucket::new(start, 100);
+
+        assert_eq!(bucket.acquire(start, 100, 100), Duration::ZERO);
+        assert_eq!(bucket.acquire(start, 50, 100), Duration::from_millis(500));
+
+        // Refilled by 100 bytes, 50 of which were owed.
+        let later = start + Duration::from_secs(1);
+        assert_eq!(bucket.acquire(later, 50, 100), Duration::ZERO);
+
+        // Never holds more than one second.
+        let much_later = start + Duration::from_secs(10);
+        assert_eq!(
+            bucket.acquire(much_later, 150, 100),
+            Duration::from_millis(500)
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use crate::util;
+use flate2::read::ZlibDecoder;
+use localsend::webrtc::signaling::{SignalingErrorCode, WsClientSdpMessage};
+use std::io::Read;
+
+/// Maximum length of the SDP as sent by the client (compressed and encoded).
+pub const MAX_ENCODED_SDP_LENGTH: usize = 64 * 1024;
+
+/// Maximum size of the SDP after decompression.
+/// Protects against zip bombs.
+pub const MAX_DECOMPRESSED_SDP_SIZE: usize = 256 * 1024;
+
+pub const MAX_SESSION_ID_LENGTH: usize = 128;
+
+/// Checks an offer or answer before it is relayed to another peer.
+/// The SDP must be compressed with zlib and encoded with base64url without padding.
+pub fn validate_sdp_message(message: &WsClientSdpMessage) -> Result<(), SignalingErrorCode> {
+    if message.session_id.is_empty() || message.session_id.len() > MAX_SESSION_ID_LENGTH {
+        return Err(SignalingErrorCode::InvalidMessage);
+    }
+
+    if message.sdp.len() > MAX_ENCODED_SDP_LENGTH {
+        return Err(SignalingErrorCode::PayloadTooLarge);
+    }
+
+    let compressed =
+        util::base64::decode(&message.sdp).map_err(|_| SignalingErrorCode::InvalidMessage)?;
+
+    // Read one byte more than allowed to detect oversized SDPs without decompressing everything.
+    let mut sdp = Vec::new();
+    ZlibDecoder::new(compressed.as_slice())
+        .take(MAX_DECOMPRESSED_SDP_SIZE as u64 + 1)
+        .read_to_end(&mut sdp)
+        .map_err(|_| SignalingErrorCode::InvalidMessage)?;
+
+    if sdp.len() > MAX_DECOMPRESSED_SDP_SIZE {
+        return Err(SignalingErrorCode::PayloadTooLarge);
+    }
+
+    let sdp = std::str::from_utf8(&sdp).map_err(|_| SignalingErrorCode::InvalidMessage)?;
+    if !is_plausible_sdp(sdp) {
+        return Err(SignalingErrorCode::InvalidMessage);
+    }
+
+    Ok(())
+}
+
+/// An SDP starts with the version line and has at least one media description (the data channel).
+fn is_plausible_sdp(sdp: &str) -> bool {
+    sdp.starts_with("v=0") && sdp.lines().any(|line| line.starts_with("m="))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+    use base64::Engine;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+    use uuid::Uuid;
+
+    const SDP: &str = "v=0\r\no=- 123 2 IN IP4 127.0.0.1\r\ns=-\r\nt=0 0\r\nm=application 9 UDP/DTLS/SCTP webrtc-datachannel\r\n";
+
+    fn encode(data: &[u8]) -> String {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::best());
+        encoder.write_all(data).unwrap();
+        URL_SAFE_NO_PAD.encode(encoder.finish().unwrap())
+    }
+
+    fn message(sdp: String) -> WsClientSdpMessage {
+        WsClientSdpMessage {
+            session_id: "abc".to_string(),
+            target: Uuid::new_v4(),
+            sdp,
+        }
+    }
+
+    #[test]
+    fn test_valid_sdp() {
+        assert_eq!(
+            validate_sdp_message(&message(encode(SDP.as_bytes()))),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_invalid_sdp() {
+        for sdp in [
+            "not base64!".to_string(),
+            URL_SAFE_NO_PAD.encode(SDP),
+            encode(b"hello world"),
+            encode(&[0xff, 0xfe]),
+        ] {
+            assert_eq!(
+                validate_sdp_message(&message(sdp)),
+                Err(SignalingErrorCode::InvalidMessage)
+            );
+        }
+
+        let mut empty_session = message(encode(SDP.as_bytes()));
+        empty_session.session_id = String::new();
+        assert_eq!(
+            validate_sdp_message(&empty_session),
+            Err(SignalingErrorCode::InvalidMessage)
+        );
+    }
+
+    #[test]
+    fn test_sdp_size_limits() {
+        let too_long = "A".repeat(MAX_ENCODED_SDP_LENGTH + 1);
+        assert_eq!(
+            validate_sdp_message(&message(too_long)),
+            Err(SignalingErrorCode::PayloadTooLarge)
+        );
+
+        // Compresses to a few hundred bytes.
+        let bomb = format!("{SDP}{}", "a".repeat(MAX_DECOMPRESSED_SDP_SIZE));
+        let bomb = encode(bomb.as_bytes());
+        assert!(bomb.len() < MAX_ENCODED_SDP_LENGTH);
+        assert_eq!(
+            validate_sdp_message(&message(bomb)),
+            Err(SignalingErrorCode::PayloadTooLarge)
+        );
+    }
+}
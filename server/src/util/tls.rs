@@ -0,0 +1,107 @@
+use crate::config::settings::TlsSettings;
+use axum_server::tls_rustls::RustlsConfig;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+/// How often the certificate files are checked for changes.
+const RELOAD_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Time to let the renewal finish writing both files before reloading them.
+const SETTLE_DELAY: Duration = Duration::from_secs(5);
+
+/// Reloads the certificate and key when one of the files changes, e.g. after a renewal.
+/// If the new files cannot be loaded, the previous certificate stays in use.
+pub async fn reload_on_change(config: RustlsConfig, tls: TlsSettings) {
+    let mut last_modified = modified(&tls).await;
+    loop {
+        tokio::time::sleep(RELOAD_INTERVAL).await;
+
+        if modified(&tls).await == last_modified {
+            continue;
+        }
+
+        tokio::time::sleep(SETTLE_DELAY).await;
+        let current_modified = modified(&tls).await;
+        match config.reload_from_pem_file(&tls.cert, &tls.key).await {
+            Ok(()) => {
+                tracing::info!("Reloaded TLS certificate");
+                last_modified = current_modified;
+            }
+            Err(e) => tracing::warn!(error = ?e, "Error reloading TLS certificate"),
+        }
+    }
+}
+
+/// Modification times of the certificate and the key.
+async fn modified(tls: &TlsSettings) -> Option<(SystemTime, SystemTime)> {
+    Some((
+        modified_file(&tls.cert).await?,
+        modified_file(&tls.key).await?,
+    ))
+}
+
+async fn modified_file(path: &Path) -> Option<SystemTime> {
+    tokio::fs::metadata(path).await.ok()?.modified().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    /// Writes a new self-signed certificate and key, last modified at `modified`.
+    fn write_certificate(tls: &TlsSettings, modified: SystemTime) {
+        let key = rcgen::KeyPair::generate().unwrap();
+        let cert = rcgen::CertificateParams::new(vec!["localhost".to_string()])
+            .unwrap()
+            .self_signed(&key)
+            .unwrap();
+        std::fs::write(&tls.cert, cert.pem()).unwrap();
+        std::fs::write(&tls.key, key.serialize_pem()).unwrap();
+        touch(&tls.cert, modified);
+        touch(&tls.key, modified);
+    }
+
+    fn touch(path: &Path, modified: SystemTime) {
+        let file = std::fs::File::options().write(true).open(path).unwrap();
+        file.set_modified(modified).unwrap();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_reload_on_change() {
+        let _ = rustls::crypto::ring::default_provider().install_default();
+        let dir = std::env::temp_dir().join(format!("localsend-tls-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let tls = TlsSettings {
+            cert: dir.join("cert.pem"),
+            key: dir.join("key.pem"),
+        };
+        let start = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        write_certificate(&tls, start);
+
+        let config = RustlsConfig::from_pem_file(&tls.cert, &tls.key)
+            .await
+            .unwrap();
+        let initial = config.get_inner();
+        tokio::spawn(reload_on_change(config.clone(), tls.clone()));
+        let check = RELOAD_INTERVAL + SETTLE_DELAY + Duration::from_secs(1);
+
+        // Unchanged files are not reloaded.
+        tokio::time::sleep(check).await;
+        assert!(Arc::ptr_eq(&initial, &config.get_inner()));
+
+        // A renewed certificate replaces the previous one.
+        write_certificate(&tls, start + Duration::from_secs(60));
+        tokio::time::sleep(check).await;
+        let renewed = config.get_inner();
+        assert!(!Arc::ptr_eq(&initial, &renewed));
+
+        // Files that cannot be loaded keep the previous certificate in use.
+        std::fs::write(&tls.key, "invalid").unwrap();
+        touch(&tls.key, start + Duration::from_secs(120));
+        tokio::time::sleep(check).await;
+        assert!(Arc::ptr_eq(&renewed, &config.get_inner()));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,45 @@
+use std::io;
+use std::os::unix::fs::FileTypeExt;
+use std::path::Path;
+use tokio::net::UnixListener;
+
+/// Binds the unix socket at `path`.
+/// A socket left behind by a previous run is replaced, other files are not touched.
+pub fn bind(path: &Path) -> io::Result<UnixListener> {
+    match std::fs::symlink_metadata(path) {
+        Ok(metadata) if metadata.file_type().is_socket() => std::fs::remove_file(path)?,
+        Ok(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                "File exists and is not a socket",
+            ))
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => (),
+        Err(e) => return Err(e),
+    }
+
+    UnixListener::bind(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_bind() {
+        let dir = std::env::temp_dir().join(format!("localsend-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir(&dir).unwrap();
+
+        // Stale sockets are replaced.
+        let socket = dir.join("server.sock");
+        drop(bind(&socket).unwrap());
+        assert!(bind(&socket).is_ok());
+
+        let file = dir.join("file");
+        std::fs::write(&file, "data").unwrap();
+        assert!(bind(&file).is_err());
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "data");
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}